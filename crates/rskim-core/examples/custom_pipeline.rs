@@ -0,0 +1,139 @@
+//! A custom transform pipeline: budget cascade, validation, truncation.
+//!
+//! The CLI's `--tokens` flag is a pipeline built from public library pieces.
+//! This example assembles a variant of it for one file:
+//!
+//! 1. transform with [`TransformConfig`] (public declarations only), trying
+//!    each mode of [`Mode::cascade_from_here`] until the output fits;
+//! 2. check that structure-mode output still parses as well as the source
+//!    did, with [`validate_output`];
+//! 3. if even the most aggressive mode is over budget, cut it down with
+//!    [`truncate_to_token_budget`].
+//!
+//! Tokens are counted by a caller-supplied closure; here, whitespace-separated
+//! words stand in for a real tokenizer.
+//!
+//! ```text
+//! cargo run -p rskim-core --example custom_pipeline -- crates/rskim-core/src/lib.rs 300
+//! ```
+
+use rskim_core::{
+    Language, Mode, Result, SkimError, TransformConfig, detect_language_from_path,
+    transform_with_config, truncate_to_token_budget, validate_output,
+};
+
+fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let path = std::path::PathBuf::from(
+        args.next()
+            .ok_or("usage: custom_pipeline <FILE> [BUDGET]")?,
+    );
+    let budget = match args.next() {
+        Some(budget) => budget.parse()?,
+        None => 500,
+    };
+    let language = detect_language_from_path(&path)
+        .ok_or_else(|| SkimError::UnsupportedLanguage(path.clone()))?;
+    let source = std::fs::read_to_string(&path)?;
+
+    let result = run(&source, language, budget)?;
+    println!("{}", result.text);
+    eprintln!(
+        "mode {}{}, {} of {budget} words{}",
+        result.mode.name(),
+        if result.truncated { " (truncated)" } else { "" },
+        count_words(&result.text),
+        match result.introduced_errors {
+            Some(0) => ", parses cleanly".to_string(),
+            Some(n) => format!(", {n} new parse error(s)"),
+            None => String::new(),
+        }
+    );
+    Ok(())
+}
+
+/// The pipeline's output and how it was reached.
+#[derive(Debug)]
+struct PipelineOutput {
+    text: String,
+    /// Mode that produced `text`.
+    mode: Mode,
+    truncated: bool,
+    /// Parse errors the output has beyond the source's, for structure mode.
+    introduced_errors: Option<usize>,
+}
+
+/// Stand-in tokenizer: whitespace-separated words.
+fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// Reduce `source` to at most `budget` words of public declarations.
+fn run(source: &str, language: Language, budget: usize) -> Result<PipelineOutput> {
+    let mut last = None;
+    for &mode in Mode::Structure.cascade_from_here() {
+        let config = TransformConfig::with_mode(mode).with_public_only(true);
+        let text = transform_with_config(source, language, &config)?;
+        if count_words(&text) <= budget {
+            let introduced_errors = match mode {
+                Mode::Structure => validate_output(source, &text, language)?
+                    .map(|report| report.introduced_errors()),
+                _ => None,
+            };
+            return Ok(PipelineOutput {
+                text,
+                mode,
+                truncated: false,
+                introduced_errors,
+            });
+        }
+        last = Some((mode, text));
+    }
+
+    let Some((mode, text)) = last else {
+        return Err(SkimError::ParseError("empty mode cascade".to_string()));
+    };
+    let text = truncate_to_token_budget(&text, language, budget, count_words, None)?;
+    Ok(PipelineOutput {
+        text,
+        mode,
+        truncated: true,
+        introduced_errors: None,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "/// A user.\npub struct User {\n    pub name: String,\n}\n\n\
+                          pub fn greet(user: &User) -> String {\n    format!(\"hi {}\", user.name)\n}\n\n\
+                          fn audit(user: &User) {\n    println!(\"{}\", user.name);\n}\n";
+
+    #[test]
+    fn test_generous_budget_keeps_validated_structure() {
+        let result = run(SOURCE, Language::Rust, 1_000).unwrap();
+        assert_eq!(result.mode, Mode::Structure);
+        assert_eq!(result.introduced_errors, Some(0));
+        assert!(result.text.contains("pub fn greet(user: &User) -> String"));
+        assert!(!result.text.contains("audit"));
+    }
+
+    #[test]
+    fn test_tight_budget_cascades_to_a_smaller_mode() {
+        let structure = run(SOURCE, Language::Rust, 1_000).unwrap();
+        let result = run(SOURCE, Language::Rust, count_words(&structure.text) - 1).unwrap();
+        assert_ne!(result.mode, Mode::Structure);
+        assert!(!result.truncated);
+        assert_eq!(result.introduced_errors, None);
+    }
+
+    #[test]
+    fn test_impossible_budget_truncates_the_last_mode() {
+        let result = run(SOURCE, Language::Rust, 1).unwrap();
+        assert!(result.truncated);
+        assert_eq!(result.mode, Mode::Types);
+        assert!(count_words(&result.text) <= 1);
+    }
+}
@@ -0,0 +1,157 @@
+//! Pack a directory into one bundle for an LLM prompt.
+//!
+//! Walks a directory, transforms every file rskim-core recognizes, and
+//! concatenates the results under `// <path>` headers until a byte budget is
+//! spent: the library-side equivalent of `skim <dir>`.
+//!
+//! ```text
+//! cargo run -p rskim-core --example pack_repo -- crates/rskim-core/src --mode signatures --max-bytes 20000
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use rskim_core::{Mode, TransformConfig, detect_language_from_path, transform_with_config};
+
+/// Directories never worth packing.
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules", "dist", "build"];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let mut root = None;
+    let mut mode = Mode::Structure;
+    let mut max_bytes = usize::MAX;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--mode" => {
+                let name = args.next().ok_or("--mode requires a value")?;
+                mode = Mode::parse(&name).ok_or_else(|| format!("unknown mode '{name}'"))?;
+            }
+            "--max-bytes" => {
+                max_bytes = args.next().ok_or("--max-bytes requires a value")?.parse()?;
+            }
+            _ => root = Some(PathBuf::from(arg)),
+        }
+    }
+    let root = root.ok_or("usage: pack_repo <DIR> [--mode MODE] [--max-bytes N]")?;
+
+    let mut files = Vec::new();
+    for path in collect_files(&root)? {
+        // Binary and non-UTF-8 files are not source code.
+        if let Ok(source) = std::fs::read_to_string(&path) {
+            files.push((path, source));
+        }
+    }
+    let packed = pack(&files, mode, max_bytes);
+    print!("{}", packed.text);
+    eprintln!(
+        "packed {} file(s) in {} bytes ({} unsupported, {} over budget)",
+        packed.included,
+        packed.text.len(),
+        packed.unsupported,
+        packed.omitted
+    );
+    Ok(())
+}
+
+/// A packed bundle and what went into it.
+#[derive(Debug, Default)]
+struct Packed {
+    text: String,
+    included: usize,
+    /// Files rskim-core has no language for, or failed to transform.
+    unsupported: usize,
+    /// Files left out once the budget was spent.
+    omitted: usize,
+}
+
+/// Transform `files` with `mode` and concatenate them, in order, under
+/// `// <path>` headers, stopping before the bundle exceeds `max_bytes`.
+fn pack(files: &[(PathBuf, String)], mode: Mode, max_bytes: usize) -> Packed {
+    let config = TransformConfig::with_mode(mode);
+    let mut packed = Packed::default();
+    for (path, source) in files {
+        let output = detect_language_from_path(path)
+            .and_then(|language| transform_with_config(source, language, &config).ok());
+        let Some(output) = output else {
+            packed.unsupported += 1;
+            continue;
+        };
+        let entry = format!("// {}\n{}\n\n", path.display(), output.trim_end());
+        if packed.omitted > 0 || packed.text.len() + entry.len() > max_bytes {
+            packed.omitted += 1;
+            continue;
+        }
+        packed.text.push_str(&entry);
+        packed.included += 1;
+    }
+    packed
+}
+
+/// Every file under `root`, sorted, skipping hidden and build directories.
+fn collect_files(root: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if name.starts_with('.') {
+                continue;
+            }
+            if path.is_dir() {
+                if !SKIPPED_DIRS.contains(&name) {
+                    pending.push(path);
+                }
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn files() -> Vec<(PathBuf, String)> {
+        vec![
+            (
+                PathBuf::from("src/lib.rs"),
+                "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n".to_string(),
+            ),
+            (PathBuf::from("logo.bin"), "\u{1}\u{2}".to_string()),
+            (
+                PathBuf::from("app.py"),
+                "def main():\n    print('hi')\n".to_string(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_pack_transforms_supported_files_under_headers() {
+        let packed = pack(&files(), Mode::Signatures, usize::MAX);
+        assert_eq!(
+            packed.text,
+            "// src/lib.rs\npub fn add(a: i32, b: i32) -> i32\n\n// app.py\ndef main():\n\n"
+        );
+        assert_eq!((packed.included, packed.unsupported), (2, 1));
+    }
+
+    #[test]
+    fn test_pack_stops_at_the_budget() {
+        let packed = pack(&files(), Mode::Signatures, 60);
+        assert_eq!(packed.included, 1);
+        assert_eq!(packed.omitted, 1);
+        assert!(packed.text.len() <= 60);
+    }
+
+    #[test]
+    fn test_collect_files_walks_this_crate() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let files = collect_files(&root).unwrap();
+        assert!(files.iter().any(|path| path.ends_with("pack_repo.rs")));
+    }
+}
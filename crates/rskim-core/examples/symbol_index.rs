@@ -0,0 +1,138 @@
+//! Build a symbol index over source files and look names up in it.
+//!
+//! [`rskim_core::symbols`] lists the declarations of one file with their
+//! qualified names and line ranges. Collected over many files it is a small
+//! go-to-definition index, without a language server:
+//!
+//! ```text
+//! cargo run -p rskim-core --example symbol_index -- crates/rskim-core/src/*.rs --find symbols
+//! ```
+//!
+//! Without `--find`, every symbol is printed as `name<TAB>path:line<TAB>kind`.
+
+use std::path::{Path, PathBuf};
+
+use rskim_core::{Symbol, detect_language_from_path, symbols};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let mut paths = Vec::new();
+    let mut query = None;
+    while let Some(arg) = args.next() {
+        if arg == "--find" {
+            query = Some(args.next().ok_or("--find requires a name")?);
+        } else {
+            paths.push(PathBuf::from(arg));
+        }
+    }
+    if paths.is_empty() {
+        return Err("usage: symbol_index <FILE>... [--find NAME]".into());
+    }
+
+    let mut index = Vec::new();
+    for path in &paths {
+        let source = std::fs::read_to_string(path)?;
+        index.extend(index_file(path, &source));
+    }
+    index.sort_by(|a, b| a.name.cmp(&b.name).then(a.line.cmp(&b.line)));
+
+    let entries: Vec<&Entry> = match &query {
+        Some(name) => lookup(&index, name),
+        None => index.iter().collect(),
+    };
+    for entry in &entries {
+        println!(
+            "{}\t{}:{}\t{}",
+            entry.name,
+            entry.path.display(),
+            entry.line,
+            entry.kind
+        );
+    }
+    if query.is_some() && entries.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// One indexed declaration.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    /// Qualified name (`Config.validate`).
+    name: String,
+    path: PathBuf,
+    /// 1-indexed start line.
+    line: usize,
+    kind: &'static str,
+}
+
+/// Index the declarations of one file; files in languages without symbol
+/// extraction contribute nothing.
+fn index_file(path: &Path, source: &str) -> Vec<Entry> {
+    let Some(language) = detect_language_from_path(path) else {
+        return Vec::new();
+    };
+    symbols(source, language)
+        .unwrap_or_default()
+        .into_iter()
+        .map(
+            |Symbol {
+                 name,
+                 kind,
+                 start_line,
+                 ..
+             }| Entry {
+                name,
+                path: path.to_path_buf(),
+                line: start_line,
+                kind,
+            },
+        )
+        .collect()
+}
+
+/// Entries named `name`, matching either the qualified name or its last
+/// segment, so `validate` finds `Config.validate`.
+fn lookup<'a>(index: &'a [Entry], name: &str) -> Vec<&'a Entry> {
+    index
+        .iter()
+        .filter(|entry| entry.name == name || entry.name.rsplit('.').next() == Some(name))
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_and_lookup_across_languages() {
+        let mut index = index_file(
+            Path::new("config.rs"),
+            "pub struct Config;\n\nimpl Config {\n    pub fn validate(&self) {}\n}\n",
+        );
+        index.extend(index_file(
+            Path::new("check.py"),
+            "def validate(data):\n    return True\n",
+        ));
+
+        let found = lookup(&index, "validate");
+        let locations: Vec<(String, usize)> = found
+            .iter()
+            .map(|entry| (entry.path.display().to_string(), entry.line))
+            .collect();
+        assert_eq!(
+            locations,
+            [("config.rs".to_string(), 4), ("check.py".to_string(), 1)]
+        );
+        // The struct and its `impl` block both declare `Config`.
+        let kinds: Vec<&str> = lookup(&index, "Config").iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, ["struct_item", "impl_item"]);
+        assert!(lookup(&index, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_files_are_skipped() {
+        assert!(index_file(Path::new("notes.unknown"), "fn x() {}\n").is_empty());
+    }
+}
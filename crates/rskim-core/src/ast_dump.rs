@@ -0,0 +1,245 @@
+//! Sanitized AST dumps for query authors and bug reports.
+//!
+//! # Design
+//!
+//! The dump contains node kinds, field names, byte spans, and row/column
+//! positions — never source text — so it can be pasted into an issue without
+//! leaking the file's contents. Anonymous nodes (punctuation and keywords) are
+//! omitted because they are always leaves and carry no structure; MISSING
+//! nodes inserted by error recovery are kept and flagged, since they are
+//! usually what a bug report is about.
+//!
+//! Two formats are supported:
+//!
+//! - [`AstFormat::SExpression`]: one node per line, indented by depth, in the
+//!   style of tree-sitter's `to_sexp()` with spans added —
+//!   `name: (identifier [3..7])`.
+//! - [`AstFormat::Json`]: a single JSON object per file with nested `children`.
+//!   The root object also carries `schema_version`
+//!   ([`AST_JSON_SCHEMA_VERSION`]), bumped on any incompatible change to the
+//!   node shape.
+//!
+//! # Example
+//!
+//! ```
+//! use rskim_core::{dump_ast, AstFormat, Language};
+//!
+//! let dump = dump_ast("fn main() {}", Language::Rust, AstFormat::SExpression)?;
+//! assert!(dump.starts_with("(source_file [0..12]"));
+//! assert!(dump.contains("name: (identifier [3..7])"));
+//! # Ok::<(), rskim_core::SkimError>(())
+//! ```
+
+use crate::transform::minimal::{MAX_AST_DEPTH, MAX_AST_NODES};
+use crate::{Language, Parser, Result, SkimError};
+use serde_json::{Map, Value, json};
+use std::fmt::Write as _;
+use tree_sitter::Node;
+
+/// Output format for [`dump_ast`](crate::dump_ast).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AstFormat {
+    /// Indented S-expression with byte spans, one named node per line.
+    SExpression,
+    /// Nested JSON objects with kinds, fields, byte spans, and positions.
+    Json,
+}
+
+/// Version of the [`AstFormat::Json`] node shape, emitted as the root's
+/// `schema_version`.
+pub const AST_JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Parse `source` and render its sanitized AST in `format`.
+pub(crate) fn dump_ast(source: &str, language: Language, format: AstFormat) -> Result<String> {
+    let tree = Parser::new(language)?.parse(source)?;
+    let mut nodes = 0;
+    match format {
+        AstFormat::SExpression => {
+            let mut out = String::with_capacity(source.len() * 2);
+            write_sexp(&mut out, tree.root_node(), None, 0, &mut nodes)?;
+            out.push('\n');
+            Ok(out)
+        }
+        AstFormat::Json => {
+            let value = to_json(tree.root_node(), None, 0, &mut nodes)?;
+            let mut out = value.to_string();
+            out.push('\n');
+            Ok(out)
+        }
+    }
+}
+
+/// Whether `node` appears in the dump.
+fn is_dumped(node: Node) -> bool {
+    node.is_named() || node.is_missing()
+}
+
+/// Enforce the shared depth and node-count safety caps.
+fn check_limits(depth: usize, nodes: &mut usize) -> Result<()> {
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {}",
+            MAX_AST_DEPTH
+        )));
+    }
+    *nodes += 1;
+    if *nodes > MAX_AST_NODES {
+        return Err(SkimError::ComplexityLimit {
+            what: "AST nodes",
+            count: *nodes,
+            max: MAX_AST_NODES,
+        });
+    }
+    Ok(())
+}
+
+/// Visit the dumped children of `node` with their field names.
+fn for_each_dumped_child(
+    node: Node,
+    mut f: impl FnMut(Node, Option<&'static str>) -> Result<()>,
+) -> Result<()> {
+    let mut cursor = node.walk();
+    if !cursor.goto_first_child() {
+        return Ok(());
+    }
+    loop {
+        let child = cursor.node();
+        if is_dumped(child) {
+            f(child, cursor.field_name())?;
+        }
+        if !cursor.goto_next_sibling() {
+            return Ok(());
+        }
+    }
+}
+
+fn write_sexp(
+    out: &mut String,
+    node: Node,
+    field: Option<&str>,
+    depth: usize,
+    nodes: &mut usize,
+) -> Result<()> {
+    check_limits(depth, nodes)?;
+
+    if depth > 0 {
+        out.push('\n');
+    }
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+    if let Some(field) = field {
+        out.push_str(field);
+        out.push_str(": ");
+    }
+    out.push('(');
+    if node.is_missing() {
+        out.push_str("MISSING ");
+    }
+    // Infallible: writing to a String cannot fail. Anonymous kinds (only
+    // reachable for MISSING nodes) are quoted like tree-sitter's `to_sexp()`,
+    // so a missing `)` cannot unbalance the expression.
+    let _ = if node.is_named() {
+        write!(out, "{}", node.kind())
+    } else {
+        write!(out, "{:?}", node.kind())
+    };
+    let _ = write!(out, " [{}..{}]", node.start_byte(), node.end_byte());
+    for_each_dumped_child(node, |child, field| {
+        write_sexp(out, child, field, depth + 1, nodes)
+    })?;
+    out.push(')');
+    Ok(())
+}
+
+fn to_json(node: Node, field: Option<&str>, depth: usize, nodes: &mut usize) -> Result<Value> {
+    check_limits(depth, nodes)?;
+
+    let mut obj = Map::new();
+    if depth == 0 {
+        obj.insert("schema_version".into(), json!(AST_JSON_SCHEMA_VERSION));
+    }
+    obj.insert("kind".into(), json!(node.kind()));
+    if let Some(field) = field {
+        obj.insert("field".into(), json!(field));
+    }
+    obj.insert("start_byte".into(), json!(node.start_byte()));
+    obj.insert("end_byte".into(), json!(node.end_byte()));
+    let (start, end) = (node.start_position(), node.end_position());
+    obj.insert("start".into(), json!([start.row, start.column]));
+    obj.insert("end".into(), json!([end.row, end.column]));
+    if node.is_missing() {
+        obj.insert("missing".into(), json!(true));
+    }
+
+    let mut children = Vec::new();
+    for_each_dumped_child(node, |child, field| {
+        children.push(to_json(child, field, depth + 1, nodes)?);
+        Ok(())
+    })?;
+    if !children.is_empty() {
+        obj.insert("children".into(), Value::Array(children));
+    }
+    Ok(Value::Object(obj))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sexp_has_fields_spans_and_no_source_text() {
+        let source = "def secret_token():\n    return 'hunter2'\n";
+        let dump = dump_ast(source, Language::Python, AstFormat::SExpression).unwrap();
+        assert!(dump.starts_with("(module [0.."), "{dump}");
+        assert!(dump.contains("\n  (function_definition [0..40]"), "{dump}");
+        assert!(dump.contains("    name: (identifier [4..16])"), "{dump}");
+        assert!(
+            !dump.contains("secret_token"),
+            "dump must not contain source text"
+        );
+        assert!(
+            !dump.contains("hunter2"),
+            "dump must not contain source text"
+        );
+        assert!(dump.ends_with(")\n"));
+        assert_eq!(
+            dump.matches('(').count(),
+            dump.matches(')').count(),
+            "{dump}"
+        );
+    }
+
+    #[test]
+    fn test_sexp_flags_missing_nodes() {
+        let dump = dump_ast(
+            "fn main() { let x = 1 }",
+            Language::Rust,
+            AstFormat::SExpression,
+        )
+        .unwrap();
+        assert!(dump.contains("(MISSING \";\" ["), "{dump}");
+    }
+
+    #[test]
+    fn test_json_nests_children_with_positions() {
+        let dump = dump_ast("fn main() {}", Language::Rust, AstFormat::Json).unwrap();
+        let value: Value = serde_json::from_str(&dump).unwrap();
+        assert_eq!(value["schema_version"], AST_JSON_SCHEMA_VERSION);
+        assert_eq!(value["kind"], "source_file");
+        let function = &value["children"][0];
+        assert!(function.get("schema_version").is_none());
+        assert_eq!(function["kind"], "function_item");
+        let name = &function["children"][0];
+        assert_eq!(name["field"], "name");
+        assert_eq!(name["start_byte"], 3);
+        assert_eq!(name["end_byte"], 7);
+        assert_eq!(name["start"], json!([0, 3]));
+    }
+
+    #[test]
+    fn test_dump_rejects_non_tree_sitter_languages() {
+        assert!(dump_ast("{}", Language::Json, AstFormat::Json).is_err());
+    }
+}
@@ -44,6 +44,14 @@ pub use types::{Language, Mode, Parser, Result, SkimError, TransformConfig, Tran
 
 pub use ast_walk::{AstWalkConfig, AstWalkIter, AstWalkNode};
 
+pub use ast_dump::{AST_JSON_SCHEMA_VERSION, AstFormat};
+
+pub use symbols::Symbol;
+
+pub use transform::explain::{ExplainAction, ExplainDecision, ExplainReason, Explanation};
+
+pub use validate::OutputValidation;
+
 /// Return the structural priority of a tree-sitter node kind (1–5).
 ///
 /// Used by the BM25F classifier to map node kinds to [`SearchField`] variants.
@@ -63,10 +71,13 @@ pub fn node_kind_priority(kind: &str) -> u8 {
     transform::utils::node_kind_info(kind).1
 }
 
+mod ast_dump;
 pub mod ast_walk;
 mod parser;
+mod symbols;
 mod transform;
 mod types;
+mod validate;
 
 // NOTE: Caching is implemented at the CLI layer (rskim binary), not in the core library.
 // The core library remains pure and I/O-free.
@@ -230,6 +241,34 @@ pub fn transform_with_line_map(
     language.transform_source_with_line_map(source, config)
 }
 
+/// Transform source code once per configuration, parsing it at most once.
+///
+/// Equivalent to calling [`transform_with_line_map`] for each entry of
+/// `configs`, in order, but tree-sitter languages share a single parse.
+/// Used to fill the cache for several modes from one read of a file.
+///
+/// # Errors
+///
+/// The first error from any configuration; a parse failure fails them all.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rskim_core::{transform_many_with_line_map, Language, Mode, TransformConfig};
+///
+/// let configs: Vec<_> = Mode::ALL.into_iter().map(TransformConfig::with_mode).collect();
+/// let results = transform_many_with_line_map("fn main() {}", Language::Rust, &configs)?;
+/// assert_eq!(results.len(), Mode::ALL.len());
+/// # Ok::<(), rskim_core::SkimError>(())
+/// ```
+pub fn transform_many_with_line_map(
+    source: &str,
+    language: Language,
+    configs: &[TransformConfig],
+) -> Result<Vec<types::LineMappedOutput>> {
+    language.transform_source_many_with_line_map(source, configs)
+}
+
 /// Transform source code with automatic language detection from file path
 ///
 /// Convenience function that detects language from file extension.
@@ -342,6 +381,131 @@ pub fn transform_detailed(source: &str, language: Language, mode: Mode) -> Resul
     })
 }
 
+/// Dump the parsed AST of `source` with byte spans
+///
+/// The dump lists named node kinds, field names, and spans but no source
+/// text, so it is safe to attach to bug reports. Useful when writing custom
+/// tree-sitter queries or pinpointing an extraction bug.
+///
+/// # Errors
+///
+/// - `SkimError::ConfigError` - the language is not parsed with tree-sitter
+///   (JSON, YAML, TOML, Vue, Svelte, XML, HTML, HCL,
+///   Dockerfile, Groovy, CSV, .env, INI, reStructuredText, AsciiDoc)
+/// - `SkimError::ParseError` - tree-sitter failed to parse, or the tree is
+///   deeper than the safety cap
+/// - `SkimError::ComplexityLimit` - the tree has more nodes than the safety cap
+///
+/// # Examples
+///
+/// ```
+/// use rskim_core::{dump_ast, AstFormat, Language};
+///
+/// let sexp = dump_ast("let x = 1;", Language::JavaScript, AstFormat::SExpression)?;
+/// assert!(sexp.contains("(lexical_declaration [0..10]"));
+/// # Ok::<(), rskim_core::SkimError>(())
+/// ```
+pub fn dump_ast(source: &str, language: Language, format: AstFormat) -> Result<String> {
+    ast_dump::dump_ast(source, language, format)
+}
+
+/// List the named declarations in `source`, in source order
+///
+/// Reports functions, methods, types and containers (classes, impls, modules)
+/// with their 1-indexed line range, nesting depth, and visibility. Nested
+/// symbols are qualified by their parents (`Config.validate`). Data formats,
+/// Markdown, reStructuredText, AsciiDoc, XML, HTML, HCL, Dockerfile, Groovy,
+/// CSV, `.env`, INI, and Vue/Svelte files have no symbols.
+///
+/// # Errors
+///
+/// - `SkimError::ParseError` - tree-sitter failed to parse, or the tree is
+///   deeper than the safety cap
+///
+/// # Examples
+///
+/// ```
+/// use rskim_core::{symbols, Language};
+///
+/// let found = symbols("def load():\n    pass\n", Language::Python)?;
+/// assert_eq!(found[0].name, "load");
+/// assert_eq!((found[0].start_line, found[0].end_line), (1, 2));
+/// # Ok::<(), rskim_core::SkimError>(())
+/// ```
+pub fn symbols(source: &str, language: Language) -> Result<Vec<Symbol>> {
+    symbols::symbols(source, language)
+}
+
+/// Trace the decisions structure mode makes for `source`
+///
+/// Reports the named AST node kinds visited, which nodes matched as functions
+/// or type definitions, which body/template-literal replacements were planned
+/// and why, and which were dropped by overlap resolution. Intended for
+/// debugging "why did skim keep/drop X" without reading the transformer.
+/// Honors `config.template_literal_threshold`; the mode is ignored.
+///
+/// # Errors
+///
+/// - `SkimError::ConfigError` - the language does not use body replacement
+///   (Markdown, JSON, YAML, TOML, Vue, Svelte, XML, HTML, HCL,
+///   Dockerfile, Groovy, OCaml interfaces, CSV, .env, INI, reStructuredText,
+///   AsciiDoc)
+/// - `SkimError::ParseError` - tree-sitter failed to parse
+///
+/// # Examples
+///
+/// ```no_run
+/// use rskim_core::{explain_structure, ExplainAction, Language, Mode, TransformConfig};
+///
+/// let config = TransformConfig::with_mode(Mode::Structure);
+/// let trace = explain_structure("fn main() { run(); }", Language::Rust, &config)?;
+/// assert!(matches!(trace.decisions[0].action, ExplainAction::Replaced { .. }));
+/// # Ok::<(), rskim_core::SkimError>(())
+/// ```
+pub fn explain_structure(
+    source: &str,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<Explanation> {
+    transform::explain::explain_structure(source, language, config)
+}
+
+/// Re-parse transformed `output` and count syntax errors against `source`
+///
+/// Both are parsed with `language`'s tree-sitter grammar; the report's
+/// [`OutputValidation::introduced_errors`] is how many more errors the output
+/// has than the source. Skim's `{...}` body placeholder is normalized to `{}`
+/// before parsing, so structure-mode elisions are not errors. Signatures,
+/// types and pseudo output are listings rather than programs, and usually
+/// report errors.
+///
+/// Returns `Ok(None)` for languages without a tree-sitter grammar (JSON,
+/// YAML, TOML, and the scanner-based formats).
+///
+/// # Errors
+///
+/// - `SkimError::TreeSitterError` - Grammar loading failed
+/// - `SkimError::ParseError` - tree-sitter failed to parse
+///
+/// # Examples
+///
+/// ```
+/// use rskim_core::{transform, validate_output, Language, Mode};
+///
+/// let source = "export function load(id: string) { return db.get(id); }\n";
+/// let output = transform(source, Language::TypeScript, Mode::Structure)?;
+/// let report = validate_output(source, &output, Language::TypeScript)?.unwrap();
+/// assert_eq!(report.introduced_errors(), 0);
+/// # Ok::<(), rskim_core::SkimError>(())
+/// ```
+pub fn validate_output(
+    source: &str,
+    output: &str,
+    language: Language,
+) -> Result<Option<OutputValidation>> {
+    validate::validate_output(source, output, language)
+}
+
 // ============================================================================
 // Token Budget Truncation
 // ============================================================================
@@ -444,6 +608,7 @@ pub fn version() -> &'static str {
 pub fn supported_languages() -> &'static [Language] {
     &[
         Language::TypeScript,
+        Language::Tsx,
         Language::JavaScript,
         Language::Python,
         Language::Rust,
@@ -460,6 +625,26 @@ pub fn supported_languages() -> &'static [Language] {
         Language::Sql,
         Language::Kotlin,
         Language::Swift,
+        Language::Vue,
+        Language::Svelte,
+        Language::Xml,
+        Language::Html,
+        Language::Hcl,
+        Language::Dockerfile,
+        Language::Shell,
+        Language::Elixir,
+        Language::Dart,
+        Language::Solidity,
+        Language::ObjectiveC,
+        Language::Groovy,
+        Language::Julia,
+        Language::Ocaml,
+        Language::OcamlInterface,
+        Language::Csv,
+        Language::Env,
+        Language::Ini,
+        Language::Rst,
+        Language::AsciiDoc,
     ]
 }
 
@@ -479,7 +664,8 @@ mod tests {
 
     #[test]
     fn test_supported_languages() {
-        assert_eq!(supported_languages().len(), 17);
+        assert_eq!(supported_languages().len(), 38);
+        assert!(supported_languages().contains(&Language::Tsx));
         assert!(supported_languages().contains(&Language::Markdown));
         assert!(supported_languages().contains(&Language::Json));
         assert!(supported_languages().contains(&Language::Yaml));
@@ -572,4 +758,28 @@ mod tests {
         );
         assert_eq!(content, source, "Full mode should return source unchanged");
     }
+
+    #[test]
+    fn test_transform_many_matches_single_transforms() {
+        let cases = [
+            (
+                Language::Rust,
+                "/// Doc\nfn main() {\n    // note\n    run();\n}\nstruct P { x: i32 }\n",
+            ),
+            (Language::Json, r#"{"key": "value", "n": 42}"#),
+        ];
+        for (language, source) in cases {
+            let configs: Vec<_> = Mode::ALL
+                .into_iter()
+                .map(|mode| TransformConfig::with_mode(mode).with_line_numbers(true))
+                .collect();
+            let many = transform_many_with_line_map(source, language, &configs)
+                .expect("all modes should transform");
+            for (config, result) in configs.iter().zip(many) {
+                let single = transform_with_line_map(source, language, config)
+                    .expect("single transform should succeed");
+                assert_eq!(result, single, "{language:?} {:?}", config.mode);
+            }
+        }
+    }
 }
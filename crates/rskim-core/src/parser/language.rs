@@ -17,7 +17,7 @@ use crate::Language;
 /// Returns None for languages that don't use tree-sitter (e.g., JSON).
 pub(crate) fn get_node_types(language: Language) -> Option<LanguageNodeTypes> {
     match language {
-        Language::TypeScript | Language::JavaScript => Some(LanguageNodeTypes {
+        Language::TypeScript | Language::Tsx | Language::JavaScript => Some(LanguageNodeTypes {
             function: "function_declaration",
             class: "class_declaration",
             interface: "interface_declaration",
@@ -95,9 +95,71 @@ pub(crate) fn get_node_types(language: Language) -> Option<LanguageNodeTypes> {
             interface: "protocol_declaration",
             type_alias: "typealias_declaration",
         }),
+        Language::Shell => Some(LanguageNodeTypes {
+            function: "function_definition",
+            class: "",      // Shell has no classes
+            interface: "",  // Shell has no interfaces
+            type_alias: "", // Shell has no type aliases
+        }),
+        Language::Elixir => Some(LanguageNodeTypes {
+            function: "call", // def/defp are macro calls; see FunctionNodeTypes::definition_calls
+            class: "",        // Elixir has no classes (defmodule is also a call)
+            interface: "",    // protocols are defprotocol calls
+            type_alias: "",   // @type is a module attribute
+        }),
+        Language::Dart => Some(LanguageNodeTypes {
+            function: "function_signature",
+            class: "class_definition",
+            interface: "", // Dart has no interfaces; every class is one
+            type_alias: "type_alias",
+        }),
+        Language::Solidity => Some(LanguageNodeTypes {
+            function: "function_definition",
+            class: "contract_declaration",
+            interface: "interface_declaration",
+            type_alias: "user_defined_type_definition",
+        }),
+        Language::ObjectiveC => Some(LanguageNodeTypes {
+            function: "function_definition",
+            class: "class_interface",
+            interface: "protocol_declaration",
+            type_alias: "type_definition",
+        }),
+        Language::Julia => Some(LanguageNodeTypes {
+            function: "function_definition",
+            class: "struct_definition",
+            interface: "abstract_definition",
+            type_alias: "", // `const Alias = T` is a plain assignment
+        }),
+        Language::Ocaml => Some(LanguageNodeTypes {
+            function: "let_binding",
+            class: "module_definition",
+            interface: "module_type_definition",
+            type_alias: "type_definition",
+        }),
+        Language::OcamlInterface => Some(LanguageNodeTypes {
+            function: "value_specification",
+            class: "module_definition",
+            interface: "module_type_definition",
+            type_alias: "type_definition",
+        }),
         // ARCHITECTURE: Serde-based languages use their own parsers, not tree-sitter.
         // This is enforced by the Strategy Pattern in Language::transform_source().
-        Language::Json | Language::Yaml | Language::Toml => None,
+        Language::Json
+        | Language::Yaml
+        | Language::Toml
+        | Language::Vue
+        | Language::Svelte
+        | Language::Xml
+        | Language::Html
+        | Language::Hcl
+        | Language::Dockerfile
+        | Language::Groovy
+        | Language::Csv
+        | Language::Env
+        | Language::Ini
+        | Language::Rst
+        | Language::AsciiDoc => None,
     }
 }
 
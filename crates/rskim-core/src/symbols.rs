@@ -0,0 +1,676 @@
+//! Declaration outline: named symbols with their source line ranges.
+//!
+//! # Design
+//!
+//! Output layers that address code by name (Markdown bundles with per-symbol
+//! anchors, tag files) need to know which declarations a file contains and
+//! where they are, independently of the transformation mode. [`symbols`]
+//! walks the tree once and reports every named function, method, type and
+//! container, in source order.
+//!
+//! Nested symbols carry a dotted qualified name (`Config.validate`) and their
+//! nesting depth, so callers that only want top-level sections can filter on
+//! `depth == 0`. Wrapper nodes (`export_statement`, Python decorators, C++
+//! templates) are transparent: the wrapped declaration is reported. Elixir
+//! definitions are macro calls (`defmodule`, `def`, ...) and are recognised
+//! by the macro name instead of the node kind. Julia definitions have no
+//! `name` field and are named from their signature or type head; one-line
+//! definitions (`area(c) = ...`) are assignments reported as functions.
+//! OCaml bindings are named from their binding node (`let_binding`,
+//! `type_binding`, `module_binding`, ...); plain values are not symbols.
+//!
+//! # Example
+//!
+//! ```
+//! use rskim_core::{symbols, Language};
+//!
+//! let found = symbols("pub struct A;\nimpl A {\n    fn new() -> A { A }\n}\n", Language::Rust)?;
+//! let names: Vec<&str> = found.iter().map(|s| s.name.as_str()).collect();
+//! assert_eq!(names, ["A", "A", "A.new"]);
+//! # Ok::<(), rskim_core::SkimError>(())
+//! ```
+
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::transform::signatures::is_function_prototype;
+use crate::transform::utils::{
+    ELIXIR_DEFINITION_CALLS, find_body_child, is_ocaml_method, node_kind_info, ocaml_function_body,
+    parameter_list, to_static_node_kind,
+};
+use crate::transform::visibility::{PrivateFilter, Visibility};
+use crate::{Language, Parser, Result, SkimError};
+use tree_sitter::{Node, Point, Tree};
+
+/// A named declaration found by [`symbols`](crate::symbols).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    /// Dotted name, qualified by enclosing symbols (`Config.validate`).
+    pub name: String,
+    /// Tree-sitter node kind of the declaration (`function_item`, ...).
+    /// Elixir definitions, which are all `call`s, report `module` or
+    /// `function_definition`.
+    pub kind: &'static str,
+    /// 1-indexed first source line.
+    pub start_line: usize,
+    /// 1-indexed last source line (inclusive).
+    pub end_line: usize,
+    /// Number of enclosing symbols (0 = top level).
+    pub depth: usize,
+    /// Whether the declaration is public, as `--public-only` judges it.
+    pub public: bool,
+    /// Full parameter list text of a function or method (`(a: i32, b: i32)`),
+    /// kept even when signatures mode elides it with `max_params`.
+    pub parameters: Option<String>,
+}
+
+/// Container kinds that are symbols even though they rank below functions.
+const CONTAINER_KINDS: &[&str] = &[
+    "class_declaration",
+    "abstract_class_declaration",
+    "record_declaration",
+    "mod_item",
+    "impl_item",
+    "class_specifier",
+    "namespace_definition",
+    "namespace_declaration",
+    "module_declaration",
+    "class",
+    "module",
+    "mixin_declaration",
+    "extension_declaration",
+    "contract_declaration",
+    "library_declaration",
+    "class_interface",
+    "class_implementation",
+];
+
+/// Kinds whose own name is uninteresting: the wrapped declaration is reported.
+const TRANSPARENT_KINDS: &[&str] = &["template_declaration", "decorated_definition"];
+
+/// Elixir macros that declare modules; their `do` blocks hold definitions.
+const ELIXIR_MODULE_CALLS: &[&str] = &["defmodule", "defprotocol", "defimpl"];
+
+/// Parse `source` and list its named declarations in source order.
+pub(crate) fn symbols(source: &str, language: Language) -> Result<Vec<Symbol>> {
+    if language.is_serde_based() || language.is_scanner_based() || language == Language::Markdown {
+        return Ok(Vec::new());
+    }
+    let tree = Parser::new(language)?.parse(source)?;
+    symbols_in_tree(source, &tree, language)
+}
+
+/// [`symbols`] on an existing parse of `source`.
+pub(crate) fn symbols_in_tree(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+) -> Result<Vec<Symbol>> {
+    let filter = PrivateFilter::new(tree.root_node(), source, language);
+    let mut out = Vec::new();
+    collect(
+        tree.root_node(),
+        source,
+        language,
+        &filter,
+        None,
+        0,
+        &mut out,
+        0,
+    )?;
+    Ok(out)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn collect(
+    node: Node,
+    source: &str,
+    language: Language,
+    filter: &PrivateFilter,
+    parent: Option<&str>,
+    symbol_depth: usize,
+    out: &mut Vec<Symbol>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested or malicious input
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {} (possible malicious input)",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    let text = |n: Node| n.utf8_text(source.as_bytes()).ok();
+    let declared = match language {
+        Language::Elixir => elixir_definition(node, source)
+            .map(|(kind, name, params)| (kind, name, params.and_then(text))),
+        Language::Julia => julia_definition(node, source)
+            .map(|(kind, name, params)| (kind, name, params.and_then(text))),
+        Language::Ocaml | Language::OcamlInterface => ocaml_definition(node, source),
+        _ if is_symbol_kind(node) => symbol_name(node, source).map(|name| {
+            let params = match language {
+                Language::Solidity => inline_parameters(node, source),
+                _ => parameter_list(node).and_then(text),
+            };
+            (to_static_node_kind(node.kind()), name, params)
+        }),
+        _ => None,
+    };
+    let mut qualified = None;
+    if let Some((kind, name, params)) = declared {
+        let name = match parent {
+            Some(parent) => format!("{parent}.{name}"),
+            None => name.to_string(),
+        };
+        out.push(Symbol {
+            name: name.clone(),
+            kind,
+            start_line: node.start_position().row + 1,
+            end_line: declaration_end(node).row + 1,
+            depth: symbol_depth,
+            public: filter.visibility(node) != Some(Visibility::Private),
+            parameters: params.map(str::to_string),
+        });
+        qualified = Some(name);
+    }
+
+    let (parent, symbol_depth) = match &qualified {
+        Some(name) => (Some(name.as_str()), symbol_depth + 1),
+        None => (parent, symbol_depth),
+    };
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect(
+            child,
+            source,
+            language,
+            filter,
+            parent,
+            symbol_depth,
+            out,
+            depth + 1,
+        )?;
+    }
+    Ok(())
+}
+
+/// End of a declaration, including a body that tree-sitter-dart places next
+/// to the signature (or to the `method_signature` wrapping it) rather than
+/// inside it.
+fn declaration_end(node: Node) -> Point {
+    find_body_child(node)
+        .or_else(|| {
+            node.parent()
+                .filter(|parent| parent.kind() == "method_signature")
+                .and_then(find_body_child)
+        })
+        .filter(|body| body.end_byte() > node.end_byte())
+        .map_or(node.end_position(), |body| body.end_position())
+}
+
+/// Whether `node` is a declaration that can be reported as a symbol.
+pub(crate) fn is_symbol_kind(node: Node) -> bool {
+    let kind = node.kind();
+    if TRANSPARENT_KINDS.contains(&kind) {
+        return false;
+    }
+    match kind {
+        // C/C++ `declaration` is also a variable declaration; only
+        // prototypes name a function.
+        "declaration" | "field_declaration" => is_function_prototype(node),
+        "atx_heading" | "setext_heading" | "fenced_code_block" | "statement" => false,
+        // Objective-C properties and instance variables wrap a C struct field.
+        "struct_declaration" => !node.parent().is_some_and(|parent| {
+            matches!(parent.kind(), "property_declaration" | "instance_variable")
+        }),
+        // Solidity `using Lib for T` names a library, not a new type.
+        "type_alias" => node
+            .parent()
+            .is_none_or(|parent| parent.kind() != "using_directive"),
+        _ => node_kind_info(kind).1 >= 4 || CONTAINER_KINDS.contains(&kind),
+    }
+}
+
+/// Name of a declaration, or `None` for anonymous ones.
+fn symbol_name<'src>(node: Node, source: &'src str) -> Option<&'src str> {
+    let text = |n: Node| n.utf8_text(source.as_bytes()).ok();
+    if DART_CONSTRUCTOR_KINDS.contains(&node.kind()) {
+        return dart_constructor_name(node, source);
+    }
+    if let Some(name) = node.child_by_field_name("name") {
+        return text(name);
+    }
+    match node.kind() {
+        // Solidity `constructor`, `fallback` and `receive` are named by keyword.
+        "constructor_definition" | "fallback_receive_definition" => {
+            return node.child(0).and_then(text);
+        }
+        // `impl Display for Config` is named after the implementing type.
+        "impl_item" => return node.child_by_field_name("type").and_then(text),
+        // Go `type ( A struct{}; B int )`: named after the first spec.
+        "type_declaration" => {
+            let mut cursor = node.walk();
+            let spec = node
+                .named_children(&mut cursor)
+                .find(|c| matches!(c.kind(), "type_spec" | "type_alias"));
+            return spec
+                .and_then(|s| s.child_by_field_name("name"))
+                .and_then(text);
+        }
+        // `const load = () => ...`, `{ load: function () {} }`
+        "arrow_function" | "function_expression" | "function" | "generator_function" => {
+            let parent = node.parent()?;
+            let name = match parent.kind() {
+                "variable_declarator" | "public_field_definition" | "field_definition" => {
+                    parent.child_by_field_name("name")
+                }
+                "pair" => parent.child_by_field_name("key"),
+                "assignment_expression" => parent.child_by_field_name("left"),
+                _ => None,
+            };
+            return name.and_then(text);
+        }
+        _ => {}
+    }
+    // C/C++: the name sits at the bottom of the declarator chain.
+    let mut declarator = node.child_by_field_name("declarator");
+    while let Some(d) = declarator {
+        match d.kind() {
+            "identifier"
+            | "field_identifier"
+            | "qualified_identifier"
+            | "operator_name"
+            | "destructor_name"
+            | "type_identifier" => return text(d),
+            _ => {
+                declarator = d
+                    .child_by_field_name("declarator")
+                    .or_else(|| d.named_child(0))
+            }
+        }
+    }
+    // Grammars without a `name` field (Kotlin, Swift): first identifier child.
+    let mut cursor = node.walk();
+    let ident = node.named_children(&mut cursor).find(|c| {
+        matches!(
+            c.kind(),
+            "identifier" | "simple_identifier" | "type_identifier" | "constant"
+        )
+    });
+    ident.and_then(text)
+}
+
+/// Parameters written directly inside a declaration, from its first `(` to
+/// the next `)`: Solidity functions have no parameter list node.
+fn inline_parameters<'src>(node: Node, source: &'src str) -> Option<&'src str> {
+    let mut cursor = node.walk();
+    let mut children = node.children(&mut cursor);
+    let open = children.find(|child| child.kind() == "(")?;
+    let close = children.find(|child| child.kind() == ")")?;
+    source.get(open.start_byte()..close.end_byte())
+}
+
+/// Kind, name and parameter list of an Elixir definition. Modules report
+/// `module` and `def`-style functions `function_definition`, the kinds of the
+/// equivalent declarations in other grammars.
+pub(crate) fn elixir_definition<'tree, 'src>(
+    node: Node<'tree>,
+    source: &'src str,
+) -> Option<(&'static str, &'src str, Option<Node<'tree>>)> {
+    if node.kind() != "call" {
+        return None;
+    }
+    let text = |n: Node| n.utf8_text(source.as_bytes()).ok();
+    let target = text(node.child_by_field_name("target")?)?;
+    let kind = if ELIXIR_MODULE_CALLS.contains(&target) {
+        "module"
+    } else if ELIXIR_DEFINITION_CALLS.contains(&target) {
+        "function_definition"
+    } else {
+        return None;
+    };
+    // `def name(args) when guard` wraps the head in a binary operator.
+    let mut head = node
+        .named_children(&mut node.walk())
+        .find(|child| child.kind() == "arguments")?
+        .named_child(0)?;
+    if head.kind() == "binary_operator" {
+        head = head.child_by_field_name("left")?;
+    }
+    if head.kind() != "call" {
+        // `defmodule Name`, or a function without parentheses.
+        return Some((kind, text(head)?, None));
+    }
+    let params = head
+        .named_children(&mut head.walk())
+        .find(|child| child.kind() == "arguments");
+    Some((kind, text(head.child_by_field_name("target")?)?, params))
+}
+
+/// Kind, name and parameter list of a Julia definition. A one-line
+/// definition (`area(c) = ...`) is an `assignment` to a call and reports
+/// `function_definition`, like the `function ... end` form.
+pub(crate) fn julia_definition<'tree, 'src>(
+    node: Node<'tree>,
+    source: &'src str,
+) -> Option<(&'static str, &'src str, Option<Node<'tree>>)> {
+    let text = |n: Node| n.utf8_text(source.as_bytes()).ok();
+    let child = |kind: &str| {
+        node.named_children(&mut node.walk())
+            .find(|child| child.kind() == kind)
+    };
+    let (kind, mut head) = match node.kind() {
+        "module_definition" => {
+            return Some((
+                "module_definition",
+                text(node.child_by_field_name("name")?)?,
+                None,
+            ));
+        }
+        // `struct Circle <: Shape`, `mutable struct Rectangle{T<:Real}`
+        "struct_definition" | "abstract_definition" | "primitive_definition" => {
+            let mut head = child("type_head")?.named_child(0)?;
+            while matches!(
+                head.kind(),
+                "binary_expression" | "parametrized_type_expression"
+            ) {
+                head = head.named_child(0)?;
+            }
+            return Some((to_static_node_kind(node.kind()), text(head)?, None));
+        }
+        "function_definition" | "macro_definition" => (
+            to_static_node_kind(node.kind()),
+            child("signature")?.named_child(0)?,
+        ),
+        "assignment" => ("function_definition", node.named_child(0)?),
+        _ => return None,
+    };
+    // `f(x)::T` and `f(x) where {T}` wrap the call.
+    while matches!(head.kind(), "typed_expression" | "where_expression") {
+        head = head.named_child(0)?;
+    }
+    if head.kind() != "call_expression" {
+        return None;
+    }
+    let params = head
+        .named_children(&mut head.walk())
+        .find(|child| child.kind() == "argument_list");
+    Some((kind, text(head.named_child(0)?)?, params))
+}
+
+/// Kind, name and parameter text of an OCaml definition. Bindings report the
+/// kinds of the equivalent declarations in other grammars: functions
+/// (`let f x = ...`, `external`, interface `val f : a -> b`)
+/// `function_definition`, methods `method_definition`, `type t = ...`
+/// `type_definition`, modules `module`, module types `interface_declaration`
+/// and classes `class`. Parameters are separate nodes in tree-sitter-ocaml,
+/// so their text runs from the first to the last.
+pub(crate) fn ocaml_definition<'src>(
+    node: Node,
+    source: &'src str,
+) -> Option<(&'static str, &'src str, Option<&'src str>)> {
+    let text = |n: Node| n.utf8_text(source.as_bytes()).ok();
+    let first_named = |kind: &str| {
+        node.named_children(&mut node.walk())
+            .find(|child| child.kind() == kind)
+    };
+    let (kind, name) = match node.kind() {
+        "let_binding" => {
+            ocaml_function_body(node)?;
+            let pattern = node.child_by_field_name("pattern")?;
+            // `let () = ...` is an entry point, not a named function.
+            if !matches!(pattern.kind(), "value_name" | "parenthesized_operator") {
+                return None;
+            }
+            ("function_definition", pattern)
+        }
+        "method_definition" if is_ocaml_method(node) => {
+            ("method_definition", first_named("method_name")?)
+        }
+        "external" => ("function_definition", first_named("value_name")?),
+        // Interface `val f : a -> b`; `val x : int` is a plain value.
+        "value_specification"
+            if node
+                .child_by_field_name("type")
+                .is_some_and(|ty| ty.kind() == "function_type") =>
+        {
+            ("function_definition", first_named("value_name")?)
+        }
+        "type_binding" => ("type_definition", node.child_by_field_name("name")?),
+        "module_binding" => ("module", first_named("module_name")?),
+        "module_type_definition" => ("interface_declaration", first_named("module_type_name")?),
+        "class_binding" => ("class", first_named("class_name")?),
+        _ => return None,
+    };
+    let mut cursor = node.walk();
+    let parameters: Vec<Node> = node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "parameter")
+        .collect();
+    let params = match (parameters.first(), parameters.last()) {
+        (Some(first), Some(last)) => source.get(first.start_byte()..last.end_byte()),
+        _ => None,
+    };
+    Some((kind, text(name)?, params))
+}
+
+/// Dart constructor signatures, whose name can span several nodes.
+const DART_CONSTRUCTOR_KINDS: &[&str] = &[
+    "constructor_signature",
+    "constant_constructor_signature",
+    "factory_constructor_signature",
+    "redirecting_factory_constructor_signature",
+];
+
+/// Name of a Dart constructor: the class name, or `fromJson` for the named
+/// constructor `Todo.fromJson` (two `identifier`s, or one `qualified`).
+fn dart_constructor_name<'src>(node: Node, source: &'src str) -> Option<&'src str> {
+    let mut cursor = node.walk();
+    let mut name = node
+        .named_children(&mut cursor)
+        .skip_while(|c| !matches!(c.kind(), "identifier" | "qualified"))
+        .take_while(|c| matches!(c.kind(), "identifier" | "qualified"))
+        .last()?;
+    if name.kind() == "qualified" {
+        name = name.named_child(name.named_child_count().checked_sub(1)?)?;
+    }
+    name.utf8_text(source.as_bytes()).ok()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn outline(source: &str, language: Language) -> Vec<(String, usize, usize, bool)> {
+        symbols(source, language)
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.name, s.start_line, s.depth, s.public))
+            .collect()
+    }
+
+    #[test]
+    fn test_typescript_classes_methods_and_arrow_functions() {
+        let source = "export class Api {\n  load(): void {}\n  private reset(): void {}\n}\n\n\
+                      export const parse = (s: string) => s;\n\nfunction helper() {}\n";
+        assert_eq!(
+            outline(source, Language::TypeScript),
+            [
+                ("Api".to_string(), 1, 0, true),
+                ("Api.load".to_string(), 2, 1, true),
+                ("Api.reset".to_string(), 3, 1, false),
+                ("parse".to_string(), 6, 0, true),
+                ("helper".to_string(), 8, 0, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_python_decorated_methods_are_named() {
+        let source = "class Temp:\n    @property\n    def celsius(self):\n        return 1\n\n\
+                      def _private():\n    pass\n";
+        assert_eq!(
+            outline(source, Language::Python),
+            [
+                ("Temp".to_string(), 1, 0, true),
+                ("Temp.celsius".to_string(), 3, 1, true),
+                ("_private".to_string(), 6, 0, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_c_functions_named_through_declarators() {
+        let source = "static char *dup(const char *s) {\n    return 0;\n}\nint add(int a, int b);\nint counter = 0;\n";
+        let names: Vec<String> = outline(source, Language::C)
+            .into_iter()
+            .map(|s| s.0)
+            .collect();
+        assert_eq!(names, ["dup", "add"]);
+    }
+
+    #[test]
+    fn test_dart_constructors_and_sibling_bodies() {
+        let source = "class Todo {\n  Todo.fromJson(Map json) {\n    load(json);\n  }\n\n  \
+                      const Todo.empty();\n}\n\nvoid main() {\n  print(1);\n}\n";
+        let found = symbols(source, Language::Dart).unwrap();
+        let spans: Vec<(&str, usize, usize)> = found
+            .iter()
+            .map(|s| (s.name.as_str(), s.start_line, s.end_line))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("Todo", 1, 7),
+                ("Todo.fromJson", 2, 4),
+                ("Todo.empty", 6, 6),
+                ("main", 9, 11),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_elixir_definitions_are_named_by_macro() {
+        let source = "defmodule App.Users do\n  def get(id) when is_integer(id) do\n    \
+                      Repo.get(id)\n  end\n\n  defp cache_key, do: :users\nend\n";
+        let found = symbols(source, Language::Elixir).unwrap();
+        let spans: Vec<(&str, &str, usize, usize, Option<&str>)> = found
+            .iter()
+            .map(|s| {
+                (
+                    s.name.as_str(),
+                    s.kind,
+                    s.start_line,
+                    s.end_line,
+                    s.parameters.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("App.Users", "module", 1, 7, None),
+                ("App.Users.get", "function_definition", 2, 4, Some("(id)")),
+                ("App.Users.cache_key", "function_definition", 6, 6, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_julia_definitions_are_named_from_signatures_and_type_heads() {
+        let source = "module Shapes\nmutable struct Box{T<:Real} <: Shape\n  w::T\nend\n\
+                      function area(b::Box{T})::T where {T}\n  b.w^2\nend\n\
+                      Base.show(io::IO, b::Box) = print(io, b.w)\nx = 1\nend\n";
+        let found = symbols(source, Language::Julia).unwrap();
+        let spans: Vec<(&str, &str, usize, Option<&str>)> = found
+            .iter()
+            .map(|s| {
+                (
+                    s.name.as_str(),
+                    s.kind,
+                    s.start_line,
+                    s.parameters.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("Shapes", "module_definition", 1, None),
+                ("Shapes.Box", "struct_definition", 2, None),
+                ("Shapes.area", "function_definition", 5, Some("(b::Box{T})")),
+                (
+                    "Shapes.Base.show",
+                    "function_definition",
+                    8,
+                    Some("(io::IO, b::Box)")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ocaml_bindings_are_named_and_values_skipped() {
+        let source = "type shape = Circle of float\nlet pi = 3.14\nmodule Geo = struct\n\
+                      \x20 let area s = match s with Circle r -> pi *. r\nend\n\
+                      class counter = object\n  method get = 0\nend\n\
+                      let () = print_newline ()\n";
+        let found = symbols(source, Language::Ocaml).unwrap();
+        let spans: Vec<(&str, &str, usize, Option<&str>)> = found
+            .iter()
+            .map(|s| {
+                (
+                    s.name.as_str(),
+                    s.kind,
+                    s.start_line,
+                    s.parameters.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("shape", "type_definition", 1, None),
+                ("Geo", "module", 3, None),
+                ("Geo.area", "function_definition", 4, Some("s")),
+                ("counter", "class", 6, None),
+                ("counter.get", "method_definition", 7, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ocaml_interface_counts_function_vals() {
+        let source = "type t\nval empty : t\nval push : t -> int -> unit\n\
+                      module type S = sig end\n";
+        let found = symbols(source, Language::OcamlInterface).unwrap();
+        let names: Vec<(&str, &str)> = found.iter().map(|s| (s.name.as_str(), s.kind)).collect();
+        assert_eq!(
+            names,
+            [
+                ("t", "type_definition"),
+                ("push", "function_definition"),
+                ("S", "interface_declaration"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_functions_carry_parameter_lists() {
+        let found = symbols(
+            "def load(path: str, *, strict: bool = False):\n    pass\n\nclass A:\n    pass\n",
+            Language::Python,
+        )
+        .unwrap();
+        assert_eq!(
+            found[0].parameters.as_deref(),
+            Some("(path: str, *, strict: bool = False)")
+        );
+        assert_eq!(found[1].parameters, None);
+    }
+
+    #[test]
+    fn test_data_formats_have_no_symbols() {
+        assert!(symbols("{\"a\": 1}", Language::Json).unwrap().is_empty());
+        assert!(symbols("# Title\n", Language::Markdown).unwrap().is_empty());
+    }
+}
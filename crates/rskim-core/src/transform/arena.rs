@@ -0,0 +1,72 @@
+//! Per-thread bump arena for transformation scratch data
+//!
+//! ARCHITECTURE: Structure and signatures modes build short-lived lists
+//! (body replacements, extracted signatures) that are discarded as soon as the
+//! output string is assembled. Allocating them from a thread-local bump arena
+//! that is reset after every file replaces thousands of small malloc/free pairs
+//! per file with pointer bumps, which matters on 100k-file batch runs where
+//! each rayon worker transforms files back to back.
+//!
+//! The arena is reset when the outermost [`with_scratch`] call returns, so the
+//! batch path gets a clean arena per file without any caller bookkeeping.
+//! Nested calls (e.g. Markdown fenced code or SFC `<script>` blocks that are
+//! transformed while the host document's scratch data is live) get a private
+//! temporary arena instead of sharing the borrowed one.
+
+use bumpalo::Bump;
+use std::cell::RefCell;
+
+/// Arena capacity retained between files.
+///
+/// `Bump::reset` keeps the largest chunk; after an unusually large file we drop
+/// it instead so one outlier does not pin memory for the rest of the batch.
+const MAX_RETAINED_BYTES: usize = 16 * 1024 * 1024;
+
+thread_local! {
+    static SCRATCH: RefCell<Bump> = RefCell::new(Bump::new());
+}
+
+/// Run `f` with this thread's scratch arena, resetting it afterwards.
+///
+/// Anything allocated from the arena must not outlive `f`; copy results into
+/// owned values before returning.
+pub(crate) fn with_scratch<R>(f: impl FnOnce(&Bump) -> R) -> R {
+    SCRATCH.with(|cell| match cell.try_borrow_mut() {
+        Ok(mut bump) => {
+            let result = f(&bump);
+            if bump.allocated_bytes() > MAX_RETAINED_BYTES {
+                *bump = Bump::new();
+            } else {
+                bump.reset();
+            }
+            result
+        }
+        // Re-entrant call: the outer transform still holds the arena.
+        Err(_) => f(&Bump::new()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scratch_is_reset_between_calls() {
+        with_scratch(|bump| {
+            let _ = bump.alloc_slice_fill_copy(4096, 0u8);
+        });
+        // The chunk is retained and its full capacity is available again.
+        with_scratch(|bump| assert!(bump.chunk_capacity() >= 4096));
+    }
+
+    #[test]
+    fn test_nested_scratch_uses_private_arena() {
+        let outer_len = with_scratch(|outer| {
+            let data = outer.alloc_str("outer");
+            let inner = with_scratch(|inner| inner.alloc_str("inner").len());
+            assert_eq!(data, "outer");
+            inner
+        });
+        assert_eq!(outer_len, 5);
+    }
+}
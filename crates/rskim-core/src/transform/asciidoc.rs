@@ -0,0 +1,183 @@
+//! AsciiDoc section outline
+//!
+//! ARCHITECTURE: Like the Markdown and reStructuredText transforms this
+//! extracts headings instead of replacing bodies. AsciiDoc titles are
+//! single-line and marked by leading `=` (or Markdown-style `#`) signs, so a
+//! line scanner is enough; the only context it tracks is whether it is inside
+//! a delimited block, where `=` lines are content, not titles.
+//!
+//! # Level Mapping
+//!
+//! The number of `=` signs is the header depth: the document title (`= T`)
+//! maps to H1, `== Section` to H2, and so on down to `======` (H6).
+//! Markdown-style titles, which Asciidoctor also accepts, map the same way
+//! (`## Section` is H2).
+//! Structure mode keeps H1-H3 and signatures/types modes keep H1-H6, exactly
+//! as for Markdown.
+//!
+//! # Rules
+//! - Titles are emitted verbatim, including symmetric trailing `=` signs
+//! - A title needs a space after its `=` or `#` signs and non-blank text
+//! - Listing (`----`), literal (`....`), example (`====`), sidebar (`****`),
+//!   quote (`____`), passthrough (`++++`), comment (`////`), table (`|===`)
+//!   and fenced (```` ``` ````, with an optional language) blocks are skipped
+//!   until their closing delimiter
+//! - A delimited block still open at end of file sets `has_errors`
+
+use crate::{Mode, Result, SkimError};
+
+/// Maximum number of section titles
+///
+/// SECURITY: Matches MAX_MARKDOWN_HEADERS to bound output size on adversarial
+/// input. Exceeding the cap degrades to passthrough like every other
+/// complexity limit.
+const MAX_ASCIIDOC_HEADERS: usize = 10_000;
+
+/// Deepest header level AsciiDoc defines (`======`).
+const MAX_LEVEL: usize = 6;
+
+/// Outline an AsciiDoc document as its section titles, returning
+/// `(content, has_errors)`.
+pub(crate) fn transform_asciidoc(source: &str, mode: Mode) -> Result<(String, bool)> {
+    let max_level = match mode {
+        Mode::Structure => 3,
+        _ => MAX_LEVEL,
+    };
+
+    let mut result = String::new();
+    let mut count = 0usize;
+    // Closing delimiter of the block the scanner is inside.
+    let mut open_block: Option<&str> = None;
+
+    for line in source.lines() {
+        let line = line.trim_end();
+
+        if let Some(delimiter) = open_block {
+            if line == delimiter {
+                open_block = None;
+            }
+            continue;
+        }
+        if let Some(delimiter) = block_delimiter(line) {
+            open_block = Some(delimiter);
+            continue;
+        }
+
+        let Some(level) = title_level(line) else {
+            continue;
+        };
+        if level <= max_level {
+            count += 1;
+            if count > MAX_ASCIIDOC_HEADERS {
+                return Err(SkimError::ComplexityLimit {
+                    what: "AsciiDoc headers",
+                    count,
+                    max: MAX_ASCIIDOC_HEADERS,
+                });
+            }
+            result.push_str(line);
+            result.push('\n');
+        }
+    }
+
+    Ok((result, open_block.is_some()))
+}
+
+/// The header depth of a section title line (`== Install` and `## Install`
+/// are 2).
+fn title_level(line: &str) -> Option<usize> {
+    let marker = match line.bytes().next() {
+        Some(marker @ (b'=' | b'#')) => marker,
+        _ => return None,
+    };
+    let level = line.bytes().take_while(|&b| b == marker).count();
+    if !(1..=MAX_LEVEL).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    (rest.starts_with(' ') && !rest.trim().is_empty()).then_some(level)
+}
+
+/// The closing delimiter when `line` opens a delimited block: four or more
+/// of one delimiter character, a table (`|===`, or `,===`/`:===` for CSV and
+/// DSV data), or a Markdown-style ```` ``` ```` fence with an optional
+/// language, which closes on a bare fence.
+fn block_delimiter(line: &str) -> Option<&str> {
+    if line.starts_with("```") {
+        return Some("```");
+    }
+    let first = line.chars().next()?;
+    let is_delimiter = match first {
+        '-' | '.' | '=' | '*' | '_' | '+' | '/' => {
+            line.len() >= 4 && line.chars().all(|c| c == first)
+        }
+        '|' | ',' | ':' | '!' => line.len() >= 4 && line[1..].chars().all(|c| c == '='),
+        _ => false,
+    };
+    is_delimiter.then_some(line)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str, mode: Mode) -> String {
+        transform_asciidoc(source, mode).unwrap().0
+    }
+
+    #[test]
+    fn test_levels_map_to_header_depths() {
+        let source = "= Guide\nAuthor Name\n\nIntro.\n\n== Install\n\n=== From source\n\n\
+                      ==== Linux\n\n===== Debian\n\n====== Bookworm ======\n";
+        assert_eq!(
+            skim(source, Mode::Structure),
+            "= Guide\n== Install\n=== From source\n"
+        );
+        assert_eq!(
+            skim(source, Mode::Signatures),
+            "= Guide\n== Install\n=== From source\n==== Linux\n===== Debian\n\
+             ====== Bookworm ======\n"
+        );
+    }
+
+    #[test]
+    fn test_delimited_blocks_are_skipped() {
+        let source = "== Usage\n\n[source,python]\n----\n= not a title\n----\n\n\
+                      ////\n== commented out\n////\n\n====\nExample body.\n====\n\n\
+                      ```\n== fenced\n```\n\n== Next\n";
+        let (result, has_errors) = transform_asciidoc(source, Mode::Types).unwrap();
+        assert_eq!(result, "== Usage\n== Next\n");
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_markdown_style_titles_and_tables() {
+        let source = "# Guide\n\n```ruby\n# comment\n```\n\n## Options\n\n|===\n| a\n== cell\n|===\n\n\
+                      #highlight# text\n### Details\n";
+        let (result, has_errors) = transform_asciidoc(source, Mode::Signatures).unwrap();
+        assert_eq!(result, "# Guide\n## Options\n### Details\n");
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_non_titles_are_ignored() {
+        let source = "=Attached\n==\n=======  Too deep\n== \nx == y\n";
+        assert_eq!(skim(source, Mode::Signatures), "");
+    }
+
+    #[test]
+    fn test_unclosed_block_sets_has_errors() {
+        let (result, has_errors) =
+            transform_asciidoc("== Start\n....\n== inside\n", Mode::Structure).unwrap();
+        assert_eq!(result, "== Start\n");
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_too_many_headers_is_complexity_limit() {
+        let source = "== Section\n".repeat(MAX_ASCIIDOC_HEADERS + 1);
+        let err = transform_asciidoc(&source, Mode::Structure).unwrap_err();
+        assert!(err.is_complexity_limit());
+    }
+}
@@ -0,0 +1,417 @@
+//! Call-graph mode - `caller -> callee` edges between declarations
+//!
+//! ARCHITECTURE: Outline mode says what a file declares; call-graph mode says
+//! how those declarations use each other, without their bodies. Every call
+//! expression is attributed to the innermost declaration enclosing it (see
+//! [`crate::symbols`]) and printed once per caller, in order of first call:
+//!
+//! ```text
+//! main -> Config.load
+//! main -> run
+//! Config.load -> fs::read_to_string
+//! Config.load -> Self::validate
+//! ```
+//!
+//! Callees are printed as written at the call site (`self.validate`,
+//! `fmt.Println`); a callee that is not a plain path (`foo().bar`,
+//! `Vec::<u8>::new`) is cut down to its final name. Calls outside any
+//! declaration belong to `(top level)`. Macros and constructor expressions
+//! (`new Foo()`) are not calls, and tree-sitter does not parse Rust macro
+//! arguments, so calls inside `println!(...)` are not seen.
+//!
+//! With [`TransformConfig::dot_graph`] the same edges are emitted as a
+//! Graphviz `digraph calls { ... }` with quoted node names.
+
+use super::truncate::NodeSpan;
+use super::utils::to_static_node_kind;
+use crate::symbols::{elixir_definition, julia_definition, ocaml_definition, symbols_in_tree};
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::{Language, Result, SkimError, TransformConfig};
+use std::collections::{HashMap, HashSet, VecDeque};
+use tree_sitter::{Node, Tree};
+
+/// Caller name for calls made outside every declaration.
+const TOP_LEVEL: &str = "(top level)";
+
+/// Elixir macros parsed as calls that are control flow or declarations,
+/// not calls to user code.
+const ELIXIR_SPECIAL_FORMS: &[&str] = &[
+    "alias",
+    "case",
+    "cond",
+    "def",
+    "defguard",
+    "defguardp",
+    "defimpl",
+    "defmacro",
+    "defmacrop",
+    "defmodule",
+    "defp",
+    "defprotocol",
+    "defstruct",
+    "for",
+    "if",
+    "import",
+    "quote",
+    "receive",
+    "require",
+    "try",
+    "unless",
+    "unquote",
+    "use",
+    "with",
+];
+
+/// Transform to call-graph mode, returning text and per-edge spans.
+pub(crate) fn transform_call_graph_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_call_graph_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to call-graph mode, also mapping each edge to the source line
+/// of its first call (`0` for the DOT header and footer).
+pub(crate) fn transform_call_graph_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let mut callers: HashMap<(&'static str, usize, usize), VecDeque<String>> = HashMap::new();
+    for symbol in symbols_in_tree(source, tree, language)? {
+        callers
+            .entry((symbol.kind, symbol.start_line, symbol.end_line))
+            .or_default()
+            .push_back(symbol.name);
+    }
+    let mut walk = Walk {
+        source,
+        language,
+        callers,
+        seen: HashSet::new(),
+        edges: Vec::new(),
+    };
+    walk.visit(tree.root_node(), TOP_LEVEL, 0)?;
+
+    let mut text = String::new();
+    let mut spans = Vec::with_capacity(walk.edges.len());
+    let mut line_map = Vec::with_capacity(walk.edges.len() + 2);
+    if config.dot_graph {
+        text.push_str("digraph calls {\n");
+        line_map.push(0);
+    }
+    for edge in &walk.edges {
+        if config.dot_graph {
+            text.push_str(&format!(
+                "  {} -> {};\n",
+                dot_id(&edge.caller),
+                dot_id(&edge.callee)
+            ));
+        } else {
+            text.push_str(&format!("{} -> {}\n", edge.caller, edge.callee));
+        }
+        spans.push(NodeSpan::new(line_map.len()..line_map.len() + 1, edge.kind));
+        line_map.push(edge.line);
+    }
+    if config.dot_graph {
+        text.push_str("}\n");
+        line_map.push(0);
+    }
+    Ok((text, spans, line_map))
+}
+
+/// One `caller -> callee` edge, at its first call.
+struct Edge {
+    caller: String,
+    callee: String,
+    /// 1-indexed line of the first call.
+    line: usize,
+    kind: &'static str,
+}
+
+struct Walk<'src> {
+    source: &'src str,
+    language: Language,
+    /// Symbol names by `(kind, start_line, end_line)`, in source order.
+    callers: HashMap<(&'static str, usize, usize), VecDeque<String>>,
+    seen: HashSet<(String, String)>,
+    edges: Vec<Edge>,
+}
+
+impl Walk<'_> {
+    fn visit(&mut self, node: Node, caller: &str, depth: usize) -> Result<()> {
+        // SECURITY: Prevent stack overflow from deeply nested or malicious input
+        if depth > MAX_AST_DEPTH {
+            return Err(SkimError::ParseError(format!(
+                "Maximum AST depth exceeded: {} (possible malicious input)",
+                MAX_AST_DEPTH
+            )));
+        }
+
+        let kind = match self.language {
+            Language::Elixir => elixir_definition(node, self.source).map(|(kind, ..)| kind),
+            Language::Julia => julia_definition(node, self.source).map(|(kind, ..)| kind),
+            Language::Ocaml => ocaml_definition(node, self.source).map(|(kind, ..)| kind),
+            _ => None,
+        };
+        let key = (
+            kind.unwrap_or_else(|| to_static_node_kind(node.kind())),
+            node.start_position().row + 1,
+            node.end_position().row + 1,
+        );
+        let symbol = self.callers.get_mut(&key).and_then(VecDeque::pop_front);
+        if let Some(name) = &symbol {
+            return self.visit_children(node, name, true, depth);
+        }
+
+        if let Some(callee) = self.callee(node) {
+            let pair = (caller.to_string(), callee);
+            if !self.seen.contains(&pair) {
+                self.edges.push(Edge {
+                    caller: pair.0.clone(),
+                    callee: pair.1.clone(),
+                    line: node.start_position().row + 1,
+                    kind: to_static_node_kind(node.kind()),
+                });
+                self.seen.insert(pair);
+            }
+        }
+        self.visit_children(node, caller, false, depth)
+    }
+
+    fn visit_children(
+        &mut self,
+        node: Node,
+        caller: &str,
+        declaration: bool,
+        depth: usize,
+    ) -> Result<()> {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if self.is_header(child, declaration) {
+                // `def f(x), do: body` keeps its body among the arguments.
+                if self.language == Language::Elixir && child.kind() == "arguments" {
+                    let mut args = child.walk();
+                    for keywords in child
+                        .named_children(&mut args)
+                        .filter(|arg| arg.kind() == "keywords")
+                    {
+                        self.visit(keywords, caller, depth + 2)?;
+                    }
+                }
+                continue;
+            }
+            self.visit(child, caller, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Whether `node` is declaration syntax that parses as a call: a Julia
+    /// signature or one-line definition head, the head of an Elixir `def`,
+    /// or an Elixir `@attribute`.
+    fn is_header(&self, node: Node, in_declaration: bool) -> bool {
+        match self.language {
+            Language::Julia => {
+                node.kind() == "signature"
+                    || (in_declaration
+                        && node.parent().is_some_and(|parent| {
+                            parent.kind() == "assignment" && parent.named_child(0) == Some(node)
+                        }))
+            }
+            Language::Elixir => {
+                (in_declaration && node.kind() == "arguments")
+                    || (node.kind() == "unary_operator"
+                        && node
+                            .child_by_field_name("operator")
+                            .is_some_and(|op| op.kind() == "@"))
+            }
+            _ => false,
+        }
+    }
+
+    /// The compacted callee of `node`, if it is a call.
+    fn callee(&self, node: Node) -> Option<String> {
+        let field = |name| node.child_by_field_name(name);
+        let text = |node: Node| node.utf8_text(self.source.as_bytes()).ok();
+        let callee = match node.kind() {
+            "call_expression" | "invocation_expression" | "application_expression" => {
+                let function = field("function").or_else(|| node.named_child(0))?;
+                // OCaml `Circle (x, y)` builds a variant value, it is not a call.
+                if function.kind() == "constructor_path" {
+                    return None;
+                }
+                text(function)?.to_string()
+            }
+            "call" if self.language == Language::Python => text(field("function")?)?.to_string(),
+            "call" if self.language == Language::Elixir => {
+                let target = field("target")?;
+                // `user.name` without parentheses reads a field of a variable.
+                let field_access = target.kind() == "dot"
+                    && target
+                        .child_by_field_name("left")
+                        .is_some_and(|left| left.kind() == "identifier")
+                    && !has_arguments(node);
+                let target = text(target)?;
+                if field_access || ELIXIR_SPECIAL_FORMS.contains(&target) {
+                    return None;
+                }
+                target.to_string()
+            }
+            "call" | "message_expression" => {
+                let method = text(field("method")?)?;
+                match field("receiver").and_then(text) {
+                    Some(receiver) => format!("{receiver}.{method}"),
+                    None => method.to_string(),
+                }
+            }
+            "method_invocation" => {
+                let name = text(field("name")?)?;
+                match field("object").and_then(text) {
+                    Some(object) => format!("{object}.{name}"),
+                    None => name.to_string(),
+                }
+            }
+            "command" => {
+                let name = text(field("name")?)?;
+                // `"$@"`, `$cmd`: the command is only known at run time.
+                if name.starts_with(['$', '"', '\'']) {
+                    return None;
+                }
+                name.to_string()
+            }
+            _ => return None,
+        };
+        compact(&callee)
+    }
+}
+
+/// `callee` with whitespace removed if it is a plain path (`a.b`, `a::b`,
+/// `a->b`), else its final name; `None` if it has no name at all.
+fn compact(callee: &str) -> Option<String> {
+    let callee: String = callee.split_whitespace().collect();
+    let is_name = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '!' | '?');
+    let plain = callee
+        .chars()
+        .all(|c| is_name(c) || matches!(c, '.' | ':' | '-' | '>'));
+    if plain && callee.starts_with(is_name) && callee.ends_with(is_name) {
+        return Some(callee);
+    }
+    let end = callee.rfind(is_name)? + 1;
+    let start = callee[..end]
+        .rfind(|c: char| !is_name(c))
+        .map_or(0, |at| at + 1);
+    Some(callee[start..end].to_string())
+}
+
+/// Whether an Elixir `call` has an argument list (`f()`, `f x`).
+fn has_arguments(call: Node) -> bool {
+    let mut cursor = call.walk();
+    call.named_children(&mut cursor)
+        .any(|child| child.kind() == "arguments")
+}
+
+/// `name` as a quoted DOT identifier.
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+    use crate::{Mode, Parser};
+
+    fn call_graph(source: &str, language: Language) -> String {
+        let tree = Parser::new(language).unwrap().parse(source).unwrap();
+        let config = TransformConfig::with_mode(Mode::CallGraph);
+        transform_call_graph_with_spans(source, &tree, language, &config)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_rust_edges_per_caller_in_call_order() {
+        let source = "fn main() {\n    let c = Config::load(\"a\");\n    run(&c);\n    \
+                      println!(\"{}\", c.name());\n    run(&c);\n}\n\n\
+                      impl Config {\n    fn load(p: &str) -> Self {\n        \
+                      let s = fs::read_to_string(p).unwrap();\n        \
+                      Self::validate(s.trim().len())\n    }\n}\n";
+        assert_eq!(
+            call_graph(source, Language::Rust),
+            "main -> Config::load\nmain -> run\n\
+             Config.load -> unwrap\nConfig.load -> fs::read_to_string\n\
+             Config.load -> Self::validate\nConfig.load -> len\nConfig.load -> s.trim\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_functions_own_their_calls() {
+        let source = "def outer():\n    setup()\n    def inner():\n        work()\n    \
+                      inner()\n\nif __name__ == \"__main__\":\n    outer()\n";
+        assert_eq!(
+            call_graph(source, Language::Python),
+            "outer -> setup\nouter.inner -> work\nouter -> inner\n(top level) -> outer\n"
+        );
+    }
+
+    #[test]
+    fn test_elixir_definitions_are_not_calls() {
+        let source = "defmodule M do\n  @spec main(integer) :: :ok\n  def main(x) do\n    \
+                      if x do\n      A.bar(x)\n    end\n  end\nend\n";
+        assert_eq!(call_graph(source, Language::Elixir), "M.main -> A.bar\n");
+    }
+
+    #[test]
+    fn test_elixir_keyword_bodies_and_field_access() {
+        let source = "defmodule M do\n  def name(user), do: user.name\n  \
+                      def load(path), do: File.read!(path)\n  def now, do: DateTime.utc_now\nend\n";
+        assert_eq!(
+            call_graph(source, Language::Elixir),
+            "M.load -> File.read!\nM.now -> DateTime.utc_now\n"
+        );
+    }
+
+    #[test]
+    fn test_julia_one_line_definitions_are_callers() {
+        let source = "scale(c, k) = Circle(c.r * k)\nfunction grow(c)\n  scale(c, 2)\nend\n";
+        assert_eq!(
+            call_graph(source, Language::Julia),
+            "scale -> Circle\ngrow -> scale\n"
+        );
+    }
+
+    #[test]
+    fn test_ocaml_constructor_applications_are_not_calls() {
+        let source = "let scale s k = Circle (radius s *. k)\nlet wrap x = Some (M.Leaf x)\n";
+        assert_eq!(call_graph(source, Language::Ocaml), "scale -> radius\n");
+    }
+
+    #[test]
+    fn test_dot_output_quotes_names() {
+        let source = "class A { void run() { this.check(); } }\n";
+        let tree = Parser::new(Language::Java).unwrap().parse(source).unwrap();
+        let config = TransformConfig::with_mode(Mode::CallGraph).with_dot_graph(true);
+        let (text, _, line_map) =
+            transform_call_graph_with_spans_and_line_map(source, &tree, Language::Java, &config)
+                .unwrap();
+        assert_eq!(text, "digraph calls {\n  \"A.run\" -> \"this.check\";\n}\n");
+        assert_eq!(line_map, [0, 1, 0]);
+    }
+
+    #[test]
+    fn test_compact_keeps_paths_and_cuts_expressions() {
+        assert_eq!(
+            compact("self.items .push").as_deref(),
+            Some("self.items.push")
+        );
+        assert_eq!(compact("obj->run").as_deref(), Some("obj->run"));
+        assert_eq!(compact("foo().bar").as_deref(), Some("bar"));
+        assert_eq!(compact("Vec::<u8>::new").as_deref(), Some("new"));
+        assert_eq!(compact("()"), None);
+    }
+}
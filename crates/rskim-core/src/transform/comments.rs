@@ -0,0 +1,305 @@
+//! Comments mode - comments and doc comments with their owning symbols
+//!
+//! ARCHITECTURE: Every other mode treats comments as noise to keep or strip
+//! around the code. Comments mode inverts that: it keeps every comment, doc
+//! comment and Python docstring and drops the code, so TODOs, design notes
+//! and embedded documentation can be read (or grepped) on their own.
+//!
+//! Adjacent own-line comments at the same indentation form one block. Each
+//! block is printed under a header naming its 1-indexed start line and the
+//! symbol it belongs to (see [`crate::symbols`]), with blocks separated by a
+//! blank line:
+//!
+//! ```text
+//! 1:
+//! //! Config loading.
+//!
+//! 3: Config
+//! /// Configuration read from disk.
+//!
+//! 9: Config.validate
+//! // TODO: reject symlinks
+//! ```
+//!
+//! A block directly above a declaration (attributes and decorators between
+//! them allowed) belongs to that declaration, so ordinary comments above a
+//! function are attributed like doc comments. Any other block belongs to the
+//! innermost symbol enclosing it; file-level blocks get a bare `1:` header.
+//! Rust inner doc comments (`//!`, `/*!`) document their enclosing item, not
+//! the next one.
+
+use super::docs::{ATTRIBUTE_KINDS, last_row, reindent};
+use super::truncate::NodeSpan;
+use crate::symbols::{Symbol, symbols_in_tree};
+use crate::transform::minimal::{MAX_AST_DEPTH, is_comment_node};
+use crate::{Language, Result, SkimError, TransformConfig};
+use tree_sitter::{Node, Tree};
+
+/// Transform to comments mode, returning text and per-block spans.
+pub(crate) fn transform_comments_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_comments_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to comments mode, also mapping each output line to its source
+/// line (`0` for the blank separators between blocks).
+pub(crate) fn transform_comments_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    _config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let mut nodes = Vec::new();
+    collect_comments(tree.root_node(), language, &mut nodes, 0)?;
+    let blocks = group_blocks(&nodes, source);
+    if blocks.is_empty() {
+        return Ok((String::new(), Vec::new(), Vec::new()));
+    }
+    let symbols = symbols_in_tree(source, tree, language)?;
+
+    let mut text = String::new();
+    let mut spans = Vec::with_capacity(blocks.len());
+    let mut line_map = Vec::new();
+    for block in &blocks {
+        if !line_map.is_empty() {
+            text.push('\n');
+            line_map.push(0);
+        }
+        let start_row = block.first.start_position().row;
+        let first_line = line_map.len();
+        match owner(block, source, &symbols) {
+            Some(symbol) => text.push_str(&format!("{}: {}\n", start_row + 1, symbol.name)),
+            None => text.push_str(&format!("{}:\n", start_row + 1)),
+        }
+        line_map.push(start_row + 1);
+
+        let body = source
+            .get(block.first.start_byte()..block.last.end_byte())
+            .unwrap_or_default()
+            .trim_end();
+        for (offset, line) in reindent(body, block.first.start_position().column, "")
+            .lines()
+            .enumerate()
+        {
+            text.push_str(line);
+            text.push('\n');
+            line_map.push(start_row + offset + 1);
+        }
+        spans.push(NodeSpan::new(
+            first_line..line_map.len(),
+            block.first.kind(),
+        ));
+    }
+    Ok((text, spans, line_map))
+}
+
+/// A comment, or a Python docstring.
+#[derive(Clone, Copy)]
+pub(crate) struct Comment<'tree> {
+    node: Node<'tree>,
+    docstring: bool,
+}
+
+/// A run of comments printed together.
+pub(crate) struct Block<'tree> {
+    pub(crate) first: Node<'tree>,
+    pub(crate) last: Node<'tree>,
+    /// Whether the block opens its line, rather than trailing code.
+    own_line: bool,
+    docstring: bool,
+}
+
+/// Every comment and docstring under `node`, in source order.
+pub(crate) fn collect_comments<'tree>(
+    node: Node<'tree>,
+    language: Language,
+    out: &mut Vec<Comment<'tree>>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested or malicious input
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {} (possible malicious input)",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    if is_comment_node(node.kind(), language) {
+        out.push(Comment {
+            node,
+            docstring: false,
+        });
+        return Ok(());
+    }
+    if language == Language::Python && is_docstring(node) {
+        out.push(Comment {
+            node,
+            docstring: true,
+        });
+        return Ok(());
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_comments(child, language, out, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Whether `node` is a lone string opening a Python module, class or
+/// function body.
+fn is_docstring(node: Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    let opens_body = match parent.kind() {
+        "module" => true,
+        "block" => parent.parent().is_some_and(|owner| {
+            matches!(owner.kind(), "function_definition" | "class_definition")
+        }),
+        _ => false,
+    };
+    node.kind() == "expression_statement"
+        && node.named_child_count() == 1
+        && node
+            .named_child(0)
+            .is_some_and(|child| child.kind() == "string")
+        && opens_body
+        && parent.named_child(0) == Some(node)
+}
+
+/// Merge own-line comments on consecutive lines, at the same column and
+/// under the same parent, into blocks. Docstrings and trailing comments
+/// stand alone.
+pub(crate) fn group_blocks<'tree>(comments: &[Comment<'tree>], source: &str) -> Vec<Block<'tree>> {
+    let mut blocks: Vec<Block<'tree>> = Vec::new();
+    for comment in comments {
+        let node = comment.node;
+        let own_line = opens_line(node, source);
+        if let Some(block) = blocks.last_mut()
+            && own_line
+            && block.own_line
+            && !comment.docstring
+            && !block.docstring
+            && node.start_position().row == last_row(block.last) + 1
+            && node.start_position().column == block.first.start_position().column
+            && node.parent() == block.last.parent()
+        {
+            block.last = node;
+            continue;
+        }
+        blocks.push(Block {
+            first: node,
+            last: node,
+            own_line,
+            docstring: comment.docstring,
+        });
+    }
+    blocks
+}
+
+/// Whether only whitespace precedes `node` on its first line.
+fn opens_line(node: Node, source: &str) -> bool {
+    let start = node.start_byte();
+    let line_start = source[..start].rfind('\n').map_or(0, |at| at + 1);
+    source[line_start..start].trim().is_empty()
+}
+
+/// The symbol `block` documents or sits in, if any.
+pub(crate) fn owner<'s>(block: &Block, source: &str, symbols: &'s [Symbol]) -> Option<&'s Symbol> {
+    documented(block, source, symbols).or_else(|| {
+        let line = block.first.start_position().row + 1;
+        symbols
+            .iter()
+            .filter(|symbol| symbol.start_line <= line && line <= symbol.end_line)
+            .max_by_key(|symbol| symbol.depth)
+    })
+}
+
+/// The declaration directly below `block`, looking past attributes and
+/// decorators; a blank line in between ends the search.
+fn documented<'s>(block: &Block, source: &str, symbols: &'s [Symbol]) -> Option<&'s Symbol> {
+    let inner_doc = block
+        .first
+        .utf8_text(source.as_bytes())
+        .is_ok_and(|text| text.starts_with("//!") || text.starts_with("/*!"));
+    if !block.own_line || block.docstring || inner_doc {
+        return None;
+    }
+
+    let mut end_row = last_row(block.last);
+    let mut next = block.last.next_sibling();
+    while let Some(node) = next {
+        if node.start_position().row > end_row + 1 {
+            return None;
+        }
+        if !ATTRIBUTE_KINDS.contains(&node.kind()) {
+            // The outermost symbol starting inside the declaration is the
+            // declaration itself, whatever wraps it (`export`, decorators).
+            let rows = node.start_position().row + 1..=last_row(node) + 1;
+            return symbols
+                .iter()
+                .find(|symbol| rows.contains(&symbol.start_line));
+        }
+        end_row = last_row(node);
+        next = node.next_sibling();
+    }
+    None
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use crate::{Language, Mode, transform};
+
+    fn comments(source: &str, language: Language) -> String {
+        transform(source, language, Mode::Comments).unwrap()
+    }
+
+    #[test]
+    fn test_rust_comments_name_their_owners() {
+        let source = "//! Config loading.\n\n/// Configuration read from disk.\n\
+                      #[derive(Debug)]\npub struct Config;\n\nimpl Config {\n    \
+                      fn validate(&self) {\n        // TODO: reject symlinks\n        \
+                      // and sockets\n        let ok = true; // assume the best\n    }\n}\n";
+        assert_eq!(
+            comments(source, Language::Rust),
+            "1:\n//! Config loading.\n\n\
+             3: Config\n/// Configuration read from disk.\n\n\
+             9: Config.validate\n// TODO: reject symlinks\n// and sockets\n\n\
+             11: Config.validate\n// assume the best\n"
+        );
+    }
+
+    #[test]
+    fn test_python_docstrings_and_comments() {
+        let source = "\"\"\"Module docs.\"\"\"\n\n# Helpers\n\n\
+                      def load(path):\n    \"\"\"Load a file.\n\n    Returns text.\n    \"\"\"\n    \
+                      x = \"not a docstring\"\n    return x\n";
+        assert_eq!(
+            comments(source, Language::Python),
+            "1:\n\"\"\"Module docs.\"\"\"\n\n3:\n# Helpers\n\n\
+             6: load\n\"\"\"Load a file.\n\nReturns text.\n\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_comment_above_decorated_function_belongs_to_it() {
+        let source = "// Entry point.\n@Component({})\nexport class App {}\n";
+        assert_eq!(
+            comments(source, Language::TypeScript),
+            "1: App\n// Entry point.\n"
+        );
+    }
+
+    #[test]
+    fn test_no_comments_gives_empty_output() {
+        assert_eq!(comments("fn main() {}\n", Language::Rust), "");
+    }
+}
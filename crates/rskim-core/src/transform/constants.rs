@@ -0,0 +1,623 @@
+//! Constants mode - constant, enum and configuration declarations with values
+//!
+//! ARCHITECTURE: Types mode keeps type definitions and drops every value;
+//! constants mode keeps the declarations whose values are the answer to
+//! "what is the timeout / the default port / the list of regions":
+//!
+//! - `const` and `static` items, `final`/`readonly` static fields, `#define`
+//!   macros with a value, Go `const`/`var` blocks, Python module-level
+//!   assignments, Ruby `CONSTANTS`, shell variables, Elixir module attributes
+//! - enums (Python `Enum` subclasses and Julia `@enum` included)
+//! - exported config objects (`export default {...}`, `module.exports = {...}`)
+//!
+//! Declarations are copied verbatim, values included, and only outside
+//! function bodies: at the top level, in modules and namespaces, and as
+//! members of classes, structs and `impl` blocks. Functions bound to
+//! constants (`const handler = () => ...`) and `require(...)` imports are not
+//! constants. Declarations on consecutive source lines stay together; a blank
+//! line separates groups. Comments are dropped, as in types mode.
+
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::transform::truncate::NodeSpan;
+use crate::transform::utils::{align_to_char_boundaries, ocaml_function_body, to_static_node_kind};
+use crate::{Language, Result, SkimError, TransformConfig};
+use tree_sitter::{Node, Tree};
+
+/// Maximum number of constant declarations to prevent memory exhaustion
+const MAX_CONSTANTS: usize = 10_000;
+
+/// JS/TS initializer kinds that make a `const` a function or class binding.
+const FUNCTION_VALUE_KINDS: &[&str] = &[
+    "arrow_function",
+    "function_expression",
+    "function",
+    "generator_function",
+    "class",
+];
+
+/// Elixir module attributes that carry docs, specs or compiler directives
+/// rather than values.
+const ELIXIR_DIRECTIVE_ATTRIBUTES: &[&str] = &[
+    "doc",
+    "moduledoc",
+    "typedoc",
+    "spec",
+    "type",
+    "typep",
+    "opaque",
+    "callback",
+    "macrocallback",
+    "impl",
+    "behaviour",
+    "derive",
+    "enforce_keys",
+    "dialyzer",
+    "compile",
+];
+
+/// One extracted declaration: its text, node kind and 1-indexed source lines.
+struct Constant {
+    text: String,
+    kind: &'static str,
+    start_line: usize,
+    end_line: usize,
+}
+
+/// Transform to constants mode, returning text and per-declaration spans.
+pub(crate) fn transform_constants_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_constants_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to constants mode, also mapping each output line to its source
+/// line. Blank lines between groups map to `0`.
+pub(crate) fn transform_constants_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    _config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let mut constants = Vec::new();
+    collect_constants(tree.root_node(), source, language, true, &mut constants, 0)?;
+
+    // Declaration count over the cap: a legitimate but very large file (a
+    // generated lookup table), not an attack. Signal a complexity limit so
+    // the dispatcher degrades to a lossless raw passthrough. (#317)
+    if constants.len() > MAX_CONSTANTS {
+        return Err(SkimError::ComplexityLimit {
+            what: "constant declarations",
+            count: constants.len(),
+            max: MAX_CONSTANTS,
+        });
+    }
+
+    let mut text = String::new();
+    let mut spans = Vec::with_capacity(constants.len());
+    let mut line_map = Vec::new();
+    let mut previous_end = None;
+    for constant in constants {
+        if let Some(end) = previous_end {
+            if constant.start_line == end + 1 {
+                text.push('\n');
+            } else {
+                text.push_str("\n\n");
+                line_map.push(0);
+            }
+        }
+        let line_count = constant.text.lines().count().max(1);
+        spans.push(NodeSpan::new(
+            line_map.len()..line_map.len() + line_count,
+            constant.kind,
+        ));
+        line_map.extend((0..line_count).map(|i| constant.start_line + i));
+        text.push_str(&constant.text);
+        previous_end = Some(constant.end_line);
+    }
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    Ok((text, spans, line_map))
+}
+
+/// Recursively collect constant declarations, descending only into
+/// containers (see [`is_container`]) so function bodies are never entered.
+fn collect_constants(
+    node: Node,
+    source: &str,
+    language: Language,
+    top_level: bool,
+    constants: &mut Vec<Constant>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested or malicious input
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {} (possible malicious input)",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if is_constant(child, source, language, top_level) {
+            constants.extend(extract(child, source));
+        } else if is_container(child, source, language) {
+            // Namespaces and header guards keep their contents top-level.
+            let top_level = top_level && is_namespace(child.kind());
+            collect_constants(child, source, language, top_level, constants, depth + 1)?;
+        }
+    }
+    Ok(())
+}
+
+/// Copy `node`'s text, dedenting continuation lines by its first line's
+/// indentation.
+fn extract(node: Node, source: &str) -> Option<Constant> {
+    // Class fields and C enums leave their `;` to the enclosing node.
+    let end = match node.next_sibling() {
+        Some(semicolon) if semicolon.kind() == ";" => semicolon.end_byte(),
+        _ => node.end_byte(),
+    };
+    let (start, end) = align_to_char_boundaries(source, node.start_byte(), end);
+    let text = source.get(start..end)?.trim_end();
+    if text.is_empty() {
+        return None;
+    }
+    let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &source[line_start..start];
+    let text = if !indent.is_empty() && indent.trim().is_empty() {
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| match i {
+                0 => line,
+                _ => line.strip_prefix(indent).unwrap_or(line),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        text.to_string()
+    };
+    // Some nodes (`#define`) end after their newline: count the text's lines.
+    let start_line = node.start_position().row + 1;
+    Some(Constant {
+        end_line: start_line + text.lines().count() - 1,
+        text,
+        kind: to_static_node_kind(node.kind()),
+        start_line,
+    })
+}
+
+/// Whether `node` is a constant, enum or config declaration in `language`.
+///
+/// `top_level` is false inside classes, structs and `impl` blocks, where
+/// only static constants count (instance fields are state, not constants).
+fn is_constant(node: Node, source: &str, language: Language, top_level: bool) -> bool {
+    let kind = node.kind();
+    match language {
+        Language::Rust => matches!(kind, "const_item" | "static_item" | "enum_item"),
+        Language::TypeScript | Language::Tsx | Language::JavaScript => match kind {
+            "lexical_declaration" => is_js_constant(node, source),
+            "enum_declaration" => true,
+            "public_field_definition" => {
+                has_keyword(node, source, "static") && has_keyword(node, source, "readonly")
+            }
+            "export_statement" => match node.child_by_field_name("declaration") {
+                Some(declaration) => is_constant(declaration, source, language, top_level),
+                // `export default { ... }`
+                None => node.child_by_field_name("value").is_some_and(|value| {
+                    matches!(value.kind(), "object" | "array") && is_plain_value(value)
+                }),
+            },
+            // `module.exports = { ... }`
+            "expression_statement" => node.named_child(0).is_some_and(|assignment| {
+                assignment.kind() == "assignment_expression"
+                    && text(assignment.child_by_field_name("left"), source) == "module.exports"
+                    && assignment
+                        .child_by_field_name("right")
+                        .is_some_and(|value| value.kind() == "object" && is_plain_value(value))
+            }),
+            _ => false,
+        },
+        Language::Python => match kind {
+            "expression_statement" => top_level && is_python_assignment(node),
+            "class_definition" => is_python_enum(node, source),
+            _ => false,
+        },
+        Language::Go => top_level && matches!(kind, "const_declaration" | "var_declaration"),
+        Language::Java => match kind {
+            "field_declaration" => {
+                has_keyword(node, source, "static") && has_keyword(node, source, "final")
+            }
+            "constant_declaration" | "enum_declaration" => true,
+            _ => false,
+        },
+        Language::CSharp => match kind {
+            "field_declaration" => {
+                has_keyword(node, source, "const")
+                    || (has_keyword(node, source, "static")
+                        && has_keyword(node, source, "readonly"))
+            }
+            "enum_declaration" => true,
+            _ => false,
+        },
+        Language::Kotlin => match kind {
+            "property_declaration" => {
+                has_keyword(node, source, "const")
+                    || (top_level && has_keyword(node, source, "val"))
+            }
+            "class_declaration" => has_keyword(node, source, "enum"),
+            _ => false,
+        },
+        Language::Swift => match kind {
+            "property_declaration" => {
+                let is_let = text(child_of_kind(node, "value_binding_pattern"), source) == "let";
+                is_let && (top_level || has_keyword(node, source, "static"))
+            }
+            "class_declaration" => child_of_kind(node, "enum_class_body").is_some(),
+            _ => false,
+        },
+        Language::C | Language::Cpp | Language::ObjectiveC => match kind {
+            // `#define NAME value`; include guards have no value.
+            "preproc_def" => node.child_by_field_name("value").is_some(),
+            "declaration" => {
+                is_c_constant(node, source) && child_of_kind(node, "init_declarator").is_some()
+            }
+            "field_declaration" => {
+                is_c_constant(node, source)
+                    && text(child_of_kind(node, "storage_class_specifier"), source) == "static"
+                    && node.child_by_field_name("default_value").is_some()
+            }
+            "enum_specifier" => node.child_by_field_name("body").is_some(),
+            "type_definition" => node.child_by_field_name("type").is_some_and(|ty| {
+                ty.kind() == "enum_specifier" && ty.child_by_field_name("body").is_some()
+            }),
+            _ => false,
+        },
+        Language::Ruby => {
+            kind == "assignment"
+                && node
+                    .child_by_field_name("left")
+                    .is_some_and(|left| left.kind() == "constant")
+        }
+        Language::Shell => {
+            top_level
+                && match kind {
+                    "variable_assignment" => true,
+                    "declaration_command" => child_of_kind(node, "variable_assignment").is_some(),
+                    _ => false,
+                }
+        }
+        Language::Elixir => kind == "unary_operator" && is_elixir_attribute(node, source),
+        Language::Julia => match kind {
+            "const_statement" => true,
+            "macrocall_expression" => {
+                text(child_of_kind(node, "macro_identifier"), source) == "@enum"
+            }
+            _ => false,
+        },
+        Language::Dart => match kind {
+            "local_variable_declaration" => {
+                top_level
+                    && child_of_kind(node, "initialized_variable_definition").is_some_and(|def| {
+                        child_of_kind(def, "const_builtin").is_some()
+                            || child_of_kind(def, "final_builtin").is_some()
+                    })
+            }
+            "class_member_definition" => child_of_kind(node, "declaration").is_some_and(|decl| {
+                has_keyword(decl, source, "static")
+                    && (child_of_kind(decl, "const_builtin").is_some()
+                        || child_of_kind(decl, "final_builtin").is_some())
+            }),
+            "enum_declaration" => true,
+            _ => false,
+        },
+        Language::Solidity => match kind {
+            "state_variable_declaration" => {
+                has_keyword(node, source, "constant") || has_keyword(node, source, "immutable")
+            }
+            "constant_variable_declaration" | "enum_declaration" => true,
+            _ => false,
+        },
+        Language::Ocaml => {
+            kind == "value_definition"
+                && child_of_kind(node, "let_binding")
+                    .is_some_and(|binding| ocaml_function_body(binding).is_none())
+        }
+        _ => false,
+    }
+}
+
+/// Whether `node` holds declarations constants mode should look inside:
+/// modules, namespaces, classes and their bodies, header guards. Function
+/// bodies never are.
+fn is_container(node: Node, source: &str, language: Language) -> bool {
+    let kind = node.kind();
+    match language {
+        Language::Rust => matches!(
+            kind,
+            "mod_item" | "impl_item" | "trait_item" | "declaration_list"
+        ),
+        Language::TypeScript | Language::Tsx | Language::JavaScript => matches!(
+            kind,
+            "export_statement"
+                | "expression_statement"
+                | "internal_module"
+                | "module"
+                | "statement_block"
+                | "class_declaration"
+                | "abstract_class_declaration"
+                | "class_body"
+        ),
+        Language::Java => matches!(
+            kind,
+            "class_declaration" | "class_body" | "interface_declaration" | "interface_body"
+        ),
+        Language::CSharp => matches!(
+            kind,
+            "namespace_declaration"
+                | "file_scoped_namespace_declaration"
+                | "declaration_list"
+                | "class_declaration"
+                | "struct_declaration"
+                | "interface_declaration"
+        ),
+        Language::Kotlin => matches!(
+            kind,
+            "class_declaration" | "class_body" | "object_declaration" | "companion_object"
+        ),
+        Language::Swift => matches!(kind, "class_declaration" | "class_body"),
+        Language::C | Language::Cpp | Language::ObjectiveC => matches!(
+            kind,
+            "preproc_ifdef"
+                | "preproc_if"
+                | "preproc_else"
+                | "preproc_elif"
+                | "linkage_specification"
+                | "namespace_definition"
+                | "declaration_list"
+                | "class_specifier"
+                | "struct_specifier"
+                | "field_declaration_list"
+        ),
+        Language::Ruby => matches!(kind, "module" | "class" | "body_statement"),
+        Language::Elixir => {
+            kind == "do_block"
+                || (kind == "call"
+                    && text(node.child_by_field_name("target"), source) == "defmodule")
+        }
+        Language::Julia => kind == "module_definition",
+        Language::Dart => matches!(kind, "class_definition" | "class_body"),
+        Language::Solidity => matches!(
+            kind,
+            "contract_declaration"
+                | "interface_declaration"
+                | "library_declaration"
+                | "contract_body"
+        ),
+        Language::Ocaml => matches!(kind, "module_definition" | "module_binding" | "structure"),
+        _ => false,
+    }
+}
+
+/// Containers whose contents count as top-level: modules, namespaces and
+/// preprocessor conditionals, as opposed to classes and `impl` blocks.
+fn is_namespace(kind: &str) -> bool {
+    matches!(
+        kind,
+        "mod_item"
+            | "declaration_list"
+            | "export_statement"
+            | "expression_statement"
+            | "internal_module"
+            | "module"
+            | "statement_block"
+            | "namespace_declaration"
+            | "file_scoped_namespace_declaration"
+            | "namespace_definition"
+            | "linkage_specification"
+            | "preproc_ifdef"
+            | "preproc_if"
+            | "preproc_else"
+            | "preproc_elif"
+            | "call"
+            | "do_block"
+            | "module_definition"
+            | "module_binding"
+            | "structure"
+    )
+}
+
+/// The source text of `node`, or `""`.
+fn text<'a>(node: Option<Node>, source: &'a str) -> &'a str {
+    node.and_then(|n| source.get(n.start_byte()..n.end_byte()))
+        .unwrap_or_default()
+}
+
+/// The first named child of `node` of kind `kind`.
+fn child_of_kind<'tree>(node: Node<'tree>, kind: &str) -> Option<Node<'tree>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .find(|child| child.kind() == kind)
+}
+
+/// Whether `node` carries keyword `word`, as an anonymous token or inside
+/// one of its modifier children (`modifiers`, `modifier`, `property_modifier`).
+fn has_keyword(node: Node, source: &str, word: &str) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).any(|child| {
+        child.kind() == word
+            || (child.kind().contains("modifier")
+                && text(Some(child), source)
+                    .split_whitespace()
+                    .any(|w| w == word))
+    })
+}
+
+/// A JS/TS `const` declaration of values rather than functions, classes or
+/// `require(...)` imports.
+fn is_js_constant(node: Node, source: &str) -> bool {
+    if node
+        .child(0)
+        .is_none_or(|keyword| keyword.kind() != "const")
+    {
+        return false;
+    }
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|child| child.kind() == "variable_declarator")
+        .all(|declarator| {
+            declarator
+                .child_by_field_name("value")
+                .is_some_and(|value| {
+                    is_plain_value(value)
+                        && !(value.kind() == "call_expression"
+                            && text(value.child_by_field_name("function"), source) == "require")
+                })
+        })
+}
+
+/// A JS/TS value that is data rather than code: not a function or class,
+/// and not an object with methods (a Vue component's options, a handler
+/// table).
+fn is_plain_value(value: Node) -> bool {
+    if FUNCTION_VALUE_KINDS.contains(&value.kind()) {
+        return false;
+    }
+    if value.kind() != "object" {
+        return true;
+    }
+    let mut cursor = value.walk();
+    value
+        .named_children(&mut cursor)
+        .all(|member| match member.kind() {
+            "method_definition" => false,
+            "pair" => member
+                .child_by_field_name("value")
+                .is_none_or(|value| !FUNCTION_VALUE_KINDS.contains(&value.kind())),
+            _ => true,
+        })
+}
+
+/// A Python statement assigning a value to names (not to attributes or
+/// subscripts, and not a bare annotation).
+fn is_python_assignment(node: Node) -> bool {
+    node.named_child(0).is_some_and(|assignment| {
+        assignment.kind() == "assignment"
+            && assignment.child_by_field_name("right").is_some()
+            && assignment.child_by_field_name("left").is_some_and(|left| {
+                matches!(left.kind(), "identifier" | "pattern_list" | "tuple_pattern")
+            })
+    })
+}
+
+/// A Python class deriving from `Enum`, `IntEnum`, `Flag` and the like.
+fn is_python_enum(node: Node, source: &str) -> bool {
+    let Some(bases) = node.child_by_field_name("superclasses") else {
+        return false;
+    };
+    let mut cursor = bases.walk();
+    bases.named_children(&mut cursor).any(|base| {
+        let name = text(Some(base), source);
+        let name = name.rsplit('.').next().unwrap_or(name);
+        name.ends_with("Enum") || name.ends_with("Flag")
+    })
+}
+
+/// A C/C++ declaration qualified `const` or `constexpr`.
+fn is_c_constant(node: Node, source: &str) -> bool {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).any(|child| {
+        child.kind() == "type_qualifier"
+            && matches!(text(Some(child), source), "const" | "constexpr")
+    })
+}
+
+/// An Elixir module attribute holding a value (`@timeout 5_000`), as opposed
+/// to `@doc`, `@spec` and other directives.
+fn is_elixir_attribute(node: Node, source: &str) -> bool {
+    if node.child(0).is_none_or(|operator| operator.kind() != "@") {
+        return false;
+    }
+    node.child_by_field_name("operand").is_some_and(|operand| {
+        operand.kind() == "call"
+            && !ELIXIR_DIRECTIVE_ATTRIBUTES
+                .contains(&text(operand.child_by_field_name("target"), source))
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use crate::{Language, Mode, transform};
+
+    fn constants(source: &str, language: Language) -> String {
+        transform(source, language, Mode::Constants).unwrap()
+    }
+
+    #[test]
+    fn test_rust_keeps_consts_statics_and_enums() {
+        let source = "use std::time::Duration;\n\npub const MAX: usize = 10;\n\
+                      static NAME: &str = \"skim\";\n\nfn main() {\n    const LOCAL: u8 = 1;\n}\n\n\
+                      impl Config {\n    pub const TIMEOUT: Duration = Duration::from_secs(\n        30,\n    );\n\n    \
+                      fn new() {}\n}\n\nenum Color {\n    Red,\n}\n";
+        assert_eq!(
+            constants(source, Language::Rust),
+            "pub const MAX: usize = 10;\nstatic NAME: &str = \"skim\";\n\n\
+             pub const TIMEOUT: Duration = Duration::from_secs(\n    30,\n);\n\n\
+             enum Color {\n    Red,\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_typescript_skips_functions_and_requires() {
+        let source = "const fs = require('fs');\nexport const PORT = 8080;\nconst handler = () => 1;\n\
+                      let counter = 0;\nexport enum Level { Low }\n\
+                      class Api {\n  static readonly BASE = '/v1';\n  private url = '';\n}\n\
+                      export default { port: PORT };\n";
+        assert_eq!(
+            constants(source, Language::TypeScript),
+            "export const PORT = 8080;\n\nexport enum Level { Low }\n\n\
+             static readonly BASE = '/v1';\n\nexport default { port: PORT };\n"
+        );
+    }
+
+    #[test]
+    fn test_python_module_assignments_and_enums() {
+        let source = "import os\n\nTIMEOUT = 30\nREGIONS: list[str] = [\"eu\", \"us\"]\nos.environ[\"X\"] = \"1\"\n\n\
+                      class Color(enum.Enum):\n    RED = 1\n\nclass App:\n    name = \"app\"\n\n\
+                      def main():\n    local = 1\n";
+        assert_eq!(
+            constants(source, Language::Python),
+            "TIMEOUT = 30\nREGIONS: list[str] = [\"eu\", \"us\"]\n\n\
+             class Color(enum.Enum):\n    RED = 1\n"
+        );
+    }
+
+    #[test]
+    fn test_java_static_finals_only() {
+        let source = "public class Config {\n    public static final int MAX = 10;\n    \
+                      private final int size = 1;\n    enum Mode { FAST }\n}\n";
+        assert_eq!(
+            constants(source, Language::Java),
+            "public static final int MAX = 10;\n\nenum Mode { FAST }\n"
+        );
+    }
+
+    #[test]
+    fn test_c_defines_consts_and_enums_inside_include_guards() {
+        let source = "#ifndef CONFIG_H\n#define CONFIG_H\n\n#define MAX_USERS 64\n\
+                      static const char *NAME = \"skim\";\nint counter = 0;\n\
+                      enum color { RED, GREEN };\nint area(int w);\n\n#endif\n";
+        assert_eq!(
+            constants(source, Language::C),
+            "#define MAX_USERS 64\nstatic const char *NAME = \"skim\";\n\n\
+             enum color { RED, GREEN };\n"
+        );
+    }
+}
@@ -0,0 +1,414 @@
+//! CSV/TSV schema summary
+//!
+//! ARCHITECTURE: Delimited data has no declarations to keep, and its rows are
+//! rarely worth their tokens. The scanner reads the records (RFC 4180
+//! quoting, so quoted delimiters and newlines stay inside their field) and
+//! renders the header row, a type per column inferred from a sample of rows,
+//! and the row count.
+//!
+//! # Output Format (Structure / Signatures / Types)
+//!
+//! ```text
+//! id,name,price,created_at
+//! # id: integer
+//! # name: text
+//! # price: float, nullable
+//! # created_at: date
+//! # 1204 rows (types from the first 100)
+//! ```
+//!
+//! # Rules
+//! - The delimiter is whichever of `,`, tab, `;` and `|` occurs most often
+//!   outside quotes in the first line (comma on a tie), so `.tsv` files and
+//!   semicolon exports need no flag
+//! - The header line is kept verbatim; each column is listed with the type
+//!   of its non-empty cells among the first [`SAMPLE_ROWS`] rows: `integer`,
+//!   `float`, `boolean`, `date`, `datetime`, or `text` (`empty` when every
+//!   sampled cell is empty); `nullable` marks columns with empty cells
+//! - When the delimiter is not a comma, decimal commas (`12,50`, `1.250,00`)
+//!   count as floats, as in spreadsheet exports from European locales
+//! - A leading byte order mark is not part of the first column name
+//! - Blank lines are not rows
+//! - Rows whose field count differs from the header, and unterminated quotes,
+//!   set `has_errors`
+
+use std::fmt::Write as _;
+
+use crate::{Result, SkimError};
+
+/// Maximum number of header columns
+///
+/// SECURITY: Output has one line per column, so this bounds output size on
+/// adversarial input. Exceeding the cap degrades to passthrough like every
+/// other complexity limit.
+const MAX_CSV_COLUMNS: usize = 10_000;
+
+/// Number of data rows whose cells decide the column types.
+const SAMPLE_ROWS: usize = 100;
+
+/// Delimiters considered when sniffing the first line, in tie-break order.
+const DELIMITERS: &[char] = &[',', '\t', ';', '|'];
+
+/// Summarize a CSV/TSV file as its header, column types and row count,
+/// returning `(content, has_errors)`.
+pub(crate) fn transform_csv(source: &str) -> Result<(String, bool)> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+    let delimiter = sniff_delimiter(source);
+    let decimal_comma = delimiter != ',';
+    let mut records = Records::new(source, delimiter);
+
+    let Some(header) = records.next() else {
+        return Ok((String::new(), false));
+    };
+    if header.fields.len() > MAX_CSV_COLUMNS {
+        return Err(SkimError::ComplexityLimit {
+            what: "CSV columns",
+            count: header.fields.len(),
+            max: MAX_CSV_COLUMNS,
+        });
+    }
+
+    let mut columns = vec![ColumnType::default(); header.fields.len()];
+    let mut has_errors = false;
+    let mut rows = 0usize;
+    for record in records.by_ref() {
+        has_errors |= record.fields.len() != header.fields.len();
+        if rows < SAMPLE_ROWS {
+            for (column, cell) in columns.iter_mut().zip(&record.fields) {
+                column.observe(cell, decimal_comma);
+            }
+        }
+        rows += 1;
+    }
+    has_errors |= records.unterminated;
+
+    let mut result = String::with_capacity(header.raw.len() + 32 * columns.len());
+    result.push_str(header.raw.trim_end_matches(['\r', '\n']));
+    result.push('\n');
+    for (name, column) in header.fields.iter().zip(&columns) {
+        let _ = writeln!(result, "# {}: {}", name.trim(), column.describe());
+    }
+    let _ = write!(
+        result,
+        "# {rows} {}",
+        if rows == 1 { "row" } else { "rows" }
+    );
+    if rows > SAMPLE_ROWS {
+        let _ = write!(result, " (types from the first {SAMPLE_ROWS})");
+    }
+    result.push('\n');
+    Ok((result, has_errors))
+}
+
+/// The delimiter occurring most often outside quotes in the first line.
+fn sniff_delimiter(source: &str) -> char {
+    let first_line = source.lines().next().unwrap_or_default();
+    let mut counts = [0usize; DELIMITERS.len()];
+    let mut in_quotes = false;
+    for ch in first_line.chars() {
+        if ch == '"' {
+            in_quotes = !in_quotes;
+        } else if !in_quotes && let Some(idx) = DELIMITERS.iter().position(|&d| d == ch) {
+            counts[idx] += 1;
+        }
+    }
+    // `max_by_key` keeps the last maximum; iterate in reverse so ties go to
+    // the earlier delimiter.
+    DELIMITERS
+        .iter()
+        .zip(counts)
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map_or(',', |(&delimiter, _)| delimiter)
+}
+
+/// One parsed record: its source text and its unquoted fields.
+struct Record<'a> {
+    raw: &'a str,
+    fields: Vec<String>,
+}
+
+/// Iterator over the records of delimited text.
+struct Records<'a> {
+    source: &'a str,
+    pos: usize,
+    delimiter: char,
+    /// Set when the input ends inside a quoted field.
+    unterminated: bool,
+}
+
+impl<'a> Records<'a> {
+    fn new(source: &'a str, delimiter: char) -> Self {
+        Self {
+            source,
+            pos: 0,
+            delimiter,
+            unterminated: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Record<'a>> {
+        loop {
+            let rest = &self.source[self.pos..];
+            if rest.is_empty() {
+                return None;
+            }
+
+            let mut fields = Vec::new();
+            let mut field = String::new();
+            let mut in_quotes = false;
+            let mut chars = rest.char_indices().peekable();
+            let mut end = rest.len();
+            while let Some((idx, ch)) = chars.next() {
+                match ch {
+                    '"' if in_quotes => {
+                        if chars.next_if(|&(_, next)| next == '"').is_some() {
+                            field.push('"');
+                        } else {
+                            in_quotes = false;
+                        }
+                    }
+                    '"' if field.is_empty() => in_quotes = true,
+                    '\n' if !in_quotes => {
+                        end = idx + 1;
+                        break;
+                    }
+                    '\r' if !in_quotes => {}
+                    ch if ch == self.delimiter && !in_quotes => {
+                        fields.push(std::mem::take(&mut field));
+                    }
+                    ch => field.push(ch),
+                }
+            }
+            self.unterminated |= in_quotes;
+            fields.push(field);
+
+            let raw = &rest[..end];
+            self.pos += end;
+            if raw.trim().is_empty() {
+                continue;
+            }
+            return Some(Record { raw, fields });
+        }
+    }
+}
+
+/// Kind of value seen in a column's non-empty cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ValueKind {
+    Integer,
+    Float,
+    Boolean,
+    Date,
+    DateTime,
+    Text,
+}
+
+impl ValueKind {
+    fn of(cell: &str, decimal_comma: bool) -> Self {
+        let cell = cell.trim();
+        if is_integer(cell) {
+            Self::Integer
+        } else if is_float(cell) || (decimal_comma && is_decimal_comma_float(cell)) {
+            Self::Float
+        } else if cell.eq_ignore_ascii_case("true") || cell.eq_ignore_ascii_case("false") {
+            Self::Boolean
+        } else if is_date(cell) {
+            if cell.len() == 10 {
+                Self::Date
+            } else {
+                Self::DateTime
+            }
+        } else {
+            Self::Text
+        }
+    }
+
+    /// The kind describing both `self` and `other`: numbers widen to float,
+    /// dates to datetime, anything else mixed to text.
+    fn join(self, other: Self) -> Self {
+        match (self.min(other), self.max(other)) {
+            (a, b) if a == b => a,
+            (Self::Integer, Self::Float) => Self::Float,
+            (Self::Date, Self::DateTime) => Self::DateTime,
+            _ => Self::Text,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Boolean => "boolean",
+            Self::Date => "date",
+            Self::DateTime => "datetime",
+            Self::Text => "text",
+        }
+    }
+}
+
+/// Type of one column, accumulated over its sampled cells.
+#[derive(Debug, Clone, Copy, Default)]
+struct ColumnType {
+    kind: Option<ValueKind>,
+    nullable: bool,
+}
+
+impl ColumnType {
+    fn observe(&mut self, cell: &str, decimal_comma: bool) {
+        if cell.trim().is_empty() {
+            self.nullable = true;
+            return;
+        }
+        let kind = ValueKind::of(cell, decimal_comma);
+        self.kind = Some(self.kind.map_or(kind, |seen| seen.join(kind)));
+    }
+
+    fn describe(self) -> String {
+        match (self.kind, self.nullable) {
+            (None, _) => "empty".to_string(),
+            (Some(kind), false) => kind.name().to_string(),
+            (Some(kind), true) => format!("{}, nullable", kind.name()),
+        }
+    }
+}
+
+fn is_integer(cell: &str) -> bool {
+    let digits = cell.strip_prefix(['-', '+']).unwrap_or(cell);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Decimal or exponent notation; `inf`/`NaN` spellings are text.
+fn is_float(cell: &str) -> bool {
+    cell.bytes().any(|b| b.is_ascii_digit())
+        && cell
+            .bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'-' | b'+' | b'e' | b'E'))
+        && cell.parse::<f64>().is_ok()
+}
+
+/// `12,50` or `1.250,00`: one decimal comma, optionally with `.` grouping.
+fn is_decimal_comma_float(cell: &str) -> bool {
+    cell.matches(',').count() == 1 && is_float(&cell.replace('.', "").replace(',', "."))
+}
+
+/// `YYYY-MM-DD`, optionally followed by a `T` or space and a time.
+fn is_date(cell: &str) -> bool {
+    let bytes = cell.as_bytes();
+    if bytes.len() < 10 {
+        return false;
+    }
+    let date_shape = bytes[..10].iter().enumerate().all(|(idx, b)| match idx {
+        4 | 7 => *b == b'-',
+        _ => b.is_ascii_digit(),
+    });
+    date_shape
+        && match bytes.get(10) {
+            None => true,
+            Some(b'T' | b' ') => bytes.get(11).is_some_and(u8::is_ascii_digit),
+            Some(_) => false,
+        }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str) -> String {
+        transform_csv(source).unwrap().0
+    }
+
+    #[test]
+    fn test_csv_keeps_header_types_and_row_count() {
+        let source = "id,name,price,active,created_at\n\
+                      1,Widget,9.99,true,2024-01-05\n\
+                      2,Gadget,,false,2024-02-11T08:30:00Z\n\
+                      3,\"Doohickey, large\",12,TRUE,2024-03-01\n";
+        let (result, has_errors) = transform_csv(source).unwrap();
+        assert_eq!(
+            result,
+            "id,name,price,active,created_at\n\
+             # id: integer\n\
+             # name: text\n\
+             # price: float, nullable\n\
+             # active: boolean\n\
+             # created_at: datetime\n\
+             # 3 rows\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_tsv_delimiter_is_sniffed() {
+        let source = "city\tpopulation\nOslo\t709000\nBergen\t291000\n";
+        assert_eq!(
+            skim(source),
+            "city\tpopulation\n# city: text\n# population: integer\n# 2 rows\n"
+        );
+    }
+
+    #[test]
+    fn test_quoted_newlines_stay_in_one_row() {
+        let source = "id;note\r\n1;\"line one\nline \"\"two\"\"\"\r\n\r\n2;plain\r\n";
+        assert_eq!(
+            skim(source),
+            "id;note\n# id: integer\n# note: text\n# 2 rows\n"
+        );
+    }
+
+    #[test]
+    fn test_types_come_from_the_sample_only() {
+        let mut source = String::from("n\n");
+        for i in 0..SAMPLE_ROWS {
+            source.push_str(&format!("{i}\n"));
+        }
+        source.push_str("not a number\n");
+        assert_eq!(
+            skim(&source),
+            format!(
+                "n\n# n: integer\n# {} rows (types from the first {SAMPLE_ROWS})\n",
+                SAMPLE_ROWS + 1
+            )
+        );
+    }
+
+    #[test]
+    fn test_empty_column_and_header_only() {
+        assert_eq!(
+            skim("a,b\n1,\n"),
+            "a,b\n# a: integer\n# b: empty\n# 1 row\n"
+        );
+        assert_eq!(skim("a,b\n"), "a,b\n# a: empty\n# b: empty\n# 0 rows\n");
+        assert_eq!(skim(""), "");
+    }
+
+    #[test]
+    fn test_decimal_commas_are_floats_with_other_delimiters() {
+        assert_eq!(
+            skim("\u{feff}net;vat\n1.250,00;237,50\n89,9;17\n"),
+            "net;vat\n# net: float\n# vat: float\n# 2 rows\n"
+        );
+        assert_eq!(
+            skim("a,b\n\"12,50\",1\n"),
+            "a,b\n# a: text\n# b: integer\n# 1 row\n"
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows_and_open_quotes_set_has_errors() {
+        assert!(transform_csv("a,b\n1,2,3\n").unwrap().1);
+        assert!(transform_csv("a,b\n1,\"open\n").unwrap().1);
+    }
+
+    #[test]
+    fn test_too_many_columns_is_complexity_limit() {
+        let header = vec!["c"; MAX_CSV_COLUMNS + 1].join(",");
+        let err = transform_csv(&header).unwrap_err();
+        assert!(err.is_complexity_limit());
+    }
+}
@@ -0,0 +1,427 @@
+//! Dockerfile instruction skeleton
+//!
+//! ARCHITECTURE: Dockerfiles are line-oriented, so there is no grammar. The
+//! scanner joins continuation lines into logical instructions (honouring the
+//! `# escape=` parser directive), drops heredoc bodies, and renders each
+//! instruction by keyword.
+//!
+//! # Output Format (Structure / Signatures / Types)
+//!
+//! ```text
+//! FROM rust:1.80 AS build
+//! WORKDIR /src
+//! COPY Cargo.toml Cargo.lock ./
+//! RUN apt-get update && apt-get install ... && rm -rf ...
+//! RUN cargo build ...
+//!
+//! FROM debian:bookworm-slim
+//! COPY --from=build /src/target/release/app /usr/local/bin/app
+//! EXPOSE 8080
+//! ENTRYPOINT ["app"]
+//! ```
+//!
+//! # Rules
+//! - One line per instruction; continuation lines are joined and keywords
+//!   are upper-cased
+//! - `RUN` instructions that span lines or chain commands with `&&`, `||` or
+//!   `;` collapse to the first two words of each command (stopping early at
+//!   quotes, variables, and redirections); flags (`--mount=...`) and heredoc
+//!   bodies are dropped
+//! - `COPY`/`ADD` keep `--from` and their sources and destination; other
+//!   flags (`--chown`, `--chmod`, `--link`) and heredoc bodies are dropped
+//! - Other instructions (`FROM`, `EXPOSE`, `ENTRYPOINT`, `CMD`, `ENV`, ...)
+//!   are kept verbatim
+//! - Comments and parser directives are dropped; a blank line separates
+//!   build stages
+//! - Lines that do not start with an instruction keyword, and unterminated
+//!   continuations or heredocs, set `has_errors`
+
+use std::str::Lines;
+
+use crate::{Result, SkimError};
+
+/// Maximum number of instructions per file
+///
+/// SECURITY: Bounds output size on adversarial input. Exceeding the cap
+/// degrades to passthrough like every other complexity limit.
+const MAX_DOCKERFILE_INSTRUCTIONS: usize = 100_000;
+
+/// Dockerfile instruction keywords (upper case).
+const INSTRUCTIONS: &[&str] = &[
+    "ADD",
+    "ARG",
+    "CMD",
+    "COPY",
+    "ENTRYPOINT",
+    "ENV",
+    "EXPOSE",
+    "FROM",
+    "HEALTHCHECK",
+    "LABEL",
+    "MAINTAINER",
+    "ONBUILD",
+    "RUN",
+    "SHELL",
+    "STOPSIGNAL",
+    "USER",
+    "VOLUME",
+    "WORKDIR",
+];
+
+/// Words of each command kept when a `RUN` chain is collapsed.
+const RUN_COMMAND_WORDS: usize = 2;
+
+/// Skim a Dockerfile to its instruction skeleton, returning
+/// `(content, has_errors)`.
+pub(crate) fn transform_dockerfile(source: &str) -> Result<(String, bool)> {
+    let escape = escape_char(source);
+    let mut lines = source.lines();
+    let mut result = String::with_capacity(source.len() / 2);
+    let mut has_errors = false;
+    let mut count = 0usize;
+
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (instruction, multi_line) = join_continuations(line, escape, &mut lines);
+        has_errors |= instruction.is_none();
+        let instruction = instruction.unwrap_or_default();
+        let (keyword, args) = split_first_word(&instruction);
+        let keyword = keyword.to_ascii_uppercase();
+        if !INSTRUCTIONS.contains(&keyword.as_str()) {
+            has_errors = true;
+            continue;
+        }
+
+        count += 1;
+        if count > MAX_DOCKERFILE_INSTRUCTIONS {
+            return Err(SkimError::ComplexityLimit {
+                what: "Dockerfile instructions",
+                count,
+                max: MAX_DOCKERFILE_INSTRUCTIONS,
+            });
+        }
+
+        let heredoc = matches!(keyword.as_str(), "RUN" | "COPY" | "ADD")
+            && skip_heredocs(args, &mut lines, &mut has_errors);
+        let rendered = match keyword.as_str() {
+            "RUN" => render_run(args, multi_line || heredoc),
+            "COPY" | "ADD" => render_copy(args),
+            _ => args.to_string(),
+        };
+
+        if keyword == "FROM" && !result.is_empty() {
+            result.push('\n');
+        }
+        result.push_str(&keyword);
+        if !rendered.is_empty() {
+            result.push(' ');
+            result.push_str(&rendered);
+        }
+        result.push('\n');
+    }
+
+    Ok((result, has_errors))
+}
+
+/// The line-continuation character: `\` unless an `# escape=` parser
+/// directive at the top of the file says otherwise.
+fn escape_char(source: &str) -> char {
+    for line in source.lines() {
+        let Some(directive) = line.trim().strip_prefix('#') else {
+            break;
+        };
+        let Some((name, value)) = directive.split_once('=') else {
+            break;
+        };
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("escape") {
+            return value.trim().chars().next().unwrap_or('\\');
+        }
+        if !name.eq_ignore_ascii_case("syntax") && !name.eq_ignore_ascii_case("check") {
+            break;
+        }
+    }
+    '\\'
+}
+
+/// Join `first` with its continuation lines, skipping comment lines inside
+/// the continuation as Docker does. Returns the logical instruction (`None`
+/// when input ends mid-continuation) and whether it spanned several lines.
+fn join_continuations(first: &str, escape: char, lines: &mut Lines<'_>) -> (Option<String>, bool) {
+    let mut joined = String::new();
+    let mut current = first;
+    let mut multi_line = false;
+    loop {
+        let Some(head) = current.strip_suffix(escape) else {
+            push_part(&mut joined, current);
+            return (Some(joined), multi_line);
+        };
+        push_part(&mut joined, head.trim_end());
+        multi_line = true;
+        loop {
+            match lines.next() {
+                Some(next) if next.trim_start().starts_with('#') => continue,
+                Some(next) => {
+                    current = next.trim();
+                    break;
+                }
+                None => return (None, multi_line),
+            }
+        }
+    }
+}
+
+/// Append `part` to `joined`, space-separated.
+fn push_part(joined: &mut String, part: &str) {
+    if part.is_empty() {
+        return;
+    }
+    if !joined.is_empty() {
+        joined.push(' ');
+    }
+    joined.push_str(part);
+}
+
+/// Split off the first whitespace-delimited word.
+fn split_first_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    match text.find(char::is_whitespace) {
+        Some(i) => (&text[..i], text[i..].trim_start()),
+        None => (text, ""),
+    }
+}
+
+/// Consume the bodies of any heredocs (`<<EOF`, `<<-"EOF"`) opened in
+/// `args`. Returns whether there were any.
+fn skip_heredocs(args: &str, lines: &mut Lines<'_>, has_errors: &mut bool) -> bool {
+    let markers: Vec<&str> = args
+        .split("<<")
+        .skip(1)
+        .filter_map(|rest| {
+            let rest = rest.strip_prefix('-').unwrap_or(rest);
+            let word = rest.split(|c: char| c.is_whitespace()).next()?;
+            let word = word.trim_matches(|c| c == '"' || c == '\'');
+            let valid = !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            valid.then_some(word)
+        })
+        .collect();
+
+    for marker in &markers {
+        if !lines.by_ref().any(|line| line.trim() == *marker) {
+            *has_errors = true; // Unterminated heredoc
+        }
+    }
+    !markers.is_empty()
+}
+
+/// Strip leading `--flag` words, returning them and the rest.
+fn split_flags(args: &str) -> (Vec<&str>, &str) {
+    let mut flags = Vec::new();
+    let mut rest = args;
+    loop {
+        let (word, after) = split_first_word(rest);
+        if !word.starts_with("--") {
+            return (flags, rest);
+        }
+        flags.push(word);
+        rest = after;
+    }
+}
+
+/// Render `RUN` arguments, collapsing chains when `collapse` is set or the
+/// shell command has several `&&`-joined commands.
+fn render_run(args: &str, collapse: bool) -> String {
+    let (_, command) = split_flags(args);
+    if command.starts_with('[') {
+        return command.to_string(); // Exec form
+    }
+    if command.starts_with("<<") {
+        let (opener, _) = split_first_word(command);
+        return format!("{opener} ...");
+    }
+
+    let commands = split_commands(command);
+    if !collapse && commands.len() <= 1 {
+        return command.to_string();
+    }
+    let mut out = String::new();
+    for (cmd, separator) in commands {
+        let words: Vec<&str> = cmd.split_whitespace().collect();
+        let kept = words
+            .iter()
+            .take(RUN_COMMAND_WORDS)
+            .take_while(|word| is_plain_word(word))
+            .count()
+            .max(1);
+        out.push_str(&words[..kept].join(" "));
+        if kept < words.len() {
+            out.push_str(" ...");
+        }
+        match separator {
+            "" => {}
+            ";" => out.push_str("; "),
+            _ => {
+                out.push(' ');
+                out.push_str(separator);
+                out.push(' ');
+            }
+        }
+    }
+    out
+}
+
+/// Whether a shell word reads as a command name or plain argument, rather
+/// than a quoted string, variable, or redirection.
+fn is_plain_word(word: &str) -> bool {
+    word.chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./:=+@,\\".contains(c))
+}
+
+/// Split a shell command on `&&`, `||` and `;` outside quotes, pairing each
+/// command with the separator that follows it (`""` for the last).
+fn split_commands(command: &str) -> Vec<(&str, &str)> {
+    let bytes = command.as_bytes();
+    let mut commands = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let separator_len = match (quote, bytes[i]) {
+            (None, b'\'' | b'"') => {
+                quote = Some(bytes[i]);
+                0
+            }
+            (Some(q), b) if b == q => {
+                quote = None;
+                0
+            }
+            (Some(b'"'), b'\\') => {
+                i += 1;
+                0
+            }
+            (None, b @ (b'&' | b'|')) if bytes.get(i + 1) == Some(&b) => 2,
+            (None, b';') => 1,
+            _ => 0,
+        };
+        if separator_len > 0 {
+            let cmd = command[start..i].trim();
+            let separator = &command[i..i + separator_len];
+            if !cmd.is_empty() {
+                commands.push((cmd, separator));
+            }
+            i += separator_len;
+            start = i;
+            continue;
+        }
+        i += 1;
+    }
+    let last = command[start.min(command.len())..].trim();
+    if last.is_empty() {
+        // A trailing separator (`cmd;`) ends the chain.
+        if let Some((_, separator)) = commands.last_mut() {
+            *separator = "";
+        }
+    } else {
+        commands.push((last, ""));
+    }
+    commands
+}
+
+/// Render `COPY`/`ADD` arguments, keeping only the `--from` flag.
+fn render_copy(args: &str) -> String {
+    let (flags, paths) = split_flags(args);
+    let mut out: Vec<&str> = flags
+        .into_iter()
+        .filter(|flag| flag.starts_with("--from="))
+        .collect();
+    if !paths.is_empty() {
+        out.push(paths);
+    }
+    out.join(" ")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str) -> String {
+        transform_dockerfile(source).unwrap().0
+    }
+
+    #[test]
+    fn test_dockerfile_keeps_instruction_skeleton() {
+        let source = "# syntax=docker/dockerfile:1\nFROM node:20 AS build\n# deps first\nWORKDIR /app\nCOPY --chown=node:node package*.json ./\nRUN npm ci\n\nFROM nginx:alpine\nCOPY --from=build --link /app/dist /usr/share/nginx/html\nEXPOSE 80\nCMD [\"nginx\", \"-g\", \"daemon off;\"]\n";
+        let (result, has_errors) = transform_dockerfile(source).unwrap();
+        assert_eq!(
+            result,
+            "FROM node:20 AS build\nWORKDIR /app\nCOPY package*.json ./\nRUN npm ci\n\n\
+             FROM nginx:alpine\nCOPY --from=build /app/dist /usr/share/nginx/html\nEXPOSE 80\n\
+             CMD [\"nginx\", \"-g\", \"daemon off;\"]\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_dockerfile_collapses_run_chains() {
+        let source = "FROM debian\nRUN apt-get update \\\n    # comment inside continuation\n    && apt-get install -y --no-install-recommends curl ca-certificates \\\n    && rm -rf /var/lib/apt/lists/*\nRUN --mount=type=cache,target=/root/.cache pip install -r requirements.txt\nRUN test -f \"a && b\" && make\n";
+        assert_eq!(
+            skim(source),
+            "FROM debian\nRUN apt-get update && apt-get install ... && rm -rf ...\n\
+             RUN pip install -r requirements.txt\nRUN test -f ... && make\n"
+        );
+    }
+
+    #[test]
+    fn test_dockerfile_collapses_semicolon_and_or_chains() {
+        let source = "FROM debian\nRUN set -eux; \\\n    apt-get update; \\\n    apt-get install -y curl;\n\
+                      RUN make test || exit 1\nRUN echo 'a; b'\n";
+        assert_eq!(
+            skim(source),
+            "FROM debian\nRUN set -eux; apt-get update; apt-get install ...\n\
+             RUN make test || exit 1\nRUN echo 'a; b'\n"
+        );
+    }
+
+    #[test]
+    fn test_dockerfile_heredoc_bodies_dropped() {
+        let source = "FROM alpine\nRUN <<EOF\nset -e\nFROM not-an-instruction\nEOF\nCOPY <<-'CONF' /etc/app.conf\nkey=value\nCONF\nUSER app\n";
+        let (result, has_errors) = transform_dockerfile(source).unwrap();
+        assert_eq!(
+            result,
+            "FROM alpine\nRUN <<EOF ...\nCOPY <<-'CONF' /etc/app.conf\nUSER app\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_dockerfile_escape_directive() {
+        let source = "# escape=`\nFROM mcr.microsoft.com/windows/servercore\nRUN dir c:\\ `\n    && echo done\n";
+        assert_eq!(
+            skim(source),
+            "FROM mcr.microsoft.com/windows/servercore\nRUN dir c:\\ && echo done\n"
+        );
+    }
+
+    #[test]
+    fn test_dockerfile_lowercase_keywords_and_unknown_lines() {
+        let (result, has_errors) =
+            transform_dockerfile("from alpine\nbogus line\nexpose 8080\n").unwrap();
+        assert_eq!(result, "FROM alpine\nEXPOSE 8080\n");
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_dockerfile_unterminated_continuation_is_error() {
+        let (result, has_errors) = transform_dockerfile("FROM alpine\nRUN make \\\n").unwrap();
+        assert_eq!(result, "FROM alpine\n");
+        assert!(has_errors);
+    }
+}
@@ -0,0 +1,213 @@
+//! Docs mode - signatures with their documentation
+//!
+//! ARCHITECTURE: Docs mode is signatures mode plus each signature's attached
+//! documentation — the API-reference view of a file. Signatures mode does the
+//! collection; for every signature it finds, this module supplies:
+//!
+//! - the doc comment block directly above the declaration (`///`, `/** */`,
+//!   Go doc comments, ocamldoc, ... — whatever minimal mode keeps as a doc
+//!   comment for the language), emitted before the signature. Attributes and
+//!   decorators between the comment and the declaration are skipped, and
+//!   wrappers (`export`, `const f = () => ...`) are looked through. A blank
+//!   line ends the block.
+//! - for Python, the docstring that opens the function body, emitted after
+//!   the signature at its relative indentation.
+//!
+//! Doc text is re-indented so continuation lines line up with the
+//! (unindented) signature.
+
+use crate::Language;
+use crate::transform::minimal::{is_comment_node, is_doc_comment};
+use bumpalo::Bump;
+use tree_sitter::Node;
+
+/// Nodes that wrap a declaration without separating it from its doc comment.
+const WRAPPER_KINDS: &[&str] = &[
+    "export_statement",
+    "decorated_definition",
+    "template_declaration",
+    "variable_declarator",
+    "lexical_declaration",
+    "variable_declaration",
+    "value_definition",
+];
+
+/// Nodes allowed between a doc comment and the declaration it documents.
+pub(crate) const ATTRIBUTE_KINDS: &[&str] =
+    &["attribute_item", "decorator", "annotation", "attribute"];
+
+/// The doc comment block documenting the declaration at `node`, re-indented,
+/// with its 1-indexed start line.
+pub(crate) fn leading_doc<'a>(
+    node: Node,
+    source: &'a str,
+    language: Language,
+    bump: &'a Bump,
+) -> Option<(&'a str, usize)> {
+    let mut anchor = node;
+    while let Some(parent) = anchor.parent()
+        && WRAPPER_KINDS.contains(&parent.kind())
+    {
+        anchor = parent;
+    }
+
+    let mut block: Option<(Node, Node)> = None;
+    let mut next_row = anchor.start_position().row;
+    let mut sibling = anchor.prev_sibling();
+    while let Some(prev) = sibling {
+        if last_row(prev) + 1 < next_row {
+            break; // blank line
+        }
+        if block.is_none() && ATTRIBUTE_KINDS.contains(&prev.kind()) {
+            // attribute between doc comment and declaration
+        } else if is_outer_doc_comment(prev, source, language) {
+            block = Some((prev, block.map_or(prev, |(_, last)| last)));
+        } else {
+            break;
+        }
+        next_row = prev.start_position().row;
+        sibling = prev.prev_sibling();
+    }
+
+    let (first, last) = block?;
+    let text = source.get(first.start_byte()..last.end_byte())?.trim_end();
+    let text = reindent(text, first.start_position().column, "");
+    Some((bump.alloc_str(&text), first.start_position().row + 1))
+}
+
+/// The docstring opening the body of the Python function at `node`,
+/// re-indented relative to the `def`, with its 1-indexed start line.
+pub(crate) fn python_docstring<'a>(
+    node: Node,
+    source: &'a str,
+    bump: &'a Bump,
+) -> Option<(&'a str, usize)> {
+    let body = node.child_by_field_name("body")?;
+    let statement = body.named_child(0)?;
+    let string = statement.named_child(0)?;
+    if statement.kind() != "expression_statement"
+        || statement.named_child_count() != 1
+        || string.kind() != "string"
+    {
+        return None;
+    }
+    let column = string.start_position().column;
+    let indent = " ".repeat(column.saturating_sub(node.start_position().column));
+    let text = reindent(string.utf8_text(source.as_bytes()).ok()?, column, &indent);
+    Some((bump.alloc_str(&text), string.start_position().row + 1))
+}
+
+/// Whether `node` is a doc comment documenting what follows it; Rust inner
+/// doc comments (`//!`) document the enclosing item instead.
+fn is_outer_doc_comment(node: Node, source: &str, language: Language) -> bool {
+    is_comment_node(node.kind(), language)
+        && is_doc_comment(node, source, language)
+        && node
+            .utf8_text(source.as_bytes())
+            .is_ok_and(|text| !text.starts_with("//!") && !text.starts_with("/*!"))
+}
+
+/// Last row holding text of `node`; line comments that include their
+/// newline end at column 0 of the next row.
+pub(crate) fn last_row(node: Node) -> usize {
+    let end = node.end_position();
+    if end.column == 0 && end.row > node.start_position().row {
+        end.row - 1
+    } else {
+        end.row
+    }
+}
+
+/// Prefix every line of `text` with `indent`, first removing up to `column`
+/// leading whitespace characters from continuation lines (the first line
+/// starts at the node, so it has none).
+pub(crate) fn reindent(text: &str, column: usize, indent: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let line = if i == 0 {
+            line
+        } else {
+            let strip = line
+                .char_indices()
+                .take(column)
+                .take_while(|(_, c)| c.is_whitespace())
+                .map(|(at, c)| at + c.len_utf8())
+                .last()
+                .unwrap_or(0);
+            &line[strip..]
+        };
+        if !line.is_empty() {
+            out.push_str(indent);
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+    use crate::{Mode, transform};
+
+    fn docs(source: &str, language: Language) -> String {
+        transform(source, language, Mode::Docs).unwrap()
+    }
+
+    #[test]
+    fn test_rust_doc_comments_skip_attributes_and_blank_lines() {
+        let source = "// licence header\n\n/// Add two numbers.\n///\n/// Wraps on overflow.\n\
+                      #[inline]\npub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n\n\
+                      /// Detached.\n\nfn plain() {}\n";
+        assert_eq!(
+            docs(source, Language::Rust),
+            "/// Add two numbers.\n///\n/// Wraps on overflow.\n\
+             pub fn add(a: i32, b: i32) -> i32\nfn plain()"
+        );
+    }
+
+    #[test]
+    fn test_jsdoc_through_export_and_arrow_wrappers() {
+        let source = "/** Parse input. */\nexport const parse = (s: string) => s;\n\n\
+                      class Api {\n    /**\n     * Load.\n     */\n    load(): void {}\n\
+                      \n    // not a doc comment\n    reset(): void {}\n}\n";
+        assert_eq!(
+            docs(source, Language::TypeScript),
+            "/** Parse input. */\n(s: string) => s\n/**\n * Load.\n */\nload(): void\nreset(): void"
+        );
+    }
+
+    #[test]
+    fn test_python_docstring_follows_signature() {
+        let source = "def area(r):\n    \"\"\"Circle area.\n\n    Uses pi.\n    \"\"\"\n    \
+                      return 3.14 * r * r\n\ndef bare():\n    pass\n";
+        assert_eq!(
+            docs(source, Language::Python),
+            "def area(r):\n    \"\"\"Circle area.\n\n    Uses pi.\n    \"\"\"\ndef bare():"
+        );
+    }
+
+    #[test]
+    fn test_ocamldoc_through_value_definitions() {
+        let source = "(** Area of [s]. *)\nlet area s = s *. s\n\n(* plain *)\nlet id x = x\n";
+        assert_eq!(
+            docs(source, Language::Ocaml),
+            "(** Area of [s]. *)\nlet area s\nlet id x"
+        );
+    }
+
+    #[test]
+    fn test_reindent_strips_the_block_column() {
+        assert_eq!(
+            reindent("/**\n     * Load.\n     */", 4, ""),
+            "/**\n * Load.\n */"
+        );
+        assert_eq!(
+            reindent("\"\"\"Sum.\n\n        More.\n        \"\"\"", 8, "    "),
+            "    \"\"\"Sum.\n\n    More.\n    \"\"\""
+        );
+    }
+}
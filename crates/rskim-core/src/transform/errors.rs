@@ -0,0 +1,327 @@
+//! Errors mode - the error surface of a file
+//!
+//! ARCHITECTURE: An agent changing error handling needs to know which errors
+//! a module defines and where it produces them, not the code in between.
+//! Errors mode lists both, one line each in source order:
+//!
+//! ```text
+//! 3: pub enum ConfigError
+//! 18: return Err(ConfigError::Missing(key)); (Config.get)
+//! 31: raise ValueError(f"bad path {path}") (load)
+//! ```
+//!
+//! Error types are type declarations named `...Error`/`...Exception`, or
+//! deriving from one (`class NotFound(HttpError)`, `struct E: Error`,
+//! `#[derive(thiserror::Error)]`), plus OCaml `exception` and Solidity
+//! `error` declarations; they are shown by their header line. Error sites are
+//! `throw`/`raise`/`revert` statements and the error-producing calls of
+//! languages without them (Rust `Err(...)`, `panic!`, `bail!`; Go
+//! `errors.New`, `fmt.Errorf`, `panic`; Ruby and Elixir `raise`; OCaml
+//! `raise`/`failwith`; Julia `throw`/`error`; shell `exit` with a nonzero
+//! status). A site is shown by its source line, followed by the innermost
+//! symbol enclosing it in parentheses.
+
+use super::summary::CLASS_KINDS;
+use super::truncate::NodeSpan;
+use super::utils::{node_kind_info, to_static_node_kind};
+use crate::symbols::{Symbol, symbols_in_tree};
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::{Language, Result, SkimError, TransformConfig};
+use tree_sitter::{Node, Tree};
+
+/// Statement and expression kinds that raise an error.
+const SITE_KINDS: &[&str] = &[
+    "throw_statement",
+    "throw_expression",
+    "raise_statement",
+    "revert_statement",
+];
+
+/// Declaration kinds that define an error whatever their name.
+const ERROR_DECLARATION_KINDS: &[&str] = &["exception_definition", "error_declaration"];
+
+/// Name suffixes marking a type as an error type.
+const ERROR_SUFFIXES: &[&str] = &["Error", "Exception"];
+
+/// One listed line: its 1-indexed source line, text and owner.
+struct Entry<'s> {
+    line: usize,
+    text: String,
+    kind: &'static str,
+    owner: Option<&'s Symbol>,
+}
+
+/// Transform to errors mode, returning text and per-entry spans.
+pub(crate) fn transform_errors_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_errors_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to errors mode, also mapping each output line to the source
+/// line it lists.
+pub(crate) fn transform_errors_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    _config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    if matches!(language, Language::Markdown | Language::Sql) {
+        return Ok((String::new(), Vec::new(), Vec::new()));
+    }
+    let symbols = symbols_in_tree(source, tree, language)?;
+    let mut entries: Vec<Entry> = symbols
+        .iter()
+        .filter(|symbol| is_error_type(symbol, source))
+        .map(|symbol| Entry {
+            line: symbol.start_line,
+            text: header(source_line(source, symbol.start_line)),
+            kind: symbol.kind,
+            owner: None,
+        })
+        .collect();
+    let mut sites = Vec::new();
+    collect_sites(tree.root_node(), source, language, &mut sites, 0)?;
+    for (line, kind) in sites {
+        let text = source_line(source, line);
+        let entry = if ERROR_DECLARATION_KINDS.contains(&kind) {
+            Entry {
+                line,
+                text: header(text),
+                kind,
+                owner: None,
+            }
+        } else {
+            Entry {
+                line,
+                text: text.trim().to_string(),
+                kind,
+                owner: innermost(&symbols, line),
+            }
+        };
+        entries.push(entry);
+    }
+    entries.sort_by_key(|entry| entry.line);
+    entries.dedup_by_key(|entry| entry.line);
+
+    let mut text = String::new();
+    let mut spans = Vec::with_capacity(entries.len());
+    let mut line_map = Vec::with_capacity(entries.len());
+    for entry in entries {
+        match entry.owner {
+            Some(symbol) => text.push_str(&format!(
+                "{}: {} ({})\n",
+                entry.line, entry.text, symbol.name
+            )),
+            None => text.push_str(&format!("{}: {}\n", entry.line, entry.text)),
+        }
+        spans.push(NodeSpan::new(
+            line_map.len()..line_map.len() + 1,
+            entry.kind,
+        ));
+        line_map.push(entry.line);
+    }
+    Ok((text, spans, line_map))
+}
+
+/// Record the line and kind of every error site and error declaration under
+/// `node`.
+fn collect_sites(
+    node: Node,
+    source: &str,
+    language: Language,
+    sites: &mut Vec<(usize, &'static str)>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested or malicious input
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {} (possible malicious input)",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    let kind = node.kind();
+    let is_site = SITE_KINDS.contains(&kind)
+        || ERROR_DECLARATION_KINDS.contains(&kind)
+        // Swift: `throw` is a control transfer like `return`.
+        || (kind == "control_transfer_statement"
+            && node.child(0).is_some_and(|c| c.kind() == "throw_keyword"))
+        || error_callees(language).contains(&callee(node, source))
+        || (language == Language::Shell && is_failing_exit(node, source));
+    if is_site {
+        sites.push((node.start_position().row + 1, to_static_node_kind(kind)));
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_sites(child, source, language, sites, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Functions and macros that produce an error in `language`.
+fn error_callees(language: Language) -> &'static [&'static str] {
+    match language {
+        Language::Rust => &["Err", "panic", "bail", "unreachable"],
+        Language::Go => &["errors.New", "fmt.Errorf", "panic"],
+        Language::Ruby => &["raise", "fail"],
+        Language::Elixir => &["raise", "reraise"],
+        Language::Julia => &["throw", "error"],
+        Language::Ocaml => &["raise", "failwith", "invalid_arg"],
+        Language::Solidity => &["require"],
+        _ => &[],
+    }
+}
+
+/// The callee of a call node (`Err`, `errors.New`, `raise`), or `""` for
+/// anything else. Ruby calls with a receiver (`logger.fail`) have none.
+fn callee<'a>(node: Node, source: &'a str) -> &'a str {
+    let callee = match node.kind() {
+        "call_expression" | "application_expression" => node
+            .child_by_field_name("function")
+            .or_else(|| node.named_child(0)),
+        "macro_invocation" => node.child_by_field_name("macro"),
+        "call" if node.child_by_field_name("receiver").is_none() => node
+            .child_by_field_name("method")
+            .or_else(|| node.child_by_field_name("target")),
+        _ => None,
+    };
+    callee
+        .and_then(|callee| source.get(callee.start_byte()..callee.end_byte()))
+        .unwrap_or_default()
+}
+
+/// Shell: an `exit` with a status other than `0`. A bare `exit` passes on
+/// the last command's status and is not listed.
+fn is_failing_exit(node: Node, source: &str) -> bool {
+    let text = |field| {
+        node.child_by_field_name(field)
+            .and_then(|n| source.get(n.start_byte()..n.end_byte()))
+    };
+    node.kind() == "command"
+        && text("name") == Some("exit")
+        && text("argument").is_some_and(|status| status != "0")
+}
+
+/// Whether `symbol` declares an error type: a type named `...Error` or
+/// `...Exception`, or one whose header or Rust derives mention such a type.
+fn is_error_type(symbol: &Symbol, source: &str) -> bool {
+    if !CLASS_KINDS.contains(&symbol.kind) && node_kind_info(symbol.kind).1 != 5 {
+        return false;
+    }
+    let name = symbol.name.rsplit('.').next().unwrap_or(&symbol.name);
+    if is_error_name(name) {
+        return true;
+    }
+    // A C `typedef` has no bases; the rest of its line is the aliased type.
+    if symbol.kind == "type_definition" {
+        return false;
+    }
+    // Bases: `(HttpError)`, `extends Error`, `: Exception`, `< StandardError`
+    let line = source_line(source, symbol.start_line);
+    let bases = line.find(name).map_or("", |at| &line[at + name.len()..]);
+    if bases
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(is_error_name)
+    {
+        return true;
+    }
+    // `#[derive(Debug, thiserror::Error)]` above a Rust type
+    let above: Vec<&str> = source.lines().take(symbol.start_line - 1).collect();
+    above
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with("#["))
+        .any(|attribute| attribute.starts_with("#[derive(") && attribute.contains("Error"))
+}
+
+/// Whether an identifier names an error type.
+fn is_error_name(name: &str) -> bool {
+    ERROR_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+/// Source line `line` (1-indexed), or `""`.
+fn source_line(source: &str, line: usize) -> &str {
+    source.lines().nth(line - 1).unwrap_or_default()
+}
+
+/// A declaration's header: its first line without the opening `{` or `:`.
+fn header(line: &str) -> String {
+    let line = line.trim();
+    let line = line.strip_suffix('{').unwrap_or(line).trim_end();
+    line.strip_suffix(':').unwrap_or(line).to_string()
+}
+
+/// The innermost symbol enclosing `line`.
+fn innermost(symbols: &[Symbol], line: usize) -> Option<&Symbol> {
+    symbols
+        .iter()
+        .filter(|symbol| symbol.start_line <= line && line <= symbol.end_line)
+        .max_by_key(|symbol| symbol.depth)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use crate::{Language, Mode, transform};
+
+    fn errors(source: &str, language: Language) -> String {
+        transform(source, language, Mode::Errors).unwrap()
+    }
+
+    #[test]
+    fn test_typedef_parameters_are_not_bases() {
+        let source = "typedef void (*callback)(int code, Error *error);\n\
+                      typedef struct { int code; } ParseError;\n";
+        assert_eq!(
+            errors(source, Language::C),
+            "2: typedef struct { int code; } ParseError;\n"
+        );
+    }
+
+    #[test]
+    fn test_rust_error_types_and_err_sites() {
+        let source = "#[derive(Debug, thiserror::Error)]\npub enum LoadFailure {\n    Missing,\n}\n\n\
+                      pub struct Config;\n\nimpl Config {\n    pub fn get(&self, key: &str) -> Result<u8, LoadFailure> {\n        \
+                      if key.is_empty() {\n            return Err(LoadFailure::Missing);\n        }\n        \
+                      self.lookup(key).ok_or_else(|| panic!(\"no {key}\"))\n    }\n}\n";
+        assert_eq!(
+            errors(source, Language::Rust),
+            "2: pub enum LoadFailure\n\
+             11: return Err(LoadFailure::Missing); (Config.get)\n\
+             13: self.lookup(key).ok_or_else(|| panic!(\"no {key}\")) (Config.get)\n"
+        );
+    }
+
+    #[test]
+    fn test_python_exception_classes_and_raises() {
+        let source = "class ConfigError(Exception):\n    pass\n\nclass Missing(ConfigError):\n    pass\n\n\
+                      class Loader:\n    def load(self, path):\n        if not path:\n            \
+                      raise Missing(path)\n        return open(path)\n";
+        assert_eq!(
+            errors(source, Language::Python),
+            "1: class ConfigError(Exception)\n4: class Missing(ConfigError)\n\
+             10: raise Missing(path) (Loader.load)\n"
+        );
+    }
+
+    #[test]
+    fn test_go_sentinel_errors_and_panics() {
+        let source = "package config\n\nvar ErrMissing = errors.New(\"missing\")\n\n\
+                      func Load(path string) error {\n\tif path == \"\" {\n\t\t\
+                      return fmt.Errorf(\"load %s: %w\", path, ErrMissing)\n\t}\n\tpanic(\"todo\")\n}\n";
+        assert_eq!(
+            errors(source, Language::Go),
+            "3: var ErrMissing = errors.New(\"missing\")\n\
+             7: return fmt.Errorf(\"load %s: %w\", path, ErrMissing) (Load)\n\
+             9: panic(\"todo\") (Load)\n"
+        );
+    }
+}
@@ -0,0 +1,429 @@
+//! Structure-mode decision trace (`skim explain`)
+//!
+//! ARCHITECTURE: Re-walks the tree exactly like
+//! `structure::collect_body_replacements` and then replays the overlap pass of
+//! `structure::apply_body_replacements`, recording every decision instead of
+//! building output. Both walks share the same predicates
+//! (`matches_function_node`, `find_body_node`, `init_attribute_replacements`,
+//! template literal constants, `collect_script_replacements`,
+//! `collect_long_block_replacements`), so a change to what structure mode matches
+//! shows up here automatically; the
+//! traversal order and overlap rule are mirrored and pinned by
+//! `test_replaced_decisions_reproduce_structure_output`.
+
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::transform::script::collect_script_replacements;
+use crate::transform::structure::{
+    LONG_BLOCK_PLACEHOLDER, MAX_KEPT_BLOCK_LINES, NodeTypes, TEMPLATE_LITERAL_KIND,
+    TEMPLATE_LITERAL_PLACEHOLDER, body_placeholder, collect_long_block_replacements,
+    find_body_node, get_node_types_for_language, init_attribute_replacements,
+    matches_function_node,
+};
+use crate::transform::utils::{end_terminated_body, node_kind_info};
+use crate::{Language, Parser, Result, SkimError, TransformConfig};
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+/// Why structure mode planned a replacement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainReason {
+    /// The body of a function, method, or constructor.
+    FunctionBody,
+    /// A template literal longer than the configured threshold.
+    TemplateLiteral {
+        /// The `template_literal_threshold` the literal exceeded.
+        threshold: usize,
+    },
+    /// Part of a Python `__init__` body other than its `self.x = ...`
+    /// attribute assignments, which are kept.
+    InitBody,
+    /// A multi-line top-level statement (or its call arguments or literal)
+    /// condensed because the file is script-style.
+    ScriptStatement,
+    /// The inside of a control-flow block (shell `if`/`case`) longer than
+    /// `max_lines` lines; the header and closing keyword are kept.
+    LongBlock {
+        /// The line count the block exceeded.
+        max_lines: usize,
+    },
+}
+
+/// What structure mode did with one AST node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainAction {
+    /// Replacement planned and applied.
+    Replaced {
+        /// Why the range was replaced.
+        reason: ExplainReason,
+        /// Text written in place of the range.
+        replacement: &'static str,
+    },
+    /// Replacement planned but dropped by overlap resolution because its range
+    /// starts inside an earlier replacement (e.g. a closure inside a stripped body).
+    SkippedOverlap {
+        /// Why the replacement was planned.
+        reason: ExplainReason,
+        /// 1-indexed source line of the node whose replacement covers this one.
+        covered_by_line: usize,
+    },
+    /// Matched as a function, but it has no body (declaration, abstract or
+    /// interface method); kept verbatim.
+    NoBody,
+    /// Classified as a type definition; kept verbatim.
+    KeptType,
+}
+
+/// One traced decision, in source order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExplainDecision {
+    /// Tree-sitter kind of the matched node.
+    pub node_kind: &'static str,
+    /// 1-indexed source line where the matched node starts.
+    pub line: usize,
+    /// Byte range affected: the body for function replacements, the node otherwise.
+    pub byte_range: (usize, usize),
+    /// What structure mode did.
+    pub action: ExplainAction,
+}
+
+/// Trace of structure-mode transformation decisions for one source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// Language the source was parsed as.
+    pub language: Language,
+    /// Whether tree-sitter reported syntax errors.
+    pub has_errors: bool,
+    /// Named AST node kinds visited, most frequent first.
+    pub node_kinds: Vec<(&'static str, usize)>,
+    /// Function, type, and template literal decisions in source order.
+    pub decisions: Vec<ExplainDecision>,
+}
+
+/// Trace how structure mode would transform `source`.
+pub(crate) fn explain_structure(
+    source: &str,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<Explanation> {
+    // Markdown is extracted, not replaced; serde languages and SFCs never reach
+    // the tree-sitter replacement path.
+    let node_types = get_node_types_for_language(language)
+        .filter(|_| language != Language::Markdown)
+        .ok_or_else(|| {
+            SkimError::ConfigError(format!(
+                "{} does not use structure-mode body replacement; nothing to explain",
+                language.name()
+            ))
+        })?;
+
+    let tree = Parser::new(language)?.parse(source)?;
+    let root = tree.root_node();
+
+    let mut trace = Trace {
+        source,
+        node_types: &node_types,
+        body_placeholder: body_placeholder(language, config),
+        template_threshold: config.template_literal_threshold,
+        kind_counts: HashMap::new(),
+        decisions: Vec::new(),
+        planned: Vec::new(),
+    };
+    trace.visit(root, 0)?;
+    for summary in collect_script_replacements(root, language)? {
+        trace.plan(
+            summary.node_kind,
+            summary.line,
+            summary.byte_range,
+            ExplainReason::ScriptStatement,
+            summary.replacement,
+        );
+    }
+    for block in collect_long_block_replacements(root, language)? {
+        trace.plan(
+            block.node_kind,
+            block.line,
+            block.byte_range,
+            ExplainReason::LongBlock {
+                max_lines: MAX_KEPT_BLOCK_LINES,
+            },
+            LONG_BLOCK_PLACEHOLDER,
+        );
+    }
+    trace.resolve_overlaps();
+
+    let mut node_kinds: Vec<(&'static str, usize)> = trace.kind_counts.into_iter().collect();
+    node_kinds.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+    let mut decisions = trace.decisions;
+    decisions.sort_by_key(|d| (d.byte_range.0, d.line));
+
+    Ok(Explanation {
+        language,
+        has_errors: root.has_error(),
+        node_kinds,
+        decisions,
+    })
+}
+
+struct Trace<'a> {
+    source: &'a str,
+    node_types: &'a NodeTypes,
+    body_placeholder: &'static str,
+    template_threshold: Option<usize>,
+    kind_counts: HashMap<&'static str, usize>,
+    decisions: Vec<ExplainDecision>,
+    /// Indices into `decisions` of planned replacements, in collection order.
+    planned: Vec<usize>,
+}
+
+impl Trace<'_> {
+    fn visit(&mut self, node: Node, depth: usize) -> Result<()> {
+        if depth > MAX_AST_DEPTH {
+            return Err(SkimError::ParseError(format!(
+                "Maximum AST depth exceeded: {}",
+                MAX_AST_DEPTH
+            )));
+        }
+
+        let kind = node.kind();
+        if node.is_named() {
+            *self.kind_counts.entry(kind).or_default() += 1;
+        }
+        let line = node.start_position().row + 1;
+
+        if matches_function_node(node, self.source, self.node_types) {
+            let body = find_body_node(node);
+            let init_summary =
+                body.and_then(|body| init_attribute_replacements(node, body, self.source));
+            match (body, init_summary) {
+                (Some(_), Some(summary)) => {
+                    for (byte_range, replacement) in summary {
+                        self.plan(kind, line, byte_range, ExplainReason::InitBody, replacement);
+                    }
+                }
+                (Some(body), None) => self.plan(
+                    kind,
+                    line,
+                    (body.start_byte(), body.end_byte()),
+                    ExplainReason::FunctionBody,
+                    self.body_placeholder,
+                ),
+                (None, _) => match end_terminated_body(node) {
+                    Some(byte_range) => self.plan(
+                        kind,
+                        line,
+                        byte_range,
+                        ExplainReason::FunctionBody,
+                        self.body_placeholder,
+                    ),
+                    None => self.record(kind, line, node, ExplainAction::NoBody),
+                },
+            }
+        } else if node.is_named() && node_kind_info(kind).1 == 5 {
+            self.record(kind, line, node, ExplainAction::KeptType);
+        }
+
+        if kind == TEMPLATE_LITERAL_KIND
+            && let Some(threshold) = self.template_threshold
+            && node.end_byte() - node.start_byte() > threshold
+        {
+            self.plan(
+                kind,
+                line,
+                (node.start_byte(), node.end_byte()),
+                ExplainReason::TemplateLiteral { threshold },
+                TEMPLATE_LITERAL_PLACEHOLDER,
+            );
+            return Ok(());
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.visit(child, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, node_kind: &'static str, line: usize, node: Node, action: ExplainAction) {
+        self.decisions.push(ExplainDecision {
+            node_kind,
+            line,
+            byte_range: (node.start_byte(), node.end_byte()),
+            action,
+        });
+    }
+
+    fn plan(
+        &mut self,
+        node_kind: &'static str,
+        line: usize,
+        byte_range: (usize, usize),
+        reason: ExplainReason,
+        replacement: &'static str,
+    ) {
+        self.planned.push(self.decisions.len());
+        self.decisions.push(ExplainDecision {
+            node_kind,
+            line,
+            byte_range,
+            // Provisional; settled by `resolve_overlaps`.
+            action: ExplainAction::Replaced {
+                reason,
+                replacement,
+            },
+        });
+    }
+
+    /// Replay `apply_body_replacements`: sort by start with the last-collected
+    /// of equal starts first, then drop any range starting before the end of
+    /// the previous applied one.
+    fn resolve_overlaps(&mut self) {
+        let mut order = std::mem::take(&mut self.planned);
+        order.reverse();
+        order.sort_by_key(|&i| self.decisions[i].byte_range.0);
+
+        let mut last_end = 0;
+        let mut last_line = 0;
+        for i in order {
+            let decision = &mut self.decisions[i];
+            let (start, end) = decision.byte_range;
+            let ExplainAction::Replaced { reason, .. } = decision.action else {
+                continue;
+            };
+            if start < last_end {
+                decision.action = ExplainAction::SkippedOverlap {
+                    reason,
+                    covered_by_line: last_line,
+                };
+            } else {
+                last_end = end;
+                last_line = decision.line;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::Mode;
+
+    const SOURCE: &str = "interface User { name: string }\n\
+        function outer(u: User) {\n  const inner = () => { return 1; };\n  return inner();\n}\n\
+        abstract class Base {\n  abstract run(): void;\n  go() { return `${1}`; }\n}\n";
+
+    fn explain(source: &str, config: &TransformConfig) -> Explanation {
+        explain_structure(source, Language::TypeScript, config).expect("explain should succeed")
+    }
+
+    #[test]
+    fn test_explain_classifies_functions_types_and_overlaps() {
+        let trace = explain(SOURCE, &TransformConfig::with_mode(Mode::Structure));
+        assert!(!trace.has_errors);
+
+        let find = |kind: &str| {
+            trace
+                .decisions
+                .iter()
+                .find(|d| d.node_kind == kind)
+                .expect(kind)
+        };
+        assert_eq!(
+            find("interface_declaration").action,
+            ExplainAction::KeptType
+        );
+        assert!(matches!(
+            find("function_declaration").action,
+            ExplainAction::Replaced {
+                reason: ExplainReason::FunctionBody,
+                ..
+            }
+        ));
+        assert_eq!(
+            find("arrow_function").action,
+            ExplainAction::SkippedOverlap {
+                reason: ExplainReason::FunctionBody,
+                covered_by_line: 2,
+            }
+        );
+        assert_eq!(find("method_definition").line, 8);
+        assert!(
+            trace
+                .node_kinds
+                .iter()
+                .any(|(k, n)| *k == "identifier" && *n > 3)
+        );
+    }
+
+    #[test]
+    fn test_explain_reports_oversized_template_literal() {
+        let source = format!("const page = `{}`;\n", "x".repeat(64));
+        let config =
+            TransformConfig::with_mode(Mode::Structure).with_template_literal_threshold(Some(16));
+        let trace = explain(&source, &config);
+        assert_eq!(
+            trace.decisions[0].action,
+            ExplainAction::Replaced {
+                reason: ExplainReason::TemplateLiteral { threshold: 16 },
+                replacement: TEMPLATE_LITERAL_PLACEHOLDER,
+            }
+        );
+    }
+
+    #[test]
+    fn test_replaced_decisions_reproduce_structure_output() {
+        let config = TransformConfig::with_mode(Mode::Structure);
+        let trace = explain(SOURCE, &config);
+
+        let mut rebuilt = String::new();
+        let mut pos = 0;
+        for d in &trace.decisions {
+            if let ExplainAction::Replaced { replacement, .. } = d.action {
+                rebuilt.push_str(&SOURCE[pos..d.byte_range.0]);
+                rebuilt.push_str(replacement);
+                pos = d.byte_range.1;
+            }
+        }
+        rebuilt.push_str(&SOURCE[pos..]);
+
+        let expected = crate::transform_with_config(SOURCE, Language::TypeScript, &config).unwrap();
+        assert_eq!(rebuilt, expected);
+    }
+
+    #[test]
+    fn test_script_summaries_reproduce_structure_output() {
+        let source = "import sys\n\nrun(\n    sys.argv,\n)\n\nif __name__ == '__main__':\n    def helper():\n        pass\n    run([])\n";
+        let config = TransformConfig::with_mode(Mode::Structure);
+        let trace = explain_structure(source, Language::Python, &config).unwrap();
+
+        let mut rebuilt = String::new();
+        let mut pos = 0;
+        for d in &trace.decisions {
+            if let ExplainAction::Replaced { replacement, .. } = d.action {
+                rebuilt.push_str(&source[pos..d.byte_range.0]);
+                rebuilt.push_str(replacement);
+                pos = d.byte_range.1;
+            }
+        }
+        rebuilt.push_str(&source[pos..]);
+
+        let expected = crate::transform_with_config(source, Language::Python, &config).unwrap();
+        assert_eq!(rebuilt, expected);
+        assert!(trace.decisions.iter().any(|d| matches!(
+            d.action,
+            ExplainAction::SkippedOverlap {
+                reason: ExplainReason::FunctionBody,
+                covered_by_line: 7,
+            }
+        )));
+    }
+
+    #[test]
+    fn test_explain_rejects_non_replacement_languages() {
+        let config = TransformConfig::with_mode(Mode::Structure);
+        for language in [Language::Markdown, Language::Json, Language::Vue] {
+            assert!(explain_structure("", language, &config).is_err());
+        }
+    }
+}
@@ -0,0 +1,801 @@
+//! Groovy and Gradle build script structure extraction
+//!
+//! ARCHITECTURE: Groovy is scanned by hand rather than through a grammar.
+//! Build scripts are mostly nested closure calls, so the scanner only splits
+//! the file into statements and `header { body }` blocks, tracking just enough
+//! syntax (strings with `${...}` interpolation, triple-quoted strings,
+//! brackets, comments) to find where each statement and block ends. Which
+//! blocks stay open is decided from the block header alone.
+//!
+//! # Output Format (Structure / Signatures / Types)
+//!
+//! ```text
+//! plugins {
+//!     id 'java-library'
+//! }
+//! group = 'com.example'
+//! dependencies {
+//!     implementation 'org.slf4j:slf4j-api:2.0.9'
+//!     testImplementation('junit:junit:4.13.2') {...}
+//! }
+//! repositories {...}
+//! tasks.register('docs', Copy) {...}
+//! class Release {
+//!     String version
+//!     def publish(String target) {...}
+//! }
+//! ```
+//!
+//! # Rules
+//! - Statements outside closures (imports, assignments, `apply plugin:`,
+//!   `include ':app'`, fields) are kept verbatim
+//! - `plugins { }` and `dependencies { }` keep every declaration; a
+//!   declaration's own configuration closure collapses to `{...}`
+//! - Task declarations (`task x`, `tasks.register(...)`, `tasks.named(...)`,
+//!   ...) and method definitions keep their header; the body collapses
+//! - `class` / `interface` / `enum` / `trait` bodies stay open and follow the
+//!   same rules
+//! - Any other closure stays open only when it (transitively) contains
+//!   plugins, dependencies, tasks, methods, types, or `apply` statements;
+//!   otherwise it collapses to `name {...}`. Empty closures render as `{}`
+//! - Comments are dropped and output is re-indented four spaces per level
+//! - Unclosed blocks, strings, or comments set `has_errors`
+//!
+//! Summary mode counts the same declarations through [`symbols`]: method
+//! definitions, constructors, and the abstract methods of a type body are
+//! functions; `class`/`record` are classes; `interface`/`trait`/`enum` are
+//! types.
+
+use crate::symbols::Symbol;
+use crate::{Result, SkimError};
+
+/// Maximum block (and string interpolation) nesting depth
+///
+/// SECURITY: Matches MAX_HCL_DEPTH; bounds recursion.
+const MAX_GROOVY_DEPTH: usize = 500;
+
+/// Maximum number of statements and blocks per file
+///
+/// SECURITY: Bounds memory use on adversarial input. Exceeding the cap
+/// degrades to passthrough like every other complexity limit.
+const MAX_GROOVY_ITEMS: usize = 100_000;
+
+/// Block headers that introduce plugin or dependency declarations.
+const DECLARATION_BLOCKS: &[&str] = &["plugins", "dependencies"];
+
+/// Keywords that start a control-flow header rather than a method definition.
+const CONTROL_KEYWORDS: &[&str] = &[
+    "if",
+    "else",
+    "for",
+    "while",
+    "switch",
+    "catch",
+    "synchronized",
+    "return",
+    "new",
+    "try",
+    "finally",
+];
+
+/// Modifiers that may precede a type or method declaration.
+const MODIFIERS: &[&str] = &[
+    "public",
+    "protected",
+    "private",
+    "static",
+    "abstract",
+    "final",
+    "sealed",
+    "non-sealed",
+    "strictfp",
+];
+
+const TYPE_KEYWORDS: &[&str] = &[
+    "class",
+    "interface",
+    "@interface",
+    "enum",
+    "trait",
+    "record",
+];
+
+/// A body entry: a statement kept as written, or a `header { ... }` block.
+#[derive(Debug)]
+enum Item<'a> {
+    Statement(&'a str),
+    Block {
+        header: &'a str,
+        body: Vec<Item<'a>>,
+        /// Byte offsets of the header start and the closing `}` (exclusive).
+        span: (usize, usize),
+    },
+}
+
+/// How a block renders, decided from its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockKind {
+    /// `plugins` / `dependencies`: keep each declaration.
+    Declarations,
+    /// Task declaration or method definition: collapse the body.
+    Collapsed,
+    /// Class-like declaration: keep the body.
+    Type,
+    /// Any other closure: open only if it contains something significant.
+    Closure,
+}
+
+/// Skim a Groovy source or Gradle build script to its declarations,
+/// returning `(content, has_errors)`.
+pub(crate) fn transform_groovy(source: &str) -> Result<(String, bool)> {
+    let (items, has_errors) = scan(source)?;
+    let mut result = String::with_capacity(source.len() / 4);
+    render(&items, 0, &mut result);
+    Ok((result, has_errors))
+}
+
+/// Types, methods and constructors declared in a Groovy source, for summary
+/// mode.
+pub(crate) fn symbols(source: &str) -> Result<Vec<Symbol>> {
+    let (items, _) = scan(source)?;
+    let newlines: Vec<usize> = source.match_indices('\n').map(|(i, _)| i).collect();
+    let mut out = Vec::new();
+    collect_symbols(source, &newlines, &items, None, 0, &mut out);
+    Ok(out)
+}
+
+fn scan(source: &str) -> Result<(Vec<Item<'_>>, bool)> {
+    let mut scanner = Scanner {
+        source,
+        bytes: source.as_bytes(),
+        pos: 0,
+        items: 0,
+        has_errors: false,
+    };
+    let items = scanner.body(0)?;
+    Ok((items, scanner.has_errors))
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    /// Current byte offset. Non-ASCII characters are always stepped over
+    /// whole, so slicing at it is char-safe.
+    pos: usize,
+    items: usize,
+    has_errors: bool,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    fn starts_with(&self, pattern: &str) -> bool {
+        self.source[self.pos..].starts_with(pattern)
+    }
+
+    /// Step over one whole character.
+    fn advance_char(&mut self) {
+        self.pos += self.source[self.pos..]
+            .chars()
+            .next()
+            .map_or(1, char::len_utf8);
+    }
+
+    /// Parse body items up to the `}` closing a block at `depth` (consumed),
+    /// or end of input at the top level.
+    fn body(&mut self, depth: usize) -> Result<Vec<Item<'a>>> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => {
+                    self.has_errors |= depth > 0; // Unclosed block
+                    return Ok(items);
+                }
+                Some(b'}') => {
+                    self.pos += 1;
+                    if depth > 0 {
+                        return Ok(items);
+                    }
+                    self.has_errors = true; // Stray closing brace
+                }
+                Some(_) => {
+                    self.items += 1;
+                    if self.items > MAX_GROOVY_ITEMS {
+                        return Err(SkimError::ComplexityLimit {
+                            what: "Groovy items",
+                            count: self.items,
+                            max: MAX_GROOVY_ITEMS,
+                        });
+                    }
+                    if let Some(item) = self.statement(depth)? {
+                        items.push(item);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parse one statement: up to a newline or `;` outside brackets, the `}`
+    /// closing the enclosing block (left in place), or a block body.
+    fn statement(&mut self, depth: usize) -> Result<Option<Item<'a>>> {
+        let start = self.pos;
+        // End of the last significant token, so trailing comments and
+        // whitespace are not part of the statement text.
+        let mut end = self.pos;
+        let mut brackets = 0usize;
+        while let Some(b) = self.peek() {
+            match b {
+                b'\n' if brackets == 0 => {
+                    if !self.continues_after_newline(&self.source[start..end]) {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                b';' if brackets == 0 => {
+                    self.pos += 1;
+                    break;
+                }
+                b'}' if brackets == 0 => break,
+                b'{' if brackets == 0 => {
+                    let header = self.source[start..end].trim();
+                    self.pos += 1;
+                    if depth + 1 > MAX_GROOVY_DEPTH {
+                        return Err(SkimError::ComplexityLimit {
+                            what: "Groovy nesting depth",
+                            count: depth + 1,
+                            max: MAX_GROOVY_DEPTH,
+                        });
+                    }
+                    let body = self.body(depth + 1)?;
+                    return Ok(Some(Item::Block {
+                        header,
+                        body,
+                        span: (start, self.pos),
+                    }));
+                }
+                b'/' if self.peek_at(1) == Some(b'/') => self.skip_to_newline(),
+                b'/' if self.peek_at(1) == Some(b'*') => self.skip_block_comment(),
+                b'\'' | b'"' => {
+                    self.skip_string(depth)?;
+                    end = self.pos;
+                }
+                b'(' | b'[' | b'{' => {
+                    brackets += 1;
+                    self.pos += 1;
+                    end = self.pos;
+                }
+                b')' | b']' | b'}' => {
+                    brackets = brackets.saturating_sub(1);
+                    self.pos += 1;
+                    end = self.pos;
+                }
+                _ if b.is_ascii_whitespace() => self.pos += 1,
+                _ => {
+                    self.advance_char();
+                    end = self.pos;
+                }
+            }
+        }
+        self.has_errors |= brackets > 0; // Unclosed bracket at end of input
+        let text = self.source[start..end].trim();
+        Ok((!text.is_empty()).then_some(Item::Statement(text)))
+    }
+
+    /// Whether the statement so far continues past the newline at `pos`:
+    /// it ends in an operator or comma, or the next line starts a method
+    /// chain (`.foo`, `?.foo`) or the statement's block (`{`).
+    fn continues_after_newline(&self, text: &str) -> bool {
+        let text = text.trim_end();
+        if text.is_empty() {
+            return true;
+        }
+        if text.ends_with([',', '+', '-', '=', '&', '|', '?', ':', '.']) {
+            return true;
+        }
+        let rest = self.source[self.pos..].trim_start();
+        (rest.starts_with('.') && !rest.starts_with(".."))
+            || rest.starts_with("?.")
+            || rest.starts_with('{')
+    }
+
+    /// Skip whitespace (including newlines), `;`, and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.peek() {
+                Some(b) if b.is_ascii_whitespace() || b == b';' => self.pos += 1,
+                // Shebang line of an executable script
+                Some(b'#') if self.pos == 0 && self.peek_at(1) == Some(b'!') => {
+                    self.skip_to_newline();
+                }
+                Some(b'/') if self.peek_at(1) == Some(b'/') => self.skip_to_newline(),
+                Some(b'/') if self.peek_at(1) == Some(b'*') => self.skip_block_comment(),
+                _ => return,
+            }
+        }
+    }
+
+    /// Move to the next newline (not past it), or to end of input.
+    fn skip_to_newline(&mut self) {
+        self.pos = self.source[self.pos..]
+            .find('\n')
+            .map_or(self.bytes.len(), |i| self.pos + i);
+    }
+
+    fn skip_block_comment(&mut self) {
+        match self.source[self.pos + 2..].find("*/") {
+            Some(i) => self.pos += 2 + i + 2,
+            None => {
+                self.has_errors = true; // Unterminated comment
+                self.pos = self.bytes.len();
+            }
+        }
+    }
+
+    /// Skip a string literal starting at `'` or `"`: single-line or
+    /// triple-quoted, with `${...}` interpolation in double-quoted forms.
+    fn skip_string(&mut self, nesting: usize) -> Result<()> {
+        if nesting > MAX_GROOVY_DEPTH {
+            return Err(SkimError::ComplexityLimit {
+                what: "Groovy nesting depth",
+                count: nesting,
+                max: MAX_GROOVY_DEPTH,
+            });
+        }
+        let quote = self.bytes[self.pos];
+        let triple = self.peek_at(1) == Some(quote) && self.peek_at(2) == Some(quote);
+        let delimiter = match (quote, triple) {
+            (b'"', true) => "\"\"\"",
+            (b'"', false) => "\"",
+            (_, true) => "'''",
+            (_, false) => "'",
+        };
+        self.pos += delimiter.len();
+        while let Some(b) = self.peek() {
+            match b {
+                b'\\' => {
+                    self.pos += 1;
+                    if self.pos < self.bytes.len() {
+                        self.advance_char();
+                    }
+                }
+                _ if self.starts_with(delimiter) => {
+                    self.pos += delimiter.len();
+                    return Ok(());
+                }
+                b'$' if quote == b'"' && self.peek_at(1) == Some(b'{') => {
+                    self.pos += 2;
+                    self.skip_interpolation(nesting + 1)?;
+                }
+                b'\n' if !triple => break, // Only triple-quoted strings span lines
+                _ => self.advance_char(),
+            }
+        }
+        self.has_errors = true; // Unterminated string
+        Ok(())
+    }
+
+    /// Skip a `${...}` body up to and including its closing `}`.
+    fn skip_interpolation(&mut self, nesting: usize) -> Result<()> {
+        let mut braces = 1usize;
+        while let Some(b) = self.peek() {
+            match b {
+                b'\'' | b'"' => self.skip_string(nesting)?,
+                b'{' => {
+                    braces += 1;
+                    self.pos += 1;
+                }
+                b'}' => {
+                    braces -= 1;
+                    self.pos += 1;
+                    if braces == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => self.advance_char(),
+            }
+        }
+        self.has_errors = true; // Unterminated interpolation
+        Ok(())
+    }
+}
+
+fn block_kind(header: &str) -> BlockKind {
+    if DECLARATION_BLOCKS.contains(&header) {
+        BlockKind::Declarations
+    } else if is_task_header(header) || is_method_header(header) {
+        BlockKind::Collapsed
+    } else if is_type_header(header) {
+        BlockKind::Type
+    } else {
+        BlockKind::Closure
+    }
+}
+
+/// `task hello`, `task('hello')`, `tasks.register('hello')`, `tasks.named(...)`,
+/// `tasks.withType(Test).configureEach`, ...
+fn is_task_header(header: &str) -> bool {
+    header.starts_with("task ") || header.starts_with("task(") || header.starts_with("tasks.")
+}
+
+/// `class Foo`, `public abstract class Foo<T> extends Bar`, `enum Color`, ...
+fn is_type_header(header: &str) -> bool {
+    header
+        .split_whitespace()
+        .find(|word| (!MODIFIERS.contains(word) && !word.starts_with('@')) || *word == "@interface")
+        .is_some_and(|word| TYPE_KEYWORDS.contains(&word))
+}
+
+/// `def run()`, `static void main(String[] args)`, `Map<String, X> load(path)
+/// throws IOException`: at least a return type or `def` before the name, so
+/// calls with a trailing closure (`doLast()`, `files(x)`) are not matched.
+fn is_method_header(header: &str) -> bool {
+    let Some(open) = header.find('(') else {
+        return false;
+    };
+    let prefix = header[..open].trim();
+    if prefix.contains('=') {
+        return false;
+    }
+    let words: Vec<&str> = prefix.split_whitespace().collect();
+    let [first, .., name] = words.as_slice() else {
+        return false;
+    };
+    let name_ok = name.ends_with(['"', '\''])
+        || name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    name_ok
+        && !CONTROL_KEYWORDS.contains(first)
+        && (header.ends_with(')') || header.contains(") throws "))
+}
+
+/// The name a type header declares: `Release` in `@CompileStatic class
+/// Release<T> extends Base`.
+fn type_name(header: &str) -> Option<&str> {
+    let mut words = header.split_whitespace();
+    words.find(|word| TYPE_KEYWORDS.contains(word))?;
+    let name = words.next()?;
+    Some(name.find('<').map_or(name, |i| &name[..i]))
+}
+
+/// Name and parameter list of a method or constructor header.
+fn method_parts(header: &str) -> Option<(&str, &str)> {
+    let open = header.find('(')?;
+    let close = header.rfind(')')?;
+    let name = header[..open].split_whitespace().last()?;
+    Some((name.trim_matches(['"', '\'']), header.get(open..=close)?))
+}
+
+fn collect_symbols(
+    source: &str,
+    newlines: &[usize],
+    items: &[Item<'_>],
+    enclosing: Option<(&str, &str)>,
+    depth: usize,
+    out: &mut Vec<Symbol>,
+) {
+    let line = |offset: usize| newlines.partition_point(|&i| i < offset) + 1;
+    let qualify = |name: &str| match enclosing {
+        Some((owner, _)) => format!("{owner}.{name}"),
+        None => name.to_string(),
+    };
+    let public = |header: &str| {
+        !header
+            .split_whitespace()
+            .any(|word| word == "private" || word == "protected")
+    };
+    for item in items {
+        match item {
+            // Abstract method of an interface or trait: `void publish(Release r)`
+            Item::Statement(text) if enclosing.is_some() && is_method_header(text) => {
+                if let Some((name, parameters)) = method_parts(text) {
+                    // Statements are slices of `source`.
+                    let offset = text.as_ptr() as usize - source.as_ptr() as usize;
+                    out.push(Symbol {
+                        name: qualify(name),
+                        kind: "method_declaration",
+                        start_line: line(offset),
+                        end_line: line(offset + text.len()),
+                        depth,
+                        public: public(text),
+                        parameters: Some(parameters.to_string()),
+                    });
+                }
+            }
+            Item::Statement(_) => {}
+            Item::Block { header, body, span } => {
+                let (start_line, end_line) = (line(span.0), line(span.1));
+                if is_type_header(header) {
+                    let Some(name) = type_name(header) else {
+                        continue;
+                    };
+                    let kind = match header
+                        .split_whitespace()
+                        .find(|w| TYPE_KEYWORDS.contains(w))
+                    {
+                        Some("class" | "record") => "class_declaration",
+                        Some("enum") => "enum_declaration",
+                        _ => "interface_declaration",
+                    };
+                    let qualified = qualify(name);
+                    out.push(Symbol {
+                        name: qualified.clone(),
+                        kind,
+                        start_line,
+                        end_line,
+                        depth,
+                        public: public(header),
+                        parameters: None,
+                    });
+                    collect_symbols(
+                        source,
+                        newlines,
+                        body,
+                        Some((&qualified, name)),
+                        depth + 1,
+                        out,
+                    );
+                    continue;
+                }
+                let kind = if is_method_header(header) && !is_task_header(header) {
+                    "method_declaration"
+                } else if enclosing.is_some_and(|(_, owner)| {
+                    header.split('(').next().map(str::trim) == Some(owner)
+                }) {
+                    "constructor_declaration"
+                } else {
+                    continue;
+                };
+                if let Some((name, parameters)) = method_parts(header) {
+                    out.push(Symbol {
+                        name: qualify(name),
+                        kind,
+                        start_line,
+                        end_line,
+                        depth,
+                        public: public(header),
+                        parameters: Some(parameters.to_string()),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Whether an item keeps its enclosing closure open.
+fn is_significant(item: &Item<'_>) -> bool {
+    match item {
+        Item::Statement(text) => text.starts_with("apply ") || text.starts_with("apply("),
+        Item::Block { header, body, .. } => match block_kind(header) {
+            BlockKind::Closure => body.iter().any(is_significant),
+            _ => true,
+        },
+    }
+}
+
+fn render(items: &[Item<'_>], depth: usize, out: &mut String) {
+    for item in items {
+        match item {
+            Item::Statement(text) => push_lines(text, depth, out),
+            Item::Block { header, body, .. } => {
+                let open = match block_kind(header) {
+                    BlockKind::Collapsed => false,
+                    BlockKind::Declarations | BlockKind::Type => true,
+                    BlockKind::Closure => body.iter().any(is_significant),
+                };
+                if open && !body.is_empty() {
+                    push_open(header, depth, out);
+                    if block_kind(header) == BlockKind::Declarations {
+                        render_declarations(body, depth + 1, out);
+                    } else {
+                        render(body, depth + 1, out);
+                    }
+                    push_indent(depth, out);
+                    out.push_str("}\n");
+                } else {
+                    push_collapsed(header, body.is_empty(), depth, out);
+                }
+            }
+        }
+    }
+}
+
+/// Declarations are kept; a declaration's configuration closure collapses.
+fn render_declarations(items: &[Item<'_>], depth: usize, out: &mut String) {
+    for item in items {
+        match item {
+            Item::Statement(text) => push_lines(text, depth, out),
+            Item::Block { header, body, .. } => push_collapsed(header, body.is_empty(), depth, out),
+        }
+    }
+}
+
+fn push_collapsed(header: &str, empty: bool, depth: usize, out: &mut String) {
+    push_header(header, depth, out);
+    out.push_str(if empty { "{}\n" } else { "{...}\n" });
+}
+
+fn push_open(header: &str, depth: usize, out: &mut String) {
+    push_header(header, depth, out);
+    out.push_str("{\n");
+}
+
+/// Push a block header up to (not including) its opening brace. A bare
+/// closure literal has no header and only gets its indentation.
+fn push_header(header: &str, depth: usize, out: &mut String) {
+    if header.is_empty() {
+        push_indent(depth, out);
+        return;
+    }
+    push_lines(header, depth, out);
+    out.pop(); // Trailing newline
+    out.push(' ');
+}
+
+/// Push text, re-indenting its first line to `depth` and any continuation
+/// lines one level deeper.
+fn push_lines(text: &str, depth: usize, out: &mut String) {
+    for (i, line) in text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .enumerate()
+    {
+        push_indent(if i == 0 { depth } else { depth + 1 }, out);
+        out.push_str(line);
+        out.push('\n');
+    }
+}
+
+fn push_indent(depth: usize, out: &mut String) {
+    for _ in 0..depth {
+        out.push_str("    ");
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str) -> String {
+        transform_groovy(source).unwrap().0
+    }
+
+    #[test]
+    fn test_groovy_plugins_and_dependencies_keep_declarations() {
+        let source = "plugins {\n  id 'application'\n}\ndependencies {\n  implementation 'a:b:1'\n  \
+                      testImplementation('c:d:2') {\n    transitive = false\n  }\n}\n";
+        assert_eq!(
+            skim(source),
+            "plugins {\n    id 'application'\n}\ndependencies {\n    implementation 'a:b:1'\n    \
+             testImplementation('c:d:2') {...}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_groovy_tasks_and_methods_collapse() {
+        let source = "task hello {\n  doLast { println 'hi' }\n}\ntask('bye') {}\n\
+                      tasks.named('test') {\n  useJUnitPlatform()\n}\n\
+                      static void main(String[] args) throws IOException {\n  run()\n}\n";
+        assert_eq!(
+            skim(source),
+            "task hello {...}\ntask('bye') {}\ntasks.named('test') {...}\n\
+             static void main(String[] args) throws IOException {...}\n"
+        );
+    }
+
+    #[test]
+    fn test_groovy_closures_open_only_around_significant_items() {
+        let source = "android {\n  compileSdk 34\n}\nallprojects {\n  group = 'x'\n  \
+                      dependencies {\n    api 'a:b:1'\n  }\n}\n";
+        assert_eq!(
+            skim(source),
+            "android {...}\nallprojects {\n    group = 'x'\n    dependencies {\n        api 'a:b:1'\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_groovy_calls_with_closures_are_not_methods() {
+        assert!(!is_method_header("doLast()"));
+        assert!(!is_method_header("else if (ready)"));
+        assert!(!is_method_header("def x = foo()"));
+        assert!(is_method_header("def run()"));
+        assert!(is_method_header("Map<String, Integer> counts(List items)"));
+        assert!(is_method_header("def \"adds two numbers\"()"));
+    }
+
+    #[test]
+    fn test_groovy_types_keep_members() {
+        let source = "@CompileStatic\npublic abstract class Greeter<T> extends Base {\n  \
+                      private String name\n  static {\n    init()\n  }\n  \
+                      String greet(T who) {\n    \"hi $who\"\n  }\n}\n";
+        assert_eq!(
+            skim(source),
+            "@CompileStatic\npublic abstract class Greeter<T> extends Base {\n    private String name\n    \
+             static {...}\n    String greet(T who) {...}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_groovy_strings_and_comments_do_not_open_blocks() {
+        let source = "def s = \"${ [a: 1].collect { it } } }\" // { not a block\n\
+                      def t = '''multi\n{ line\n'''\n/* } */\nversion = 'ü{'\n";
+        assert_eq!(
+            skim(source),
+            "def s = \"${ [a: 1].collect { it } } }\"\ndef t = '''multi\n    { line\n    '''\nversion = 'ü{'\n"
+        );
+    }
+
+    #[test]
+    fn test_groovy_multiline_statements_join() {
+        let source = "def files = fileTree('src')\n    .matching { include '**/*.groovy' }\n\
+                      def list = [\n  'a',\n  'b'\n]\n";
+        assert_eq!(
+            skim(source),
+            "def files = fileTree('src')\n    .matching {...}\ndef list = [\n    'a',\n    'b'\n    ]\n"
+        );
+    }
+
+    #[test]
+    fn test_groovy_unclosed_block_and_stray_brace_are_errors() {
+        let (result, has_errors) = transform_groovy("plugins {\n  id 'java'\n").unwrap();
+        assert_eq!(result, "plugins {\n    id 'java'\n}\n");
+        assert!(has_errors);
+
+        let (result, has_errors) = transform_groovy("a = 1\n}\nb = 2\n").unwrap();
+        assert_eq!(result, "a = 1\nb = 2\n");
+        assert!(has_errors);
+
+        let (_, has_errors) = transform_groovy("version = 'oops\n").unwrap();
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_groovy_symbols_name_types_methods_and_constructors() {
+        let source = "task hello {\n}\ndef run() {\n}\nclass Greeter<T> {\n  Greeter(T who) {\n  }\n  \
+                      private String greet() {\n  }\n}\ninterface Named {\n  String name()\n}\n";
+        let found: Vec<_> = symbols(source)
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.name, s.kind, s.start_line, s.end_line, s.public))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("run".to_string(), "method_declaration", 3, 4, true),
+                ("Greeter".to_string(), "class_declaration", 5, 10, true),
+                (
+                    "Greeter.Greeter".to_string(),
+                    "constructor_declaration",
+                    6,
+                    7,
+                    true
+                ),
+                (
+                    "Greeter.greet".to_string(),
+                    "method_declaration",
+                    8,
+                    9,
+                    false
+                ),
+                ("Named".to_string(), "interface_declaration", 11, 13, true),
+                ("Named.name".to_string(), "method_declaration", 12, 12, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_groovy_depth_cap_is_complexity_limit() {
+        let source = "a {\n".repeat(MAX_GROOVY_DEPTH + 1);
+        let err = transform_groovy(&source).err().unwrap();
+        assert!(err.is_complexity_limit(), "got: {err}");
+    }
+}
@@ -0,0 +1,459 @@
+//! HCL (Terraform) structure extraction
+//!
+//! ARCHITECTURE: HCL is scanned by hand rather than through a grammar. The
+//! output only needs block types, labels, and attribute names, so the scanner
+//! tracks just enough syntax (strings with `${...}` interpolation, heredocs,
+//! brackets, comments) to find where each attribute's value ends.
+//!
+//! # Output Format (Structure / Signatures / Types)
+//!
+//! ```text
+//! terraform {
+//!   required_version
+//! }
+//! resource "aws_instance" "web" {
+//!   ami
+//!   tags
+//!   ebs_block_device {
+//!     device_name
+//!   }
+//! }
+//! ```
+//!
+//! # Rules
+//! - Block types and labels are kept verbatim; empty blocks render as `{}`
+//! - Attributes keep only their key; the value is dropped however many lines
+//!   it spans (objects, lists, heredocs, multi-line function calls)
+//! - Comments (`#`, `//`, `/* */`) are dropped
+//! - Output is re-indented two spaces per level
+//! - Unclosed blocks, strings, or heredocs and unrecognised lines set
+//!   `has_errors`; the scanner skips the offending line and keeps going
+
+use crate::{Result, SkimError};
+
+/// Maximum block (and string interpolation) nesting depth
+///
+/// SECURITY: Matches MAX_JSON_DEPTH / MAX_YAML_DEPTH; bounds recursion.
+const MAX_HCL_DEPTH: usize = 500;
+
+/// Maximum number of blocks and attributes per file
+///
+/// SECURITY: Bounds memory use on adversarial input. Exceeding the cap
+/// degrades to passthrough like every other complexity limit.
+const MAX_HCL_ITEMS: usize = 100_000;
+
+/// A body entry: an attribute key or a nested block.
+#[derive(Debug)]
+enum Item<'a> {
+    Attribute(&'a str),
+    Block {
+        kind: &'a str,
+        /// Labels as written (`"aws_instance"`, or a bare identifier).
+        labels: Vec<&'a str>,
+        body: Vec<Item<'a>>,
+    },
+}
+
+/// Skim an HCL file to its block and attribute-key hierarchy, returning
+/// `(content, has_errors)`.
+pub(crate) fn transform_hcl(source: &str) -> Result<(String, bool)> {
+    let mut scanner = Scanner {
+        source,
+        bytes: source.as_bytes(),
+        pos: 0,
+        items: 0,
+        has_errors: false,
+    };
+    let items = scanner.body(0)?;
+    let mut result = String::with_capacity(source.len() / 4);
+    render(&items, 0, &mut result);
+    Ok((result, scanner.has_errors))
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    /// Current byte offset. Only ever moved onto ASCII delimiters or past
+    /// whole identifiers, so slicing at it is char-safe.
+    pos: usize,
+    items: usize,
+    has_errors: bool,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    /// Parse body items up to the `}` closing a block at `depth` (consumed),
+    /// or end of input at the top level.
+    fn body(&mut self, depth: usize) -> Result<Vec<Item<'a>>> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => {
+                    self.has_errors |= depth > 0; // Unclosed block
+                    return Ok(items);
+                }
+                Some(b'}') => {
+                    self.pos += 1;
+                    if depth > 0 {
+                        return Ok(items);
+                    }
+                    self.has_errors = true; // Stray closing brace
+                }
+                Some(b) if is_ident_start(b) => {
+                    self.items += 1;
+                    if self.items > MAX_HCL_ITEMS {
+                        return Err(SkimError::ComplexityLimit {
+                            what: "HCL items",
+                            count: self.items,
+                            max: MAX_HCL_ITEMS,
+                        });
+                    }
+                    if let Some(item) = self.item(depth)? {
+                        items.push(item);
+                    }
+                }
+                Some(_) => {
+                    self.has_errors = true;
+                    self.skip_line();
+                }
+            }
+        }
+    }
+
+    /// Parse an attribute or block starting at an identifier.
+    fn item(&mut self, depth: usize) -> Result<Option<Item<'a>>> {
+        let name = self.ident();
+        self.skip_inline_space();
+        if self.peek() == Some(b'=') && self.peek_at(1) != Some(b'=') {
+            self.pos += 1;
+            self.skip_expression(0)?;
+            return Ok(Some(Item::Attribute(name)));
+        }
+
+        let mut labels = Vec::new();
+        loop {
+            self.skip_inline_space();
+            match self.peek() {
+                Some(b'{') => break,
+                Some(b'"') => {
+                    let start = self.pos;
+                    self.skip_string(0)?;
+                    labels.push(&self.source[start..self.pos]);
+                }
+                Some(b) if is_ident_start(b) => labels.push(self.ident()),
+                _ => {
+                    self.has_errors = true;
+                    self.skip_line();
+                    return Ok(None);
+                }
+            }
+        }
+
+        self.pos += 1; // `{`
+        if depth + 1 > MAX_HCL_DEPTH {
+            return Err(SkimError::ComplexityLimit {
+                what: "HCL nesting depth",
+                count: depth + 1,
+                max: MAX_HCL_DEPTH,
+            });
+        }
+        let body = self.body(depth + 1)?;
+        Ok(Some(Item::Block {
+            kind: name,
+            labels,
+            body,
+        }))
+    }
+
+    fn ident(&mut self) -> &'a str {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|b| is_ident_start(b) || b.is_ascii_digit() || b == b'-')
+        {
+            self.pos += 1;
+        }
+        &self.source[start..self.pos]
+    }
+
+    /// Skip whitespace (including newlines) and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.peek() {
+                Some(b) if b.is_ascii_whitespace() => self.pos += 1,
+                Some(b'#') => self.skip_line(),
+                Some(b'/') if self.peek_at(1) == Some(b'/') => self.skip_line(),
+                Some(b'/') if self.peek_at(1) == Some(b'*') => self.skip_block_comment(),
+                _ => return,
+            }
+        }
+    }
+
+    fn skip_inline_space(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Move past the next newline, or to end of input.
+    fn skip_line(&mut self) {
+        self.pos = self.source[self.pos..]
+            .find('\n')
+            .map_or(self.bytes.len(), |i| self.pos + i + 1);
+    }
+
+    fn skip_block_comment(&mut self) {
+        match self.source[self.pos + 2..].find("*/") {
+            Some(i) => self.pos += 2 + i + 2,
+            None => {
+                self.has_errors = true;
+                self.pos = self.bytes.len();
+            }
+        }
+    }
+
+    /// Skip an attribute value: up to a newline outside brackets, or an
+    /// unmatched closing bracket (left in place for the enclosing body).
+    fn skip_expression(&mut self, nesting: usize) -> Result<()> {
+        let mut brackets = 0usize;
+        while let Some(b) = self.peek() {
+            match b {
+                b'\n' if brackets == 0 => return Ok(()),
+                b'"' => self.skip_string(nesting)?,
+                b'<' if self.peek_at(1) == Some(b'<') => self.skip_heredoc(),
+                b'#' => self.skip_to_newline(),
+                b'/' if self.peek_at(1) == Some(b'/') => self.skip_to_newline(),
+                b'/' if self.peek_at(1) == Some(b'*') => self.skip_block_comment(),
+                b'(' | b'[' | b'{' => {
+                    brackets += 1;
+                    self.pos += 1;
+                }
+                b')' | b']' | b'}' => {
+                    if brackets == 0 {
+                        return Ok(());
+                    }
+                    brackets -= 1;
+                    self.pos += 1;
+                }
+                _ => self.pos += 1,
+            }
+        }
+        Ok(())
+    }
+
+    /// Move to the next newline (not past it), or to end of input.
+    fn skip_to_newline(&mut self) {
+        self.pos = self.source[self.pos..]
+            .find('\n')
+            .map_or(self.bytes.len(), |i| self.pos + i);
+    }
+
+    /// Skip a quoted string starting at `"`, including `${...}` and `%{...}`
+    /// template sequences, which may themselves contain strings.
+    fn skip_string(&mut self, nesting: usize) -> Result<()> {
+        if nesting > MAX_HCL_DEPTH {
+            return Err(SkimError::ComplexityLimit {
+                what: "HCL nesting depth",
+                count: nesting,
+                max: MAX_HCL_DEPTH,
+            });
+        }
+        self.pos += 1; // Opening quote
+        while let Some(b) = self.peek() {
+            match b {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                // `$${` and `%%{` are escaped literals.
+                b'$' | b'%' if self.peek_at(1) == Some(b) && self.peek_at(2) == Some(b'{') => {
+                    self.pos += 3;
+                }
+                b'$' | b'%' if self.peek_at(1) == Some(b'{') => {
+                    self.pos += 2;
+                    self.skip_template(nesting + 1)?;
+                }
+                b'\n' => break, // Strings cannot span lines
+                _ => self.pos += 1,
+            }
+        }
+        self.has_errors = true; // Unterminated string
+        self.pos = self.pos.min(self.bytes.len());
+        Ok(())
+    }
+
+    /// Skip a template sequence body up to and including its closing `}`.
+    fn skip_template(&mut self, nesting: usize) -> Result<()> {
+        let mut braces = 1usize;
+        while let Some(b) = self.peek() {
+            match b {
+                b'"' => self.skip_string(nesting)?,
+                b'{' => {
+                    braces += 1;
+                    self.pos += 1;
+                }
+                b'}' => {
+                    braces -= 1;
+                    self.pos += 1;
+                    if braces == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => self.pos += 1,
+            }
+        }
+        self.has_errors = true; // Unterminated template sequence
+        Ok(())
+    }
+
+    /// Skip a heredoc (`<<EOF` / `<<-EOF`) through its closing marker line.
+    /// A `<<` not followed by a marker is skipped as an operator.
+    fn skip_heredoc(&mut self) {
+        self.pos += 2;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let marker = self.ident();
+        if marker.is_empty() {
+            return;
+        }
+        self.skip_line();
+        while self.pos < self.bytes.len() {
+            let line_end = self.source[self.pos..]
+                .find('\n')
+                .map_or(self.bytes.len(), |i| self.pos + i);
+            let line = &self.source[self.pos..line_end];
+            self.pos = line_end;
+            if line.trim() == marker {
+                return;
+            }
+            self.pos = (line_end + 1).min(self.bytes.len());
+        }
+        self.has_errors = true; // Unterminated heredoc
+    }
+}
+
+/// Identifier start byte. Non-ASCII bytes count, so Unicode identifiers are
+/// consumed whole and slicing stays on char boundaries.
+fn is_ident_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || !b.is_ascii()
+}
+
+fn render(items: &[Item<'_>], depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    for item in items {
+        out.push_str(&indent);
+        match item {
+            Item::Attribute(key) => out.push_str(key),
+            Item::Block { kind, labels, body } => {
+                out.push_str(kind);
+                for label in labels {
+                    out.push(' ');
+                    out.push_str(label);
+                }
+                if body.is_empty() {
+                    out.push_str(" {}");
+                } else {
+                    out.push_str(" {\n");
+                    render(body, depth + 1, out);
+                    out.push_str(&indent);
+                    out.push('}');
+                }
+            }
+        }
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str) -> String {
+        transform_hcl(source).unwrap().0
+    }
+
+    #[test]
+    fn test_hcl_keeps_blocks_labels_and_keys() {
+        let source = r#"# Main instance
+resource "aws_instance" "web" {
+  ami           = "ami-123456"
+  instance_type = var.instance_type // inline comment
+
+  tags = {
+    Name = "web-${var.env}"
+  }
+
+  ebs_block_device {
+    device_name = "/dev/sdg"
+  }
+}
+"#;
+        let (result, has_errors) = transform_hcl(source).unwrap();
+        assert_eq!(
+            result,
+            "resource \"aws_instance\" \"web\" {\n  ami\n  instance_type\n  tags\n  \
+             ebs_block_device {\n    device_name\n  }\n}\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_hcl_multiline_values_are_dropped() {
+        let source = "locals {\n  ids = [\n    \"a\",\n    \"b\",\n  ]\n  merged = merge(\n    local.a,\n    { x = 1 },\n  )\n  after = 1\n}\n";
+        assert_eq!(skim(source), "locals {\n  ids\n  merged\n  after\n}\n");
+    }
+
+    #[test]
+    fn test_hcl_heredoc_and_interpolated_strings() {
+        let source = "resource \"x\" \"y\" {\n  policy = <<-EOT\n    { \"a\": \"}\" }\n    bogus = 1\n  EOT\n  name = \"${lookup(var.m, \"k}\", \"d\")}-x\"\n}\n";
+        let (result, has_errors) = transform_hcl(source).unwrap();
+        assert_eq!(result, "resource \"x\" \"y\" {\n  policy\n  name\n}\n");
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_hcl_empty_and_one_line_blocks() {
+        let source = "provider \"aws\" {}\nlifecycle { prevent_destroy = true }\n/* block\ncomment */\nterraform { backend \"s3\" {} }\n";
+        assert_eq!(
+            skim(source),
+            "provider \"aws\" {}\nlifecycle {\n  prevent_destroy\n}\nterraform {\n  backend \"s3\" {}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_hcl_tfvars_attributes_only() {
+        assert_eq!(
+            skim("region = \"us-east-1\"\ncount  = 3\n"),
+            "region\ncount\n"
+        );
+    }
+
+    #[test]
+    fn test_hcl_unclosed_block_and_stray_brace_are_errors() {
+        let (result, has_errors) = transform_hcl("module \"m\" {\n  source = \"./m\"\n").unwrap();
+        assert_eq!(result, "module \"m\" {\n  source\n}\n");
+        assert!(has_errors);
+
+        let (result, has_errors) = transform_hcl("a = 1\n}\nb = 2\n").unwrap();
+        assert_eq!(result, "a\nb\n");
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_hcl_depth_cap_is_complexity_limit() {
+        let source = "a {\n".repeat(MAX_HCL_DEPTH + 1);
+        let err = transform_hcl(&source).err().unwrap();
+        assert!(err.is_complexity_limit(), "got: {err}");
+    }
+}
@@ -0,0 +1,190 @@
+//! HTML outline extraction
+//!
+//! ARCHITECTURE: HTML shares the hand-written element scanner in `xml` (under
+//! [`Dialect::Html`], which knows about void elements, optional end tags, and
+//! raw-text `<script>`/`<style>`). This module only decides what of the tree
+//! to print: a DOM skeleton an LLM can navigate.
+//!
+//! # Output Format (Structure / Signatures / Types)
+//!
+//! ```text
+//! <html>
+//!   <head>
+//!     <title>Dashboard</title>
+//!   </head>
+//!   <body class="app">
+//!     <nav id="top">
+//!       <a>...</a>
+//!     </nav>
+//!     <h1 id="summary">Weekly summary</h1>
+//!     <p>...</p>
+//!     <img>
+//!   </body>
+//! </html>
+//! ```
+//!
+//! # Rules
+//! - The tag hierarchy is kept; only `id` and `class` attributes are printed
+//! - Heading (`h1`-`h6`) and `<title>` text is kept, whitespace-normalised
+//! - Other text collapses to `...`; elements holding only text stay on one line
+//! - `<script>` and `<style>` elements are dropped with their contents, as are
+//!   comments and `<!DOCTYPE>`
+//! - `<textarea>` and `<title>` contents are text even when they look like tags
+//! - Void elements (`<img>`, `<br>`, ...) print without a closing tag
+//! - Output is re-indented two spaces per level
+
+use super::xml::{self, Dialect, Element, HTML_TEXT_ELEMENTS, HTML_VOID_ELEMENTS};
+use crate::Result;
+
+/// Attributes printed in the outline, in this order.
+const KEPT_ATTRIBUTES: &[&str] = &["id", "class"];
+
+/// Skim an HTML document to its DOM skeleton, returning `(content, has_errors)`.
+pub(crate) fn transform_html(source: &str) -> Result<(String, bool)> {
+    let (roots, has_errors) = xml::parse(source, Dialect::Html)?;
+    let mut result = String::with_capacity(source.len() / 4);
+    for root in &roots {
+        render(root, 0, &mut result);
+    }
+    Ok((result, has_errors))
+}
+
+/// Render one element and its descendants at `depth`.
+fn render(element: &Element<'_>, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&indent);
+    out.push('<');
+    out.push_str(element.name);
+    for kept in KEPT_ATTRIBUTES {
+        let value = element
+            .attrs
+            .iter()
+            .find(|a| a.name.eq_ignore_ascii_case(kept))
+            .and_then(|a| a.value);
+        if let Some(value) = value {
+            out.push(' ');
+            out.push_str(kept);
+            out.push_str("=\"");
+            out.push_str(&normalize_whitespace(value));
+            out.push('"');
+        }
+    }
+    out.push('>');
+
+    if element.is_any(HTML_VOID_ELEMENTS, Dialect::Html) {
+        out.push('\n');
+        return;
+    }
+
+    if element.is_any(HTML_TEXT_ELEMENTS, Dialect::Html) {
+        out.push_str(&normalize_whitespace(&element.text));
+    } else if element.children.is_empty() {
+        if element.has_text {
+            out.push_str("...");
+        }
+    } else {
+        out.push('\n');
+        for child in &element.children {
+            render(child, depth + 1, out);
+        }
+        out.push_str(&indent);
+    }
+    out.push_str("</");
+    out.push_str(element.name);
+    out.push_str(">\n");
+}
+
+/// Collapse runs of whitespace to single spaces and trim the ends.
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str) -> String {
+        transform_html(source).unwrap().0
+    }
+
+    #[test]
+    fn test_html_outline_keeps_ids_classes_and_headings() {
+        let source = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <title>  Weekly
+    report </title>
+  <style>.card { color: red; }</style>
+</head>
+<body class="app  dark" data-x="1">
+  <h1 id="summary">Weekly <em>summary</em></h1>
+  <p>Lots of prose here.</p>
+  <script>document.write("<div>fake</div>");</script>
+</body>
+</html>
+"#;
+        assert_eq!(
+            skim(source),
+            "<html>\n  <head>\n    <title>Weekly report</title>\n  </head>\n  \
+             <body class=\"app dark\">\n    <h1 id=\"summary\">Weekly summary</h1>\n    \
+             <p>...</p>\n  </body>\n</html>\n"
+        );
+    }
+
+    #[test]
+    fn test_html_void_elements_do_not_nest() {
+        let result = skim("<div><img src=\"a.png\"><br><span>x</span></div>");
+        assert_eq!(
+            result,
+            "<div>\n  <img>\n  <br>\n  <span>...</span>\n</div>\n"
+        );
+    }
+
+    #[test]
+    fn test_html_optional_end_tags_close_implicitly() {
+        let (result, has_errors) = transform_html("<ul><li>one<li>two</ul><p>a<p>b").unwrap();
+        assert_eq!(
+            result,
+            "<ul>\n  <li>...</li>\n  <li>...</li>\n</ul>\n<p>...</p>\n<p>...</p>\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_html_tag_names_are_case_insensitive() {
+        let (result, has_errors) = transform_html("<DIV ID=\"x\"><P>a</p></Div>").unwrap();
+        assert_eq!(result, "<DIV id=\"x\">\n  <P>...</P>\n</DIV>\n");
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_html_unquoted_and_bare_attributes() {
+        let result = skim("<input disabled class=big id=name>");
+        assert_eq!(result, "<input id=\"name\" class=\"big\">\n");
+    }
+
+    #[test]
+    fn test_html_script_with_markup_is_skipped_whole() {
+        let result =
+            skim("<body><SCRIPT>if (a < b) { x = '</div>'; }</script><main></main></body>");
+        assert_eq!(result, "<body>\n  <main></main>\n</body>\n");
+    }
+
+    #[test]
+    fn test_html_textarea_markup_is_text() {
+        let result =
+            skim("<form><textarea>a <b>bold</b> note</textarea><button>Go</button></form>");
+        assert_eq!(
+            result,
+            "<form>\n  <textarea>...</textarea>\n  <button>...</button>\n</form>\n"
+        );
+    }
+
+    #[test]
+    fn test_html_unterminated_comment_is_an_error() {
+        let (result, has_errors) = transform_html("<main></main>\n<!-- <footer></footer>").unwrap();
+        assert_eq!(result, "<main></main>\n");
+        assert!(has_errors);
+    }
+}
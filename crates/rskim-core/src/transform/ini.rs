@@ -0,0 +1,211 @@
+//! INI/conf key structure
+//!
+//! ARCHITECTURE: INI dialects disagree on too much (delimiters, comment
+//! characters, continuation lines) for a strict parser, so this is a line
+//! scanner. Like the JSON/YAML/TOML transforms it keeps the key structure and
+//! strips every value.
+//!
+//! # Output Format (Structure / Signatures / Types)
+//!
+//! ```text
+//! debug
+//! [server]
+//!   host
+//!   port
+//! [database]
+//!   url
+//!   pool_size
+//! ```
+//!
+//! # Rules
+//! - `[section]` headers are kept; keys inside a section are indented
+//! - A key ends at the first `=`, `:` or whitespace, so `key value` lines
+//!   (`redis.conf`, `ssh_config`) work; flag-style lines (`skip-name-resolve`)
+//!   are keys too
+//! - Values, comments (`;` or `#`), blank lines and continuation lines
+//!   (indented deeper than their key) are dropped
+//! - Lines indented under a `key value` line (`Host` blocks in `ssh_config`)
+//!   are keys nested one level under it
+//! - Unterminated `[section` headers and lines without a usable key (such as
+//!   the braces of block-structured `.conf` files) set `has_errors`
+
+use crate::{Result, SkimError};
+
+/// Maximum number of keys and sections
+///
+/// SECURITY: Matches MAX_TOML_KEYS / MAX_JSON_KEYS to bound output size on
+/// adversarial input. Exceeding the cap degrades to passthrough like every
+/// other complexity limit.
+const MAX_INI_KEYS: usize = 10_000;
+
+/// Summarize an INI/conf file as its sections and key names, returning
+/// `(content, has_errors)`.
+pub(crate) fn transform_ini(source: &str) -> Result<(String, bool)> {
+    let mut result = String::new();
+    let mut has_errors = false;
+    let mut in_section = false;
+    // Indentation of the previous key line, while the previous entry is a key.
+    let mut key_indent: Option<usize> = None;
+    // Indentation of the `key value` line whose indented block we are in.
+    let mut block_indent: Option<usize> = None;
+    let mut count = 0usize;
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with([';', '#']) {
+            continue;
+        }
+
+        // Lines indented deeper than the key above them continue its value;
+        // keys indented alike (tab-indented, as in `.gitconfig`) are keys.
+        let indent = line.len() - line.trim_start().len();
+        if block_indent.is_some_and(|block_indent| indent <= block_indent) {
+            block_indent = None;
+        }
+        if key_indent.is_some_and(|key_indent| indent > key_indent) {
+            continue;
+        }
+
+        count += 1;
+        if count > MAX_INI_KEYS {
+            return Err(SkimError::ComplexityLimit {
+                what: "INI keys",
+                count,
+                max: MAX_INI_KEYS,
+            });
+        }
+
+        if trimmed.starts_with('[') {
+            match section_header(trimmed) {
+                Some(header) => {
+                    result.push_str(header);
+                    result.push('\n');
+                    in_section = true;
+                }
+                None => has_errors = true,
+            }
+            key_indent = None;
+            block_indent = None;
+            continue;
+        }
+
+        let Some((key, opens_block)) = key_name(trimmed) else {
+            has_errors = true;
+            key_indent = None;
+            continue;
+        };
+        if in_section {
+            result.push_str("  ");
+        }
+        if block_indent.is_some() {
+            result.push_str("  ");
+        } else if opens_block {
+            // `Host bastion`: deeper lines are its options, not a value.
+            block_indent = Some(indent);
+            result.push_str(key);
+            result.push('\n');
+            key_indent = None;
+            continue;
+        }
+        result.push_str(key);
+        result.push('\n');
+        key_indent = Some(indent);
+    }
+
+    Ok((result, has_errors))
+}
+
+/// `[section]` with any trailing comment removed, or `None` when the
+/// bracket is not closed.
+fn section_header(line: &str) -> Option<&str> {
+    let end = line.find(']')?;
+    Some(&line[..=end])
+}
+
+/// The key of an assignment line and whether it is a `key value` line, or
+/// `None` when it has no alphanumeric characters (stray braces, punctuation).
+fn key_name(line: &str) -> Option<(&str, bool)> {
+    let end = line
+        .find(|c: char| c == '=' || c == ':' || c.is_whitespace())
+        .unwrap_or(line.len());
+    let key = &line[..end];
+    let rest = line[end..].trim_start();
+    let space_separated = !rest.is_empty() && !rest.starts_with(['=', ':']);
+    key.chars()
+        .any(char::is_alphanumeric)
+        .then_some((key, space_separated))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str) -> String {
+        transform_ini(source).unwrap().0
+    }
+
+    #[test]
+    fn test_ini_keeps_sections_and_keys() {
+        let source = "; global\ndebug = true\n\n[server]\nhost = 0.0.0.0\nport: 8080\n\n\
+                      [database] ; primary\nurl=postgres://db/app\n";
+        let (result, has_errors) = transform_ini(source).unwrap();
+        assert_eq!(
+            result,
+            "debug\n[server]\n  host\n  port\n[database]\n  url\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_continuation_lines_are_dropped() {
+        let source = "[options]\ninstall_requires =\n    requests>=2\n    click\npython_requires = >=3.9\n\
+                      [options.entry_points]\nconsole_scripts =\n    app = app.cli:main\n";
+        assert_eq!(
+            skim(source),
+            "[options]\n  install_requires\n  python_requires\n\
+             [options.entry_points]\n  console_scripts\n"
+        );
+    }
+
+    #[test]
+    fn test_indented_assignments_are_keys() {
+        let source = "[user]\n\tname = Ada\n\temail = ada@example.com\n";
+        assert_eq!(skim(source), "[user]\n  name\n  email\n");
+    }
+
+    #[test]
+    fn test_space_separated_and_flag_lines() {
+        let source = "# redis.conf\nport 6379\nappendonly yes\n[mysqld]\nskip-name-resolve\n";
+        assert_eq!(
+            skim(source),
+            "port\nappendonly\n[mysqld]\n  skip-name-resolve\n"
+        );
+    }
+
+    #[test]
+    fn test_indented_lines_under_key_value_lines_are_nested_keys() {
+        let source = "Host bastion\n    HostName bastion.example.com\n    User deploy\n\n\
+                      Host *\n    ServerAliveInterval 60\nbind 127.0.0.1 ::1\n";
+        let (result, has_errors) = transform_ini(source).unwrap();
+        assert_eq!(
+            result,
+            "Host\n  HostName\n  User\nHost\n  ServerAliveInterval\nbind\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_block_braces_and_open_headers_set_has_errors() {
+        let (result, has_errors) = transform_ini("events {\n}\n[broken\nkey=1\n").unwrap();
+        assert_eq!(result, "events\nkey\n");
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_too_many_keys_is_complexity_limit() {
+        let source = "k=1\n".repeat(MAX_INI_KEYS + 1);
+        let err = transform_ini(&source).unwrap_err();
+        assert!(err.is_complexity_limit());
+    }
+}
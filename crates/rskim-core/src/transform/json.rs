@@ -29,6 +29,8 @@
 //! - Empty arrays/objects → just show key name
 //! - Nested arrays → just show key name
 //! - Mixed types in arrays → just show key name
+//! - OpenAPI/Swagger specs → paths, methods and schema names instead (see `openapi`)
+//! - `package.json` manifests → dependency, script and bin names instead (see `manifest`)
 
 use crate::{Result, SkimError};
 use serde_json::Value;
@@ -52,6 +54,13 @@ pub(crate) fn transform_json(source: &str) -> Result<String> {
     let value: Value = serde_json::from_str(source)
         .map_err(|e| SkimError::ParseError(format!("Invalid JSON: {}", e)))?;
 
+    if let Some(summary) = super::openapi::summarize(&value)? {
+        return Ok(summary);
+    }
+    if let Some(summary) = super::manifest::summarize_package_json(&value)? {
+        return Ok(summary);
+    }
+
     // Extract structure with integrated depth and key validation (single pass)
     let mut key_count = 0;
     let structure = extract_structure(&value, 0, &mut key_count)?;
@@ -0,0 +1,313 @@
+//! Package manifest summary (`package.json`, `Cargo.toml`)
+//!
+//! ARCHITECTURE: A manifest answers two questions at a glance — what does
+//! this project depend on, and what commands does it define. The generic
+//! key-only view answers them poorly: every `package.json` key survives
+//! (including whole `jest` or `eslintConfig` bodies), and every inline
+//! dependency table in a `Cargo.toml` expands into its `version`/`features`
+//! keys. When the parsed document is recognized as a manifest, the JSON and
+//! TOML transforms emit this summary instead:
+//!
+//! ```text
+//! package: web-app 1.4.0
+//! scripts:
+//!   build
+//!   test
+//! dependencies:
+//!   react
+//!   zod
+//! devDependencies:
+//!   vitest
+//! ```
+//!
+//! # Rules
+//! - `package.json`: an object with a `name` or `version` and at least one
+//!   `scripts`/dependency table whose values are all strings (which rules out
+//!   lockfiles). Kept: name and version, `scripts`, `bin` and the four
+//!   dependency tables, in source order
+//! - `Cargo.toml`: a `[workspace]` table, or a `[package]` table next to a
+//!   dependency, `[target.*]`, `[[bin]]` or `[features]` table. Kept: name
+//!   and version, `[[bin]]` targets, `[features]`, workspace members and every
+//!   dependency table (including `[target.*]` and `[workspace.dependencies]`),
+//!   by name only. TOML tables are read back sorted, so names are alphabetical
+//! - Versions, version requirements and script bodies are dropped
+//!
+//! Detection is by content, like the OpenAPI summary: the core never sees
+//! file names.
+
+use crate::{Result, SkimError};
+
+/// Maximum number of names in a summary
+///
+/// SECURITY: Matches MAX_JSON_KEYS / MAX_TOML_KEYS to bound output size;
+/// exceeding it falls back to passthrough like every other complexity limit.
+const MAX_MANIFEST_ENTRIES: usize = 10_000;
+
+/// `package.json` tables that are kept, by name.
+const PACKAGE_JSON_TABLES: &[&str] = &[
+    "scripts",
+    "bin",
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// `Cargo.toml` dependency tables, under the root, a `[target.*]` table or
+/// `[workspace]`.
+const CARGO_DEPENDENCY_TABLES: &[&str] =
+    &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Output under construction, counting names against [`MAX_MANIFEST_ENTRIES`].
+#[derive(Default)]
+struct Summary {
+    out: String,
+    entries: usize,
+}
+
+impl Summary {
+    /// `heading:` followed by one indented line per name; nothing when there
+    /// are no names.
+    fn section<'a>(
+        &mut self,
+        heading: &str,
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<()> {
+        let mut names = names.into_iter().peekable();
+        if names.peek().is_none() {
+            return Ok(());
+        }
+        self.out.push_str(heading);
+        self.out.push_str(":\n");
+        for name in names {
+            self.entries += 1;
+            if self.entries > MAX_MANIFEST_ENTRIES {
+                return Err(SkimError::ComplexityLimit {
+                    what: "manifest entries",
+                    count: self.entries,
+                    max: MAX_MANIFEST_ENTRIES,
+                });
+            }
+            self.out.push_str("  ");
+            self.out.push_str(name);
+            self.out.push('\n');
+        }
+        Ok(())
+    }
+
+    /// `label: name version` with whichever of the two are present.
+    fn package_line(&mut self, label: &str, name: Option<&str>, version: Option<&str>) {
+        let parts: Vec<&str> = [name, version].into_iter().flatten().collect();
+        if !parts.is_empty() {
+            self.out.push_str(label);
+            self.out.push_str(": ");
+            self.out.push_str(&parts.join(" "));
+            self.out.push('\n');
+        }
+    }
+}
+
+/// Summarize `document` if it is a `package.json`.
+///
+/// Returns `Ok(None)` for any other document.
+pub(crate) fn summarize_package_json(document: &serde_json::Value) -> Result<Option<String>> {
+    let Some(root) = document.as_object() else {
+        return Ok(None);
+    };
+    let name = root.get("name").and_then(serde_json::Value::as_str);
+    let version = root.get("version").and_then(serde_json::Value::as_str);
+    let has_string_table = PACKAGE_JSON_TABLES
+        .iter()
+        .filter_map(|key| root.get(*key)?.as_object())
+        .any(|table| !table.is_empty() && table.values().all(serde_json::Value::is_string));
+    if (name.is_none() && version.is_none()) || !has_string_table {
+        return Ok(None);
+    }
+
+    let mut summary = Summary::default();
+    summary.package_line("package", name, version);
+    for (key, value) in root {
+        if !PACKAGE_JSON_TABLES.contains(&key.as_str()) {
+            continue;
+        }
+        match value {
+            serde_json::Value::Object(table) => {
+                summary.section(key, table.keys().map(String::as_str))?;
+            }
+            // `"bin": "cli.js"` installs one command named after the package.
+            serde_json::Value::String(_) if key == "bin" => {
+                summary.section(key, name)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(Some(summary.out))
+}
+
+/// Summarize `document` if it is a `Cargo.toml`.
+///
+/// Returns `Ok(None)` for any other document.
+pub(crate) fn summarize_cargo_toml(document: &toml::Value) -> Result<Option<String>> {
+    let package = document.get("package").and_then(toml::Value::as_table);
+    let workspace = document.get("workspace").and_then(toml::Value::as_table);
+    let has_cargo_table = CARGO_DEPENDENCY_TABLES
+        .iter()
+        .chain(&["target", "bin", "features"])
+        .any(|key| document.get(*key).is_some());
+    if workspace.is_none() && !(package.is_some() && has_cargo_table) {
+        return Ok(None);
+    }
+
+    let mut summary = Summary::default();
+    if let Some(package) = package {
+        summary.package_line(
+            "package",
+            package.get("name").and_then(toml::Value::as_str),
+            package.get("version").and_then(toml::Value::as_str),
+        );
+    }
+    if let Some(workspace) = workspace {
+        let members = workspace
+            .get("members")
+            .and_then(toml::Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(toml::Value::as_str);
+        summary.section("workspace.members", members)?;
+    }
+
+    let bins = document
+        .get("bin")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|bin| bin.get("name")?.as_str());
+    summary.section("bin", bins)?;
+    summary.section("features", table_keys(document.get("features")))?;
+
+    for table in CARGO_DEPENDENCY_TABLES {
+        summary.section(table, table_keys(document.get(*table)))?;
+    }
+    if let Some(targets) = document.get("target").and_then(toml::Value::as_table) {
+        for (target, tables) in targets {
+            for table in CARGO_DEPENDENCY_TABLES {
+                summary.section(
+                    &format!("target.{target}.{table}"),
+                    table_keys(tables.get(*table)),
+                )?;
+            }
+        }
+    }
+    if let Some(workspace) = workspace {
+        summary.section(
+            "workspace.dependencies",
+            table_keys(workspace.get("dependencies")),
+        )?;
+    }
+    Ok(Some(summary.out))
+}
+
+/// Keys of `value` if it is a table.
+fn table_keys(value: Option<&toml::Value>) -> impl Iterator<Item = &str> {
+    value
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flat_map(|table| table.keys().map(String::as_str))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn package_json(source: &str) -> Option<String> {
+        summarize_package_json(&serde_json::from_str(source).unwrap()).unwrap()
+    }
+
+    fn cargo_toml(source: &str) -> Option<String> {
+        summarize_cargo_toml(&source.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_package_json_keeps_names_in_source_order() {
+        let source = r#"{
+            "name": "web-app",
+            "version": "1.4.0",
+            "description": "dropped",
+            "dependencies": {"react": "^18.2.0", "zod": "3.x"},
+            "scripts": {"build": "vite build", "test": "vitest run"},
+            "jest": {"testEnvironment": "node"},
+            "bin": "cli.js"
+        }"#;
+        assert_eq!(
+            package_json(source).unwrap(),
+            "package: web-app 1.4.0\ndependencies:\n  react\n  zod\n\
+             scripts:\n  build\n  test\nbin:\n  web-app\n"
+        );
+    }
+
+    #[test]
+    fn test_non_manifests_are_not_summarized() {
+        // No dependency or script tables.
+        assert_eq!(package_json(r#"{"name": "x", "private": true}"#), None);
+        // Lockfile: dependency values are objects.
+        assert_eq!(
+            package_json(
+                r#"{"name": "x", "lockfileVersion": 1, "dependencies": {"a": {"version": "1.0.0"}}}"#
+            ),
+            None
+        );
+        // No name or version.
+        assert_eq!(package_json(r#"{"dependencies": {"a": "1"}}"#), None);
+        assert_eq!(cargo_toml("[server]\nport = 8080\n"), None);
+        // A `[package]` table alone is not enough.
+        assert_eq!(
+            cargo_toml("[package]\nname = \"x\"\n\n[server]\nport = 8080\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cargo_toml_keeps_dependency_feature_and_bin_names() {
+        let source = r#"
+[package]
+name = "skim"
+version = "2.10.0"
+
+[[bin]]
+name = "skim"
+path = "src/main.rs"
+
+[features]
+default = ["cli"]
+cli = []
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+anyhow = "1"
+
+[dev-dependencies]
+tempfile = "3"
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+"#;
+        assert_eq!(
+            cargo_toml(source).unwrap(),
+            "package: skim 2.10.0\nbin:\n  skim\nfeatures:\n  cli\n  default\n\
+             dependencies:\n  anyhow\n  serde\ndev-dependencies:\n  tempfile\n\
+             target.cfg(unix).dependencies:\n  libc\n"
+        );
+    }
+
+    #[test]
+    fn test_cargo_workspace_root() {
+        let source = "[workspace]\nmembers = [\"crates/core\", \"crates/cli\"]\n\n\
+                      [workspace.dependencies]\ntoml = \"0.8\"\n";
+        assert_eq!(
+            cargo_toml(source).unwrap(),
+            "workspace.members:\n  crates/core\n  crates/cli\n\
+             workspace.dependencies:\n  toml\n"
+        );
+    }
+}
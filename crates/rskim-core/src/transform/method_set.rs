@@ -0,0 +1,140 @@
+//! Go interface synthesis from struct method sets
+//!
+//! ARCHITECTURE: Go types are satisfied implicitly, so a struct's behavior is
+//! scattered across top-level `func (s *Server) ...` declarations that types
+//! mode otherwise drops. For each struct with exported methods, types mode
+//! emits a synthesized interface listing them, right after the struct:
+//!
+//! ```text
+//! // skim: exported method set of Server
+//! type ServerMethods interface {
+//!     Start(ctx context.Context) error
+//!     Stop() error
+//! }
+//! ```
+//!
+//! The interface covers the method set of `*Server` (pointer and value
+//! receivers). It is not in the source, so its lines carry no source line
+//! number and it is the first thing dropped by `--max-lines` truncation.
+
+use crate::transform::visibility::PrivateFilter;
+use tree_sitter::Node;
+
+/// Node kind recorded for synthesized interfaces. Unknown to the priority
+/// table, so it scores lowest.
+pub(crate) const METHOD_SET_KIND: &str = "method_set";
+
+/// A synthesized interface for one struct.
+pub(crate) struct MethodSet {
+    /// 1-indexed start line of the `type` declaration holding the struct.
+    pub(crate) declaration_line: usize,
+    /// Interface text, including the leading marker comment.
+    pub(crate) text: String,
+}
+
+/// Synthesize an interface for every struct in `root` that has exported methods.
+pub(crate) fn go_method_sets(
+    root: Node,
+    source: &str,
+    filter: Option<&PrivateFilter>,
+) -> Vec<MethodSet> {
+    let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("");
+    let visible = |node: Node| !filter.is_some_and(|f| f.hides(node));
+
+    let mut structs: Vec<(&str, usize)> = Vec::new();
+    let mut methods: Vec<(&str, &str)> = Vec::new();
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        if !visible(child) {
+            continue;
+        }
+        match child.kind() {
+            "type_declaration" => {
+                let line = child.start_position().row + 1;
+                let mut spec_cursor = child.walk();
+                for spec in child.named_children(&mut spec_cursor) {
+                    let is_struct = spec.kind() == "type_spec"
+                        && spec
+                            .child_by_field_name("type")
+                            .is_some_and(|ty| ty.kind() == "struct_type");
+                    if is_struct
+                        && visible(spec)
+                        && let Some(name) = spec.child_by_field_name("name")
+                    {
+                        structs.push((text(name), line));
+                    }
+                }
+            }
+            "method_declaration" => {
+                if let Some(method) = exported_method(child, source) {
+                    methods.push(method);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    structs
+        .into_iter()
+        .filter_map(|(name, declaration_line)| {
+            let signatures: Vec<&str> = methods
+                .iter()
+                .filter(|(receiver, _)| *receiver == name)
+                .map(|(_, signature)| *signature)
+                .collect();
+            if signatures.is_empty() {
+                return None;
+            }
+            let mut text =
+                format!("// skim: exported method set of {name}\ntype {name}Methods interface {{");
+            for signature in signatures {
+                text.push_str("\n\t");
+                text.push_str(signature);
+            }
+            text.push_str("\n}");
+            Some(MethodSet {
+                declaration_line,
+                text,
+            })
+        })
+        .collect()
+}
+
+/// `(receiver type name, interface method line)` for an exported method.
+///
+/// The interface line is the method's source text from its name up to the
+/// body: `Start(ctx context.Context) error`.
+fn exported_method<'src>(method: Node, source: &'src str) -> Option<(&'src str, &'src str)> {
+    let name = method.child_by_field_name("name")?;
+    let name_text = name.utf8_text(source.as_bytes()).ok()?;
+    if !name_text.starts_with(|c: char| c.is_uppercase()) {
+        return None;
+    }
+    let receiver = receiver_type_name(method.child_by_field_name("receiver")?, source)?;
+    let end = method
+        .child_by_field_name("body")
+        .map_or(method.end_byte(), |body| body.start_byte());
+    let signature = source.get(name.start_byte()..end)?.trim();
+    Some((receiver, signature))
+}
+
+/// Base type name of a receiver list: `Server` for `(s *Server)`,
+/// `(Server)` and `(s *Cache[K, V])`.
+fn receiver_type_name<'src>(receiver: Node, source: &'src str) -> Option<&'src str> {
+    let mut cursor = receiver.walk();
+    let parameter = receiver
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "parameter_declaration")?;
+    let mut ty = parameter.child_by_field_name("type")?;
+    loop {
+        match ty.kind() {
+            "type_identifier" => return ty.utf8_text(source.as_bytes()).ok(),
+            "pointer_type" | "generic_type" | "parenthesized_type" => {
+                ty = ty
+                    .child_by_field_name("type")
+                    .or_else(|| ty.named_child(0))?;
+            }
+            _ => return None,
+        }
+    }
+}
@@ -5,7 +5,7 @@
 //!
 //! Token reduction target: 15-30%
 
-use crate::transform::utils::is_inside_function_body;
+use crate::transform::utils::{align_to_char_boundaries, is_inside_function_body};
 use crate::{Language, Result, SkimError, TransformConfig};
 use tree_sitter::{Node, Tree};
 
@@ -120,6 +120,7 @@ fn is_shebang(node: Node, source: &str) -> bool {
 pub(crate) fn is_comment_node(kind: &str, language: Language) -> bool {
     match language {
         Language::TypeScript
+        | Language::Tsx
         | Language::JavaScript
         | Language::Python
         | Language::Go
@@ -127,13 +128,35 @@ pub(crate) fn is_comment_node(kind: &str, language: Language) -> bool {
         | Language::Cpp
         | Language::CSharp
         | Language::Ruby
-        | Language::Sql => kind == "comment",
+        | Language::Sql
+        | Language::Shell
+        | Language::Elixir
+        | Language::Solidity
+        | Language::ObjectiveC => kind == "comment",
         Language::Rust | Language::Java | Language::Kotlin => {
             kind == "line_comment" || kind == "block_comment"
         }
         Language::Swift => kind == "comment" || kind == "multiline_comment",
-        // Markdown, JSON, YAML, TOML don't have comment nodes to strip
-        Language::Markdown | Language::Json | Language::Yaml | Language::Toml => false,
+        Language::Julia => kind == "line_comment" || kind == "block_comment",
+        Language::Ocaml | Language::OcamlInterface => kind == "comment",
+        Language::Dart => kind == "comment" || kind == "documentation_comment",
+        // Markdown, JSON, YAML, TOML and scanner-based formats don't have comment nodes to strip
+        Language::Markdown
+        | Language::Json
+        | Language::Yaml
+        | Language::Toml
+        | Language::Vue
+        | Language::Svelte
+        | Language::Xml
+        | Language::Html
+        | Language::Hcl
+        | Language::Dockerfile
+        | Language::Groovy
+        | Language::Csv
+        | Language::Env
+        | Language::Ini
+        | Language::Rst
+        | Language::AsciiDoc => false,
     }
 }
 
@@ -149,7 +172,7 @@ pub(crate) fn is_removable_comment(node: Node, source: &str, language: Language)
         return false;
     }
     let should_preserve = is_shebang(node, source)
-        || is_inside_function_body(node, language)
+        || is_inside_function_body(node, source, language)
         || is_doc_comment(node, source, language);
     !should_preserve
 }
@@ -158,14 +181,14 @@ pub(crate) fn is_removable_comment(node: Node, source: &str, language: Language)
 ///
 /// Language-specific doc comment detection. See match arms below for
 /// per-language rules covering all supported tree-sitter languages.
-fn is_doc_comment(node: Node, source: &str, language: Language) -> bool {
+pub(crate) fn is_doc_comment(node: Node, source: &str, language: Language) -> bool {
     let text = match node.utf8_text(source.as_bytes()) {
         Ok(t) => t,
         Err(_) => return false,
     };
 
     match language {
-        Language::TypeScript | Language::JavaScript => {
+        Language::TypeScript | Language::Tsx | Language::JavaScript => {
             // JSDoc comments start with /**
             text.starts_with("/**")
         }
@@ -190,7 +213,7 @@ fn is_doc_comment(node: Node, source: &str, language: Language) -> bool {
             // Javadoc comments start with /**
             text.starts_with("/**")
         }
-        Language::C | Language::Cpp => {
+        Language::C | Language::Cpp | Language::ObjectiveC => {
             // Doxygen comments: /** or ///
             text.starts_with("/**") || text.starts_with("///")
         }
@@ -215,8 +238,47 @@ fn is_doc_comment(node: Node, source: &str, language: Language) -> bool {
             // SQL `--` comments have no doc comment convention
             false
         }
-        // Markdown, JSON, YAML, TOML don't reach here
-        Language::Markdown | Language::Json | Language::Yaml | Language::Toml => false,
+        Language::Shell => {
+            // Shell `#` comments have no doc comment convention
+            false
+        }
+        Language::Elixir => {
+            // Elixir docs are `@doc`/`@moduledoc` attributes, not comments
+            false
+        }
+        Language::Dart => {
+            // Dart doc comments: /// or /** */ (dartdoc)
+            text.starts_with("///") || text.starts_with("/**")
+        }
+        Language::Solidity => {
+            // Solidity NatSpec comments: /// or /** */
+            text.starts_with("///") || text.starts_with("/**")
+        }
+        Language::Julia => {
+            // Julia docstrings are string literals, not comments
+            false
+        }
+        Language::Ocaml | Language::OcamlInterface => {
+            // ocamldoc comments: (** *), but not (*** *) banners
+            text.starts_with("(**") && !text.starts_with("(***")
+        }
+        // Markdown, JSON, YAML, TOML and scanner-based formats don't reach here
+        Language::Markdown
+        | Language::Json
+        | Language::Yaml
+        | Language::Toml
+        | Language::Vue
+        | Language::Svelte
+        | Language::Xml
+        | Language::Html
+        | Language::Hcl
+        | Language::Dockerfile
+        | Language::Groovy
+        | Language::Csv
+        | Language::Env
+        | Language::Ini
+        | Language::Rst
+        | Language::AsciiDoc => false,
     }
 }
 
@@ -343,30 +405,19 @@ pub(crate) fn remove_ranges(source: &str, ranges: &[(usize, usize)]) -> Result<S
             )));
         }
 
+        // Re-align to UTF-8 boundaries rather than failing the whole file
+        let (start, end) = align_to_char_boundaries(source, start, end);
+
         // Skip overlapping ranges, extending the removal window if needed
         if start < last_pos {
             last_pos = last_pos.max(end);
             continue;
         }
 
-        if !source.is_char_boundary(start) || !source.is_char_boundary(end) {
-            return Err(SkimError::ParseError(format!(
-                "Invalid UTF-8 boundary at range [{}, {})",
-                start, end
-            )));
-        }
-
         result.push_str(&source[last_pos..start]);
         last_pos = end;
     }
 
-    if !source.is_char_boundary(last_pos) {
-        return Err(SkimError::ParseError(format!(
-            "Invalid UTF-8 boundary at position {}",
-            last_pos
-        )));
-    }
-
     result.push_str(&source[last_pos..]);
 
     Ok(result)
@@ -522,19 +573,13 @@ mod tests {
     }
 
     #[test]
-    fn test_remove_ranges_non_char_boundary() {
+    fn test_remove_ranges_non_char_boundary_realigns() {
         // Multi-byte UTF-8 character: the euro sign takes 3 bytes
         let source = "a\u{20AC}b"; // "a" + euro sign (3 bytes) + "b" = 5 bytes total
-        // Byte 2 is in the middle of the euro sign (bytes 1..4)
+        // Byte 2 is in the middle of the euro sign (bytes 1..4); the range
+        // widens to the whole character instead of failing the file
         let ranges = vec![(2, 4)];
-        let result = remove_ranges(source, &ranges);
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(
-            err_msg.contains("Invalid UTF-8 boundary"),
-            "Expected 'Invalid UTF-8 boundary' error, got: {}",
-            err_msg
-        );
+        assert_eq!(remove_ranges(source, &ranges).unwrap(), "ab");
     }
 
     #[test]
@@ -3,16 +3,48 @@
 //! ARCHITECTURE: This module operates on tree-sitter Trees.
 //! Each mode has its own transformation strategy.
 //! JSON, YAML, and TOML are handled separately without tree-sitter (serde-based).
-
+//! Vue and Svelte SFCs are scanned for `<script>` blocks (see `sfc`), and XML
+//! and HTML by a dedicated element scanner (see `xml` and `html`).
+
+pub(crate) mod arena;
+pub(crate) mod asciidoc;
+pub(crate) mod call_graph;
+pub(crate) mod comments;
+pub(crate) mod constants;
+pub(crate) mod csv;
+pub(crate) mod dockerfile;
+pub(crate) mod docs;
+pub(crate) mod env;
+pub(crate) mod errors;
+pub(crate) mod explain;
+pub(crate) mod groovy;
+pub(crate) mod hcl;
+pub(crate) mod html;
+pub(crate) mod ini;
 pub(crate) mod json;
+pub(crate) mod manifest;
+pub(crate) mod method_set;
 pub(crate) mod minimal;
+pub(crate) mod openapi;
+pub(crate) mod outline;
 pub(crate) mod pseudo;
+pub(crate) mod public_api;
+pub(crate) mod pyi;
+pub(crate) mod query;
+pub(crate) mod rst;
+pub(crate) mod script;
+pub(crate) mod sfc;
 pub(crate) mod signatures;
 pub(crate) mod structure;
+pub(crate) mod summary;
+pub(crate) mod todos;
 pub(crate) mod toml;
 pub(crate) mod truncate;
 pub(crate) mod types;
 pub(crate) mod utils;
+pub(crate) mod visibility;
+pub(crate) mod wrap;
+pub(crate) mod xml;
 pub(crate) mod yaml;
 
 use crate::{Language, Mode, Result, TransformConfig};
@@ -71,11 +103,27 @@ fn transform_tree_with_spans(
         Mode::Structure => {
             structure::transform_structure_with_spans(source, tree, language, config)
         }
-        Mode::Signatures => {
+        // Docs mode is signatures mode with doc comments attached.
+        Mode::Signatures | Mode::Docs => {
             signatures::transform_signatures_with_spans(source, tree, language, config)
         }
+        Mode::PublicApi => {
+            public_api::transform_public_api_with_spans(source, tree, language, config)
+        }
         Mode::Types => types::transform_types_with_spans(source, tree, language, config),
         Mode::Pseudo => pseudo::transform_pseudo_with_spans(source, tree, language, config),
+        Mode::Comments => comments::transform_comments_with_spans(source, tree, language, config),
+        Mode::Outline => outline::transform_outline_with_spans(source, tree, language, config),
+        Mode::CallGraph => {
+            call_graph::transform_call_graph_with_spans(source, tree, language, config)
+        }
+        Mode::Todos => todos::transform_todos_with_spans(source, tree, language, config),
+        Mode::Summary => summary::transform_summary_with_spans(source, tree, language, config),
+        Mode::Constants => {
+            constants::transform_constants_with_spans(source, tree, language, config)
+        }
+        Mode::Errors => errors::transform_errors_with_spans(source, tree, language, config),
+        Mode::Query => query::transform_query_with_spans(source, tree, language, config),
         // ARCHITECTURE: Full and Minimal produce a single "source_file" span
         // inline (no _with_spans variant needed since there is no AST ranking).
         Mode::Full => {
@@ -124,12 +172,39 @@ pub(crate) fn transform_tree_with_line_map(
         Mode::Structure => {
             structure::transform_structure_with_spans_and_line_map(source, tree, language, config)?
         }
-        Mode::Signatures => signatures::transform_signatures_with_spans_and_line_map(
+        Mode::Signatures | Mode::Docs => signatures::transform_signatures_with_spans_and_line_map(
+            source, tree, language, config,
+        )?,
+        Mode::PublicApi => public_api::transform_public_api_with_spans_and_line_map(
             source, tree, language, config,
         )?,
         Mode::Types => {
             types::transform_types_with_spans_and_line_map(source, tree, language, config)?
         }
+        Mode::Comments => {
+            comments::transform_comments_with_spans_and_line_map(source, tree, language, config)?
+        }
+        Mode::Outline => {
+            outline::transform_outline_with_spans_and_line_map(source, tree, language, config)?
+        }
+        Mode::CallGraph => call_graph::transform_call_graph_with_spans_and_line_map(
+            source, tree, language, config,
+        )?,
+        Mode::Todos => {
+            todos::transform_todos_with_spans_and_line_map(source, tree, language, config)?
+        }
+        Mode::Summary => {
+            summary::transform_summary_with_spans_and_line_map(source, tree, language, config)?
+        }
+        Mode::Constants => {
+            constants::transform_constants_with_spans_and_line_map(source, tree, language, config)?
+        }
+        Mode::Errors => {
+            errors::transform_errors_with_spans_and_line_map(source, tree, language, config)?
+        }
+        Mode::Query => {
+            query::transform_query_with_spans_and_line_map(source, tree, language, config)?
+        }
         Mode::Full => {
             // Full mode: identity map
             let text = source.to_string();
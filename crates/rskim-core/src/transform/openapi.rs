@@ -0,0 +1,244 @@
+//! OpenAPI / Swagger summary
+//!
+//! ARCHITECTURE: API specs are JSON or YAML, but the generic key-only view
+//! throws away exactly what a reader wants from them: every path key is kept
+//! while the HTTP methods and `operationId`s under it become anonymous nested
+//! keys. When the parsed document is detected as a spec (a top-level
+//! `openapi` or `swagger` key), the JSON and YAML transforms emit this summary
+//! instead:
+//!
+//! ```text
+//! openapi: 3.0.3
+//! info: Swagger Petstore 1.0.0
+//! paths:
+//!   /pets:
+//!     GET listPets
+//!     POST createPets
+//!   /pets/{petId}:
+//!     GET showPetById
+//! schemas:
+//!   Pet
+//!   Error
+//! ```
+//!
+//! # Rules
+//! - Paths and operations keep source order; non-operation path-item keys
+//!   (`parameters`, `summary`, `$ref`, ...) are dropped
+//! - An operation without an `operationId` is shown as its method alone
+//! - Schema names come from `components.schemas` (OpenAPI 3) or
+//!   `definitions` (Swagger 2), under the same heading as in the source
+//!
+//! The summary works over both parsers' value types through [`SpecNode`], so
+//! neither document has to be converted into the other's representation.
+
+use crate::{Result, SkimError};
+
+/// Maximum number of paths, operations and schema names in a summary
+///
+/// SECURITY: Matches MAX_JSON_KEYS to bound output size; exceeding it falls
+/// back to passthrough like every other complexity limit.
+const MAX_OPENAPI_ENTRIES: usize = 10_000;
+
+/// Path-item keys that are operations.
+const HTTP_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Read access to a parsed JSON or YAML document.
+pub(crate) trait SpecNode: Sized {
+    /// The value under string key `key`, if this is a mapping.
+    fn get(&self, key: &str) -> Option<&Self>;
+    /// The entries of a mapping with string keys, in source order; empty for
+    /// anything else.
+    fn entries(&self) -> Vec<(&str, &Self)>;
+    /// The text of a string or number.
+    fn scalar(&self) -> Option<String>;
+}
+
+impl SpecNode for serde_json::Value {
+    fn get(&self, key: &str) -> Option<&Self> {
+        self.as_object()?.get(key)
+    }
+
+    fn entries(&self) -> Vec<(&str, &Self)> {
+        self.as_object()
+            .map(|map| map.iter().map(|(k, v)| (k.as_str(), v)).collect())
+            .unwrap_or_default()
+    }
+
+    fn scalar(&self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s.clone()),
+            Self::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl SpecNode for serde_yaml_ng::Value {
+    fn get(&self, key: &str) -> Option<&Self> {
+        self.as_mapping()?.get(key)
+    }
+
+    fn entries(&self) -> Vec<(&str, &Self)> {
+        self.as_mapping()
+            .map(|map| {
+                map.iter()
+                    .filter_map(|(k, v)| Some((k.as_str()?, v)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn scalar(&self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s.clone()),
+            Self::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Summarize `document` if it is an OpenAPI or Swagger spec.
+///
+/// Returns `Ok(None)` for any other document, which then gets the generic
+/// key-only view.
+pub(crate) fn summarize<N: SpecNode>(document: &N) -> Result<Option<String>> {
+    let Some((version_key, version)) = ["openapi", "swagger"]
+        .iter()
+        .find_map(|key| Some((*key, document.get(key)?.scalar()?)))
+    else {
+        return Ok(None);
+    };
+
+    let mut out = format!("{version_key}: {version}\n");
+    if let Some(info) = document.get("info") {
+        let title_version: Vec<String> = ["title", "version"]
+            .iter()
+            .filter_map(|key| info.get(key)?.scalar())
+            .collect();
+        if !title_version.is_empty() {
+            out.push_str("info: ");
+            out.push_str(&title_version.join(" "));
+            out.push('\n');
+        }
+    }
+
+    let mut count = 0usize;
+    let mut push_entry = |out: &mut String, indent: &str, text: &str| -> Result<()> {
+        count += 1;
+        if count > MAX_OPENAPI_ENTRIES {
+            return Err(SkimError::ComplexityLimit {
+                what: "OpenAPI entries",
+                count,
+                max: MAX_OPENAPI_ENTRIES,
+            });
+        }
+        out.push_str(indent);
+        out.push_str(text);
+        out.push('\n');
+        Ok(())
+    };
+
+    let paths = document.get("paths").map(SpecNode::entries);
+    if let Some(paths) = paths.filter(|paths| !paths.is_empty()) {
+        out.push_str("paths:\n");
+        for (path, item) in paths {
+            push_entry(&mut out, "  ", &format!("{path}:"))?;
+            for (method, operation) in item.entries() {
+                if !HTTP_METHODS.contains(&method) {
+                    continue;
+                }
+                let mut line = method.to_ascii_uppercase();
+                if let Some(id) = operation.get("operationId").and_then(SpecNode::scalar) {
+                    line.push(' ');
+                    line.push_str(&id);
+                }
+                push_entry(&mut out, "    ", &line)?;
+            }
+        }
+    }
+
+    let schemas = match document.get("components").and_then(|c| c.get("schemas")) {
+        Some(schemas) => Some(("schemas", schemas)),
+        None => document.get("definitions").map(|d| ("definitions", d)),
+    };
+    if let Some((heading, schemas)) = schemas {
+        let names = schemas.entries();
+        if !names.is_empty() {
+            out.push_str(heading);
+            out.push_str(":\n");
+            for (name, _) in names {
+                push_entry(&mut out, "  ", name)?;
+            }
+        }
+    }
+
+    Ok(Some(out))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn json(source: &str) -> Option<String> {
+        let value: serde_json::Value = serde_json::from_str(source).unwrap();
+        summarize(&value).unwrap()
+    }
+
+    fn yaml(source: &str) -> Option<String> {
+        let value: serde_yaml_ng::Value = serde_yaml_ng::from_str(source).unwrap();
+        summarize(&value).unwrap()
+    }
+
+    #[test]
+    fn test_non_spec_documents_are_not_summarized() {
+        assert_eq!(json(r#"{"name": "app", "paths": {"/a": {}}}"#), None);
+        assert_eq!(yaml("openapi:\n  nested: true\n"), None);
+        assert_eq!(json("[1, 2]"), None);
+    }
+
+    #[test]
+    fn test_openapi3_paths_operations_and_schemas() {
+        let source = r#"{
+            "openapi": "3.0.3",
+            "info": {"title": "Petstore", "version": "1.0.0"},
+            "paths": {
+                "/pets": {
+                    "parameters": [],
+                    "get": {"operationId": "listPets"},
+                    "post": {"summary": "no id"}
+                }
+            },
+            "components": {"schemas": {"Pet": {}, "Error": {}}}
+        }"#;
+        assert_eq!(
+            json(source).unwrap(),
+            "openapi: 3.0.3\ninfo: Petstore 1.0.0\npaths:\n  /pets:\n    GET listPets\n    POST\n\
+             schemas:\n  Pet\n  Error\n"
+        );
+    }
+
+    #[test]
+    fn test_swagger2_yaml_uses_definitions() {
+        let source = "swagger: 2.0\ninfo:\n  title: Legacy\npaths:\n  /users/{id}:\n    \
+                      delete:\n      operationId: deleteUser\n      responses:\n        204:\n          \
+                      description: gone\ndefinitions:\n  User:\n    type: object\n";
+        assert_eq!(
+            yaml(source).unwrap(),
+            "swagger: 2.0\ninfo: Legacy\npaths:\n  /users/{id}:\n    DELETE deleteUser\n\
+             definitions:\n  User\n"
+        );
+    }
+
+    #[test]
+    fn test_too_many_entries_is_complexity_limit() {
+        let mut paths = serde_json::Map::new();
+        for i in 0..=MAX_OPENAPI_ENTRIES {
+            paths.insert(format!("/r{i}"), serde_json::json!({}));
+        }
+        let document = serde_json::json!({"openapi": "3.1.0", "paths": paths});
+        assert!(summarize(&document).unwrap_err().is_complexity_limit());
+    }
+}
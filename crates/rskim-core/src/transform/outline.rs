@@ -0,0 +1,271 @@
+//! Outline mode - indented symbol tree with source line numbers
+//!
+//! ARCHITECTURE: Every other mode drops location information: an agent that
+//! skims a file in structure mode and then wants one body back has to search
+//! for it. Outline mode lists the file's declarations (see [`crate::symbols`])
+//! one per line, prefixed with their 1-indexed start line and indented two
+//! spaces per nesting level:
+//!
+//! ```text
+//! 1: pub struct Config
+//! 6: impl Config
+//!   7: pub fn new(...)
+//!   12: fn validate()
+//! 20: pub fn process_file(...)
+//! ```
+//!
+//! Each entry is the declaration's head as written — everything up to and
+//! including its name — followed by `(...)` for callables with parameters or
+//! `()` for callables without. Return types, generics and bodies are dropped.
+//! Markdown documents list their headings instead.
+
+use super::truncate::NodeSpan;
+use crate::symbols::{Symbol, symbols_in_tree};
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::{Language, Result, SkimError, TransformConfig};
+use tree_sitter::{Node, Tree};
+
+/// How many lines past a symbol's start are searched for its name, for
+/// declarations that open with attributes or annotations on their own lines.
+const MAX_HEAD_LINES: usize = 8;
+
+/// Transform to outline mode, returning text and per-line spans.
+pub(crate) fn transform_outline_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_outline_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to outline mode, also mapping each output line to the source
+/// line it anchors.
+pub(crate) fn transform_outline_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let entries = if language == Language::Markdown {
+        markdown_headings(source, tree)?
+    } else {
+        let lines: Vec<&str> = source.lines().collect();
+        symbols_in_tree(source, tree, language)?
+            .into_iter()
+            .filter(|symbol| symbol.public || !config.public_only)
+            .map(|symbol| Entry {
+                head: declaration_head(&symbol, &lines),
+                line: symbol.start_line,
+                depth: symbol.depth,
+                kind: symbol.kind,
+            })
+            .collect()
+    };
+
+    let mut text = String::new();
+    let mut spans = Vec::with_capacity(entries.len());
+    let mut line_map = Vec::with_capacity(entries.len());
+    for (index, entry) in entries.into_iter().enumerate() {
+        text.push_str(&"  ".repeat(entry.depth));
+        text.push_str(&format!("{}: {}\n", entry.line, entry.head));
+        spans.push(NodeSpan::new(index..index + 1, entry.kind));
+        line_map.push(entry.line);
+    }
+    Ok((text, spans, line_map))
+}
+
+/// Add `offset` to the line number of every line of `outline`, for outlines
+/// of a fragment embedded `offset` lines into a larger file.
+pub(crate) fn shift_line_numbers(outline: &str, offset: usize) -> String {
+    let mut out = String::with_capacity(outline.len());
+    for line in outline.lines() {
+        let body = line.trim_start();
+        let indent = &line[..line.len() - body.len()];
+        match body.split_once(": ") {
+            Some((number, head)) if let Ok(number) = number.parse::<usize>() => {
+                out.push_str(&format!("{indent}{}: {head}\n", number + offset));
+            }
+            _ => {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+/// One outline line before rendering.
+struct Entry {
+    head: String,
+    line: usize,
+    depth: usize,
+    kind: &'static str,
+}
+
+/// The declaration text up to its name, plus a parameter marker.
+///
+/// Falls back to the bare name when the name is not found near the start
+/// (e.g. a declaration assembled by a macro).
+fn declaration_head(symbol: &Symbol, lines: &[&str]) -> String {
+    let name = symbol.name.rsplit('.').next().unwrap_or(&symbol.name);
+    let last = symbol
+        .end_line
+        .min(symbol.start_line + MAX_HEAD_LINES)
+        .min(lines.len());
+    let head = lines
+        .get(symbol.start_line.saturating_sub(1)..last)
+        .unwrap_or_default()
+        .iter()
+        .find_map(|line| find_word(line, name).map(|at| line[..at + name.len()].trim()))
+        .unwrap_or(name);
+
+    let params = match symbol.parameters.as_deref() {
+        Some(params) if params.trim_matches(['(', ')']).trim().is_empty() => "()",
+        Some(_) => "(...)",
+        None => "",
+    };
+    format!("{head}{params}")
+}
+
+/// Byte offset of the first occurrence of `word` in `line` that is not part
+/// of a longer identifier.
+fn find_word(line: &str, word: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    line.match_indices(word).map(|(at, _)| at).find(|&at| {
+        !line[..at].chars().next_back().is_some_and(is_ident)
+            && !line[at + word.len()..].chars().next().is_some_and(is_ident)
+    })
+}
+
+/// Markdown headings as outline entries, indented by level.
+fn markdown_headings(source: &str, tree: &Tree) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    collect_headings(tree.root_node(), source, &mut entries, 0)?;
+    Ok(entries)
+}
+
+fn collect_headings(
+    node: Node,
+    source: &str,
+    entries: &mut Vec<Entry>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested or malicious input
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum markdown depth exceeded: {} (possible malicious input)",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    let kind = node.kind();
+    if matches!(kind, "atx_heading" | "setext_heading") {
+        let text = node
+            .utf8_text(source.as_bytes())
+            .map_err(|e| SkimError::ParseError(format!("UTF-8 error in header: {}", e)))?;
+        let title = text.lines().next().unwrap_or_default().trim();
+        let (level, head) = if kind == "atx_heading" {
+            let level = title.chars().take_while(|&c| c == '#').count();
+            (level, title.to_string())
+        } else {
+            let mut cursor = node.walk();
+            let level = if node
+                .children(&mut cursor)
+                .any(|child| child.kind() == "setext_h2_underline")
+            {
+                2
+            } else {
+                1
+            };
+            (level, format!("{} {title}", "#".repeat(level)))
+        };
+        entries.push(Entry {
+            head,
+            line: node.start_position().row + 1,
+            depth: level.saturating_sub(1),
+            kind,
+        });
+        return Ok(());
+    }
+    if kind == "fenced_code_block" {
+        return Ok(());
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_headings(child, source, entries, depth + 1)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+    use crate::{Mode, Parser};
+
+    fn outline(source: &str, language: Language) -> String {
+        let tree = Parser::new(language).unwrap().parse(source).unwrap();
+        let config = TransformConfig::with_mode(Mode::Outline);
+        transform_outline_with_spans(source, &tree, language, &config)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_rust_outline_nests_methods_with_line_numbers() {
+        let source = "pub struct Config;\n\nimpl Config {\n    #[must_use]\n    \
+                      pub fn new(path: &str) -> Self {\n        Config\n    }\n\n    \
+                      fn validate() {}\n}\n";
+        assert_eq!(
+            outline(source, Language::Rust),
+            "1: pub struct Config\n3: impl Config\n  5: pub fn new(...)\n  9: fn validate()\n"
+        );
+    }
+
+    #[test]
+    fn test_head_is_declaration_text_up_to_the_name() {
+        let source = "export const parse = (s: string): string => s;\n\n\
+                      export default async function load<T>(url: string): Promise<T> {}\n";
+        assert_eq!(
+            outline(source, Language::TypeScript),
+            "1: export const parse(...)\n3: export default async function load(...)\n"
+        );
+    }
+
+    #[test]
+    fn test_annotations_keep_the_start_line_but_not_the_head() {
+        let source = "class Job {\n    @Override\n    public void run() {}\n}\n";
+        assert_eq!(
+            outline(source, Language::Java),
+            "1: class Job\n  2: public void run()\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_outline_lists_headings() {
+        let source =
+            "# Guide\n\nIntro\n\n## Install\n\n```sh\n# not a heading\n```\n\nUsage\n-----\n";
+        assert_eq!(
+            outline(source, Language::Markdown),
+            "1: # Guide\n  5: ## Install\n  11: ## Usage\n"
+        );
+    }
+
+    #[test]
+    fn test_find_word_skips_longer_identifiers() {
+        assert_eq!(find_word("fn run_all() { run() }", "run"), Some(15));
+        assert_eq!(find_word("fn running()", "run"), None);
+    }
+
+    #[test]
+    fn test_shift_line_numbers_keeps_indentation() {
+        assert_eq!(
+            shift_line_numbers("1: class A\n  2: run()\n", 10),
+            "11: class A\n  12: run()\n"
+        );
+    }
+}
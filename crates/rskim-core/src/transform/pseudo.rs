@@ -76,7 +76,7 @@ struct PseudoRules {
 
 fn get_pseudo_rules(language: Language) -> PseudoRules {
     match language {
-        Language::TypeScript => PseudoRules {
+        Language::TypeScript | Language::Tsx => PseudoRules {
             strip_kinds: &[
                 "type_annotation",
                 "type_parameters",
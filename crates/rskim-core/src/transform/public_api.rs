@@ -0,0 +1,200 @@
+//! Public-API mode - exported declarations only
+//!
+//! ARCHITECTURE: The public API of a file is its public callables plus its
+//! public types. Signatures mode has the first, types mode the second, and
+//! both already know how to drop private declarations (see
+//! [`super::visibility`]). Public-API mode runs both with that filter forced
+//! on and interleaves their entries by source line, so a struct is followed
+//! by the methods of its `impl`, and a Go type by its exported functions:
+//!
+//! ```text
+//! pub struct Config {
+//!     pub path: PathBuf,
+//! }
+//! pub fn new(path: &str) -> Self
+//! pub fn process_file(config: &Config) -> Result<()>
+//! ```
+//!
+//! An entry both modes produce (the module doc comment) is kept once.
+//! Markdown and `.pyi` stubs have one shared emitter for both modes, so
+//! their signatures-mode output is used as is.
+
+use super::truncate::NodeSpan;
+use super::{signatures, types};
+use crate::{Language, Mode, Result, TransformConfig};
+use tree_sitter::Tree;
+
+/// Transform to public-API mode, returning text and per-entry spans.
+pub(crate) fn transform_public_api_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_public_api_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to public-API mode, also mapping each output line to its
+/// 1-indexed source line.
+pub(crate) fn transform_public_api_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<ModeOutput> {
+    let with_mode = |mode| {
+        let mut config = config.clone();
+        config.mode = mode;
+        config.public_only = true;
+        config
+    };
+    let signatures = signatures::transform_signatures_with_spans_and_line_map(
+        source,
+        tree,
+        language,
+        &with_mode(Mode::Signatures),
+    )?;
+    if language == Language::Markdown || (language == Language::Python && config.python_stub) {
+        return Ok(signatures);
+    }
+    let types = types::transform_types_with_spans_and_line_map(
+        source,
+        tree,
+        language,
+        &with_mode(Mode::Types),
+    )?;
+
+    let types = entries(&types);
+    let mut signatures = entries(&signatures);
+    // A lone comment is signatures mode's "no signatures found" marker; it
+    // only stands in for the output when there are no types either.
+    if !types.is_empty() && signatures.len() == 1 && signatures[0].kind == "comment" {
+        signatures.clear();
+    }
+
+    let mut merged: Vec<Entry> = types.into_iter().chain(signatures).collect();
+    // Stable: on equal lines types come first, then signatures in order.
+    merged.sort_by_key(|entry| entry.line);
+    merged.dedup_by(|later, earlier| later.line == earlier.line && later.lines == earlier.lines);
+
+    let mut text = String::new();
+    let mut spans = Vec::with_capacity(merged.len());
+    let mut line_map = Vec::new();
+    for entry in merged {
+        let start = line_map.len();
+        for (line, source_line) in entry.lines.iter().zip(&entry.line_map) {
+            if !line_map.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(line);
+            line_map.push(*source_line);
+        }
+        spans.push(NodeSpan::new(start..line_map.len(), entry.kind));
+    }
+    Ok((text, spans, line_map))
+}
+
+/// Text, spans and source line map of one mode's output.
+type ModeOutput = (String, Vec<NodeSpan>, Vec<usize>);
+
+/// One signature or type definition from a mode's output.
+struct Entry<'a> {
+    /// Source line it sorts by.
+    line: usize,
+    lines: Vec<&'a str>,
+    line_map: Vec<usize>,
+    kind: &'static str,
+}
+
+/// Split a mode's output into its spans' entries.
+///
+/// Synthesized entries with no source line (Go method sets) sort with the
+/// entry before them.
+fn entries((text, spans, line_map): &ModeOutput) -> Vec<Entry<'_>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut entries: Vec<Entry> = Vec::with_capacity(spans.len());
+    for span in spans {
+        let range = span.transformed_range.clone();
+        let Some(entry_lines) = lines.get(range.clone()) else {
+            continue;
+        };
+        let entry_map = line_map.get(range).unwrap_or_default().to_vec();
+        let line = match entry_map.first() {
+            Some(&line) if line > 0 => line,
+            _ => entries.last().map_or(0, |previous| previous.line),
+        };
+        entries.push(Entry {
+            line,
+            lines: entry_lines.to_vec(),
+            line_map: entry_map,
+            kind: span.node_kind,
+        });
+    }
+    entries
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use crate::{Language, Mode, transform};
+
+    fn public_api(source: &str, language: Language) -> String {
+        transform(source, language, Mode::PublicApi).unwrap()
+    }
+
+    #[test]
+    fn test_rust_public_types_and_functions_in_source_order() {
+        let source = "pub struct Config {\n    pub path: String,\n}\n\nstruct Cache;\n\n\
+                      impl Config {\n    pub fn new(path: &str) -> Self {\n        todo!()\n    }\n\n    \
+                      fn validate(&self) {}\n}\n\nfn helper() {}\n\npub fn run() {}\n";
+        let output = public_api(source, Language::Rust);
+        assert!(output.starts_with("pub struct Config"), "{output}");
+        assert!(
+            output.contains("pub fn new(path: &str) -> Self"),
+            "{output}"
+        );
+        assert!(output.ends_with("pub fn run()"), "{output}");
+        for private in ["Cache", "validate", "helper"] {
+            assert!(!output.contains(private), "{private} leaked: {output}");
+        }
+        let new = output.find("pub fn new").unwrap();
+        assert!(output.find("pub struct Config").unwrap() < new);
+        assert!(new < output.find("pub fn run").unwrap());
+    }
+
+    #[test]
+    fn test_typescript_keeps_exports_only() {
+        let source = "export interface Options {\n  verbose: boolean;\n}\n\n\
+                      interface Internal {\n  id: number;\n}\n\n\
+                      export function load(options: Options): void {}\n\n\
+                      function helper(): void {}\n";
+        assert_eq!(
+            public_api(source, Language::TypeScript),
+            "interface Options {\n  verbose: boolean;\n}\n\
+             function load(options: Options): void"
+        );
+    }
+
+    #[test]
+    fn test_go_exported_names_only() {
+        let source = "package store\n\ntype Store struct {\n\tItems []string\n}\n\n\
+                      type cache struct{}\n\nfunc New() *Store { return nil }\n\n\
+                      func (s *Store) Add(item string) {}\n\nfunc helper() {}\n";
+        let output = public_api(source, Language::Go);
+        assert!(output.contains("type Store struct"), "{output}");
+        assert!(output.contains("func New() *Store"), "{output}");
+        assert!(output.contains("Add(item string)"), "{output}");
+        assert!(!output.contains("cache"), "{output}");
+        assert!(!output.contains("helper"), "{output}");
+    }
+
+    #[test]
+    fn test_no_public_declarations_keeps_the_marker() {
+        let output = public_api("fn helper() {}\n", Language::Rust);
+        assert!(output.contains("no signatures found"), "{output}");
+        let output = public_api("print('hi')\n", Language::Python);
+        assert!(output.contains("no signatures found"), "{output}");
+    }
+}
@@ -0,0 +1,311 @@
+//! `.pyi` stub output for Python signatures and types mode
+//!
+//! ARCHITECTURE: The regular signatures output is a flat list of `def` lines,
+//! which loses class nesting and is not valid Python. When
+//! `TransformConfig::python_stub` is set, Python signatures and types mode
+//! route here instead and emit a stub file that type checkers accept:
+//!
+//! - imports and `type X = ...` aliases are kept verbatim
+//! - `def` headers keep parameters, annotations and decorators; bodies become `...`
+//! - classes keep their bases and nest their members; empty classes become `class X: ...`
+//! - annotated assignments drop their value (`x: int`), including annotated
+//!   `self.x: T` attributes assigned in `__init__`
+//! - single-line unannotated assignments (`T = TypeVar("T")`, `UserId = int`)
+//!   are kept verbatim, longer ones become `name = ...`
+//! - `if TYPE_CHECKING:` blocks are inlined; other statements and docstrings are dropped
+//!
+//! Types mode omits module-level functions; signatures mode keeps them.
+
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::transform::truncate::NodeSpan;
+use crate::transform::utils::{is_python_init, self_attribute_assignment, to_static_node_kind};
+use crate::transform::visibility::PrivateFilter;
+use crate::{Mode, Result, SkimError, TransformConfig};
+use tree_sitter::{Node, Tree};
+
+/// Indentation per nesting level in the emitted stub.
+const INDENT: &str = "    ";
+
+/// Stub body placeholder.
+const ELLIPSIS: &str = "...";
+
+/// Transform a Python tree into a `.pyi` stub.
+///
+/// Returns the stub text, one NodeSpan per top-level item, and a source line
+/// map (0 for the blank lines separating top-level definitions).
+pub(crate) fn transform_python_stub(
+    source: &str,
+    tree: &Tree,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let filter = config
+        .public_only
+        .then(|| PrivateFilter::new(tree.root_node(), source, crate::Language::Python));
+    let mut stub = Stub {
+        source,
+        filter: filter.as_ref(),
+        module_functions: config.mode == Mode::Signatures,
+        lines: Vec::new(),
+        line_map: Vec::new(),
+    };
+
+    let mut spans = Vec::new();
+    let mut cursor = tree.root_node().walk();
+    for child in tree.root_node().named_children(&mut cursor) {
+        let start = stub.lines.len();
+        let separate = is_definition(child) && start > 0;
+        if separate {
+            stub.push_blank();
+        }
+        stub.statement(child, 0, 0)?;
+        if stub.lines.len() == start + usize::from(separate) {
+            // Nothing emitted: drop the separator again.
+            stub.lines.truncate(start);
+            stub.line_map.truncate(start);
+            continue;
+        }
+        let first = start + usize::from(separate);
+        spans.push(NodeSpan::new(
+            first..stub.lines.len(),
+            to_static_node_kind(child.kind()),
+        ));
+    }
+
+    Ok((stub.lines.join("\n"), spans, stub.line_map))
+}
+
+/// Whether a top-level statement is a class or function, separated by a blank line.
+fn is_definition(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "function_definition" | "class_definition" | "decorated_definition"
+    )
+}
+
+/// Stub being built: output lines and the 1-indexed source line of each.
+struct Stub<'a> {
+    source: &'a str,
+    filter: Option<&'a PrivateFilter<'a>>,
+    /// Emit module-level functions (signatures mode); types mode keeps only
+    /// classes, aliases and annotated variables at module level.
+    module_functions: bool,
+    lines: Vec<String>,
+    line_map: Vec<usize>,
+}
+
+impl<'a> Stub<'a> {
+    fn text(&self, node: Node) -> &'a str {
+        node.utf8_text(self.source.as_bytes()).unwrap_or("")
+    }
+
+    fn push(&mut self, level: usize, text: &str, source_line: usize) {
+        self.lines.push(format!("{}{text}", INDENT.repeat(level)));
+        self.line_map.push(source_line);
+    }
+
+    fn push_blank(&mut self) {
+        self.lines.push(String::new());
+        self.line_map.push(0);
+    }
+
+    /// Push possibly multi-line source text; continuation lines keep their
+    /// source indentation.
+    fn push_source(&mut self, level: usize, text: &str, source_line: usize) {
+        for (offset, line) in text.lines().enumerate() {
+            if offset == 0 {
+                self.push(level, line.trim_start(), source_line);
+            } else {
+                self.lines.push(line.to_string());
+                self.line_map.push(source_line + offset);
+            }
+        }
+    }
+
+    /// Emit the stub form of one statement at nesting `level`.
+    fn statement(&mut self, node: Node, level: usize, depth: usize) -> Result<()> {
+        // SECURITY: Prevent stack overflow from deeply nested classes
+        if depth > MAX_AST_DEPTH {
+            return Err(SkimError::ParseError(format!(
+                "Maximum AST depth exceeded: {} (possible malicious input)",
+                MAX_AST_DEPTH
+            )));
+        }
+        if self.filter.is_some_and(|f| f.hides(node)) {
+            return Ok(());
+        }
+
+        let line = node.start_position().row + 1;
+        match node.kind() {
+            "import_statement"
+            | "import_from_statement"
+            | "future_import_statement"
+            | "type_alias_statement" => self.push_source(level, self.text(node), line),
+            "expression_statement" => self.assignment(node, level),
+            "function_definition" if level > 0 || self.module_functions => {
+                self.function(node, level);
+            }
+            "function_definition" => {}
+            "class_definition" => self.class(node, level, depth)?,
+            "decorated_definition" => self.decorated(node, level, depth)?,
+            "if_statement" if self.is_type_checking(node) => {
+                if let Some(block) = node.child_by_field_name("consequence") {
+                    self.block(block, level, depth + 1)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Emit every statement of a block.
+    fn block(&mut self, block: Node, level: usize, depth: usize) -> Result<()> {
+        let mut cursor = block.walk();
+        for child in block.named_children(&mut cursor) {
+            self.statement(child, level, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    /// `if TYPE_CHECKING:` / `if typing.TYPE_CHECKING:`
+    fn is_type_checking(&self, node: Node) -> bool {
+        node.child_by_field_name("condition")
+            .is_some_and(|cond| matches!(self.text(cond), "TYPE_CHECKING" | "typing.TYPE_CHECKING"))
+    }
+
+    /// `def` header (up to and including the colon) followed by ` ...`.
+    fn function(&mut self, node: Node, level: usize) {
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+        let header = self
+            .source
+            .get(node.start_byte()..body.start_byte())
+            .unwrap_or("")
+            .trim_end();
+        self.push_source(
+            level,
+            &format!("{header} {ELLIPSIS}"),
+            node.start_position().row + 1,
+        );
+    }
+
+    fn decorated(&mut self, node: Node, level: usize, depth: usize) -> Result<()> {
+        let Some(definition) = node.child_by_field_name("definition") else {
+            return Ok(());
+        };
+        if definition.kind() == "function_definition" && level == 0 && !self.module_functions {
+            return Ok(());
+        }
+        let mut cursor = node.walk();
+        for decorator in node
+            .named_children(&mut cursor)
+            .filter(|child| child.kind() == "decorator")
+        {
+            self.push_source(
+                level,
+                self.text(decorator),
+                decorator.start_position().row + 1,
+            );
+        }
+        match definition.kind() {
+            "class_definition" => self.class(definition, level, depth)?,
+            _ => self.function(definition, level),
+        }
+        Ok(())
+    }
+
+    fn class(&mut self, node: Node, level: usize, depth: usize) -> Result<()> {
+        let Some(body) = node.child_by_field_name("body") else {
+            return Ok(());
+        };
+        let header = self
+            .source
+            .get(node.start_byte()..body.start_byte())
+            .unwrap_or("")
+            .trim_end();
+        let line = node.start_position().row + 1;
+        let header_index = self.lines.len();
+        self.push_source(level, header, line);
+        let members_start = self.lines.len();
+
+        self.init_attributes(body, level + 1);
+        self.block(body, level + 1, depth)?;
+
+        if self.lines.len() == members_start {
+            // Only docstrings, `pass` or private members: `class X: ...`
+            self.lines[header_index].push_str(&format!(" {ELLIPSIS}"));
+        }
+        Ok(())
+    }
+
+    /// Annotated `self.x: T = ...` assignments in `__init__`, declared as
+    /// class-level `x: T` the way stubs spell instance attributes.
+    fn init_attributes(&mut self, class_body: Node, level: usize) {
+        let mut cursor = class_body.walk();
+        let Some(init) = class_body.named_children(&mut cursor).find_map(|child| {
+            let function = match child.kind() {
+                "decorated_definition" => child.child_by_field_name("definition")?,
+                _ => child,
+            };
+            is_python_init(function, self.source).then_some(function)
+        }) else {
+            return;
+        };
+        let Some(body) = init.child_by_field_name("body") else {
+            return;
+        };
+        let mut cursor = body.walk();
+        for statement in body.named_children(&mut cursor) {
+            let Some(assignment) = self_attribute_assignment(statement, self.source) else {
+                continue;
+            };
+            let (Some(target), Some(annotation)) = (
+                assignment.child_by_field_name("left"),
+                assignment.child_by_field_name("type"),
+            ) else {
+                continue;
+            };
+            let Some(attribute) = target.child_by_field_name("attribute") else {
+                continue;
+            };
+            let name = self.text(attribute);
+            if self.filter.is_some() && name.starts_with('_') {
+                continue;
+            }
+            self.push(
+                level,
+                &format!("{name}: {}", self.text(annotation)),
+                statement.start_position().row + 1,
+            );
+        }
+    }
+
+    /// Module and class variables. Annotated ones drop their value;
+    /// unannotated single-line ones (aliases, `TypeVar`s) are kept.
+    fn assignment(&mut self, statement: Node, level: usize) {
+        let Some(assignment) = statement
+            .named_child(0)
+            .filter(|node| node.kind() == "assignment")
+        else {
+            return;
+        };
+        let Some(target) = assignment
+            .child_by_field_name("left")
+            .filter(|node| node.kind() == "identifier")
+        else {
+            return;
+        };
+        let name = self.text(target);
+        if self.filter.is_some() && name.starts_with('_') && !name.starts_with("__") {
+            return;
+        }
+        let line = statement.start_position().row + 1;
+        if let Some(annotation) = assignment.child_by_field_name("type") {
+            self.push(level, &format!("{name}: {}", self.text(annotation)), line);
+        } else if statement.start_position().row == statement.end_position().row {
+            self.push(level, self.text(statement), line);
+        } else {
+            self.push(level, &format!("{name} = {ELLIPSIS}"), line);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Query mode - nodes captured by a user-supplied tree-sitter query
+//!
+//! ARCHITECTURE: Every other mode is a fixed extraction. Query mode runs the
+//! tree-sitter query in [`TransformConfig::query`] against the file and emits
+//! the text of each captured node, so a new structural view needs a `.scm`
+//! file instead of a new built-in mode:
+//!
+//! ```text
+//! (function_item name: (identifier) @name)
+//! ((call_expression function: (identifier) @_callee) @call (#eq? @_callee "spawn"))
+//! ```
+//!
+//! Captures are emitted in document order and verbatim, with the indentation
+//! of their first line removed from the rest. A node captured more than once
+//! is emitted once, and captures named with a leading `_` (`@_callee`) only
+//! feed predicates and are not emitted. Captures on adjacent lines stay
+//! together; a blank line separates the rest. A query is written against one
+//! grammar, so it fails to compile for files of other languages. Without a
+//! query the output is empty.
+
+use std::collections::HashSet;
+
+use crate::transform::truncate::NodeSpan;
+use crate::transform::utils::{align_to_char_boundaries, to_static_node_kind};
+use crate::{Language, Result, SkimError, TransformConfig};
+use tree_sitter::{Node, Query, QueryCursor, StreamingIterator, Tree};
+
+/// Maximum number of emitted captures to prevent memory exhaustion
+const MAX_CAPTURES: usize = 10_000;
+
+/// One emitted capture, dedented.
+struct Capture {
+    start_byte: usize,
+    start_line: usize,
+    end_line: usize,
+    text: String,
+    kind: &'static str,
+}
+
+/// Transform to query mode, returning text and per-capture spans.
+pub(crate) fn transform_query_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_query_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to query mode, also mapping each output line to its source line
+/// (`0` for the blank separators).
+pub(crate) fn transform_query_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let Some(query_source) = config.query.as_deref() else {
+        return Ok((String::new(), Vec::new(), Vec::new()));
+    };
+    let query = Query::new(&tree.language(), query_source).map_err(|e| {
+        SkimError::ConfigError(format!("invalid query for {}: {e}", language.name()))
+    })?;
+    let captures = collect_captures(&query, tree, source)?;
+
+    let mut text = String::new();
+    let mut spans = Vec::with_capacity(captures.len());
+    let mut line_map = Vec::new();
+    let mut previous_end = None;
+    for capture in captures {
+        if let Some(end) = previous_end {
+            if capture.start_line <= end + 1 {
+                text.push('\n');
+            } else {
+                text.push_str("\n\n");
+                line_map.push(0);
+            }
+        }
+        let line_count = capture.text.lines().count().max(1);
+        spans.push(NodeSpan::new(
+            line_map.len()..line_map.len() + line_count,
+            capture.kind,
+        ));
+        line_map.extend((0..line_count).map(|i| capture.start_line + i));
+        text.push_str(&capture.text);
+        previous_end = Some(capture.end_line);
+    }
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    Ok((text, spans, line_map))
+}
+
+/// Run `query` over `tree` and collect its emitted captures in document order.
+fn collect_captures(query: &Query, tree: &Tree, source: &str) -> Result<Vec<Capture>> {
+    let names = query.capture_names();
+    let mut cursor = QueryCursor::new();
+    let mut found = cursor.captures(query, tree.root_node(), source.as_bytes());
+    let mut seen = HashSet::new();
+    let mut captures = Vec::new();
+    while let Some((query_match, index)) = found.next() {
+        let capture = query_match.captures[*index];
+        if names[capture.index as usize].starts_with('_') || !seen.insert(capture.node.id()) {
+            continue;
+        }
+        if captures.len() == MAX_CAPTURES {
+            return Err(SkimError::ComplexityLimit {
+                what: "query captures",
+                count: captures.len() + 1,
+                max: MAX_CAPTURES,
+            });
+        }
+        captures.extend(extract(capture.node, source));
+    }
+    captures.sort_by_key(|capture| capture.start_byte);
+    Ok(captures)
+}
+
+/// The dedented text of a captured node, or `None` when it is empty.
+fn extract(node: Node, source: &str) -> Option<Capture> {
+    let (start, end) = align_to_char_boundaries(source, node.start_byte(), node.end_byte());
+    let text = source.get(start..end)?.trim_end();
+    if text.is_empty() {
+        return None;
+    }
+    let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &source[line_start..start];
+    let text = if !indent.is_empty() && indent.trim().is_empty() {
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| match i {
+                0 => line,
+                _ => line.strip_prefix(indent).unwrap_or(line),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        text.to_string()
+    };
+    let start_line = node.start_position().row + 1;
+    Some(Capture {
+        start_byte: start,
+        end_line: start_line + text.lines().count() - 1,
+        text,
+        kind: to_static_node_kind(node.kind()),
+        start_line,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use crate::{Language, Mode, SkimError, TransformConfig, transform_with_config};
+
+    fn query(source: &str, language: Language, query: &str) -> crate::Result<String> {
+        let config = TransformConfig::with_mode(Mode::Query).with_query(query);
+        transform_with_config(source, language, &config)
+    }
+
+    #[test]
+    fn test_captures_are_emitted_in_document_order() {
+        let source = "struct Config;\n\nfn load() {}\n\nimpl Config {\n    fn get(&self) {}\n}\n";
+        assert_eq!(
+            query(
+                source,
+                Language::Rust,
+                "(function_item name: (identifier) @name) (struct_item) @struct"
+            )
+            .unwrap(),
+            "struct Config;\n\nload\n\nget\n"
+        );
+    }
+
+    #[test]
+    fn test_predicates_and_underscore_captures() {
+        let source = "def main():\n    spawn(worker)\n    print(spawn)\n    \
+                      if ready:\n        spawn(\n            other,\n        )\n";
+        assert_eq!(
+            query(
+                source,
+                Language::Python,
+                "((call function: (identifier) @_f) @call (#eq? @_f \"spawn\"))"
+            )
+            .unwrap(),
+            "spawn(worker)\n\nspawn(\n    other,\n)\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_is_a_config_error() {
+        let err = query("fn main() {}\n", Language::Rust, "(no_such_node) @x").unwrap_err();
+        assert!(
+            matches!(err, SkimError::ConfigError(ref msg) if msg.contains("invalid query for Rust"))
+        );
+    }
+
+    #[test]
+    fn test_no_query_gives_empty_output() {
+        let config = TransformConfig::with_mode(Mode::Query);
+        assert_eq!(
+            transform_with_config("fn main() {}\n", Language::Rust, &config).unwrap(),
+            ""
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! reStructuredText section outline
+//!
+//! ARCHITECTURE: There is no maintained tree-sitter grammar for
+//! reStructuredText, so this is a line scanner. Like the Markdown transform it
+//! extracts headings rather than replacing bodies: a section title is a line
+//! underlined (and optionally overlined) by a run of one punctuation
+//! character. reStructuredText has no fixed heading characters; levels are
+//! assigned by the order in which each adornment style first appears, exactly
+//! as docutils does.
+//!
+//! # Output Format
+//!
+//! Structure mode keeps the top three section levels (the H1-H3 analogue);
+//! signatures and types modes keep every section title plus the opening line
+//! of each directive (`.. code-block:: python`, `.. automodule:: app`):
+//!
+//! ```text
+//! ========
+//! Overview
+//! ========
+//! Install
+//! -------
+//! .. code-block:: console
+//! ```
+//!
+//! # Rules
+//! - Titles and their adornment lines are emitted verbatim
+//! - The underline must be at least as long as the title, or at least four
+//!   characters; a lone punctuation run between blank lines is a transition
+//! - Comments (`.. text`), link targets (`.. _name:`), footnotes and
+//!   substitution definitions are not directives
+//! - An overline without a matching underline sets `has_errors`
+
+use crate::{Mode, Result, SkimError};
+
+/// Maximum number of section titles and directives
+///
+/// SECURITY: Matches MAX_MARKDOWN_HEADERS to bound output size on adversarial
+/// input. Exceeding the cap degrades to passthrough like every other
+/// complexity limit.
+const MAX_RST_ENTRIES: usize = 10_000;
+
+/// Deepest section level kept in structure mode (mirrors Markdown's H1-H3).
+const STRUCTURE_MAX_LEVEL: usize = 3;
+
+/// An underline shorter than the title still counts from this length on.
+const MIN_ADORNMENT_LEN: usize = 4;
+
+/// Outline a reStructuredText document as its section titles (and, outside
+/// structure mode, its directives), returning `(content, has_errors)`.
+pub(crate) fn transform_rst(source: &str, mode: Mode) -> Result<(String, bool)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let max_level = match mode {
+        Mode::Structure => STRUCTURE_MAX_LEVEL,
+        _ => usize::MAX,
+    };
+    let keep_directives = mode != Mode::Structure;
+
+    let mut result = String::new();
+    let mut has_errors = false;
+    // Adornment styles (character, overlined) in order of first appearance;
+    // a style's index + 1 is its section level.
+    let mut styles: Vec<(char, bool)> = Vec::new();
+    let mut count = 0usize;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let after_blank = i == 0 || lines[i - 1].trim().is_empty();
+
+        let title_lines = if !after_blank {
+            None
+        } else if let Some(over) = adornment(line) {
+            match overlined_title(&lines, i, over) {
+                Some(n) => Some((n, (over, true))),
+                None => {
+                    // A transition, or an overline with a missing or
+                    // mismatched underline.
+                    if lines.get(i + 1).is_some_and(|next| !next.trim().is_empty()) {
+                        has_errors = true;
+                    }
+                    i += 1;
+                    continue;
+                }
+            }
+        } else {
+            underlined_title(&lines, i).map(|under| (2, (under, false)))
+        };
+
+        if let Some((n, style)) = title_lines {
+            let level = match styles.iter().position(|&s| s == style) {
+                Some(index) => index + 1,
+                None => {
+                    styles.push(style);
+                    styles.len()
+                }
+            };
+            if level <= max_level {
+                count += 1;
+                for title_line in &lines[i..i + n] {
+                    result.push_str(title_line.trim_end());
+                    result.push('\n');
+                }
+            }
+            i += n;
+        } else {
+            if keep_directives && is_directive(line) {
+                count += 1;
+                result.push_str(line.trim_end());
+                result.push('\n');
+            }
+            i += 1;
+        }
+
+        if count > MAX_RST_ENTRIES {
+            return Err(SkimError::ComplexityLimit {
+                what: "reStructuredText sections",
+                count,
+                max: MAX_RST_ENTRIES,
+            });
+        }
+    }
+
+    Ok((result, has_errors))
+}
+
+/// The adornment character when `line` is an unindented run of a single
+/// ASCII punctuation character.
+fn adornment(line: &str) -> Option<char> {
+    let line = line.trim_end();
+    let first = line.chars().next()?;
+    (first.is_ascii_punctuation() && line.len() >= 2 && line.chars().all(|c| c == first))
+        .then_some(first)
+}
+
+/// Whether an adornment of `adornment_len` characters is long enough for
+/// `title`.
+fn fits(title: &str, adornment_len: usize) -> bool {
+    adornment_len >= title.trim().chars().count() || adornment_len >= MIN_ADORNMENT_LEN
+}
+
+/// The underline character when `lines[i]` is a title underlined by
+/// `lines[i + 1]`.
+fn underlined_title(lines: &[&str], i: usize) -> Option<char> {
+    let title = lines[i];
+    if title.trim().is_empty() || title.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let under_line = lines.get(i + 1)?;
+    let under = adornment(under_line)?;
+    fits(title, under_line.trim_end().len()).then_some(under)
+}
+
+/// The number of lines (3) when `lines[i]` overlines a title closed by an
+/// underline of the same character and length.
+fn overlined_title(lines: &[&str], i: usize, over: char) -> Option<usize> {
+    let over_len = lines[i].trim_end().len();
+    let title = lines.get(i + 1)?;
+    let under_line = lines.get(i + 2)?;
+    let matches = !title.trim().is_empty()
+        && adornment(title).is_none()
+        && adornment(under_line) == Some(over)
+        && under_line.trim_end().len() == over_len
+        && fits(title, over_len);
+    matches.then_some(3)
+}
+
+/// Whether `line` opens a directive: `.. name::` with a name that starts
+/// with a letter (so not a `.. _target:`, `.. [footnote]` or
+/// `.. |substitution|`).
+fn is_directive(line: &str) -> bool {
+    let Some(rest) = line.trim_start().strip_prefix(".. ") else {
+        return false;
+    };
+    let Some(end) = rest.find("::") else {
+        return false;
+    };
+    let name = &rest[..end];
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.' | '+'))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn skim(source: &str, mode: Mode) -> String {
+        transform_rst(source, mode).unwrap().0
+    }
+
+    #[test]
+    fn test_levels_follow_first_seen_adornment() {
+        let source = "=====\nTitle\n=====\n\nIntro.\n\nUsage\n-----\n\nText.\n\n\
+                      Details\n~~~~~~~\n\nDeep\n^^^^\n\nOther\n-----\n";
+        assert_eq!(
+            skim(source, Mode::Structure),
+            "=====\nTitle\n=====\nUsage\n-----\nDetails\n~~~~~~~\nOther\n-----\n"
+        );
+        assert_eq!(
+            skim(source, Mode::Signatures),
+            "=====\nTitle\n=====\nUsage\n-----\nDetails\n~~~~~~~\nDeep\n^^^^\nOther\n-----\n"
+        );
+    }
+
+    #[test]
+    fn test_directives_only_outside_structure_mode() {
+        let source = "Install\n=======\n\n.. code-block:: console\n\n   $ pip install app\n\n\
+                      .. note::\n   Requires Python 3.\n";
+        assert_eq!(skim(source, Mode::Structure), "Install\n=======\n");
+        assert_eq!(
+            skim(source, Mode::Types),
+            "Install\n=======\n.. code-block:: console\n.. note::\n"
+        );
+    }
+
+    #[test]
+    fn test_comments_targets_and_substitutions_are_not_directives() {
+        let source = ".. a comment\n\n.. _install:\n\n.. [1] Footnote\n\n\
+                      .. |logo| image:: logo.png\n\n.. py:function:: run()\n";
+        assert_eq!(skim(source, Mode::Signatures), ".. py:function:: run()\n");
+    }
+
+    #[test]
+    fn test_transitions_and_short_underlines_are_not_titles() {
+        let source = "Para one.\n\n----------\n\nA longer paragraph line\n--\n";
+        let (result, has_errors) = transform_rst(source, Mode::Signatures).unwrap();
+        assert_eq!(result, "");
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_mismatched_overline_sets_has_errors() {
+        let (result, has_errors) =
+            transform_rst("=======\nBroken\n-------\n\nFine\n----\n", Mode::Structure).unwrap();
+        assert_eq!(result, "Fine\n----\n");
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_too_many_sections_is_complexity_limit() {
+        let source = "Title\n=====\n\n".repeat(MAX_RST_ENTRIES + 1);
+        let err = transform_rst(&source, Mode::Structure).unwrap_err();
+        assert!(err.is_complexity_limit());
+    }
+}
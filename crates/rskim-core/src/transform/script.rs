@@ -0,0 +1,397 @@
+//! Top-level statement summarization for script-style files
+//!
+//! ARCHITECTURE: Structure mode strips function bodies, which leaves scripts
+//! (Python entry points, JS bootstrap files, SQL migrations) almost
+//! untouched: their logic lives in top-level statements. When a file is
+//! script-style, each multi-line top-level statement is condensed in place so
+//! the output reads as a list of operations:
+//!
+//! - control flow keeps its header: `if __name__ == "__main__":` + `...`
+//! - calls keep their callee: `app.listen(...)`
+//! - assignments keep their target: `config = {...}`
+//! - SQL `INSERT`/`UPDATE`/`DELETE` keep their first line:
+//!   `INSERT INTO users (id, email) VALUES ...`
+//!
+//! Every SQL file is script-style: its DDL (`CREATE TABLE`, `ALTER TABLE`) is
+//! the schema and is kept, while data changes are operations.
+//!
+//! Summaries are ordinary structure-mode replacements (no newlines in the
+//! replacement text), so line maps and overlap resolution work unchanged.
+//! Single-line statements are already as short as a summary and are kept.
+
+use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::{Language, Result, SkimError};
+use tree_sitter::Node;
+
+/// SQL statements that change data, condensed to their first line.
+const SQL_OPERATIONS: &[&str] = &["insert", "update", "delete"];
+
+/// Text replacing everything after the first line of a SQL operation.
+const SQL_OPERATION_PLACEHOLDER: &str = " ...";
+
+/// A planned summary of part of a top-level statement.
+pub(crate) struct ScriptReplacement {
+    /// Kind of the condensed node (argument list, literal, or statement).
+    pub(crate) node_kind: &'static str,
+    /// 1-indexed source line where the condensed node starts.
+    pub(crate) line: usize,
+    /// Byte range replaced.
+    pub(crate) byte_range: (usize, usize),
+    /// Text written in place of the range.
+    pub(crate) replacement: &'static str,
+}
+
+/// Node kinds that drive script detection and summarization for one language.
+struct ScriptNodeTypes {
+    /// Top-level definitions; files dominated by these are modules, not scripts.
+    definitions: &'static [&'static str],
+    /// Control-flow statements, with the field naming the body to condense.
+    control_flow: &'static [(&'static str, &'static str)],
+    /// Statements whose multi-line calls and literals are condensed.
+    operations: &'static [&'static str],
+    /// Call argument list kind.
+    arguments: &'static str,
+    /// Multi-line literal kinds and their placeholders.
+    literals: &'static [(&'static str, &'static str)],
+    /// Placeholder for a condensed control-flow body.
+    body_placeholder: &'static str,
+}
+
+fn get_script_node_types(language: Language) -> Option<ScriptNodeTypes> {
+    match language {
+        Language::Python => Some(ScriptNodeTypes {
+            definitions: &[
+                "function_definition",
+                "class_definition",
+                "decorated_definition",
+            ],
+            control_flow: &[
+                ("if_statement", "consequence"),
+                ("for_statement", "body"),
+                ("while_statement", "body"),
+                ("with_statement", "body"),
+                ("try_statement", "body"),
+            ],
+            operations: &["expression_statement"],
+            arguments: "argument_list",
+            literals: &[
+                ("dictionary", "{...}"),
+                ("list", "[...]"),
+                ("set", "{...}"),
+                ("tuple", "(...)"),
+            ],
+            body_placeholder: "...",
+        }),
+        Language::TypeScript | Language::Tsx | Language::JavaScript => Some(ScriptNodeTypes {
+            definitions: &[
+                "function_declaration",
+                "generator_function_declaration",
+                "class_declaration",
+                "abstract_class_declaration",
+                "interface_declaration",
+                "type_alias_declaration",
+                "enum_declaration",
+            ],
+            control_flow: &[
+                ("if_statement", "consequence"),
+                ("for_statement", "body"),
+                ("for_in_statement", "body"),
+                ("while_statement", "body"),
+                ("do_statement", "body"),
+                ("try_statement", "body"),
+            ],
+            operations: &[
+                "expression_statement",
+                "lexical_declaration",
+                "variable_declaration",
+            ],
+            arguments: "arguments",
+            literals: &[("object", "{...}"), ("array", "[...]")],
+            body_placeholder: "{...}",
+        }),
+        _ => None,
+    }
+}
+
+/// Plan summaries for the top-level statements of a script-style file.
+///
+/// Returns an empty list for languages without script support and for files
+/// that are not script-style: a file is script-style when it has at least one
+/// top-level executable statement (a call or control flow) and no more
+/// top-level definitions than executable statements.
+pub(crate) fn collect_script_replacements(
+    root: Node,
+    language: Language,
+) -> Result<Vec<ScriptReplacement>> {
+    if language == Language::Sql {
+        return Ok(collect_sql_replacements(root));
+    }
+    let Some(types) = get_script_node_types(language) else {
+        return Ok(Vec::new());
+    };
+
+    let mut cursor = root.walk();
+    let statements: Vec<Node> = root.named_children(&mut cursor).collect();
+    let definitions = statements
+        .iter()
+        .filter(|s| {
+            types
+                .definitions
+                .contains(&exported_declaration(**s).kind())
+        })
+        .count();
+    let executable = statements
+        .iter()
+        .filter(|s| is_executable(**s, &types))
+        .count();
+    if executable == 0 || definitions > executable {
+        return Ok(Vec::new());
+    }
+
+    let mut replacements = Vec::new();
+    for statement in statements {
+        if statement.start_position().row == statement.end_position().row {
+            continue;
+        }
+        let kind = statement.kind();
+        if let Some(&(_, field)) = types.control_flow.iter().find(|(k, _)| *k == kind) {
+            if let Some(body) = statement.child_by_field_name(field) {
+                replacements.push(ScriptReplacement {
+                    node_kind: kind,
+                    line: statement.start_position().row + 1,
+                    byte_range: (body.start_byte(), statement.end_byte()),
+                    replacement: types.body_placeholder,
+                });
+            }
+        } else if types.operations.contains(&kind) {
+            collect_condensed(statement, &types, &mut replacements, 0)?;
+        }
+    }
+    Ok(replacements)
+}
+
+/// The declaration an ES `export` statement wraps, or the statement itself.
+///
+/// `export function load() {}` is a definition like `function load() {}`.
+fn exported_declaration(statement: Node) -> Node {
+    match statement.kind() {
+        "export_statement" => statement
+            .child_by_field_name("declaration")
+            .unwrap_or(statement),
+        _ => statement,
+    }
+}
+
+/// Condense each multi-line SQL data change to its first line.
+///
+/// Statements inside `BEGIN; ... COMMIT;` transactions count as top-level.
+fn collect_sql_replacements(root: Node) -> Vec<ScriptReplacement> {
+    let mut replacements = Vec::new();
+    let mut cursor = root.walk();
+    for child in root.named_children(&mut cursor) {
+        let statements: Vec<Node> = if child.kind() == "transaction" {
+            let mut inner = child.walk();
+            child.named_children(&mut inner).collect()
+        } else {
+            vec![child]
+        };
+        for statement in statements {
+            let is_operation = statement
+                .named_child(0)
+                .is_some_and(|operation| SQL_OPERATIONS.contains(&operation.kind()));
+            if let Some(first_line_end) = is_operation.then(|| first_line_end(statement)).flatten()
+            {
+                replacements.push(ScriptReplacement {
+                    node_kind: statement.named_child(0).map_or("statement", |n| n.kind()),
+                    line: statement.start_position().row + 1,
+                    byte_range: (first_line_end, statement.end_byte()),
+                    replacement: SQL_OPERATION_PLACEHOLDER,
+                });
+            }
+        }
+    }
+    replacements
+}
+
+/// End byte of the last token on `node`'s first line, or `None` when the
+/// node fits on one line.
+fn first_line_end(node: Node) -> Option<usize> {
+    let row = node.start_position().row;
+    let mut cursor = node.walk();
+    let mut last_end = None;
+    loop {
+        let current = cursor.node();
+        if current.start_position().row > row {
+            return last_end;
+        }
+        if current.child_count() == 0 {
+            last_end = Some(current.end_byte());
+        }
+        if cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() || cursor.node() == node {
+                return None;
+            }
+        }
+    }
+}
+
+/// Whether a top-level statement executes code (as opposed to defining,
+/// importing, or assigning a plain value).
+fn is_executable(statement: Node, types: &ScriptNodeTypes) -> bool {
+    let kind = statement.kind();
+    if types.control_flow.iter().any(|(k, _)| *k == kind) {
+        return true;
+    }
+    // Only expression statements that are calls count; assignments, string
+    // directives (`'use strict'`), and docstrings are neutral.
+    kind == "expression_statement"
+        && statement.named_child(0).is_some_and(|expr| {
+            matches!(
+                expr.kind(),
+                "call" | "call_expression" | "await" | "await_expression"
+            )
+        })
+}
+
+/// Condense the outermost multi-line argument lists and literals under `node`.
+fn collect_condensed(
+    node: Node,
+    types: &ScriptNodeTypes,
+    replacements: &mut Vec<ScriptReplacement>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested AST
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {}",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    let kind = node.kind();
+    if is_inline_function(kind) {
+        // Bodies are handled by structure mode's function-body stripping.
+        return Ok(());
+    }
+    // Arguments that include a callback (`describe('name', () => {...})`)
+    // already lose their bulk to function-body stripping and carry the most
+    // useful text in the other arguments, so descend instead of condensing.
+    let placeholder = if kind == types.arguments {
+        (!has_callback_argument(node)).then_some("(...)")
+    } else {
+        types
+            .literals
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, p)| *p)
+    };
+    if let Some(replacement) = placeholder
+        && node.start_position().row != node.end_position().row
+    {
+        replacements.push(ScriptReplacement {
+            node_kind: kind,
+            line: node.start_position().row + 1,
+            byte_range: (node.start_byte(), node.end_byte()),
+            replacement,
+        });
+        return Ok(());
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_condensed(child, types, replacements, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Whether any argument in `arguments` is an inline function.
+fn has_callback_argument(arguments: Node) -> bool {
+    let mut cursor = arguments.walk();
+    arguments
+        .named_children(&mut cursor)
+        .any(|arg| is_inline_function(arg.kind()))
+}
+
+fn is_inline_function(kind: &str) -> bool {
+    matches!(
+        kind,
+        "arrow_function" | "function_expression" | "function" | "lambda"
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn planned(source: &str, language: Language) -> Vec<(&'static str, &'static str)> {
+        let tree = Parser::new(language).unwrap().parse(source).unwrap();
+        collect_script_replacements(tree.root_node(), language)
+            .unwrap()
+            .into_iter()
+            .map(|r| (r.node_kind, r.replacement))
+            .collect()
+    }
+
+    #[test]
+    fn test_python_script_condenses_calls_literals_and_main_block() {
+        let source = "import sys\n\nSETTINGS = {\n    'debug': True,\n}\n\n\
+                      run(\n    sys.argv,\n)\n\nif __name__ == '__main__':\n    run([])\n";
+        assert_eq!(
+            planned(source, Language::Python),
+            [
+                ("dictionary", "{...}"),
+                ("argument_list", "(...)"),
+                ("if_statement", "..."),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_module_with_definitions_is_not_script_style() {
+        let source =
+            "def a():\n    pass\n\ndef b():\n    pass\n\nif __name__ == '__main__':\n    a()\n";
+        assert!(planned(source, Language::Python).is_empty());
+    }
+
+    #[test]
+    fn test_callback_arguments_are_not_condensed() {
+        let source = "describe('parser', () => {\n  it('works', () => {\n    run();\n  });\n});\n";
+        assert!(planned(source, Language::TypeScript).is_empty());
+    }
+
+    #[test]
+    fn test_single_line_statements_are_kept() {
+        let source = "const app = express();\napp.use(cors());\napp.listen(3000);\n";
+        assert!(planned(source, Language::JavaScript).is_empty());
+    }
+
+    #[test]
+    fn test_exported_definitions_keep_a_module_from_being_script_style() {
+        let source = "export function load() {}\n\nexport class Store {}\n\n\
+                      const DEFAULTS = {\n  retries: 3,\n};\n\ninit();\n";
+        assert!(planned(source, Language::TypeScript).is_empty());
+    }
+
+    #[test]
+    fn test_sql_data_changes_are_condensed() {
+        let source = "CREATE TABLE users (\n  id INT\n);\n\nBEGIN;\n\
+                      INSERT INTO users (id) VALUES\n  (1),\n  (2);\n\
+                      DELETE FROM users\nWHERE id > 1;\nCOMMIT;\n\
+                      UPDATE users SET id = 3;\n";
+        assert_eq!(
+            planned(source, Language::Sql),
+            [("insert", " ..."), ("delete", " ...")]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_language_plans_nothing() {
+        let source = "fn main() {\n    run(\n        1,\n    );\n}\n";
+        assert!(planned(source, Language::Rust).is_empty());
+    }
+}
@@ -0,0 +1,628 @@
+//! Vue / Svelte single-file component (SFC) extraction
+//!
+//! ARCHITECTURE: SFCs are HTML-like containers rather than a single grammar, so
+//! they do not go through tree-sitter directly. Instead we scan for top-level
+//! `<script>` blocks, skim each block's contents as TypeScript or JavaScript via
+//! the regular tree-sitter path, and summarize the surrounding markup: a Vue
+//! `<template>` becomes its element skeleton (via the HTML scanner in `xml`),
+//! anything else a list of the custom components it references.
+//!
+//! # Output Format (Structure)
+//!
+//! ```text
+//! <template>
+//!   <div>
+//!     <UserCard v-for="user in users"/>
+//!     <p>...</p>
+//!   </div>
+//! </template>
+//! <script setup lang="ts">
+//! export function useUser(id: string): User { /* ... */ }
+//! </script>
+//! ```
+//!
+//! # Output Format (Signatures / Types)
+//!
+//! ```text
+//! <script setup lang="ts">
+//! export function useUser(id: string): User { /* ... */ }
+//! </script>
+//! <!-- components: UserCard, router-view -->
+//! ```
+//!
+//! # Output Format (Minimal / Pseudo)
+//!
+//! The file is returned in place with only the script contents transformed —
+//! templates and styles are kept verbatim since these modes are meant to strip
+//! noise, not markup.
+//!
+//! # Rules
+//! - `lang="ts"` / `lang="tsx"` / `lang="typescript"` scripts are skimmed as
+//!   TypeScript, all others as JavaScript
+//! - A `<script>` or `<style>` block left open runs to end of file and sets
+//!   `has_errors`
+//! - `<style>` blocks are dropped in Structure/Signatures/Types modes
+//! - The template skeleton keeps every element but no text (text-only elements
+//!   show `...`) and only control-flow and slot directives (`v-if`, `v-else-if`,
+//!   `v-else`, `v-for`, `v-slot`, `#slot`)
+//! - Components are PascalCase tags (both frameworks) or kebab-case tags (Vue only),
+//!   listed once each in first-seen order
+
+use super::outline;
+use super::xml::{self, Dialect, Element};
+use crate::symbols::Symbol;
+use crate::{Language, Mode, Result, SkimError, TransformConfig};
+
+/// Maximum number of `<script>` blocks scanned per file
+///
+/// SECURITY: Real SFCs have at most two (`<script>` + `<script setup>`); the cap
+/// bounds work on adversarial input.
+const MAX_SFC_SCRIPT_BLOCKS: usize = 64;
+
+/// Maximum number of distinct component names collected from markup
+///
+/// SECURITY: Matches MAX_JSON_KEYS / MAX_TOML_KEYS to bound memory use.
+const MAX_SFC_COMPONENTS: usize = 10_000;
+
+/// Vue directives kept on template skeleton elements, besides slot directives.
+const VUE_SKELETON_DIRECTIVES: &[&str] = &["v-if", "v-else-if", "v-else", "v-for"];
+
+/// A `<script>` or `<style>` block located in the SFC source.
+///
+/// All offsets are byte offsets into the original source and always fall on
+/// ASCII delimiters, so they are valid char boundaries.
+struct SfcBlock<'a> {
+    /// Start of the opening tag (`<script ...>`).
+    tag_start: usize,
+    /// First byte after the opening tag's `>`.
+    content_start: usize,
+    /// First byte of the closing tag (`</script>`).
+    content_end: usize,
+    /// First byte after the closing tag.
+    block_end: usize,
+    /// The opening tag text, e.g. `<script setup lang="ts">`.
+    open_tag: &'a str,
+    /// Whether this is a `<script>` (true) or `<style>` (false) block.
+    is_script: bool,
+}
+
+/// Transform a Vue or Svelte SFC, returning `(content, has_errors)`.
+///
+/// `has_errors` is `true` when any embedded script had tree-sitter parse errors
+/// or a block is never closed.
+pub(crate) fn transform_sfc(
+    source: &str,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, bool)> {
+    let (blocks, unterminated) = find_blocks(source)?;
+
+    if matches!(config.mode, Mode::Minimal | Mode::Pseudo) {
+        let (result, has_errors) = transform_scripts_in_place(source, &blocks, config.mode)?;
+        return Ok((result, has_errors || unterminated));
+    }
+
+    let mut result = String::with_capacity(source.len() / 2);
+    let mut has_errors = unterminated;
+    let markup = markup_only(source, &blocks);
+
+    let mut skeleton = false;
+    if language == Language::Vue && config.mode == Mode::Structure {
+        let (roots, errors) = xml::parse(&markup, Dialect::Html)?;
+        if let Some(template) = roots.iter().find(|e| e.is("template", Dialect::Html)) {
+            render_skeleton(template, 0, &mut result);
+            has_errors |= errors;
+            skeleton = true;
+        }
+    }
+
+    for block in blocks.iter().filter(|b| b.is_script) {
+        let (content, errors) = skim_script(source, block, config.mode)?;
+        has_errors |= errors;
+        result.push_str(block.open_tag);
+        result.push('\n');
+        let content = content.trim_matches('\n');
+        if !content.is_empty() {
+            result.push_str(content);
+            result.push('\n');
+        }
+        result.push_str("</script>\n");
+    }
+
+    // The skeleton already shows every component in place.
+    let components = if skeleton {
+        Vec::new()
+    } else {
+        collect_components(&markup, language)?
+    };
+    if !components.is_empty() {
+        result.push_str("<!-- components: ");
+        result.push_str(&components.join(", "));
+        result.push_str(" -->\n");
+    }
+
+    Ok((result, has_errors))
+}
+
+/// Symbols declared in the component's `<script>` blocks, for summary mode.
+pub(crate) fn script_symbols(source: &str) -> Result<Vec<Symbol>> {
+    let mut out = Vec::new();
+    for block in find_blocks(source)?.0.iter().filter(|b| b.is_script) {
+        let script = &source[block.content_start..block.content_end];
+        out.extend(crate::symbols::symbols(
+            script,
+            script_language(block.open_tag),
+        )?);
+    }
+    Ok(out)
+}
+
+/// Minimal/Pseudo: rewrite script contents in place, keep everything else verbatim.
+fn transform_scripts_in_place(
+    source: &str,
+    blocks: &[SfcBlock<'_>],
+    mode: Mode,
+) -> Result<(String, bool)> {
+    let mut result = String::with_capacity(source.len());
+    let mut last_pos = 0;
+    let mut has_errors = false;
+
+    for block in blocks.iter().filter(|b| b.is_script) {
+        let (content, errors) = skim_script(source, block, mode)?;
+        has_errors |= errors;
+        // Keep the whitespace around the script so the tags stay on their
+        // own lines and the first statement keeps its indentation.
+        let script = &source[block.content_start..block.content_end];
+        let body = script.trim();
+        let lead = &script[..script.len() - script.trim_start().len()];
+        let trail = &script[lead.len() + body.len()..];
+        result.push_str(&source[last_pos..block.content_start]);
+        result.push_str(lead);
+        result.push_str(content.trim());
+        result.push_str(trail);
+        last_pos = block.content_end;
+    }
+    result.push_str(&source[last_pos..]);
+
+    Ok((result, has_errors))
+}
+
+/// Skim the contents of a single `<script>` block with the appropriate language.
+///
+/// Outside Minimal/Pseudo (which rewrite the block in place) the script is
+/// dedented first, so Svelte's conventionally indented scripts skim like
+/// top-level code.
+fn skim_script(source: &str, block: &SfcBlock<'_>, mode: Mode) -> Result<(String, bool)> {
+    let script = &source[block.content_start..block.content_end];
+    if script.trim().is_empty() {
+        return Ok((script.to_string(), false));
+    }
+    let dedented;
+    let script = if matches!(mode, Mode::Minimal | Mode::Pseudo) {
+        script
+    } else {
+        dedented = dedent(script);
+        dedented.as_str()
+    };
+    let config = TransformConfig::with_mode(mode);
+    if matches!(mode, Mode::Comments | Mode::Todos | Mode::Errors) {
+        // Comment headers may sit above verbatim comment text that looks like
+        // a line number, so pad the block instead of rewriting the output.
+        let offset = source[..block.content_start].matches('\n').count();
+        let padded = format!("{}{script}", "\n".repeat(offset));
+        return script_language(block.open_tag).transform_source(&padded, &config);
+    }
+    let (content, has_errors) =
+        script_language(block.open_tag).transform_source(script, &config)?;
+    if mode == Mode::Outline {
+        // Outline line numbers count from the block; anchor them to the file.
+        let offset = source[..block.content_start].matches('\n').count();
+        return Ok((outline::shift_line_numbers(&content, offset), has_errors));
+    }
+    Ok((content, has_errors))
+}
+
+/// Remove the leading spaces and tabs shared by every non-blank line.
+fn dedent(script: &str) -> String {
+    let indent = script
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+        .reduce(|common, indent| {
+            let shared = common
+                .bytes()
+                .zip(indent.bytes())
+                .take_while(|(a, b)| a == b)
+                .count();
+            &common[..shared]
+        })
+        .unwrap_or("");
+    if indent.is_empty() {
+        return script.to_string();
+    }
+    script
+        .split_inclusive('\n')
+        .map(|line| {
+            line.strip_prefix(indent)
+                .unwrap_or(line.trim_start_matches([' ', '\t']))
+        })
+        .collect()
+}
+
+/// Pick TypeScript, TSX or JavaScript from the `lang` attribute of a `<script>` tag.
+fn script_language(open_tag: &str) -> Language {
+    let tag = open_tag.to_ascii_lowercase();
+    let is_tsx = ["lang=\"tsx\"", "lang='tsx'", "lang=tsx"]
+        .iter()
+        .any(|attr| tag.contains(attr));
+    let is_ts = [
+        "lang=\"ts\"",
+        "lang='ts'",
+        "lang=ts",
+        "lang=\"typescript\"",
+        "lang='typescript'",
+        "lang=typescript",
+    ]
+    .iter()
+    .any(|attr| tag.contains(attr));
+    if is_tsx {
+        Language::Tsx
+    } else if is_ts {
+        Language::TypeScript
+    } else {
+        Language::JavaScript
+    }
+}
+
+/// Locate top-level `<script>` and `<style>` blocks in source order, returning
+/// `(blocks, unterminated)` where `unterminated` is true when the last block
+/// or its opening tag runs to end of file.
+fn find_blocks(source: &str) -> Result<(Vec<SfcBlock<'_>>, bool)> {
+    // ASCII-lowercasing preserves byte offsets, so indices into `lower` are
+    // valid indices into `source`.
+    let lower = source.to_ascii_lowercase();
+    let mut blocks = Vec::new();
+    let mut unterminated = false;
+    let mut pos = 0;
+
+    while let Some(rel) = lower[pos..].find('<') {
+        let tag_start = pos + rel;
+        let rest = &lower[tag_start..];
+
+        let (name, is_script) = if rest.starts_with("<script") {
+            ("script", true)
+        } else if rest.starts_with("<style") {
+            ("style", false)
+        } else if rest.starts_with("<!--") {
+            // Skip HTML comments so commented-out scripts are not extracted.
+            pos = lower[tag_start..]
+                .find("-->")
+                .map_or(source.len(), |end| tag_start + end + 3);
+            continue;
+        } else {
+            pos = tag_start + 1;
+            continue;
+        };
+
+        // Require a tag-name boundary (`<scripts>` is not a script block).
+        let after_name = tag_start + 1 + name.len();
+        if !lower[after_name..].starts_with(|c: char| c == '>' || c.is_ascii_whitespace()) {
+            pos = after_name;
+            continue;
+        }
+
+        let Some(open_end) = lower[after_name..].find('>').map(|i| after_name + i + 1) else {
+            unterminated = true; // Unterminated opening tag: treat the rest as markup
+            break;
+        };
+        let closing = format!("</{name}");
+        let content_end = match lower[open_end..].find(&closing) {
+            Some(i) => open_end + i,
+            None => {
+                // Unterminated block: its contents run to end of file
+                unterminated = true;
+                source.len()
+            }
+        };
+        let block_end = lower[content_end..]
+            .find('>')
+            .map_or(source.len(), |i| content_end + i + 1);
+
+        if blocks.len() >= MAX_SFC_SCRIPT_BLOCKS {
+            return Err(SkimError::ComplexityLimit {
+                what: "SFC blocks",
+                count: blocks.len() + 1,
+                max: MAX_SFC_SCRIPT_BLOCKS,
+            });
+        }
+
+        blocks.push(SfcBlock {
+            tag_start,
+            content_start: open_end,
+            content_end,
+            block_end,
+            open_tag: &source[tag_start..open_end],
+            is_script,
+        });
+        pos = block_end;
+    }
+
+    Ok((blocks, unterminated))
+}
+
+/// Return the source with all script/style blocks removed.
+fn markup_only(source: &str, blocks: &[SfcBlock<'_>]) -> String {
+    let mut markup = String::with_capacity(source.len());
+    let mut last_pos = 0;
+    for block in blocks {
+        markup.push_str(&source[last_pos..block.tag_start]);
+        last_pos = block.block_end;
+    }
+    markup.push_str(&source[last_pos..]);
+    markup
+}
+
+/// Collect custom component tag names referenced in markup, in first-seen order.
+fn collect_components(markup: &str, language: Language) -> Result<Vec<String>> {
+    let mut components: Vec<String> = Vec::new();
+    let bytes = markup.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'<' {
+            i += 1;
+            continue;
+        }
+        // `i` sits on an ASCII `<`, so it is a valid char boundary.
+        if markup[i..].starts_with("<!--") {
+            i = markup[i..]
+                .find("-->")
+                .map_or(bytes.len(), |end| i + end + 3);
+            continue;
+        }
+        if !bytes.get(i + 1).is_some_and(u8::is_ascii_alphabetic) {
+            i += 1;
+            continue;
+        }
+
+        let start = i + 1;
+        let end = markup[start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+            .map_or(markup.len(), |n| start + n);
+        let name = &markup[start..end];
+
+        if is_component_tag(name, language) && !components.iter().any(|c| c == name) {
+            if components.len() >= MAX_SFC_COMPONENTS {
+                return Err(SkimError::ComplexityLimit {
+                    what: "SFC components",
+                    count: components.len() + 1,
+                    max: MAX_SFC_COMPONENTS,
+                });
+            }
+            components.push(name.to_string());
+        }
+        i = end;
+    }
+
+    Ok(components)
+}
+
+/// Render a Vue template element as an indented skeleton at `depth`.
+fn render_skeleton(element: &Element<'_>, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&indent);
+    out.push('<');
+    out.push_str(element.name);
+    for attr in element
+        .attrs
+        .iter()
+        .filter(|a| is_skeleton_directive(a.name))
+    {
+        out.push(' ');
+        out.push_str(attr.name);
+        if let Some(value) = attr.value {
+            out.push_str("=\"");
+            out.push_str(value);
+            out.push('"');
+        }
+    }
+
+    if element.children.is_empty() {
+        if element.has_text {
+            out.push_str(">...</");
+            out.push_str(element.name);
+            out.push_str(">\n");
+        } else {
+            out.push_str("/>\n");
+        }
+        return;
+    }
+
+    out.push_str(">\n");
+    for child in &element.children {
+        render_skeleton(child, depth + 1, out);
+    }
+    out.push_str(&indent);
+    out.push_str("</");
+    out.push_str(element.name);
+    out.push_str(">\n");
+}
+
+/// Whether a template attribute shapes the rendered tree (control flow or slots).
+fn is_skeleton_directive(name: &str) -> bool {
+    VUE_SKELETON_DIRECTIVES.contains(&name) || name.starts_with("v-slot") || name.starts_with('#')
+}
+
+/// Whether a tag name refers to a user component rather than an HTML element.
+///
+/// PascalCase tags are components in both frameworks. Kebab-case tags are
+/// components in Vue (`<router-view>`); Svelte treats them as custom elements.
+fn is_component_tag(name: &str, language: Language) -> bool {
+    let starts_upper = name.starts_with(|c: char| c.is_ascii_uppercase());
+    let is_vue_kebab = language == Language::Vue && name.contains('-');
+    starts_upper || is_vue_kebab
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    const VUE_SFC: &str = r#"<template>
+  <div class="profile">
+    <UserCard :user="user" />
+    <router-view />
+    <UserCard :user="other" />
+  </div>
+</template>
+
+<script setup lang="ts">
+import { ref } from "vue";
+
+export function greet(name: string): string {
+  return `Hello ${name}`;
+}
+</script>
+
+<style scoped>
+.profile { color: red; }
+</style>
+"#;
+
+    fn run(source: &str, language: Language, mode: Mode) -> String {
+        transform_sfc(source, language, &TransformConfig::with_mode(mode))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_sfc_structure_skims_script_block() {
+        let result = run(VUE_SFC, Language::Vue, Mode::Structure);
+        assert!(
+            result.contains("<script setup lang=\"ts\">"),
+            "got:\n{result}"
+        );
+        assert!(
+            result.contains("export function greet(name: string): string"),
+            "got:\n{result}"
+        );
+        assert!(
+            !result.contains("Hello ${name}"),
+            "body should be stripped, got:\n{result}"
+        );
+        assert!(
+            !result.contains(".profile"),
+            "style should be dropped, got:\n{result}"
+        );
+    }
+
+    #[test]
+    fn test_vue_structure_emits_template_skeleton_first() {
+        let result = run(VUE_SFC, Language::Vue, Mode::Structure);
+        assert!(
+            result.starts_with(
+                "<template>\n  <div>\n    <UserCard/>\n    <router-view/>\n    <UserCard/>\n  </div>\n</template>\n<script setup lang=\"ts\">\n"
+            ),
+            "got:\n{result}"
+        );
+        assert!(!result.contains("<!-- components:"), "got:\n{result}");
+    }
+
+    #[test]
+    fn test_vue_skeleton_keeps_control_flow_and_slot_directives() {
+        let source = "<template>\n  <ul v-if=\"ready\" class=\"list\">\n    <li v-for=\"item in items\" :key=\"item.id\" @click=\"pick(item)\">{{ item.name }}</li>\n  </ul>\n  <Modal v-else>\n    <template #footer><button>OK</button></template>\n    <template v-slot:header=\"{ title }\">{{ title }}</template>\n  </Modal>\n</template>\n";
+        let result = run(source, Language::Vue, Mode::Structure);
+        assert_eq!(
+            result,
+            "<template>\n  <ul v-if=\"ready\">\n    <li v-for=\"item in items\">...</li>\n  </ul>\n  <Modal v-else>\n    <template #footer>\n      <button>...</button>\n    </template>\n    <template v-slot:header=\"{ title }\">...</template>\n  </Modal>\n</template>\n"
+        );
+    }
+
+    #[test]
+    fn test_sfc_lists_components_once_in_order() {
+        let result = run(VUE_SFC, Language::Vue, Mode::Signatures);
+        assert!(
+            result.contains("<!-- components: UserCard, router-view -->"),
+            "got:\n{result}"
+        );
+    }
+
+    #[test]
+    fn test_svelte_ignores_kebab_custom_elements() {
+        let source = "<script>\n  export let name;\n</script>\n\n<Nav />\n<my-element></my-element>\n<svelte:head></svelte:head>\n";
+        let result = run(source, Language::Svelte, Mode::Structure);
+        assert!(
+            result.contains("<!-- components: Nav -->"),
+            "got:\n{result}"
+        );
+    }
+
+    #[test]
+    fn test_sfc_minimal_keeps_markup_verbatim() {
+        let result = run(VUE_SFC, Language::Vue, Mode::Minimal);
+        assert!(result.contains("<router-view />"), "got:\n{result}");
+        assert!(
+            result.contains(".profile { color: red; }"),
+            "got:\n{result}"
+        );
+    }
+
+    #[test]
+    fn test_sfc_commented_out_script_ignored() {
+        let source =
+            "<!-- <script>secret()</script> -->\n<script>\nfunction a() { return 1; }\n</script>\n";
+        let result = run(source, Language::Vue, Mode::Structure);
+        assert!(!result.contains("secret"), "got:\n{result}");
+        assert!(result.contains("function a()"), "got:\n{result}");
+    }
+
+    #[test]
+    fn test_script_language_from_lang_attribute() {
+        assert_eq!(
+            script_language("<script lang=\"ts\">"),
+            Language::TypeScript
+        );
+        assert_eq!(script_language("<script setup lang='tsx'>"), Language::Tsx);
+        assert_eq!(
+            script_language("<script lang=\"typescript\">"),
+            Language::TypeScript
+        );
+        assert_eq!(script_language("<script>"), Language::JavaScript);
+    }
+
+    #[test]
+    fn test_sfc_without_script_lists_components_only() {
+        let result = run(
+            "<template><Foo/></template>\n",
+            Language::Vue,
+            Mode::Signatures,
+        );
+        assert_eq!(result, "<!-- components: Foo -->\n");
+    }
+
+    #[test]
+    fn test_vue_without_script_emits_skeleton_only() {
+        let result = run(
+            "<template><Foo/></template>\n",
+            Language::Vue,
+            Mode::Structure,
+        );
+        assert_eq!(result, "<template>\n  <Foo/>\n</template>\n");
+    }
+
+    #[test]
+    fn test_sfc_unclosed_script_runs_to_end_of_file() {
+        let source = "<template><Foo/></template>\n<script>\nfunction a() { return 1; }\n";
+        let (result, has_errors) = transform_sfc(
+            source,
+            Language::Vue,
+            &TransformConfig::with_mode(Mode::Signatures),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            "<script>\nfunction a()\n</script>\n<!-- components: Foo -->\n"
+        );
+        assert!(has_errors);
+    }
+}
@@ -3,12 +3,27 @@
 //! ARCHITECTURE: Extract ONLY function/method signatures.
 //!
 //! Token reduction target: 85-92%
+//!
+//! Docs mode runs the same extraction and attaches each signature's doc
+//! comment or docstring (see `docs`).
 
+use crate::transform::arena::with_scratch;
+use crate::transform::docs;
 use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::transform::pyi;
 use crate::transform::structure::extract_markdown_headers_with_spans;
 use crate::transform::truncate::NodeSpan;
-use crate::transform::utils::{FunctionNodeTypes, to_static_node_kind};
-use crate::{Language, Result, SkimError, TransformConfig};
+use crate::transform::utils::{
+    ELIXIR_DEFINITION_CALLS, FunctionNodeTypes, align_to_char_boundaries, count_parameters,
+    end_terminated_signature, get_comment_prefix, get_comment_suffix, is_definition_call,
+    is_ocaml_entry_point, is_ocaml_method, module_doc_comment, ocaml_function_body, parameter_list,
+    to_static_node_kind,
+};
+use crate::transform::visibility::PrivateFilter;
+use crate::transform::wrap::wrap_line;
+use crate::{Language, Mode, Result, SkimError, TransformConfig};
+use bumpalo::Bump;
+use bumpalo::collections::Vec as BumpVec;
 use tree_sitter::{Node, Tree};
 
 /// Maximum number of signatures to prevent memory exhaustion
@@ -72,14 +87,22 @@ pub(crate) fn transform_signatures_with_spans_and_line_map(
     source: &str,
     tree: &Tree,
     language: Language,
-    _config: &TransformConfig,
+    config: &TransformConfig,
 ) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
     // ARCHITECTURE: Markdown signatures mode extracts ALL headers (H1-H6)
     if language == Language::Markdown {
-        let (text, spans, line_map) = extract_markdown_headers_with_spans(source, tree, 1, 6)?;
+        let fenced_code_mode = config.fenced_code.then_some(Mode::Signatures);
+        let (text, spans, line_map) =
+            extract_markdown_headers_with_spans(source, tree, 1, 6, fenced_code_mode)?;
         return Ok((text, spans, line_map));
     }
 
+    // ARCHITECTURE: `.pyi` stubs nest methods under their classes, which the
+    // flat signature list cannot express
+    if language == Language::Python && config.python_stub {
+        return pyi::transform_python_stub(source, tree, config);
+    }
+
     // ARCHITECTURE: JSON is handled by Strategy Pattern in Language::transform_source()
     // and never reaches this code path.
     let node_types = get_signature_node_types(language).ok_or_else(|| {
@@ -89,15 +112,75 @@ pub(crate) fn transform_signatures_with_spans_and_line_map(
         ))
     })?;
 
-    let mut signatures: Vec<(String, &'static str, usize)> = Vec::new();
-    collect_signatures_with_kinds_and_lines(
-        tree.root_node(),
-        source,
-        &node_types,
-        &mut signatures,
-        0,
-    )?;
+    // Signature list is scratch data: build it in the per-file arena. Entries
+    // borrow their text from `source` (or, for composed accessor signatures,
+    // from the arena), so only the final output is copied.
+    with_scratch(|bump| {
+        let mut signatures = BumpVec::new_in(bump);
+        let filter = config
+            .public_only
+            .then(|| PrivateFilter::new(tree.root_node(), source, language));
+        let docs = (config.mode == Mode::Docs).then_some(language);
+        collect_signatures_with_kinds_and_lines(
+            tree.root_node(),
+            source,
+            bump,
+            &node_types,
+            filter.as_ref(),
+            config.max_params,
+            docs,
+            &mut signatures,
+            0,
+        )?;
+        if config.module_doc
+            && let Some((doc, line)) = module_doc_comment(tree.root_node(), source, language)
+        {
+            signatures.insert(0, (doc, "module_doc", line));
+        }
+        if signatures.is_empty() {
+            return Ok(no_signatures_marker(tree.root_node(), language));
+        }
+        assemble_signatures(&signatures, config.wrap_width)
+    })
+}
 
+/// Fallback output for a non-empty file with no callable signatures.
+///
+/// Scripts made only of top-level statements would otherwise produce empty
+/// output, which agents read as "empty file". Emit a single comment naming how
+/// many top-level statements were skipped instead. Files with no statements
+/// (empty or comments only) still produce empty output.
+fn no_signatures_marker(root: Node, language: Language) -> (String, Vec<NodeSpan>, Vec<usize>) {
+    let mut cursor = root.walk();
+    let mut statements = root
+        .named_children(&mut cursor)
+        .filter(|child| !child.kind().contains("comment"));
+    let Some(first) = statements.next() else {
+        return (String::new(), Vec::new(), Vec::new());
+    };
+    let count = 1 + statements.count();
+
+    let marker = format!(
+        "{} no signatures found ({count} top-level statement{}){}",
+        get_comment_prefix(language),
+        if count == 1 { "" } else { "s" },
+        get_comment_suffix(language)
+    );
+    (
+        marker,
+        vec![NodeSpan::new(0..1, "comment")],
+        vec![first.start_position().row + 1],
+    )
+}
+
+/// Join extracted signatures into the output text, spans, and source line map.
+///
+/// With `wrap_width`, each signature line wider than that is soft-wrapped;
+/// every wrapped segment maps to the source line it was cut from.
+fn assemble_signatures(
+    signatures: &[(&str, &'static str, usize)],
+    wrap_width: Option<usize>,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
     // Signature count over the cap: a legitimate but very large file, not an
     // attack. Signal a complexity limit so the dispatcher degrades to a lossless
     // raw passthrough instead of failing the command. (#317)
@@ -114,34 +197,85 @@ pub(crate) fn transform_signatures_with_spans_and_line_map(
     let mut source_line_map: Vec<usize> = Vec::new();
     let mut current_output_line = 0;
 
-    let texts: Vec<String> = signatures
-        .into_iter()
-        .map(|(sig, kind, source_start_line)| {
-            let line_count = sig.lines().count().max(1);
-            spans.push(NodeSpan::new(
-                current_output_line..current_output_line + line_count,
-                kind,
-            ));
-            // Map each output line to consecutive source lines from source_start_line
-            for i in 0..line_count {
-                source_line_map.push(source_start_line + i);
+    let total_len: usize = signatures.iter().map(|(sig, _, _)| sig.len() + 1).sum();
+    let mut text = String::with_capacity(total_len);
+
+    for (idx, &(sig, kind, source_start_line)) in signatures.iter().enumerate() {
+        if idx > 0 {
+            text.push('\n');
+        }
+        let line_count = match wrap_width {
+            Some(width) => push_wrapped(
+                &mut text,
+                &mut source_line_map,
+                sig,
+                source_start_line,
+                width,
+            ),
+            None => {
+                // Map each output line to consecutive source lines from source_start_line
+                let line_count = sig.lines().count().max(1);
+                for i in 0..line_count {
+                    source_line_map.push(source_start_line + i);
+                }
+                text.push_str(sig);
+                line_count
             }
-            current_output_line += line_count;
-            sig
-        })
-        .collect();
+        };
+        spans.push(NodeSpan::new(
+            current_output_line..current_output_line + line_count,
+            kind,
+        ));
+        current_output_line += line_count;
+    }
 
-    Ok((texts.join("\n"), spans, source_line_map))
+    Ok((text, spans, source_line_map))
+}
+
+/// Push `sig` soft-wrapped at `width` columns, mapping each segment to the
+/// source line it came from. Returns the number of output lines pushed.
+fn push_wrapped(
+    text: &mut String,
+    source_line_map: &mut Vec<usize>,
+    sig: &str,
+    source_start_line: usize,
+    width: usize,
+) -> usize {
+    let mut line_count = 0;
+    for (i, line) in sig.lines().enumerate() {
+        for segment in wrap_line(line, width) {
+            if line_count > 0 {
+                text.push('\n');
+            }
+            text.push_str(&segment);
+            source_line_map.push(source_start_line + i);
+            line_count += 1;
+        }
+    }
+    if line_count == 0 {
+        source_line_map.push(source_start_line);
+        line_count = 1;
+    }
+    line_count
 }
 
 /// Recursively collect function/method signatures with node kind AND source start line.
 ///
 /// The source start line is `node.start_position().row + 1` (1-indexed).
-fn collect_signatures_with_kinds_and_lines(
+/// Signatures that are not a contiguous source slice (prefixed Python
+/// properties, C# accessor lists, elided parameter lists) are composed in
+/// `bump`. With `docs` (docs mode), each signature is accompanied by its doc
+/// comment or docstring as a separate `comment` entry.
+#[allow(clippy::too_many_arguments)]
+fn collect_signatures_with_kinds_and_lines<'a>(
     node: Node,
-    source: &str,
+    source: &'a str,
+    bump: &'a Bump,
     node_types: &SignatureNodeTypes,
-    signatures: &mut Vec<(String, &'static str, usize)>,
+    filter: Option<&PrivateFilter>,
+    max_params: Option<usize>,
+    docs: Option<Language>,
+    signatures: &mut BumpVec<'_, (&'a str, &'static str, usize)>,
     depth: usize,
 ) -> Result<()> {
     // SECURITY: Prevent stack overflow from deeply nested or malicious input
@@ -152,74 +286,243 @@ fn collect_signatures_with_kinds_and_lines(
         )));
     }
 
+    // `--public-only`: a private declaration hides its members too.
+    if filter.is_some_and(|f| f.hides(node)) {
+        return Ok(());
+    }
+
     let kind = node.kind();
 
-    if is_signature_node(kind, node_types)
-        && let Some(sig) = extract_signature(node, source, node_types)?
-    {
+    let signature = if is_signature_node(node, source, node_types) || is_function_prototype(node) {
+        let sig = match max_params.and_then(|max| elide_parameters(node, source, bump, max)) {
+            Some(elided) => Some(elided),
+            None => extract_signature(node, source, node_types)?,
+        };
+        match (sig, property_decorator(node, source)) {
+            (Some(sig), Some(decorator)) => {
+                let prefixed = bumpalo::format!(in bump, "{} {}", decorator, sig);
+                Some(prefixed.into_bump_str())
+            }
+            (sig, _) => sig,
+        }
+    } else {
+        accessor_property_signature(node, source, bump)
+    };
+    if let Some(sig) = signature {
         let static_kind = to_static_node_kind(kind);
         // 1-indexed source line where this signature starts
         let source_start_line = node.start_position().row + 1;
+        if let Some(language) = docs
+            && let Some((doc, line)) = docs::leading_doc(node, source, language, bump)
+        {
+            signatures.push((doc, "comment", line));
+        }
         signatures.push((sig, static_kind, source_start_line));
+        if docs == Some(Language::Python)
+            && let Some((doc, line)) = docs::python_docstring(node, source, bump)
+        {
+            signatures.push((doc, "comment", line));
+        }
     }
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        collect_signatures_with_kinds_and_lines(child, source, node_types, signatures, depth + 1)?;
+        collect_signatures_with_kinds_and_lines(
+            child,
+            source,
+            bump,
+            node_types,
+            filter,
+            max_params,
+            docs,
+            signatures,
+            depth + 1,
+        )?;
     }
 
     Ok(())
 }
 
 /// Check if node is a signature-bearing node
-fn is_signature_node(kind: &str, node_types: &SignatureNodeTypes) -> bool {
+fn is_signature_node(node: Node, source: &str, node_types: &SignatureNodeTypes) -> bool {
+    // Keyword tokens can share a definition's kind (OCaml `external`).
+    if !node.is_named() {
+        return false;
+    }
+    let kind = node.kind();
+    match kind {
+        // OCaml: plain value bindings and `let () =` entry points have no
+        // signature, and a `function` match expression has no body to stop at.
+        "let_binding" => {
+            return kind == node_types.function
+                && !is_ocaml_entry_point(node)
+                && ocaml_function_body(node).is_some();
+        }
+        "function_expression" if find_body_for_signature(node).is_none() => return false,
+        _ => {}
+    }
     kind == node_types.function
         || kind == node_types.method
         || kind == "arrow_function"
         || kind == "function_expression"
         || node_types.extra_function_kinds.contains(&kind)
+        || is_definition_call(node, source, node_types.definition_calls)
+}
+
+/// Return the property decorator (`@property`, `@name.setter`, ...) of a
+/// Python method, used as an indicative prefix on its signature.
+///
+/// Decorators live on the enclosing `decorated_definition`, outside the
+/// `function_definition` slice, so properties would otherwise be
+/// indistinguishable from plain methods.
+fn property_decorator<'src>(function: Node, source: &'src str) -> Option<&'src str> {
+    let parent = function.parent()?;
+    if function.kind() != "function_definition" || parent.kind() != "decorated_definition" {
+        return None;
+    }
+    let mut cursor = parent.walk();
+    parent
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "decorator")
+        .filter_map(|decorator| decorator.utf8_text(source.as_bytes()).ok())
+        .map(str::trim)
+        .find(|text| {
+            let name = text.trim_start_matches('@');
+            matches!(
+                name,
+                "property" | "cached_property" | "functools.cached_property"
+            ) || name.ends_with(".setter")
+                || name.ends_with(".deleter")
+        })
+}
+
+/// Build the signature of a C# property or indexer: its declaration followed
+/// by the accessors with bodies removed (`public string Name { get; private set; }`).
+///
+/// Expression-bodied properties (`public int Count => items.Count;`) are
+/// read-only and render as `{ get; }`. Returns `None` for any other node.
+fn accessor_property_signature<'a>(node: Node, source: &'a str, bump: &'a Bump) -> Option<&'a str> {
+    if !matches!(node.kind(), "property_declaration" | "indexer_declaration") {
+        return None;
+    }
+    let text = |start: usize, end: usize| source.get(start..end).map(str::trim);
+
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    if let Some(accessors) = children.iter().find(|c| c.kind() == "accessor_list") {
+        let header = text(node.start_byte(), accessors.start_byte())?;
+        let mut sig = bumpalo::collections::String::from_str_in(header, bump);
+        sig.push_str(" {");
+        let mut accessor_cursor = accessors.walk();
+        for accessor in accessors.named_children(&mut accessor_cursor) {
+            if accessor.kind() != "accessor_declaration" {
+                continue;
+            }
+            let end = accessor
+                .child_by_field_name("body")
+                .map_or(accessor.end_byte(), |body| body.start_byte());
+            let accessor_text = text(accessor.start_byte(), end)?.trim_end_matches(';');
+            sig.push(' ');
+            sig.push_str(accessor_text.trim_end());
+            sig.push(';');
+        }
+        sig.push_str(" }");
+        return Some(sig.into_bump_str());
+    }
+    let arrow = children
+        .iter()
+        .find(|c| c.kind() == "arrow_expression_clause")?;
+    let header = text(node.start_byte(), arrow.start_byte())?;
+    Some(bumpalo::format!(in bump, "{} {{ get; }}", header).into_bump_str())
+}
+
+/// Check if node is a C/C++ function prototype (`int add(int a, int b);`)
+///
+/// Headers declare functions without defining them, so the grammar emits a
+/// `declaration` (or `field_declaration` for C++ member prototypes) whose
+/// declarator is a `function_declarator`, possibly behind pointer declarators
+/// (`char *dup(const char *s);`). Function-pointer variables
+/// (`int (*cb)(int);`) wrap the name in a `parenthesized_declarator` and are
+/// not prototypes.
+pub(crate) fn is_function_prototype(node: Node) -> bool {
+    if !matches!(node.kind(), "declaration" | "field_declaration") {
+        return false;
+    }
+    let mut declarator = node.child_by_field_name("declarator");
+    while let Some(d) = declarator {
+        match d.kind() {
+            "pointer_declarator" | "reference_declarator" => {
+                declarator = d
+                    .child_by_field_name("declarator")
+                    .or_else(|| d.named_child(0));
+            }
+            "function_declarator" => {
+                return d
+                    .child_by_field_name("declarator")
+                    .is_some_and(|name| name.kind() != "parenthesized_declarator");
+            }
+            // C++ conversion operator: `explicit operator bool() const;`
+            "operator_cast" => return true,
+            _ => return false,
+        }
+    }
+    false
 }
 
 /// Extract signature text from node
-fn extract_signature(
+fn extract_signature<'src>(
     node: Node,
-    source: &str,
+    source: &'src str,
     _node_types: &SignatureNodeTypes,
-) -> Result<Option<String>> {
-    // Find the body node
-    let body_node = find_body_for_signature(node);
+) -> Result<Option<&'src str>> {
+    // Extract everything before the body, or the entire node if it has none
+    let end_pos = signature_end(node);
 
-    let end_pos = if let Some(body) = body_node {
-        // Extract everything before the body
-        body.start_byte()
-    } else {
-        // No body found, use entire node
-        node.end_byte()
-    };
-
-    let start = node.start_byte();
+    let start = signature_start(node);
 
     // Validate byte ranges
     if end_pos < start || end_pos > source.len() {
         return Ok(None);
     }
 
-    // Validate UTF-8 boundaries
-    if !source.is_char_boundary(start) || !source.is_char_boundary(end_pos) {
-        return Err(SkimError::ParseError(format!(
-            "Invalid UTF-8 boundary at signature range [{}, {})",
-            start, end_pos
-        )));
-    }
+    // Re-align to UTF-8 boundaries rather than failing the whole file
+    let (start, end_pos) = align_to_char_boundaries(source, start, end_pos);
 
-    let signature = source[start..end_pos].trim();
+    let mut signature = source[start..end_pos].trim();
+    if node.kind() == "let_binding" || is_ocaml_method(node) {
+        // `let add x y =` reads as a signature without its `=`
+        signature = signature.trim_end_matches('=').trim_end();
+    }
 
     // Skip empty signatures
     if signature.is_empty() {
         return Ok(None);
     }
 
-    Ok(Some(signature.to_string()))
+    Ok(Some(signature))
+}
+
+/// Signature with its parameter list replaced by `(... N params)`, or `None`
+/// when it declares at most `max` parameters.
+fn elide_parameters<'a>(
+    node: Node,
+    source: &'a str,
+    bump: &'a Bump,
+    max: usize,
+) -> Option<&'a str> {
+    let params = parameter_list(node)?;
+    let count = count_parameters(params);
+    if count <= max {
+        return None;
+    }
+    let end = signature_end(node);
+    let head = source.get(node.start_byte()..params.start_byte())?;
+    let tail = source.get(params.end_byte()..end.max(params.end_byte()))?;
+    Some(
+        bumpalo::format!(in bump, "{}(... {} params){}", head, count, tail)
+            .into_bump_str()
+            .trim(),
+    )
 }
 
 /// Find body node for a function/method
@@ -229,6 +532,30 @@ fn find_body_for_signature(node: Node) -> Option<Node> {
     crate::transform::utils::find_body_child(node)
 }
 
+/// Byte offset where a function/method's signature text starts: the node's
+/// start, widened over the `let`/`let rec`/`and` keywords an OCaml binding
+/// shares with its enclosing definition.
+fn signature_start(node: Node) -> usize {
+    let mut start = node.start_byte();
+    if node.kind() == "let_binding" {
+        let mut previous = node.prev_sibling();
+        while let Some(keyword) = previous.filter(|sibling| !sibling.is_named()) {
+            start = keyword.start_byte();
+            previous = keyword.prev_sibling();
+        }
+    }
+    start
+}
+
+/// Byte offset where a function/method's signature text ends: the start of
+/// its body, the end of a Julia-style `signature` child, or the node's end.
+fn signature_end(node: Node) -> usize {
+    match find_body_for_signature(node) {
+        Some(body) => body.start_byte(),
+        None => end_terminated_signature(node).map_or(node.end_byte(), |sig| sig.end_byte()),
+    }
+}
+
 /// Type alias: signatures mode reuses the shared FunctionNodeTypes struct from utils.
 /// The factory function (get_signature_node_types) produces intentionally different
 /// values than structure mode — e.g., omitting node kinds with no extractable signature.
@@ -240,69 +567,172 @@ type SignatureNodeTypes = FunctionNodeTypes;
 /// ARCHITECTURE: JSON is handled by the Strategy Pattern in Language::transform_source().
 fn get_signature_node_types(language: Language) -> Option<SignatureNodeTypes> {
     match language {
-        Language::TypeScript | Language::JavaScript => Some(SignatureNodeTypes {
+        Language::TypeScript | Language::Tsx | Language::JavaScript => Some(SignatureNodeTypes {
             function: "function_declaration",
             method: "method_definition",
-            extra_function_kinds: &[],
+            // `function_signature`: TS overloads and `declare function`
+            extra_function_kinds: &[
+                "generator_function_declaration",
+                "generator_function",
+                "function_signature",
+            ],
+            definition_calls: &[],
         }),
         Language::Python => Some(SignatureNodeTypes {
             function: "function_definition",
             method: "function_definition",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Rust => Some(SignatureNodeTypes {
             function: "function_item",
             method: "function_item",
-            extra_function_kinds: &[],
+            // Bodiless trait methods and `extern` block declarations
+            extra_function_kinds: &["function_signature_item"],
+            definition_calls: &[],
         }),
         Language::Go => Some(SignatureNodeTypes {
             function: "function_declaration",
             method: "method_declaration",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Java => Some(SignatureNodeTypes {
             function: "method_declaration",
             method: "method_declaration",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         // Unreachable: Markdown returns early via extract_markdown_headers_with_spans
         Language::Markdown => Some(SignatureNodeTypes {
             function: "atx_heading",
             method: "atx_heading",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::C | Language::Cpp => Some(SignatureNodeTypes {
             function: "function_definition",
             method: "function_definition",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::CSharp => Some(SignatureNodeTypes {
             function: "method_declaration",
             method: "constructor_declaration",
-            extra_function_kinds: &[],
+            extra_function_kinds: &[
+                "destructor_declaration",
+                "operator_declaration",
+                "conversion_operator_declaration",
+            ],
+            definition_calls: &[],
         }),
         Language::Ruby => Some(SignatureNodeTypes {
             function: "method",
             method: "singleton_method",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Sql => Some(SignatureNodeTypes {
             function: "create_table",
             method: "create_index",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Kotlin => Some(SignatureNodeTypes {
             function: "function_declaration",
             method: "function_declaration",
             // anonymous_initializer (init {}) omitted: has no parameters/signature to extract
             extra_function_kinds: &["secondary_constructor"],
+            definition_calls: &[],
         }),
         Language::Swift => Some(SignatureNodeTypes {
             function: "function_declaration",
             method: "function_declaration",
             // deinit_declaration omitted: has no parameters/signature to extract
             extra_function_kinds: &["init_declaration"],
+            definition_calls: &[],
+        }),
+        Language::Shell => Some(SignatureNodeTypes {
+            function: "function_definition",
+            method: "function_definition",
+            extra_function_kinds: &[],
+            definition_calls: &[],
+        }),
+        // Elixir definitions are macro calls (`def`, `defp`, ...), matched by
+        // target name rather than node kind.
+        Language::Elixir => Some(SignatureNodeTypes {
+            function: "",
+            method: "",
+            extra_function_kinds: &[],
+            definition_calls: ELIXIR_DEFINITION_CALLS,
+        }),
+        // Dart: match the inner signature kinds rather than `method_signature`,
+        // which wraps them, so each member is listed once. Constructors without
+        // a body (`const MyWidget({super.key});`) are `declaration`s wrapping
+        // the same kinds, which keeps widget constructors in the output.
+        Language::Dart => Some(SignatureNodeTypes {
+            function: "function_signature",
+            method: "constructor_signature",
+            extra_function_kinds: &[
+                "constant_constructor_signature",
+                "factory_constructor_signature",
+                "redirecting_factory_constructor_signature",
+                "getter_signature",
+                "setter_signature",
+                "operator_signature",
+            ],
+            definition_calls: &[],
+        }),
+        // Body-less events and custom errors are part of a contract's interface.
+        Language::Solidity => Some(SignatureNodeTypes {
+            function: "function_definition",
+            method: "constructor_definition",
+            extra_function_kinds: &[
+                "fallback_receive_definition",
+                "modifier_definition",
+                "event_definition",
+                "error_declaration",
+            ],
+            definition_calls: &[],
+        }),
+        // Interface method declarations cover headers; definitions cover .m files.
+        Language::ObjectiveC => Some(SignatureNodeTypes {
+            function: "function_definition",
+            method: "method_definition",
+            extra_function_kinds: &["method_declaration"],
+            definition_calls: &[],
+        }),
+        // One-line `f(x) = ...` definitions are plain assignments and are not
+        // listed.
+        Language::Julia => Some(SignatureNodeTypes {
+            function: "function_definition",
+            method: "function_definition",
+            extra_function_kinds: &["macro_definition"],
+            definition_calls: &[],
+        }),
+        // Only function bindings and methods are listed; see
+        // `ocaml_function_body`.
+        Language::Ocaml => Some(SignatureNodeTypes {
+            function: "let_binding",
+            method: "method_definition",
+            extra_function_kinds: &["external"],
+            definition_calls: &[],
         }),
-        Language::Json | Language::Yaml | Language::Toml => None,
+        Language::Json
+        | Language::Yaml
+        | Language::Toml
+        | Language::OcamlInterface
+        | Language::Vue
+        | Language::Svelte
+        | Language::Xml
+        | Language::Html
+        | Language::Hcl
+        | Language::Dockerfile
+        | Language::Groovy
+        | Language::Csv
+        | Language::Env
+        | Language::Ini
+        | Language::Rst
+        | Language::AsciiDoc => None,
     }
 }
@@ -4,12 +4,20 @@
 //!
 //! Token reduction target: 70-80%
 
+use crate::symbols::is_symbol_kind;
+use crate::transform::arena::with_scratch;
 use crate::transform::compute_line_starts;
 use crate::transform::minimal::{MAX_AST_DEPTH, MAX_AST_NODES};
+use crate::transform::script::collect_script_replacements;
 use crate::transform::truncate::NodeSpan;
-use crate::transform::utils::{FunctionNodeTypes, to_static_node_kind};
-use crate::{Language, Result, SkimError, TransformConfig};
-use std::collections::HashMap;
+use crate::transform::utils::{
+    ELIXIR_DEFINITION_CALLS, FunctionNodeTypes, align_to_char_boundaries, end_terminated_body,
+    is_definition_call, is_ocaml_method, is_python_init, self_attribute_assignment,
+    to_static_node_kind,
+};
+use crate::transform::visibility::PrivateFilter;
+use crate::{Language, Mode, Result, SkimError, TransformConfig};
+use bumpalo::collections::Vec as BumpVec;
 use tree_sitter::{Node, Tree};
 
 /// Maximum number of markdown headers to prevent memory exhaustion
@@ -27,8 +35,11 @@ const MAX_MARKDOWN_HEADERS: usize = 10_000;
 ///
 /// # What to Remove
 ///
-/// - Function bodies → `{...}`
+/// - Function bodies → `{...}` (`{ todo!() }` in Rust with `config.todo_bodies`),
+///   or all but their first `config.body_statements` statements → `...`
 /// - Implementation details
+/// - In script-style files, multi-line top-level statements are condensed
+///   (see `script`)
 /// - Non-structural comments
 #[cfg(test)]
 #[allow(dead_code)] // Convenience wrapper available for tests
@@ -73,12 +84,14 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
     source: &str,
     tree: &Tree,
     language: Language,
-    _config: &TransformConfig,
+    config: &TransformConfig,
 ) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
     // ARCHITECTURE: Markdown uses extraction, not replacement
     // Extract H1-H3 headers only (top-level document structure)
     if language == Language::Markdown {
-        let (text, spans, line_map) = extract_markdown_headers_with_spans(source, tree, 1, 3)?;
+        let fenced_code_mode = config.fenced_code.then_some(Mode::Signatures);
+        let (text, spans, line_map) =
+            extract_markdown_headers_with_spans(source, tree, 1, 3, fenced_code_mode)?;
         return Ok((text, spans, line_map));
     }
 
@@ -92,10 +105,241 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
         ))
     })?;
 
-    // Find all body nodes to replace
-    let mut replacements: HashMap<(usize, usize), &'static str> = HashMap::new();
-    collect_body_replacements(tree.root_node(), &node_types, &mut replacements, 0)?;
+    // Replacement lists are scratch data: build them in the per-file arena.
+    with_scratch(|bump| {
+        let mut replacements = BumpVec::new_in(bump);
+        collect_body_replacements(
+            tree.root_node(),
+            source,
+            &node_types,
+            language,
+            config,
+            &mut replacements,
+            0,
+        )?;
+        for summary in collect_script_replacements(tree.root_node(), language)? {
+            replacements.push((summary.byte_range, summary.replacement));
+        }
+        for block in collect_long_block_replacements(tree.root_node(), language)? {
+            replacements.push((block.byte_range, LONG_BLOCK_PLACEHOLDER));
+        }
+        if config.public_only {
+            // Collected last so a removal wins over a body replacement that
+            // starts at the same byte.
+            let filter = PrivateFilter::new(tree.root_node(), source, language);
+            collect_private_removals(tree.root_node(), source, &filter, &mut replacements, 0)?;
+        }
+        if let Some(max_nesting) = config.max_nesting_kept {
+            // Declarations inside a collapsed body are gone already.
+            let covered = CoveredRanges::new(replacements.iter().map(|(range, _)| *range));
+            collect_nested_removals(
+                tree.root_node(),
+                source,
+                max_nesting,
+                &covered,
+                &mut replacements,
+                0,
+                0,
+            )?;
+        }
+        apply_body_replacements(source, tree, replacements)
+    })
+}
+
+/// Plan the removal of every private declaration (`--public-only`).
+///
+/// Each removal covers the declaration's whole lines, plus the doc comments
+/// and attributes directly above it, so no blank or indent-only line is left
+/// behind. The replacement is empty, which keeps the no-newline invariant.
+fn collect_private_removals(
+    node: Node,
+    source: &str,
+    filter: &PrivateFilter,
+    replacements: &mut BumpVec<'_, ((usize, usize), &'static str)>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested or malicious input
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {} (possible malicious input)",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    if filter.hides(node) {
+        replacements.push((whole_line_range(node, source), ""));
+        return Ok(());
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_private_removals(child, source, filter, replacements, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Wrappers removed together with the declaration they wrap.
+const DECLARATION_WRAPPERS: &[&str] = &[
+    "export_statement",
+    "decorated_definition",
+    "template_declaration",
+];
+
+/// Plan the removal of every declaration nested inside more than
+/// `max_nesting` other declarations (`max_nesting_kept`).
+///
+/// Declarations are the kinds the symbol outline reports, anonymous functions
+/// included, so `nesting` matches `Symbol::depth` for named ones. A removed
+/// declaration takes its nested ones with it.
+fn collect_nested_removals(
+    node: Node,
+    source: &str,
+    max_nesting: usize,
+    covered: &CoveredRanges,
+    replacements: &mut BumpVec<'_, ((usize, usize), &'static str)>,
+    nesting: usize,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested or malicious input
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {} (possible malicious input)",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    if covered.contains(node.start_byte(), node.end_byte()) {
+        return Ok(());
+    }
+
+    let mut nesting = nesting;
+    // The root is the file (Python's and Ruby's is a `module`), not a declaration.
+    if node.parent().is_some() && is_symbol_kind(node) {
+        if nesting > max_nesting {
+            replacements.push((whole_line_range(removal_target(node), source), ""));
+            return Ok(());
+        }
+        nesting += 1;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_nested_removals(
+            child,
+            source,
+            max_nesting,
+            covered,
+            replacements,
+            nesting,
+            depth + 1,
+        )?;
+    }
+    Ok(())
+}
+
+/// Byte ranges that earlier replacements already cover.
+struct CoveredRanges {
+    /// Range starts, ascending.
+    starts: Vec<usize>,
+    /// `max_ends[i]`: the furthest end among the first `i + 1` ranges.
+    max_ends: Vec<usize>,
+}
+
+impl CoveredRanges {
+    fn new(ranges: impl Iterator<Item = (usize, usize)>) -> Self {
+        let mut ranges: Vec<(usize, usize)> = ranges.collect();
+        ranges.sort_unstable();
+        let starts = ranges.iter().map(|&(start, _)| start).collect();
+        let max_ends = ranges
+            .iter()
+            .scan(0, |max_end, &(_, end)| {
+                *max_end = end.max(*max_end);
+                Some(*max_end)
+            })
+            .collect();
+        Self { starts, max_ends }
+    }
+
+    /// Whether `start..end` lies inside one of the ranges.
+    fn contains(&self, start: usize, end: usize) -> bool {
+        let candidates = self.starts.partition_point(|&s| s <= start);
+        candidates > 0 && self.max_ends[candidates - 1] >= end
+    }
+}
+
+/// The node to cut out when removing the declaration `node`: its wrapper
+/// (`export`, decorators, `template<...>`), or for a function value the
+/// member or variable it initialises.
+fn removal_target(node: Node) -> Node {
+    let mut target = node;
+    if matches!(
+        node.kind(),
+        "arrow_function" | "function_expression" | "function" | "generator_function"
+    ) && let Some(parent) = node.parent()
+    {
+        target = match parent.kind() {
+            "pair" | "public_field_definition" | "field_definition" => parent,
+            "variable_declarator" => parent
+                .parent()
+                .filter(|decl| decl.named_child_count() == 1)
+                .unwrap_or(parent),
+            _ => target,
+        };
+    }
+    while let Some(parent) = target
+        .parent()
+        .filter(|parent| DECLARATION_WRAPPERS.contains(&parent.kind()))
+    {
+        target = parent;
+    }
+    target
+}
+
+/// Byte range of `node`'s lines, extended upwards over directly preceding
+/// comments and attributes. Falls back to the node itself when it shares a
+/// line with other code.
+fn whole_line_range(node: Node, source: &str) -> (usize, usize) {
+    let mut first = node;
+    while let Some(prev) = first.prev_sibling() {
+        let attached = matches!(
+            prev.kind(),
+            "comment" | "line_comment" | "block_comment" | "attribute_item" | "decorator"
+        ) && prev.end_position().row + 1 >= first.start_position().row;
+        if !attached {
+            break;
+        }
+        first = prev;
+    }
+
+    let bytes = source.as_bytes();
+    let line_start = source[..first.start_byte()]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let line_end = source[node.end_byte()..]
+        .find('\n')
+        .map_or(source.len(), |offset| node.end_byte() + offset + 1);
+    let blank = |range: std::ops::Range<usize>| bytes[range].iter().all(u8::is_ascii_whitespace);
+    // Some grammars leave a member's terminating `;` (or a list element's
+    // `,`) outside the node; it goes with the member.
+    let trailing = source[node.end_byte()..line_end].trim_start_matches([';', ',']);
+    if blank(line_start..first.start_byte()) && trailing.trim().is_empty() {
+        (line_start, line_end)
+    } else {
+        (node.start_byte(), node.end_byte())
+    }
+}
 
+/// Splice collected replacements into `source`, returning the output with its
+/// spans and source line map.
+///
+/// Replacements are `((start_byte, end_byte), text)` in collection order. When
+/// two share a range, the one collected last wins (a collapsed template literal
+/// that is also an arrow function's expression body).
+fn apply_body_replacements(
+    source: &str,
+    tree: &Tree,
+    mut replacements: BumpVec<'_, ((usize, usize), &'static str)>,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
     // Node count over the cap: typically a legitimate but very large file (e.g.
     // a machine-generated weight table), not an attack. Signal a complexity
     // limit so the dispatcher degrades to a lossless raw passthrough instead of
@@ -113,9 +357,10 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
     let mut result = String::with_capacity(estimated_capacity);
     let mut last_pos = 0;
 
-    // Sort replacements by start position
-    let mut sorted_replacements: Vec<_> = replacements.into_iter().collect();
-    sorted_replacements.sort_unstable_by_key(|(range, _)| range.0);
+    // Sort replacements by start position. Reversing first makes the stable sort
+    // put the last-collected of any same-start replacements first, so it wins.
+    replacements.reverse();
+    replacements.sort_by_key(|(range, _)| range.0);
 
     // Track cumulative byte offset delta (output_pos - source_pos)
     // offset_map entries: (source_end_byte, cumulative_delta)
@@ -124,7 +369,7 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
     let mut offset_delta: i64 = 0;
     let mut offset_map: Vec<(usize, i64)> = Vec::new(); // (source_byte_end, delta)
 
-    for ((start, end), replacement) in sorted_replacements {
+    for ((start, end), replacement) in replacements {
         // Validate byte ranges
         if end < start {
             return Err(SkimError::ParseError(format!(
@@ -140,18 +385,19 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
             )));
         }
 
+        // Re-align to UTF-8 boundaries rather than failing the whole file
+        let (start, end) = align_to_char_boundaries(source, start, end);
+
         // Skip overlapping replacements (nested functions already handled by parent)
         if start < last_pos {
             continue;
         }
 
-        // Validate UTF-8 boundaries before slicing
-        if !source.is_char_boundary(start) || !source.is_char_boundary(end) {
-            return Err(SkimError::ParseError(format!(
-                "Invalid UTF-8 boundary at range [{}, {})",
-                start, end
-            )));
-        }
+        let (start, end) = if replacement.is_empty() {
+            widen_line_removal(source, &result, last_pos, start, end)
+        } else {
+            (start, end)
+        };
 
         // Copy everything before this replacement
         result.push_str(&source[last_pos..start]);
@@ -168,15 +414,7 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
         last_pos = end;
     }
 
-    // Validate final position
-    if !source.is_char_boundary(last_pos) {
-        return Err(SkimError::ParseError(format!(
-            "Invalid UTF-8 boundary at position {}",
-            last_pos
-        )));
-    }
-
-    // Copy remaining source
+    // Copy remaining source (last_pos is a boundary: every range was aligned)
     result.push_str(&source[last_pos..]);
 
     // Build NodeSpans from top-level AST children
@@ -188,6 +426,80 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
     Ok((result, spans, source_line_map))
 }
 
+/// Widen a whole-line removal (`--public-only`, `max_nesting_kept`) over the
+/// blank lines around it, so removed declarations leave no gap behind.
+///
+/// Blank lines on both sides go when the removal opens a block (the line
+/// above ends with `{`, `[`, `(` or `:`) or closes one (the line below starts
+/// with a closing bracket or `end`), or at either end of the file. Between
+/// two surviving lines, one blank line is kept if there was one above, so
+/// separated declarations stay separated. `start..end` must not precede
+/// `last_pos`, the end of what `output` already holds; ranges that do not
+/// span whole lines are returned unchanged.
+fn widen_line_removal(
+    source: &str,
+    output: &str,
+    last_pos: usize,
+    start: usize,
+    end: usize,
+) -> (usize, usize) {
+    let bytes = source.as_bytes();
+    let at_line_start = |pos: usize| pos == 0 || bytes[pos - 1] == b'\n';
+    if !at_line_start(start) || !(at_line_start(end) || end == source.len()) {
+        return (start, end);
+    }
+    let is_blank = |line: &str| line.trim().is_empty();
+
+    // Blank lines directly above that are not in `output` yet...
+    let mut before = start;
+    while before > last_pos {
+        let line_start = source[last_pos..before - 1]
+            .rfind('\n')
+            .map_or(last_pos, |newline| last_pos + newline + 1);
+        if !at_line_start(line_start) || !is_blank(&source[line_start..before]) {
+            break;
+        }
+        before = line_start;
+    }
+    // ...and directly below.
+    let mut after = end;
+    while after < source.len() {
+        let line_end = source[after..]
+            .find('\n')
+            .map_or(source.len(), |newline| after + newline + 1);
+        if !is_blank(&source[after..line_end]) {
+            break;
+        }
+        after = line_end;
+    }
+
+    let pending = &source[last_pos..before];
+    let previous = pending
+        .trim_end()
+        .chars()
+        .last()
+        .or_else(|| output.trim_end().chars().last());
+    let next = source[after..].lines().next().map(str::trim_start);
+    let opens = previous.is_none_or(|c| matches!(c, '{' | '[' | '(' | ':'));
+    let closes = next.is_none_or(|line| {
+        line.starts_with(['}', ']', ')']) || line == "end" || line.starts_with("end ")
+    });
+    if opens || closes {
+        return (before, after);
+    }
+    // `output` may already end with the blank line a previous removal kept.
+    let blank_above = before < start
+        || (pending.is_empty()
+            && output
+                .strip_suffix('\n')
+                .is_some_and(|text| is_blank(text.rsplit('\n').next().unwrap_or(text))));
+    if blank_above {
+        (start, after)
+    } else {
+        (start, end)
+    }
+}
+
 /// Compute the source line map for structure mode output using the offset_map.
 ///
 /// For each output line (by its start byte offset), reverse-maps to a source byte
@@ -195,7 +507,7 @@ pub(crate) fn transform_structure_with_spans_and_line_map(
 /// the 1-indexed source line number.
 ///
 /// # Correctness Invariant
-/// The replacement text `" {...}"` contains no newlines. Therefore no
+/// The replacement texts (`" {...}"`, `` `...` ``) contain no newlines. Therefore no
 /// output line ever starts inside a replacement region — all output line start
 /// bytes are in verbatim-copied regions where the reverse mapping is exact.
 pub(crate) fn compute_source_line_map_from_offset_map(
@@ -276,15 +588,25 @@ pub(crate) fn compute_source_line_map_from_offset_map(
         .collect()
 }
 
-/// Recursively collect body nodes that should be replaced
+/// Recursively collect body nodes that should be replaced with the body
+/// placeholder (see [`body_placeholder`])
+///
+/// With `config.body_statements` set, the first statements of each body are
+/// kept and only the rest is replaced (see [`elided_statements`]). Template
+/// literals longer than `config.template_literal_threshold` bytes are
+/// collapsed to `` `...` `` as well; their substitutions are not descended
+/// into.
 ///
 /// # Security
 /// - Enforces MAX_AST_DEPTH to prevent stack overflow
 /// - Returns error if depth limit exceeded
 fn collect_body_replacements(
     node: Node,
+    source: &str,
     node_types: &NodeTypes,
-    replacements: &mut HashMap<(usize, usize), &'static str>,
+    language: Language,
+    config: &TransformConfig,
+    replacements: &mut BumpVec<'_, ((usize, usize), &'static str)>,
     depth: usize,
 ) -> Result<()> {
     // SECURITY: Prevent stack overflow from deeply nested AST
@@ -298,95 +620,400 @@ fn collect_body_replacements(
     let kind = node.kind();
 
     // Check if this is a function/method with a body
-    if matches_function_node(kind, node_types)
-        && let Some(body) = find_body_node(node)
+    if matches_function_node(node, source, node_types) {
+        let placeholder = body_placeholder(language, config);
+        let keep = config.body_statements;
+        if let Some(body) = find_body_node(node) {
+            // OCaml bodies are bare expressions that already follow a space.
+            let placeholder = if kind == "let_binding" || is_ocaml_method(node) {
+                placeholder.trim_start()
+            } else {
+                placeholder
+            };
+            match init_attribute_replacements(node, body, source) {
+                Some(summary) => replacements.extend(summary),
+                None if keep > 0 => {
+                    // An expression body is a single statement: kept whole.
+                    let statements = body_statement_list(body).map(|list| {
+                        let mut cursor = list.walk();
+                        list.named_children(&mut cursor).collect::<Vec<_>>()
+                    });
+                    if let Some(range) = statements.and_then(|s| elided_statements(&s, keep)) {
+                        replacements.push((range, elision_placeholder(language, config)));
+                    }
+                }
+                None => replacements.push(((body.start_byte(), body.end_byte()), placeholder)),
+            }
+        } else if let Some(range) = end_terminated_body(node) {
+            if keep > 0 {
+                let mut cursor = node.walk();
+                let statements: Vec<Node> = node
+                    .named_children(&mut cursor)
+                    .filter(|c| c.start_byte() >= range.0)
+                    .collect();
+                if let Some(range) = elided_statements(&statements, keep) {
+                    replacements.push((range, elision_placeholder(language, config)));
+                }
+            } else {
+                // The range starts at the first statement, after its indentation.
+                replacements.push((range, placeholder.trim_start()));
+            }
+        }
+    }
+
+    if kind == TEMPLATE_LITERAL_KIND
+        && config
+            .template_literal_threshold
+            .is_some_and(|max| node.end_byte() - node.start_byte() > max)
     {
-        let start = body.start_byte();
-        let end = body.end_byte();
-        replacements.insert((start, end), " {...}");
+        replacements.push((
+            (node.start_byte(), node.end_byte()),
+            TEMPLATE_LITERAL_PLACEHOLDER,
+        ));
+        return Ok(());
     }
 
     // Recursively process children with incremented depth
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        collect_body_replacements(child, node_types, replacements, depth + 1)?;
+        collect_body_replacements(
+            child,
+            source,
+            node_types,
+            language,
+            config,
+            replacements,
+            depth + 1,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Replacement for elided statements in a summarized `__init__` body.
+pub(crate) const INIT_ELISION_PLACEHOLDER: &str = "...";
+
+/// Summarize a Python `__init__` body down to its instance attributes.
+///
+/// Python declares instance attributes by assigning them in `__init__`, so
+/// replacing the whole body (as for other functions) loses the class's
+/// fields. Instead, `self.x = ...` statements directly in the body are kept,
+/// each run of other statements becomes `...`, and multi-line right-hand
+/// sides become `...`. Returns `None` (strip the body as usual) for anything
+/// other than an `__init__` that assigns at least one attribute.
+pub(crate) fn init_attribute_replacements(
+    function: Node,
+    body: Node,
+    source: &str,
+) -> Option<Vec<((usize, usize), &'static str)>> {
+    if !is_python_init(function, source) {
+        return None;
+    }
+    let mut cursor = body.walk();
+    let statements: Vec<Node> = body.named_children(&mut cursor).collect();
+    if !statements
+        .iter()
+        .any(|s| self_attribute_assignment(*s, source).is_some())
+    {
+        return None;
+    }
+
+    let mut replacements = Vec::new();
+    let mut run: Option<(usize, usize)> = None;
+    for statement in statements {
+        match self_attribute_assignment(statement, source) {
+            Some(assignment) => {
+                replacements.extend(run.take().map(|r| (r, INIT_ELISION_PLACEHOLDER)));
+                if let Some(value) = assignment.child_by_field_name("right")
+                    && value.start_position().row != value.end_position().row
+                {
+                    replacements.push((
+                        (value.start_byte(), value.end_byte()),
+                        INIT_ELISION_PLACEHOLDER,
+                    ));
+                }
+            }
+            None => {
+                let start = run.map_or(statement.start_byte(), |(start, _)| start);
+                run = Some((start, statement.end_byte()));
+            }
+        }
+    }
+    replacements.extend(run.map(|r| (r, INIT_ELISION_PLACEHOLDER)));
+    Some(replacements)
+}
+
+/// Replacement for stripped function bodies.
+pub(crate) const BODY_PLACEHOLDER: &str = " {...}";
+
+/// Replacement for stripped Rust function bodies with `--todo-bodies`: a body
+/// that type-checks for any return type, so the skeleton still compiles.
+pub(crate) const TODO_BODY_PLACEHOLDER: &str = " { todo!() }";
+
+/// Body replacement text for `language` under `config`.
+pub(crate) fn body_placeholder(language: Language, config: &TransformConfig) -> &'static str {
+    if config.todo_bodies && language == Language::Rust {
+        TODO_BODY_PLACEHOLDER
+    } else {
+        BODY_PLACEHOLDER
+    }
+}
+
+/// Replacement for the statements elided from a body with
+/// `--body-statements`.
+pub(crate) const ELIDED_STATEMENTS_PLACEHOLDER: &str = "...";
+
+/// Replacement for the elided statements of a Rust body with `--todo-bodies`.
+pub(crate) const TODO_STATEMENTS_PLACEHOLDER: &str = "todo!()";
+
+/// Elided-statements replacement text for `language` under `config`.
+fn elision_placeholder(language: Language, config: &TransformConfig) -> &'static str {
+    if config.todo_bodies && language == Language::Rust {
+        TODO_STATEMENTS_PLACEHOLDER
+    } else {
+        ELIDED_STATEMENTS_PLACEHOLDER
+    }
+}
+
+/// Body kinds whose named children are the body's statements. Some grammars
+/// nest the list one level deeper (see [`body_statement_list`]).
+const STATEMENT_LIST_KINDS: &[&str] = &[
+    "block",
+    "statement_block",
+    "compound_statement",
+    "constructor_body",
+    "body_statement",
+    "do_block",
+    "statement_list",
+    "statements",
+];
+
+/// The node holding the statements of function body `body`, or `None` for
+/// an expression body (Kotlin `= expr`, OCaml).
+///
+/// Go wraps its statements in `block > statement_list`; Swift, Kotlin and
+/// Dart in `function_body > statements`/`block`.
+fn body_statement_list(body: Node) -> Option<Node> {
+    let mut list = body;
+    while list.named_child_count() == 1
+        && let Some(inner) = list.named_child(0)
+        && STATEMENT_LIST_KINDS.contains(&inner.kind())
+        && (!STATEMENT_LIST_KINDS.contains(&list.kind())
+            || matches!(inner.kind(), "statement_list" | "statements"))
+    {
+        list = inner;
+    }
+    STATEMENT_LIST_KINDS.contains(&list.kind()).then_some(list)
+}
+
+/// Byte range of the statements after the first `keep` (at least one) of a
+/// statement list's named `children`, or `None` when there are no more than
+/// `keep`. Comments do not count as statements, and one on the last kept
+/// statement's line stays with it. The range runs from the next node to the
+/// end of the list, so the body keeps its indentation and closing line.
+fn elided_statements(children: &[Node], keep: usize) -> Option<(usize, usize)> {
+    let mut statements = children
+        .iter()
+        .enumerate()
+        .filter(|(_, child)| !child.kind().contains("comment"))
+        .map(|(idx, _)| idx);
+    let last_kept = statements.nth(keep.saturating_sub(1))?;
+    let first_elided = statements.next()?;
+    let start = children[last_kept + 1..first_elided]
+        .iter()
+        .find(|comment| comment.start_position().row != children[last_kept].end_position().row)
+        .unwrap_or(&children[first_elided]);
+    Some((start.start_byte(), children.last()?.end_byte()))
+}
+
+/// Tree-sitter node kind for JS/TS template literals (`` `...${expr}...` ``).
+pub(crate) const TEMPLATE_LITERAL_KIND: &str = "template_string";
+
+/// Replacement for collapsed template literals. Contains no newline, preserving
+/// the line-map invariant documented on `compute_source_line_map_from_offset_map`.
+pub(crate) const TEMPLATE_LITERAL_PLACEHOLDER: &str = "`...`";
+
+/// Blocks spanning more lines than this are collapsed by
+/// [`collect_long_block_replacements`].
+pub(crate) const MAX_KEPT_BLOCK_LINES: usize = 8;
+
+/// Replacement for the inside of a collapsed block. Contains no newline, so the
+/// header and closing keyword end up on one line (`if ...; then ... fi`).
+pub(crate) const LONG_BLOCK_PLACEHOLDER: &str = " ... ";
+
+/// Control-flow blocks collapsed when long, as `(kind, keyword ending the
+/// header, closing keyword)`. Only languages whose logic lives mostly in
+/// top-level control flow (shell scripts) collapse blocks.
+fn get_collapsible_blocks(
+    language: Language,
+) -> &'static [(&'static str, &'static str, &'static str)] {
+    match language {
+        Language::Shell => &[
+            ("if_statement", "then", "fi"),
+            ("case_statement", "in", "esac"),
+        ],
+        _ => &[],
     }
+}
 
+/// A planned collapse of a long control-flow block.
+pub(crate) struct BlockCollapse {
+    /// Kind of the collapsed block.
+    pub(crate) node_kind: &'static str,
+    /// 1-indexed source line where the block starts.
+    pub(crate) line: usize,
+    /// Byte range replaced: from the end of the header to the closing keyword.
+    pub(crate) byte_range: (usize, usize),
+}
+
+/// Plan collapses for control-flow blocks spanning more than
+/// [`MAX_KEPT_BLOCK_LINES`] lines.
+///
+/// The header (`if [ -f "$f" ]; then`, `case "$1" in`) and the closing
+/// keyword are kept; everything between them becomes
+/// [`LONG_BLOCK_PLACEHOLDER`]. Only the outermost long block is planned.
+/// Blocks inside function bodies are planned too and then dropped by overlap
+/// resolution, since the body replacement starts first.
+pub(crate) fn collect_long_block_replacements(
+    root: Node,
+    language: Language,
+) -> Result<Vec<BlockCollapse>> {
+    let blocks = get_collapsible_blocks(language);
+    let mut collapses = Vec::new();
+    if !blocks.is_empty() {
+        collect_long_blocks(root, blocks, &mut collapses, 0)?;
+    }
+    Ok(collapses)
+}
+
+fn collect_long_blocks(
+    node: Node,
+    blocks: &[(&'static str, &'static str, &'static str)],
+    collapses: &mut Vec<BlockCollapse>,
+    depth: usize,
+) -> Result<()> {
+    // SECURITY: Prevent stack overflow from deeply nested AST
+    if depth > MAX_AST_DEPTH {
+        return Err(SkimError::ParseError(format!(
+            "Maximum AST depth exceeded: {}",
+            MAX_AST_DEPTH
+        )));
+    }
+
+    let kind = node.kind();
+    if let Some(&(node_kind, open, close)) = blocks.iter().find(|(k, _, _)| *k == kind)
+        && node.end_position().row - node.start_position().row >= MAX_KEPT_BLOCK_LINES
+    {
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+        let header_end = children.iter().find(|c| c.kind() == open);
+        let closing = children.iter().rev().find(|c| c.kind() == close);
+        if let (Some(header_end), Some(closing)) = (header_end, closing)
+            && header_end.end_byte() < closing.start_byte()
+        {
+            collapses.push(BlockCollapse {
+                node_kind,
+                line: node.start_position().row + 1,
+                byte_range: (header_end.end_byte(), closing.start_byte()),
+            });
+            return Ok(());
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_long_blocks(child, blocks, collapses, depth + 1)?;
+    }
     Ok(())
 }
 
-/// Check if node kind matches a function/method/constructor
-fn matches_function_node(kind: &str, node_types: &NodeTypes) -> bool {
+/// Check if node matches a function/method/constructor
+pub(crate) fn matches_function_node(node: Node, source: &str, node_types: &NodeTypes) -> bool {
+    let kind = node.kind();
     kind == node_types.function
         || kind == node_types.method
         || kind == "arrow_function"
         || kind == "function_expression"
         || node_types.extra_function_kinds.contains(&kind)
+        || is_definition_call(node, source, node_types.definition_calls)
 }
 
 /// Find the body node of a function/method
 ///
 /// Delegates to shared `find_body_child` in utils.rs.
-fn find_body_node(node: Node) -> Option<Node> {
+pub(crate) fn find_body_node(node: Node) -> Option<Node> {
     crate::transform::utils::find_body_child(node)
 }
 
 /// Type alias: structure mode reuses the shared FunctionNodeTypes struct from utils.
 /// This avoids renaming all usages within the module while making the shared origin clear.
-type NodeTypes = FunctionNodeTypes;
+pub(crate) type NodeTypes = FunctionNodeTypes;
 
 /// Get node types based on language
 ///
 /// Returns None for languages that don't use tree-sitter node types (e.g., JSON).
 /// ARCHITECTURE: JSON is handled by the Strategy Pattern in Language::transform_source(),
 /// which calls json::transform_json() directly instead of using tree-sitter parsing.
-fn get_node_types_for_language(language: Language) -> Option<NodeTypes> {
+pub(crate) fn get_node_types_for_language(language: Language) -> Option<NodeTypes> {
     match language {
-        Language::TypeScript | Language::JavaScript => Some(NodeTypes {
+        Language::TypeScript | Language::Tsx | Language::JavaScript => Some(NodeTypes {
             function: "function_declaration",
             method: "method_definition",
-            extra_function_kinds: &[],
+            extra_function_kinds: &["generator_function_declaration", "generator_function"],
+            definition_calls: &[],
         }),
         Language::Python => Some(NodeTypes {
             function: "function_definition",
             method: "function_definition",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Rust => Some(NodeTypes {
             function: "function_item",
             method: "function_item",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Go => Some(NodeTypes {
             function: "function_declaration",
             method: "method_declaration",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Java => Some(NodeTypes {
             function: "method_declaration",
             method: "method_declaration",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         // Unreachable: Markdown returns early via extract_markdown_headers_with_spans
         Language::Markdown => Some(NodeTypes {
             function: "atx_heading",
             method: "atx_heading",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::C | Language::Cpp => Some(NodeTypes {
             function: "function_definition",
             method: "function_definition",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::CSharp => Some(NodeTypes {
             function: "method_declaration",
             method: "constructor_declaration",
-            extra_function_kinds: &[],
+            extra_function_kinds: &[
+                "destructor_declaration",
+                "operator_declaration",
+                "conversion_operator_declaration",
+            ],
+            definition_calls: &[],
         }),
         Language::Ruby => Some(NodeTypes {
             function: "method",
             method: "singleton_method",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         // ARCHITECTURE: SQL maps both function and method to "statement" because
         // SQL is a declarative language where all top-level constructs are statements
@@ -398,18 +1025,92 @@ fn get_node_types_for_language(language: Language) -> Option<NodeTypes> {
             function: "statement",
             method: "statement",
             extra_function_kinds: &[],
+            definition_calls: &[],
         }),
         Language::Kotlin => Some(NodeTypes {
             function: "function_declaration",
             method: "function_declaration", // Kotlin doesn't distinguish methods from functions
             extra_function_kinds: &["secondary_constructor", "anonymous_initializer"],
+            definition_calls: &[],
         }),
         Language::Swift => Some(NodeTypes {
             function: "function_declaration",
             method: "function_declaration", // Swift methods are also function_declaration
             extra_function_kinds: &["init_declaration", "deinit_declaration"],
+            definition_calls: &[],
+        }),
+        Language::Shell => Some(NodeTypes {
+            function: "function_definition",
+            method: "function_definition",
+            extra_function_kinds: &[],
+            definition_calls: &[],
+        }),
+        // Elixir definitions are macro calls (`def`, `defp`, ...), matched by
+        // target name rather than node kind.
+        Language::Elixir => Some(NodeTypes {
+            function: "",
+            method: "",
+            extra_function_kinds: &[],
+            definition_calls: ELIXIR_DEFINITION_CALLS,
+        }),
+        // Dart bodies are siblings of their signature (see `find_body_child`).
+        // Signatures nested in a `method_signature` have no sibling body, so
+        // only the outer node is replaced.
+        Language::Dart => Some(NodeTypes {
+            function: "function_signature",
+            method: "method_signature",
+            extra_function_kinds: &["getter_signature", "setter_signature"],
+            definition_calls: &[],
+        }),
+        // Modifiers are left whole: their `require` checks are the access
+        // control an auditor needs to see.
+        Language::Solidity => Some(NodeTypes {
+            function: "function_definition",
+            method: "constructor_definition",
+            extra_function_kinds: &["fallback_receive_definition"],
+            definition_calls: &[],
+        }),
+        // @interface and @protocol blocks hold only declarations and are kept
+        // as written; method bodies live in @implementation.
+        Language::ObjectiveC => Some(NodeTypes {
+            function: "function_definition",
+            method: "method_definition",
+            extra_function_kinds: &[],
+            definition_calls: &[],
+        }),
+        // Julia definitions have no body node; see `end_terminated_body`.
+        // One-line `f(x) = ...` definitions are kept as written.
+        Language::Julia => Some(NodeTypes {
+            function: "function_definition",
+            method: "function_definition",
+            extra_function_kinds: &["macro_definition"],
+            definition_calls: &[],
+        }),
+        // `let` bindings with parameters (or bound to `fun`/`function`) and
+        // class methods lose their body; plain values are kept. See
+        // `ocaml_function_body`.
+        Language::Ocaml => Some(NodeTypes {
+            function: "let_binding",
+            method: "method_definition",
+            extra_function_kinds: &[],
+            definition_calls: &[],
         }),
-        Language::Json | Language::Yaml | Language::Toml => None,
+        Language::Json
+        | Language::Yaml
+        | Language::Toml
+        | Language::OcamlInterface
+        | Language::Vue
+        | Language::Svelte
+        | Language::Xml
+        | Language::Html
+        | Language::Hcl
+        | Language::Dockerfile
+        | Language::Groovy
+        | Language::Csv
+        | Language::Env
+        | Language::Ini
+        | Language::Rst
+        | Language::AsciiDoc => None,
     }
 }
 
@@ -490,7 +1191,7 @@ pub(crate) fn extract_markdown_headers(
     max_level: u32,
 ) -> Result<String> {
     let (text, _spans, _line_map) =
-        extract_markdown_headers_with_spans(source, tree, min_level, max_level)?;
+        extract_markdown_headers_with_spans(source, tree, min_level, max_level, None)?;
     Ok(text)
 }
 
@@ -513,9 +1214,9 @@ pub(crate) fn extract_markdown_headers_with_spans(
     tree: &Tree,
     min_level: u32,
     max_level: u32,
+    fenced_code_mode: Option<Mode>,
 ) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
-    // Headers: (text, node_kind, source_start_line_1indexed)
-    let mut headers: Vec<(String, &'static str, usize)> = Vec::new();
+    let mut entries: Vec<MarkdownEntry> = Vec::new();
     let root = tree.root_node();
 
     let mut visit_stack = vec![(0_usize, root)];
@@ -530,10 +1231,10 @@ pub(crate) fn extract_markdown_headers_with_spans(
 
         // Markdown-header count over the cap: a legitimate but very large document.
         // Signal a complexity limit so the dispatcher degrades to passthrough. (#317)
-        if headers.len() > MAX_MARKDOWN_HEADERS {
+        if entries.len() > MAX_MARKDOWN_HEADERS {
             return Err(SkimError::ComplexityLimit {
                 what: "markdown headers",
-                count: headers.len(),
+                count: entries.len(),
                 max: MAX_MARKDOWN_HEADERS,
             });
         }
@@ -559,7 +1260,11 @@ pub(crate) fn extract_markdown_headers_with_spans(
                         SkimError::ParseError(format!("UTF-8 error in header: {}", e))
                     })?;
                     let source_start_line = node.start_position().row + 1;
-                    headers.push((header_text.to_string(), "atx_heading", source_start_line));
+                    entries.push(MarkdownEntry::heading(
+                        header_text.to_string(),
+                        "atx_heading",
+                        source_start_line,
+                    ));
                 }
             }
         } else if node_type == "setext_heading" {
@@ -584,8 +1289,20 @@ pub(crate) fn extract_markdown_headers_with_spans(
                     SkimError::ParseError(format!("UTF-8 error in setext header: {}", e))
                 })?;
                 let source_start_line = node.start_position().row + 1;
-                headers.push((header_text.to_string(), "setext_heading", source_start_line));
+                entries.push(MarkdownEntry::heading(
+                    header_text.to_string(),
+                    "setext_heading",
+                    source_start_line,
+                ));
+            }
+        } else if node_type == "fenced_code_block" {
+            if let Some(mode) = fenced_code_mode
+                && let Some(entry) = skim_fenced_code_block(node, source, mode)
+            {
+                entries.push(entry);
             }
+            // Fenced blocks contain no headings; skip descending into them.
+            continue;
         }
 
         let mut child_cursor = node.walk();
@@ -594,42 +1311,115 @@ pub(crate) fn extract_markdown_headers_with_spans(
         }
     }
 
-    // Sort headers into document order (ascending source start line).
+    // Sort entries into document order (ascending source start line).
     // The LIFO visit_stack produces children in reverse sibling order, so without
     // this sort siblings appear reversed in output.  Stable sort ensures that
     // setext headings (which share a start line with their underline) remain in
     // insertion order when source lines are equal (in practice, distinct headers
     // always have distinct start lines).
     // A1 invariant: output is now in document order; texts/spans/source_line_map
-    // are all derived from the sorted slice so they remain consistent.
-    headers.sort_by_key(|h| h.2);
+    // are all derived from the sorted slice so they remain consistent. Fenced
+    // code entries sort in alongside headings, so they land under their section.
+    entries.sort_by_key(|e| e.source_start_line);
 
     // Build text, spans, and source line map
-    let mut spans = Vec::with_capacity(headers.len());
+    let mut spans = Vec::with_capacity(entries.len());
     let mut source_line_map: Vec<usize> = Vec::new();
     let mut current_output_line = 0;
 
-    let texts: Vec<String> = headers
+    let texts: Vec<String> = entries
         .into_iter()
-        .map(|(text, kind, source_start_line)| {
-            let line_count = text.lines().count().max(1);
+        .map(|entry| {
+            let line_count = entry.text.lines().count().max(1);
             spans.push(NodeSpan::new(
                 current_output_line..current_output_line + line_count,
-                kind,
+                entry.kind,
             ));
-            // Map each output line to consecutive source lines from source_start_line.
-            // ATX headings are always 1 line; setext headings span 2 lines (text + underline).
             for i in 0..line_count {
-                source_line_map.push(source_start_line + i);
+                source_line_map.push(entry.source_line_for(i));
             }
             current_output_line += line_count;
-            text
+            entry.text
         })
         .collect();
 
     Ok((texts.join("\n"), spans, source_line_map))
 }
 
+/// One extracted Markdown element: a heading, or a skimmed fenced code block.
+struct MarkdownEntry {
+    text: String,
+    kind: &'static str,
+    /// 1-indexed source line of the element's first line.
+    source_start_line: usize,
+    /// Whether output lines map 1:1 onto consecutive source lines.
+    ///
+    /// True for headings (ATX headings are 1 line; setext headings span 2 lines:
+    /// text + underline). False for skimmed code, whose body lines no longer
+    /// correspond to source lines — only the opening fence is annotated.
+    contiguous: bool,
+}
+
+impl MarkdownEntry {
+    fn heading(text: String, kind: &'static str, source_start_line: usize) -> Self {
+        Self {
+            text,
+            kind,
+            source_start_line,
+            contiguous: true,
+        }
+    }
+
+    /// Source line annotation for the `i`-th output line of this entry (0 = none).
+    fn source_line_for(&self, i: usize) -> usize {
+        if self.contiguous || i == 0 {
+            self.source_start_line + i
+        } else {
+            0
+        }
+    }
+}
+
+/// Skim a fenced code block tagged with a supported language.
+///
+/// Returns `None` for untagged blocks, unknown or Markdown tags (no recursion),
+/// and blocks whose contents fail to transform or skim down to nothing — an
+/// example snippet that cannot be skimmed is omitted exactly as it would be
+/// without the option, rather than failing the whole document.
+fn skim_fenced_code_block(node: Node, source: &str, mode: Mode) -> Option<MarkdownEntry> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+
+    let tag = children
+        .iter()
+        .find(|c| c.kind() == "info_string")
+        .and_then(|info| info.utf8_text(source.as_bytes()).ok())
+        .and_then(|info| info.split_whitespace().next())
+        .map(|tag| tag.trim_matches(|c| matches!(c, '{' | '}' | '.')))?;
+
+    let language = Language::from_fence_tag(tag).filter(|l| *l != Language::Markdown)?;
+
+    let code = children
+        .iter()
+        .find(|c| c.kind() == "code_fence_content")
+        .and_then(|content| content.utf8_text(source.as_bytes()).ok())?;
+
+    let (skimmed, _has_errors) = language
+        .transform_source(code, &TransformConfig::with_mode(mode))
+        .ok()?;
+    let skimmed = skimmed.trim_matches('\n');
+    if skimmed.trim().is_empty() {
+        return None;
+    }
+
+    Some(MarkdownEntry {
+        text: format!("```{tag}\n{skimmed}\n```"),
+        kind: "fenced_code_block",
+        source_start_line: node.start_position().row + 1,
+        contiguous: false,
+    })
+}
+
 // ============================================================================
 // Unit tests for compute_source_line_map_from_offset_map
 // ============================================================================
@@ -935,7 +1725,7 @@ mod offset_map_tests {
 #[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
 mod markdown_line_map_tests {
     use super::extract_markdown_headers_with_spans;
-    use crate::{Language, Parser};
+    use crate::{Language, Mode, Parser};
 
     /// Parse markdown with tree-sitter and return the source line map from
     /// `extract_markdown_headers_with_spans`.
@@ -943,7 +1733,7 @@ mod markdown_line_map_tests {
         let mut parser = Parser::new(Language::Markdown).unwrap();
         let tree = parser.parse(source).unwrap();
         let (_text, _spans, line_map) =
-            extract_markdown_headers_with_spans(source, &tree, 1, 6).unwrap();
+            extract_markdown_headers_with_spans(source, &tree, 1, 6, None).unwrap();
         line_map
     }
 
@@ -1052,7 +1842,7 @@ mod markdown_line_map_tests {
         let mut parser = crate::Parser::new(Language::Markdown).unwrap();
         let tree = parser.parse(source).unwrap();
         let (text, _spans, line_map) =
-            extract_markdown_headers_with_spans(source, &tree, 1, 6).unwrap();
+            extract_markdown_headers_with_spans(source, &tree, 1, 6, None).unwrap();
 
         // All headings must appear in the text
         assert!(text.contains("Alpha"), "Alpha missing from output: {text}");
@@ -1129,4 +1919,59 @@ mod markdown_line_map_tests {
             line_map[0]
         );
     }
+
+    // ========================================================================
+    // Fenced code blocks
+    // ========================================================================
+
+    fn extract_with_fenced_code(source: &str) -> (String, Vec<usize>) {
+        let mut parser = Parser::new(Language::Markdown).unwrap();
+        let tree = parser.parse(source).unwrap();
+        let (text, _spans, line_map) =
+            extract_markdown_headers_with_spans(source, &tree, 1, 6, Some(Mode::Signatures))
+                .unwrap();
+        (text, line_map)
+    }
+
+    #[test]
+    fn test_markdown_fenced_code_skimmed_under_heading() {
+        let source = "# Api\n\n```rust\npub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n```\n\
+                      \n# Next\n";
+        let (text, line_map) = extract_with_fenced_code(source);
+
+        assert!(text.contains("```rust\n"), "got:\n{text}");
+        assert!(
+            text.contains("pub fn add(a: i32, b: i32) -> i32"),
+            "got:\n{text}"
+        );
+        assert!(
+            !text.contains("a + b"),
+            "body should be skimmed, got:\n{text}"
+        );
+
+        let api = text.find("# Api").unwrap();
+        let fence = text.find("```rust").unwrap();
+        let next = text.find("# Next").unwrap();
+        assert!(api < fence && fence < next, "got:\n{text}");
+
+        // Opening fence maps to its source line; skimmed body lines are unannotated.
+        assert_eq!(line_map[0], 1);
+        assert_eq!(line_map[1], 3);
+        assert_eq!(line_map[2], 0);
+        assert_eq!(*line_map.last().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_markdown_fenced_code_untagged_and_unknown_dropped() {
+        let source = "# Doc\n\n```\nplain\n```\n\n```brainfuck\n+++\n```\n";
+        let (text, _) = extract_with_fenced_code(source);
+        assert_eq!(text.trim_end(), "# Doc");
+    }
+
+    #[test]
+    fn test_markdown_fenced_code_disabled_by_default() {
+        let source = "# Doc\n\n```python\ndef f():\n    return 1\n```\n";
+        let line_map = parse_and_extract_line_map(source);
+        assert_eq!(line_map, vec![1]);
+    }
 }
@@ -0,0 +1,152 @@
+//! Summary mode - per-file counts instead of content
+//!
+//! ARCHITECTURE: Every other mode prints some part of the file. Summary mode
+//! prints one line of numbers about it, so a first pass over a large tree
+//! costs a few tokens per file and shows where the code (and the token cost)
+//! is before anything is skimmed in detail:
+//!
+//! ```text
+//! lines: 120, functions: 8, classes: 2, types: 3, tokens: ~1450
+//! ```
+//!
+//! Counts come from [`crate::symbols`], nested declarations included:
+//! functions and methods (C/C++ prototypes too), classes (structs, records,
+//! Kotlin objects), and other type definitions (interfaces, traits, enums,
+//! unions, aliases). Data and markup formats have no declarations and report
+//! only lines and tokens; Vue and Svelte components count the declarations
+//! of their `<script>` blocks, and Groovy the types and methods its scanner
+//! finds. The token count is an estimate from the byte length (see
+//! [`Language::bytes_per_token`]), not a tokenizer run.
+
+use super::sfc;
+use super::truncate::NodeSpan;
+use super::utils::node_kind_info;
+use crate::symbols::{Symbol, symbols_in_tree};
+use crate::types::LineMappedOutput;
+use crate::{Language, Result, TransformConfig};
+use tree_sitter::Tree;
+
+/// Declaration kinds counted as classes rather than types.
+pub(crate) const CLASS_KINDS: &[&str] = &[
+    "class_declaration",
+    "abstract_class_declaration",
+    "class_definition",
+    "class_specifier",
+    "class",
+    "record_declaration",
+    "object_declaration",
+    "struct_item",
+    "struct_specifier",
+    "struct_declaration",
+    "contract_declaration",
+    "class_interface",
+    "struct_definition",
+];
+
+/// Transform to summary mode, returning text and a single file span.
+pub(crate) fn transform_summary_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_summary_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to summary mode; the counts line maps to no source line (`0`).
+pub(crate) fn transform_summary_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    _config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let symbols = symbols_in_tree(source, tree, language)?;
+    Ok((
+        summarize(source, language, &symbols),
+        vec![NodeSpan::new(0..1, "source_file")],
+        vec![0],
+    ))
+}
+
+/// Summary mode for languages skim does not parse with tree-sitter.
+pub(crate) fn transform_summary_unparsed(
+    source: &str,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<LineMappedOutput> {
+    let symbols = match language {
+        Language::Vue | Language::Svelte => sfc::script_symbols(source)?,
+        Language::Groovy => super::groovy::symbols(source)?,
+        _ => Vec::new(),
+    };
+    let line_map = config.line_numbers.then(|| vec![0]);
+    Ok((
+        summarize(source, language, &symbols),
+        false,
+        line_map,
+        false,
+    ))
+}
+
+/// The counts line for `source`.
+fn summarize(source: &str, language: Language, symbols: &[Symbol]) -> String {
+    let (mut functions, mut classes, mut types) = (0, 0, 0);
+    for symbol in symbols {
+        if CLASS_KINDS.contains(&symbol.kind) {
+            classes += 1;
+        } else {
+            match node_kind_info(symbol.kind).1 {
+                5 => types += 1,
+                4 => functions += 1,
+                // Member prototypes (`int area() const;`) are functions too.
+                _ if symbol.kind == "field_declaration" => functions += 1,
+                _ => {}
+            }
+        }
+    }
+    let tokens = (source.len() as f64 / language.bytes_per_token()).ceil() as usize;
+    format!(
+        "lines: {}, functions: {functions}, classes: {classes}, types: {types}, tokens: ~{tokens}\n",
+        source.lines().count()
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use crate::{Language, Mode, transform};
+
+    fn summary(source: &str, language: Language) -> String {
+        transform(source, language, Mode::Summary).unwrap()
+    }
+
+    #[test]
+    fn test_rust_counts_functions_classes_and_types() {
+        let source = "pub struct Config;\n\npub enum Kind { A }\n\ntrait Load {}\n\n\
+                      impl Config {\n    fn new() -> Self { Config }\n}\n\nfn main() {}\n";
+        assert_eq!(
+            summary(source, Language::Rust),
+            "lines: 11, functions: 2, classes: 1, types: 2, tokens: ~34\n"
+        );
+    }
+
+    #[test]
+    fn test_data_formats_report_lines_and_tokens() {
+        assert_eq!(
+            summary("{\"name\": \"skim\"}\n", Language::Json),
+            "lines: 1, functions: 0, classes: 0, types: 0, tokens: ~6\n"
+        );
+    }
+
+    #[test]
+    fn test_vue_counts_script_declarations() {
+        let source = "<template><p/></template>\n<script setup lang=\"ts\">\n\
+                      function load() {}\ninterface Props {}\n</script>\n";
+        assert!(
+            summary(source, Language::Vue)
+                .starts_with("lines: 5, functions: 1, classes: 0, types: 1,")
+        );
+    }
+}
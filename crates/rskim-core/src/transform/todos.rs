@@ -0,0 +1,160 @@
+//! Todos mode - TODO/FIXME/HACK/XXX markers with their owning symbols
+//!
+//! ARCHITECTURE: Comments mode prints every comment; todos mode keeps only
+//! the comment lines carrying a work marker, so a file (or, through the
+//! multi-file `// path` headers, a whole tree) reduces to a backlog digest.
+//!
+//! Each marker becomes one line: its 1-indexed source line, the comment text
+//! from the marker on, and the symbol the comment belongs to in parentheses:
+//!
+//! ```text
+//! 3: TODO: support YAML (Config)
+//! 9: FIXME(io): reject symlinks (Config.validate)
+//! 14: XXX this leaks
+//! ```
+//!
+//! Comments are grouped and attributed exactly as in comments mode (see
+//! [`super::comments`]): a comment block directly above a declaration belongs
+//! to it, any other to the innermost symbol enclosing it; file-level markers
+//! have no owner. Markers must be upper case and stand as whole words, so
+//! `todos` or `TODOLIST` do not count. Closing delimiters (`*/`, `-->`,
+//! `"""`) are trimmed from the text.
+
+use super::comments::{collect_comments, group_blocks, owner};
+use super::truncate::NodeSpan;
+use crate::symbols::symbols_in_tree;
+use crate::{Language, Result, TransformConfig};
+use tree_sitter::Tree;
+
+/// Words that mark a comment line as a work item.
+const MARKERS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
+/// Comment terminators left on a marker's line.
+const CLOSERS: &[&str] = &["*/", "-->", "\"\"\"", "'''", "=end"];
+
+/// Transform to todos mode, returning text and per-marker spans.
+pub(crate) fn transform_todos_with_spans(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>)> {
+    let (text, spans, _line_map) =
+        transform_todos_with_spans_and_line_map(source, tree, language, config)?;
+    Ok((text, spans))
+}
+
+/// Transform to todos mode, also mapping each output line to the source line
+/// of its marker.
+pub(crate) fn transform_todos_with_spans_and_line_map(
+    source: &str,
+    tree: &Tree,
+    language: Language,
+    _config: &TransformConfig,
+) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
+    let mut nodes = Vec::new();
+    collect_comments(tree.root_node(), language, &mut nodes, 0)?;
+    let blocks = group_blocks(&nodes, source);
+    let mut text = String::new();
+    let mut spans = Vec::new();
+    let mut line_map = Vec::new();
+    // Parsed only once a marker turns up: most comments carry none.
+    let mut symbols = None;
+    for block in &blocks {
+        let body = source
+            .get(block.first.start_byte()..block.last.end_byte())
+            .unwrap_or_default();
+        for (offset, line) in body.lines().enumerate() {
+            let Some(item) = marker_text(line) else {
+                continue;
+            };
+            let symbols = match &mut symbols {
+                Some(symbols) => symbols,
+                None => symbols.insert(symbols_in_tree(source, tree, language)?),
+            };
+            let row = block.first.start_position().row + offset;
+            match owner(block, source, symbols) {
+                Some(symbol) => text.push_str(&format!("{}: {item} ({})\n", row + 1, symbol.name)),
+                None => text.push_str(&format!("{}: {item}\n", row + 1)),
+            }
+            spans.push(NodeSpan::new(
+                line_map.len()..line_map.len() + 1,
+                block.first.kind(),
+            ));
+            line_map.push(row + 1);
+        }
+    }
+    Ok((text, spans, line_map))
+}
+
+/// The text of `line` from its first work marker on, without a trailing
+/// comment terminator.
+fn marker_text(line: &str) -> Option<&str> {
+    let start = MARKERS
+        .iter()
+        .filter_map(|marker| find_word(line, marker))
+        .min()?;
+    let mut item = line[start..].trim_end();
+    for closer in CLOSERS {
+        item = item.strip_suffix(closer).unwrap_or(item).trim_end();
+    }
+    Some(item)
+}
+
+/// Byte offset of the first occurrence of `word` in `line` that is not part
+/// of a longer identifier.
+fn find_word(line: &str, word: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    line.match_indices(word).map(|(at, _)| at).find(|&at| {
+        let before = line[..at].chars().next_back();
+        let after = line[at + word.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::marker_text;
+    use crate::{Language, Mode, transform};
+
+    fn todos(source: &str, language: Language) -> String {
+        transform(source, language, Mode::Todos).unwrap()
+    }
+
+    #[test]
+    fn test_rust_markers_name_their_owners() {
+        let source = "// TODO: split this file\n\n/// Configuration.\n/// FIXME(io): support YAML\n\
+                      pub struct Config;\n\nimpl Config {\n    fn validate(&self) {\n        \
+                      // first check\n        // HACK: skip symlinks\n        \
+                      let ok = true; /* XXX leaks */\n    }\n}\n";
+        assert_eq!(
+            todos(source, Language::Rust),
+            "1: TODO: split this file\n\
+             4: FIXME(io): support YAML (Config)\n\
+             10: HACK: skip symlinks (Config.validate)\n\
+             11: XXX leaks (Config.validate)\n"
+        );
+    }
+
+    #[test]
+    fn test_python_comments_and_docstrings() {
+        let source = "def load(path):\n    \"\"\"Load a file.\n\n    TODO: cache results\"\"\"\n    \
+                      return open(path)  # FIXME close the file\n";
+        assert_eq!(
+            todos(source, Language::Python),
+            "4: TODO: cache results (load)\n5: FIXME close the file (load)\n"
+        );
+    }
+
+    #[test]
+    fn test_markers_must_be_whole_words() {
+        assert_eq!(marker_text("// TODOLIST and todos"), None);
+        assert_eq!(marker_text("// see XXX_PATH"), None);
+        assert_eq!(marker_text("/* note: FIXME! */"), Some("FIXME!"));
+        assert_eq!(
+            todos("fn main() {\n    let s = \"TODO\";\n}\n", Language::Rust),
+            ""
+        );
+    }
+}
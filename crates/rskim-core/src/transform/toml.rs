@@ -25,6 +25,7 @@
 //! - Primitive arrays just show key name
 //! - Empty tables/arrays just show key name
 //! - Inline tables are expanded into nested key structure
+//! - `Cargo.toml` manifests → dependency, feature and bin names instead (see `manifest`)
 
 use crate::{Result, SkimError};
 use toml::Value;
@@ -47,6 +48,10 @@ pub(crate) fn transform_toml(source: &str) -> Result<String> {
         .parse::<Value>()
         .map_err(|e| SkimError::ParseError(format!("Invalid TOML: {}", e)))?;
 
+    if let Some(summary) = super::manifest::summarize_cargo_toml(&value)? {
+        return Ok(summary);
+    }
+
     let mut key_count = 0;
     extract_structure(&value, 0, &mut key_count)
 }
@@ -4,11 +4,17 @@
 //!
 //! Token reduction target: 90-95%
 
+use crate::transform::method_set::{METHOD_SET_KIND, MethodSet, go_method_sets};
 use crate::transform::minimal::MAX_AST_DEPTH;
+use crate::transform::pyi;
 use crate::transform::structure::extract_markdown_headers_with_spans;
 use crate::transform::truncate::NodeSpan;
-use crate::transform::utils::to_static_node_kind;
-use crate::{Language, Result, SkimError, TransformConfig};
+use crate::transform::utils::{
+    align_to_char_boundaries, find_body_child, is_python_init, module_doc_comment,
+    self_attribute_assignment, to_static_node_kind,
+};
+use crate::transform::visibility::PrivateFilter;
+use crate::{Language, Mode, Result, SkimError, TransformConfig};
 use tree_sitter::{Node, Tree};
 
 /// Maximum number of type definitions to prevent memory exhaustion
@@ -22,7 +28,7 @@ const MAX_TYPE_DEFS: usize = 10_000;
 /// - Interface declarations
 /// - Enum definitions
 /// - Struct definitions (Rust/Go)
-/// - Class declarations (name only, no methods)
+/// - Class declarations with their fields/properties (no methods)
 ///
 /// # What to Remove
 ///
@@ -73,14 +79,21 @@ pub(crate) fn transform_types_with_spans_and_line_map(
     source: &str,
     tree: &Tree,
     language: Language,
-    _config: &TransformConfig,
+    config: &TransformConfig,
 ) -> Result<(String, Vec<NodeSpan>, Vec<usize>)> {
     // ARCHITECTURE: Markdown types mode extracts ALL headers (H1-H6)
     if language == Language::Markdown {
-        let (text, spans, line_map) = extract_markdown_headers_with_spans(source, tree, 1, 6)?;
+        let fenced_code_mode = config.fenced_code.then_some(Mode::Types);
+        let (text, spans, line_map) =
+            extract_markdown_headers_with_spans(source, tree, 1, 6, fenced_code_mode)?;
         return Ok((text, spans, line_map));
     }
 
+    // ARCHITECTURE: `.pyi` stubs share one emitter with signatures mode
+    if language == Language::Python && config.python_stub {
+        return pyi::transform_python_stub(source, tree, config);
+    }
+
     // ARCHITECTURE: JSON is handled by Strategy Pattern in Language::transform_source()
     // and never reaches this code path.
     let node_types = get_type_node_types(language).ok_or_else(|| {
@@ -91,13 +104,28 @@ pub(crate) fn transform_types_with_spans_and_line_map(
     })?;
 
     let mut type_defs: Vec<(String, &'static str, usize)> = Vec::new();
+    let filter = config
+        .public_only
+        .then(|| PrivateFilter::new(tree.root_node(), source, language));
     collect_type_definitions_with_kinds_and_lines(
         tree.root_node(),
         source,
         &node_types,
+        filter.as_ref(),
         &mut type_defs,
         0,
     )?;
+    if language == Language::Go {
+        insert_method_sets(
+            &mut type_defs,
+            go_method_sets(tree.root_node(), source, filter.as_ref()),
+        );
+    }
+    if config.module_doc
+        && let Some((doc, line)) = module_doc_comment(tree.root_node(), source, language)
+    {
+        type_defs.insert(0, (doc.to_string(), "module_doc", line));
+    }
 
     // Type-def count over the cap: a legitimate but very large file, not an
     // attack. Signal a complexity limit so the dispatcher degrades to a lossless
@@ -126,9 +154,14 @@ pub(crate) fn transform_types_with_spans_and_line_map(
                 current_output_line..current_output_line + line_count,
                 kind,
             ));
-            // Map each output line to consecutive source lines from source_start_line
+            // Map each output line to consecutive source lines from source_start_line.
+            // Synthesized interfaces have no source lines.
             for i in 0..line_count {
-                source_line_map.push(source_start_line + i);
+                source_line_map.push(if kind == METHOD_SET_KIND {
+                    0
+                } else {
+                    source_start_line + i
+                });
             }
             current_output_line += line_count;
             // Account for the blank line separator between defs (\n\n → 1 extra blank line)
@@ -143,6 +176,26 @@ pub(crate) fn transform_types_with_spans_and_line_map(
     Ok((texts.join("\n\n"), spans, source_line_map))
 }
 
+/// Place each synthesized Go interface right after the `type` declaration
+/// holding its struct.
+fn insert_method_sets(
+    type_defs: &mut Vec<(String, &'static str, usize)>,
+    method_sets: Vec<MethodSet>,
+) {
+    if method_sets.is_empty() {
+        return;
+    }
+    let mut method_sets = method_sets.into_iter().peekable();
+    let mut merged = Vec::with_capacity(type_defs.len() + method_sets.len());
+    for (def, kind, line) in type_defs.drain(..) {
+        merged.push((def, kind, line));
+        while let Some(set) = method_sets.next_if(|set| set.declaration_line == line) {
+            merged.push((set.text, METHOD_SET_KIND, line));
+        }
+    }
+    *type_defs = merged;
+}
+
 /// Recursively collect type definitions with node kind AND source start line.
 ///
 /// The source start line is `node.start_position().row + 1` (1-indexed).
@@ -150,6 +203,7 @@ fn collect_type_definitions_with_kinds_and_lines(
     node: Node,
     source: &str,
     node_types: &TypeNodeTypes,
+    filter: Option<&PrivateFilter>,
     type_defs: &mut Vec<(String, &'static str, usize)>,
     depth: usize,
 ) -> Result<()> {
@@ -161,15 +215,20 @@ fn collect_type_definitions_with_kinds_and_lines(
         )));
     }
 
+    // `--public-only`: a private declaration hides nested types too.
+    if filter.is_some_and(|f| f.hides(node)) {
+        return Ok(());
+    }
+
     let kind = node.kind();
 
     if is_type_node(kind, node_types) {
-        // For C/C++ struct_specifier and enum_specifier, only extract actual definitions
+        // For C/C++ struct/union/enum specifiers, only extract actual definitions
         // (nodes with a body), not bare type references like `struct Point` in return types.
         if is_type_reference(kind, &node) {
             return Ok(());
         }
-        if let Some(type_def) = extract_type_definition(node, source, node_types)? {
+        if let Some(type_def) = extract_type_definition(node, source, node_types, filter)? {
             let static_kind = to_static_node_kind(kind);
             // 1-indexed source line where this type definition starts
             let source_start_line = node.start_position().row + 1;
@@ -184,6 +243,7 @@ fn collect_type_definitions_with_kinds_and_lines(
             child,
             source,
             node_types,
+            filter,
             type_defs,
             depth + 1,
         )?;
@@ -199,15 +259,25 @@ fn is_type_node(kind: &str, node_types: &TypeNodeTypes) -> bool {
         || kind == node_types.enum_def
         || kind == node_types.class_decl
         || kind == node_types.struct_def
+        || kind == node_types.union_def
+        || node_types.extra_class_kinds.contains(&kind)
+}
+
+/// Check if node is a class-like definition (body stripped, fields kept)
+fn is_class_like(kind: &str, node_types: &TypeNodeTypes) -> bool {
+    kind == node_types.class_decl || node_types.extra_class_kinds.contains(&kind)
 }
 
-/// Check if a C/C++ struct_specifier or enum_specifier is just a type
+/// Check if a C/C++ struct, union, or enum specifier is just a type
 /// reference (no body), not an actual definition. Only applies to these
 /// specific node kinds since they represent both definitions and references
 /// in C/C++ grammars. Other languages (Rust `enum_item`, TS `enum_declaration`)
 /// don't have this ambiguity.
 fn is_type_reference(kind: &str, node: &Node) -> bool {
-    if kind != "struct_specifier" && kind != "enum_specifier" {
+    if !matches!(
+        kind,
+        "struct_specifier" | "union_specifier" | "enum_specifier"
+    ) {
         return false;
     }
     // A definition has a body child (field_declaration_list, enumerator_list, etc.)
@@ -226,15 +296,18 @@ fn extract_type_definition(
     node: Node,
     source: &str,
     node_types: &TypeNodeTypes,
+    filter: Option<&PrivateFilter>,
 ) -> Result<Option<String>> {
     let start = node.start_byte();
     let mut end = node.end_byte();
+    let mut class_body = None;
 
-    // For classes, extract only the declaration (strip method bodies)
-    if node.kind() == node_types.class_decl {
+    // For classes, extract only the declaration and its fields (strip methods)
+    if is_class_like(node.kind(), node_types) {
         // Find class body and strip it
         if let Some(body_node) = find_class_body(node) {
             end = body_node.start_byte();
+            class_body = Some(body_node);
         }
     }
 
@@ -243,13 +316,8 @@ fn extract_type_definition(
         return Ok(None);
     }
 
-    // Validate UTF-8 boundaries
-    if !source.is_char_boundary(start) || !source.is_char_boundary(end) {
-        return Err(SkimError::ParseError(format!(
-            "Invalid UTF-8 boundary at type definition range [{}, {})",
-            start, end
-        )));
-    }
+    // Re-align to UTF-8 boundaries rather than failing the whole file
+    let (start, end) = align_to_char_boundaries(source, start, end);
 
     let type_def = source[start..end].trim();
 
@@ -258,9 +326,98 @@ fn extract_type_definition(
         return Ok(None);
     }
 
+    let fields = class_body
+        .map(|body| collect_class_fields(body, source, node_types, filter))
+        .unwrap_or_default();
+    if let Some(body) = class_body
+        && !fields.is_empty()
+    {
+        // Python class bodies are indented blocks; everything else uses braces.
+        let braced = body.kind() != "block";
+        let mut def = String::from(type_def);
+        if braced {
+            def.push_str(" {");
+        }
+        for field in fields {
+            def.push_str("\n    ");
+            def.push_str(&field);
+        }
+        if braced {
+            def.push_str("\n}");
+        }
+        return Ok(Some(def));
+    }
+
     Ok(Some(type_def.to_string()))
 }
 
+/// Collect the field and property declarations of a class body.
+///
+/// Keeps members whose kind is listed in `class_fields` (TS/JS class
+/// properties, Java fields, Python class-level assignments). Python instance
+/// attributes are declared by assignment in `__init__`, so its
+/// `self.x = ...` statements are summarized as fields too, with multi-line
+/// values elided. Under `--public-only`, private members are skipped.
+fn collect_class_fields(
+    body: Node,
+    source: &str,
+    node_types: &TypeNodeTypes,
+    filter: Option<&PrivateFilter>,
+) -> Vec<String> {
+    let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("").trim();
+    // TS and Dart leave the terminating `;` outside the field node.
+    let field_text = |node: Node| match node.next_sibling() {
+        Some(next) if next.kind() == ";" => source
+            .get(node.start_byte()..next.end_byte())
+            .unwrap_or("")
+            .trim(),
+        _ => text(node),
+    };
+    let mut fields = Vec::new();
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        if filter.is_some_and(|f| f.hides(member)) {
+            continue;
+        }
+        // Dart wraps every member in `class_member_definition`.
+        let member = if member.kind() == "class_member_definition" {
+            member.named_child(0).unwrap_or(member)
+        } else {
+            member
+        };
+        let kind = member.kind();
+        if node_types.class_fields.contains(&kind) {
+            // Python docstrings and bare calls are expression statements too.
+            let is_field = kind != "expression_statement"
+                || member
+                    .named_child(0)
+                    .is_some_and(|expr| expr.kind() == "assignment");
+            if is_field {
+                fields.push(field_text(member).to_string());
+            }
+        } else if is_python_init(member, source)
+            && let Some(init_body) = find_body_child(member)
+        {
+            let mut init_cursor = init_body.walk();
+            for statement in init_body.named_children(&mut init_cursor) {
+                let Some(assignment) = self_attribute_assignment(statement, source) else {
+                    continue;
+                };
+                match assignment.child_by_field_name("right") {
+                    Some(value) if value.start_position().row != value.end_position().row => {
+                        let prefix = source
+                            .get(assignment.start_byte()..value.start_byte())
+                            .unwrap_or("");
+                        fields.push(format!("{prefix}..."));
+                    }
+                    _ => fields.push(text(assignment).to_string()),
+                }
+            }
+        }
+    }
+    fields
+}
+
 /// Find class body node
 fn find_class_body(node: Node) -> Option<Node> {
     let mut cursor = node.walk();
@@ -272,7 +429,9 @@ fn find_class_body(node: Node) -> Option<Node> {
             | "field_declaration_list"
             | "body_statement"
             | "enum_class_body"
-            | "protocol_body" => return Some(child),
+            | "protocol_body"
+            | "extension_body"
+            | "contract_body" => return Some(child),
             _ => continue,
         }
     }
@@ -286,6 +445,11 @@ struct TypeNodeTypes {
     enum_def: &'static str,
     class_decl: &'static str,
     struct_def: &'static str,
+    union_def: &'static str,
+    /// Class body members kept under the class header (fields/properties).
+    class_fields: &'static [&'static str],
+    /// Additional class-like kinds, extracted like `class_decl` (C# records).
+    extra_class_kinds: &'static [&'static str],
 }
 
 /// Get type node types for language
@@ -294,12 +458,15 @@ struct TypeNodeTypes {
 /// ARCHITECTURE: JSON is handled by the Strategy Pattern in Language::transform_source().
 fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
     match language {
-        Language::TypeScript => Some(TypeNodeTypes {
+        Language::TypeScript | Language::Tsx => Some(TypeNodeTypes {
             type_alias: "type_alias_declaration",
             interface: "interface_declaration",
             enum_def: "enum_declaration",
             class_decl: "class_declaration",
             struct_def: "", // Not applicable
+            union_def: "",
+            class_fields: &["public_field_definition"],
+            extra_class_kinds: &[],
         }),
         Language::JavaScript => Some(TypeNodeTypes {
             type_alias: "",
@@ -307,6 +474,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "",
             class_decl: "class_declaration",
             struct_def: "",
+            union_def: "",
+            class_fields: &["field_definition"],
+            extra_class_kinds: &[],
         }),
         Language::Python => Some(TypeNodeTypes {
             type_alias: "type_alias_statement",
@@ -314,6 +484,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "",
             class_decl: "class_definition",
             struct_def: "",
+            union_def: "",
+            class_fields: &["expression_statement"],
+            extra_class_kinds: &[],
         }),
         Language::Rust => Some(TypeNodeTypes {
             type_alias: "type_item",
@@ -321,6 +494,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "enum_item",
             class_decl: "",
             struct_def: "struct_item",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         Language::Go => Some(TypeNodeTypes {
             type_alias: "type_declaration",
@@ -328,6 +504,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "",
             class_decl: "",
             struct_def: "struct_type",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         Language::Java => Some(TypeNodeTypes {
             type_alias: "",
@@ -335,6 +514,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "enum_declaration",
             class_decl: "class_declaration",
             struct_def: "",
+            union_def: "",
+            class_fields: &["field_declaration"],
+            extra_class_kinds: &[],
         }),
         // Unreachable: Markdown returns early via extract_markdown_headers_with_spans
         Language::Markdown => Some(TypeNodeTypes {
@@ -343,6 +525,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "",
             class_decl: "",
             struct_def: "",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         Language::C => Some(TypeNodeTypes {
             type_alias: "type_definition",
@@ -350,6 +535,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "enum_specifier",
             class_decl: "",
             struct_def: "struct_specifier",
+            union_def: "union_specifier",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         Language::Cpp => Some(TypeNodeTypes {
             type_alias: "type_definition",
@@ -357,6 +545,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "enum_specifier",
             class_decl: "class_specifier",
             struct_def: "struct_specifier",
+            union_def: "union_specifier",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         Language::CSharp => Some(TypeNodeTypes {
             type_alias: "",
@@ -364,6 +555,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "enum_declaration",
             class_decl: "class_declaration",
             struct_def: "struct_declaration",
+            union_def: "",
+            class_fields: &["field_declaration", "property_declaration"],
+            extra_class_kinds: &["record_declaration"],
         }),
         Language::Ruby => Some(TypeNodeTypes {
             type_alias: "",
@@ -371,6 +565,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "",
             class_decl: "class",
             struct_def: "",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         Language::Sql => Some(TypeNodeTypes {
             type_alias: "",
@@ -378,6 +575,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "",
             class_decl: "",
             struct_def: "create_table", // CREATE TABLE defines the type structure in SQL
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         // ARCHITECTURE: tree-sitter-kotlin uses class_declaration for all class-like
         // constructs (class, interface, data class, sealed class, enum class). There is
@@ -388,6 +588,9 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "",
             class_decl: "class_declaration",
             struct_def: "",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
         // ARCHITECTURE: tree-sitter-swift uses class_declaration for struct, class, and
         // enum declarations. Only protocol_declaration is a distinct grammar node.
@@ -399,7 +602,102 @@ fn get_type_node_types(language: Language) -> Option<TypeNodeTypes> {
             enum_def: "class_declaration",
             class_decl: "class_declaration",
             struct_def: "",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
+        }),
+        Language::Dart => Some(TypeNodeTypes {
+            type_alias: "type_alias",
+            interface: "",
+            enum_def: "enum_declaration",
+            class_decl: "class_definition",
+            struct_def: "",
+            union_def: "",
+            // Fields and body-less constructors (`const MyWidget({super.key})`)
+            class_fields: &["declaration"],
+            extra_class_kinds: &["mixin_declaration", "extension_declaration"],
+        }),
+        // Contracts and libraries keep their state, events, errors and nested
+        // types; interfaces have no bodies and are kept whole.
+        Language::Solidity => Some(TypeNodeTypes {
+            type_alias: "user_defined_type_definition",
+            interface: "interface_declaration",
+            enum_def: "enum_declaration",
+            class_decl: "contract_declaration",
+            struct_def: "struct_declaration",
+            union_def: "",
+            class_fields: &[
+                "state_variable_declaration",
+                "struct_declaration",
+                "enum_declaration",
+                "event_definition",
+                "error_declaration",
+            ],
+            extra_class_kinds: &["library_declaration"],
+        }),
+        // @interface and @protocol blocks have no body node and are kept whole,
+        // with their properties and method declarations.
+        Language::ObjectiveC => Some(TypeNodeTypes {
+            type_alias: "type_definition",
+            interface: "protocol_declaration",
+            enum_def: "enum_specifier",
+            class_decl: "class_interface",
+            struct_def: "struct_specifier",
+            union_def: "union_specifier",
+            class_fields: &[],
+            extra_class_kinds: &["category_interface"],
+        }),
+        // Julia structs and abstract/primitive types have no body node and
+        // are kept whole, fields included.
+        Language::Julia => Some(TypeNodeTypes {
+            type_alias: "",
+            interface: "abstract_definition",
+            enum_def: "",
+            class_decl: "",
+            struct_def: "struct_definition",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &["primitive_definition"],
+        }),
+        // OCaml type, module type and exception declarations are kept whole;
+        // types nested in `module ... = struct ... end` are found by recursion.
+        Language::Ocaml => Some(TypeNodeTypes {
+            type_alias: "type_definition",
+            interface: "module_type_definition",
+            enum_def: "exception_definition",
+            class_decl: "",
+            struct_def: "",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
+        }),
+        // Shell has no type declarations, and Elixir's typespecs and structs are
+        // macro calls; types mode keeps nothing for either.
+        Language::Shell | Language::Elixir => Some(TypeNodeTypes {
+            type_alias: "",
+            interface: "",
+            enum_def: "",
+            class_decl: "",
+            struct_def: "",
+            union_def: "",
+            class_fields: &[],
+            extra_class_kinds: &[],
         }),
-        Language::Json | Language::Yaml | Language::Toml => None,
+        Language::Json
+        | Language::Yaml
+        | Language::Toml
+        | Language::OcamlInterface
+        | Language::Vue
+        | Language::Svelte
+        | Language::Xml
+        | Language::Html
+        | Language::Hcl
+        | Language::Dockerfile
+        | Language::Groovy
+        | Language::Csv
+        | Language::Env
+        | Language::Ini
+        | Language::Rst
+        | Language::AsciiDoc => None,
     }
 }
@@ -22,6 +22,29 @@ pub(crate) struct FunctionNodeTypes {
     /// Extra node kinds that behave like functions (e.g., Swift init/deinit, Kotlin constructors).
     /// Language-specific kinds are data-driven, not hardcoded in match logic.
     pub(crate) extra_function_kinds: &'static [&'static str],
+    /// Macro names whose `call` nodes define functions (Elixir `def`/`defp`).
+    /// Elixir has no dedicated definition node; `def foo(x) do ... end` parses
+    /// as a generic `call` whose `target` identifier names the macro.
+    pub(crate) definition_calls: &'static [&'static str],
+}
+
+/// Elixir macros that define functions. `defmodule`/`defprotocol`/`defimpl`
+/// are deliberately absent: their `do` blocks hold the definitions to keep.
+pub(crate) const ELIXIR_DEFINITION_CALLS: &[&str] = &["def", "defp", "defmacro", "defmacrop"];
+
+/// Check if a node is a function definition written as a macro call
+///
+/// Matches `call` nodes whose `target` identifier is one of
+/// `definition_calls`. Always false for an empty list, so grammars that also
+/// have `call` nodes (Ruby, Python) are unaffected.
+pub(crate) fn is_definition_call(node: Node, source: &str, definition_calls: &[&str]) -> bool {
+    if definition_calls.is_empty() || node.kind() != "call" {
+        return false;
+    }
+    node.child_by_field_name("target")
+        .filter(|target| target.kind() == "identifier")
+        .and_then(|target| target.utf8_text(source.as_bytes()).ok())
+        .is_some_and(|name| definition_calls.contains(&name))
 }
 
 /// Check if a node is inside a function/method body
@@ -34,10 +57,14 @@ pub(crate) struct FunctionNodeTypes {
 /// of `function_definition`, not of `block`. We must also check for function
 /// definition ancestors to handle this correctly.
 ///
+/// NOTE: Elixir modules and functions both use `do_block`; a block only
+/// counts as a function body when its parent is a `def`-style call.
+///
 /// # Arguments
 /// * `node` - The AST node to check
+/// * `source` - Source text (needed to read Elixir definition macro names)
 /// * `language` - The language (determines which node types are "body" nodes)
-pub(crate) fn is_inside_function_body(node: Node, language: Language) -> bool {
+pub(crate) fn is_inside_function_body(node: Node, source: &str, language: Language) -> bool {
     let body_kinds = get_body_node_kinds(language);
     let fn_kinds = get_function_node_kinds(language);
     let mut current = node.parent();
@@ -50,7 +77,12 @@ pub(crate) fn is_inside_function_body(node: Node, language: Language) -> bool {
             return false;
         }
         let kind = parent.kind();
-        if body_kinds.contains(&kind) {
+        if body_kinds.contains(&kind)
+            && (kind != "do_block"
+                || parent
+                    .parent()
+                    .is_some_and(|call| is_definition_call(call, source, ELIXIR_DEFINITION_CALLS)))
+        {
             return true;
         }
         // In some grammars (Python), comments are children of the function
@@ -68,7 +100,7 @@ pub(crate) fn is_inside_function_body(node: Node, language: Language) -> bool {
 /// Get the node kinds that represent function/method bodies for a language
 fn get_body_node_kinds(language: Language) -> &'static [&'static str] {
     match language {
-        Language::TypeScript | Language::JavaScript => &["statement_block"],
+        Language::TypeScript | Language::Tsx | Language::JavaScript => &["statement_block"],
         Language::Python | Language::Rust | Language::Go => &["block"],
         Language::Java => &["block", "constructor_body"],
         Language::C | Language::Cpp => &["compound_statement"],
@@ -77,7 +109,29 @@ fn get_body_node_kinds(language: Language) -> &'static [&'static str] {
         Language::Sql => &[], // SQL has no function bodies
         Language::Kotlin => &["function_body", "block"],
         Language::Swift => &["function_body"],
-        Language::Markdown | Language::Json | Language::Yaml | Language::Toml => &[],
+        Language::Shell => &["compound_statement", "subshell"],
+        Language::Elixir => &["do_block"],
+        Language::Dart => &["function_body", "function_expression_body"],
+        Language::Solidity => &["function_body"],
+        Language::ObjectiveC => &["compound_statement"],
+        Language::Julia => &[], // No body node; see `end_terminated_body`
+        Language::Ocaml | Language::OcamlInterface => &[], // See `ocaml_function_body`
+        Language::Markdown
+        | Language::Json
+        | Language::Yaml
+        | Language::Toml
+        | Language::Vue
+        | Language::Svelte
+        | Language::Xml
+        | Language::Html
+        | Language::Hcl
+        | Language::Dockerfile
+        | Language::Groovy
+        | Language::Csv
+        | Language::Env
+        | Language::Ini
+        | Language::Rst
+        | Language::AsciiDoc => &[],
     }
 }
 
@@ -92,6 +146,8 @@ fn get_body_node_kinds(language: Language) -> &'static [&'static str] {
 fn get_function_node_kinds(language: Language) -> &'static [&'static str] {
     match language {
         Language::Python => &["function_definition"],
+        // OCaml bodies are arbitrary expressions with no block node to find.
+        Language::Ocaml => &["let_binding", "method_definition"],
         // Other languages correctly place comments inside body blocks,
         // so no function-level check needed.
         _ => &[],
@@ -108,18 +164,293 @@ fn get_function_node_kinds(language: Language) -> &'static [&'static str] {
 /// from `get_body_node_kinds()`. This is the single source of truth for
 /// "what is a body child node" when walking DOWN from a function node.
 /// `get_body_node_kinds()` is used for walking UP (checking ancestry).
+///
+/// NOTE: tree-sitter-dart places the body NEXT TO its signature
+/// (`function_signature function_body`, `method_signature function_body`),
+/// so a `function_body` sibling counts as the body when no child matches.
 pub(crate) fn find_body_child(node: Node) -> Option<Node> {
+    if node.kind() == "let_binding" || is_ocaml_method(node) {
+        return ocaml_function_body(node);
+    }
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "statement_block" | "block" | "compound_statement" | "constructor_body"
-            | "body_statement" | "function_body" => return Some(child),
+            "statement_block"
+            | "block"
+            | "compound_statement"
+            | "constructor_body"
+            | "body_statement"
+            | "function_body"
+            | "do_block"
+            | "function_expression_body" => {
+                return Some(child);
+            }
+            // Shell `name() ( ... )` runs its body in a subshell.
+            "subshell" if node.kind() == "function_definition" => return Some(child),
             _ => continue,
         }
     }
+    node.next_sibling()
+        .filter(|sibling| sibling.kind() == "function_body")
+}
+
+/// Body of an OCaml function binding (`let f x = ...`, `let f = fun x -> ...`)
+/// or method (`method m = ...`)
+///
+/// tree-sitter-ocaml gives every `let_binding` a `body` field, plain values
+/// (`let limit = 10`) included. Only bindings that take parameters, are
+/// bound to a `fun`/`function` expression, or are `let () = ...` entry
+/// points count as functions. Methods always do.
+pub(crate) fn ocaml_function_body(node: Node) -> Option<Node> {
+    let body = node.child_by_field_name("body")?;
+    if is_ocaml_method(node) {
+        return Some(body);
+    }
+    let mut cursor = node.walk();
+    let has_parameters = node
+        .named_children(&mut cursor)
+        .any(|child| child.kind() == "parameter");
+    (has_parameters
+        || is_ocaml_entry_point(node)
+        || matches!(body.kind(), "fun_expression" | "function_expression"))
+    .then_some(body)
+}
+
+/// Whether `node` is a top-level OCaml `let () = ...` binding, the idiomatic
+/// program entry point (a local `let () = ... in` is just sequencing).
+pub(crate) fn is_ocaml_entry_point(node: Node) -> bool {
+    node.kind() == "let_binding"
+        && node
+            .child_by_field_name("pattern")
+            .is_some_and(|pattern| pattern.kind() == "unit")
+        && node
+            .parent()
+            .and_then(|definition| definition.parent())
+            .is_some_and(|scope| matches!(scope.kind(), "compilation_unit" | "structure"))
+}
+
+/// Whether `node` is a method of an OCaml `object ... end` expression
+/// (other grammars use `method_definition` for block-bodied methods).
+pub(crate) fn is_ocaml_method(node: Node) -> bool {
+    node.kind() == "method_definition"
+        && node
+            .parent()
+            .is_some_and(|parent| parent.kind() == "object_expression")
+}
+
+/// Signature of an `end`-terminated definition whose grammar has no body node
+///
+/// tree-sitter-julia places the statements of `function ... end` and
+/// `macro ... end` directly under the definition, after its `signature`
+/// child. Returns that signature for nodes of this shape; `None` otherwise.
+pub(crate) fn end_terminated_signature(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let mut children = node.children(&mut cursor);
+    let signature = children.find(|c| c.kind() == "signature")?;
+    (children.last()?.kind() == "end").then_some(signature)
+}
+
+/// Byte range of the statements between an `end`-terminated definition's
+/// signature and its `end`, or `None` when the body is empty
+///
+/// Runs from the first statement to the last, so a replacement keeps the
+/// body's indentation and the line break before `end`. Used where other
+/// grammars replace or skip a body node (see [`end_terminated_signature`]).
+pub(crate) fn end_terminated_body(node: Node) -> Option<(usize, usize)> {
+    let signature = end_terminated_signature(node)?;
+    let mut cursor = node.walk();
+    let mut statements = node
+        .named_children(&mut cursor)
+        .filter(|c| c.start_byte() >= signature.end_byte());
+    let first = statements.next()?;
+    let last = statements.last().unwrap_or(first);
+    Some((first.start_byte(), last.end_byte()))
+}
+
+/// Parameter list kinds of grammars that do not expose a `parameters` field.
+const PARAMETER_LIST_KINDS: &[&str] = &[
+    "function_value_parameters",
+    "formal_parameter_list",
+    "formal_parameters",
+    "parameter_list",
+    "method_parameters",
+];
+
+/// Find the parameter list of a function/method node
+///
+/// Tries the `parameters` field, then a child of a known parameter list kind
+/// (Kotlin, Dart), then descends the `declarator` chain where C/C++ keep the
+/// `function_declarator`.
+pub(crate) fn parameter_list(node: Node) -> Option<Node> {
+    let mut current = node;
+    // C/C++ nest the function declarator behind pointer/reference declarators.
+    for _ in 0..4 {
+        if let Some(params) = current.child_by_field_name("parameters") {
+            return Some(params);
+        }
+        let mut cursor = current.walk();
+        if let Some(params) = current
+            .named_children(&mut cursor)
+            .find(|child| PARAMETER_LIST_KINDS.contains(&child.kind()))
+        {
+            return Some(params);
+        }
+        current = current.child_by_field_name("declarator")?;
+    }
     None
 }
 
+/// Number of parameters in a parameter list node (comments excluded).
+pub(crate) fn count_parameters(params: Node) -> usize {
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|child| !child.kind().contains("comment"))
+        .count()
+}
+
+/// Find the file-level doc comment: Rust `//!` lines, a Python module
+/// docstring, a Go package comment, or a `/** ... */` block tagged
+/// `@fileoverview`/`@file`/`@module`/`@packageDocumentation` (JS/TS and other
+/// C-family languages).
+///
+/// Returns the doc text and its 1-indexed start line. Only comments before the
+/// first declaration are considered.
+pub(crate) fn module_doc_comment<'src>(
+    root: Node,
+    source: &'src str,
+    language: Language,
+) -> Option<(&'src str, usize)> {
+    let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("");
+    let is_comment = |node: Node| node.kind().contains("comment");
+    let mut cursor = root.walk();
+    let children: Vec<Node> = root.named_children(&mut cursor).collect();
+
+    let (first, last) = match language {
+        Language::Rust => {
+            let is_inner_doc = |node: Node| {
+                is_comment(node) && (text(node).starts_with("//!") || text(node).starts_with("/*!"))
+            };
+            let mut docs = children
+                .iter()
+                .skip_while(|c| c.kind() == "shebang")
+                .take_while(|c| is_inner_doc(**c));
+            let first = *docs.next()?;
+            (first, docs.last().copied().unwrap_or(first))
+        }
+        Language::Python => {
+            let statement = *children.iter().find(|c| !is_comment(**c))?;
+            let is_docstring = statement.kind() == "expression_statement"
+                && statement.named_child_count() == 1
+                && statement
+                    .named_child(0)
+                    .is_some_and(|s| s.kind() == "string");
+            if !is_docstring {
+                return None;
+            }
+            (statement, statement)
+        }
+        Language::Go => {
+            let package = children.iter().position(|c| c.kind() == "package_clause")?;
+            let mut first = None;
+            let mut expected_row = children[package].start_position().row;
+            for comment in children[..package].iter().rev() {
+                if !is_comment(*comment) || comment.end_position().row + 1 != expected_row {
+                    break;
+                }
+                first = Some(*comment);
+                expected_row = comment.start_position().row;
+            }
+            let first = first?;
+            (first, children[package - 1])
+        }
+        Language::TypeScript
+        | Language::Tsx
+        | Language::JavaScript
+        | Language::Java
+        | Language::C
+        | Language::Cpp
+        | Language::CSharp
+        | Language::Kotlin
+        | Language::Swift => {
+            const TAGS: &[&str] = &["@fileoverview", "@file", "@module", "@packageDocumentation"];
+            let doc = *children.iter().take_while(|c| is_comment(**c)).find(|c| {
+                let t = text(**c);
+                t.starts_with("/**") && TAGS.iter().any(|tag| t.contains(tag))
+            })?;
+            (doc, doc)
+        }
+        _ => return None,
+    };
+
+    let doc = source.get(first.start_byte()..last.end_byte())?.trim_end();
+    (!doc.is_empty()).then_some((doc, first.start_position().row + 1))
+}
+
+/// Whether `function` is a Python `__init__` method.
+pub(crate) fn is_python_init(function: Node, source: &str) -> bool {
+    function.kind() == "function_definition"
+        && function.child_by_field_name("name").is_some_and(|name| {
+            name.kind() == "identifier"
+                && name.utf8_text(source.as_bytes()).unwrap_or("") == "__init__"
+        })
+}
+
+/// If `statement` assigns an instance attribute (`self.x = ...` or
+/// `self.x: int = ...`), return the assignment node.
+///
+/// Used to summarize Python `__init__` bodies, where instance attributes are
+/// declared, in structure and types mode.
+pub(crate) fn self_attribute_assignment<'t>(statement: Node<'t>, source: &str) -> Option<Node<'t>> {
+    if statement.kind() != "expression_statement" {
+        return None;
+    }
+    let assignment = statement.named_child(0)?;
+    if assignment.kind() != "assignment" {
+        return None;
+    }
+    let target = assignment.child_by_field_name("left")?;
+    let object = target.child_by_field_name("object")?;
+    (target.kind() == "attribute" && object.utf8_text(source.as_bytes()).unwrap_or("") == "self")
+        .then_some(assignment)
+}
+
+// ============================================================================
+// UTF-8 Boundary Alignment
+// ============================================================================
+
+/// Widen `start..end` outward to the nearest char boundaries in `source`.
+///
+/// Every transform slices the source by tree-sitter byte offsets. A range that
+/// splits a multi-byte character (a grammar reporting a mid-character offset
+/// inside an identifier, string, or comment) would otherwise fail the whole
+/// file; widening keeps the straddled character whole and loses nothing.
+/// Offsets past the end clamp to `source.len()`.
+pub(crate) fn align_to_char_boundaries(source: &str, start: usize, end: usize) -> (usize, usize) {
+    (
+        floor_char_boundary(source, start),
+        ceil_char_boundary(source, end),
+    )
+}
+
+/// Nearest char boundary at or before `pos`.
+pub(crate) fn floor_char_boundary(source: &str, pos: usize) -> usize {
+    let mut pos = pos.min(source.len());
+    while !source.is_char_boundary(pos) {
+        pos -= 1;
+    }
+    pos
+}
+
+/// Nearest char boundary at or after `pos`.
+pub(crate) fn ceil_char_boundary(source: &str, pos: usize) -> usize {
+    let mut pos = pos.min(source.len());
+    while !source.is_char_boundary(pos) {
+        pos += 1;
+    }
+    pos
+}
+
 // ============================================================================
 // Priority Scoring for AST-aware truncation
 // ============================================================================
@@ -164,6 +495,7 @@ pub(crate) fn node_kind_info(kind: &str) -> (&'static str, u8) {
         "enum_declaration" => ("enum_declaration", 5),
         "struct_specifier" => ("struct_specifier", 5),
         "enum_specifier" => ("enum_specifier", 5),
+        "union_specifier" => ("union_specifier", 5),
         "type_definition" => ("type_definition", 5),
         "type_item" => ("type_item", 5),
         "type_alias_statement" => ("type_alias_statement", 5),
@@ -177,8 +509,13 @@ pub(crate) fn node_kind_info(kind: &str) -> (&'static str, u8) {
         "object_declaration" => ("object_declaration", 5), // Kotlin object/singleton
         "typealias_declaration" => ("typealias_declaration", 5), // Swift typealias
         "protocol_declaration" => ("protocol_declaration", 5), // Swift protocol
+        "struct_definition" => ("struct_definition", 5), // Julia struct
+        "abstract_definition" => ("abstract_definition", 5), // Julia abstract type
+        "primitive_definition" => ("primitive_definition", 5), // Julia primitive type
         "atx_heading" => ("atx_heading", 5),
         "setext_heading" => ("setext_heading", 5),
+        // Synthetic: file-level doc comment kept by `TransformConfig::module_doc`
+        "module_doc" => ("module_doc", 5),
 
         // Priority 4: Function/method declarations
         "function_declaration" => ("function_declaration", 4),
@@ -197,6 +534,22 @@ pub(crate) fn node_kind_info(kind: &str) -> (&'static str, u8) {
         "deinit_declaration" => ("deinit_declaration", 4),           // Swift deinit
         "secondary_constructor" => ("secondary_constructor", 4),     // Kotlin constructor
         "anonymous_initializer" => ("anonymous_initializer", 4),     // Kotlin init block
+        "generator_function_declaration" => ("generator_function_declaration", 4), // JS `function*`
+        "generator_function" => ("generator_function", 4),
+        "function_signature_item" => ("function_signature_item", 4), // Rust trait/extern fn
+        "function_signature" => ("function_signature", 4),           // TS overload / declare
+        "constructor_signature" => ("constructor_signature", 4),     // Dart constructors
+        "constant_constructor_signature" => ("constant_constructor_signature", 4),
+        "factory_constructor_signature" => ("factory_constructor_signature", 4),
+        "redirecting_factory_constructor_signature" => {
+            ("redirecting_factory_constructor_signature", 4)
+        }
+        "getter_signature" => ("getter_signature", 4), // Dart getters/setters
+        "setter_signature" => ("setter_signature", 4),
+        "constructor_definition" => ("constructor_definition", 4), // Solidity constructor
+        "modifier_definition" => ("modifier_definition", 4),       // Solidity modifier
+        "fallback_receive_definition" => ("fallback_receive_definition", 4),
+        "fenced_code_block" => ("fenced_code_block", 4), // Markdown --fenced-code
 
         // Priority 3: Import statements
         "import_statement" => ("import_statement", 3),
@@ -225,8 +578,12 @@ pub(crate) fn node_kind_info(kind: &str) -> (&'static str, u8) {
         "namespace_declaration" => ("namespace_declaration", 2), // C# namespace
         "interface_type" => ("interface_type", 2),
         "struct_type" => ("struct_type", 2),
-        "class" => ("class", 2),   // Ruby class
-        "module" => ("module", 2), // Ruby module
+        "class" => ("class", 2),                               // Ruby class
+        "module" => ("module", 2),                             // Ruby module
+        "contract_declaration" => ("contract_declaration", 2), // Solidity contract
+        "library_declaration" => ("library_declaration", 2),   // Solidity library
+        "class_interface" => ("class_interface", 2),           // Objective-C @interface
+        "class_implementation" => ("class_implementation", 2),
         // ARCHITECTURE: "statement" is a SUPERTYPE (abstract) in TypeScript,
         // JavaScript, C, C++, Java, and Kotlin — tree-sitter resolves these to
         // concrete subtypes (e.g., "expression_statement", "break_statement"),
@@ -271,6 +628,7 @@ pub(crate) fn score_node_kind(kind: &str) -> u8 {
 pub(crate) fn get_comment_prefix(language: Language) -> &'static str {
     match language {
         Language::TypeScript
+        | Language::Tsx
         | Language::JavaScript
         | Language::Rust
         | Language::Go
@@ -279,20 +637,38 @@ pub(crate) fn get_comment_prefix(language: Language) -> &'static str {
         | Language::Cpp
         | Language::CSharp
         | Language::Kotlin
-        | Language::Swift => "//",
-        Language::Python | Language::Ruby => "#",
+        | Language::Swift
+        | Language::Dart
+        | Language::Solidity
+        | Language::ObjectiveC
+        | Language::Groovy => "//",
+        Language::Python
+        | Language::Ruby
+        | Language::Shell
+        | Language::Elixir
+        | Language::Julia => "#",
         Language::Sql => "--",
+        Language::Ocaml | Language::OcamlInterface => "(*",
         Language::Markdown => "<!--",
         Language::Json => "//", // JSON has no comments; // is JSONC-compatible
         Language::Yaml => "#",
-        Language::Toml => "#",
+        Language::Toml | Language::Hcl | Language::Dockerfile | Language::Csv | Language::Env => {
+            "#"
+        }
+        Language::Ini => ";",
+        Language::Rst => "..",
+        Language::AsciiDoc => "//",
+        Language::Vue | Language::Svelte | Language::Xml | Language::Html => "<!--",
     }
 }
 
 /// Get the comment suffix for a language (empty for most, closing tag for Markdown)
 pub(crate) fn get_comment_suffix(language: Language) -> &'static str {
     match language {
-        Language::Markdown => " -->",
+        Language::Markdown | Language::Vue | Language::Svelte | Language::Xml | Language::Html => {
+            " -->"
+        }
+        Language::Ocaml | Language::OcamlInterface => " *)",
         _ => "",
     }
 }
@@ -301,6 +677,27 @@ pub(crate) fn get_comment_suffix(language: Language) -> &'static str {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_align_to_char_boundaries_widens_split_characters() {
+        // "a" + euro sign (bytes 1..4) + "b"
+        let source = "a\u{20AC}b";
+        assert_eq!(align_to_char_boundaries(source, 2, 3), (1, 4));
+        assert_eq!(align_to_char_boundaries(source, 0, 5), (0, 5));
+        assert_eq!(align_to_char_boundaries(source, 1, 99), (1, 5));
+    }
+
+    #[test]
+    fn test_char_boundary_helpers_on_emoji_and_combining_marks() {
+        // Family emoji: four 4-byte code points joined by 3-byte ZWJs
+        let emoji = "x\u{1F468}\u{200D}\u{1F469}y";
+        assert_eq!(floor_char_boundary(emoji, 3), 1);
+        assert_eq!(ceil_char_boundary(emoji, 3), 5);
+        // "e" + combining acute accent (2 bytes)
+        let combining = "e\u{301}";
+        assert_eq!(ceil_char_boundary(combining, 2), 3);
+        assert_eq!(floor_char_boundary(combining, 2), 1);
+    }
+
     #[test]
     fn test_score_node_kind_priority_5() {
         assert_eq!(score_node_kind("type_alias_declaration"), 5);
@@ -356,6 +753,7 @@ mod tests {
             "enum_declaration",
             "struct_specifier",
             "enum_specifier",
+            "union_specifier",
             "type_definition",
             "type_item",
             "type_alias_statement",
@@ -371,6 +769,7 @@ mod tests {
             "protocol_declaration",
             "atx_heading",
             "setext_heading",
+            "module_doc",
             // Priority 4
             "function_declaration",
             "function_item",
@@ -387,7 +786,12 @@ mod tests {
             "init_declaration",
             "deinit_declaration",
             "secondary_constructor",
+            "generator_function_declaration",
+            "generator_function",
+            "function_signature_item",
+            "function_signature",
             "anonymous_initializer",
+            "fenced_code_block",
             // Priority 3
             "import_statement",
             "use_declaration",
@@ -474,6 +878,7 @@ mod tests {
         assert_eq!(get_comment_prefix(Language::Json), "//");
         assert_eq!(get_comment_prefix(Language::Yaml), "#");
         assert_eq!(get_comment_prefix(Language::Toml), "#");
+        assert_eq!(get_comment_prefix(Language::Ocaml), "(*");
     }
 
     #[test]
@@ -495,5 +900,6 @@ mod tests {
         assert_eq!(get_comment_suffix(Language::Json), "");
         assert_eq!(get_comment_suffix(Language::Yaml), "");
         assert_eq!(get_comment_suffix(Language::Toml), "");
+        assert_eq!(get_comment_suffix(Language::Ocaml), " *)");
     }
 }
@@ -0,0 +1,477 @@
+//! Declaration visibility for `--public-only` filtering
+//!
+//! ARCHITECTURE: Each language marks its public surface differently: keywords
+//! (`pub`, `public`, `export`), naming conventions (Python `_helper`, Go
+//! lowercase identifiers), or position (C++ access sections). This module
+//! reduces all of them to one question per declaration node, "is it private?",
+//! which structure, signatures and types mode ask while walking the tree.
+//!
+//! Classification is deliberately conservative: a declaration is only hidden
+//! when the source says it is private. Package-private Java members, C#
+//! `internal` types, and Ruby methods below a bare `private` call are kept.
+
+use crate::Language;
+use tree_sitter::Node;
+
+/// Visibility of a declaration, as far as the source spells it out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Visibility {
+    Public,
+    Private,
+}
+
+/// Per-file visibility oracle used when `TransformConfig::public_only` is set.
+pub(crate) struct PrivateFilter<'src> {
+    source: &'src str,
+    language: Language,
+    /// TS/JS: whether the file exports anything. Non-exported top-level
+    /// declarations are private only in files that do.
+    has_exports: bool,
+    /// TS/JS: names exported separately from their declaration
+    /// (`export { helper }`, `export default helper`).
+    exported_names: Vec<&'src str>,
+}
+
+impl<'src> PrivateFilter<'src> {
+    pub(crate) fn new(root: Node, source: &'src str, language: Language) -> Self {
+        let mut filter = Self {
+            source,
+            language,
+            has_exports: false,
+            exported_names: Vec::new(),
+        };
+        if matches!(
+            language,
+            Language::TypeScript | Language::Tsx | Language::JavaScript
+        ) {
+            let mut cursor = root.walk();
+            for export in root
+                .children(&mut cursor)
+                .filter(|child| child.kind() == "export_statement")
+            {
+                filter.has_exports = true;
+                filter.collect_exported_names(export);
+            }
+        }
+        filter
+    }
+
+    fn collect_exported_names(&mut self, export: Node) {
+        if let Some(value) = export.child_by_field_name("value")
+            && value.kind() == "identifier"
+        {
+            self.exported_names.push(self.text(value));
+        }
+        let mut cursor = export.walk();
+        for clause in export
+            .named_children(&mut cursor)
+            .filter(|child| child.kind() == "export_clause")
+        {
+            let mut specifiers = clause.walk();
+            for specifier in clause.named_children(&mut specifiers) {
+                if let Some(name) = specifier.child_by_field_name("name") {
+                    self.exported_names.push(self.text(name));
+                }
+            }
+        }
+    }
+
+    /// Whether `node` is a declaration its language marks as private.
+    ///
+    /// Returns `false` for every node that is not a declaration, so callers
+    /// can ask about each node they visit.
+    pub(crate) fn hides(&self, node: Node) -> bool {
+        self.visibility(node) == Some(Visibility::Private)
+    }
+
+    /// Visibility of `node`, or `None` when it is not a declaration.
+    pub(crate) fn visibility(&self, node: Node) -> Option<Visibility> {
+        let private = match self.language {
+            Language::Rust => self.rust_private(node)?,
+            Language::Python => self.python_private(node)?,
+            Language::Go => self.go_private(node)?,
+            Language::TypeScript | Language::Tsx | Language::JavaScript => self.js_private(node)?,
+            Language::Java => self.modifier_private(node, JAVA_DECLARATIONS, &["private"])?,
+            Language::CSharp => self.csharp_private(node)?,
+            Language::Kotlin => {
+                self.modifier_private(node, KOTLIN_DECLARATIONS, &["private", "internal"])?
+            }
+            Language::Swift => {
+                self.modifier_private(node, SWIFT_DECLARATIONS, &["private", "fileprivate"])?
+            }
+            Language::C | Language::Cpp => self.c_private(node)?,
+            _ => return None,
+        };
+        Some(if private {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        })
+    }
+
+    fn text(&self, node: Node) -> &'src str {
+        node.utf8_text(self.source.as_bytes()).unwrap_or("")
+    }
+
+    fn name(&self, node: Node) -> Option<&'src str> {
+        node.child_by_field_name("name").map(|name| self.text(name))
+    }
+
+    /// Rust: items without a `pub` visibility modifier (any `pub(...)` form
+    /// counts as public). Trait members and trait impl members take the
+    /// visibility of the trait.
+    fn rust_private(&self, node: Node) -> Option<bool> {
+        if !RUST_DECLARATIONS.contains(&node.kind()) {
+            return None;
+        }
+        let in_trait = node
+            .parent()
+            .filter(|list| list.kind() == "declaration_list")
+            .and_then(|list| list.parent())
+            .is_some_and(|owner| {
+                owner.kind() == "trait_item"
+                    || (owner.kind() == "impl_item" && owner.child_by_field_name("trait").is_some())
+            });
+        Some(!in_trait && !has_child_kind(node, "visibility_modifier"))
+    }
+
+    /// Python: `_leading_underscore` names. Dunder methods are public.
+    fn python_private(&self, node: Node) -> Option<bool> {
+        let definition = match node.kind() {
+            "function_definition" | "class_definition" => node,
+            "decorated_definition" => node.child_by_field_name("definition")?,
+            _ => return None,
+        };
+        let name = self.name(definition)?;
+        let dunder = name.len() > 4 && name.starts_with("__") && name.ends_with("__");
+        Some(name.starts_with('_') && !dunder)
+    }
+
+    /// Go: identifiers that do not start with an uppercase letter.
+    fn go_private(&self, node: Node) -> Option<bool> {
+        let name = match node.kind() {
+            "function_declaration" | "method_declaration" | "type_spec" | "type_alias" => {
+                self.name(node)?
+            }
+            "type_declaration" => {
+                let mut cursor = node.walk();
+                let spec = node
+                    .named_children(&mut cursor)
+                    .find(|c| matches!(c.kind(), "type_spec" | "type_alias"))?;
+                self.name(spec)?
+            }
+            _ => return None,
+        };
+        Some(!name.chars().next().is_some_and(char::is_uppercase))
+    }
+
+    /// TS/JS: `private`/`#private` class members, and top-level declarations
+    /// that are not exported from a file that exports something.
+    fn js_private(&self, node: Node) -> Option<bool> {
+        let kind = node.kind();
+        if JS_MEMBERS.contains(&kind) {
+            let marked_private = {
+                let mut cursor = node.walk();
+                node.children(&mut cursor).any(|child| {
+                    child.kind() == "private_property_identifier"
+                        || (child.kind() == "accessibility_modifier"
+                            && self.text(child) == "private")
+                })
+            };
+            return Some(marked_private);
+        }
+        if !JS_DECLARATIONS.contains(&kind) {
+            return None;
+        }
+        let top_level = node.parent().is_some_and(|p| p.kind() == "program");
+        Some(top_level && self.has_exports && !self.exported_separately(node))
+    }
+
+    /// Whether a declaration's name appears in an `export { ... }` clause or
+    /// `export default` elsewhere in the file.
+    fn exported_separately(&self, declaration: Node) -> bool {
+        let name = self.name(declaration).or_else(|| {
+            // `const helper = ...`: the name is on the first declarator.
+            let mut cursor = declaration.walk();
+            let declarator = declaration
+                .named_children(&mut cursor)
+                .find(|c| c.kind() == "variable_declarator");
+            declarator.and_then(|d| self.name(d))
+        });
+        name.is_some_and(|name| self.exported_names.contains(&name))
+    }
+
+    /// C#: explicit `private`, or a class/struct member with no access modifier
+    /// (members default to private). Interface members are always public.
+    fn csharp_private(&self, node: Node) -> Option<bool> {
+        if !CSHARP_DECLARATIONS.contains(&node.kind()) {
+            return None;
+        }
+        let words = self.modifier_words(node);
+        if words.contains(&"private") {
+            return Some(true);
+        }
+        let access_spelled = words
+            .iter()
+            .any(|w| matches!(*w, "public" | "protected" | "internal"));
+        let is_member = node
+            .parent()
+            .filter(|list| list.kind() == "declaration_list")
+            .and_then(|list| list.parent())
+            .is_some_and(|owner| owner.kind() != "interface_declaration");
+        Some(is_member && !access_spelled)
+    }
+
+    /// C/C++: file-scope `static` functions, and C++ members in a `private:`
+    /// section (or before any access specifier in a `class`).
+    fn c_private(&self, node: Node) -> Option<bool> {
+        if !matches!(
+            node.kind(),
+            "function_definition" | "declaration" | "field_declaration"
+        ) {
+            return None;
+        }
+        let Some(parent) = node.parent() else {
+            return Some(false);
+        };
+        if parent.kind() == "translation_unit" {
+            let mut cursor = node.walk();
+            let is_static = node.children(&mut cursor).any(|child| {
+                child.kind() == "storage_class_specifier" && self.text(child) == "static"
+            });
+            return Some(is_static);
+        }
+        if parent.kind() != "field_declaration_list" {
+            return Some(false);
+        }
+        let mut access = match parent.parent().map(|p| p.kind()) {
+            Some("class_specifier") => "private",
+            _ => "public",
+        };
+        let mut sibling = node.prev_sibling();
+        while let Some(s) = sibling {
+            if s.kind() == "access_specifier" {
+                access = self.text(s);
+                break;
+            }
+            sibling = s.prev_sibling();
+        }
+        Some(access == "private")
+    }
+
+    /// Keyword-modifier languages (Java, Kotlin, Swift): private when any
+    /// modifier is one of `private_words`.
+    fn modifier_private(
+        &self,
+        node: Node,
+        declarations: &[&str],
+        private_words: &[&str],
+    ) -> Option<bool> {
+        if !declarations.contains(&node.kind()) {
+            return None;
+        }
+        Some(
+            self.modifier_words(node)
+                .iter()
+                .any(|w| private_words.contains(w)),
+        )
+    }
+
+    /// Words of the modifier nodes directly under `node`.
+    fn modifier_words(&self, node: Node) -> Vec<&'src str> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor)
+            .filter(|child| matches!(child.kind(), "modifiers" | "modifier"))
+            .flat_map(|child| self.text(child).split_whitespace())
+            .collect()
+    }
+}
+
+fn has_child_kind(node: Node, kind: &str) -> bool {
+    let mut cursor = node.walk();
+    node.children(&mut cursor).any(|child| child.kind() == kind)
+}
+
+const RUST_DECLARATIONS: &[&str] = &[
+    "function_item",
+    "function_signature_item",
+    "struct_item",
+    "enum_item",
+    "union_item",
+    "trait_item",
+    "type_item",
+    "const_item",
+    "static_item",
+    "mod_item",
+];
+
+const JS_MEMBERS: &[&str] = &[
+    "method_definition",
+    "public_field_definition",
+    "field_definition",
+    "method_signature",
+    "abstract_method_signature",
+];
+
+const JS_DECLARATIONS: &[&str] = &[
+    "function_declaration",
+    "generator_function_declaration",
+    "function_signature",
+    "class_declaration",
+    "abstract_class_declaration",
+    "interface_declaration",
+    "type_alias_declaration",
+    "enum_declaration",
+    "lexical_declaration",
+    "variable_declaration",
+];
+
+const JAVA_DECLARATIONS: &[&str] = &[
+    "method_declaration",
+    "constructor_declaration",
+    "field_declaration",
+    "class_declaration",
+    "interface_declaration",
+    "enum_declaration",
+    "record_declaration",
+];
+
+const CSHARP_DECLARATIONS: &[&str] = &[
+    "method_declaration",
+    "constructor_declaration",
+    "field_declaration",
+    "property_declaration",
+    "indexer_declaration",
+    "operator_declaration",
+    "class_declaration",
+    "struct_declaration",
+    "record_declaration",
+    "interface_declaration",
+    "enum_declaration",
+];
+
+const KOTLIN_DECLARATIONS: &[&str] = &[
+    "function_declaration",
+    "class_declaration",
+    "object_declaration",
+    "property_declaration",
+    "secondary_constructor",
+    "type_alias",
+];
+
+const SWIFT_DECLARATIONS: &[&str] = &[
+    "function_declaration",
+    "class_declaration",
+    "protocol_declaration",
+    "property_declaration",
+    "init_declaration",
+    "typealias_declaration",
+];
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    /// First lines of the outermost declarations `PrivateFilter` hides,
+    /// without a trailing `;` (grammars differ on whether it is included).
+    fn hidden(source: &str, language: Language) -> Vec<String> {
+        let tree = Parser::new(language).unwrap().parse(source).unwrap();
+        let root = tree.root_node();
+        let filter = PrivateFilter::new(root, source, language);
+        let mut out = Vec::new();
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if filter.hides(node) {
+                let line = node.utf8_text(source.as_bytes()).unwrap().lines().next();
+                out.push(line.unwrap().trim().trim_end_matches(';').to_string());
+                continue;
+            }
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
+        }
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn test_rust_private_items_and_trait_members() {
+        let source = "pub fn api() {}\nfn helper() {}\npub(crate) struct Inner;\n\
+                      impl Display for Inner {\n    fn fmt(&self) {}\n}\n\
+                      impl Inner {\n    fn secret(&self) {}\n    pub fn open(&self) {}\n}\n";
+        assert_eq!(
+            hidden(source, Language::Rust),
+            ["fn helper() {}", "fn secret(&self) {}"]
+        );
+    }
+
+    #[test]
+    fn test_python_underscore_names() {
+        let source = "def run():\n    pass\n\ndef _helper():\n    pass\n\n\
+                      class Api:\n    def __init__(self):\n        pass\n\n    def _cache(self):\n        pass\n";
+        assert_eq!(
+            hidden(source, Language::Python),
+            ["def _cache(self):", "def _helper():"]
+        );
+    }
+
+    #[test]
+    fn test_go_lowercase_identifiers() {
+        let source = "package p\n\nfunc Serve() {}\n\nfunc helper() {}\n\ntype config struct{}\n";
+        assert_eq!(
+            hidden(source, Language::Go),
+            ["func helper() {}", "type config struct{}"]
+        );
+    }
+
+    #[test]
+    fn test_typescript_exports_and_private_members() {
+        let source = "export class Api {\n  private token = '';\n  #retries = 0;\n  fetch() {}\n}\n\
+                      function helper() {}\n";
+        assert_eq!(
+            hidden(source, Language::TypeScript),
+            ["#retries = 0", "function helper() {}", "private token = ''"]
+        );
+    }
+
+    #[test]
+    fn test_typescript_export_clause_counts_as_export() {
+        let source = "function helper() {}\nconst util = () => 1;\nfunction hidden() {}\n\
+                      export { helper };\nexport default util;\n";
+        assert_eq!(
+            hidden(source, Language::TypeScript),
+            ["function hidden() {}"]
+        );
+    }
+
+    #[test]
+    fn test_typescript_without_exports_keeps_top_level() {
+        let source = "function main() {}\nfunction helper() {}\n";
+        assert!(hidden(source, Language::TypeScript).is_empty());
+    }
+
+    #[test]
+    fn test_csharp_default_member_access_is_private() {
+        let source = "public class Api {\n    public void Run() {}\n    void Helper() {}\n    private int _count;\n}\n\
+                      interface IApi {\n    void Run();\n}\n";
+        assert_eq!(
+            hidden(source, Language::CSharp),
+            ["private int _count", "void Helper() {}"]
+        );
+    }
+
+    #[test]
+    fn test_cpp_access_sections_and_static_functions() {
+        let source = "static int helper() { return 0; }\nint api() { return 1; }\n\
+                      class Box {\n    int raw;\npublic:\n    int size() const;\nprivate:\n    void grow();\n};\n";
+        assert_eq!(
+            hidden(source, Language::Cpp),
+            [
+                "int raw",
+                "static int helper() { return 0; }",
+                "void grow()"
+            ]
+        );
+    }
+}
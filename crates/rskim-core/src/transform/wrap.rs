@@ -0,0 +1,154 @@
+//! Display-width soft wrapping for signature lines (`--wrap`)
+//!
+//! ARCHITECTURE: Applied to assembled signatures one source line at a time,
+//! so every wrapped segment still maps back to the source line it came from.
+//! Widths are terminal columns as reported by `unicode-width`: CJK and most
+//! emoji count two, combining marks zero. Wrapping only inserts line breaks
+//! and indentation; no characters are dropped.
+
+use unicode_width::UnicodeWidthChar;
+
+/// Extra indentation for continuation segments, on top of the line's own.
+const CONTINUATION_INDENT: &str = "    ";
+
+/// Columns a tab counts for (signature lines are measured, never expanded).
+const TAB_WIDTH: usize = 4;
+
+/// Split `line` into segments no wider than `width` display columns.
+///
+/// Breaks at the last space that fits; a run with no usable space (a long
+/// generic type, a URL in a default value) is broken between characters.
+/// Continuation segments are indented four columns past the line's own
+/// indentation, or not at all when that indentation would take more than
+/// half the width. A line that already fits is returned unchanged as a
+/// single segment.
+pub(crate) fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    if display_width(line) <= width {
+        return vec![line.to_string()];
+    }
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let mut continuation = format!("{indent}{CONTINUATION_INDENT}");
+    if display_width(&continuation) * 2 > width {
+        continuation.clear();
+    }
+    let continuation_width = display_width(&continuation);
+
+    let mut segments = Vec::new();
+    let mut current = String::with_capacity(line.len());
+    let mut current_width = 0;
+    // Leading bytes of `current` that are indentation, never a break point
+    let mut content_start = indent.len();
+    // Byte offset in `current` of the last space the segment could end at
+    let mut last_space: Option<usize> = None;
+
+    for ch in line.chars() {
+        let ch_width = char_width(ch);
+        if current_width + ch_width > width && current.len() > content_start {
+            // Carry the partial word after the last space onto the next
+            // segment, unless it would not fit there either.
+            let carried = match last_space {
+                Some(at)
+                    if continuation_width + display_width(&current[at + 1..]) + ch_width
+                        <= width =>
+                {
+                    let carried = current[at + 1..].to_string();
+                    current.truncate(at);
+                    carried
+                }
+                _ => String::new(),
+            };
+            segments.push(current.trim_end().to_string());
+            current.clear();
+            current.push_str(&continuation);
+            current.push_str(&carried);
+            current_width = continuation_width + display_width(&carried);
+            content_start = continuation.len();
+            last_space = None;
+            if ch == ' ' && carried.is_empty() {
+                continue; // Don't open a continuation with the break's space
+            }
+        }
+        if ch == ' ' && current.len() > content_start {
+            last_space = Some(current.len());
+        }
+        current.push(ch);
+        current_width += ch_width;
+    }
+    segments.push(current);
+    segments
+}
+
+/// Display width of `text` in terminal columns.
+pub(crate) fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+fn char_width(ch: char) -> usize {
+    if ch == '\t' {
+        TAB_WIDTH
+    } else {
+        ch.width().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_line_that_fits_is_unchanged() {
+        assert_eq!(wrap_line("fn run(a: u32)", 14), vec!["fn run(a: u32)"]);
+    }
+
+    #[test]
+    fn test_wrap_breaks_at_last_fitting_space() {
+        assert_eq!(
+            wrap_line("fn connect(host: &str, port: u16, timeout: Duration)", 24),
+            vec![
+                "fn connect(host: &str,",
+                "    port: u16, timeout:",
+                "    Duration)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrap_keeps_indentation_for_continuations() {
+        assert_eq!(
+            wrap_line("    def fetch(self, key, default=None)", 28),
+            vec!["    def fetch(self, key,", "        default=None)"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_hard_breaks_long_words() {
+        assert_eq!(
+            wrap_line("abcdefghijklmnop", 10),
+            vec!["abcdefghij", "    klmnop"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_counts_wide_characters_as_two_columns() {
+        // Each CJK character is two columns wide: six of them fill 12.
+        let segments = wrap_line("fn 名前を取得する(id: u32)", 12);
+        assert!(
+            segments.iter().all(|s| display_width(s) <= 12),
+            "{segments:?}"
+        );
+        assert_eq!(
+            segments.concat().replace(' ', ""),
+            "fn名前を取得する(id:u32)"
+        );
+    }
+
+    #[test]
+    fn test_wrap_emoji_never_split_mid_character() {
+        let line = "fn react(👍: Emoji, 🎉: Emoji, 🚀: Emoji)";
+        for width in 8..line.len() {
+            for segment in wrap_line(line, width) {
+                assert!(display_width(&segment) <= width, "{width}: {segment:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,569 @@
+//! XML structure extraction
+//!
+//! ARCHITECTURE: XML is scanned by hand rather than through a grammar or a
+//! serde model — the output only needs element names, attribute names, and
+//! nesting, and a scanner keeps going past the malformed fragments real-world
+//! files (templated manifests, hand-edited POMs) tend to contain.
+//!
+//! # Output Format (Structure / Signatures / Types)
+//!
+//! ```text
+//! <project xmlns="...">
+//!   <modelVersion>...</modelVersion>
+//!   <dependencies>
+//!     <dependency scope="...">
+//!       <groupId>...</groupId>
+//!     </dependency>
+//!   </dependencies>
+//! </project>
+//! ```
+//!
+//! # Rules
+//! - Element nesting and attribute names are kept; attribute values become `"..."`
+//! - Text and CDATA collapse to `...`; elements holding only text stay on one line
+//! - Empty elements render self-closing (`<br/>`)
+//! - Comments, processing instructions (including `<?xml ...?>`) and `<!DOCTYPE>`
+//!   are dropped
+//! - Output is re-indented two spaces per level
+//! - Mismatched or unclosed tags set `has_errors`; the scanner recovers by
+//!   closing open elements up to the nearest matching name
+//!
+//! The same scanner builds the element tree for HTML outlines (see `html`)
+//! under [`Dialect::Html`].
+
+use crate::{Result, SkimError};
+
+/// Maximum element nesting depth
+///
+/// SECURITY: Matches MAX_JSON_DEPTH / MAX_YAML_DEPTH; bounds recursion when
+/// rendering.
+const MAX_XML_DEPTH: usize = 500;
+
+/// Maximum number of elements per file
+///
+/// SECURITY: Bounds memory use on adversarial input. Exceeding the cap
+/// degrades to passthrough like every other complexity limit.
+const MAX_XML_ELEMENTS: usize = 100_000;
+
+/// Markup flavour the scanner follows.
+///
+/// HTML differs from XML in ways that change the tree, not just the output:
+/// case-insensitive names, void elements without closing tags, optional end
+/// tags (`<li>`, `<p>`, ...), raw-text `<script>`/`<style>` contents, and
+/// `<textarea>`/`<title>` contents that are text even when they look like
+/// markup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Dialect {
+    Xml,
+    Html,
+}
+
+/// HTML elements that never have content or a closing tag.
+pub(super) const HTML_VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// HTML elements whose contents are raw text, dropped with the element.
+const HTML_RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+/// HTML elements whose contents are text up to their end tag (`<b>` inside a
+/// `<textarea>` is not an element).
+const HTML_ESCAPABLE_RAW_TEXT_ELEMENTS: &[&str] = &["textarea", "title"];
+
+/// HTML elements whose text is collected into [`Element::text`].
+pub(super) const HTML_TEXT_ELEMENTS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6", "title"];
+
+/// HTML elements whose end tag may be omitted, each with the sibling tags
+/// that implicitly close it.
+const HTML_OPTIONAL_END: &[(&str, &[&str])] = &[
+    ("li", &["li"]),
+    ("dt", &["dt", "dd"]),
+    ("dd", &["dt", "dd"]),
+    (
+        "p",
+        &[
+            "p", "div", "ul", "ol", "table", "section", "h1", "h2", "h3", "h4", "h5", "h6",
+        ],
+    ),
+    ("option", &["option", "optgroup"]),
+    ("tr", &["tr"]),
+    ("td", &["td", "th", "tr"]),
+    ("th", &["td", "th", "tr"]),
+];
+
+/// An attribute on an element.
+pub(super) struct Attr<'a> {
+    pub(super) name: &'a str,
+    /// Raw value without quotes; `None` for a bare attribute (`<input disabled>`).
+    pub(super) value: Option<&'a str>,
+}
+
+/// An element collected by the scanner.
+pub(super) struct Element<'a> {
+    pub(super) name: &'a str,
+    /// Attributes in source order.
+    pub(super) attrs: Vec<Attr<'a>>,
+    pub(super) children: Vec<Element<'a>>,
+    /// Whether the element directly contains non-whitespace text or CDATA.
+    pub(super) has_text: bool,
+    /// Raw text of the element and its descendants, concatenated; only
+    /// collected for [`HTML_TEXT_ELEMENTS`].
+    pub(super) text: String,
+}
+
+impl<'a> Element<'a> {
+    fn new(name: &'a str, attrs: Vec<Attr<'a>>) -> Self {
+        Self {
+            name,
+            attrs,
+            children: Vec::new(),
+            has_text: false,
+            text: String::new(),
+        }
+    }
+
+    /// Whether this element's name is `name` under `dialect`'s case rules.
+    pub(super) fn is(&self, name: &str, dialect: Dialect) -> bool {
+        match dialect {
+            Dialect::Xml => self.name == name,
+            Dialect::Html => self.name.eq_ignore_ascii_case(name),
+        }
+    }
+
+    pub(super) fn is_any(&self, names: &[&str], dialect: Dialect) -> bool {
+        names.iter().any(|name| self.is(name, dialect))
+    }
+}
+
+/// Skim an XML document to its element and attribute-name hierarchy,
+/// returning `(content, has_errors)`.
+pub(crate) fn transform_xml(source: &str) -> Result<(String, bool)> {
+    let (roots, has_errors) = parse(source, Dialect::Xml)?;
+    let mut result = String::with_capacity(source.len() / 4);
+    for root in &roots {
+        render(root, 0, &mut result);
+    }
+    Ok((result, has_errors))
+}
+
+/// Scan `source` into a forest of elements, returning `(roots, has_errors)`.
+pub(super) fn parse(source: &str, dialect: Dialect) -> Result<(Vec<Element<'_>>, bool)> {
+    let html = dialect == Dialect::Html;
+    let bytes = source.as_bytes();
+    let mut roots: Vec<Element<'_>> = Vec::new();
+    let mut stack: Vec<Element<'_>> = Vec::new();
+    let mut element_count = 0;
+    let mut has_errors = false;
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        if bytes[pos] != b'<' {
+            // Text run up to the next tag. `pos` always sits on an ASCII
+            // delimiter or the start of input, so slicing is char-safe.
+            let end = source[pos..].find('<').map_or(bytes.len(), |i| pos + i);
+            let text = &source[pos..end];
+            if !text.trim().is_empty()
+                && let Some(open) = stack.last_mut()
+            {
+                open.has_text = true;
+            }
+            if html {
+                collect_text(&mut stack, text);
+            }
+            pos = end;
+            continue;
+        }
+
+        let rest = &source[pos..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            has_errors |= !comment.contains("-->"); // Unterminated comment
+            pos = skip_past(source, pos + 4, "-->");
+        } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            if let Some(open) = stack.last_mut() {
+                open.has_text = true;
+            }
+            has_errors |= !cdata.contains("]]>"); // Unterminated CDATA
+            pos = skip_past(source, pos + 9, "]]>");
+        } else if rest.starts_with("<?") {
+            pos = skip_past(source, pos + 2, "?>");
+        } else if rest.starts_with("<!") {
+            pos = skip_declaration(bytes, pos + 2);
+        } else if rest.starts_with("</") {
+            let name_end = name_end(bytes, pos + 2);
+            let name = &source[pos + 2..name_end];
+            pos = skip_past(source, name_end, ">");
+            match stack.iter().rposition(|e| e.is(name, dialect)) {
+                Some(idx) => {
+                    while stack.len() > idx {
+                        if let Some(done) = stack.pop() {
+                            // Elements left open inside the closed one are
+                            // errors unless HTML lets their end tag be omitted.
+                            let implicit = html && optional_end_closers(&done).is_some();
+                            has_errors |= stack.len() > idx && !implicit;
+                            attach(done, &mut stack, &mut roots);
+                        }
+                    }
+                }
+                None => has_errors = true, // Stray closing tag
+            }
+        } else if bytes.get(pos + 1).is_some_and(|&b| is_name_start(b)) {
+            let name_end = name_end(bytes, pos + 1);
+            let name = &source[pos + 1..name_end];
+            let (attrs, tag_end, self_closing, terminated) = scan_attributes(source, name_end);
+            has_errors |= !terminated;
+            pos = tag_end;
+
+            let mut element = Element::new(name, attrs);
+            if html && element.is_any(HTML_RAW_TEXT_ELEMENTS, dialect) {
+                // Skip the contents and the element itself.
+                if !self_closing {
+                    pos = raw_text_end(source, pos, name).1;
+                }
+                continue;
+            }
+
+            element_count += 1;
+            if element_count > MAX_XML_ELEMENTS {
+                return Err(SkimError::ComplexityLimit {
+                    what: "XML elements",
+                    count: element_count,
+                    max: MAX_XML_ELEMENTS,
+                });
+            }
+
+            if html {
+                close_implied(&mut stack, &mut roots, &element);
+            }
+            if self_closing || (html && element.is_any(HTML_VOID_ELEMENTS, dialect)) {
+                attach(element, &mut stack, &mut roots);
+            } else if html && element.is_any(HTML_ESCAPABLE_RAW_TEXT_ELEMENTS, dialect) {
+                let (text_end, end) = raw_text_end(source, pos, name);
+                let text = &source[pos..text_end];
+                element.has_text = !text.trim().is_empty();
+                if element.is_any(HTML_TEXT_ELEMENTS, dialect) {
+                    element.text.push_str(text);
+                }
+                pos = end;
+                attach(element, &mut stack, &mut roots);
+            } else {
+                if stack.len() >= MAX_XML_DEPTH {
+                    return Err(SkimError::ComplexityLimit {
+                        what: "XML nesting depth",
+                        count: stack.len() + 1,
+                        max: MAX_XML_DEPTH,
+                    });
+                }
+                stack.push(element);
+            }
+        } else {
+            // A bare `<` that opens nothing: treat it as text.
+            if let Some(open) = stack.last_mut() {
+                open.has_text = true;
+            }
+            has_errors |= !html; // Common in hand-written HTML (`a < b`)
+            pos += 1;
+        }
+    }
+
+    // Unclosed elements at end of input. HTML routinely omits `</body>`-style
+    // end tags, so only XML flags them.
+    has_errors |= !html && !stack.is_empty();
+    while let Some(done) = stack.pop() {
+        attach(done, &mut stack, &mut roots);
+    }
+
+    Ok((roots, has_errors))
+}
+
+/// Attach a finished element to its parent, or to the roots at top level.
+fn attach<'a>(element: Element<'a>, stack: &mut [Element<'a>], roots: &mut Vec<Element<'a>>) {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(element),
+        None => roots.push(element),
+    }
+}
+
+/// The tags that implicitly close `element`, if HTML lets its end tag be omitted.
+fn optional_end_closers(element: &Element<'_>) -> Option<&'static [&'static str]> {
+    HTML_OPTIONAL_END
+        .iter()
+        .find(|(name, _)| element.is(name, Dialect::Html))
+        .map(|(_, closers)| *closers)
+}
+
+/// Close the open HTML elements whose end tags are implied by `next` starting
+/// (a `<li>` following an unclosed `<li>`; a `<tr>` closing both an open
+/// `<td>` and its row).
+fn close_implied<'a>(
+    stack: &mut Vec<Element<'a>>,
+    roots: &mut Vec<Element<'a>>,
+    next: &Element<'a>,
+) {
+    while stack
+        .last()
+        .and_then(optional_end_closers)
+        .is_some_and(|closers| next.is_any(closers, Dialect::Html))
+    {
+        if let Some(done) = stack.pop() {
+            attach(done, stack, roots);
+        }
+    }
+}
+
+/// Append `text` to the innermost open element that collects text.
+fn collect_text(stack: &mut [Element<'_>], text: &str) {
+    let Some(owner) = stack
+        .iter_mut()
+        .rev()
+        .find(|e| e.is_any(HTML_TEXT_ELEMENTS, Dialect::Html))
+    else {
+        return;
+    };
+    owner.text.push_str(text);
+}
+
+/// Position just past the next `terminator` at or after `from`, or end of input.
+fn skip_past(source: &str, from: usize, terminator: &str) -> usize {
+    source
+        .get(from..)
+        .and_then(|rest| rest.find(terminator))
+        .map_or(source.len(), |i| from + i + terminator.len())
+}
+
+/// Find the end of raw-text contents starting at `from`: returns where the
+/// `</name>` end tag (ASCII case-insensitive) starts and the position just
+/// past it, or end of input for both.
+fn raw_text_end(source: &str, from: usize, name: &str) -> (usize, usize) {
+    let closing = format!("</{}", name.to_ascii_lowercase());
+    source
+        .get(from..)
+        .and_then(|rest| rest.to_ascii_lowercase().find(&closing))
+        .map_or((source.len(), source.len()), |i| {
+            (from + i, skip_past(source, from + i, ">"))
+        })
+}
+
+/// Skip a `<!DOCTYPE ...>`-style declaration, including any `[...]` internal
+/// subset, returning the position after its closing `>`.
+fn skip_declaration(bytes: &[u8], mut pos: usize) -> usize {
+    let mut bracket_depth = 0usize;
+    let mut quote: Option<u8> = None;
+    while pos < bytes.len() {
+        let b = bytes[pos];
+        pos += 1;
+        match (quote, b) {
+            (Some(q), _) if b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(b),
+            (None, b'[') => bracket_depth += 1,
+            (None, b']') => bracket_depth = bracket_depth.saturating_sub(1),
+            (None, b'>') if bracket_depth == 0 => return pos,
+            _ => {}
+        }
+    }
+    bytes.len()
+}
+
+/// Scan the attributes of an opening tag starting at `pos` (just after the
+/// element name).
+///
+/// Returns `(attributes, tag_end, self_closing, terminated)` where `tag_end`
+/// is the position after the closing `>` and `terminated` is false when input
+/// ended inside the tag. Values may be double-quoted, single-quoted (both may
+/// contain `>` and `/`), or unquoted up to whitespace or `>`. Names may also
+/// start with `@` or `#` (Vue's `@click` and `#slot` shorthands).
+fn scan_attributes(source: &str, mut pos: usize) -> (Vec<Attr<'_>>, usize, bool, bool) {
+    let bytes = source.as_bytes();
+    let skip_ws = |mut p: usize| {
+        while bytes.get(p).is_some_and(u8::is_ascii_whitespace) {
+            p += 1;
+        }
+        p
+    };
+    let mut attrs = Vec::new();
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'>' => return (attrs, pos + 1, false, true),
+            b'/' if bytes.get(pos + 1) == Some(&b'>') => return (attrs, pos + 2, true, true),
+            b if is_name_start(b) || matches!(b, b'@' | b'#') => {
+                let end = name_end(bytes, pos);
+                let name = &source[pos..end];
+                let after = skip_ws(end);
+                if bytes.get(after) != Some(&b'=') {
+                    attrs.push(Attr { name, value: None });
+                    pos = end;
+                    continue;
+                }
+                let value_start = skip_ws(after + 1);
+                let (value, next) = match bytes.get(value_start) {
+                    Some(&quote @ (b'"' | b'\'')) => {
+                        let open = value_start + 1;
+                        let close = bytes[open..]
+                            .iter()
+                            .position(|&b| b == quote)
+                            .map_or(bytes.len(), |i| open + i);
+                        (&source[open..close], (close + 1).min(bytes.len()))
+                    }
+                    _ => {
+                        let close = bytes[value_start..]
+                            .iter()
+                            .position(|&b| b.is_ascii_whitespace() || b == b'>')
+                            .map_or(bytes.len(), |i| value_start + i);
+                        (&source[value_start..close], close)
+                    }
+                };
+                attrs.push(Attr {
+                    name,
+                    value: Some(value),
+                });
+                pos = next;
+            }
+            _ => pos += 1,
+        }
+    }
+
+    (attrs, bytes.len(), false, false)
+}
+
+/// Whether `b` can start an element or attribute name.
+///
+/// Non-ASCII bytes are accepted so names in other scripts are kept whole.
+fn is_name_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || b == b':' || !b.is_ascii()
+}
+
+/// End of the name starting at `pos`: the first whitespace, `=`, `/`, `>`, or quote.
+fn name_end(bytes: &[u8], pos: usize) -> usize {
+    bytes[pos..]
+        .iter()
+        .position(|&b| b.is_ascii_whitespace() || matches!(b, b'=' | b'/' | b'>' | b'"' | b'\''))
+        .map_or(bytes.len(), |i| pos + i)
+}
+
+/// Render one element and its descendants at `depth`.
+fn render(element: &Element<'_>, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&indent);
+    out.push('<');
+    out.push_str(element.name);
+    for attr in &element.attrs {
+        out.push(' ');
+        out.push_str(attr.name);
+        if attr.value.is_some() {
+            out.push_str("=\"...\"");
+        }
+    }
+
+    if element.children.is_empty() {
+        if element.has_text {
+            out.push_str(">...</");
+            out.push_str(element.name);
+            out.push_str(">\n");
+        } else {
+            out.push_str("/>\n");
+        }
+        return;
+    }
+
+    out.push_str(">\n");
+    for child in &element.children {
+        render(child, depth + 1, out);
+    }
+    out.push_str(&indent);
+    out.push_str("</");
+    out.push_str(element.name);
+    out.push_str(">\n");
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    const POM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Build file -->
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <dependencies>
+    <dependency scope="test">
+      <groupId>junit</groupId>
+      <artifactId>junit</artifactId>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+    fn skim(source: &str) -> String {
+        transform_xml(source).unwrap().0
+    }
+
+    #[test]
+    fn test_xml_keeps_hierarchy_and_collapses_values() {
+        let (result, has_errors) = transform_xml(POM).unwrap();
+        assert_eq!(
+            result,
+            "<project xmlns=\"...\">\n  <modelVersion>...</modelVersion>\n  <dependencies>\n    \
+             <dependency scope=\"...\">\n      <groupId>...</groupId>\n      \
+             <artifactId>...</artifactId>\n    </dependency>\n  </dependencies>\n</project>\n"
+        );
+        assert!(!has_errors);
+    }
+
+    #[test]
+    fn test_xml_self_closing_and_empty_elements() {
+        let result = skim(
+            "<manifest package=\"a.b\">\n  <uses-permission android:name=\"x\"/>\n  <application></application>\n</manifest>\n",
+        );
+        assert_eq!(
+            result,
+            "<manifest package=\"...\">\n  <uses-permission android:name=\"...\"/>\n  <application/>\n</manifest>\n"
+        );
+    }
+
+    #[test]
+    fn test_xml_quoted_values_may_contain_markup_characters() {
+        let result = skim("<a href=\"x > y\" title='a/>b'><b/></a>");
+        assert_eq!(result, "<a href=\"...\" title=\"...\">\n  <b/>\n</a>\n");
+    }
+
+    #[test]
+    fn test_xml_cdata_is_text_and_doctype_is_dropped() {
+        let source = "<!DOCTYPE note [\n  <!ENTITY x \"y\">\n]>\n<note><body><![CDATA[<not-a-tag/>]]></body></note>";
+        assert_eq!(skim(source), "<note>\n  <body>...</body>\n</note>\n");
+    }
+
+    #[test]
+    fn test_xml_mixed_content_keeps_children_only() {
+        let result = skim("<p>Hello <b>world</b>!</p>");
+        assert_eq!(result, "<p>\n  <b>...</b>\n</p>\n");
+    }
+
+    #[test]
+    fn test_xml_unclosed_elements_are_recovered_with_errors() {
+        let (result, has_errors) = transform_xml("<a><b><c>text</a>").unwrap();
+        assert_eq!(result, "<a>\n  <b>\n    <c>...</c>\n  </b>\n</a>\n");
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_xml_stray_closing_tag_is_an_error() {
+        let (result, has_errors) = transform_xml("<a></b></a>").unwrap();
+        assert_eq!(result, "<a/>\n");
+        assert!(has_errors);
+    }
+
+    #[test]
+    fn test_xml_depth_cap_is_complexity_limit() {
+        let source = "<a>".repeat(MAX_XML_DEPTH + 1);
+        let err = transform_xml(&source).err().unwrap();
+        assert!(err.is_complexity_limit(), "got: {err}");
+    }
+
+    #[test]
+    fn test_xml_non_ascii_names_and_text() {
+        let result = skim("<données clé=\"é\">texte</données>");
+        assert_eq!(result, "<données clé=\"...\">...</données>\n");
+    }
+}
@@ -37,6 +37,8 @@
 //! - Empty arrays/objects -> just show key name
 //! - Multi-document files -> show all documents with `---` preserved
 //! - Anchors/aliases -> resolved by serde_yaml_ng (not preserved)
+//! - Single-document OpenAPI/Swagger specs -> paths, methods and schema names
+//!   instead (see `openapi`)
 
 use crate::{Result, SkimError};
 use serde_yaml_ng::Value;
@@ -108,6 +110,10 @@ fn transform_single_document(source: &str) -> Result<String> {
     let value: Value = serde_yaml_ng::from_str(source)
         .map_err(|e| SkimError::ParseError(format!("Invalid YAML: {}", e)))?;
 
+    if let Some(summary) = super::openapi::summarize(&value)? {
+        return Ok(summary);
+    }
+
     let mut key_count = 0;
     extract_structure(&value, 0, &mut key_count)
 }
@@ -20,6 +20,9 @@ use thiserror::Error;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     TypeScript,
+    /// TypeScript with JSX (`.tsx`): parsed with the TSX grammar, which
+    /// rejects `<T>expr` type assertions but accepts JSX elements
+    Tsx,
     JavaScript,
     Python,
     Rust,
@@ -36,6 +39,32 @@ pub enum Language {
     Sql,
     Kotlin,
     Swift,
+    Vue,
+    Svelte,
+    Xml,
+    Html,
+    Hcl,
+    Dockerfile,
+    Shell,
+    Elixir,
+    Dart,
+    Solidity,
+    ObjectiveC,
+    Groovy,
+    Julia,
+    Ocaml,
+    /// OCaml interface files (`.mli`): already signatures, passed through in every mode
+    OcamlInterface,
+    /// Delimited data (`.csv`, `.tsv`): summarized as header, column types and row count
+    Csv,
+    /// Environment files (`.env`, `.env.*`): keys only, values redacted in every mode
+    Env,
+    /// INI-style config (`.ini`, `.cfg`, `.conf`): sections and key names, values dropped
+    Ini,
+    /// reStructuredText (`.rst`): section titles, plus directive names outside structure mode
+    Rst,
+    /// AsciiDoc (`.adoc`, `.asciidoc`): section titles, `=` count mapped to header depth
+    AsciiDoc,
 }
 
 impl Language {
@@ -52,7 +81,8 @@ impl Language {
     /// ```
     pub fn from_extension(ext: &str) -> Option<Self> {
         match ext {
-            "ts" | "tsx" | "mts" | "cts" => Some(Self::TypeScript),
+            "ts" | "mts" | "cts" => Some(Self::TypeScript),
+            "tsx" => Some(Self::Tsx),
             "js" | "jsx" | "cjs" | "mjs" => Some(Self::JavaScript),
             "py" | "pyi" => Some(Self::Python),
             "rs" => Some(Self::Rust),
@@ -72,12 +102,76 @@ impl Language {
             "sql" => Some(Self::Sql),
             "kt" | "kts" => Some(Self::Kotlin),
             "swift" => Some(Self::Swift),
+            "vue" => Some(Self::Vue),
+            "svelte" => Some(Self::Svelte),
+            "xml" | "xsd" | "xsl" | "xslt" | "csproj" | "fsproj" | "vbproj" | "props"
+            | "targets" => Some(Self::Xml),
+            "html" | "htm" => Some(Self::Html),
+            "tf" | "hcl" | "tfvars" => Some(Self::Hcl),
+            "dockerfile" | "containerfile" => Some(Self::Dockerfile),
+            "sh" | "bash" | "zsh" => Some(Self::Shell),
+            "ex" | "exs" => Some(Self::Elixir),
+            "dart" => Some(Self::Dart),
+            "sol" => Some(Self::Solidity),
+            "groovy" | "gradle" => Some(Self::Groovy),
+            "jl" => Some(Self::Julia),
+            "ml" => Some(Self::Ocaml),
+            "mli" => Some(Self::OcamlInterface),
+            "csv" | "tsv" => Some(Self::Csv),
+            "env" => Some(Self::Env),
+            "ini" | "cfg" | "conf" | "cnf" | "service" => Some(Self::Ini),
+            "rst" => Some(Self::Rst),
+            "adoc" | "asciidoc" => Some(Self::AsciiDoc),
+            // NOTE: Objective-C headers use .h and are read as C; pass
+            // --language objc for headers with @interface declarations.
+            "m" | "mm" => Some(Self::ObjectiveC),
             _ => None,
         }
     }
 
+    /// Detect language from a Markdown fenced-code info-string tag
+    ///
+    /// Accepts the common long-form names used in fences (`typescript`,
+    /// `python`, `golang`, `c++`, …) and falls back to [`Language::from_extension`]
+    /// for short tags (`ts`, `py`, `rs`). Matching is case-insensitive.
+    ///
+    /// # Examples
+    /// ```
+    /// use rskim_core::Language;
+    ///
+    /// assert_eq!(Language::from_fence_tag("rust"), Some(Language::Rust));
+    /// assert_eq!(Language::from_fence_tag("TS"), Some(Language::TypeScript));
+    /// assert_eq!(Language::from_fence_tag("text"), None);
+    /// ```
+    pub fn from_fence_tag(tag: &str) -> Option<Self> {
+        let tag = tag.to_ascii_lowercase();
+        match tag.as_str() {
+            "typescript" => Some(Self::TypeScript),
+            "javascript" | "node" => Some(Self::JavaScript),
+            "python" | "python3" => Some(Self::Python),
+            "rust" => Some(Self::Rust),
+            "golang" => Some(Self::Go),
+            "c++" => Some(Self::Cpp),
+            "csharp" | "c#" => Some(Self::CSharp),
+            "ruby" => Some(Self::Ruby),
+            "kotlin" => Some(Self::Kotlin),
+            "shell" => Some(Self::Shell),
+            "elixir" => Some(Self::Elixir),
+            "solidity" => Some(Self::Solidity),
+            "objc" | "objective-c" | "objectivec" => Some(Self::ObjectiveC),
+            "julia" => Some(Self::Julia),
+            "ocaml" => Some(Self::Ocaml),
+            other => Self::from_extension(other),
+        }
+    }
+
     /// Detect language from file path
     ///
+    /// Uses the extension, falling back to well-known file names for
+    /// formats conventionally named without one (`Dockerfile`,
+    /// `Dockerfile.prod`, `api.Dockerfile`, `Containerfile`, `.env`,
+    /// `.env.local`).
+    ///
     /// # Security
     /// Rejects paths with parent directory traversal components (`..`)
     /// to prevent path traversal attacks in future caching features.
@@ -96,12 +190,29 @@ impl Language {
         path.extension()
             .and_then(|ext| ext.to_str())
             .and_then(Self::from_extension)
+            .or_else(|| path.file_name()?.to_str().and_then(Self::from_file_name))
+    }
+
+    /// Detect language from an extensionless or suffixed file name.
+    fn from_file_name(name: &str) -> Option<Self> {
+        if name == ".env" || name.starts_with(".env.") {
+            return Some(Self::Env);
+        }
+        if name.ends_with(".Dockerfile") || name.ends_with(".Containerfile") {
+            return Some(Self::Dockerfile);
+        }
+        let stem = name.split_once('.').map_or(name, |(stem, _)| stem);
+        match stem {
+            "Dockerfile" | "dockerfile" | "Containerfile" => Some(Self::Dockerfile),
+            _ => None,
+        }
     }
 
     /// Get language name for display
     pub fn name(self) -> &'static str {
         match self {
             Self::TypeScript => "TypeScript",
+            Self::Tsx => "TSX",
             Self::JavaScript => "JavaScript",
             Self::Python => "Python",
             Self::Rust => "Rust",
@@ -118,6 +229,26 @@ impl Language {
             Self::Sql => "SQL",
             Self::Kotlin => "Kotlin",
             Self::Swift => "Swift",
+            Self::Vue => "Vue",
+            Self::Svelte => "Svelte",
+            Self::Xml => "XML",
+            Self::Html => "HTML",
+            Self::Hcl => "HCL",
+            Self::Dockerfile => "Dockerfile",
+            Self::Shell => "Shell",
+            Self::Elixir => "Elixir",
+            Self::Dart => "Dart",
+            Self::Solidity => "Solidity",
+            Self::ObjectiveC => "Objective-C",
+            Self::Groovy => "Groovy",
+            Self::Julia => "Julia",
+            Self::Ocaml => "OCaml",
+            Self::OcamlInterface => "OCaml interface",
+            Self::Csv => "CSV",
+            Self::Env => "dotenv",
+            Self::Ini => "INI",
+            Self::Rst => "reStructuredText",
+            Self::AsciiDoc => "AsciiDoc",
         }
     }
 
@@ -129,6 +260,7 @@ impl Language {
     pub fn as_str(self) -> &'static str {
         match self {
             Self::TypeScript => "typescript",
+            Self::Tsx => "tsx",
             Self::JavaScript => "javascript",
             Self::Python => "python",
             Self::Rust => "rust",
@@ -145,6 +277,26 @@ impl Language {
             Self::Sql => "sql",
             Self::Kotlin => "kotlin",
             Self::Swift => "swift",
+            Self::Vue => "vue",
+            Self::Svelte => "svelte",
+            Self::Xml => "xml",
+            Self::Html => "html",
+            Self::Hcl => "hcl",
+            Self::Dockerfile => "dockerfile",
+            Self::Shell => "shell",
+            Self::Elixir => "elixir",
+            Self::Dart => "dart",
+            Self::Solidity => "solidity",
+            Self::ObjectiveC => "objc",
+            Self::Groovy => "groovy",
+            Self::Julia => "julia",
+            Self::Ocaml => "ocaml",
+            Self::OcamlInterface => "ocaml-interface",
+            Self::Csv => "csv",
+            Self::Env => "env",
+            Self::Ini => "ini",
+            Self::Rst => "rst",
+            Self::AsciiDoc => "asciidoc",
         }
     }
 
@@ -163,6 +315,7 @@ impl Language {
     pub(crate) fn to_tree_sitter(self) -> Option<tree_sitter::Language> {
         match self {
             Self::TypeScript => Some(tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()),
+            Self::Tsx => Some(tree_sitter_typescript::LANGUAGE_TSX.into()),
             Self::JavaScript => Some(tree_sitter_javascript::LANGUAGE.into()),
             Self::Python => Some(tree_sitter_python::LANGUAGE.into()),
             Self::Rust => Some(tree_sitter_rust::LANGUAGE.into()),
@@ -179,6 +332,26 @@ impl Language {
             Self::Sql => Some(tree_sitter_sequel::LANGUAGE.into()),
             Self::Kotlin => Some(tree_sitter_kotlin_ng::LANGUAGE.into()),
             Self::Swift => Some(tree_sitter_swift::LANGUAGE.into()),
+            Self::Vue => None,    // Uses SFC script extraction, not tree-sitter
+            Self::Svelte => None, // Uses SFC script extraction, not tree-sitter
+            Self::Xml => None,    // Uses the XML element scanner, not tree-sitter
+            Self::Html => None,   // Uses the HTML outline scanner, not tree-sitter
+            Self::Hcl => None,    // Uses the HCL block scanner, not tree-sitter
+            Self::Dockerfile => None, // Uses the Dockerfile instruction scanner, not tree-sitter
+            Self::Groovy => None, // Uses the Groovy/Gradle statement scanner, not tree-sitter
+            Self::Shell => Some(tree_sitter_bash::LANGUAGE.into()),
+            Self::Elixir => Some(tree_sitter_elixir::LANGUAGE.into()),
+            Self::Dart => Some(tree_sitter_dart::language()),
+            Self::Solidity => Some(tree_sitter_solidity::LANGUAGE.into()),
+            Self::ObjectiveC => Some(tree_sitter_objc::LANGUAGE.into()),
+            Self::Julia => Some(tree_sitter_julia::LANGUAGE.into()),
+            Self::Ocaml => Some(tree_sitter_ocaml::LANGUAGE_OCAML.into()),
+            Self::OcamlInterface => Some(tree_sitter_ocaml::LANGUAGE_OCAML_INTERFACE.into()),
+            Self::Csv => None,      // Uses the CSV record scanner, not tree-sitter
+            Self::Env => None,      // Uses the .env key scanner, not tree-sitter
+            Self::Ini => None,      // Uses the INI line scanner, not tree-sitter
+            Self::Rst => None,      // Uses the reStructuredText section scanner, not tree-sitter
+            Self::AsciiDoc => None, // Uses the AsciiDoc section scanner, not tree-sitter
         }
     }
 
@@ -188,6 +361,248 @@ impl Language {
         matches!(self, Self::Json | Self::Yaml | Self::Toml)
     }
 
+    /// Returns true for languages handled by a dedicated text scanner instead of
+    /// tree-sitter or serde (Vue/Svelte single-file components, XML, HTML, HCL,
+    /// Dockerfile, Groovy, CSV, .env, INI, reStructuredText, AsciiDoc).
+    /// Unlike serde formats, SFC output differs per mode, so SFCs do not
+    /// passthrough in minimal/pseudo mode; the others do.
+    pub fn is_scanner_based(self) -> bool {
+        matches!(
+            self,
+            Self::Vue
+                | Self::Svelte
+                | Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Groovy
+                | Self::Csv
+                | Self::Env
+                | Self::Ini
+                | Self::Rst
+                | Self::AsciiDoc
+        )
+    }
+
+    /// Returns true for formats whose values must never be emitted (`.env`
+    /// files).
+    ///
+    /// These are transformed in every mode, including full, and callers must
+    /// not substitute the raw source for the output (e.g. when compressed
+    /// output would be larger than the input).
+    pub fn is_redacted(self) -> bool {
+        matches!(self, Self::Env)
+    }
+
+    /// Average UTF-8 bytes per cl100k token for this language.
+    ///
+    /// Languages with long identifiers and keywords pack more bytes per
+    /// token, while punctuation-heavy data formats (JSON braces and quotes)
+    /// pack fewer. Dividing a byte length by this estimates its token count
+    /// without running a tokenizer.
+    pub fn bytes_per_token(self) -> f64 {
+        match self {
+            Self::Java | Self::CSharp | Self::Kotlin | Self::Swift => 4.0,
+            Self::Python | Self::Ruby | Self::Go => 3.8,
+            Self::Markdown => 4.2,
+            Self::Rust | Self::C | Self::Cpp => 3.5,
+            Self::Json => 3.0,
+            Self::Yaml | Self::Toml => 3.3,
+            Self::Sql => 3.9,
+            _ => 3.6,
+        }
+    }
+
+    /// Explain how `mode` degenerates for this language, if it does.
+    ///
+    /// Returns a one-line note describing the effective behavior and a better
+    /// mode when `mode` is ignored or keeps almost nothing for this language
+    /// (e.g. types mode on JavaScript, signatures mode on JSON). Returns `None`
+    /// when the mode behaves as documented. Callers surface the note as a
+    /// warning; the transform itself is unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use rskim_core::{Language, Mode};
+    ///
+    /// assert!(Language::Json.mode_caveat(Mode::Signatures).is_some());
+    /// assert!(Language::Rust.mode_caveat(Mode::Signatures).is_none());
+    /// ```
+    pub fn mode_caveat(self, mode: Mode) -> Option<&'static str> {
+        match (self, mode) {
+            (
+                Self::Json
+                | Self::Yaml
+                | Self::Toml
+                | Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Csv
+                | Self::Env
+                | Self::Ini,
+                Mode::Signatures | Mode::Docs | Mode::PublicApi | Mode::Types,
+            ) => Some(
+                "data files have no signatures or types; output is the same key structure as \
+                 structure mode (use --mode structure)",
+            ),
+            (
+                Self::Json
+                | Self::Yaml
+                | Self::Toml
+                | Self::Markdown
+                | Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Groovy
+                | Self::Csv
+                | Self::Ini
+                | Self::Rst
+                | Self::AsciiDoc,
+                Mode::Minimal | Mode::Pseudo,
+            ) => Some("this mode returns the file unchanged (use --mode structure to compress it)"),
+            (Self::Env, Mode::Full | Mode::Minimal | Mode::Pseudo) => Some(
+                ".env values are redacted in every mode; output is the same key list as \
+                 structure mode",
+            ),
+            (
+                Self::Json
+                | Self::Yaml
+                | Self::Toml
+                | Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Groovy
+                | Self::Csv
+                | Self::Env
+                | Self::Ini
+                | Self::Rst
+                | Self::AsciiDoc,
+                Mode::Outline,
+            ) => Some(
+                "this format has no line-numbered outline; output is the same as signatures \
+                 mode (use --mode signatures)",
+            ),
+            (
+                Self::Json
+                | Self::Yaml
+                | Self::Toml
+                | Self::Markdown
+                | Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Groovy
+                | Self::Csv
+                | Self::Env
+                | Self::Ini
+                | Self::Rst
+                | Self::AsciiDoc,
+                Mode::Comments | Mode::Todos,
+            ) => Some(
+                "skim does not extract comments from this format; output is its usual \
+                 structural outline (use --mode structure)",
+            ),
+            (
+                Self::Json
+                | Self::Yaml
+                | Self::Toml
+                | Self::Markdown
+                | Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Groovy
+                | Self::Csv
+                | Self::Env
+                | Self::Ini
+                | Self::Rst
+                | Self::AsciiDoc,
+                Mode::CallGraph,
+            ) => Some(
+                "this format has no calls; output is its usual structural outline \
+                 (use --mode structure)",
+            ),
+            (
+                Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Groovy
+                | Self::Csv
+                | Self::Env
+                | Self::Rst
+                | Self::AsciiDoc,
+                Mode::Constants,
+            ) => Some(
+                "skim does not extract constants from this format; output is its usual \
+                 structural outline (use --mode structure)",
+            ),
+            (Self::Markdown | Self::Sql, Mode::Constants) => Some(
+                "skim does not recognize constants in this language; constants output is \
+                 empty (use --mode structure)",
+            ),
+            (
+                Self::Json
+                | Self::Yaml
+                | Self::Toml
+                | Self::Xml
+                | Self::Html
+                | Self::Hcl
+                | Self::Dockerfile
+                | Self::Groovy
+                | Self::Csv
+                | Self::Env
+                | Self::Ini
+                | Self::Rst
+                | Self::AsciiDoc,
+                Mode::Errors,
+            ) => Some(
+                "this format raises no errors; output is its usual structural outline \
+                 (use --mode structure)",
+            ),
+            (Self::Markdown | Self::Sql, Mode::Errors) => Some(
+                "skim does not recognize errors in this language; errors output is empty \
+                 (use --mode structure)",
+            ),
+            (language, Mode::Query) if language.is_serde_based() || language.is_scanner_based() => {
+                Some(
+                    "skim does not parse this format with tree-sitter; query output is empty \
+                     (use --mode structure)",
+                )
+            }
+            (Self::Dart | Self::Sql, Mode::CallGraph) => Some(
+                "skim does not recognize calls in this language; call-graph output is empty \
+                 (use --mode outline)",
+            ),
+            (Self::Groovy, Mode::Signatures | Mode::Docs | Mode::PublicApi | Mode::Types) => Some(
+                "Groovy is scanned, not parsed; output is the same declaration outline as \
+                 structure mode (use --mode structure)",
+            ),
+            (Self::JavaScript, Mode::Types) => Some(
+                "JavaScript has no type declarations; types mode keeps only class declarations \
+                 (use --mode signatures)",
+            ),
+            (Self::Shell, Mode::Types) => Some(
+                "shell scripts have no type declarations; types mode keeps nothing \
+                 (use --mode signatures)",
+            ),
+            (Self::Ruby | Self::Sql | Self::Shell | Self::Elixir | Self::Julia, Mode::Docs) => {
+                Some(
+                    "this language has no doc comment syntax skim recognizes; docs mode keeps \
+                     signatures only (use --mode signatures)",
+                )
+            }
+            (Self::Elixir, Mode::Types) => Some(
+                "Elixir typespecs and structs are macro calls; types mode keeps nothing \
+                 (use --mode signatures)",
+            ),
+            _ => None,
+        }
+    }
+
     /// Transform source code for this language, returning `(content, has_errors)`.
     ///
     /// `has_errors` is `true` when the tree-sitter parser encountered syntax
@@ -240,45 +655,149 @@ impl Language {
         source: &str,
         config: &TransformConfig,
     ) -> Result<(String, bool, Option<Vec<usize>>, bool)> {
+        if let Some(result) = self.transform_unparsed_with_line_map(source, config) {
+            return result;
+        }
+
+        // Tree-sitter path (all non-serde languages in Structure/Signatures/Types/Minimal/Pseudo)
+        let mut parser = Parser::new(self)?;
+        let tree = parser.parse(source)?;
+        self.transform_parsed_with_line_map(source, &tree, config)
+    }
+
+    /// [`transform_source_with_line_map`](Self::transform_source_with_line_map)
+    /// once per config, in order, parsing `source` at most once.
+    ///
+    /// The tree-sitter parse is the shared cost; configs that take the
+    /// passthrough, serde or scanner paths never trigger it.
+    pub(crate) fn transform_source_many_with_line_map(
+        self,
+        source: &str,
+        configs: &[TransformConfig],
+    ) -> Result<Vec<LineMappedOutput>> {
+        let mut parsed = None;
+        configs
+            .iter()
+            .map(|config| {
+                if let Some(result) = self.transform_unparsed_with_line_map(source, config) {
+                    return result;
+                }
+                let tree = if let Some(tree) = &parsed {
+                    tree
+                } else {
+                    parsed.insert(Parser::new(self)?.parse(source)?)
+                };
+                self.transform_parsed_with_line_map(source, tree, config)
+            })
+            .collect()
+    }
+
+    /// The branches of `transform_source_with_line_map` that need no
+    /// tree-sitter parse: passthrough, serde-based and scanner-based.
+    ///
+    /// `None` when the config takes the tree-sitter path.
+    fn transform_unparsed_with_line_map(
+        self,
+        source: &str,
+        config: &TransformConfig,
+    ) -> Option<Result<LineMappedOutput>> {
         debug_assert!(
             !(config.max_lines.is_some() && config.last_lines.is_some()),
             "max_lines and last_lines are mutually exclusive"
         );
 
-        // Passthrough: Full mode (all languages) or Minimal/Pseudo for
-        // serde-based and Markdown languages (no noise to strip).
-        let is_passthrough = config.mode == Mode::Full
+        // Summary mode counts without parsing where skim has no tree-sitter
+        // view of the file.
+        if config.mode == Mode::Summary && (self.is_serde_based() || self.is_scanner_based()) {
+            return Some(crate::transform::summary::transform_summary_unparsed(
+                source, self, config,
+            ));
+        }
+
+        // Query mode has no tree for a query to match.
+        if config.mode == Mode::Query && (self.is_serde_based() || self.is_scanner_based()) {
+            let line_map = config.line_numbers.then(Vec::new);
+            return Some(Ok((String::new(), false, line_map, false)));
+        }
+
+        // Passthrough: Full mode (all languages but redacted ones), Constants
+        // mode for data files (all values), OCaml interfaces (already
+        // signatures), or Minimal/Pseudo for serde-based,
+        // Markdown, and markup/config scanner languages (no noise to strip).
+        let is_passthrough = (config.mode == Mode::Full && !self.is_redacted())
+            || (config.mode == Mode::Constants && (self.is_serde_based() || self == Self::Ini))
+            || (self == Self::OcamlInterface
+                && !matches!(config.mode, Mode::Summary | Mode::Query))
             || (matches!(config.mode, Mode::Minimal | Mode::Pseudo)
-                && (self.is_serde_based() || self == Self::Markdown));
+                && (self.is_serde_based()
+                    || matches!(
+                        self,
+                        Self::Markdown
+                            | Self::Xml
+                            | Self::Html
+                            | Self::Hcl
+                            | Self::Dockerfile
+                            | Self::Groovy
+                            | Self::Csv
+                            | Self::Ini
+                            | Self::Rst
+                            | Self::AsciiDoc
+                    )));
 
         if is_passthrough {
-            let (content, has_errors, line_map) =
-                self.transform_passthrough_with_line_map(source, config)?;
-            return Ok((content, has_errors, line_map, false)); // expected passthrough, not degraded
+            // Expected passthrough, not degraded.
+            let passthrough = self.transform_passthrough_with_line_map(source, config);
+            return Some(
+                passthrough
+                    .map(|(content, has_errors, line_map)| (content, has_errors, line_map, false)),
+            );
         }
 
         // Serde-based non-full modes: restructured output, no meaningful source line map.
         // A key-count cap overflow (ComplexityLimit) degrades to passthrough, mirroring the
         // tree-sitter path. (#317: compress, never truncate; if we can't compress, passthrough.)
         if self.is_serde_based() {
-            return match self.transform_serde_with_line_map(source, config) {
+            return Some(match self.transform_serde_with_line_map(source, config) {
                 Err(e) if e.is_complexity_limit() => {
-                    let (content, _has_errors, line_map) =
-                        self.transform_passthrough_with_line_map(source, config)?;
-                    Ok((content, false, line_map, true)) // degraded: key-count cap hit
+                    let passthrough = self.transform_passthrough_with_line_map(source, config);
+                    // degraded: key-count cap hit
+                    passthrough
+                        .map(|(content, _has_errors, line_map)| (content, false, line_map, true))
                 }
                 Ok((content, has_errors, line_map)) => Ok((content, has_errors, line_map, false)),
                 Err(e) => Err(e),
-            };
+            });
         }
 
-        // Tree-sitter path (all non-serde languages in Structure/Signatures/Types/Minimal/Pseudo)
-        let mut parser = Parser::new(self)?;
-        let tree = parser.parse(source)?;
+        // Scanner-based non-full modes: same shape as the serde path — restructured
+        // output, no meaningful source line map, complexity caps degrade to passthrough.
+        if self.is_scanner_based() {
+            return Some(match self.transform_scanner_with_line_map(source, config) {
+                Err(e) if e.is_complexity_limit() => {
+                    let passthrough = self.transform_passthrough_with_line_map(source, config);
+                    // degraded: scanner cap hit
+                    passthrough
+                        .map(|(content, _has_errors, line_map)| (content, false, line_map, true))
+                }
+                Ok((content, has_errors, line_map)) => Ok((content, has_errors, line_map, false)),
+                Err(e) => Err(e),
+            });
+        }
+
+        None
+    }
+
+    /// Tree-sitter branch of `transform_source_with_line_map`, on an existing parse.
+    fn transform_parsed_with_line_map(
+        self,
+        source: &str,
+        tree: &tree_sitter::Tree,
+        config: &TransformConfig,
+    ) -> Result<(String, bool, Option<Vec<usize>>, bool)> {
         let parse_errors = tree.root_node().has_error();
 
         let (result, line_map) =
-            match crate::transform::transform_tree_with_line_map(source, &tree, self, config) {
+            match crate::transform::transform_tree_with_line_map(source, tree, self, config) {
                 Ok(v) => v,
                 // A structural safety cap overflowed — a legitimate but very large
                 // file (e.g. a machine-generated weight table) that we cannot
@@ -420,19 +939,54 @@ impl Language {
             // SAFETY: callers must only invoke this for is_serde_based() languages.
             _ => unreachable!("transform_serde_with_line_map called for non-serde language"),
         };
-        // Apply max_lines truncation (no meaningful line map for restructured output).
+        let result = self.truncate_restructured(raw_result, config)?;
+        Ok((result, has_errors, None))
+    }
+
+    /// Scanner branch of `transform_source_with_line_map`.
+    ///
+    /// Handles Vue/Svelte SFCs, XML, HTML, HCL, Dockerfiles, Groovy, CSV, INI,
+    /// reStructuredText and AsciiDoc in non-Full modes, and `.env` files in every mode. Like the
+    /// serde branch, the output is restructured, so the source line map is always `None`.
+    fn transform_scanner_with_line_map(
+        self,
+        source: &str,
+        config: &TransformConfig,
+    ) -> Result<(String, bool, Option<Vec<usize>>)> {
+        let (raw_result, has_errors) = match self {
+            Self::Vue | Self::Svelte => crate::transform::sfc::transform_sfc(source, self, config)?,
+            Self::Xml => crate::transform::xml::transform_xml(source)?,
+            Self::Html => crate::transform::html::transform_html(source)?,
+            Self::Hcl => crate::transform::hcl::transform_hcl(source)?,
+            Self::Dockerfile => crate::transform::dockerfile::transform_dockerfile(source)?,
+            Self::Groovy => crate::transform::groovy::transform_groovy(source)?,
+            Self::Csv => crate::transform::csv::transform_csv(source)?,
+            Self::Env => crate::transform::env::transform_env(source)?,
+            Self::Ini => crate::transform::ini::transform_ini(source)?,
+            Self::Rst => crate::transform::rst::transform_rst(source, config.mode)?,
+            Self::AsciiDoc => crate::transform::asciidoc::transform_asciidoc(source, config.mode)?,
+            // SAFETY: callers must only invoke this for is_scanner_based() languages.
+            _ => unreachable!("transform_scanner_with_line_map called for non-scanner language"),
+        };
+        let result = self.truncate_restructured(raw_result, config)?;
+        Ok((result, has_errors, None))
+    }
+
+    /// Apply `max_lines` / `last_lines` to restructured (serde or scanner) output.
+    ///
+    /// There is no meaningful line map for restructured output, so plain line
+    /// truncation is used rather than the AST-aware priority selection.
+    fn truncate_restructured(self, raw_result: String, config: &TransformConfig) -> Result<String> {
         let result = if let Some(max_lines) = config.max_lines {
             crate::transform::truncate::simple_line_truncate(&raw_result, self, max_lines)?
         } else {
             raw_result
         };
-        // Apply last_lines truncation.
-        let result = if let Some(n) = config.last_lines {
-            crate::transform::truncate::simple_last_line_truncate(&result, self, n)?
+        if let Some(n) = config.last_lines {
+            crate::transform::truncate::simple_last_line_truncate(&result, self, n)
         } else {
-            result
-        };
-        Ok((result, has_errors, None))
+            Ok(result)
+        }
     }
 }
 
@@ -469,6 +1023,30 @@ pub enum Mode {
     /// Keeps ONLY callable signatures, removes everything else.
     Signatures,
 
+    /// Keep function/method signatures with their documentation
+    ///
+    /// Token reduction: ~75-90%
+    ///
+    /// Signatures mode plus each signature's attached doc comment (JSDoc,
+    /// rustdoc, Javadoc, Go doc comments, ...) or Python docstring: an API
+    /// reference view of the file.
+    ///
+    /// Only listed in its own cascade: falls back to Signatures, then Types.
+    Docs,
+
+    /// Keep only the public API: exported signatures and type definitions
+    ///
+    /// Token reduction: ~88-96%
+    ///
+    /// Signatures and type definitions, interleaved in source order, minus
+    /// every declaration its language marks as private (see
+    /// [`TransformConfig::public_only`], which this mode implies): `export`
+    /// in TS/JS, `pub` in Rust, capitalized names in Go, `public` in Java.
+    ///
+    /// Not part of any cascade: falling back to types mode would bring
+    /// private types back.
+    PublicApi,
+
     /// Keep only type definitions
     ///
     /// Token reduction: ~90-95%
@@ -534,18 +1112,127 @@ pub enum Mode {
     /// Passthrough (return source unchanged):
     /// - JSON, YAML, TOML, Markdown
     Pseudo,
+
+    /// Outline mode - indented declaration tree with source line numbers
+    ///
+    /// Token reduction: ~95-98%
+    ///
+    /// Lists classes, functions, methods and other named declarations, one
+    /// per line, as `42: fn process_file(...)`, indented by nesting depth.
+    /// Markdown lists its headings. The line numbers let a caller ask for a
+    /// specific body afterwards.
+    Outline,
+
+    /// Comments mode - comments and doc comments only, with their owners
+    ///
+    /// Token reduction: ~85-95%
+    ///
+    /// Keeps every comment, doc comment and Python docstring, each block
+    /// under a `42: Config.validate` header naming the declaration it
+    /// documents or sits in (a bare `42:` at file level). All code is
+    /// dropped. Useful for mining TODOs, design notes and documentation.
+    Comments,
+
+    /// Call-graph mode - `caller -> callee` edges, no code
+    ///
+    /// Token reduction: ~95-99%
+    ///
+    /// Lists each call made inside a declaration as `Config.load -> validate`,
+    /// once per caller and callee, attributing calls to the innermost
+    /// enclosing function, method or class (`(top level)` outside them). With
+    /// [`TransformConfig::dot_graph`] the edges form a Graphviz digraph.
+    CallGraph,
+
+    /// Todos mode - TODO/FIXME/HACK/XXX comment lines, no code
+    ///
+    /// Token reduction: ~98-99%
+    ///
+    /// Lists each comment line carrying a work marker as
+    /// `9: FIXME: reject symlinks (Config.validate)`: its source line, the
+    /// text from the marker on, and the symbol the comment belongs to, as
+    /// comments mode attributes it.
+    Todos,
+
+    /// Summary mode - per-file counts instead of content
+    ///
+    /// Token reduction: ~99%
+    ///
+    /// Prints one line of counts: source lines, functions and methods,
+    /// classes and structs, other type definitions, and the file's estimated
+    /// token count (see [`Language::bytes_per_token`]). A cheap first pass
+    /// over a large tree, to decide what to skim in detail.
+    Summary,
+
+    /// Constants mode - constant, enum and config declarations with values
+    ///
+    /// Token reduction: ~90-98%
+    ///
+    /// Keeps `const`/`static` items, static final fields, `#define` values,
+    /// enums, Python module-level assignments and exported config objects
+    /// verbatim, values included, from outside function bodies. Data files
+    /// (JSON, YAML, TOML, INI) are all values and pass through unchanged.
+    Constants,
+
+    /// Errors mode - error types and the sites that raise errors
+    ///
+    /// Token reduction: ~95-99%
+    ///
+    /// Lists error and exception type declarations by their header line,
+    /// and each `throw`/`raise`/`revert` statement or error-producing call
+    /// (`return Err(...)`, `errors.New`, `panic!`) by its source line with
+    /// the symbol enclosing it: `18: return Err(Missing); (Config.get)`.
+    Errors,
+
+    /// Query mode - nodes captured by a user-supplied tree-sitter query
+    ///
+    /// Token reduction: depends on the query
+    ///
+    /// Runs [`TransformConfig::query`] against the parse tree and emits the
+    /// text of each captured node in document order. Captures named with a
+    /// leading `_` only feed predicates. Without a query the output is empty.
+    Query,
 }
 
 impl Mode {
+    /// Every mode, least to most aggressive.
+    pub const ALL: [Self; 16] = [
+        Self::Full,
+        Self::Minimal,
+        Self::Pseudo,
+        Self::Structure,
+        Self::Docs,
+        Self::Signatures,
+        Self::PublicApi,
+        Self::Types,
+        Self::Comments,
+        Self::Outline,
+        Self::CallGraph,
+        Self::Todos,
+        Self::Summary,
+        Self::Constants,
+        Self::Errors,
+        Self::Query,
+    ];
+
     /// Parse mode from string (for CLI/API)
     pub fn parse(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "structure" => Some(Self::Structure),
             "signatures" => Some(Self::Signatures),
+            "docs" => Some(Self::Docs),
+            "public-api" => Some(Self::PublicApi),
             "types" => Some(Self::Types),
             "full" => Some(Self::Full),
             "minimal" => Some(Self::Minimal),
             "pseudo" => Some(Self::Pseudo),
+            "comments" => Some(Self::Comments),
+            "outline" => Some(Self::Outline),
+            "call-graph" => Some(Self::CallGraph),
+            "todos" => Some(Self::Todos),
+            "summary" => Some(Self::Summary),
+            "constants" => Some(Self::Constants),
+            "errors" => Some(Self::Errors),
+            "query" => Some(Self::Query),
             _ => None,
         }
     }
@@ -555,10 +1242,20 @@ impl Mode {
         match self {
             Self::Structure => "structure",
             Self::Signatures => "signatures",
+            Self::Docs => "docs",
+            Self::PublicApi => "public-api",
             Self::Types => "types",
             Self::Full => "full",
             Self::Minimal => "minimal",
             Self::Pseudo => "pseudo",
+            Self::Comments => "comments",
+            Self::Outline => "outline",
+            Self::CallGraph => "call-graph",
+            Self::Todos => "todos",
+            Self::Summary => "summary",
+            Self::Constants => "constants",
+            Self::Errors => "errors",
+            Self::Query => "query",
         }
     }
 
@@ -569,16 +1266,36 @@ impl Mode {
     /// - Minimal(1): Strip non-doc comments, ~15-30% reduction
     /// - Pseudo(2): Strip syntactic noise, ~30-50% reduction
     /// - Structure(3): Strip bodies, ~70-80% reduction
-    /// - Signatures(4): Signatures only, ~85-92% reduction
-    /// - Types(5): Types only, ~90-95% reduction
+    /// - Docs(4): Signatures with doc comments, ~75-90% reduction
+    /// - Signatures(5): Signatures only, ~85-92% reduction
+    /// - PublicApi(6): Public signatures and types, ~88-96% reduction
+    /// - Types(7): Types only, ~90-95% reduction
+    /// - Comments(8): Comments with their owning symbols, ~85-95% reduction
+    /// - Outline(9): Declaration names and line numbers, ~95-98% reduction
+    /// - CallGraph(10): Caller -> callee edges, ~95-99% reduction
+    /// - Todos(11): TODO/FIXME/HACK/XXX comment lines, ~98-99% reduction
+    /// - Summary(12): Per-file counts, ~99% reduction
+    /// - Constants(13): Constant, enum and config declarations, ~90-98% reduction
+    /// - Errors(14): Error types and raise sites, ~95-99% reduction
+    /// - Query(15): Nodes captured by a user-supplied query, reduction varies
     pub fn aggressiveness(self) -> u8 {
         match self {
             Self::Full => 0,
             Self::Minimal => 1,
             Self::Pseudo => 2,
             Self::Structure => 3,
-            Self::Signatures => 4,
-            Self::Types => 5,
+            Self::Docs => 4,
+            Self::Signatures => 5,
+            Self::PublicApi => 6,
+            Self::Types => 7,
+            Self::Comments => 8,
+            Self::Outline => 9,
+            Self::CallGraph => 10,
+            Self::Todos => 11,
+            Self::Summary => 12,
+            Self::Constants => 13,
+            Self::Errors => 14,
+            Self::Query => 15,
         }
     }
 
@@ -587,6 +1304,11 @@ impl Mode {
     /// Used by the token budget cascade: try each mode from least to most
     /// aggressive until the output fits within the token budget.
     ///
+    /// The view modes (outline, comments, call graph, todos, summary,
+    /// constants, errors and query) pick what to show rather than compress,
+    /// so they are not part of any cascade: each returns only itself, and a
+    /// budget it misses is left to truncation.
+    ///
     /// Returns a static slice to avoid heap allocation on every call.
     ///
     /// # Examples
@@ -618,8 +1340,23 @@ impl Mode {
             ],
             Self::Pseudo => &[Self::Pseudo, Self::Structure, Self::Signatures, Self::Types],
             Self::Structure => &[Self::Structure, Self::Signatures, Self::Types],
+            // Docs keeps more than signatures but is not a step between
+            // structure and signatures: a budget cascade that reaches
+            // signatures was already asked to give up documentation.
+            Self::Docs => &[Self::Docs, Self::Signatures, Self::Types],
             Self::Signatures => &[Self::Signatures, Self::Types],
+            // Types mode has no public filter of its own in a cascade, so
+            // falling back to it would bring private types back.
+            Self::PublicApi => &[Self::PublicApi],
             Self::Types => &[Self::Types],
+            Self::Comments => &[Self::Comments],
+            Self::Outline => &[Self::Outline],
+            Self::CallGraph => &[Self::CallGraph],
+            Self::Todos => &[Self::Todos],
+            Self::Summary => &[Self::Summary],
+            Self::Constants => &[Self::Constants],
+            Self::Errors => &[Self::Errors],
+            Self::Query => &[Self::Query],
         }
     }
 }
@@ -681,6 +1418,115 @@ pub struct TransformConfig {
     /// When `false` (default), the source line map is `None` and no line number
     /// computation is performed.
     pub line_numbers: bool,
+
+    /// Skim fenced code blocks embedded in Markdown.
+    ///
+    /// When `true`, Markdown structure/signatures/types output includes each
+    /// fenced code block tagged with a supported language (```` ```rust ````,
+    /// ```` ```ts ````, …), reduced to its signatures (types in types mode) and
+    /// placed under the heading it belongs to. Untagged blocks and unknown tags
+    /// are omitted as before. Has no effect on other languages.
+    pub fenced_code: bool,
+
+    /// Collapse oversized template literals in structure mode.
+    ///
+    /// Template literals longer than this many bytes (HTML emails, GraphQL
+    /// queries, SQL blobs in top-level constants) are replaced with a `` `...` ``
+    /// placeholder, just as function bodies become `{...}`. Smaller literals are
+    /// kept verbatim. `None` disables collapsing.
+    ///
+    /// Defaults to [`TransformConfig::DEFAULT_TEMPLATE_LITERAL_THRESHOLD`].
+    pub template_literal_threshold: Option<usize>,
+
+    /// Drop declarations their language marks as private.
+    ///
+    /// Applies to structure, signatures and types mode. Private means what the
+    /// source spells out: a missing `pub` (Rust), a `_name` (Python), a
+    /// lowercase identifier (Go), a `private` modifier (Java, C#, Kotlin,
+    /// Swift, TS class members), a non-exported top-level declaration in a
+    /// TS/JS module, or a file-scope `static` (C/C++). Public-API mode
+    /// implies it; other modes ignore it.
+    pub public_only: bool,
+
+    /// Lead signatures and types output with the file-level doc comment.
+    ///
+    /// Rust `//!` lines, a Python module docstring, a Go package comment, or
+    /// a `/** @fileoverview ... */` block is copied verbatim above the
+    /// extracted signatures or types. Structure mode already keeps it.
+    pub module_doc: bool,
+
+    /// Emit Python signatures and types output as a `.pyi` stub.
+    ///
+    /// Instead of a flat list of `def` lines, the output is a valid stub file:
+    /// imports, classes with their nested members, `def` headers ending in
+    /// `...`, and annotated variables without values. Types mode omits
+    /// module-level functions. Has no effect on other languages or modes.
+    pub python_stub: bool,
+
+    /// Emit call-graph output as a Graphviz DOT digraph.
+    ///
+    /// The `caller -> callee` edges become `"caller" -> "callee";` statements
+    /// inside `digraph calls { ... }`, ready for `dot -Tsvg`. Has no effect on
+    /// other modes.
+    pub dot_graph: bool,
+
+    /// Replace Rust function bodies with `{ todo!() }` in structure mode.
+    ///
+    /// Structure mode already keeps `use` declarations, attributes and
+    /// derives; with `{ todo!() }` bodies the skeleton type-checks for any
+    /// return type, so it usually still passes `cargo check` in isolation.
+    /// Functions returning `impl Trait` are the main exception. Has no effect
+    /// on other languages or modes.
+    pub todo_bodies: bool,
+
+    /// Elide parameter lists longer than this in signatures mode.
+    ///
+    /// A signature declaring more than N parameters has its parameter list
+    /// replaced with `(... 12 params)`, which keeps generated API clients
+    /// readable. The full list stays available through
+    /// [`Symbol::parameters`](crate::Symbol::parameters). `None` (default)
+    /// keeps every list. Python `.pyi` stubs are not elided.
+    pub max_params: Option<usize>,
+
+    /// Soft-wrap signature lines wider than this many display columns.
+    ///
+    /// Applies to signatures mode. Widths are measured with `unicode-width`,
+    /// so CJK and emoji count two columns. Long lines break at the last space
+    /// that fits (or between characters in a run without one), and
+    /// continuation lines are indented four columns past the original line.
+    /// `None` (default) never wraps. Markdown headers and Python `.pyi`
+    /// stubs are not wrapped.
+    pub wrap_width: Option<usize>,
+
+    /// Drop declarations nested deeper than this in structure mode.
+    ///
+    /// A declaration inside more than N enclosing declarations (methods of a
+    /// nested class, inner closures, local classes, functions in nested
+    /// modules) is removed entirely, with its doc comments and attributes,
+    /// instead of being emitted. `Some(0)` keeps only top-level declarations;
+    /// `Some(1)` also keeps class members and `impl` methods. Depth is counted
+    /// as for [`Symbol::depth`](crate::Symbol::depth). `None` (default) keeps
+    /// everything. Has no effect on other modes.
+    pub max_nesting_kept: Option<usize>,
+
+    /// Keep the first N statements of each function body in structure mode.
+    ///
+    /// Instead of collapsing a body to `{...}`, structure mode keeps its
+    /// opening statements (guard clauses, early returns, local type hints)
+    /// and replaces the rest with `...` (`todo!()` in Rust with
+    /// [`todo_bodies`](Self::todo_bodies)). Bodies with no more than N
+    /// statements are kept whole; comments do not count as statements. `0`
+    /// (default) collapses every body. Has no effect on other modes.
+    pub body_statements: usize,
+
+    /// Tree-sitter query source for query mode.
+    ///
+    /// Query mode emits the text of every node this query captures, in
+    /// document order; captures named with a leading `_` only feed
+    /// predicates. The query must be written for the file's grammar: one that
+    /// does not compile is a [`SkimError::ConfigError`]. `None` (default)
+    /// makes query output empty. Has no effect on other modes.
+    pub query: Option<String>,
 }
 
 impl Default for TransformConfig {
@@ -692,11 +1538,26 @@ impl Default for TransformConfig {
             max_lines: None,
             last_lines: None,
             line_numbers: false,
+            fenced_code: false,
+            template_literal_threshold: Some(Self::DEFAULT_TEMPLATE_LITERAL_THRESHOLD),
+            public_only: false,
+            module_doc: false,
+            python_stub: false,
+            dot_graph: false,
+            todo_bodies: false,
+            max_params: None,
+            wrap_width: None,
+            max_nesting_kept: None,
+            body_statements: 0,
+            query: None,
         }
     }
 }
 
 impl TransformConfig {
+    /// Default byte length above which structure mode collapses a template literal.
+    pub const DEFAULT_TEMPLATE_LITERAL_THRESHOLD: usize = 512;
+
     /// Create config with specific mode
     pub fn with_mode(mode: Mode) -> Self {
         Self {
@@ -744,6 +1605,78 @@ impl TransformConfig {
         self.line_numbers = enabled;
         self
     }
+
+    /// Builder: Skim language-tagged fenced code blocks in Markdown.
+    pub fn with_fenced_code(mut self, enabled: bool) -> Self {
+        self.fenced_code = enabled;
+        self
+    }
+
+    /// Builder: Set the template literal collapse threshold (`None` disables).
+    pub fn with_template_literal_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.template_literal_threshold = threshold;
+        self
+    }
+
+    /// Builder: Keep only public declarations (structure/signatures/types)
+    pub fn with_public_only(mut self, enabled: bool) -> Self {
+        self.public_only = enabled;
+        self
+    }
+
+    /// Builder: Keep the file-level doc comment in signatures/types output
+    pub fn with_module_doc(mut self, enabled: bool) -> Self {
+        self.module_doc = enabled;
+        self
+    }
+
+    /// Builder: Emit Python signatures/types output as a `.pyi` stub
+    pub fn with_python_stub(mut self, enabled: bool) -> Self {
+        self.python_stub = enabled;
+        self
+    }
+
+    /// Builder: Emit call-graph output as a Graphviz DOT digraph
+    pub fn with_dot_graph(mut self, enabled: bool) -> Self {
+        self.dot_graph = enabled;
+        self
+    }
+
+    /// Builder: Replace Rust function bodies with `{ todo!() }` in structure mode
+    pub fn with_todo_bodies(mut self, enabled: bool) -> Self {
+        self.todo_bodies = enabled;
+        self
+    }
+
+    /// Builder: Elide signature parameter lists longer than `n` parameters
+    pub fn with_max_params(mut self, n: usize) -> Self {
+        self.max_params = Some(n);
+        self
+    }
+
+    /// Builder: Soft-wrap signature lines at `columns` display columns
+    pub fn with_wrap_width(mut self, columns: usize) -> Self {
+        self.wrap_width = Some(columns);
+        self
+    }
+
+    /// Builder: Drop declarations nested deeper than `n` levels in structure mode
+    pub fn with_max_nesting_kept(mut self, n: usize) -> Self {
+        self.max_nesting_kept = Some(n);
+        self
+    }
+
+    /// Builder: Keep the first `n` statements of each body in structure mode
+    pub fn with_body_statements(mut self, n: usize) -> Self {
+        self.body_statements = n;
+        self
+    }
+
+    /// Builder: Set the tree-sitter query run by query mode
+    pub fn with_query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
 }
 
 // ============================================================================
@@ -870,6 +1803,9 @@ impl SkimError {
 /// ARCHITECTURE: Use this instead of std::result::Result throughout the library.
 pub type Result<T> = std::result::Result<T, SkimError>;
 
+/// One line-mapped transform: `(output, has_errors, source_line_map, degraded)`.
+pub(crate) type LineMappedOutput = (String, bool, Option<Vec<usize>>, bool);
+
 // ============================================================================
 // Cache Types (Reserved for Future Library Users)
 // ============================================================================
@@ -961,11 +1897,131 @@ mod tests {
     #[test]
     fn test_language_from_extension() {
         assert_eq!(Language::from_extension("ts"), Some(Language::TypeScript));
-        assert_eq!(Language::from_extension("tsx"), Some(Language::TypeScript));
+        assert_eq!(Language::from_extension("tsx"), Some(Language::Tsx));
         assert_eq!(Language::from_extension("py"), Some(Language::Python));
+        assert_eq!(Language::from_extension("zsh"), Some(Language::Shell));
+        assert_eq!(Language::from_extension("ex"), Some(Language::Elixir));
+        assert_eq!(Language::from_extension("exs"), Some(Language::Elixir));
+        assert_eq!(Language::from_extension("dart"), Some(Language::Dart));
+        assert_eq!(Language::from_extension("sol"), Some(Language::Solidity));
+        assert_eq!(Language::from_extension("m"), Some(Language::ObjectiveC));
+        assert_eq!(Language::from_extension("mm"), Some(Language::ObjectiveC));
+        assert_eq!(Language::from_extension("groovy"), Some(Language::Groovy));
+        assert_eq!(Language::from_extension("gradle"), Some(Language::Groovy));
+        assert_eq!(Language::from_extension("jl"), Some(Language::Julia));
+        assert_eq!(Language::from_extension("ml"), Some(Language::Ocaml));
+        assert_eq!(
+            Language::from_extension("mli"),
+            Some(Language::OcamlInterface)
+        );
+        assert_eq!(Language::from_extension("csv"), Some(Language::Csv));
+        assert_eq!(Language::from_extension("tsv"), Some(Language::Csv));
+        assert_eq!(Language::from_extension("ini"), Some(Language::Ini));
+        assert_eq!(Language::from_extension("cfg"), Some(Language::Ini));
+        assert_eq!(Language::from_extension("conf"), Some(Language::Ini));
+        assert_eq!(Language::from_extension("cnf"), Some(Language::Ini));
+        assert_eq!(Language::from_extension("service"), Some(Language::Ini));
+        assert_eq!(Language::from_extension("rst"), Some(Language::Rst));
+        assert_eq!(Language::from_extension("adoc"), Some(Language::AsciiDoc));
+        assert_eq!(
+            Language::from_extension("asciidoc"),
+            Some(Language::AsciiDoc)
+        );
         assert_eq!(Language::from_extension("unknown"), None);
     }
 
+    #[test]
+    fn test_language_from_fence_tag() {
+        assert_eq!(
+            Language::from_fence_tag("typescript"),
+            Some(Language::TypeScript)
+        );
+        assert_eq!(Language::from_fence_tag("Python"), Some(Language::Python));
+        assert_eq!(Language::from_fence_tag("go"), Some(Language::Go));
+        assert_eq!(Language::from_fence_tag("golang"), Some(Language::Go));
+        assert_eq!(Language::from_fence_tag("c++"), Some(Language::Cpp));
+        assert_eq!(Language::from_fence_tag("sh"), Some(Language::Shell));
+        assert_eq!(Language::from_fence_tag("shell"), Some(Language::Shell));
+        assert_eq!(Language::from_fence_tag("elixir"), Some(Language::Elixir));
+        assert_eq!(
+            Language::from_fence_tag("solidity"),
+            Some(Language::Solidity)
+        );
+        assert_eq!(Language::from_fence_tag("objc"), Some(Language::ObjectiveC));
+        assert_eq!(
+            Language::from_fence_tag("objective-c"),
+            Some(Language::ObjectiveC)
+        );
+        assert_eq!(Language::from_fence_tag("julia"), Some(Language::Julia));
+        assert_eq!(Language::from_fence_tag("ocaml"), Some(Language::Ocaml));
+        assert_eq!(Language::from_fence_tag("text"), None);
+        assert_eq!(Language::from_fence_tag(""), None);
+    }
+
+    #[test]
+    fn test_mode_caveat_flags_degenerate_modes() {
+        assert!(Language::JavaScript.mode_caveat(Mode::Types).is_some());
+        assert!(Language::Yaml.mode_caveat(Mode::Signatures).is_some());
+        assert!(Language::Toml.mode_caveat(Mode::Types).is_some());
+        assert!(Language::Markdown.mode_caveat(Mode::Minimal).is_some());
+        assert!(Language::Json.mode_caveat(Mode::Pseudo).is_some());
+        assert!(Language::Groovy.mode_caveat(Mode::Signatures).is_some());
+        assert!(Language::Groovy.mode_caveat(Mode::Minimal).is_some());
+        assert!(Language::Csv.mode_caveat(Mode::Types).is_some());
+        assert!(Language::Env.mode_caveat(Mode::Full).is_some());
+        assert!(Language::Ini.mode_caveat(Mode::Minimal).is_some());
+        assert!(Language::Rst.mode_caveat(Mode::Pseudo).is_some());
+        assert!(Language::Rst.mode_caveat(Mode::Signatures).is_none());
+        assert!(Language::AsciiDoc.mode_caveat(Mode::Minimal).is_some());
+        assert!(Language::Yaml.mode_caveat(Mode::Outline).is_some());
+        assert!(Language::Json.mode_caveat(Mode::Docs).is_some());
+        assert!(Language::Ruby.mode_caveat(Mode::Docs).is_some());
+        assert!(Language::Rust.mode_caveat(Mode::Docs).is_none());
+        assert!(Language::Yaml.mode_caveat(Mode::PublicApi).is_some());
+        assert!(Language::Go.mode_caveat(Mode::PublicApi).is_none());
+        assert!(Language::Rust.mode_caveat(Mode::Outline).is_none());
+        assert!(Language::Markdown.mode_caveat(Mode::Outline).is_none());
+        assert!(Language::Toml.mode_caveat(Mode::Comments).is_some());
+        assert!(Language::Python.mode_caveat(Mode::Comments).is_none());
+        assert!(Language::Json.mode_caveat(Mode::CallGraph).is_some());
+        assert!(Language::Dart.mode_caveat(Mode::CallGraph).is_some());
+        assert!(Language::Go.mode_caveat(Mode::CallGraph).is_none());
+        assert!(Language::Yaml.mode_caveat(Mode::Todos).is_some());
+        assert!(Language::Rust.mode_caveat(Mode::Todos).is_none());
+        assert!(Language::Html.mode_caveat(Mode::Constants).is_some());
+        assert!(Language::Python.mode_caveat(Mode::Constants).is_none());
+        assert!(Language::Yaml.mode_caveat(Mode::Errors).is_some());
+        assert!(Language::Java.mode_caveat(Mode::Errors).is_none());
+        assert!(Language::Toml.mode_caveat(Mode::Query).is_some());
+        assert!(Language::Markdown.mode_caveat(Mode::Query).is_none());
+
+        assert!(Language::Json.mode_caveat(Mode::Structure).is_none());
+        assert!(Language::Json.mode_caveat(Mode::Constants).is_none());
+        assert!(Language::Markdown.mode_caveat(Mode::Signatures).is_none());
+        assert!(Language::TypeScript.mode_caveat(Mode::Types).is_none());
+        assert!(Language::JavaScript.mode_caveat(Mode::Signatures).is_none());
+        for language in [Language::Json, Language::JavaScript, Language::Rust] {
+            assert!(language.mode_caveat(Mode::Full).is_none());
+        }
+    }
+
+    #[test]
+    fn test_tsx_parses_jsx_with_the_tsx_grammar() {
+        let source = "export function App(): JSX.Element {\n    return <div className=\"app\"><Header /></div>;\n}\n";
+        let mut parser = Parser::new(Language::Tsx).unwrap();
+        assert!(!parser.parse(source).unwrap().root_node().has_error());
+    }
+
+    #[test]
+    fn test_typescript_keeps_angle_bracket_assertions() {
+        // `<T>expr` assertions are invalid TSX; `.ts` files keep the plain grammar.
+        let source = "const n = <number>value;\n";
+        let mut parser = Parser::new(Language::TypeScript).unwrap();
+        assert!(!parser.parse(source).unwrap().root_node().has_error());
+        let mut parser = Parser::new(Language::Tsx).unwrap();
+        assert!(parser.parse(source).unwrap().root_node().has_error());
+    }
+
     #[test]
     fn test_language_from_path() {
         assert_eq!(
@@ -979,15 +2035,73 @@ mod tests {
         assert_eq!(Language::from_path(Path::new("no_extension")), None);
     }
 
+    #[test]
+    fn test_language_from_file_name() {
+        for name in [
+            "Dockerfile",
+            "docker/Dockerfile.prod",
+            "Containerfile",
+            "api.dockerfile",
+            "api.Dockerfile",
+            "build/worker.Containerfile",
+        ] {
+            assert_eq!(
+                Language::from_path(Path::new(name)),
+                Some(Language::Dockerfile),
+                "{name}"
+            );
+        }
+        // A known extension still wins over the name.
+        assert_eq!(
+            Language::from_path(Path::new("Dockerfile.json")),
+            Some(Language::Json)
+        );
+        assert_eq!(Language::from_path(Path::new("Dockerfiles")), None);
+    }
+
+    #[test]
+    fn test_language_from_env_file_name() {
+        for name in [
+            ".env",
+            "app/.env.local",
+            ".env.production.local",
+            "prod.env",
+        ] {
+            assert_eq!(
+                Language::from_path(Path::new(name)),
+                Some(Language::Env),
+                "{name}"
+            );
+        }
+        assert_eq!(Language::from_path(Path::new(".envrc")), None);
+        assert_eq!(
+            Language::from_path(Path::new(".env.json")),
+            Some(Language::Json)
+        );
+    }
+
     #[test]
     fn test_mode_parse() {
         assert_eq!(Mode::parse("structure"), Some(Mode::Structure));
         assert_eq!(Mode::parse("STRUCTURE"), Some(Mode::Structure));
         assert_eq!(Mode::parse("pseudo"), Some(Mode::Pseudo));
         assert_eq!(Mode::parse("PSEUDO"), Some(Mode::Pseudo));
+        assert_eq!(Mode::parse("public-api"), Some(Mode::PublicApi));
         assert_eq!(Mode::parse("invalid"), None);
     }
 
+    #[test]
+    fn test_mode_all_round_trips_and_is_ordered() {
+        for mode in Mode::ALL {
+            assert_eq!(Mode::parse(mode.name()), Some(mode));
+        }
+        assert!(
+            Mode::ALL
+                .windows(2)
+                .all(|w| w[0].aggressiveness() < w[1].aggressiveness())
+        );
+    }
+
     #[test]
     fn test_transform_config_builder() {
         let config = TransformConfig::with_mode(Mode::Signatures)
@@ -1101,8 +2215,18 @@ mod tests {
         assert_eq!(Mode::Minimal.aggressiveness(), 1);
         assert_eq!(Mode::Pseudo.aggressiveness(), 2);
         assert_eq!(Mode::Structure.aggressiveness(), 3);
-        assert_eq!(Mode::Signatures.aggressiveness(), 4);
-        assert_eq!(Mode::Types.aggressiveness(), 5);
+        assert_eq!(Mode::Docs.aggressiveness(), 4);
+        assert_eq!(Mode::Signatures.aggressiveness(), 5);
+        assert_eq!(Mode::PublicApi.aggressiveness(), 6);
+        assert_eq!(Mode::Types.aggressiveness(), 7);
+        assert_eq!(Mode::Comments.aggressiveness(), 8);
+        assert_eq!(Mode::Outline.aggressiveness(), 9);
+        assert_eq!(Mode::CallGraph.aggressiveness(), 10);
+        assert_eq!(Mode::Todos.aggressiveness(), 11);
+        assert_eq!(Mode::Summary.aggressiveness(), 12);
+        assert_eq!(Mode::Constants.aggressiveness(), 13);
+        assert_eq!(Mode::Errors.aggressiveness(), 14);
+        assert_eq!(Mode::Query.aggressiveness(), 15);
     }
 
     #[test]
@@ -1163,6 +2287,112 @@ mod tests {
         assert_eq!(cascade, vec![Mode::Types]);
     }
 
+    #[test]
+    fn test_cascade_from_docs() {
+        let cascade = Mode::Docs.cascade_from_here();
+        assert_eq!(cascade, vec![Mode::Docs, Mode::Signatures, Mode::Types]);
+        assert!(!Mode::Structure.cascade_from_here().contains(&Mode::Docs));
+    }
+
+    #[test]
+    fn test_cascade_from_public_api() {
+        assert_eq!(Mode::PublicApi.cascade_from_here(), vec![Mode::PublicApi]);
+        assert!(
+            !Mode::Signatures
+                .cascade_from_here()
+                .contains(&Mode::PublicApi)
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_outline() {
+        let cascade = Mode::Outline.cascade_from_here();
+        assert_eq!(cascade, vec![Mode::Outline]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::Outline)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::Outline))
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_comments() {
+        assert_eq!(Mode::Comments.cascade_from_here(), vec![Mode::Comments]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::Comments)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::Comments))
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_call_graph() {
+        assert_eq!(Mode::CallGraph.cascade_from_here(), vec![Mode::CallGraph]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::CallGraph)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::CallGraph))
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_todos() {
+        assert_eq!(Mode::Todos.cascade_from_here(), vec![Mode::Todos]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::Todos)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::Todos))
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_summary() {
+        assert_eq!(Mode::Summary.cascade_from_here(), vec![Mode::Summary]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::Summary)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::Summary))
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_constants() {
+        assert_eq!(Mode::Constants.cascade_from_here(), vec![Mode::Constants]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::Constants)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::Constants))
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_errors() {
+        assert_eq!(Mode::Errors.cascade_from_here(), vec![Mode::Errors]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::Errors)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::Errors))
+        );
+    }
+
+    #[test]
+    fn test_cascade_from_query() {
+        assert_eq!(Mode::Query.cascade_from_here(), vec![Mode::Query]);
+        assert!(
+            Mode::ALL
+                .iter()
+                .filter(|&&mode| mode != Mode::Query)
+                .all(|mode| !mode.cascade_from_here().contains(&Mode::Query))
+        );
+    }
+
     #[test]
     fn test_cascade_from_minimal() {
         let cascade = Mode::Minimal.cascade_from_here();
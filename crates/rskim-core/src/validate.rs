@@ -0,0 +1,171 @@
+//! Output validation: re-parse transformed output with the source grammar.
+//!
+//! # Design
+//!
+//! A skimmed file is often handed on to tools that need it to parse —
+//! compilers checking a skeleton, formatters, structured-output consumers.
+//! [`validate_output`] parses the output with the same tree-sitter grammar
+//! as the source and counts syntax errors (`ERROR` and `MISSING` nodes) in
+//! both, so callers can tell errors the transform introduced from errors the
+//! source already had.
+//!
+//! Structure mode replaces bodies with skim's `{...}` placeholder, which is
+//! not valid syntax in most grammars but is an intended elision rather than
+//! a broken transform. Before parsing, the placeholder is normalized to an
+//! empty block `{}`; the rewrite keeps every line in place, so reported
+//! line numbers match the output as printed.
+//!
+//! Languages without a tree-sitter grammar (JSON, YAML, TOML and the
+//! scanner-based formats) have no validator: their output is a key or
+//! section listing, not a document in the source format.
+//!
+//! # Example
+//!
+//! ```
+//! use rskim_core::{validate_output, Language};
+//!
+//! let source = "fn add(a: i32) -> i32 { a + 1 }\n";
+//! let report = validate_output(source, "fn add(a: i32) -> i32 {...}\n", Language::Rust)?
+//!     .expect("Rust has a validator");
+//! assert_eq!(report.introduced_errors(), 0);
+//!
+//! let report = validate_output(source, "fn add(a: i32 -> i32 {...}\n", Language::Rust)?
+//!     .expect("Rust has a validator");
+//! assert!(report.introduced_errors() > 0);
+//! assert_eq!(report.first_error_line, Some(1));
+//! # Ok::<(), rskim_core::SkimError>(())
+//! ```
+
+use crate::transform::structure::BODY_PLACEHOLDER;
+use crate::{Language, Parser, Result};
+use tree_sitter::Tree;
+
+/// Syntax-error counts for a source file and its transformed output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputValidation {
+    /// Syntax errors in the source.
+    pub source_errors: usize,
+    /// Syntax errors in the output, after placeholder normalization.
+    pub output_errors: usize,
+    /// 1-indexed output line of the first output error, if any.
+    pub first_error_line: Option<usize>,
+}
+
+impl OutputValidation {
+    /// Errors in the output beyond those already in the source.
+    #[must_use]
+    pub fn introduced_errors(&self) -> usize {
+        self.output_errors.saturating_sub(self.source_errors)
+    }
+}
+
+/// Parse `source` and `output` with `language`'s grammar and count errors.
+///
+/// Returns `Ok(None)` for languages without a tree-sitter grammar.
+pub(crate) fn validate_output(
+    source: &str,
+    output: &str,
+    language: Language,
+) -> Result<Option<OutputValidation>> {
+    if language.to_tree_sitter().is_none() {
+        return Ok(None);
+    }
+    let mut parser = Parser::new(language)?;
+    let (source_errors, _) = count_errors(&parser.parse(source)?);
+    let normalized = output.replace(BODY_PLACEHOLDER.trim_start(), "{}");
+    let (output_errors, first_error_line) = count_errors(&parser.parse(&normalized)?);
+    Ok(Some(OutputValidation {
+        source_errors,
+        output_errors,
+        first_error_line,
+    }))
+}
+
+/// Count `ERROR` and `MISSING` nodes in `tree`, with the 1-indexed line of
+/// the first one.
+///
+/// An `ERROR` node counts once however many errors recovery nested inside
+/// it, and subtrees without errors are skipped. Walks with a cursor, so deep
+/// trees cannot overflow the stack.
+fn count_errors(tree: &Tree) -> (usize, Option<usize>) {
+    let mut count = 0;
+    let mut first_line = None;
+    let mut cursor = tree.walk();
+    loop {
+        let node = cursor.node();
+        let descend = if node.is_error() || node.is_missing() {
+            count += 1;
+            first_line.get_or_insert(node.start_position().row + 1);
+            false
+        } else {
+            node.has_error()
+        };
+        if descend && cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return (count, first_line);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn validate(source: &str, output: &str, language: Language) -> OutputValidation {
+        validate_output(source, output, language).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_body_placeholder_is_not_an_error() {
+        let report = validate(
+            "function f(a) { return a; }\n",
+            "function f(a) {...}\n",
+            Language::JavaScript,
+        );
+        assert_eq!(report.output_errors, 0);
+        assert_eq!(report.first_error_line, None);
+
+        let report = validate(
+            "def f(x):\n    return x\n",
+            "def f(x):\n     {...}\n",
+            Language::Python,
+        );
+        assert_eq!(report.output_errors, 0);
+    }
+
+    #[test]
+    fn test_source_errors_are_not_introduced() {
+        let report = validate("fn f( {}\n", "fn f( {}\n", Language::Rust);
+        assert!(report.source_errors > 0);
+        assert_eq!(report.output_errors, report.source_errors);
+        assert_eq!(report.introduced_errors(), 0);
+    }
+
+    #[test]
+    fn test_first_error_line_is_one_indexed() {
+        let report = validate(
+            "type A = string;\ntype B = number;\n",
+            "type A = string;\ntype B = ;\n",
+            Language::TypeScript,
+        );
+        assert_eq!(report.introduced_errors(), 1);
+        assert_eq!(report.first_error_line, Some(2));
+    }
+
+    #[test]
+    fn test_languages_without_grammar_have_no_validator() {
+        for language in [
+            Language::Json,
+            Language::Yaml,
+            Language::Toml,
+            Language::Xml,
+        ] {
+            assert_eq!(validate_output("", "", language).unwrap(), None);
+        }
+    }
+}
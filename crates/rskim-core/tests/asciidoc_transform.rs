@@ -0,0 +1,273 @@
+//! AsciiDoc transformation tests — verify section outlines across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const GUIDE_ADOC: &str = include_str!("../../../tests/fixtures/asciidoc/guide.adoc");
+const README_ADOC: &str = include_str!("../../../tests/fixtures/asciidoc/README.adoc");
+const MANUAL_ADOC: &str = include_str!("../../../tests/fixtures/asciidoc/manual.adoc");
+const API_ADOC: &str = include_str!("../../../tests/fixtures/asciidoc/api.adoc");
+const BROKEN_ADOC: &str = include_str!("../../../tests/fixtures/asciidoc/broken.adoc");
+
+const FIXTURES: [(&str, &str); 4] = [
+    ("guide.adoc", GUIDE_ADOC),
+    ("README.adoc", README_ADOC),
+    ("manual.adoc", MANUAL_ADOC),
+    ("api.adoc", API_ADOC),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::AsciiDoc, &config)
+        .unwrap()
+        .1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_asciidoc_language_detection() {
+    use std::path::Path;
+    assert_eq!(
+        rskim_core::detect_language("adoc"),
+        Some(Language::AsciiDoc)
+    );
+    for path in ["docs/guide.asciidoc", "README.adoc", "manual/index.adoc"] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::AsciiDoc),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_asciidoc_structure_keeps_h1_to_h3() {
+    let result = transform(GUIDE_ADOC, Language::AsciiDoc, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "= Acme Orders Guide
+== Installation
+=== From source
+== Configuration
+=== Environment variables
+== API
+"
+    );
+}
+
+#[test]
+fn test_asciidoc_header_attributes_and_comments_are_dropped() {
+    let result = transform(GUIDE_ADOC, Language::AsciiDoc, Mode::Signatures).unwrap();
+    for dropped in ["Ada Lovelace", ":toc:", "// Developer guide", "NOTE:"] {
+        assert!(!result.contains(dropped), "{dropped} kept:\n{result}");
+    }
+}
+
+#[test]
+fn test_asciidoc_markdown_style_titles() {
+    let result = transform(README_ADOC, Language::AsciiDoc, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "# Acme Orders
+## Quick start
+### Requirements
+## Options
+## Contributing
+"
+    );
+}
+
+#[test]
+fn test_asciidoc_fence_with_language_closes_on_bare_fence() {
+    assert!(README_ADOC.contains("```console\n"));
+    let result = transform(README_ADOC, Language::AsciiDoc, Mode::Signatures).unwrap();
+    assert!(!result.contains("not a heading"), "got:\n{result}");
+    assert!(result.contains("### Requirements\n"), "got:\n{result}");
+    assert!(!has_errors(README_ADOC));
+}
+
+#[test]
+fn test_asciidoc_tables_and_highlights_are_not_titles() {
+    let result = transform(README_ADOC, Language::AsciiDoc, Mode::Signatures).unwrap();
+    assert!(!result.contains("highlighted"), "got:\n{result}");
+    assert!(!result.contains("Listen port"), "got:\n{result}");
+    assert!(!result.contains("Python 3.10"), "got:\n{result}");
+}
+
+#[test]
+fn test_asciidoc_symmetric_titles_and_block_attributes() {
+    let result = transform(MANUAL_ADOC, Language::AsciiDoc, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "= Acme Orders Manual
+== Preface ==
+== Operation ==
+=== Starting the server
+=== Health checks
+== Troubleshooting
+"
+    );
+    for dropped in ["[preface]", "[appendix]", "[discrete]", "include::"] {
+        assert!(!result.contains(dropped), "{dropped} kept:\n{result}");
+    }
+}
+
+#[test]
+fn test_asciidoc_nested_example_and_sidebar_blocks_are_skipped() {
+    let result = transform(MANUAL_ADOC, Language::AsciiDoc, Mode::Signatures).unwrap();
+    assert!(!result.contains("Listening"), "got:\n{result}");
+    assert!(!result.contains("not a section"), "got:\n{result}");
+    assert!(!has_errors(MANUAL_ADOC));
+}
+
+// ============================================================================
+// Signatures / types
+// ============================================================================
+
+#[test]
+fn test_asciidoc_signatures_and_types_keep_all_levels() {
+    for mode in [Mode::Signatures, Mode::Types] {
+        let result = transform(GUIDE_ADOC, Language::AsciiDoc, mode).unwrap();
+        assert!(result.contains("==== Linux\n"), "{mode:?}");
+        assert!(result.contains("===== Logging\n"), "{mode:?}");
+        assert!(!result.contains("not a heading"), "{mode:?}");
+        assert!(!result.contains("pip install"), "{mode:?}");
+    }
+}
+
+#[test]
+fn test_asciidoc_every_delimited_block_kind_is_skipped() {
+    let result = transform(API_ADOC, Language::AsciiDoc, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "= API Reference
+== Client
+=== get_order
+=== list_orders
+==== Parameters
+===== status
+====== Allowed values
+"
+    );
+}
+
+#[test]
+fn test_asciidoc_listing_closes_only_on_matching_length() {
+    assert!(API_ADOC.contains("------\n"));
+    let result = transform(API_ADOC, Language::AsciiDoc, Mode::Signatures).unwrap();
+    assert!(!result.contains("still inside"), "got:\n{result}");
+    assert!(!has_errors(API_ADOC));
+}
+
+#[test]
+fn test_asciidoc_structure_drops_levels_four_to_six() {
+    let result = transform(API_ADOC, Language::AsciiDoc, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "= API Reference\n== Client\n=== get_order\n=== list_orders\n"
+    );
+}
+
+#[test]
+fn test_asciidoc_crlf_line_endings() {
+    let crlf = MANUAL_ADOC.replace('\n', "\r\n");
+    let result = transform(&crlf, Language::AsciiDoc, Mode::Structure).unwrap();
+    let expected = transform(MANUAL_ADOC, Language::AsciiDoc, Mode::Structure).unwrap();
+    assert_eq!(result, expected);
+    assert!(!has_errors(&crlf));
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_asciidoc_unclosed_listing_is_a_parse_error() {
+    assert!(has_errors(BROKEN_ADOC));
+    let result = transform(BROKEN_ADOC, Language::AsciiDoc, Mode::Structure).unwrap();
+    assert_eq!(result, "= Broken Guide\n== Install\n");
+}
+
+// ============================================================================
+// Passthrough modes
+// ============================================================================
+
+#[test]
+fn test_asciidoc_full_minimal_and_pseudo_passthrough() {
+    for mode in [Mode::Full, Mode::Minimal, Mode::Pseudo] {
+        let result = transform(GUIDE_ADOC, Language::AsciiDoc, mode).unwrap();
+        assert_eq!(result, GUIDE_ADOC, "{mode:?}");
+    }
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_asciidoc_outline_and_analysis_modes_fall_back_to_signatures() {
+    let signatures = transform(API_ADOC, Language::AsciiDoc, Mode::Signatures).unwrap();
+    for mode in [
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::CallGraph,
+        Mode::Docs,
+    ] {
+        let result = transform(API_ADOC, Language::AsciiDoc, mode).unwrap();
+        assert_eq!(result, signatures, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_asciidoc_summary_reports_lines_and_tokens_only() {
+    let result = transform(API_ADOC, Language::AsciiDoc, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 38, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_asciidoc_mode_caveats() {
+    for mode in [Mode::Outline, Mode::Comments, Mode::Minimal, Mode::Pseudo] {
+        assert!(Language::AsciiDoc.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    for mode in [Mode::Structure, Mode::Signatures] {
+        assert!(Language::AsciiDoc.mode_caveat(mode).is_none(), "{mode:?}");
+    }
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_asciidoc_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::AsciiDoc, Mode::Structure).unwrap();
+        assert!(!result.is_empty(), "{name}");
+        assert!(result.len() < source.len() / 2, "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_asciidoc_all_fixtures_emit_only_title_lines() {
+    for (name, source) in FIXTURES {
+        let result = transform(source, Language::AsciiDoc, Mode::Signatures).unwrap();
+        for line in result.lines() {
+            assert!(line.starts_with(['=', '#']), "{name}: {line:?}");
+            assert!(source.lines().any(|l| l == line), "{name}: {line:?}");
+        }
+    }
+}
@@ -8,6 +8,7 @@ const SIMPLE_CS: &str = include_str!("../../../tests/fixtures/csharp/simple.cs")
 const TYPES_CS: &str = include_str!("../../../tests/fixtures/csharp/types.cs");
 const INTERFACES_CS: &str = include_str!("../../../tests/fixtures/csharp/interfaces.cs");
 const GENERICS_CS: &str = include_str!("../../../tests/fixtures/csharp/generics.cs");
+const RECORDS_CS: &str = include_str!("../../../tests/fixtures/csharp/records.cs");
 
 // ============================================================================
 // Language detection
@@ -73,6 +74,31 @@ fn test_csharp_structure_preserves_using_directives() {
     );
 }
 
+#[test]
+fn test_csharp_structure_keeps_records_and_properties() {
+    let result = transform(RECORDS_CS, Language::CSharp, Mode::Structure).unwrap();
+    assert!(
+        result.contains("public record Money(decimal Amount, string Currency);"),
+        "positional record should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("public Guid Id { get; init; }"),
+        "record properties should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("public int Pending { get; private set; }"),
+        "class properties should be preserved, got:\n{result}"
+    );
+    assert!(
+        !result.contains("Lines.Sum"),
+        "record method bodies should be stripped, got:\n{result}"
+    );
+    assert!(
+        !result.contains("\"disposed\""),
+        "destructor bodies should be stripped, got:\n{result}"
+    );
+}
+
 // ============================================================================
 // Signatures mode
 // ============================================================================
@@ -96,6 +122,53 @@ fn test_csharp_signatures_extracts_methods() {
     );
 }
 
+#[test]
+fn test_csharp_operator_overloads() {
+    let source = "public struct Money {\n    public static Money operator +(Money a, Money b) {\n        return new Money(a.Cents + b.Cents);\n    }\n    \
+                  public static implicit operator decimal(Money m) {\n        return m.Cents / 100m;\n    }\n}\n";
+    let signatures = transform(source, Language::CSharp, Mode::Signatures).unwrap();
+    assert!(
+        signatures.contains("public static Money operator +(Money a, Money b)"),
+        "operator overload should be extracted, got:\n{signatures}"
+    );
+    assert!(
+        signatures.contains("public static implicit operator decimal(Money m)"),
+        "conversion operator should be extracted, got:\n{signatures}"
+    );
+
+    let structure = transform(source, Language::CSharp, Mode::Structure).unwrap();
+    assert!(
+        !structure.contains("return"),
+        "operator bodies should be stripped, got:\n{structure}"
+    );
+}
+
+#[test]
+fn test_csharp_signatures_include_property_accessors() {
+    let source = "public class Account {\n    public string Name { get; private set; }\n    public decimal Balance {\n        get { return _balance; }\n        set { _balance = value; }\n    }\n    public bool IsEmpty => _balance == 0;\n    public int this[int i] { get => _items[i]; }\n}\n";
+    let result = transform(source, Language::CSharp, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("public string Name { get; private set; }"),
+        "auto-property should be extracted, got:\n{result}"
+    );
+    assert!(
+        result.contains("public decimal Balance { get; set; }"),
+        "accessor bodies should be removed, got:\n{result}"
+    );
+    assert!(
+        result.contains("public bool IsEmpty { get; }"),
+        "expression-bodied property should render as get-only, got:\n{result}"
+    );
+    assert!(
+        result.contains("public int this[int i] { get; }"),
+        "indexer should be extracted, got:\n{result}"
+    );
+    assert!(
+        !result.contains("_balance = value"),
+        "accessor bodies must not leak, got:\n{result}"
+    );
+}
+
 // ============================================================================
 // Types mode
 // ============================================================================
@@ -136,6 +209,34 @@ fn test_csharp_types_extracts_classes() {
     );
 }
 
+#[test]
+fn test_csharp_types_extracts_records() {
+    let result = transform(RECORDS_CS, Language::CSharp, Mode::Types).unwrap();
+    assert!(
+        result.contains("public record Money(decimal Amount, string Currency)"),
+        "positional record should be extracted, got:\n{result}"
+    );
+    assert!(
+        result.contains("public record Order {\n    public Guid Id { get; init; }"),
+        "record header and properties should be extracted, got:\n{result}"
+    );
+    assert!(
+        !result.contains("Total()"),
+        "record methods should not be extracted, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_csharp_types_keeps_class_fields_and_properties() {
+    let result = transform(RECORDS_CS, Language::CSharp, Mode::Types).unwrap();
+    assert!(
+        result.contains(
+            "public class OrderService {\n    private readonly ILogger _logger;\n    public int Pending { get; private set; }\n}"
+        ),
+        "class fields and properties should be extracted, got:\n{result}"
+    );
+}
+
 // ============================================================================
 // Full mode (passthrough)
 // ============================================================================
@@ -252,6 +353,7 @@ fn test_csharp_all_fixtures_parse() {
         ("types.cs", TYPES_CS),
         ("interfaces.cs", INTERFACES_CS),
         ("generics.cs", GENERICS_CS),
+        ("records.cs", RECORDS_CS),
     ] {
         for mode in [
             Mode::Structure,
@@ -0,0 +1,265 @@
+//! CSV/TSV transformation tests — verify the schema summary and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const ORDERS: &str = include_str!("../../../tests/fixtures/csv/orders.csv");
+const CITIES: &str = include_str!("../../../tests/fixtures/csv/cities.tsv");
+const INVOICES: &str = include_str!("../../../tests/fixtures/csv/invoices_eu.csv");
+const INVENTORY: &str = include_str!("../../../tests/fixtures/csv/inventory.csv");
+const READINGS: &str = include_str!("../../../tests/fixtures/csv/sensor_readings.csv");
+
+const FIXTURES: [(&str, &str); 5] = [
+    ("orders.csv", ORDERS),
+    ("cities.tsv", CITIES),
+    ("invoices_eu.csv", INVOICES),
+    ("inventory.csv", INVENTORY),
+    ("sensor_readings.csv", READINGS),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Csv, &config)
+        .unwrap()
+        .1
+}
+
+const ORDERS_SUMMARY: &str = "order_id,customer,total,paid,placed_at,notes
+# order_id: integer
+# customer: text
+# total: float
+# paid: boolean
+# placed_at: date
+# notes: text, nullable
+# 5 rows
+";
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_csv_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("csv"), Some(Language::Csv));
+    for path in ["data/orders.csv", "exports/cities.tsv"] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Csv),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure / signatures / types
+// ============================================================================
+
+#[test]
+fn test_csv_structure_summarizes_schema() {
+    let result = transform(ORDERS, Language::Csv, Mode::Structure).unwrap();
+    assert_eq!(result, ORDERS_SUMMARY);
+}
+
+#[test]
+fn test_csv_signatures_and_types_match_structure() {
+    for mode in [Mode::Signatures, Mode::Types] {
+        let result = transform(ORDERS, Language::Csv, mode).unwrap();
+        assert_eq!(result, ORDERS_SUMMARY, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_csv_quoted_fields_keep_delimiters_and_newlines() {
+    let result = transform(ORDERS, Language::Csv, Mode::Structure).unwrap();
+    assert!(result.ends_with("# 5 rows\n"), "got:\n{result}");
+    assert!(!result.contains("ring twice"), "got:\n{result}");
+    assert!(!has_errors(ORDERS));
+}
+
+#[test]
+fn test_tsv_types_signed_floats_dates_and_datetimes() {
+    let result = transform(CITIES, Language::Csv, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "name\tcountry\tlat\tlon\tpopulation\tfounded\tupdated_at\tcapital\tnotes
+# name: text
+# country: text
+# lat: float
+# lon: float
+# population: integer
+# founded: date
+# updated_at: datetime
+# capital: boolean
+# notes: empty
+# 4 rows
+"
+    );
+}
+
+#[test]
+fn test_tsv_empty_trailing_column_is_not_ragged() {
+    assert!(!has_errors(CITIES));
+}
+
+#[test]
+fn test_csv_semicolon_export_with_decimal_commas() {
+    let result = transform(INVOICES, Language::Csv, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "Invoice;Customer;Amount;VAT;Issued
+# Invoice: text
+# Customer: text
+# Amount: float
+# VAT: float
+# Issued: date
+# 4 rows
+"
+    );
+}
+
+#[test]
+fn test_csv_byte_order_mark_is_dropped() {
+    assert!(INVOICES.starts_with('\u{feff}'));
+    let result = transform(INVOICES, Language::Csv, Mode::Structure).unwrap();
+    assert!(!result.contains('\u{feff}'), "got:\n{result}");
+}
+
+#[test]
+fn test_csv_decimal_commas_are_text_in_comma_files() {
+    let source = "item,amount\nwidget,\"12,50\"\n";
+    let result = transform(source, Language::Csv, Mode::Structure).unwrap();
+    assert!(result.contains("# amount: text\n"), "got:\n{result}");
+}
+
+#[test]
+fn test_csv_pipe_delimiter_with_quoted_header_and_crlf() {
+    let result = transform(INVENTORY, Language::Csv, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "\"sku\"|\"name\"|\"qty\"|\"price\"|\"discontinued\"
+# sku: text
+# name: text
+# qty: integer
+# price: float
+# discontinued: text
+# 3 rows
+"
+    );
+    assert!(!has_errors(INVENTORY));
+}
+
+#[test]
+fn test_csv_large_file_reports_sample_size() {
+    let result = transform(READINGS, Language::Csv, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "id,reading,recorded_at,status
+# id: integer
+# reading: float
+# recorded_at: datetime
+# status: text
+# 120 rows (types from the first 100)
+"
+    );
+}
+
+#[test]
+fn test_csv_cells_past_the_sample_do_not_change_types() {
+    assert!(READINGS.contains("105,n/a,"));
+    let result = transform(READINGS, Language::Csv, Mode::Structure).unwrap();
+    assert!(result.contains("# reading: float\n"), "got:\n{result}");
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_csv_ragged_row_is_a_parse_error() {
+    let ragged = format!("{ORDERS}1006,Edsger Dijkstra,5.00\n");
+    assert!(has_errors(&ragged));
+    let result = transform(&ragged, Language::Csv, Mode::Structure).unwrap();
+    assert!(result.ends_with("# 6 rows\n"), "got:\n{result}");
+}
+
+#[test]
+fn test_csv_unterminated_quote_is_a_parse_error() {
+    let open = format!("{INVENTORY}A-103|\"Cog\r\n");
+    assert!(has_errors(&open));
+}
+
+// ============================================================================
+// Passthrough modes
+// ============================================================================
+
+#[test]
+fn test_csv_full_minimal_and_pseudo_passthrough() {
+    for mode in [Mode::Full, Mode::Minimal, Mode::Pseudo] {
+        let result = transform(ORDERS, Language::Csv, mode).unwrap();
+        assert_eq!(result, ORDERS, "{mode:?}");
+    }
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_csv_outline_and_analysis_modes_fall_back_to_structure() {
+    let structure = transform(CITIES, Language::Csv, Mode::Structure).unwrap();
+    for mode in [
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::CallGraph,
+        Mode::Constants,
+    ] {
+        let result = transform(CITIES, Language::Csv, mode).unwrap();
+        assert_eq!(result, structure, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_csv_summary_reports_lines_and_tokens_only() {
+    let result = transform(READINGS, Language::Csv, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 121, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_csv_mode_caveats() {
+    for mode in [Mode::Signatures, Mode::Outline, Mode::Minimal, Mode::Errors] {
+        assert!(Language::Csv.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    assert!(Language::Csv.mode_caveat(Mode::Structure).is_none());
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_csv_all_fixtures_summarize_without_rows() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Csv, Mode::Structure).unwrap();
+        assert!(result.len() < source.len(), "{name}:\n{result}");
+        let data_row = source.lines().nth(1).unwrap();
+        assert!(!result.contains(data_row), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_csv_all_fixtures_pass_through_in_full_and_minimal() {
+    for (name, source) in FIXTURES {
+        for mode in [Mode::Full, Mode::Minimal] {
+            let result = transform(source, Language::Csv, mode).unwrap();
+            assert_eq!(result, source, "{name} {mode:?}");
+        }
+    }
+}
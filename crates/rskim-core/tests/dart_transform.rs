@@ -0,0 +1,405 @@
+//! Dart transformation tests — verify sibling body stripping, constructors and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{AstFormat, Language, Mode, transform};
+
+const COUNTER_PAGE: &str = include_str!("../../../tests/fixtures/dart/counter_page.dart");
+const MODELS: &str = include_str!("../../../tests/fixtures/dart/models.dart");
+const TODO_SERVICE: &str = include_str!("../../../tests/fixtures/dart/todo_service.dart");
+const COMMENTS: &str = include_str!("../../../tests/fixtures/dart/comments.dart");
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_dart_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("dart"), Some(Language::Dart));
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("lib/counter_page.dart")),
+        Some(Language::Dart)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_dart_structure_strips_bodies() {
+    let result = transform(COUNTER_PAGE, Language::Dart, Mode::Structure).unwrap();
+    assert!(result.contains("{...}"), "got:\n{result}");
+    assert!(!result.contains("setState"), "got:\n{result}");
+    assert!(!result.contains("Scaffold("), "got:\n{result}");
+    assert!(!result.contains("print('[log]"), "got:\n{result}");
+    assert!(
+        !result.contains("'Count: $count'"),
+        "top-level function body should be stripped, got:\n{result}"
+    );
+    assert!(
+        !result.contains("'$this taps'"),
+        "arrow body should be stripped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_dart_structure_keeps_declarations() {
+    let result = transform(COUNTER_PAGE, Language::Dart, Mode::Structure).unwrap();
+    for expected in [
+        "import 'package:flutter/material.dart';",
+        "class CounterPage extends StatefulWidget {",
+        "const CounterPage({super.key, required this.title, this.step = defaultStep});",
+        "final String title;",
+        "Widget build(BuildContext context)",
+        "mixin Logging {",
+        "extension CountFormatting on int {",
+        "enum CounterMode { up, down }",
+        "String formatCount(int count)",
+    ] {
+        assert!(
+            result.contains(expected),
+            "missing {expected:?} in:\n{result}"
+        );
+    }
+}
+
+#[test]
+fn test_dart_structure_keeps_named_and_redirecting_constructors() {
+    let result = transform(MODELS, Language::Dart, Mode::Structure).unwrap();
+    assert!(
+        result.contains("factory Todo.fromJson(Map<String, dynamic> json)  {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("Todo.placeholder() : this(id: 0, title: 'Untitled');"),
+        "redirecting constructors have no body to strip, got:\n{result}"
+    );
+    assert!(!result.contains("json['id']"), "got:\n{result}");
+}
+
+#[test]
+fn test_dart_structure_strips_getter_and_setter_bodies() {
+    let result = transform(MODELS, Language::Dart, Mode::Structure).unwrap();
+    assert!(
+        result.contains("String get summary  {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("set completed(bool value)  {...}"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("'[x] $title'"), "got:\n{result}");
+    assert!(!result.contains("done = value;"), "got:\n{result}");
+}
+
+#[test]
+fn test_dart_structure_strips_async_and_generator_bodies() {
+    let result = transform(TODO_SERVICE, Language::Dart, Mode::Structure).unwrap();
+    assert!(
+        result.contains("Future<List<Todo>> fetchAll()  {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("Stream<Todo> watch(int id)  {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("static Future<TodoService> connect(String baseUrl)  {...}"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("await"), "got:\n{result}");
+    assert!(!result.contains("yield"), "got:\n{result}");
+}
+
+#[test]
+fn test_dart_structure_keeps_abstract_members() {
+    let result = transform(MODELS, Language::Dart, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "abstract class Repository<T> {\n  T? find(int id);\n\n  void save(T item);\n}"
+        ),
+        "got:\n{result}"
+    );
+    let result = transform(TODO_SERVICE, Language::Dart, Mode::Structure).unwrap();
+    assert!(
+        result.contains("abstract class HttpClient {\n  Future<Object?> get(String path);"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Signatures mode
+// ============================================================================
+
+#[test]
+fn test_dart_signatures_include_widget_constructor() {
+    let result = transform(COUNTER_PAGE, Language::Dart, Mode::Signatures).unwrap();
+    assert!(
+        result.contains(
+            "const CounterPage({super.key, required this.title, this.step = defaultStep})"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("Widget build(BuildContext context)"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("int get count"), "got:\n{result}");
+    assert!(
+        result.contains("String formatCount(int count)"),
+        "got:\n{result}"
+    );
+    assert_eq!(
+        result.matches("Widget build(").count(),
+        1,
+        "methods should be listed once, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_dart_signatures_list_constructors_and_accessors() {
+    let result = transform(MODELS, Language::Dart, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "Todo({required this.id, required this.title, this.done = false})",
+            "factory Todo.fromJson(Map<String, dynamic> json)",
+            "Todo.placeholder()",
+            "String get summary",
+            "set completed(bool value)",
+            "Map<String, dynamic> toJson()",
+            "String toString()",
+            "T? find(int id)",
+            "void save(T item)",
+            "const Filter()",
+            "const ShowAll()",
+            "const ShowDone(this.limit)",
+        ]
+    );
+}
+
+#[test]
+fn test_dart_signatures_keep_async_return_types() {
+    let result = transform(TODO_SERVICE, Language::Dart, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("Future<List<Todo>> fetchAll()\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("Stream<Todo> watch(int id)\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("Future<Object?> get(String path)"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("async"), "got:\n{result}");
+}
+
+// ============================================================================
+// Types mode
+// ============================================================================
+
+#[test]
+fn test_dart_types_keep_classes_and_fields() {
+    let result = transform(COUNTER_PAGE, Language::Dart, Mode::Types).unwrap();
+    assert!(
+        result.contains("class CounterPage extends StatefulWidget"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("final String title"), "got:\n{result}");
+    assert!(result.contains("mixin Logging"), "got:\n{result}");
+    assert!(result.contains("enum CounterMode"), "got:\n{result}");
+    assert!(result.contains("typedef CounterChanged"), "got:\n{result}");
+    assert!(!result.contains("Scaffold("), "got:\n{result}");
+}
+
+#[test]
+fn test_dart_types_keep_abstract_and_generic_classes() {
+    let result = transform(MODELS, Language::Dart, Mode::Types).unwrap();
+    assert!(
+        result.contains(
+            "abstract class Repository<T> {\n    T? find(int id);\n    void save(T item);\n}"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("class ShowDone extends Filter {\n    const ShowDone(this.limit);\n    final int limit;\n}"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("toJson"), "got:\n{result}");
+    assert!(!result.contains("factory"), "got:\n{result}");
+}
+
+#[test]
+fn test_dart_types_empty_without_type_declarations() {
+    let result = transform(COMMENTS, Language::Dart, Mode::Types).unwrap();
+    assert!(result.trim().is_empty(), "got:\n{result}");
+}
+
+// ============================================================================
+// Full mode (passthrough)
+// ============================================================================
+
+#[test]
+fn test_dart_full_mode_passthrough() {
+    let result = transform(TODO_SERVICE, Language::Dart, Mode::Full).unwrap();
+    assert_eq!(result, TODO_SERVICE);
+}
+
+// ============================================================================
+// Minimal mode
+// ============================================================================
+
+#[test]
+fn test_dart_minimal_keeps_doc_comments() {
+    let result = transform(COUNTER_PAGE, Language::Dart, Mode::Minimal).unwrap();
+    assert!(result.contains("/// A page that shows a counter."));
+    assert!(!result.contains("// Default step used by the page."));
+}
+
+#[test]
+fn test_dart_minimal_strips_license_keeps_body_comments() {
+    let result = transform(COMMENTS, Language::Dart, Mode::Minimal).unwrap();
+    assert!(!result.contains("Copyright"), "got:\n{result}");
+    assert!(!result.contains("/* Shared separator"), "got:\n{result}");
+    assert!(
+        result.contains("// Whole hours first, then the remaining minutes."),
+        "in-body comments should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("/**\n * Legacy block doc comment.\n */"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Outline mode
+// ============================================================================
+
+#[test]
+fn test_dart_outline_lists_constructors_and_accessors() {
+    let result = transform(MODELS, Language::Dart, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "7: class Todo\n\
+         \x20 8: Todo(...)\n\
+         \x20 11: factory Todo.fromJson(...)\n\
+         \x20 19: Todo.placeholder()\n\
+         \x20 25: String get summary\n\
+         \x20 27: set completed(...)\n\
+         \x20 31: Map<String, dynamic> toJson()\n\
+         \x20 36: String toString()\n\
+         40: abstract class Repository\n\
+         \x20 41: T? find(...)\n\
+         \x20 43: void save(...)\n\
+         46: abstract class Filter\n\
+         \x20 47: const Filter()\n\
+         50: class ShowAll\n\
+         \x20 51: const ShowAll()\n\
+         54: class ShowDone\n\
+         \x20 55: const ShowDone(...)\n"
+    );
+}
+
+#[test]
+fn test_dart_outline_nests_mixin_and_extension_members() {
+    let result = transform(COUNTER_PAGE, Language::Dart, Mode::Outline).unwrap();
+    assert!(
+        result.contains("44: mixin Logging\n  45: void log(...)\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("50: extension CountFormatting\n  51: String get label\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("10: class CounterPage\n  11: const CounterPage(...)\n"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// View modes
+// ============================================================================
+
+#[test]
+fn test_dart_comments_attribute_body_comments_to_functions() {
+    let result = transform(COMMENTS, Language::Dart, Mode::Comments).unwrap();
+    assert!(
+        result.contains("16: formatDuration\n// Whole hours first, then the remaining minutes.\n"),
+        "comments in a sibling body belong to its function, got:\n{result}"
+    );
+    assert!(
+        result.contains("25: legacyMinutes\n/**\n * Legacy block doc comment.\n */\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_dart_todos() {
+    let result = transform(COMMENTS, Language::Dart, Mode::Todos).unwrap();
+    assert_eq!(result, "30: TODO: support seconds.\n");
+}
+
+#[test]
+fn test_dart_constants_keep_consts_and_enums() {
+    let result = transform(COUNTER_PAGE, Language::Dart, Mode::Constants).unwrap();
+    assert_eq!(
+        result,
+        "const int defaultStep = 1;\n\nenum CounterMode { up, down }\n"
+    );
+}
+
+#[test]
+fn test_dart_errors_attribute_throw_in_expression_body() {
+    let result = transform(TODO_SERVICE, Language::Dart, Mode::Errors).unwrap();
+    assert_eq!(
+        result,
+        "53: throw UnimplementedError('open $baseUrl'); (HttpClient.open)\n"
+    );
+}
+
+#[test]
+fn test_dart_summary_counts_declarations() {
+    let result = transform(TODO_SERVICE, Language::Dart, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 54, functions: 8, classes: 2, types: 0,"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_dart_all_fixtures_parse_cleanly() {
+    for (name, source) in [
+        ("counter_page.dart", COUNTER_PAGE),
+        ("models.dart", MODELS),
+        ("todo_service.dart", TODO_SERVICE),
+        ("comments.dart", COMMENTS),
+    ] {
+        let ast = rskim_core::dump_ast(source, Language::Dart, AstFormat::SExpression).unwrap();
+        assert!(!ast.contains("(ERROR"), "{name} has parse errors:\n{ast}");
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Full,
+            Mode::Minimal,
+            Mode::Pseudo,
+            Mode::Outline,
+        ] {
+            let result = transform(source, Language::Dart, mode);
+            assert!(
+                result.is_ok(),
+                "Failed to transform {name} in {mode:?} mode: {:?}",
+                result.err()
+            );
+        }
+    }
+}
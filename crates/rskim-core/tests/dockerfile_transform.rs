@@ -0,0 +1,327 @@
+//! Dockerfile transformation tests — verify the instruction skeleton across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const DOCKERFILE: &str = include_str!("../../../tests/fixtures/dockerfile/Dockerfile");
+const NODE: &str = include_str!("../../../tests/fixtures/dockerfile/Dockerfile.node");
+const PYTHON: &str = include_str!("../../../tests/fixtures/dockerfile/python.Dockerfile");
+const WINDOWS: &str = include_str!("../../../tests/fixtures/dockerfile/Dockerfile.windows");
+const BROKEN: &str = include_str!("../../../tests/fixtures/dockerfile/broken.Dockerfile");
+
+const FIXTURES: [(&str, &str); 4] = [
+    ("Dockerfile", DOCKERFILE),
+    ("Dockerfile.node", NODE),
+    ("python.Dockerfile", PYTHON),
+    ("Dockerfile.windows", WINDOWS),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Dockerfile, &config)
+        .unwrap()
+        .1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_dockerfile_language_detection() {
+    use std::path::Path;
+    assert_eq!(
+        rskim_core::detect_language("dockerfile"),
+        Some(Language::Dockerfile)
+    );
+    for path in [
+        "Dockerfile",
+        "deploy/Dockerfile.dev",
+        "Containerfile",
+        "Dockerfile.node",
+        "docker/python.Dockerfile",
+        "api.dockerfile",
+    ] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Dockerfile),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_dockerfile_structure_skeleton() {
+    let result = transform(DOCKERFILE, Language::Dockerfile, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "FROM rust:1.80-bookworm AS build
+ARG TARGET=x86_64-unknown-linux-gnu
+WORKDIR /src
+COPY Cargo.toml Cargo.lock ./
+RUN mkdir src && echo ... && cargo build ... && rm -rf ...
+COPY src ./src
+RUN cargo build ... && strip ...
+
+FROM debian:bookworm-slim
+LABEL org.opencontainers.image.source=\"https://github.com/acme/api\"
+RUN apt-get update && apt-get install ... && rm -rf ...
+COPY <<EOF /etc/api/config.toml
+COPY --from=build /src/target/x86_64-unknown-linux-gnu/release/api /usr/local/bin/api
+ENV RUST_LOG=info API_PORT=8080
+USER nobody
+EXPOSE 8080
+HEALTHCHECK --interval=30s CMD [\"/usr/local/bin/api\", \"health\"]
+ENTRYPOINT [\"/usr/bin/tini\", \"--\"]
+CMD [\"/usr/local/bin/api\"]
+"
+    );
+}
+
+#[test]
+fn test_dockerfile_structure_drops_comments_and_heredoc_bodies() {
+    let result = transform(DOCKERFILE, Language::Dockerfile, Mode::Structure).unwrap();
+    assert!(!result.contains("Build stage"), "got:\n{result}");
+    assert!(!result.contains("log_level"), "heredoc leaked:\n{result}");
+}
+
+// ============================================================================
+// Multi-stage builds
+// ============================================================================
+
+#[test]
+fn test_dockerfile_node_multi_stage_skeleton() {
+    let result = transform(NODE, Language::Dockerfile, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "ARG NODE_VERSION=20
+
+FROM --platform=$BUILDPLATFORM node:${NODE_VERSION}-alpine AS deps
+WORKDIR /app
+COPY package.json package-lock.json ./
+RUN npm ci --omit=dev
+
+FROM node:${NODE_VERSION}-alpine AS build
+WORKDIR /app
+COPY --from=deps /app/node_modules ./node_modules
+COPY . .
+RUN npm run build
+
+FROM node:${NODE_VERSION}-alpine
+ENV NODE_ENV=production
+WORKDIR /app
+COPY --from=build /app/dist ./dist
+USER node
+VOLUME [\"/app/data\"]
+STOPSIGNAL SIGTERM
+EXPOSE 3000
+CMD [\"node\", \"dist/server.js\"]
+"
+    );
+}
+
+#[test]
+fn test_dockerfile_stages_are_separated_by_blank_lines() {
+    let result = transform(NODE, Language::Dockerfile, Mode::Structure).unwrap();
+    assert_eq!(result.matches("\n\nFROM ").count(), 3, "got:\n{result}");
+    assert!(!result.contains("--mount"), "got:\n{result}");
+    assert!(!result.contains("--chown"), "got:\n{result}");
+}
+
+// ============================================================================
+// RUN chains
+// ============================================================================
+
+#[test]
+fn test_dockerfile_semicolon_chains_collapse_per_command() {
+    let result = transform(PYTHON, Language::Dockerfile, Mode::Structure).unwrap();
+    assert!(
+        result.contains("RUN set -eux; apt-get update; apt-get install ...; rm -rf ...\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("libpq-dev"), "got:\n{result}");
+}
+
+#[test]
+fn test_dockerfile_or_chains_and_pipes() {
+    let result = transform(PYTHON, Language::Dockerfile, Mode::Structure).unwrap();
+    assert!(
+        result.contains("RUN curl -fsSL ... || exit 1\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("python-poetry.org"), "got:\n{result}");
+}
+
+#[test]
+fn test_dockerfile_run_heredoc_and_continued_label() {
+    let result = transform(PYTHON, Language::Dockerfile, Mode::Structure).unwrap();
+    assert!(result.contains("RUN <<EOF ...\n"), "got:\n{result}");
+    assert!(!result.contains("virtualenvs"), "got:\n{result}");
+    assert!(
+        result.contains(
+            "LABEL org.opencontainers.image.title=\"orders\" org.opencontainers.image.vendor=\"Acme\"\n"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_dockerfile_onbuild_shell_and_healthcheck_kept_verbatim() {
+    let result = transform(PYTHON, Language::Dockerfile, Mode::Structure).unwrap();
+    for line in [
+        "SHELL [\"/bin/bash\", \"-o\", \"pipefail\", \"-c\"]\n",
+        "ONBUILD COPY . /app\n",
+        "HEALTHCHECK NONE\n",
+        "RUN poetry install --only main --no-root\n",
+    ] {
+        assert!(result.contains(line), "{line:?} missing:\n{result}");
+    }
+}
+
+#[test]
+fn test_dockerfile_quoted_separators_do_not_split_commands() {
+    let source = "FROM alpine\nRUN echo 'a; b || c' \\\n    && sh -c \"x && y\"\n";
+    let result = transform(source, Language::Dockerfile, Mode::Structure).unwrap();
+    assert_eq!(result, "FROM alpine\nRUN echo ... && sh -c ...\n");
+}
+
+#[test]
+fn test_dockerfile_crlf_line_endings() {
+    let crlf = PYTHON.replace('\n', "\r\n");
+    let result = transform(&crlf, Language::Dockerfile, Mode::Structure).unwrap();
+    let expected = transform(PYTHON, Language::Dockerfile, Mode::Structure).unwrap();
+    assert_eq!(result, expected);
+    assert!(!has_errors(&crlf));
+}
+
+// ============================================================================
+// Escape directive
+// ============================================================================
+
+#[test]
+fn test_dockerfile_windows_backtick_continuations() {
+    let result = transform(WINDOWS, Language::Dockerfile, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "FROM mcr.microsoft.com/windows/servercore:ltsc2022
+SHELL [\"powershell\", \"-Command\", \"$ErrorActionPreference = 'Stop';\"]
+RUN Invoke-WebRequest https://example.com/app.zip ...; Expand-Archive C:\\app.zip ...; Remove-Item C:\\app.zip
+WORKDIR C:\\app
+COPY config\\ C:\\app\\config\\
+ENTRYPOINT [\"C:\\\\app\\\\orders.exe\"]
+"
+    );
+    assert!(!has_errors(WINDOWS));
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_dockerfile_unknown_line_and_open_heredoc_are_parse_errors() {
+    assert!(has_errors(BROKEN));
+    let result = transform(BROKEN, Language::Dockerfile, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "FROM alpine:3.20\nRUN apk add --no-cache curl\nCOPY <<EOF /etc/motd\n"
+    );
+    assert!(!result.contains("not an instruction"), "got:\n{result}");
+    assert!(!result.contains("Welcome"), "got:\n{result}");
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_dockerfile_signatures_and_types_match_structure() {
+    let structure = transform(DOCKERFILE, Language::Dockerfile, Mode::Structure).unwrap();
+    let signatures = transform(DOCKERFILE, Language::Dockerfile, Mode::Signatures).unwrap();
+    let types = transform(DOCKERFILE, Language::Dockerfile, Mode::Types).unwrap();
+    assert_eq!(structure, signatures);
+    assert_eq!(structure, types);
+}
+
+#[test]
+fn test_dockerfile_full_and_minimal_passthrough() {
+    assert_eq!(
+        transform(DOCKERFILE, Language::Dockerfile, Mode::Full).unwrap(),
+        DOCKERFILE
+    );
+    assert_eq!(
+        transform(DOCKERFILE, Language::Dockerfile, Mode::Minimal).unwrap(),
+        DOCKERFILE
+    );
+}
+
+#[test]
+fn test_dockerfile_outline_and_analysis_modes_fall_back_to_structure() {
+    let structure = transform(NODE, Language::Dockerfile, Mode::Structure).unwrap();
+    for mode in [
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::CallGraph,
+        Mode::Constants,
+        Mode::Docs,
+    ] {
+        let result = transform(NODE, Language::Dockerfile, mode).unwrap();
+        assert_eq!(result, structure, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_dockerfile_summary_reports_lines_and_tokens_only() {
+    let result = transform(NODE, Language::Dockerfile, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 23, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_dockerfile_mode_caveats() {
+    for mode in [
+        Mode::Signatures,
+        Mode::Outline,
+        Mode::Pseudo,
+        Mode::Constants,
+    ] {
+        assert!(Language::Dockerfile.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    assert!(Language::Dockerfile.mode_caveat(Mode::Structure).is_none());
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_dockerfile_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Dockerfile, Mode::Structure).unwrap();
+        assert!(result.len() < source.len(), "{name}:\n{result}");
+        assert!(!result.contains('#'), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_dockerfile_all_fixtures_one_line_per_instruction() {
+    for (name, source) in FIXTURES {
+        let result = transform(source, Language::Dockerfile, Mode::Structure).unwrap();
+        for line in result.lines().filter(|line| !line.is_empty()) {
+            let keyword = line.split_whitespace().next().unwrap();
+            assert_eq!(keyword, keyword.to_ascii_uppercase(), "{name}: {line:?}");
+        }
+        let pseudo = transform(source, Language::Dockerfile, Mode::Pseudo).unwrap();
+        assert_eq!(pseudo, source, "{name}");
+    }
+}
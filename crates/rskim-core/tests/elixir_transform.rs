@@ -0,0 +1,351 @@
+//! Elixir transformation tests — verify definition-call matching, body stripping and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{AstFormat, Language, Mode, transform};
+
+const ACCOUNTS_EX: &str = include_str!("../../../tests/fixtures/elixir/accounts.ex");
+const USER_EX: &str = include_str!("../../../tests/fixtures/elixir/user.ex");
+const CACHE_SERVER_EX: &str = include_str!("../../../tests/fixtures/elixir/cache_server.ex");
+const ACCOUNTS_TEST_EXS: &str = include_str!("../../../tests/fixtures/elixir/accounts_test.exs");
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_elixir_language_detection() {
+    use std::path::Path;
+    for ext in ["ex", "exs"] {
+        assert_eq!(rskim_core::detect_language(ext), Some(Language::Elixir));
+    }
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("test/test_helper.exs")),
+        Some(Language::Elixir)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_elixir_structure_strips_function_bodies() {
+    let result = transform(ACCOUNTS_EX, Language::Elixir, Mode::Structure).unwrap();
+    assert_eq!(
+        result.matches("{...}").count(),
+        4,
+        "each def/defp/defmacro body should be replaced, got:\n{result}"
+    );
+    assert!(
+        result.contains("def get_user(id) when is_integer(id)"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("defp expired?(user)"), "got:\n{result}");
+    assert!(
+        result.contains("defmacro with_user(id, do: block)"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("Repo.insert()"), "got:\n{result}");
+    assert!(!result.contains("Date.diff"), "got:\n{result}");
+}
+
+#[test]
+fn test_elixir_structure_keeps_module_and_attributes() {
+    let result = transform(ACCOUNTS_EX, Language::Elixir, Mode::Structure).unwrap();
+    assert!(
+        result.contains("defmodule MyApp.Accounts do"),
+        "module block must not be stripped, got:\n{result}"
+    );
+    assert!(result.contains("alias MyApp.Repo"), "got:\n{result}");
+    assert!(result.contains("@moduledoc"), "got:\n{result}");
+    assert!(
+        result.contains("@spec get_user(integer())"),
+        "got:\n{result}"
+    );
+    assert!(result.trim_end().ends_with("end"), "got:\n{result}");
+}
+
+#[test]
+fn test_elixir_structure_keeps_one_line_definitions() {
+    let result = transform(ACCOUNTS_EX, Language::Elixir, Mode::Structure).unwrap();
+    assert!(
+        result.contains("def active?(%User{status: status}), do: status == :active"),
+        "keyword-form def has no do block to strip, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_elixir_structure_keeps_schema_and_protocol_heads() {
+    let result = transform(USER_EX, Language::Elixir, Mode::Structure).unwrap();
+    assert!(
+        result.contains("  schema \"users\" do\n    field :name, :string\n"),
+        "schema is a macro call, not a definition, got:\n{result}"
+    );
+    assert!(
+        result.contains("  def changeset(user, attrs)  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("validate_required"), "got:\n{result}");
+    assert!(
+        result.contains("defprotocol MyApp.Accounts.Describable do\n  @doc \"A one-line description for logs.\"\n  def describe(value)\nend"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("defimpl MyApp.Accounts.Describable, for: MyApp.Accounts.User do\n  def describe(user)  {...}\nend"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_elixir_structure_keeps_callback_attributes() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Structure).unwrap();
+    assert!(
+        result.contains("  @impl true\n  def handle_call({:get, key}, _from, state)  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("  defp schedule_sweep  {...}\nend"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("## Client API"), "got:\n{result}");
+    assert!(!result.contains("Process.send_after"), "got:\n{result}");
+}
+
+#[test]
+fn test_elixir_structure_keeps_test_blocks() {
+    let result = transform(ACCOUNTS_TEST_EXS, Language::Elixir, Mode::Structure).unwrap();
+    assert!(
+        result.contains("    test \"rejects a non-integer id\" do\n      assert_raise"),
+        "ExUnit tests are macro calls, not definitions, got:\n{result}"
+    );
+    assert!(
+        result.contains("  defp with_status(user, status), do: %{user | status: status}\n"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Signatures mode
+// ============================================================================
+
+#[test]
+fn test_elixir_signatures_lists_function_heads() {
+    let result = transform(ACCOUNTS_EX, Language::Elixir, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("def get_user(id) when is_integer(id)"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("def create_user(attrs \\\\ %{})"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("defp expired?(user)"), "got:\n{result}");
+    assert!(
+        !result.contains("defmodule"),
+        "modules are not signatures, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_elixir_signatures_list_callbacks_in_order() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "def start_link(opts \\\\ [])",
+            "def put(server \\\\ __MODULE__, key, value, ttl \\\\ @default_ttl)",
+            "def fetch!(server \\\\ __MODULE__, key)",
+            "def init(_opts)",
+            "def handle_call({:get, key}, _from, state)",
+            "def handle_cast({:put, key, value, ttl}, state)",
+            "def handle_info(:sweep, state)",
+            "defp schedule_sweep",
+        ]
+    );
+}
+
+#[test]
+fn test_elixir_signatures_include_protocol_and_impl_functions() {
+    let result = transform(USER_EX, Language::Elixir, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "def changeset(user, attrs)",
+            "def display_name(%__MODULE__{name: name}), do: name",
+            "def describe(value)",
+            "def describe(user)",
+        ]
+    );
+}
+
+#[test]
+fn test_elixir_types_mode_caveat() {
+    assert!(Language::Elixir.mode_caveat(Mode::Types).is_some());
+    assert!(Language::Elixir.mode_caveat(Mode::Signatures).is_none());
+}
+
+// ============================================================================
+// Minimal mode
+// ============================================================================
+
+#[test]
+fn test_elixir_minimal_keeps_only_function_body_comments() {
+    let result = transform(ACCOUNTS_EX, Language::Elixir, Mode::Minimal).unwrap();
+    assert!(
+        !result.contains("# Accounts older than this"),
+        "module-level comment should be stripped, got:\n{result}"
+    );
+    assert!(
+        result.contains("# Compare against the retention window"),
+        "comment inside defp body should be kept, got:\n{result}"
+    );
+    assert!(
+        result.contains("@doc \"Fetch a user by id.\""),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_elixir_minimal_strips_section_comments() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Minimal).unwrap();
+    assert!(!result.contains("## Client API"), "got:\n{result}");
+    assert!(!result.contains("# TODO"), "got:\n{result}");
+    assert!(
+        result.contains("    # FIXME: this walks the whole map on every sweep.\n"),
+        "comment inside def body should be kept, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Full mode (passthrough)
+// ============================================================================
+
+#[test]
+fn test_elixir_full_mode_passthrough() {
+    let result = transform(USER_EX, Language::Elixir, Mode::Full).unwrap();
+    assert_eq!(result, USER_EX);
+}
+
+// ============================================================================
+// Outline and view modes
+// ============================================================================
+
+#[test]
+fn test_elixir_outline_nests_functions_under_modules() {
+    let result = transform(ACCOUNTS_EX, Language::Elixir, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "1: defmodule MyApp.Accounts\n  14: def get_user(...)\n  21: def create_user(...)\n  \
+         27: def active?(...)\n  29: defmacro with_user(...)\n  38: defp expired?(...)\n"
+    );
+}
+
+#[test]
+fn test_elixir_outline_lists_protocols_and_impls() {
+    let result = transform(USER_EX, Language::Elixir, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "1: defmodule MyApp.Accounts.User\n  28: def changeset(...)\n  36: def display_name(...)\n\
+         39: defprotocol MyApp.Accounts.Describable\n  41: def describe(...)\n\
+         44: defimpl MyApp.Accounts.Describable\n  45: def describe(...)\n"
+    );
+}
+
+#[test]
+fn test_elixir_comments_attributed_to_functions() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Comments).unwrap();
+    assert!(
+        result.contains(
+            "52: MyApp.CacheServer.handle_info\n# FIXME: this walks the whole map on every sweep.\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("11: MyApp.CacheServer\n## Client API\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_elixir_todos_name_their_owner() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "7: TODO: make the sweep interval configurable. (MyApp.CacheServer)\n\
+         52: FIXME: this walks the whole map on every sweep. (MyApp.CacheServer.handle_info)\n"
+    );
+}
+
+#[test]
+fn test_elixir_constants_keep_value_attributes() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Constants).unwrap();
+    assert_eq!(
+        result,
+        "@sweep_interval :timer.seconds(30)\n@default_ttl 300\n"
+    );
+    let result = transform(USER_EX, Language::Elixir, Mode::Constants).unwrap();
+    assert_eq!(
+        result, "@required [:name, :email]\n@email_format ~r/^[^@\\s]+@[^@\\s]+$/\n",
+        "@type, @doc and @spec are not constants"
+    );
+}
+
+#[test]
+fn test_elixir_errors_name_the_raising_function() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Errors).unwrap();
+    assert_eq!(
+        result,
+        "24: :error -> raise KeyError, key: key (MyApp.CacheServer.fetch!)\n"
+    );
+}
+
+#[test]
+fn test_elixir_call_graph_skips_field_access() {
+    let result = transform(ACCOUNTS_EX, Language::Elixir, Mode::CallGraph).unwrap();
+    assert!(
+        result.contains("MyApp.Accounts.expired? -> Date.diff\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("user.inserted_at"), "got:\n{result}");
+}
+
+#[test]
+fn test_elixir_summary_counts_definitions() {
+    let result = transform(CACHE_SERVER_EX, Language::Elixir, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 62, functions: 8, classes: 0, types: 0,"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_elixir_all_fixtures_parse_cleanly() {
+    for (name, source) in [
+        ("accounts.ex", ACCOUNTS_EX),
+        ("user.ex", USER_EX),
+        ("cache_server.ex", CACHE_SERVER_EX),
+        ("accounts_test.exs", ACCOUNTS_TEST_EXS),
+    ] {
+        let ast = rskim_core::dump_ast(source, Language::Elixir, AstFormat::SExpression).unwrap();
+        assert!(!ast.contains("(ERROR"), "{name} has parse errors:\n{ast}");
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Full,
+            Mode::Minimal,
+            Mode::Outline,
+        ] {
+            let result = transform(source, Language::Elixir, mode);
+            assert!(
+                result.is_ok(),
+                "Failed to transform {name} in {mode:?} mode: {:?}",
+                result.err()
+            );
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! .env transformation tests — verify keys are listed and values never leak in any mode
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const SAMPLE: &str = include_str!("../../../tests/fixtures/env/sample.env");
+const PRODUCTION: &str = include_str!("../../../tests/fixtures/env/production.env");
+const EXAMPLE: &str = include_str!("../../../tests/fixtures/env/.env.example");
+const WINDOWS: &str = include_str!("../../../tests/fixtures/env/windows.env");
+const BROKEN: &str = include_str!("../../../tests/fixtures/env/broken.env");
+
+const FIXTURES: [(&str, &str); 5] = [
+    ("sample.env", SAMPLE),
+    ("production.env", PRODUCTION),
+    (".env.example", EXAMPLE),
+    ("windows.env", WINDOWS),
+    ("broken.env", BROKEN),
+];
+
+const ALL_MODES: [Mode; 13] = [
+    Mode::Structure,
+    Mode::Signatures,
+    Mode::Types,
+    Mode::Full,
+    Mode::Minimal,
+    Mode::Pseudo,
+    Mode::Outline,
+    Mode::Comments,
+    Mode::Todos,
+    Mode::Errors,
+    Mode::CallGraph,
+    Mode::Constants,
+    Mode::Docs,
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Env, &config)
+        .unwrap()
+        .1
+}
+
+const SAMPLE_KEYS: &str = "NODE_ENV=
+PORT=
+DATABASE_URL=  # secret
+STRIPE_SECRET_KEY=  # secret
+SENTRY_DSN=  # secret
+JWT_PRIVATE_KEY=  # secret
+FEATURE_FLAGS=
+";
+
+/// Values and comments from the fixture that must never be emitted.
+const LEAKS: &[&str] = &[
+    "development",
+    "3000",
+    "local-password",
+    "previous-password",
+    "OLD_DATABASE_URL",
+    "sk_test_fixture",
+    "sentry.example.com",
+    "fixture-not-a-real-key",
+    "beta-ui",
+    "docker",
+];
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_env_language_detection() {
+    use std::path::Path;
+    for path in [
+        ".env",
+        "config/.env.local",
+        ".env.production",
+        "deploy/prod.env",
+    ] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Env),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Every mode
+// ============================================================================
+
+#[test]
+fn test_env_every_mode_lists_keys_only() {
+    for mode in [
+        Mode::Structure,
+        Mode::Signatures,
+        Mode::Types,
+        Mode::Full,
+        Mode::Minimal,
+        Mode::Pseudo,
+    ] {
+        let result = transform(SAMPLE, Language::Env, mode).unwrap();
+        assert_eq!(result, SAMPLE_KEYS, "{mode:?}");
+        for leak in LEAKS {
+            assert!(!result.contains(leak), "{mode:?} leaked {leak:?}");
+        }
+    }
+}
+
+#[test]
+fn test_env_every_mode_redacts_every_fixture() {
+    for (name, source) in FIXTURES {
+        let structure = transform(source, Language::Env, Mode::Structure).unwrap();
+        for mode in ALL_MODES {
+            let result = transform(source, Language::Env, mode).unwrap();
+            assert_eq!(result, structure, "{name} {mode:?}");
+        }
+    }
+}
+
+// ============================================================================
+// Key listing
+// ============================================================================
+
+#[test]
+fn test_env_production_keys_and_secret_markers() {
+    let result = transform(PRODUCTION, Language::Env, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "APP_NAME=
+APP_URL=
+LOG_LEVEL=
+REDIS_URL=  # secret
+AWS_REGION=
+AWS_ACCESS_KEY_ID=  # secret
+UPLOAD_BUCKET_KEY=  # secret
+DATA_DIR=
+WEBHOOK_SIGNING_KEY=  # secret
+"
+    );
+}
+
+#[test]
+fn test_env_production_values_never_leak() {
+    let result = transform(PRODUCTION, Language::Env, Mode::Full).unwrap();
+    for leak in [
+        "Billing",
+        "billing.example.com",
+        "warn",
+        "redis-pass-fixture",
+        "eu-west-1",
+        "AKIAFIXTURE",
+        "${HOME}",
+        "whsec_fixture",
+        "not#a#comment",
+        "deploy script",
+    ] {
+        assert!(!result.contains(leak), "leaked {leak:?}");
+    }
+}
+
+#[test]
+fn test_env_url_without_user_but_with_password_is_secret() {
+    let result = transform(PRODUCTION, Language::Env, Mode::Structure).unwrap();
+    assert!(result.contains("REDIS_URL=  # secret\n"), "got:\n{result}");
+    assert!(result.contains("APP_URL=\n"), "got:\n{result}");
+}
+
+#[test]
+fn test_env_provider_key_prefix_marks_unrecognised_names() {
+    let result = transform(PRODUCTION, Language::Env, Mode::Structure).unwrap();
+    assert!(
+        result.contains("UPLOAD_BUCKET_KEY=  # secret\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_env_bare_export_is_skipped_without_error() {
+    assert!(PRODUCTION.trim_end().ends_with("export APP_URL"));
+    let result = transform(PRODUCTION, Language::Env, Mode::Structure).unwrap();
+    assert_eq!(result.matches("APP_URL=").count(), 1, "got:\n{result}");
+    assert!(!has_errors(PRODUCTION));
+}
+
+#[test]
+fn test_env_example_marks_blank_secret_keys() {
+    let result = transform(EXAMPLE, Language::Env, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "NODE_ENV=
+PORT=
+DATABASE_URL=  # secret
+OPENAI_API_KEY=  # secret
+SMTP_PASSWORD=  # secret
+SMTP_HOST=
+"
+    );
+}
+
+#[test]
+fn test_env_spaces_around_equals_and_inline_comments() {
+    let result = transform(EXAMPLE, Language::Env, Mode::Structure).unwrap();
+    assert!(result.contains("PORT=\n"), "got:\n{result}");
+    assert!(!result.contains("mailhog"), "got:\n{result}");
+    assert!(!result.contains("Copy to .env"), "got:\n{result}");
+    assert!(!has_errors(EXAMPLE));
+}
+
+#[test]
+fn test_env_crlf_line_endings() {
+    let result = transform(WINDOWS, Language::Env, Mode::Structure).unwrap();
+    assert_eq!(result, "API_BASE=\napi_token=  # secret\nRETRIES=\n");
+    assert!(!result.contains('\r'));
+    assert!(!has_errors(WINDOWS));
+}
+
+#[test]
+fn test_env_lowercase_keys_match_suffixes_case_insensitively() {
+    let result = transform(WINDOWS, Language::Env, Mode::Structure).unwrap();
+    assert!(result.contains("api_token=  # secret\n"), "got:\n{result}");
+    assert!(!result.contains("token-fixture-value"), "got:\n{result}");
+}
+
+#[test]
+fn test_env_multiline_quoted_value_is_one_key() {
+    let result = transform(SAMPLE, Language::Env, Mode::Structure).unwrap();
+    assert!(
+        result.contains("JWT_PRIVATE_KEY=  # secret\nFEATURE_FLAGS=\n"),
+        "continuation lines must not become keys, got:\n{result}"
+    );
+    assert!(!result.contains("BEGIN"), "got:\n{result}");
+}
+
+#[test]
+fn test_env_hash_inside_single_quotes_is_part_of_the_value() {
+    let result = transform(PRODUCTION, Language::Env, Mode::Structure).unwrap();
+    assert!(
+        result.ends_with("WEBHOOK_SIGNING_KEY=  # secret\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_env_fixture_names_are_detected() {
+    use std::path::Path;
+    for path in [".env.example", "windows.env", "config/production.env"] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Env),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_env_broken_file_keeps_valid_keys() {
+    let result = transform(BROKEN, Language::Env, Mode::Structure).unwrap();
+    assert_eq!(result, "VALID_KEY=\nGOOD_AFTER_ERRORS=\nUNCLOSED=\n");
+    assert!(has_errors(BROKEN));
+}
+
+#[test]
+fn test_env_broken_lines_are_never_emitted() {
+    for mode in ALL_MODES {
+        let result = transform(BROKEN, Language::Env, mode).unwrap();
+        for leak in [
+            "this line",
+            "1STARTS_WITH_DIGIT",
+            "leaked-digit-value",
+            "never closed",
+            "still-unclosed-value",
+        ] {
+            assert!(!result.contains(leak), "{mode:?} leaked {leak:?}");
+        }
+    }
+}
+
+#[test]
+fn test_env_unterminated_quote_is_a_parse_error() {
+    assert!(has_errors("TOKEN=\"open\n"));
+    assert!(!has_errors(SAMPLE));
+}
+
+// ============================================================================
+// Summary and caveats
+// ============================================================================
+
+#[test]
+fn test_env_summary_reports_lines_and_tokens_only() {
+    let result = transform(SAMPLE, Language::Env, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 15, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_env_is_redacted_in_full_mode() {
+    assert!(Language::Env.is_redacted());
+    assert!(Language::Env.mode_caveat(Mode::Full).is_some());
+    assert!(Language::Env.mode_caveat(Mode::Structure).is_none());
+}
@@ -0,0 +1,396 @@
+//! Groovy/Gradle transformation tests — verify the declaration outline and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const BUILD_GRADLE: &str = include_str!("../../../tests/fixtures/groovy/build.gradle");
+const SETTINGS_GRADLE: &str = include_str!("../../../tests/fixtures/groovy/settings.gradle");
+const APP_GRADLE: &str = include_str!("../../../tests/fixtures/groovy/app.gradle");
+const RELEASE: &str = include_str!("../../../tests/fixtures/groovy/Release.groovy");
+const RELEASE_SPEC: &str = include_str!("../../../tests/fixtures/groovy/ReleaseSpec.groovy");
+
+const FIXTURES: [(&str, &str); 5] = [
+    ("build.gradle", BUILD_GRADLE),
+    ("settings.gradle", SETTINGS_GRADLE),
+    ("app.gradle", APP_GRADLE),
+    ("Release.groovy", RELEASE),
+    ("ReleaseSpec.groovy", RELEASE_SPEC),
+];
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_groovy_language_detection() {
+    use std::path::Path;
+    assert_eq!(
+        rskim_core::detect_language("groovy"),
+        Some(Language::Groovy)
+    );
+    for path in ["build.gradle", "app/settings.gradle", "src/Release.groovy"] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Groovy),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_gradle_structure_outline() {
+    let result = transform(BUILD_GRADLE, Language::Groovy, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "import org.apache.tools.ant.filters.ReplaceTokens
+plugins {
+    id 'java-library'
+    id 'maven-publish'
+    id 'com.diffplug.spotless' version '6.25.0'
+}
+group = 'com.example'
+version = '1.4.0'
+repositories {...}
+dependencies {
+    api 'org.slf4j:slf4j-api:2.0.9'
+    implementation(project(':core')) {...}
+    implementation libs.guava
+    testImplementation 'org.junit.jupiter:junit-jupiter:5.10.0'
+}
+java {...}
+subprojects {
+    apply plugin: 'java'
+    repositories {...}
+}
+task generateVersion(type: Copy) {...}
+tasks.register('docs') {...}
+tasks.withType(JavaCompile).configureEach {...}
+def releaseNotes(String tag) {...}
+class ReleaseExtension {
+    String channel = 'stable'
+    boolean isSnapshot() {...}
+}
+"
+    );
+}
+
+#[test]
+fn test_gradle_structure_drops_bodies_and_comments() {
+    let result = transform(BUILD_GRADLE, Language::Groovy, Mode::Structure).unwrap();
+    assert!(!result.contains("println"));
+    assert!(!result.contains("options.encoding"));
+    assert!(!result.contains("exclude group"));
+    assert!(!result.contains("test runner"));
+    assert!(!result.contains("Root build script"));
+}
+
+#[test]
+fn test_settings_gradle_keeps_includes_and_plugin_management() {
+    let result = transform(SETTINGS_GRADLE, Language::Groovy, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "pluginManagement {
+    repositories {...}
+    plugins {
+        id 'org.jetbrains.kotlin.jvm' version '1.9.22'
+    }
+}
+dependencyResolutionManagement {...}
+rootProject.name = 'release-tools'
+include ':app', ':core'
+include ':plugins:changelog'
+if (System.getenv('CI')) {...}
+"
+    );
+}
+
+#[test]
+fn test_app_gradle_keeps_apply_statements_and_dependencies() {
+    let result = transform(APP_GRADLE, Language::Groovy, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "apply plugin: 'com.android.application'\napply from: \"$rootDir/gradle/signing.gradle\"\n"
+        ),
+        "shebang and header comment should be dropped, got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "dependencies {
+    implementation fileTree(dir: 'libs', include: ['*.jar'])
+    implementation project(':core')
+    implementation('com.squareup.okhttp3:okhttp:4.12.0') {...}
+    androidTestImplementation 'androidx.test.espresso:espresso-core:3.5.1'
+}
+"
+        ),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("because"), "got:\n{result}");
+}
+
+#[test]
+fn test_app_gradle_collapses_configuration_closures() {
+    let result = transform(APP_GRADLE, Language::Groovy, Mode::Structure).unwrap();
+    assert!(result.contains("android {...}\n"), "got:\n{result}");
+    assert!(
+        result.contains("configurations.all {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with("ext.versionLabel = {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("minifyEnabled"), "got:\n{result}");
+}
+
+#[test]
+fn test_app_gradle_keeps_closures_around_tasks() {
+    let result = transform(APP_GRADLE, Language::Groovy, Mode::Structure).unwrap();
+    assert!(
+        result.contains("afterEvaluate {\n    tasks.named('assembleRelease') {...}\n}\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_groovy_class_keeps_fields_and_collapses_methods() {
+    let result = transform(RELEASE, Language::Groovy, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "package com.example.release
+import groovy.transform.CompileStatic
+import java.time.LocalDate
+@CompileStatic
+class Release implements Comparable<Release> {
+    static final String TAG_PREFIX = 'v'
+    String version
+    Channel channel = Channel.STABLE
+    LocalDate date = LocalDate.now()
+    Release(String version) {...}
+    String getTag() {...}
+    @Override
+    int compareTo(Release other) {...}
+    static Release parse(String text) throws IllegalArgumentException {...}
+}
+"
+        ),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("tokenize"), "got:\n{result}");
+    assert!(!result.contains("TODO"), "got:\n{result}");
+}
+
+#[test]
+fn test_groovy_enum_interface_and_trait_bodies_stay_open() {
+    let result = transform(RELEASE, Language::Groovy, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "enum Channel {
+    STABLE('stable'),
+        BETA('beta'),
+        NIGHTLY('nightly')
+    final String label
+    Channel(String label) {...}
+}
+interface Publisher {
+    void publish(Release release)
+}
+trait Announcing {
+    String announcement(Release release) {...}
+}
+"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_groovy_braces_in_triple_quoted_strings_do_not_close_blocks() {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    let (result, has_errors) = transform_with_quality(RELEASE, Language::Groovy, &config).unwrap();
+    assert!(!has_errors);
+    assert!(result.ends_with("}\n"), "got:\n{result}");
+    assert!(!result.contains("Released"), "got:\n{result}");
+}
+
+#[test]
+fn test_spock_feature_methods_keep_string_names() {
+    let result = transform(RELEASE_SPEC, Language::Groovy, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "package com.example.release
+import spock.lang.Specification
+import spock.lang.Unroll
+class ReleaseSpec extends Specification {
+    def \"parses a tag into a release\"() {...}
+    @Unroll
+    def \"orders #a before #b\"() {...}
+    def 'rejects untagged versions'() {...}
+    private static Release release(String version) {...}
+}
+"
+    );
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_groovy_signatures_and_types_match_structure() {
+    for (name, source) in FIXTURES {
+        let structure = transform(source, Language::Groovy, Mode::Structure).unwrap();
+        for mode in [Mode::Signatures, Mode::Types] {
+            assert_eq!(
+                transform(source, Language::Groovy, mode).unwrap(),
+                structure,
+                "{name} {mode:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_groovy_minimal_and_full_passthrough() {
+    for (name, source) in FIXTURES {
+        for mode in [Mode::Full, Mode::Minimal, Mode::Pseudo] {
+            assert_eq!(
+                transform(source, Language::Groovy, mode).unwrap(),
+                source,
+                "{name} {mode:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_groovy_comment_and_analysis_modes_fall_back_to_structure() {
+    let structure = transform(RELEASE, Language::Groovy, Mode::Structure).unwrap();
+    for mode in [
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::CallGraph,
+        Mode::Constants,
+        Mode::Docs,
+        Mode::PublicApi,
+    ] {
+        assert_eq!(
+            transform(RELEASE, Language::Groovy, mode).unwrap(),
+            structure,
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn test_groovy_mode_caveats() {
+    for mode in [
+        Mode::Signatures,
+        Mode::Types,
+        Mode::Outline,
+        Mode::Todos,
+        Mode::Minimal,
+    ] {
+        assert!(Language::Groovy.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    assert!(Language::Groovy.mode_caveat(Mode::Structure).is_none());
+    assert!(Language::Groovy.mode_caveat(Mode::Summary).is_none());
+}
+
+// ============================================================================
+// Summary mode
+// ============================================================================
+
+#[test]
+fn test_groovy_summary_counts_types_methods_and_constructors() {
+    let result = transform(RELEASE, Language::Groovy, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 62, functions: 7, classes: 1, types: 3,"),
+        "got:\n{result}"
+    );
+    let result = transform(RELEASE_SPEC, Language::Groovy, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 39, functions: 4, classes: 1, types: 0,"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_gradle_summary_does_not_count_tasks() {
+    let result = transform(BUILD_GRADLE, Language::Groovy, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 68, functions: 2, classes: 1, types: 0,"),
+        "releaseNotes and isSnapshot only, got:\n{result}"
+    );
+    let result = transform(SETTINGS_GRADLE, Language::Groovy, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 26, functions: 0, classes: 0, types: 0,"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_gradle_summary_ignores_closures_assigned_to_properties() {
+    let result = transform(APP_GRADLE, Language::Groovy, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 50, functions: 0, classes: 0, types: 0,"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Scan errors
+// ============================================================================
+
+#[test]
+fn test_groovy_truncated_fixture_reports_scan_error() {
+    let truncated = RELEASE.trim_end().strip_suffix('}').unwrap();
+    let config = TransformConfig::with_mode(Mode::Structure);
+    let (result, has_errors) =
+        transform_with_quality(truncated, Language::Groovy, &config).unwrap();
+    assert!(has_errors);
+    assert!(
+        result.ends_with("trait Announcing {\n    String announcement(Release release) {...}\n}\n"),
+        "unclosed block should still render, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_groovy_all_fixtures_scan_cleanly() {
+    for (name, source) in FIXTURES {
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Full,
+            Mode::Minimal,
+            Mode::Outline,
+        ] {
+            let config = TransformConfig::with_mode(mode);
+            let (result, has_errors) =
+                transform_with_quality(source, Language::Groovy, &config).unwrap();
+            assert!(!has_errors, "{name} has scan errors in {mode:?} mode");
+            assert!(!result.is_empty(), "{name} is empty in {mode:?} mode");
+        }
+    }
+}
+
+#[test]
+fn test_groovy_structure_drops_every_comment() {
+    for (name, source) in FIXTURES {
+        let result = transform(source, Language::Groovy, Mode::Structure).unwrap();
+        assert!(!result.contains("//"), "{name}:\n{result}");
+        assert!(!result.contains("/*"), "{name}:\n{result}");
+    }
+}
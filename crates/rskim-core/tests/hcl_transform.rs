@@ -0,0 +1,336 @@
+//! HCL transformation tests — verify block and attribute-key extraction across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const MAIN_TF: &str = include_str!("../../../tests/fixtures/hcl/main.tf");
+const VARIABLES_TF: &str = include_str!("../../../tests/fixtures/hcl/variables.tf");
+const PROD_TFVARS: &str = include_str!("../../../tests/fixtures/hcl/prod.tfvars");
+const NOMAD_JOB: &str = include_str!("../../../tests/fixtures/hcl/web.nomad.hcl");
+const PACKER: &str = include_str!("../../../tests/fixtures/hcl/build.pkr.hcl");
+const BROKEN_TF: &str = include_str!("../../../tests/fixtures/hcl/broken.tf");
+
+const FIXTURES: [(&str, &str); 5] = [
+    ("main.tf", MAIN_TF),
+    ("variables.tf", VARIABLES_TF),
+    ("prod.tfvars", PROD_TFVARS),
+    ("web.nomad.hcl", NOMAD_JOB),
+    ("build.pkr.hcl", PACKER),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Hcl, &config)
+        .unwrap()
+        .1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_hcl_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("tf"), Some(Language::Hcl));
+    assert_eq!(rskim_core::detect_language("hcl"), Some(Language::Hcl));
+    for path in [
+        "envs/prod.tfvars",
+        "modules/web/variables.tf",
+        "jobs/web.nomad.hcl",
+        "images/build.pkr.hcl",
+        ".terraform.lock.hcl",
+    ] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Hcl),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_main_tf_structure_keeps_blocks_and_keys() {
+    let result = transform(MAIN_TF, Language::Hcl, Mode::Structure).unwrap();
+
+    assert!(
+        result.contains("terraform {\n  required_version\n  required_providers {\n    aws\n  }\n")
+    );
+    assert!(result.contains("  backend \"s3\" {\n    bucket\n    key\n    region\n  }\n"));
+    assert!(result.contains("resource \"aws_instance\" \"web\" {\n  count\n  ami\n  instance_type\n  user_data\n  root_block_device {\n"));
+    assert!(
+        result.contains("  dynamic \"ingress\" {\n    for_each\n    content {\n      from_port\n")
+    );
+    assert!(result.contains("output \"public_ips\" {\n  value\n  description\n}\n"));
+}
+
+#[test]
+fn test_main_tf_structure_drops_values_and_comments() {
+    let result = transform(MAIN_TF, Language::Hcl, Mode::Structure).unwrap();
+
+    assert!(!result.contains("hashicorp/aws"), "got:\n{result}");
+    assert!(
+        !result.contains("#!/bin/bash"),
+        "heredoc body leaked:\n{result}"
+    );
+    assert!(
+        !result.contains("Environment"),
+        "object value leaked:\n{result}"
+    );
+    assert!(
+        !result.contains("Security group"),
+        "comment leaked:\n{result}"
+    );
+    assert!(result.len() < MAIN_TF.len() / 2);
+}
+
+#[test]
+fn test_variables_tf_validation_check_and_moved_blocks() {
+    let result = transform(VARIABLES_TF, Language::Hcl, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "variable \"environment\" {
+  type
+  description
+  validation {
+    condition
+    error_message
+  }
+}
+variable \"services\" {
+  type
+}
+locals {
+  ports
+  motd
+  ratio
+}
+moved {
+  from
+  to
+}
+check \"health\" {
+  data \"http\" \"site\" {
+    url
+  }
+  assert {
+    condition
+    error_message
+  }
+}
+"
+    );
+}
+
+#[test]
+fn test_variables_tf_multiline_type_constraint_is_one_attribute() {
+    let result = transform(VARIABLES_TF, Language::Hcl, Mode::Structure).unwrap();
+    assert!(!result.contains("replicas"), "got:\n{result}");
+    assert!(!result.contains("optional"), "got:\n{result}");
+}
+
+#[test]
+fn test_variables_tf_for_expressions_and_template_directives() {
+    let result = transform(VARIABLES_TF, Language::Hcl, Mode::Structure).unwrap();
+    assert!(
+        result.contains("locals {\n  ports\n  motd\n  ratio\n}\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("LIVE"), "got:\n{result}");
+    assert!(!has_errors(VARIABLES_TF));
+}
+
+// ============================================================================
+// tfvars
+// ============================================================================
+
+#[test]
+fn test_tfvars_lists_top_level_keys() {
+    let result = transform(PROD_TFVARS, Language::Hcl, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "region\ninstance_count\nenable_cdn\nallowed_cidrs\ntags\nmaintenance_banner\n"
+    );
+}
+
+#[test]
+fn test_tfvars_heredoc_and_trailing_comments_do_not_leak() {
+    let result = transform(PROD_TFVARS, Language::Hcl, Mode::Structure).unwrap();
+    assert_eq!(result.matches("region").count(), 1, "got:\n{result}");
+    for leaked in ["eu-west-1", "office VPN", "CostCenter", "maintenance on"] {
+        assert!(!result.contains(leaked), "{leaked} leaked:\n{result}");
+    }
+}
+
+// ============================================================================
+// Nomad and Packer
+// ============================================================================
+
+#[test]
+fn test_nomad_job_nests_groups_and_tasks() {
+    let result = transform(NOMAD_JOB, Language::Hcl, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with("job \"web\" {\n  datacenters\n  type\n  group \"app\" {\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("      port \"http\" {\n        to\n      }\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("    task \"server\" {\n      driver\n      config {\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_nomad_heredoc_with_go_template_braces_is_skipped() {
+    let result = transform(NOMAD_JOB, Language::Hcl, Mode::Structure).unwrap();
+    assert!(
+        result.contains("      template {\n        destination\n        data\n      }\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("DATABASE_URL"), "got:\n{result}");
+    assert!(!has_errors(NOMAD_JOB));
+}
+
+#[test]
+fn test_packer_hyphenated_blocks_and_empty_bodies() {
+    let result = transform(PACKER, Language::Hcl, Mode::Structure).unwrap();
+    assert!(
+        result.contains("source \"amazon-ebs\" \"ubuntu\" {\n  ami_name\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with(
+            "  provisioner \"shell\" {\n    inline\n  }\n  post-processor \"manifest\" {}\n}\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("{{timestamp}}"), "got:\n{result}");
+}
+
+#[test]
+fn test_hcl_crlf_line_endings() {
+    let crlf = NOMAD_JOB.replace('\n', "\r\n");
+    let result = transform(&crlf, Language::Hcl, Mode::Structure).unwrap();
+    let expected = transform(NOMAD_JOB, Language::Hcl, Mode::Structure).unwrap();
+    assert_eq!(result, expected);
+    assert!(!has_errors(&crlf));
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_unterminated_string_and_unclosed_block_are_parse_errors() {
+    assert!(has_errors(BROKEN_TF));
+    let result = transform(BROKEN_TF, Language::Hcl, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with("resource \"aws_s3_bucket\" \"logs\" {\n  bucket\n  acl\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("resource \"aws_s3_bucket\" \"assets\" {"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("acme-"), "got:\n{result}");
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_hcl_signatures_and_types_match_structure() {
+    let structure = transform(MAIN_TF, Language::Hcl, Mode::Structure).unwrap();
+    let signatures = transform(MAIN_TF, Language::Hcl, Mode::Signatures).unwrap();
+    let types = transform(MAIN_TF, Language::Hcl, Mode::Types).unwrap();
+    assert_eq!(structure, signatures);
+    assert_eq!(structure, types);
+}
+
+#[test]
+fn test_hcl_full_and_minimal_passthrough() {
+    assert_eq!(
+        transform(MAIN_TF, Language::Hcl, Mode::Full).unwrap(),
+        MAIN_TF
+    );
+    assert_eq!(
+        transform(MAIN_TF, Language::Hcl, Mode::Minimal).unwrap(),
+        MAIN_TF
+    );
+}
+
+#[test]
+fn test_hcl_outline_and_analysis_modes_fall_back_to_structure() {
+    let structure = transform(NOMAD_JOB, Language::Hcl, Mode::Structure).unwrap();
+    for mode in [
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::CallGraph,
+        Mode::Constants,
+        Mode::Docs,
+    ] {
+        let result = transform(NOMAD_JOB, Language::Hcl, mode).unwrap();
+        assert_eq!(result, structure, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_hcl_summary_reports_lines_and_tokens_only() {
+    let result = transform(VARIABLES_TF, Language::Hcl, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 38, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_hcl_mode_caveats() {
+    for mode in [
+        Mode::Signatures,
+        Mode::Outline,
+        Mode::Minimal,
+        Mode::Constants,
+    ] {
+        assert!(Language::Hcl.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    assert!(Language::Hcl.mode_caveat(Mode::Structure).is_none());
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_hcl_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Hcl, Mode::Structure).unwrap();
+        assert!(result.len() < source.len(), "{name}:\n{result}");
+        assert!(!result.contains('='), "{name}:\n{result}");
+        assert!(!result.contains('#'), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_hcl_all_fixtures_signatures_types_and_pseudo() {
+    for (name, source) in FIXTURES {
+        let structure = transform(source, Language::Hcl, Mode::Structure).unwrap();
+        for mode in [Mode::Signatures, Mode::Types] {
+            let result = transform(source, Language::Hcl, mode).unwrap();
+            assert_eq!(result, structure, "{name} {mode:?}");
+        }
+        let pseudo = transform(source, Language::Hcl, Mode::Pseudo).unwrap();
+        assert_eq!(pseudo, source, "{name}");
+    }
+}
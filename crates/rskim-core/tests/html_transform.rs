@@ -0,0 +1,390 @@
+//! HTML transformation tests — verify DOM skeleton extraction across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const INDEX_HTML: &str = include_str!("../../../tests/fixtures/html/index.html");
+const LANDING_HTML: &str = include_str!("../../../tests/fixtures/html/landing.html");
+const NEWSLETTER_HTML: &str = include_str!("../../../tests/fixtures/html/newsletter.html");
+const ORDERS_HTML: &str = include_str!("../../../tests/fixtures/html/orders.html");
+const BROKEN_HTML: &str = include_str!("../../../tests/fixtures/html/broken.html");
+
+const FIXTURES: [(&str, &str); 4] = [
+    ("index.html", INDEX_HTML),
+    ("landing.html", LANDING_HTML),
+    ("newsletter.html", NEWSLETTER_HTML),
+    ("orders.html", ORDERS_HTML),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Html, &config)
+        .unwrap()
+        .1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_html_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("html"), Some(Language::Html));
+    assert_eq!(rskim_core::detect_language("htm"), Some(Language::Html));
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("public/index.html")),
+        Some(Language::Html)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_index_structure_keeps_dom_skeleton() {
+    let result = transform(INDEX_HTML, Language::Html, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<html>\n  <head>\n    <meta>\n    <meta>\n    <title>Acme Dashboard</title>\n    \
+             <link>\n  </head>\n  <body class=\"app theme-dark\">\n"
+        ),
+        "head and body should be outlined, got:\n{result}"
+    );
+    assert!(
+        result.contains("    <header id=\"top\" class=\"site-header\">\n      <nav>\n"),
+        "only id and class attributes should be kept, got:\n{result}"
+    );
+    assert!(
+        result.contains("      <h1 id=\"summary\">Weekly summary</h1>\n"),
+        "heading text should be kept, got:\n{result}"
+    );
+    assert!(
+        result.contains("        <h2>Open orders</h2>\n"),
+        "inline markup inside headings should flatten to text, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_index_structure_closes_optional_end_tags() {
+    let result = transform(INDEX_HTML, Language::Html, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "          <li>\n            <a>...</a>\n          </li>\n          <li>\n            \
+             <a>...</a>\n          </li>\n          <li class=\"active\">\n"
+        ),
+        "unclosed <li> siblings should not nest, got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "            <th>...</th>\n          </tr>\n          <tr>\n            <td>...</td>\n"
+        ),
+        "unclosed table cells and rows should not nest, got:\n{result}"
+    );
+    assert!(
+        result.contains("      <p>\n        <br>\n      </p>\n    </footer>\n"),
+        "an unclosed <p> should close with its parent, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_index_structure_strips_text_scripts_and_styles() {
+    let result = transform(INDEX_HTML, Language::Html, Mode::Structure).unwrap();
+    for stripped in [
+        "Revenue grew",
+        "Jane Roe",
+        "border: 1px",
+        "querySelectorAll",
+        "<script",
+        "<style",
+        "analytics",
+        "DOCTYPE",
+        "placeholder",
+        "Empty",
+    ] {
+        assert!(
+            !result.contains(stripped),
+            "{stripped} should be stripped, got:\n{result}"
+        );
+    }
+}
+
+// ============================================================================
+// Fragments and modern markup
+// ============================================================================
+
+#[test]
+fn test_landing_fragment_without_html_or_body() {
+    let result = transform(LANDING_HTML, Language::Html, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<meta>\n<title>Acme Orders — Ship faster</title>\n<link>\n\
+             <app-header class=\"hero\">\n  <picture>\n    <source>\n    <img>\n  </picture>\n  \
+             <h1 class=\"hero-title\">Ship orders faster</h1>\n</app-header>\n"
+        ),
+        "top-level elements and custom elements should be outlined, got:\n{result}"
+    );
+    assert!(!result.contains("data-theme"), "got:\n{result}");
+}
+
+#[test]
+fn test_landing_definition_lists_and_options_close_implicitly() {
+    let result = transform(LANDING_HTML, Language::Html, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "  <dl>\n    <dt>...</dt>\n    <dd>...</dd>\n    <dt>...</dt>\n    <dd>...</dd>\n  </dl>\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "    <select id=\"plan\">\n      <option>...</option>\n      <option>...</option>\n    \
+             </select>\n"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_landing_textarea_contents_are_text() {
+    let result = transform(LANDING_HTML, Language::Html, Mode::Structure).unwrap();
+    assert!(
+        result.contains("    <textarea>...</textarea>\n    <button>...</button>\n"),
+        "markup inside <textarea> is text, got:\n{result}"
+    );
+    assert!(!result.contains("<b>"), "got:\n{result}");
+}
+
+#[test]
+fn test_landing_svg_template_and_noscript() {
+    let result = transform(LANDING_HTML, Language::Html, Mode::Structure).unwrap();
+    assert!(
+        result.contains("  <svg class=\"icon\">\n    <path></path>\n  </svg>\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with(
+            "<template id=\"toast\">\n  <div class=\"toast\">\n    <p class=\"message\"></p>\n  \
+             </div>\n</template>\n<noscript>\n  <p>...</p>\n</noscript>\n"
+        ),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Legacy markup
+// ============================================================================
+
+#[test]
+fn test_newsletter_uppercase_tags_and_unquoted_attributes() {
+    let result = transform(NEWSLETTER_HTML, Language::Html, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "<HTML>
+  <HEAD>
+    <TITLE>Acme Monthly</TITLE>
+  </HEAD>
+  <BODY>
+    <CENTER>
+      <TABLE class=\"wrapper\">
+        <TR>
+          <TD>
+            <IMG>
+            <H2>March highlights</H2>
+          </TD>
+        </TR>
+        <TR>
+          <TD>
+            <TABLE class=\"inner\">
+              <TR>
+                <TD>
+                  <FONT>...</FONT>
+                </TD>
+              </TR>
+              <TR>
+                <TD>
+                  <FONT>...</FONT>
+                </TD>
+              </TR>
+            </TABLE>
+          </TD>
+        </TR>
+      </TABLE>
+    </CENTER>
+  </BODY>
+</HTML>
+"
+    );
+}
+
+#[test]
+fn test_newsletter_presentational_attributes_are_dropped() {
+    let result = transform(NEWSLETTER_HTML, Language::Html, Mode::Structure).unwrap();
+    for dropped in [
+        "BGCOLOR",
+        "WIDTH",
+        "CELLPADDING",
+        "ALIGN",
+        "Arial",
+        "Refunds",
+    ] {
+        assert!(!result.contains(dropped), "{dropped} kept:\n{result}");
+    }
+    assert!(!has_errors(NEWSLETTER_HTML));
+}
+
+// ============================================================================
+// Server-side templates
+// ============================================================================
+
+#[test]
+fn test_orders_template_tags_are_text() {
+    let result = transform(ORDERS_HTML, Language::Html, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "<div id=\"orders-page\" class=\"orders\">
+  <h1>Orders for {{ customer.name }}</h1>
+  <ul class=\"order-list\">
+    <li class=\"order {% if order.late %}late{% endif %}\">
+      <a>...</a>
+    </li>
+  </ul>
+  <p class=\"empty\">...</p>
+  <pre class=\"debug\">...</pre>
+</div>
+"
+    );
+}
+
+#[test]
+fn test_orders_quoted_greater_than_in_attribute() {
+    assert!(ORDERS_HTML.contains("title=\"Open > details\""));
+    let result = transform(ORDERS_HTML, Language::Html, Mode::Structure).unwrap();
+    assert!(!result.contains("details"), "got:\n{result}");
+    assert!(!result.contains("extends"), "got:\n{result}");
+    assert!(!has_errors(ORDERS_HTML));
+}
+
+#[test]
+fn test_html_crlf_line_endings() {
+    let crlf = LANDING_HTML.replace('\n', "\r\n");
+    let result = transform(&crlf, Language::Html, Mode::Structure).unwrap();
+    let expected = transform(LANDING_HTML, Language::Html, Mode::Structure).unwrap();
+    assert_eq!(result, expected);
+    assert!(!has_errors(&crlf));
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_broken_stray_end_tag_and_open_comment_are_parse_errors() {
+    assert!(has_errors(BROKEN_HTML));
+    let result = transform(BROKEN_HTML, Language::Html, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "<div class=\"page\">
+  <h1>Broken page</h1>
+  <section id=\"a\">
+    <p>...</p>
+  </section>
+</div>
+"
+    );
+}
+
+#[test]
+fn test_html_unclosed_elements_at_end_are_not_errors() {
+    assert!(!has_errors("<div><p>Still open\n"));
+    assert!(has_errors("<div></span></div>\n"));
+}
+
+// ============================================================================
+// Passthrough modes
+// ============================================================================
+
+#[test]
+fn test_html_full_minimal_and_pseudo_passthrough() {
+    for mode in [Mode::Full, Mode::Minimal, Mode::Pseudo] {
+        let result = transform(INDEX_HTML, Language::Html, mode).unwrap();
+        assert_eq!(result, INDEX_HTML, "{mode:?}");
+    }
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_html_other_modes_fall_back_to_structure() {
+    for (name, source) in FIXTURES {
+        let structure = transform(source, Language::Html, Mode::Structure).unwrap();
+        for mode in [
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Outline,
+            Mode::Comments,
+            Mode::Todos,
+            Mode::Errors,
+            Mode::Docs,
+            Mode::Constants,
+        ] {
+            let result = transform(source, Language::Html, mode).unwrap();
+            assert_eq!(result, structure, "{name} {mode:?}");
+        }
+    }
+}
+
+#[test]
+fn test_html_summary_reports_lines_and_tokens_only() {
+    let result = transform(NEWSLETTER_HTML, Language::Html, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 25, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_html_mode_caveats() {
+    for mode in [
+        Mode::Signatures,
+        Mode::Outline,
+        Mode::Constants,
+        Mode::Pseudo,
+    ] {
+        assert!(Language::Html.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    assert!(Language::Html.mode_caveat(Mode::Structure).is_none());
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_html_all_fixtures_parse_cleanly() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Html, Mode::Structure).unwrap();
+        assert!(!result.is_empty(), "{name}");
+        assert!(!result.contains("<!"), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_html_all_fixtures_keep_only_id_and_class_attributes() {
+    for (name, source) in FIXTURES {
+        let result = transform(source, Language::Html, Mode::Structure).unwrap();
+        for line in result.lines() {
+            let tag = line.trim_start().split('>').next().unwrap();
+            let mut attrs = tag.split(' ').skip(1).filter(|a| a.contains("=\""));
+            assert!(
+                attrs.all(|a| a.starts_with("id=") || a.starts_with("class=")),
+                "{name}: {line:?}"
+            );
+        }
+    }
+}
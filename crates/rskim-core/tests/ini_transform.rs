@@ -0,0 +1,351 @@
+//! INI/conf transformation tests — verify sections and key names across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const SETUP_CFG: &str = include_str!("../../../tests/fixtures/ini/setup.cfg");
+const GITCONFIG: &str = include_str!("../../../tests/fixtures/ini/gitconfig.ini");
+const MY_CNF: &str = include_str!("../../../tests/fixtures/ini/my.cnf");
+const SERVICE: &str = include_str!("../../../tests/fixtures/ini/orders.service");
+const SSH_CONFIG: &str = include_str!("../../../tests/fixtures/ini/ssh_config.conf");
+
+const FIXTURES: [(&str, &str); 5] = [
+    ("setup.cfg", SETUP_CFG),
+    ("gitconfig.ini", GITCONFIG),
+    ("my.cnf", MY_CNF),
+    ("orders.service", SERVICE),
+    ("ssh_config.conf", SSH_CONFIG),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Ini, &config)
+        .unwrap()
+        .1
+}
+
+const SETUP_CFG_KEYS: &str = "[metadata]
+  name
+  version
+  description
+[options]
+  python_requires
+  install_requires
+[options.entry_points]
+  console_scripts
+[tool:pytest]
+  addopts
+  testpaths
+";
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_ini_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("ini"), Some(Language::Ini));
+    for path in [
+        "setup.cfg",
+        "config/app.ini",
+        "/etc/redis/redis.conf",
+        "/etc/mysql/my.cnf",
+        "/etc/systemd/system/orders.service",
+    ] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Ini),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure / signatures / types
+// ============================================================================
+
+#[test]
+fn test_ini_structure_keeps_sections_and_keys() {
+    let result = transform(SETUP_CFG, Language::Ini, Mode::Structure).unwrap();
+    assert_eq!(result, SETUP_CFG_KEYS);
+    for value in [
+        "acme-orders",
+        "1.4.0",
+        "requests",
+        "--strict-markers",
+        "Test runner",
+    ] {
+        assert!(!result.contains(value), "{value} leaked");
+    }
+}
+
+#[test]
+fn test_ini_signatures_and_types_match_structure() {
+    for (name, source) in FIXTURES {
+        let structure = transform(source, Language::Ini, Mode::Structure).unwrap();
+        for mode in [Mode::Signatures, Mode::Types] {
+            let result = transform(source, Language::Ini, mode).unwrap();
+            assert_eq!(result, structure, "{name} {mode:?}");
+        }
+    }
+}
+
+#[test]
+fn test_ini_continuation_lines_are_dropped() {
+    let result = transform(SETUP_CFG, Language::Ini, Mode::Structure).unwrap();
+    assert!(
+        result.contains("  install_requires\n[options.entry_points]\n  console_scripts\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("click"), "got:\n{result}");
+}
+
+#[test]
+fn test_ini_colon_delimiter() {
+    assert!(SETUP_CFG.contains("addopts: -ra"));
+    let result = transform(SETUP_CFG, Language::Ini, Mode::Structure).unwrap();
+    assert!(result.contains("  addopts\n"), "got:\n{result}");
+}
+
+// ============================================================================
+// .gitconfig
+// ============================================================================
+
+#[test]
+fn test_ini_gitconfig_tab_indented_keys_and_subsections() {
+    let result = transform(GITCONFIG, Language::Ini, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "[user]
+  name
+  email
+[core]
+  editor
+  autocrlf
+[remote \"origin\"]
+  url
+  fetch
+[alias]
+  lg
+  undo
+[includeIf \"gitdir:~/work/\"]
+  path
+"
+    );
+}
+
+#[test]
+fn test_ini_gitconfig_values_with_colons_do_not_leak() {
+    let result = transform(GITCONFIG, Language::Ini, Mode::Structure).unwrap();
+    for value in [
+        "Ada",
+        "github.com",
+        "refs/heads",
+        "--graph",
+        "HEAD~1",
+        ".gitconfig-work",
+    ] {
+        assert!(!result.contains(value), "{value} leaked:\n{result}");
+    }
+}
+
+// ============================================================================
+// my.cnf
+// ============================================================================
+
+#[test]
+fn test_ini_my_cnf_flag_keys() {
+    let result = transform(MY_CNF, Language::Ini, Mode::Structure).unwrap();
+    assert!(
+        result.contains("  bind-address\n  skip-name-resolve\n  max_connections\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("[mysqldump]\n  quick\n  max_allowed_packet\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_ini_my_cnf_commented_out_assignment_is_dropped() {
+    let result = transform(MY_CNF, Language::Ini, Mode::Structure).unwrap();
+    assert_eq!(
+        result.matches("innodb_buffer_pool_size").count(),
+        1,
+        "got:\n{result}"
+    );
+    assert!(!result.contains("4G"), "got:\n{result}");
+}
+
+#[test]
+fn test_ini_my_cnf_include_directive_is_a_key() {
+    let result = transform(MY_CNF, Language::Ini, Mode::Structure).unwrap();
+    assert!(result.ends_with("  !includedir\n"), "got:\n{result}");
+    assert!(!result.contains("conf.d"), "got:\n{result}");
+    assert!(!has_errors(MY_CNF));
+}
+
+// ============================================================================
+// systemd units
+// ============================================================================
+
+#[test]
+fn test_ini_systemd_unit_sections_and_directives() {
+    let result = transform(SERVICE, Language::Ini, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "[Unit]
+  Description
+  After
+  Wants
+[Service]
+  Type
+  User
+  Environment
+  ExecStartPre
+  ExecStart
+  Restart
+  RestartSec
+[Install]
+  WantedBy
+"
+    );
+}
+
+#[test]
+fn test_ini_systemd_quoted_assignments_in_values_do_not_leak() {
+    let result = transform(SERVICE, Language::Ini, Mode::Structure).unwrap();
+    for value in ["RUST_LOG", "PORT=8080", "/usr/bin/orders", "on-failure"] {
+        assert!(!result.contains(value), "{value} leaked:\n{result}");
+    }
+}
+
+// ============================================================================
+// ssh_config
+// ============================================================================
+
+#[test]
+fn test_ini_ssh_config_host_blocks_nest_their_options() {
+    let result = transform(SSH_CONFIG, Language::Ini, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "Host
+  HostName
+  User
+  IdentityFile
+Host
+  ProxyJump
+  User
+Host
+  ServerAliveInterval
+"
+    );
+    assert!(!has_errors(SSH_CONFIG));
+}
+
+#[test]
+fn test_ini_ssh_config_patterns_and_comments_do_not_leak() {
+    let result = transform(SSH_CONFIG, Language::Ini, Mode::Structure).unwrap();
+    for value in [
+        "bastion",
+        "*.internal",
+        "deploy_ed25519",
+        "60",
+        "every internal",
+    ] {
+        assert!(!result.contains(value), "{value} leaked:\n{result}");
+    }
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_ini_block_braces_and_open_headers_are_parse_errors() {
+    let source = "worker_processes 4;\nevents {\n    worker_connections 1024;\n}\n";
+    assert!(has_errors(source));
+    assert!(has_errors("[server\nport = 80\n"));
+    let result = transform("[server\nport = 80\n", Language::Ini, Mode::Structure).unwrap();
+    assert_eq!(result, "port\n");
+}
+
+// ============================================================================
+// Passthrough modes
+// ============================================================================
+
+#[test]
+fn test_ini_full_minimal_and_pseudo_passthrough() {
+    for mode in [Mode::Full, Mode::Minimal, Mode::Pseudo] {
+        let result = transform(SETUP_CFG, Language::Ini, mode).unwrap();
+        assert_eq!(result, SETUP_CFG, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_ini_constants_mode_keeps_every_value() {
+    let result = transform(MY_CNF, Language::Ini, Mode::Constants).unwrap();
+    assert_eq!(result, MY_CNF);
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_ini_outline_and_analysis_modes_fall_back_to_structure() {
+    let structure = transform(GITCONFIG, Language::Ini, Mode::Structure).unwrap();
+    for mode in [
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::CallGraph,
+    ] {
+        let result = transform(GITCONFIG, Language::Ini, mode).unwrap();
+        assert_eq!(result, structure, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_ini_summary_reports_lines_and_tokens_only() {
+    let result = transform(MY_CNF, Language::Ini, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 17, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_ini_mode_caveats() {
+    for mode in [Mode::Outline, Mode::Comments, Mode::Errors] {
+        assert!(Language::Ini.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    assert!(Language::Ini.mode_caveat(Mode::Structure).is_none());
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_ini_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Ini, Mode::Structure).unwrap();
+        assert!(result.len() < source.len(), "{name}:\n{result}");
+        assert!(!result.contains('='), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_ini_all_fixtures_pass_through_in_full_and_minimal() {
+    for (name, source) in FIXTURES {
+        for mode in [Mode::Full, Mode::Minimal] {
+            let result = transform(source, Language::Ini, mode).unwrap();
+            assert_eq!(result, source, "{name} {mode:?}");
+        }
+    }
+}
@@ -6,7 +6,7 @@
 
 use rskim_core::{
     Language, Mode, TransformConfig, transform, transform_auto, transform_with_config,
-    truncate_to_token_budget,
+    truncate_to_token_budget, validate_output,
 };
 use std::path::Path;
 
@@ -51,12 +51,28 @@ fn test_typescript_types() {
     assert!(result.contains("type UserId"));
     assert!(result.contains("interface User"));
     assert!(result.contains("enum Status"));
-    assert!(result.contains("class UserService"));
+    assert!(result.contains("class UserService {\n    private users: User[] = [];\n}"));
 
     // Should NOT contain function implementations
     assert!(!result.contains("findUser(id: UserId): User | null {"));
 }
 
+#[test]
+fn test_typescript_signatures_keep_accessor_keywords() {
+    let source = "class Box {\n  get size(): number {\n    return this.n;\n  }\n  set size(v: number) {\n    this.n = v;\n  }\n}\n";
+    let result = transform(source, Language::TypeScript, Mode::Signatures).unwrap();
+    assert_eq!(result, "get size(): number\nset size(v: number)");
+}
+
+#[test]
+fn test_typescript_structure_keeps_class_properties() {
+    let source = "class Cache {\n  private entries = new Map<string, number>();\n  readonly limit: number = 10;\n\n  get(key: string) {\n    return this.entries.get(key);\n  }\n}\n";
+    let result = transform(source, Language::TypeScript, Mode::Structure).unwrap();
+    assert!(result.contains("private entries = new Map<string, number>();"));
+    assert!(result.contains("readonly limit: number = 10;"));
+    assert!(!result.contains("return this.entries"));
+}
+
 #[test]
 fn test_typescript_full() {
     let source = include_str!("../../../tests/fixtures/typescript/simple.ts");
@@ -66,6 +82,39 @@ fn test_typescript_full() {
     assert_eq!(result, source);
 }
 
+#[test]
+fn test_typescript_structure_collapses_large_template_literal() {
+    let source = include_str!("../../../tests/fixtures/typescript/template_literals.ts");
+    let result = transform(source, Language::TypeScript, Mode::Structure).unwrap();
+
+    assert!(result.contains("export const WELCOME_EMAIL = `...`;"));
+    assert!(!result.contains("<td>Row 0</td>"));
+
+    // Short literals stay verbatim
+    assert!(result.contains("query GetUser($id: ID!)"));
+    assert!(result.lines().any(|line| {
+        line.starts_with("export function renderEmail(name: string): string")
+            && line.ends_with("{...}")
+    }));
+}
+
+#[test]
+fn test_typescript_structure_template_literal_threshold() {
+    let source = include_str!("../../../tests/fixtures/typescript/template_literals.ts");
+
+    // Opt-out keeps every literal intact
+    let config = TransformConfig::with_mode(Mode::Structure).with_template_literal_threshold(None);
+    let result = transform_with_config(source, Language::TypeScript, &config).unwrap();
+    assert!(result.contains("<td>Row 0</td>"));
+    assert!(!result.contains("`...`"));
+
+    // A low threshold collapses the short query too
+    let config =
+        TransformConfig::with_mode(Mode::Structure).with_template_literal_threshold(Some(16));
+    let result = transform_with_config(source, Language::TypeScript, &config).unwrap();
+    assert!(result.contains("export const GET_USER = `...`;"));
+}
+
 // ============================================================================
 // Python Tests
 // ============================================================================
@@ -97,6 +146,104 @@ fn test_python_signatures() {
     assert!(!result.contains("result = "));
 }
 
+#[test]
+fn test_python_script_structure_condenses_top_level_statements() {
+    let source = "import sys\n\nSETTINGS = {\n    'debug': True,\n}\n\nrun(\n    sys.argv,\n)\n\nif __name__ == '__main__':\n    run([])\n";
+    let result = transform(source, Language::Python, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "import sys\n\nSETTINGS = {...}\n\nrun(...)\n\nif __name__ == '__main__':\n    ...\n"
+    );
+}
+
+#[test]
+fn test_js_entry_point_structure_keeps_callees() {
+    let source = "const app = express();\n\napp.use(cors({\n  origin: '*',\n}));\n\nfor (const route of routes) {\n  app.use(route);\n}\n\napp.listen(3000);\n";
+    let result = transform(source, Language::JavaScript, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "const app = express();\n\napp.use(...);\n\nfor (const route of routes) {...}\n\napp.listen(3000);\n"
+    );
+}
+
+#[test]
+fn test_exported_module_structure_keeps_literals() {
+    let source = "const DEFAULTS = {\n  retries: 3,\n};\n\nexport function load() {\n  return DEFAULTS;\n}\n\nexport function save() {\n  return null;\n}\n\nregister(load);\n";
+    let result = transform(source, Language::TypeScript, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with("const DEFAULTS = {\n  retries: 3,\n};\n"),
+        "exported functions make this a module, not a script:\n{result}"
+    );
+}
+
+#[test]
+fn test_sql_migration_structure_condenses_data_changes() {
+    let source = "ALTER TABLE orders\n  ADD COLUMN user_id INT;\n\nINSERT INTO users (id, email) VALUES\n  (1, 'a@x.io'),\n  (2, 'b@x.io');\n\nUPDATE orders\nSET user_id = 1\nWHERE user_id IS NULL;\n";
+    let result = transform(source, Language::Sql, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "ALTER TABLE orders\n  ADD COLUMN user_id INT;\n\nINSERT INTO users (id, email) VALUES ...;\n\nUPDATE orders ...;\n"
+    );
+}
+
+#[test]
+fn test_signatures_marks_script_without_declarations() {
+    let source = "import sys\n\n# entry point\nprint(sys.argv)\nsys.exit(0)\n";
+    let result = transform(source, Language::Python, Mode::Signatures).unwrap();
+    assert_eq!(result, "# no signatures found (3 top-level statements)");
+}
+
+#[test]
+fn test_signatures_marker_uses_language_comment_syntax() {
+    let source = "const x = 1;";
+    let result = transform(source, Language::JavaScript, Mode::Signatures).unwrap();
+    assert_eq!(result, "// no signatures found (1 top-level statement)");
+}
+
+#[test]
+fn test_signatures_comment_only_file_stays_empty() {
+    let source = "// nothing here\n/* or here */\n";
+    let result = transform(source, Language::TypeScript, Mode::Signatures).unwrap();
+    assert_eq!(result, "");
+}
+
+#[test]
+fn test_python_signatures_prefix_properties() {
+    let source = "class Temp:\n    @property\n    def celsius(self) -> float:\n        return self._c\n\n    @celsius.setter\n    def celsius(self, value: float) -> None:\n        self._c = value\n\n    @staticmethod\n    def zero() -> 'Temp':\n        return Temp()\n";
+    let result = transform(source, Language::Python, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "@property def celsius(self) -> float:\n\
+         @celsius.setter def celsius(self, value: float) -> None:\n\
+         def zero() -> 'Temp':"
+    );
+}
+
+const PYTHON_USER_CLASS: &str = "class User:\n    kind: str = 'user'\n\n    def __init__(self, name, tags):\n        super().__init__()\n        self.name = name\n        self.tags = [\n            t.lower() for t in tags\n        ]\n        log('created')\n\n    def greet(self):\n        return self.name\n";
+
+#[test]
+fn test_python_structure_keeps_init_attributes() {
+    let result = transform(PYTHON_USER_CLASS, Language::Python, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "    def __init__(self, name, tags):\n        ...\n        self.name = name\n        self.tags = ...\n        ...\n"
+        ),
+        "got: {}",
+        result
+    );
+    assert!(!result.contains("log('created')"));
+    assert!(!result.contains("return self.name"));
+}
+
+#[test]
+fn test_python_types_lists_class_and_instance_attributes() {
+    let result = transform(PYTHON_USER_CLASS, Language::Python, Mode::Types).unwrap();
+    assert_eq!(
+        result,
+        "class User:\n    kind: str = 'user'\n    self.name = name\n    self.tags = ..."
+    );
+}
+
 // ============================================================================
 // Rust Tests
 // ============================================================================
@@ -136,6 +283,33 @@ fn test_rust_types() {
     assert!(result.contains("pub enum Status"));
 }
 
+#[test]
+fn test_rust_structure_todo_bodies() {
+    let source = "use std::fmt;\n\n#[derive(Debug, Clone)]\npub struct Point {\n    x: i32,\n}\n\n\
+                  impl fmt::Display for Point {\n    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {\n        \
+                  write!(f, \"{}\", self.x)\n    }\n}\n";
+    let config = TransformConfig::with_mode(Mode::Structure).with_todo_bodies(true);
+    let result = transform_with_config(source, Language::Rust, &config).unwrap();
+    assert_eq!(
+        result,
+        "use std::fmt;\n\n#[derive(Debug, Clone)]\npub struct Point {\n    x: i32,\n}\n\n\
+         impl fmt::Display for Point {\n    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result  { todo!() }\n}\n"
+    );
+}
+
+#[test]
+fn test_todo_bodies_ignored_for_other_languages() {
+    let config = TransformConfig::with_mode(Mode::Structure).with_todo_bodies(true);
+    let result = transform_with_config(
+        "function a() {\n  return 1;\n}\n",
+        Language::JavaScript,
+        &config,
+    )
+    .unwrap();
+    assert!(result.contains("{...}"), "got:\n{result}");
+    assert!(!result.contains("todo!"), "got:\n{result}");
+}
+
 // ============================================================================
 // Go Tests
 // ============================================================================
@@ -174,6 +348,22 @@ fn test_go_types() {
     assert!(result.contains("type Computer interface"));
 }
 
+#[test]
+fn test_go_types_synthesize_method_set_interface() {
+    let source = "package store\n\ntype Cache[K comparable, V any] struct {\n\titems map[K]V\n}\n\n\
+                  func (c *Cache[K, V]) Get(key K) (V, bool) {\n\tv, ok := c.items[key]\n\treturn v, ok\n}\n\n\
+                  func (c Cache[K, V]) Len() int {\n\treturn len(c.items)\n}\n\n\
+                  func (c *Cache[K, V]) evict() {}\n\ntype empty struct{}\n";
+    let result = transform(source, Language::Go, Mode::Types).unwrap();
+    assert_eq!(
+        result,
+        "type Cache[K comparable, V any] struct {\n\titems map[K]V\n}\n\n\
+         // skim: exported method set of Cache\n\
+         type CacheMethods interface {\n\tGet(key K) (V, bool)\n\tLen() int\n}\n\n\
+         type empty struct{}"
+    );
+}
+
 // ============================================================================
 // Java Tests
 // ============================================================================
@@ -211,6 +401,10 @@ fn test_java_types() {
     assert!(result.contains("public class Simple"));
     assert!(result.contains("interface Computer"));
     assert!(result.contains("enum Status"));
+
+    // Fields are kept under the class header; methods are not
+    assert!(result.contains("public class Simple {\n    private int value;\n}"));
+    assert!(!result.contains("public int add"));
 }
 
 // ============================================================================
@@ -302,7 +496,7 @@ fn test_detect_language_from_path() {
     );
     assert_eq!(
         detect_language_from_path(Path::new("test.tsx")),
-        Some(Language::TypeScript)
+        Some(Language::Tsx)
     );
     assert_eq!(
         detect_language_from_path(Path::new("script.py")),
@@ -480,6 +674,358 @@ fn test_signatures_more_aggressive() {
     assert!(signatures.len() < structure.len());
 }
 
+// ============================================================================
+// Signature Qualifier Tests
+// ============================================================================
+
+#[test]
+fn test_typescript_signatures_keep_async_and_generator_markers() {
+    let source = "export async function load(id: string): Promise<User> {\n  return db.get(id);\n}\n\n\
+                  function* ids(): Generator<number> {\n  yield 1;\n}\n\n\
+                  async function* pages(): AsyncGenerator<Page> {\n  yield first;\n}\n\n\
+                  class Repo {\n  async fetch(): Promise<void> {\n    await this.db;\n  }\n  *keys() {\n    yield 1;\n  }\n}\n";
+    let result = transform(source, Language::TypeScript, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "async function load(id: string): Promise<User>\n\
+         function* ids(): Generator<number>\n\
+         async function* pages(): AsyncGenerator<Page>\n\
+         async fetch(): Promise<void>\n\
+         *keys()"
+    );
+}
+
+#[test]
+fn test_typescript_signatures_include_overloads() {
+    let source = "function parse(input: string): Ast;\nfunction parse(input: Buffer): Ast;\n\
+                  function parse(input: string | Buffer): Ast {\n  return build(input);\n}\n";
+    let result = transform(source, Language::TypeScript, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "function parse(input: string): Ast;\n\
+         function parse(input: Buffer): Ast;\n\
+         function parse(input: string | Buffer): Ast"
+    );
+}
+
+#[test]
+fn test_structure_strips_generator_bodies() {
+    let source = "function* ids() {\n  let i = 0;\n  while (true) yield i++;\n}\n";
+    let result = transform(source, Language::JavaScript, Mode::Structure).unwrap();
+    assert!(result.contains("function* ids()"), "got:\n{result}");
+    assert!(
+        !result.contains("yield"),
+        "generator body should be stripped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_python_signatures_keep_async() {
+    let source = "async def fetch(url: str) -> bytes:\n    return await get(url)\n\n\
+                  class Client:\n    async def close(self) -> None:\n        await self.session.close()\n";
+    let result = transform(source, Language::Python, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "async def fetch(url: str) -> bytes:\nasync def close(self) -> None:"
+    );
+}
+
+#[test]
+fn test_rust_signatures_keep_qualifiers() {
+    let source = "pub async fn serve(addr: &str) -> io::Result<()> {\n    Ok(())\n}\n\n\
+                  pub unsafe extern \"C\" fn callback(data: *mut u8) {\n    drop(data);\n}\n\n\
+                  pub const fn limit() -> usize {\n    64\n}\n\n\
+                  extern \"C\" {\n    fn strlen(s: *const c_char) -> usize;\n}\n\n\
+                  pub trait Store {\n    unsafe fn raw(&self) -> *const u8;\n    fn len(&self) -> usize {\n        0\n    }\n}\n";
+    let result = transform(source, Language::Rust, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "pub async fn serve(addr: &str) -> io::Result<()>\n\
+         pub unsafe extern \"C\" fn callback(data: *mut u8)\n\
+         pub const fn limit() -> usize\n\
+         fn strlen(s: *const c_char) -> usize;\n\
+         unsafe fn raw(&self) -> *const u8;\n\
+         fn len(&self) -> usize"
+    );
+}
+
+#[test]
+fn test_kotlin_and_csharp_signatures_keep_modifiers() {
+    let kotlin = "suspend fun load(id: Int): User {\n    return repo.get(id)\n}\n";
+    let result = transform(kotlin, Language::Kotlin, Mode::Signatures).unwrap();
+    assert!(
+        result.starts_with("suspend fun load(id: Int): User"),
+        "got:\n{result}"
+    );
+
+    let csharp = "class Api {\n    public async Task<int> CountAsync() {\n        return await Query();\n    }\n    \
+                  public static extern int Native(int x);\n}\n";
+    let result = transform(csharp, Language::CSharp, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("public async Task<int> CountAsync()"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("public static extern int Native(int x);"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Operator Overload Tests
+// ============================================================================
+
+#[test]
+fn test_rust_signatures_include_operator_trait_methods() {
+    let source = "impl Add for Vec2 {\n    type Output = Vec2;\n\n    fn add(self, rhs: Vec2) -> Vec2 {\n        Vec2::new(self.x + rhs.x, self.y + rhs.y)\n    }\n}\n\n\
+                  impl Index<usize> for Grid {\n    type Output = Cell;\n\n    fn index(&self, i: usize) -> &Cell {\n        &self.cells[i]\n    }\n}\n";
+    let result = transform(source, Language::Rust, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "fn add(self, rhs: Vec2) -> Vec2\nfn index(&self, i: usize) -> &Cell"
+    );
+}
+
+#[test]
+fn test_python_signatures_include_dunder_methods() {
+    let source = "class Money:\n    def __init__(self, cents: int) -> None:\n        self.cents = cents\n\n\
+                  def __add__(self, other: 'Money') -> 'Money':\n        return Money(self.cents + other.cents)\n\n\
+                  def __eq__(self, other: object) -> bool:\n        return isinstance(other, Money)\n\n\
+                  def __repr__(self) -> str:\n        return f'Money({self.cents})'\n";
+    let result = transform(source, Language::Python, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "def __init__(self, cents: int) -> None:\n\
+         def __add__(self, other: 'Money') -> 'Money':\n\
+         def __eq__(self, other: object) -> bool:\n\
+         def __repr__(self) -> str:"
+    );
+}
+
+#[test]
+fn test_cpp_signatures_include_operator_overloads() {
+    let source = "class Vec {\npublic:\n    Vec operator+(const Vec& other) const;\n    \
+                  Vec& operator+=(const Vec& other);\n    bool operator==(const Vec& other) const {\n        return x == other.x;\n    }\n    \
+                  explicit operator bool() const;\n    friend std::ostream& operator<<(std::ostream& os, const Vec& v);\n};\n\n\
+                  Vec Vec::operator+(const Vec& other) const {\n    return Vec(x + other.x);\n}\n";
+    let result = transform(source, Language::Cpp, Mode::Signatures).unwrap();
+    for expected in [
+        "Vec operator+(const Vec& other) const;",
+        "Vec& operator+=(const Vec& other);",
+        "bool operator==(const Vec& other) const",
+        "explicit operator bool() const;",
+        "std::ostream& operator<<(std::ostream& os, const Vec& v);",
+        "Vec Vec::operator+(const Vec& other) const",
+    ] {
+        assert!(
+            result.contains(expected),
+            "missing `{expected}`, got:\n{result}"
+        );
+    }
+    assert!(
+        !result.contains("return"),
+        "bodies must be stripped, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Public-Only Tests
+// ============================================================================
+
+fn transform_public(source: &str, language: Language, mode: Mode) -> String {
+    let config = TransformConfig::with_mode(mode).with_public_only(true);
+    transform_with_config(source, language, &config).unwrap()
+}
+
+#[test]
+fn test_public_only_structure_removes_private_items_whole_lines() {
+    let source = "use std::io;\n\n/// Public entry.\npub fn run() {\n    helper();\n}\n\n\
+                  /// Internal helper.\n#[inline]\nfn helper() {\n    println!(\"hi\");\n}\n\n\
+                  pub struct Config {\n    pub name: String,\n}\n\n\
+                  impl Config {\n    pub fn new() -> Self {\n        todo!()\n    }\n\n    fn validate(&self) {\n        todo!()\n    }\n}\n";
+    let result = transform_public(source, Language::Rust, Mode::Structure);
+    assert!(
+        result.contains("/// Public entry.\npub fn run()"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("pub fn new() -> Self"), "got:\n{result}");
+    for private in ["helper", "#[inline]", "Internal helper", "validate"] {
+        assert!(
+            !result.contains(private),
+            "`{private}` should be removed, got:\n{result}"
+        );
+    }
+    assert!(
+        !result.lines().any(|l| !l.is_empty() && l.trim().is_empty()),
+        "removals must not leave indent-only lines, got:\n{result:?}"
+    );
+}
+
+#[test]
+fn test_public_only_structure_collapses_blank_lines_around_removals() {
+    let source = "export class A {\n  private x() {\n    return 1;\n  }\n\n  private y() { return 2; }\n\n  z() {\
+                  \n    return 3;\n  }\n\n  w() {}\n\n  private v() {}\n}\n\n\
+                  function hidden() {}\n\nexport function shown() {}\n";
+    assert_eq!(
+        transform_public(source, Language::TypeScript, Mode::Structure),
+        "export class A {\n  z()  {...}\n\n  w()  {...}\n}\n\nexport function shown()  {...}\n"
+    );
+
+    // Between two kept declarations a single blank line remains.
+    let source = "pub fn a() {}\n\nfn b() {}\n\nfn c() {}\n\npub fn d() {}\n";
+    assert_eq!(
+        transform_public(source, Language::Rust, Mode::Structure),
+        "pub fn a()  {...}\n\npub fn d()  {...}\n"
+    );
+}
+
+#[test]
+fn test_public_only_typescript_signatures_keep_exports() {
+    let source = "export function api(): void {\n  helper();\n}\n\nfunction helper(): void {}\n\n\
+                  export class Service {\n  private cache = new Map();\n  load(): void {}\n  private reset(): void {}\n}\n";
+    let result = transform_public(source, Language::TypeScript, Mode::Signatures);
+    assert_eq!(result, "function api(): void\nload(): void");
+}
+
+#[test]
+fn test_public_only_types_filters_private_types_and_fields() {
+    let source = "export interface User {\n  id: string;\n}\n\ninterface Row {\n  raw: string;\n}\n\n\
+                  export class Store {\n  public items: User[] = [];\n  private index = 0;\n}\n";
+    let result = transform_public(source, Language::TypeScript, Mode::Types);
+    assert!(result.contains("interface User"), "got:\n{result}");
+    assert!(
+        result.contains("public items: User[] = [];"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("Row"), "got:\n{result}");
+    assert!(!result.contains("index"), "got:\n{result}");
+}
+
+#[test]
+fn test_public_only_go_and_java() {
+    let go = "package p\n\nfunc Serve() error {\n\treturn nil\n}\n\nfunc parse() {\n}\n";
+    assert_eq!(
+        transform_public(go, Language::Go, Mode::Signatures),
+        "func Serve() error"
+    );
+
+    let java = "public class Api {\n    public void run() {\n    }\n    private void helper() {\n    }\n}\n";
+    let result = transform_public(java, Language::Java, Mode::Signatures);
+    assert!(result.contains("public void run()"), "got:\n{result}");
+    assert!(!result.contains("helper"), "got:\n{result}");
+}
+
+#[test]
+fn test_public_only_disabled_by_default() {
+    let source = "fn helper() {}\npub fn api() {}\n";
+    let result = transform(source, Language::Rust, Mode::Signatures).unwrap();
+    assert!(result.contains("fn helper()"));
+}
+
+// ============================================================================
+// Module Doc Tests
+// ============================================================================
+
+fn transform_with_doc(source: &str, language: Language, mode: Mode) -> String {
+    let config = TransformConfig::with_mode(mode).with_module_doc(true);
+    transform_with_config(source, language, &config).unwrap()
+}
+
+#[test]
+fn test_module_doc_rust_inner_doc_leads_signatures() {
+    let source = "//! Token counting.\n//!\n//! Wraps the tokenizer.\n\nuse std::io;\n\n\
+                  /// Count tokens.\npub fn count(s: &str) -> usize {\n    s.len()\n}\n";
+    assert_eq!(
+        transform_with_doc(source, Language::Rust, Mode::Signatures),
+        "//! Token counting.\n//!\n//! Wraps the tokenizer.\npub fn count(s: &str) -> usize"
+    );
+}
+
+#[test]
+fn test_module_doc_python_docstring_leads_types() {
+    let source = "#!/usr/bin/env python3\n\"\"\"Billing models.\"\"\"\n\nclass Invoice:\n    total: int = 0\n";
+    let result = transform_with_doc(source, Language::Python, Mode::Types);
+    assert!(
+        result.starts_with("\"\"\"Billing models.\"\"\"\n\nclass Invoice:"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_module_doc_requires_fileoverview_tag_in_typescript() {
+    let tagged =
+        "/**\n * @fileoverview HTTP client.\n */\n\nexport function get(url: string): void {}\n";
+    assert_eq!(
+        transform_with_doc(tagged, Language::TypeScript, Mode::Signatures),
+        "/**\n * @fileoverview HTTP client.\n */\nfunction get(url: string): void"
+    );
+
+    // A JSDoc block on the first declaration is not a file header.
+    let untagged = "/** Fetch a URL. */\nexport function get(url: string): void {}\n";
+    assert_eq!(
+        transform_with_doc(untagged, Language::TypeScript, Mode::Signatures),
+        "function get(url: string): void"
+    );
+}
+
+#[test]
+fn test_module_doc_go_package_comment() {
+    let source = "// Package cache stores skim output.\n// It is safe for concurrent use.\npackage cache\n\n\
+                  func Get(key string) []byte {\n\treturn nil\n}\n";
+    assert_eq!(
+        transform_with_doc(source, Language::Go, Mode::Signatures),
+        "// Package cache stores skim output.\n// It is safe for concurrent use.\nfunc Get(key string) []byte"
+    );
+}
+
+#[test]
+fn test_module_doc_disabled_by_default() {
+    let source = "//! Crate docs.\n\npub fn run() {}\n";
+    assert_eq!(
+        transform(source, Language::Rust, Mode::Signatures).unwrap(),
+        "pub fn run()"
+    );
+}
+
+// ============================================================================
+// Symbol Tests
+// ============================================================================
+
+#[test]
+fn test_symbols_typescript_nested_names_and_lines() {
+    let source = "import { x } from './x';\n\nexport class Config {\n  validate(): boolean {\n    return true;\n  }\n}\n\nconst load = () => new Config();\n";
+    let symbols = rskim_core::symbols(source, Language::TypeScript).unwrap();
+    let summary: Vec<_> = symbols
+        .iter()
+        .map(|s| (s.name.as_str(), s.start_line, s.end_line, s.depth))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("Config", 3, 7, 0),
+            ("Config.validate", 4, 6, 1),
+            ("load", 9, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_symbols_rust_marks_visibility() {
+    let source = "pub fn open() {}\nfn helper() {}\n";
+    let symbols = rskim_core::symbols(source, Language::Rust).unwrap();
+    let public: Vec<_> = symbols
+        .iter()
+        .map(|s| (s.name.as_str(), s.public))
+        .collect();
+    assert_eq!(public, vec![("open", true), ("helper", false)]);
+}
+
+#[test]
+fn test_symbols_empty_for_data_formats() {
+    let symbols = rskim_core::symbols("{\"a\": 1}", Language::Json).unwrap();
+    assert!(symbols.is_empty());
+}
+
 // ============================================================================
 // JSON Tests
 // ============================================================================
@@ -779,6 +1325,109 @@ fn test_yaml_simple_structure() {
     assert!(!result.contains("true"));
 }
 
+#[test]
+fn test_yaml_openapi_spec_summarizes_operations() {
+    let source = include_str!("../../../tests/fixtures/yaml/openapi.yaml");
+    let result = transform(source, Language::Yaml, Mode::Structure).unwrap();
+
+    assert_eq!(
+        result,
+        "openapi: 3.0.3
+info: Swagger Petstore 1.0.0
+paths:
+  /pets:
+    GET listPets
+    POST createPets
+  /pets/{petId}:
+    GET showPetById
+    DELETE
+schemas:
+  Pet
+  Pets
+  Error
+"
+    );
+}
+
+#[test]
+fn test_json_swagger_spec_summarizes_operations() {
+    let source = include_str!("../../../tests/fixtures/json/swagger.json");
+    for mode in [Mode::Structure, Mode::Signatures, Mode::Types] {
+        let result = transform(source, Language::Json, mode).unwrap();
+        assert_eq!(
+            result,
+            "swagger: 2.0
+info: User Service 2.3.1
+paths:
+  /users:
+    GET listUsers
+  /users/{id}:
+    PUT updateUser
+    DELETE deleteUser
+definitions:
+  User
+  ApiError
+",
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn test_json_package_manifest_keeps_dependency_and_script_names() {
+    let source = include_str!("../../../tests/fixtures/json/package_manifest.json");
+    let result = transform(source, Language::Json, Mode::Structure).unwrap();
+
+    assert_eq!(
+        result,
+        "package: dashboard 0.9.2
+bin:
+  dashboard-cli
+scripts:
+  dev
+  build
+  test
+  lint
+dependencies:
+  react
+  react-dom
+  zustand
+devDependencies:
+  typescript
+  vite
+  vitest
+"
+    );
+}
+
+#[test]
+fn test_toml_cargo_manifest_keeps_dependency_feature_and_bin_names() {
+    let source = include_str!("../../../tests/fixtures/toml/cargo_manifest.toml");
+    let result = transform(source, Language::Toml, Mode::Structure).unwrap();
+
+    assert_eq!(
+        result,
+        "package: ledger 0.4.1
+bin:
+  ledger
+  ledger-import
+features:
+  default
+  postgres
+  sqlite
+dependencies:
+  anyhow
+  rusqlite
+  serde
+  tokio-postgres
+dev-dependencies:
+  proptest
+target.cfg(windows).dependencies:
+  windows-sys
+"
+    );
+}
+
 #[test]
 fn test_yaml_nested_structure() {
     let source = include_str!("../../../tests/fixtures/yaml/nested.yaml");
@@ -1990,8 +2639,34 @@ fn test_c_types() {
         result
     );
 
-    // NOTE: union Value is not extracted because TypeNodeTypes does not include
-    // union_specifier. This is tracked as a known gap, not a test failure.
+    assert!(
+        result.contains("union Value"),
+        "Should extract union definition, got: {}",
+        result
+    );
+}
+
+#[test]
+fn test_c_header_signatures_include_prototypes() {
+    let source = include_str!("../../../tests/fixtures/c/header.h");
+    let result = transform(source, Language::C, Mode::Signatures).unwrap();
+
+    assert!(result.contains("Buffer *buffer_new(size_t capacity);"));
+    assert!(result.contains("int buffer_append(Buffer *buf, const char *data, size_t len);"));
+    assert!(result.contains("void buffer_free(Buffer *buf);"));
+    assert!(result.contains("static inline size_t buffer_len(const Buffer *buf);"));
+
+    // Variables and function-pointer typedefs are not prototypes
+    assert!(!result.contains("buffer_debug"));
+    assert!(!result.contains("buffer_free_fn"));
+    assert_eq!(result.lines().count(), 4, "got: {}", result);
+}
+
+#[test]
+fn test_c_function_pointer_variable_is_not_a_prototype() {
+    let source = "int (*callback)(int);\nint run(int n);\n";
+    let result = transform(source, Language::C, Mode::Signatures).unwrap();
+    assert_eq!(result, "int run(int n);");
 }
 
 #[test]
@@ -2959,3 +3634,336 @@ fn test_pseudo_auto_detection() {
     );
     assert!(result.contains("fn hello"), "function preserved");
 }
+
+// ============================================================================
+// Python Stub Tests
+// ============================================================================
+
+const PYTHON_STUB_SOURCE: &str = r#""""Billing."""
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, TypeVar
+
+if TYPE_CHECKING:
+    from .db import Session
+
+T = TypeVar("T")
+RATE: float = 0.2
+
+def total(items: list[int], *, tax: bool = True) -> int:
+    return sum(items)
+
+@dataclass
+class Invoice(Base):
+    """An invoice."""
+    id: int
+    lines: list[str] = []
+
+    def __init__(self, owner: str) -> None:
+        self.owner: str = owner
+        self.cache = {}
+
+    @property
+    def paid(self) -> bool:
+        return False
+
+    class Meta:
+        ordering = "id"
+
+class Empty:
+    pass
+"#;
+
+fn transform_stub(source: &str, mode: Mode) -> String {
+    let config = TransformConfig::with_mode(mode).with_python_stub(true);
+    transform_with_config(source, Language::Python, &config).unwrap()
+}
+
+#[test]
+fn test_python_stub_signatures() {
+    assert_eq!(
+        transform_stub(PYTHON_STUB_SOURCE, Mode::Signatures),
+        r#"from dataclasses import dataclass
+from typing import TYPE_CHECKING, TypeVar
+from .db import Session
+T = TypeVar("T")
+RATE: float
+
+def total(items: list[int], *, tax: bool = True) -> int: ...
+
+@dataclass
+class Invoice(Base):
+    owner: str
+    id: int
+    lines: list[str]
+    def __init__(self, owner: str) -> None: ...
+    @property
+    def paid(self) -> bool: ...
+    class Meta:
+        ordering = "id"
+
+class Empty: ..."#
+    );
+}
+
+#[test]
+fn test_python_stub_types_omits_module_functions() {
+    let result = transform_stub(PYTHON_STUB_SOURCE, Mode::Types);
+    assert!(!result.contains("def total"), "got:\n{result}");
+    assert!(result.contains("class Invoice(Base):"), "got:\n{result}");
+    assert!(
+        result.contains("    def paid(self) -> bool: ..."),
+        "methods stay part of the class, got:\n{result}"
+    );
+    assert!(result.contains("RATE: float"), "got:\n{result}");
+}
+
+#[test]
+fn test_python_stub_public_only() {
+    let source = "_cache: dict = {}\n\ndef _helper() -> None:\n    pass\n\nclass Api:\n    def _check(self) -> None:\n        pass\n";
+    let config = TransformConfig::with_mode(Mode::Signatures)
+        .with_python_stub(true)
+        .with_public_only(true);
+    assert_eq!(
+        transform_with_config(source, Language::Python, &config).unwrap(),
+        "class Api: ..."
+    );
+}
+
+#[test]
+fn test_python_stub_ignored_for_other_languages() {
+    let config = TransformConfig::with_mode(Mode::Signatures).with_python_stub(true);
+    let result =
+        transform_with_config("pub fn a() -> u8 {\n    1\n}\n", Language::Rust, &config).unwrap();
+    assert_eq!(result, "pub fn a() -> u8");
+}
+
+// ============================================================================
+// Parameter Elision Tests
+// ============================================================================
+
+#[test]
+fn test_max_params_elides_long_parameter_lists() {
+    let source = "export function create(a: string, b: number, c: boolean, d: Date): void {\n  return;\n}\n\nexport function get(id: string): void {}\n";
+    let config = TransformConfig::with_mode(Mode::Signatures).with_max_params(3);
+    let result = transform_with_config(source, Language::TypeScript, &config).unwrap();
+    assert!(
+        result.contains("function create(... 4 params): void"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("function get(id: string): void"),
+        "short lists are kept, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_max_params_counts_c_declarator_parameters() {
+    let source = "int add3(int a, int b, int c) {\n    return a + b + c;\n}\n";
+    let config = TransformConfig::with_mode(Mode::Signatures).with_max_params(2);
+    let result = transform_with_config(source, Language::C, &config).unwrap();
+    assert_eq!(result, "int add3(... 3 params)");
+}
+
+#[test]
+fn test_max_params_keeps_full_list_in_symbols() {
+    let source = "fn build(a: u8, b: u8, c: u8) -> u8 {\n    a\n}\n";
+    let found = rskim_core::symbols(source, Language::Rust).unwrap();
+    assert_eq!(
+        found[0].parameters.as_deref(),
+        Some("(a: u8, b: u8, c: u8)")
+    );
+}
+
+// ============================================================================
+// Soft Wrap Tests
+// ============================================================================
+
+#[test]
+fn test_wrap_width_soft_wraps_long_signatures() {
+    let source = "def request(method, url, params, data, headers, cookies, files, auth):\n    pass\n\ndef ping(host):\n    pass\n";
+    let config = TransformConfig::with_mode(Mode::Signatures).with_wrap_width(40);
+    let result = transform_with_config(source, Language::Python, &config).unwrap();
+    assert_eq!(
+        result,
+        "def request(method, url, params, data,\n    headers, cookies, files, auth):\ndef ping(host):"
+    );
+}
+
+#[test]
+fn test_wrap_width_measures_display_columns() {
+    let source = "def 获取用户(用户名, 密码, 记住我):\n    pass\n";
+    let config = TransformConfig::with_mode(Mode::Signatures).with_wrap_width(20);
+    let result = transform_with_config(source, Language::Python, &config).unwrap();
+    assert_eq!(result, "def 获取用户(用户名,\n    密码, 记住我):");
+}
+
+#[test]
+fn test_wrap_width_segments_map_to_their_source_line() {
+    let source = "def request(method, url, params, data, headers, cookies, files, auth):\n    pass\n\ndef ping(host):\n    pass\n";
+    let config = TransformConfig::with_mode(Mode::Signatures)
+        .with_wrap_width(40)
+        .with_line_numbers(true);
+    let (_, _, line_map, _) =
+        rskim_core::transform_with_line_map(source, Language::Python, &config).unwrap();
+    assert_eq!(line_map.unwrap(), vec![1, 1, 4]);
+}
+
+// ============================================================================
+// Nesting Limit Tests
+// ============================================================================
+
+#[test]
+fn test_max_nesting_kept_drops_deep_declarations() {
+    let source = "pub mod outer {\n    pub struct S;\n\n    impl S {\n        /// Builds one.\n        #[inline]\n        pub fn new() -> S {\n            S\n        }\n    }\n}\n\nfn top() {}\n";
+    let nested = |n| {
+        let config = TransformConfig::with_mode(Mode::Structure).with_max_nesting_kept(n);
+        transform_with_config(source, Language::Rust, &config).unwrap()
+    };
+    assert_eq!(nested(0), "pub mod outer {\n}\n\nfn top()  {...}\n");
+    assert_eq!(
+        nested(1),
+        "pub mod outer {\n    pub struct S;\n\n    impl S {\n    }\n}\n\nfn top()  {...}\n"
+    );
+    let unlimited = TransformConfig::with_mode(Mode::Structure);
+    assert_eq!(
+        nested(2),
+        transform_with_config(source, Language::Rust, &unlimited).unwrap()
+    );
+}
+
+#[test]
+fn test_max_nesting_kept_removes_list_elements_with_their_comma() {
+    let source = "class A {\n  static cfg = {\n    run: () => 1,\n    x: 1,\n  };\n}\n";
+    let config = TransformConfig::with_mode(Mode::Structure).with_max_nesting_kept(0);
+    assert_eq!(
+        transform_with_config(source, Language::JavaScript, &config).unwrap(),
+        "class A {\n  static cfg = {\n    x: 1,\n  };\n}\n"
+    );
+}
+
+#[test]
+fn test_max_nesting_kept_skips_collapsed_bodies_and_wrappers() {
+    // `g` lives in a body structure mode collapses anyway; it must not
+    // un-collapse `f`'s body.
+    let source = "class A:\n    def f(self):\n        def g():\n            pass\n        return g\n\n    class B:\n        @staticmethod\n        def m():\n            pass\n";
+    let config = TransformConfig::with_mode(Mode::Structure).with_max_nesting_kept(1);
+    let result = transform_with_config(source, Language::Python, &config).unwrap();
+    assert!(result.contains("def f(self):\n         {...}"), "{result}");
+    assert!(result.contains("class B:"), "{result}");
+    assert!(!result.contains("staticmethod"), "{result}");
+    assert!(!result.contains("def m"), "{result}");
+
+    let source = "export namespace N {\n  export class C {\n    run(): void { go(); }\n  }\n}\n";
+    let config = TransformConfig::with_mode(Mode::Structure).with_max_nesting_kept(0);
+    let result = transform_with_config(source, Language::TypeScript, &config).unwrap();
+    assert_eq!(result, "export namespace N {\n  export class C {\n  }\n}\n");
+}
+
+#[test]
+fn test_max_nesting_kept_ignored_outside_structure_mode() {
+    let source = "public class Outer {\n    static class Inner {\n        void b() {}\n    }\n}\n";
+    let config = TransformConfig::with_mode(Mode::Signatures).with_max_nesting_kept(0);
+    let unlimited = TransformConfig::with_mode(Mode::Signatures);
+    assert_eq!(
+        transform_with_config(source, Language::Java, &config).unwrap(),
+        transform_with_config(source, Language::Java, &unlimited).unwrap()
+    );
+}
+
+// ============================================================================
+// Body Statement Tests
+// ============================================================================
+
+#[test]
+fn test_body_statements_keeps_leading_statements() {
+    let source = "fn load(path: &str) -> Result<Config> {\n    if path.is_empty() { // guard\n        return Err(Error::Empty);\n    }\n    // read it\n    let text = read(path)?;\n    parse(&text)\n}\n\nfn tiny() -> u8 { 1 }\n";
+    let kept = |n, todo| {
+        let config = TransformConfig::with_mode(Mode::Structure)
+            .with_body_statements(n)
+            .with_todo_bodies(todo);
+        transform_with_config(source, Language::Rust, &config).unwrap()
+    };
+    assert_eq!(
+        kept(1, false),
+        "fn load(path: &str) -> Result<Config> {\n    if path.is_empty() { // guard\n        return Err(Error::Empty);\n    }\n    ...\n}\n\nfn tiny() -> u8 { 1 }\n"
+    );
+    assert!(
+        kept(2, false).contains("    // read it\n    let text = read(path)?;\n    ...\n}"),
+        "{}",
+        kept(2, false)
+    );
+    assert!(kept(1, true).contains("    }\n    todo!()\n}"));
+    assert_eq!(kept(3, false), source);
+}
+
+#[test]
+fn test_body_statements_unwraps_nested_statement_lists() {
+    let config = TransformConfig::with_mode(Mode::Structure).with_body_statements(1);
+    let go = "package m\n\nfunc f(x int) int {\n\tif x < 0 {\n\t\treturn 0\n\t}\n\treturn x\n}\n";
+    assert_eq!(
+        transform_with_config(go, Language::Go, &config).unwrap(),
+        "package m\n\nfunc f(x int) int {\n\tif x < 0 {\n\t\treturn 0\n\t}\n\t...\n}\n"
+    );
+    let python = "def f(x):\n    if x < 0:\n        return 0\n    return x\n";
+    assert_eq!(
+        transform_with_config(python, Language::Python, &config).unwrap(),
+        "def f(x):\n    if x < 0:\n        return 0\n    ...\n"
+    );
+    // An expression body is one statement.
+    let kotlin = "fun g(x: Int) = x + 1\n";
+    assert_eq!(
+        transform_with_config(kotlin, Language::Kotlin, &config).unwrap(),
+        kotlin
+    );
+}
+
+// ============================================================================
+// Output Validation Tests
+// ============================================================================
+
+#[test]
+fn test_structure_output_introduces_no_syntax_errors() {
+    let fixtures = [
+        (
+            include_str!("../../../tests/fixtures/typescript/simple.ts"),
+            Language::TypeScript,
+        ),
+        (
+            include_str!("../../../tests/fixtures/typescript/component.tsx"),
+            Language::TypeScript,
+        ),
+        (
+            include_str!("../../../tests/fixtures/python/simple.py"),
+            Language::Python,
+        ),
+        (
+            include_str!("../../../tests/fixtures/rust/simple.rs"),
+            Language::Rust,
+        ),
+        (
+            include_str!("../../../tests/fixtures/go/simple.go"),
+            Language::Go,
+        ),
+        (
+            include_str!("../../../tests/fixtures/java/Simple.java"),
+            Language::Java,
+        ),
+    ];
+    for (source, language) in fixtures {
+        let output = transform(source, language, Mode::Structure).unwrap();
+        let report = validate_output(source, &output, language).unwrap().unwrap();
+        assert_eq!(report.introduced_errors(), 0, "{language:?}: {output}");
+    }
+}
+
+#[test]
+fn test_validate_output_reports_broken_output() {
+    let source = "def f(x):\n    return x\n";
+    let report = validate_output(source, "def f(x:\n     {...}\n", Language::Python)
+        .unwrap()
+        .unwrap();
+    assert_eq!(report.source_errors, 0);
+    assert!(report.introduced_errors() > 0);
+    assert_eq!(report.first_error_line, Some(1));
+    assert_eq!(validate_output("{}", "", Language::Json).unwrap(), None);
+}
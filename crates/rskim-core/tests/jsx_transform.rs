@@ -0,0 +1,142 @@
+//! TSX/JSX transformation tests — verify JSX components parse cleanly in all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const COMPONENT_TSX: &str = include_str!("../../../tests/fixtures/typescript/component.tsx");
+const COMPONENT_JSX: &str = include_str!("../../../tests/fixtures/javascript/component.jsx");
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_tsx_jsx_language_detection() {
+    use std::path::Path;
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("App.tsx")),
+        Some(Language::Tsx)
+    );
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("app.ts")),
+        Some(Language::TypeScript)
+    );
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("App.jsx")),
+        Some(Language::JavaScript)
+    );
+}
+
+// ============================================================================
+// Parse quality
+// ============================================================================
+
+#[test]
+fn test_tsx_component_parses_without_errors() {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    let (_, has_errors) = transform_with_quality(COMPONENT_TSX, Language::Tsx, &config).unwrap();
+    assert!(
+        !has_errors,
+        "TSX fixture should parse cleanly via the TSX grammar"
+    );
+}
+
+#[test]
+fn test_jsx_component_parses_without_errors() {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    let (_, has_errors) =
+        transform_with_quality(COMPONENT_JSX, Language::JavaScript, &config).unwrap();
+    assert!(!has_errors, "JSX fixture should parse cleanly");
+}
+
+#[test]
+fn test_plain_typescript_type_assertion_still_parses() {
+    // `<T>expr` is only valid in the plain TypeScript grammar, which `.ts`
+    // files keep.
+    let source = "function f(value: unknown): number {\n  return <number>value;\n}\n";
+    let config = TransformConfig::with_mode(Mode::Structure);
+    let (result, has_errors) =
+        transform_with_quality(source, Language::TypeScript, &config).unwrap();
+    assert!(!has_errors);
+    assert!(result.contains("function f(value: unknown): number"));
+}
+
+#[test]
+fn test_grammar_follows_extension_not_content() {
+    // JSX is a syntax error under the plain grammar; `.ts` files never retry as TSX.
+    let source = "export const App = () => <div />;\n";
+    let config = TransformConfig::with_mode(Mode::Structure);
+    let (_, has_errors) = transform_with_quality(source, Language::TypeScript, &config).unwrap();
+    assert!(has_errors);
+    let (_, has_errors) = transform_with_quality(source, Language::Tsx, &config).unwrap();
+    assert!(!has_errors);
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_tsx_structure_strips_component_bodies() {
+    let result = transform(COMPONENT_TSX, Language::Tsx, Mode::Structure).unwrap();
+    assert!(
+        result.contains("export function Button({ label, onClick }: ButtonProps): JSX.Element"),
+        "component signature should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("export interface ButtonProps"),
+        "props interface should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("class Panel"),
+        "class component should be preserved, got:\n{result}"
+    );
+    assert!(
+        !result.contains("className=\"btn\""),
+        "JSX markup inside bodies should be stripped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_jsx_structure_strips_component_bodies() {
+    let result = transform(COMPONENT_JSX, Language::JavaScript, Mode::Structure).unwrap();
+    assert!(
+        result.contains("export function Button({ label, onClick })"),
+        "component signature should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("{...}"),
+        "component bodies should be replaced, got:\n{result}"
+    );
+    assert!(
+        !result.contains("<span>{count}</span>"),
+        "JSX markup inside bodies should be stripped, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Signatures / Types modes
+// ============================================================================
+
+#[test]
+fn test_tsx_signatures_lists_components() {
+    let result = transform(COMPONENT_TSX, Language::Tsx, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("Button"),
+        "function component should appear in signatures, got:\n{result}"
+    );
+    assert!(
+        result.contains("render()"),
+        "class component method should appear in signatures, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_tsx_types_keeps_props_interface() {
+    let result = transform(COMPONENT_TSX, Language::Tsx, Mode::Types).unwrap();
+    assert!(
+        result.contains("interface ButtonProps"),
+        "props interface should appear in types mode, got:\n{result}"
+    );
+}
@@ -0,0 +1,407 @@
+//! Julia transformation tests — verify function bodies are elided,
+//! module/struct declarations survive, and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{AstFormat, Language, Mode, transform};
+
+const GEOMETRY: &str = include_str!("../../../tests/fixtures/julia/geometry.jl");
+const STATS: &str = include_str!("../../../tests/fixtures/julia/stats.jl");
+const PARSING: &str = include_str!("../../../tests/fixtures/julia/Parsing.jl");
+const RUNTESTS: &str = include_str!("../../../tests/fixtures/julia/runtests.jl");
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_julia_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("jl"), Some(Language::Julia));
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("src/Geometry.jl")),
+        Some(Language::Julia)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_julia_structure_elides_function_bodies() {
+    let result = transform(GEOMETRY, Language::Julia, Mode::Structure).unwrap();
+    assert!(
+        result.contains("function area(c::Circle)\n    {...}\nend"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("function area(r::Rectangle)\n    {...}\nend"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("function perimeter(r::Rectangle{T}) where {T}\n    {...}\nend"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("macro check(expr)\n    {...}\nend"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("π * r^2"));
+    assert!(!result.contains("total += 2side"));
+    assert!(!result.contains("check failed"));
+}
+
+#[test]
+fn test_julia_structure_keeps_modules_types_and_short_definitions() {
+    let result = transform(GEOMETRY, Language::Julia, Mode::Structure).unwrap();
+    assert!(result.contains("module Geometry"));
+    assert!(result.contains("export Shape, Circle, Rectangle, area, perimeter"));
+    assert!(result.contains("abstract type Shape end"));
+    assert!(result.contains("struct Circle <: Shape\n    radius::Float64\nend"));
+    assert!(result.contains("mutable struct Rectangle{T<:Real} <: Shape"));
+    // Docstrings are string literals and stay with their definitions
+    assert!(result.contains("Return the enclosed area of `s`."));
+    // One-line definitions are kept as written
+    assert!(result.contains("scale(c::Circle, k) = Circle(c.radius * k)"));
+}
+
+#[test]
+fn test_julia_structure_elides_inner_constructors_and_nested_modules() {
+    let result = transform(PARSING, Language::Julia, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "struct Token <: AbstractToken
+    kind::TokenKind
+    text::String
+    offset::Int
+
+    function Token(kind, text, offset)
+        {...}
+    end
+end"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("module Lexer\n\n# FIXME: operators longer than one character\nisoperator(c::Char) = c in \"+-*/\"\n\nfunction classify(c::Char)\n    {...}\nend\n\nend # module Lexer"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("@enum TokenKind NUMBER IDENT OPERATOR\n\nprimitive type Tag8 8 end\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("push!"), "got:\n{result}");
+}
+
+#[test]
+fn test_julia_structure_keeps_constants_and_macro_annotated_heads() {
+    let result = transform(STATS, Language::Julia, Mode::Structure).unwrap();
+    assert!(
+        result.contains("const DEFAULT_TRIM = 0.1\nconst MAX_SAMPLES = 1_000_000\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("@inline function clamp01(x::T) where {T<:AbstractFloat}\n    {...}\nend"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "function trimmed_mean(xs::AbstractVector{<:Real}; trim::Real=DEFAULT_TRIM)\n    {...}\nend"
+        ),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("rethrow"), "got:\n{result}");
+    assert!(!result.contains("foreach"), "got:\n{result}");
+}
+
+#[test]
+fn test_julia_structure_keeps_testsets_at_top_level() {
+    let result = transform(RUNTESTS, Language::Julia, Mode::Structure).unwrap();
+    assert!(
+        result.contains("@testset \"variance\" begin\n    @test variance([1, 1, 1]) == 0\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("function approx_all(xs, ys; atol=1e-8)\n    {...}\nend"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("isapprox"), "got:\n{result}");
+}
+
+// ============================================================================
+// Signatures mode
+// ============================================================================
+
+#[test]
+fn test_julia_signatures_lists_functions_and_macros() {
+    let result = transform(GEOMETRY, Language::Julia, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("function area(c::Circle)"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("function perimeter(r::Rectangle{T}) where {T}"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("macro check(expr)"), "got:\n{result}");
+    assert!(!result.contains("return"), "got:\n{result}");
+    assert!(!result.contains("end"), "got:\n{result}");
+}
+
+#[test]
+fn test_julia_signatures_keep_keywords_defaults_and_where_clauses() {
+    let result = transform(STATS, Language::Julia, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "function trimmed_mean(xs::AbstractVector{<:Real}; trim::Real=DEFAULT_TRIM)",
+            "function variance(xs, corrected::Bool=true)",
+            "function clamp01(x::T) where {T<:AbstractFloat}",
+            "function describe(io::IO, xs)",
+        ]
+    );
+}
+
+#[test]
+fn test_julia_signatures_include_inner_constructors_and_return_types() {
+    let result = transform(PARSING, Language::Julia, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "function Token(kind, text, offset)",
+            "function classify(c::Char)",
+            "function tokenize(src::AbstractString)::Tokens",
+            "macro tok_str(s)",
+        ]
+    );
+}
+
+// ============================================================================
+// Types mode
+// ============================================================================
+
+#[test]
+fn test_julia_types_keeps_struct_and_abstract_definitions() {
+    let result = transform(GEOMETRY, Language::Julia, Mode::Types).unwrap();
+    assert!(result.contains("abstract type Shape end"), "got:\n{result}");
+    assert!(result.contains("struct Circle <: Shape"), "got:\n{result}");
+    assert!(result.contains("height::T"), "got:\n{result}");
+    assert!(!result.contains("function area"), "got:\n{result}");
+}
+
+#[test]
+fn test_julia_types_keep_primitive_abstract_and_exception_types() {
+    let result = transform(PARSING, Language::Julia, Mode::Types).unwrap();
+    assert!(
+        result.starts_with("primitive type Tag8 8 end\n\nabstract type AbstractToken end\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with("struct ParseError <: Exception\n    msg::String\n    offset::Int\nend"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("tokenize"), "got:\n{result}");
+}
+
+#[test]
+fn test_julia_types_empty_without_type_definitions() {
+    for source in [STATS, RUNTESTS] {
+        let result = transform(source, Language::Julia, Mode::Types).unwrap();
+        assert!(result.is_empty(), "got:\n{result}");
+    }
+}
+
+// ============================================================================
+// Minimal mode
+// ============================================================================
+
+#[test]
+fn test_julia_minimal_strips_comments_keeps_docstrings() {
+    let result = transform(GEOMETRY, Language::Julia, Mode::Minimal).unwrap();
+    assert!(!result.contains("# Geometry primitives"));
+    assert!(!result.contains("# πr²"));
+    assert!(result.contains("Supertype of every closed 2D shape."));
+    assert!(result.contains("return π * r^2"));
+}
+
+#[test]
+fn test_julia_minimal_strips_block_comments() {
+    let result = transform(STATS, Language::Julia, Mode::Minimal).unwrap();
+    assert!(
+        result.starts_with("const DEFAULT_TRIM = 0.1\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("#="), "got:\n{result}");
+    assert!(!result.contains("TODO"), "got:\n{result}");
+    assert!(
+        result.contains("Mean of `xs` after dropping the `trim` fraction from each tail."),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Full mode (passthrough)
+// ============================================================================
+
+#[test]
+fn test_julia_full_mode_passthrough() {
+    let result = transform(PARSING, Language::Julia, Mode::Full).unwrap();
+    assert_eq!(result, PARSING);
+}
+
+// ============================================================================
+// Outline and view modes
+// ============================================================================
+
+#[test]
+fn test_julia_outline_nests_definitions_under_modules() {
+    let result = transform(PARSING, Language::Julia, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "1: module Parsing
+  7: primitive type Tag8
+  9: abstract type AbstractToken
+  14: struct Token
+    19: function Token(...)
+  25: struct ParseError
+  30: Base.showerror(...)
+  34: module Lexer
+    37: isoperator(...)
+    39: function classify(...)
+  47: function tokenize(...)
+  57: macro tok_str(...)
+"
+    );
+}
+
+#[test]
+fn test_julia_outline_lists_one_line_definitions() {
+    let result = transform(STATS, Language::Julia, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "14: function trimmed_mean(...)\n22: function variance(...)\n\
+         29: @inline function clamp01(...)\n33: zscore(...)\n35: function describe(...)\n"
+    );
+}
+
+#[test]
+fn test_julia_call_graph_attributes_calls_to_definitions() {
+    let result = transform(GEOMETRY, Language::Julia, Mode::CallGraph).unwrap();
+    assert_eq!(
+        result,
+        "Geometry.perimeter -> zero\nGeometry.check -> esc\nGeometry.check -> error\n\
+         Geometry.scale -> Circle\n"
+    );
+    let result = transform(PARSING, Language::Julia, Mode::CallGraph).unwrap();
+    assert!(
+        result.contains("Parsing.tokenize -> Lexer.classify\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("Parsing.Base.showerror -> print\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("(top level)"), "got:\n{result}");
+}
+
+#[test]
+fn test_julia_call_graph_keeps_top_level_test_calls() {
+    let result = transform(RUNTESTS, Language::Julia, Mode::CallGraph).unwrap();
+    assert!(
+        result.contains("(top level) -> trimmed_mean\n"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("approx_all -> all\n"), "got:\n{result}");
+}
+
+#[test]
+fn test_julia_errors_list_throws_and_exception_types() {
+    let result = transform(PARSING, Language::Julia, Mode::Errors).unwrap();
+    assert_eq!(
+        result,
+        "20: offset >= 1 || throw(DomainError(offset, \"offsets are 1-based\")) (Parsing.Token.Token)
+25: struct ParseError <: Exception
+51: kind === :other && throw(ParseError(\"unexpected $(repr(c))\", i)) (Parsing.tokenize)
+"
+    );
+    let result = transform(STATS, Language::Julia, Mode::Errors).unwrap();
+    assert!(
+        result.contains("24: n > MAX_SAMPLES && error(\"too many samples: $n\") (variance)\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_julia_todos_and_comments() {
+    let result = transform(STATS, Language::Julia, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "21: TODO: switch to a streaming algorithm for large inputs (variance)\n"
+    );
+    let result = transform(PARSING, Language::Julia, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "36: FIXME: operators longer than one character (Parsing.Lexer.isoperator)\n"
+    );
+    let result = transform(STATS, Language::Julia, Mode::Comments).unwrap();
+    assert!(
+        result.starts_with("1:\n#=\nSummary statistics over numeric vectors.\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_julia_constants_keep_const_and_enum() {
+    let result = transform(STATS, Language::Julia, Mode::Constants).unwrap();
+    assert_eq!(
+        result,
+        "const DEFAULT_TRIM = 0.1\nconst MAX_SAMPLES = 1_000_000\n"
+    );
+    let result = transform(PARSING, Language::Julia, Mode::Constants).unwrap();
+    assert_eq!(
+        result,
+        "@enum TokenKind NUMBER IDENT OPERATOR\n\nconst Tokens = Vector{Token}\n"
+    );
+}
+
+#[test]
+fn test_julia_summary_counts_structs_as_classes() {
+    let result = transform(PARSING, Language::Julia, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 61, functions: 5, classes: 2, types: 2,"),
+        "got:\n{result}"
+    );
+    let result = transform(GEOMETRY, Language::Julia, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 54, functions: 4, classes: 2, types: 1,"),
+        "macros are not counted as functions, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_julia_all_fixtures_parse_cleanly() {
+    for source in [GEOMETRY, STATS, PARSING, RUNTESTS] {
+        let ast = rskim_core::dump_ast(source, Language::Julia, AstFormat::SExpression).unwrap();
+        assert!(!ast.contains("(ERROR"), "parse errors:\n{ast}");
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Full,
+            Mode::Minimal,
+            Mode::Outline,
+        ] {
+            let result = transform(source, Language::Julia, mode);
+            assert!(
+                result.is_ok(),
+                "Failed to transform in {mode:?} mode: {:?}",
+                result.err()
+            );
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//! Objective-C transformation tests — verify @interface preservation, @implementation stripping and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{AstFormat, Language, Mode, transform};
+
+const COUNTER: &str = include_str!("../../../tests/fixtures/objc/Counter.m");
+const CLIENT_H: &str = include_str!("../../../tests/fixtures/objc/NetworkClient.h");
+const CLIENT_M: &str = include_str!("../../../tests/fixtures/objc/NetworkClient.m");
+const SLUG_CATEGORY: &str = include_str!("../../../tests/fixtures/objc/NSString+Slug.m");
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_objc_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("m"), Some(Language::ObjectiveC));
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("Sources/Counter.mm")),
+        Some(Language::ObjectiveC)
+    );
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("Sources/Counter.h")),
+        Some(Language::C),
+        ".h stays C; Objective-C headers need --language objc"
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_objc_structure_strips_implementation_bodies() {
+    let result = transform(COUNTER, Language::ObjectiveC, Mode::Structure).unwrap();
+    assert!(result.contains("{...}"), "got:\n{result}");
+    assert!(!result.contains("[super init]"), "got:\n{result}");
+    assert!(
+        !result.contains("counterDidChange:_value"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("static Counter *shared"), "got:\n{result}");
+    assert!(
+        !result.contains("return value < 0"),
+        "C function body should be stripped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_objc_structure_keeps_interface_and_protocol() {
+    let result = transform(COUNTER, Language::ObjectiveC, Mode::Structure).unwrap();
+    for expected in [
+        "#import <Foundation/Foundation.h>",
+        "@protocol CounterDelegate <NSObject>",
+        "- (void)counterDidChange:(NSInteger)value;",
+        "@interface Counter : NSObject",
+        "@property (nonatomic, readonly) NSInteger value;",
+        "- (instancetype)initWithStep:(NSInteger)step;",
+        "+ (Counter *)sharedCounter;",
+        "@implementation Counter",
+        "- (void)incrementBy:(NSInteger)amount",
+        "@end",
+    ] {
+        assert!(
+            result.contains(expected),
+            "missing {expected:?} in:\n{result}"
+        );
+    }
+}
+
+#[test]
+fn test_objc_structure_keeps_header_verbatim() {
+    let result = transform(CLIENT_H, Language::ObjectiveC, Mode::Structure).unwrap();
+    assert_eq!(
+        result, CLIENT_H,
+        "a header has no bodies to strip and no comments outside docs"
+    );
+}
+
+#[test]
+fn test_objc_structure_keeps_class_extension_and_pragma_marks() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "@interface NetworkClient ()\n@property (nonatomic, strong) NSURLSession *session;\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("static const NSTimeInterval kDefaultTimeout = 30.0;\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("#pragma mark - Private\n\n- (NSURLRequest *)requestForPath:(NSString *)path method:(NSString *)method  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("completionHandler"), "got:\n{result}");
+    assert!(!result.contains("@throw"), "got:\n{result}");
+}
+
+#[test]
+fn test_objc_structure_strips_category_and_main_bodies() {
+    let result = transform(SLUG_CATEGORY, Language::ObjectiveC, Mode::Structure).unwrap();
+    assert!(
+        result.contains("@implementation NSString (Slug)\n\n/// Lowercase, hyphen-separated form suitable for URLs.\n- (NSString *)slugString  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("int main(int argc, const char *argv[])  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("@autoreleasepool"), "got:\n{result}");
+}
+
+// ============================================================================
+// Signatures mode
+// ============================================================================
+
+#[test]
+fn test_objc_signatures_list_methods() {
+    let result = transform(COUNTER, Language::ObjectiveC, Mode::Signatures).unwrap();
+    for expected in [
+        "- (void)counterDidChange:(NSInteger)value;",
+        "- (void)incrementBy:(NSInteger)amount",
+        "+ (Counter *)sharedCounter",
+        "static NSInteger clampValue(NSInteger value)",
+    ] {
+        assert!(
+            result.contains(expected),
+            "missing {expected:?} in:\n{result}"
+        );
+    }
+    assert!(!result.contains("[super init]"), "got:\n{result}");
+}
+
+#[test]
+fn test_objc_signatures_list_header_methods() {
+    let result = transform(CLIENT_H, Language::ObjectiveC, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "- (instancetype)initWithBaseURL:(NSURL *)baseURL NS_DESIGNATED_INITIALIZER;",
+            "- (instancetype)init NS_UNAVAILABLE;",
+            "- (void)getPath:(NSString *)path completion:(NetworkCompletion)completion;",
+            "- (void)postPath:(NSString *)path body:(NSDictionary *)body completion:(NetworkCompletion)completion;",
+        ]
+    );
+}
+
+#[test]
+fn test_objc_signatures_list_category_methods_and_c_functions() {
+    let result = transform(SLUG_CATEGORY, Language::ObjectiveC, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "- (NSString *)slugString;",
+            "+ (NSString *)slugFromComponents:(NSArray<NSString *> *)components;",
+            "- (NSString *)slugString",
+            "+ (NSString *)slugFromComponents:(NSArray<NSString *> *)components",
+            "int main(int argc, const char *argv[])",
+        ]
+    );
+}
+
+// ============================================================================
+// Types mode
+// ============================================================================
+
+#[test]
+fn test_objc_types_keep_interface_declarations() {
+    let result = transform(COUNTER, Language::ObjectiveC, Mode::Types).unwrap();
+    assert!(
+        result.contains("@protocol CounterDelegate <NSObject>"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("@interface Counter : NSObject"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("@property (nonatomic, weak) id<CounterDelegate> delegate;"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("[super init]"), "got:\n{result}");
+}
+
+#[test]
+fn test_objc_types_keep_typedefs_and_class_extension() {
+    let result = transform(CLIENT_H, Language::ObjectiveC, Mode::Types).unwrap();
+    assert!(
+        result.starts_with("typedef enum {\n    NetworkClientErrorTimeout = 1,\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("typedef void (^NetworkCompletion)(NSData *data, NSError *error);\n"),
+        "got:\n{result}"
+    );
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Types).unwrap();
+    assert_eq!(
+        result,
+        "@interface NetworkClient ()\n@property (nonatomic, strong) NSURLSession *session;\n\
+         - (NSURLRequest *)requestForPath:(NSString *)path method:(NSString *)method;\n@end"
+    );
+}
+
+// ============================================================================
+// Minimal mode
+// ============================================================================
+
+#[test]
+fn test_objc_minimal_keeps_doc_comments() {
+    let result = transform(COUNTER, Language::ObjectiveC, Mode::Minimal).unwrap();
+    assert!(result.contains("/// Receives counter change notifications."));
+    assert!(!result.contains("// Internal note"));
+}
+
+#[test]
+fn test_objc_minimal_keeps_body_comments() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Minimal).unwrap();
+    assert!(
+        result.contains("    // TODO: retry once on timeout.\n"),
+        "comment inside a method body should be kept, got:\n{result}"
+    );
+    let result = transform(SLUG_CATEGORY, Language::ObjectiveC, Mode::Minimal).unwrap();
+    assert!(!result.contains("FIXME"), "got:\n{result}");
+    assert!(
+        result.contains("/// Lowercase, hyphen-separated form suitable for URLs.\n"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Full mode (passthrough)
+// ============================================================================
+
+#[test]
+fn test_objc_full_mode_passthrough() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Full).unwrap();
+    assert_eq!(result, CLIENT_M);
+}
+
+// ============================================================================
+// Outline and view modes
+// ============================================================================
+
+#[test]
+fn test_objc_outline_nests_methods_under_interface_and_implementation() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "6: @interface NetworkClient\n  8: - (NSURLRequest *)requestForPath\n\
+         11: @implementation NetworkClient\n  13: - (instancetype)initWithBaseURL\n  \
+         23: - (instancetype)init\n  29: - (void)getPath\n  38: - (void)postPath\n  \
+         54: - (NSURLRequest *)requestForPath\n"
+    );
+}
+
+#[test]
+fn test_objc_outline_skips_properties_and_ivars() {
+    let result = transform(COUNTER, Language::ObjectiveC, Mode::Outline).unwrap();
+    assert!(
+        result.contains("11: @interface Counter\n  14: - (instancetype)initWithStep\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("28: @implementation Counter\n  32: - (instancetype)initWithStep\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("@property"), "got:\n{result}");
+    assert!(!result.contains("NSInteger\n"), "got:\n{result}");
+}
+
+#[test]
+fn test_objc_comments_attribute_docs_to_methods() {
+    let result = transform(CLIENT_H, Language::ObjectiveC, Mode::Comments).unwrap();
+    assert!(
+        result.contains("22: NetworkClient.getPath\n/// Fetch `path` relative to the base URL.\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("13: NetworkClient\n/// A minimal JSON-over-HTTP client.\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_objc_todos() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "31: TODO: retry once on timeout. (NetworkClient.getPath)\n"
+    );
+    let result = transform(SLUG_CATEGORY, Language::ObjectiveC, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "3: FIXME: transliterate non-Latin scripts instead of dropping them.\n"
+    );
+}
+
+#[test]
+fn test_objc_constants_keep_static_consts() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Constants).unwrap();
+    assert_eq!(
+        result,
+        "static const NSTimeInterval kDefaultTimeout = 30.0;\n"
+    );
+}
+
+#[test]
+fn test_objc_errors_list_throws_and_error_typedefs() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Errors).unwrap();
+    assert_eq!(
+        result,
+        "24: @throw [NSException exceptionWithName:NSInternalInconsistencyException (NetworkClient.init)\n"
+    );
+    let result = transform(CLIENT_H, Language::ObjectiveC, Mode::Errors).unwrap();
+    assert_eq!(
+        result, "6: typedef enum\n",
+        "a block typedef taking an NSError is not an error type"
+    );
+}
+
+#[test]
+fn test_objc_call_graph_uses_message_sends() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::CallGraph).unwrap();
+    assert!(
+        result.starts_with("NetworkClient.initWithBaseURL -> super.init\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("NetworkClient.getPath -> self.requestForPath\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("NetworkClient.requestForPath -> NSMutableURLRequest.requestWithURL\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_objc_summary_counts_interfaces_as_classes() {
+    let result = transform(CLIENT_M, Language::ObjectiveC, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 62, functions: 6, classes: 1, types: 0,"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_objc_fixtures_without_foundation_macros_parse_cleanly() {
+    // Counter.m uses NS_ENUM, which tree-sitter-objc does not expand.
+    for (name, source) in [
+        ("NetworkClient.h", CLIENT_H),
+        ("NetworkClient.m", CLIENT_M),
+        ("NSString+Slug.m", SLUG_CATEGORY),
+    ] {
+        let ast =
+            rskim_core::dump_ast(source, Language::ObjectiveC, AstFormat::SExpression).unwrap();
+        assert!(!ast.contains("(ERROR"), "{name} has parse errors:\n{ast}");
+    }
+    for source in [COUNTER, CLIENT_H, CLIENT_M, SLUG_CATEGORY] {
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Full,
+            Mode::Minimal,
+            Mode::Outline,
+        ] {
+            let result = transform(source, Language::ObjectiveC, mode);
+            assert!(
+                result.is_ok(),
+                "Failed to transform in {mode:?} mode: {:?}",
+                result.err()
+            );
+        }
+    }
+}
@@ -0,0 +1,492 @@
+//! OCaml transformation tests — verify `let` body stripping in `.ml` files
+//! and passthrough of `.mli` interface files across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const SHAPES_ML: &str = include_str!("../../../tests/fixtures/ocaml/shapes.ml");
+const SHAPES_MLI: &str = include_str!("../../../tests/fixtures/ocaml/shapes.mli");
+const HEAP_ML: &str = include_str!("../../../tests/fixtures/ocaml/heap.ml");
+const HEAP_MLI: &str = include_str!("../../../tests/fixtures/ocaml/heap.mli");
+const EXPR_ML: &str = include_str!("../../../tests/fixtures/ocaml/expr.ml");
+const SERVER_ML: &str = include_str!("../../../tests/fixtures/ocaml/server.ml");
+const BROKEN_ML: &str = include_str!("../../../tests/fixtures/ocaml/broken.ml");
+
+const FIXTURES: [(&str, &str); 4] = [
+    ("shapes.ml", SHAPES_ML),
+    ("heap.ml", HEAP_ML),
+    ("expr.ml", EXPR_ML),
+    ("server.ml", SERVER_ML),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Ocaml, &config)
+        .unwrap()
+        .1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_ocaml_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("ml"), Some(Language::Ocaml));
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("lib/shapes.mli")),
+        Some(Language::OcamlInterface)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_ocaml_structure_strips_function_bodies() {
+    let result = transform(SHAPES_ML, Language::Ocaml, Mode::Structure).unwrap();
+    // Bodies starting on the binding's line collapse in place; bodies on the
+    // next line keep their indentation.
+    assert!(result.contains("let area = {...}"), "got:\n{result}");
+    assert!(
+        result.contains("let rec perimeter shape =\n  {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("and describe shape =\n  {...}"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("let by factor = {...}"), "got:\n{result}");
+    assert!(!result.contains("corners are not rounded"));
+    assert!(!result.contains("abs_float"));
+    assert!(!result.contains("sprintf"));
+}
+
+#[test]
+fn test_ocaml_structure_keeps_types_modules_and_values() {
+    let result = transform(SHAPES_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert!(result.contains("type point = { x : float; y : float }"));
+    assert!(result.contains("  | Circle of point * float"));
+    assert!(result.contains("exception Degenerate of string"));
+    assert!(result.contains("module type SHAPE = sig"));
+    assert!(result.contains("module Scale = struct"));
+    // Plain values are not functions and are kept as written
+    assert!(result.contains("let pi = 4.0 *. atan 1.0"));
+}
+
+#[test]
+fn test_ocaml_structure_strips_functor_members() {
+    let result = transform(HEAP_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "(** Binary min-heap functor. *)
+
+module type ORDERED = sig
+  type t
+
+  val compare : t -> t -> int
+end
+
+module Make (Ord : ORDERED) = struct
+  type elt = Ord.t
+
+  type t = { mutable data : elt array; mutable size : int }
+
+  let create ?(capacity = 16) ~dummy () =
+    {...}
+
+  let is_empty heap = {...}
+
+  let swap heap i j =
+    {...}
+
+  let rec sift_up heap i =
+    {...}
+
+  let push heap x =
+    {...}
+end
+
+module IntHeap = Make (Int)
+"
+    );
+}
+
+#[test]
+fn test_ocaml_structure_strips_methods_and_entry_point() {
+    let result = transform(EXPR_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert!(
+        result.contains("let rec eval : type a. a expr -> a = {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("let ( >>= ) opt f = {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("  val mutable count = start\n"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("  method incr = {...}\n"), "got:\n{result}");
+    assert!(result.contains("  method get = {...}\n"), "got:\n{result}");
+    assert!(result.ends_with("let () =\n  {...}\n"), "got:\n{result}");
+    for body in ["Printf.printf", "self#get", "Buffer.create", "eval c"] {
+        assert!(!result.contains(body), "{body} leaked:\n{result}");
+    }
+}
+
+#[test]
+fn test_ocaml_structure_keeps_gadt_and_polymorphic_variant_types() {
+    let result = transform(EXPR_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert!(
+        result.contains("  | If : bool expr * 'a expr * 'a expr -> 'a expr\n[@@deriving show]"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("type token = [ `Num of int | `Plus | `Lparen | `Rparen ]"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_ocaml_structure_strips_binding_operator_bodies() {
+    let result = transform(SERVER_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert!(
+        result.contains("let handle_request (conn : Unix.file_descr) =\n  {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("let serve ~(config : Config.t) =\n  {...}"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("let max_body_size = 1_048_576"),
+        "got:\n{result}"
+    );
+    for body in ["let*", "let%lwt", "Lwt_main.run", "open_in path"] {
+        assert!(!result.contains(body), "{body} leaked:\n{result}");
+    }
+}
+
+#[test]
+fn test_ocaml_structure_keeps_signature_constrained_module() {
+    let result = transform(SERVER_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "module Config : sig\n  type t = { port : int; root : string }\n\n  \
+             val load : string -> t\nend = struct"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("  let load path =\n    {...}\nend"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_ocaml_crlf_line_endings() {
+    let crlf = HEAP_ML.replace('\n', "\r\n");
+    let result = transform(&crlf, Language::Ocaml, Mode::Structure).unwrap();
+    let expected = transform(HEAP_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert_eq!(result.replace("\r\n", "\n"), expected);
+    assert!(!has_errors(&crlf));
+}
+
+// ============================================================================
+// Signatures mode
+// ============================================================================
+
+#[test]
+fn test_ocaml_signatures_lists_function_bindings() {
+    let result = transform(SHAPES_ML, Language::Ocaml, Mode::Signatures).unwrap();
+    assert!(result.contains("let area"), "got:\n{result}");
+    assert!(result.contains("let rec perimeter shape"), "got:\n{result}");
+    assert!(result.contains("and describe shape"), "got:\n{result}");
+    assert!(result.contains("external hypot"), "got:\n{result}");
+    assert!(!result.contains("let pi"), "got:\n{result}");
+    assert!(!result.contains("match"), "got:\n{result}");
+}
+
+#[test]
+fn test_ocaml_signatures_include_methods_and_operators() {
+    let result = transform(EXPR_ML, Language::Ocaml, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "let rec eval : type a. a expr -> a
+let ( >>= ) opt f
+let tokenize (input : string) : token list
+method incr
+method get"
+    );
+}
+
+#[test]
+fn test_ocaml_signatures_skip_local_and_entry_point_bindings() {
+    let result = transform(SERVER_ML, Language::Ocaml, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "let load path
+let handle_request (conn : Unix.file_descr)
+let serve ~(config : Config.t)
+let main ()"
+    );
+}
+
+// ============================================================================
+// Types mode
+// ============================================================================
+
+#[test]
+fn test_ocaml_types_keeps_type_declarations() {
+    let result = transform(SHAPES_ML, Language::Ocaml, Mode::Types).unwrap();
+    assert!(result.contains("type point = { x : float; y : float }"));
+    assert!(result.contains("| Rect of point * point"));
+    assert!(result.contains("exception Degenerate of string"));
+    assert!(result.contains("module type SHAPE = sig"));
+    assert!(!result.contains("let area"), "got:\n{result}");
+}
+
+#[test]
+fn test_ocaml_types_keep_module_types_and_functor_types() {
+    let result = transform(HEAP_ML, Language::Ocaml, Mode::Types).unwrap();
+    assert_eq!(
+        result,
+        "module type ORDERED = sig
+  type t
+
+  val compare : t -> t -> int
+end
+
+type elt = Ord.t
+
+type t = { mutable data : elt array; mutable size : int }"
+    );
+}
+
+// ============================================================================
+// Minimal mode
+// ============================================================================
+
+#[test]
+fn test_ocaml_minimal_keeps_doc_comments() {
+    let result = transform(SHAPES_ML, Language::Ocaml, Mode::Minimal).unwrap();
+    assert!(!result.contains("(* Geometry helpers"));
+    assert!(result.contains("(** [area s] is the enclosed area of [s]. *)"));
+    assert!(result.contains("pi *. r *. r"));
+}
+
+#[test]
+fn test_ocaml_full_passthrough_and_pseudo_drops_comments() {
+    let full = transform(EXPR_ML, Language::Ocaml, Mode::Full).unwrap();
+    assert_eq!(full, EXPR_ML);
+    let pseudo = transform(EXPR_ML, Language::Ocaml, Mode::Pseudo).unwrap();
+    assert!(!pseudo.contains("Expression evaluator"), "got:\n{pseudo}");
+    assert!(pseudo.contains("if eval c then eval t else eval e"));
+}
+
+// ============================================================================
+// Outline / summary / call graph
+// ============================================================================
+
+#[test]
+fn test_ocaml_outline_nests_functor_members() {
+    let result = transform(HEAP_ML, Language::Ocaml, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "3: module type ORDERED
+  4: type t
+  6: val compare
+9: module Make
+  10: type elt
+  12: type t
+  14: let create(...)
+  17: let is_empty(...)
+  19: let swap(...)
+  24: let rec sift_up(...)
+  31: let push(...)
+39: module IntHeap
+"
+    );
+}
+
+#[test]
+fn test_ocaml_outline_lists_class_methods() {
+    let result = transform(EXPR_ML, Language::Ocaml, Mode::Outline).unwrap();
+    assert!(
+        result.contains("27: class counter(...)\n  30: method incr\n  32: method get\n"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("18: let ( >>= )(...)\n"), "got:\n{result}");
+    assert!(!result.contains("let ()"), "got:\n{result}");
+}
+
+#[test]
+fn test_ocaml_summary_counts_bindings() {
+    for (source, expected) in [
+        (
+            HEAP_ML,
+            "lines: 39, functions: 6, classes: 0, types: 4, tokens: ~",
+        ),
+        (
+            EXPR_ML,
+            "lines: 37, functions: 5, classes: 1, types: 2, tokens: ~",
+        ),
+        (
+            SERVER_ML,
+            "lines: 30, functions: 5, classes: 0, types: 2, tokens: ~",
+        ),
+    ] {
+        let result = transform(source, Language::Ocaml, Mode::Summary).unwrap();
+        assert!(result.starts_with(expected), "got:\n{result}");
+    }
+}
+
+#[test]
+fn test_ocaml_summary_counts_interface_vals() {
+    let result = transform(HEAP_MLI, Language::OcamlInterface, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 15, functions: 4, classes: 0, types: 3, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_ocaml_call_graph_qualifies_module_members() {
+    let result = transform(HEAP_ML, Language::Ocaml, Mode::CallGraph).unwrap();
+    assert_eq!(
+        result,
+        "Make.create -> Array.make
+Make.sift_up -> Ord.compare
+Make.sift_up -> swap
+Make.sift_up -> sift_up
+Make.push -> Array.length
+Make.push -> Array.append
+Make.push -> sift_up
+"
+    );
+}
+
+#[test]
+fn test_ocaml_call_graph_skips_constructors() {
+    let result = transform(EXPR_ML, Language::Ocaml, Mode::CallGraph).unwrap();
+    assert!(result.contains("eval -> eval\n"), "got:\n{result}");
+    assert!(
+        result.contains("(top level) -> Printf.printf\n"),
+        "got:\n{result}"
+    );
+    for constructor in ["-> If", "-> Bool", "-> Int", "-> Add"] {
+        assert!(!result.contains(constructor), "{constructor}:\n{result}");
+    }
+}
+
+// ============================================================================
+// Docs / comments / constants / errors
+// ============================================================================
+
+#[test]
+fn test_ocaml_docs_attach_ocamldoc_to_bindings() {
+    let result = transform(SHAPES_ML, Language::Ocaml, Mode::Docs).unwrap();
+    assert!(
+        result.starts_with("(** [area s] is the enclosed area of [s]. *)\nlet area\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        !result.contains("corners are not rounded"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_ocaml_comments_mode_lists_comments() {
+    let result = transform(EXPR_ML, Language::Ocaml, Mode::Comments).unwrap();
+    assert_eq!(
+        result.trim_end(),
+        "1:\n(* Expression evaluator with a GADT *)"
+    );
+}
+
+#[test]
+fn test_ocaml_constants_and_errors_modes() {
+    let constants = transform(SERVER_ML, Language::Ocaml, Mode::Constants).unwrap();
+    assert_eq!(constants.trim_end(), "let max_body_size = 1_048_576");
+    let errors = transform(SHAPES_ML, Language::Ocaml, Mode::Errors).unwrap();
+    assert!(
+        errors.contains("exception Degenerate of string"),
+        "got:\n{errors}"
+    );
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_ocaml_incomplete_match_is_a_parse_error() {
+    assert!(has_errors(BROKEN_ML));
+    let result = transform(BROKEN_ML, Language::Ocaml, Mode::Structure).unwrap();
+    assert!(result.starts_with("let ok x = {...}\n"), "got:\n{result}");
+    assert!(
+        result.trim_end().ends_with("let after = 3"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Interface files
+// ============================================================================
+
+#[test]
+fn test_ocaml_interface_passes_through_in_every_mode() {
+    for mode in [
+        Mode::Structure,
+        Mode::Signatures,
+        Mode::Types,
+        Mode::Full,
+        Mode::Minimal,
+        Mode::Pseudo,
+    ] {
+        let result = transform(SHAPES_MLI, Language::OcamlInterface, mode).unwrap();
+        assert_eq!(result, SHAPES_MLI, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_ocaml_interface_mode_caveats_and_passthrough() {
+    let result = transform(HEAP_MLI, Language::OcamlInterface, Mode::Outline).unwrap();
+    assert_eq!(result, HEAP_MLI);
+    assert!(
+        Language::OcamlInterface
+            .mode_caveat(Mode::Structure)
+            .is_none()
+    );
+    assert!(Language::Ocaml.mode_caveat(Mode::Structure).is_none());
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_ocaml_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Ocaml, Mode::Structure).unwrap();
+        assert!(result.len() < source.len(), "{name}:\n{result}");
+        assert!(result.contains("{...}"), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_ocaml_all_fixtures_signatures_have_no_bodies() {
+    for (name, source) in FIXTURES {
+        let result = transform(source, Language::Ocaml, Mode::Signatures).unwrap();
+        for line in result.lines() {
+            assert!(!line.trim_end().ends_with('='), "{name}: {line:?}");
+            assert!(!line.contains(" in "), "{name}: {line:?}");
+        }
+    }
+}
@@ -0,0 +1,303 @@
+//! reStructuredText transformation tests — verify section outlines across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const GUIDE_RST: &str = include_str!("../../../tests/fixtures/rst/guide.rst");
+const CHANGELOG_RST: &str = include_str!("../../../tests/fixtures/rst/changelog.rst");
+const API_RST: &str = include_str!("../../../tests/fixtures/rst/api.rst");
+const README_RST: &str = include_str!("../../../tests/fixtures/rst/README.rst");
+const BROKEN_RST: &str = include_str!("../../../tests/fixtures/rst/broken.rst");
+
+const FIXTURES: [(&str, &str); 4] = [
+    ("guide.rst", GUIDE_RST),
+    ("changelog.rst", CHANGELOG_RST),
+    ("api.rst", API_RST),
+    ("README.rst", README_RST),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Rst, &config)
+        .unwrap()
+        .1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_rst_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("rst"), Some(Language::Rst));
+    for path in ["docs/index.rst", "README.rst", "CHANGES.rst"] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Rst),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_rst_structure_keeps_top_sections() {
+    let result = transform(GUIDE_RST, Language::Rst, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "=============
+Acme Orders
+=============
+Installation
+============
+From source
+-----------
+Configuration
+=============
+Environment variables
+---------------------
+API
+===
+"
+    );
+}
+
+#[test]
+fn test_rst_structure_drops_directives_and_body_text() {
+    for (name, source) in FIXTURES {
+        let result = transform(source, Language::Rst, Mode::Structure).unwrap();
+        assert!(!result.contains(".. "), "{name}:\n{result}");
+        assert!(!result.contains("pip install"), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_rst_changelog_version_sections() {
+    let result = transform(CHANGELOG_RST, Language::Rst, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "=========
+Changelog
+=========
+2.1.0 (2024-03-01)
+------------------
+Added
+~~~~~
+Fixed
+~~~~~
+2.0.0 (2023-11-20)
+------------------
+Changed
+~~~~~~~
+"
+    );
+}
+
+#[test]
+fn test_rst_transition_is_not_a_title() {
+    assert!(CHANGELOG_RST.contains("\n----\n"));
+    let result = transform(CHANGELOG_RST, Language::Rst, Mode::Signatures).unwrap();
+    assert!(!result.contains("\n----\n"), "got:\n{result}");
+    assert!(!has_errors(CHANGELOG_RST));
+}
+
+#[test]
+fn test_rst_levels_follow_first_seen_adornment() {
+    // `#` overline, then `*`, then `=`: Options is level 3 and still kept.
+    let result = transform(README_RST, Language::Rst, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "##############
+ Acme Orders
+##############
+Quick start
+***********
+Options
+=======
+Contributing
+************
+"
+    );
+}
+
+#[test]
+fn test_rst_literal_block_and_table_borders_are_not_titles() {
+    let result = transform(README_RST, Language::Rst, Mode::Signatures).unwrap();
+    assert!(!result.contains("Output"), "got:\n{result}");
+    assert!(!result.contains("Option     Default"), "got:\n{result}");
+    assert!(!result.contains("=========  ========"), "got:\n{result}");
+}
+
+// ============================================================================
+// Signatures / types
+// ============================================================================
+
+#[test]
+fn test_rst_signatures_keep_all_sections_and_directives() {
+    for mode in [Mode::Signatures, Mode::Types] {
+        let result = transform(GUIDE_RST, Language::Rst, mode).unwrap();
+        assert!(result.contains("Logging\n~~~~~~~\n"), "{mode:?}");
+        assert!(result.contains(".. code-block:: console\n"), "{mode:?}");
+        assert!(result.contains(".. note::\n"), "{mode:?}");
+        assert!(
+            result.contains(".. automodule:: acme_orders.api\n"),
+            "{mode:?}"
+        );
+        for dropped in ["pip install", ".. _api:", ":members:", "Developer guide"] {
+            assert!(!result.contains(dropped), "{mode:?}: {dropped} kept");
+        }
+    }
+}
+
+#[test]
+fn test_rst_sphinx_domain_directives_keep_their_nesting() {
+    let result = transform(API_RST, Language::Rst, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "API reference
+=============
+.. module:: acme_orders.client
+.. py:class:: Client(base_url, *, timeout=10)
+   .. py:method:: get_order(order_id)
+   .. py:method:: list_orders(status=None)
+.. py:function:: connect(url)
+Models
+------
+.. autoclass:: acme_orders.models.Order
+.. toctree::
+"
+    );
+}
+
+#[test]
+fn test_rst_directive_options_and_field_lists_are_dropped() {
+    let result = transform(API_RST, Language::Rst, Mode::Signatures).unwrap();
+    for dropped in [
+        ":members:",
+        ":maxdepth:",
+        ":param order_id:",
+        "Fetch one order",
+    ] {
+        assert!(!result.contains(dropped), "{dropped} kept:\n{result}");
+    }
+}
+
+#[test]
+fn test_rst_substitutions_and_link_targets_are_not_directives() {
+    let result = transform(README_RST, Language::Rst, Mode::Signatures).unwrap();
+    assert!(!result.contains("|build|"), "got:\n{result}");
+    assert!(!result.contains("_the guide"), "got:\n{result}");
+    assert!(
+        result.ends_with(".. image:: docs/contributors.png\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_rst_signatures_and_types_agree() {
+    for (name, source) in FIXTURES {
+        let signatures = transform(source, Language::Rst, Mode::Signatures).unwrap();
+        let types = transform(source, Language::Rst, Mode::Types).unwrap();
+        assert_eq!(signatures, types, "{name}");
+    }
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_rst_mismatched_overlines_are_parse_errors() {
+    assert!(has_errors(BROKEN_RST));
+    let result = transform(BROKEN_RST, Language::Rst, Mode::Structure).unwrap();
+    assert_eq!(result, "Usage\n-----\nDetails\n~~~~~~~\n");
+}
+
+#[test]
+fn test_rst_broken_titles_are_not_emitted() {
+    let result = transform(BROKEN_RST, Language::Rst, Mode::Signatures).unwrap();
+    assert!(!result.contains("Broken overline"), "got:\n{result}");
+    assert!(!result.contains("Missing underline"), "got:\n{result}");
+}
+
+// ============================================================================
+// Passthrough modes
+// ============================================================================
+
+#[test]
+fn test_rst_full_minimal_and_pseudo_passthrough() {
+    for mode in [Mode::Full, Mode::Minimal, Mode::Pseudo] {
+        let result = transform(GUIDE_RST, Language::Rst, mode).unwrap();
+        assert_eq!(result, GUIDE_RST, "{mode:?}");
+    }
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_rst_outline_and_analysis_modes_fall_back_to_signatures() {
+    let signatures = transform(API_RST, Language::Rst, Mode::Signatures).unwrap();
+    for mode in [
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::CallGraph,
+        Mode::Docs,
+    ] {
+        let result = transform(API_RST, Language::Rst, mode).unwrap();
+        assert_eq!(result, signatures, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_rst_summary_reports_lines_and_tokens_only() {
+    let result = transform(API_RST, Language::Rst, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 34, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_rst_mode_caveats() {
+    for mode in [Mode::Outline, Mode::Comments, Mode::Minimal, Mode::Pseudo] {
+        assert!(Language::Rst.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    for mode in [Mode::Structure, Mode::Signatures] {
+        assert!(Language::Rst.mode_caveat(mode).is_none(), "{mode:?}");
+    }
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_rst_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Rst, Mode::Structure).unwrap();
+        assert!(!result.is_empty(), "{name}");
+        assert!(result.len() < source.len() / 2, "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_rst_all_fixtures_structure_is_a_subset_of_signatures() {
+    for (name, source) in FIXTURES {
+        let structure = transform(source, Language::Rst, Mode::Structure).unwrap();
+        let signatures = transform(source, Language::Rst, Mode::Signatures).unwrap();
+        let mut rest = signatures.lines();
+        for line in structure.lines() {
+            assert!(rest.any(|l| l == line), "{name}: {line:?} missing");
+        }
+    }
+}
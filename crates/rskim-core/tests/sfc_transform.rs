@@ -0,0 +1,556 @@
+//! Vue/Svelte SFC transformation tests — verify script extraction across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const USER_PROFILE_VUE: &str = include_str!("../../../tests/fixtures/vue/UserProfile.vue");
+const TODO_LIST_VUE: &str = include_str!("../../../tests/fixtures/vue/TodoList.vue");
+const DATA_TABLE_VUE: &str = include_str!("../../../tests/fixtures/vue/DataTable.vue");
+const APP_SHELL_VUE: &str = include_str!("../../../tests/fixtures/vue/AppShell.vue");
+const BROKEN_VUE: &str = include_str!("../../../tests/fixtures/vue/Broken.vue");
+const COUNTER_SVELTE: &str = include_str!("../../../tests/fixtures/svelte/Counter.svelte");
+const TODO_APP_SVELTE: &str = include_str!("../../../tests/fixtures/svelte/TodoApp.svelte");
+const PROFILE_SVELTE: &str = include_str!("../../../tests/fixtures/svelte/Profile.svelte");
+const LAYOUT_SVELTE: &str = include_str!("../../../tests/fixtures/svelte/Layout.svelte");
+const BROKEN_SVELTE: &str = include_str!("../../../tests/fixtures/svelte/Broken.svelte");
+
+const FIXTURES: [(&str, Language, &str); 8] = [
+    ("UserProfile.vue", Language::Vue, USER_PROFILE_VUE),
+    ("TodoList.vue", Language::Vue, TODO_LIST_VUE),
+    ("DataTable.vue", Language::Vue, DATA_TABLE_VUE),
+    ("AppShell.vue", Language::Vue, APP_SHELL_VUE),
+    ("Counter.svelte", Language::Svelte, COUNTER_SVELTE),
+    ("TodoApp.svelte", Language::Svelte, TODO_APP_SVELTE),
+    ("Profile.svelte", Language::Svelte, PROFILE_SVELTE),
+    ("Layout.svelte", Language::Svelte, LAYOUT_SVELTE),
+];
+
+fn has_errors(source: &str, language: Language) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, language, &config).unwrap().1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_sfc_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("vue"), Some(Language::Vue));
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("App.svelte")),
+        Some(Language::Svelte)
+    );
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("src/routes/+layout.svelte")),
+        Some(Language::Svelte)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_vue_structure_skims_script_setup() {
+    let result = transform(USER_PROFILE_VUE, Language::Vue, Mode::Structure).unwrap();
+    assert!(
+        result.contains("<script setup lang=\"ts\">"),
+        "script tag should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("function formatName(user: User): string"),
+        "function signature should be preserved, got:\n{result}"
+    );
+    assert!(
+        !result.contains("toUpperCase"),
+        "function body should be stripped, got:\n{result}"
+    );
+    assert!(
+        !result.contains("display: flex"),
+        "style block should be dropped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_vue_structure_emits_template_skeleton() {
+    let result = transform(USER_PROFILE_VUE, Language::Vue, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<template>\n  <section>\n    <UserAvatar/>\n    <h1>...</h1>\n    <router-link>...</router-link>\n    <UserAvatar/>\n  </section>\n</template>\n"
+        ),
+        "template should be reduced to its element skeleton, got:\n{result}"
+    );
+    assert!(
+        !result.contains("user.name }}"),
+        "template text should be elided, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_vue_signatures_lists_template_components() {
+    let result = transform(USER_PROFILE_VUE, Language::Vue, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("<!-- components: UserAvatar, router-link -->"),
+        "template components should be listed once, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_svelte_structure_skims_script_and_components() {
+    let result = transform(COUNTER_SVELTE, Language::Svelte, Mode::Structure).unwrap();
+    assert!(
+        result.contains("function increment()"),
+        "function signature should be preserved, got:\n{result}"
+    );
+    assert!(
+        !result.contains("count += 1"),
+        "function body should be stripped, got:\n{result}"
+    );
+    assert!(
+        result.contains("<!-- components: Button, Icons.Plus -->"),
+        "markup components should be listed, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_vue_options_api_skeleton_and_methods() {
+    let result = transform(TODO_LIST_VUE, Language::Vue, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<template>
+  <div>
+    <TodoForm/>
+    <p v-if=\"items.length === 0\">...</p>
+    <ul v-else>
+      <li v-for=\"item in items\">
+        <input/>
+        <span>...</span>
+        <button>...</button>
+      </li>
+    </ul>
+    <footer>...</footer>
+  </div>
+</template>
+<script>
+"
+        ),
+        "got:\n{result}"
+    );
+    for kept in [
+        "  data()  {...},",
+        "    add(title)  {...},",
+        "    remove(item)  {...},",
+    ] {
+        assert!(result.contains(kept), "{kept} missing:\n{result}");
+    }
+    assert!(!result.contains("Date.now"), "got:\n{result}");
+}
+
+#[test]
+fn test_vue_mustache_comparisons_and_entities_are_text() {
+    assert!(TODO_LIST_VUE.contains("{{ remaining < 5"));
+    let result = transform(TODO_LIST_VUE, Language::Vue, Mode::Structure).unwrap();
+    assert!(
+        result.contains("    <footer>...</footer>\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("Almost done"), "got:\n{result}");
+    assert!(!result.contains("&lt;"), "got:\n{result}");
+    assert!(!has_errors(TODO_LIST_VUE, Language::Vue));
+}
+
+#[test]
+fn test_vue_skeleton_drops_bindings_and_events() {
+    let result = transform(DATA_TABLE_VUE, Language::Vue, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<template>
+  <table>
+    <thead>
+      <tr>
+        <th v-for=\"column in columns\">...</th>
+      </tr>
+    </thead>
+    <tbody>
+      <tr v-for=\"row in sorted\">
+        <td v-for=\"column in columns\">
+          <slot>...</slot>
+        </td>
+      </tr>
+    </tbody>
+  </table>
+</template>
+"
+        ),
+        "template should come first even when the scripts precede it, got:\n{result}"
+    );
+    for dropped in [":key", "@click", "sortKey = column.key", "cell-"] {
+        assert!(!result.contains(dropped), "{dropped} kept:\n{result}");
+    }
+}
+
+#[test]
+fn test_vue_two_script_blocks_keep_their_tags() {
+    let result = transform(DATA_TABLE_VUE, Language::Vue, Mode::Structure).unwrap();
+    assert!(
+        result.contains("</template>\n<script lang=\"ts\">\nexport interface Column<T> {\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("<script setup lang=\"ts\" generic=\"T extends { id: string }\">\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with("function select(row: T): void  {...}\n</script>\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_vue_custom_blocks_and_scss_styles_are_dropped() {
+    for mode in [Mode::Structure, Mode::Signatures, Mode::Types] {
+        let result = transform(DATA_TABLE_VUE, Language::Vue, mode).unwrap();
+        for dropped in ["i18n", "No rows", "cursor: pointer", "<style"] {
+            assert!(!result.contains(dropped), "{mode:?}: {dropped} kept");
+        }
+    }
+}
+
+#[test]
+fn test_vue_named_slots_and_built_in_components() {
+    let result = transform(APP_SHELL_VUE, Language::Vue, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<template>
+  <AppLayout>
+    <template #header>
+      <NavBar/>
+    </template>
+    <template #default=\"{ user }\">
+      <Suspense>
+        <router-view/>
+        <template #fallback>
+          <LoadingSpinner/>
+        </template>
+      </Suspense>
+    </template>
+    <template v-slot:footer>
+      <small>...</small>
+    </template>
+  </AppLayout>
+  <Teleport>
+    <ToastList/>
+  </Teleport>
+</template>
+"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_vue_commented_out_component_is_not_listed() {
+    let result = transform(APP_SHELL_VUE, Language::Vue, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "<script setup>
+async function logout()
+</script>
+<!-- components: AppLayout, NavBar, Suspense, router-view, LoadingSpinner, Teleport, ToastList -->
+"
+    );
+}
+
+// ============================================================================
+// Svelte
+// ============================================================================
+
+#[test]
+fn test_svelte_module_and_instance_scripts_are_dedented() {
+    let result = transform(TODO_APP_SVELTE, Language::Svelte, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<script context=\"module\" lang=\"ts\">
+export interface Todo {
+  id: number;
+  title: string;
+  done: boolean;
+}
+
+export const MAX_TODOS = 50;
+</script>
+<script lang=\"ts\">
+import TodoItem from \"./TodoItem.svelte\";
+"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with(
+            "function toggle(id: number): void  {...}\n</script>\n<!-- components: TodoItem -->\n"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_svelte_logic_blocks_are_not_components() {
+    let result = transform(TODO_APP_SVELTE, Language::Svelte, Mode::Signatures).unwrap();
+    assert!(
+        result.ends_with("<!-- components: TodoItem -->\n"),
+        "got:\n{result}"
+    );
+    for dropped in ["{#each", "MAX_TODOS -", "Nothing to do", "max-width"] {
+        assert!(!result.contains(dropped), "{dropped} kept:\n{result}");
+    }
+}
+
+#[test]
+fn test_svelte_types_keep_module_interface() {
+    let result = transform(TODO_APP_SVELTE, Language::Svelte, Mode::Types).unwrap();
+    assert!(
+        result.starts_with(
+            "<script context=\"module\" lang=\"ts\">
+interface Todo {
+  id: number;
+  title: string;
+  done: boolean;
+}
+</script>
+"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_svelte_runes_and_snippets() {
+    let result = transform(PROFILE_SVELTE, Language::Svelte, Mode::Structure).unwrap();
+    for kept in [
+        "let editing = $state(false);\n",
+        "const initials = $derived(",
+        "function save()  {...}\n",
+    ] {
+        assert!(result.contains(kept), "{kept} missing:\n{result}");
+    }
+    assert!(
+        result.ends_with("</script>\n<!-- components: Avatar -->\n"),
+        "svelte:window and snippets are not components, got:\n{result}"
+    );
+    assert!(!result.contains("document.title"), "got:\n{result}");
+}
+
+#[test]
+fn test_svelte_outline_line_numbers_count_from_the_file() {
+    let result = transform(PROFILE_SVELTE, Language::Svelte, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "<script lang=\"ts\">\n15: function save()\n</script>\n<!-- components: Avatar -->\n"
+    );
+}
+
+#[test]
+fn test_svelte_sveltekit_layout_with_module_load() {
+    let result = transform(LAYOUT_SVELTE, Language::Svelte, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "<script context=\"module\">
+async function load({ fetch })
+</script>
+<script>
+// no signatures found (5 top-level statements)
+</script>
+<!-- components: Header, Footer -->
+"
+    );
+}
+
+// ============================================================================
+// Outline mode
+// ============================================================================
+
+#[test]
+fn test_vue_outline_line_numbers_count_from_the_file() {
+    let result = transform(USER_PROFILE_VUE, Language::Vue, Mode::Outline).unwrap();
+    assert!(
+        result.contains("\n22: function formatName(...)\n"),
+        "outline lines should point into the .vue file, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Comments mode
+// ============================================================================
+
+#[test]
+fn test_vue_comments_line_numbers_count_from_the_file() {
+    let source = "<template>\n  <p>hi</p>\n</template>\n\n<script>\n\
+                  // Bump the counter.\nfunction increment() {}\n</script>\n";
+    let result = transform(source, Language::Vue, Mode::Comments).unwrap();
+    assert!(
+        result.contains("<script>\n6: increment\n// Bump the counter.\n</script>\n"),
+        "comment headers should point into the .vue file, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Types / Full modes
+// ============================================================================
+
+#[test]
+fn test_vue_types_keeps_interface() {
+    let result = transform(USER_PROFILE_VUE, Language::Vue, Mode::Types).unwrap();
+    assert!(
+        result.contains("interface User"),
+        "interface should appear in types mode, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_sfc_full_mode_is_passthrough() {
+    let result = transform(USER_PROFILE_VUE, Language::Vue, Mode::Full).unwrap();
+    assert_eq!(result, USER_PROFILE_VUE);
+}
+
+#[test]
+fn test_sfc_constants_mode_keeps_script_constants() {
+    let result = transform(APP_SHELL_VUE, Language::Vue, Mode::Constants).unwrap();
+    assert!(
+        result.starts_with("<script setup>\nconst links = [\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("logout"), "got:\n{result}");
+}
+
+// ============================================================================
+// Minimal / Pseudo modes
+// ============================================================================
+
+#[test]
+fn test_sfc_minimal_keeps_script_tags_on_their_own_lines() {
+    let result = transform(TODO_APP_SVELTE, Language::Svelte, Mode::Minimal).unwrap();
+    assert!(
+        result.starts_with("<script context=\"module\" lang=\"ts\">\n  export interface Todo {\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("<script lang=\"ts\">\n  import TodoItem"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("{#each todos as todo (todo.id)}"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_sfc_minimal_strips_script_comments_only() {
+    let result = transform(LAYOUT_SVELTE, Language::Svelte, Mode::Minimal).unwrap();
+    assert!(!result.contains("Highlight the active"), "got:\n{result}");
+    assert!(
+        result.contains("  export let session;\n\n  $: active = $page.url.pathname;\n</script>\n"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("<style global>"), "got:\n{result}");
+}
+
+#[test]
+fn test_sfc_pseudo_strips_types_in_place() {
+    let result = transform(DATA_TABLE_VUE, Language::Vue, Mode::Pseudo).unwrap();
+    assert!(result.contains("function select(row) {"), "got:\n{result}");
+    assert!(
+        result.contains("<th v-for=\"column in columns\""),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_vue_unclosed_script_setup_is_a_parse_error() {
+    assert!(has_errors(BROKEN_VUE, Language::Vue));
+    let result = transform(BROKEN_VUE, Language::Vue, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "<script setup lang=\"ts\">\nfunction ok(): void\n</script>\n<!-- components: Child -->\n"
+    );
+}
+
+#[test]
+fn test_svelte_unclosed_script_swallows_the_markup() {
+    assert!(has_errors(BROKEN_SVELTE, Language::Svelte));
+    let result = transform(BROKEN_SVELTE, Language::Svelte, Mode::Signatures).unwrap();
+    assert_eq!(
+        result,
+        "<script lang=\"ts\">\nfunction first(): string\n</script>\n"
+    );
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_sfc_summary_counts_script_symbols() {
+    let result = transform(TODO_APP_SVELTE, Language::Svelte, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 46, functions: 1, classes: 0, types: 1, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_sfc_has_no_mode_caveats() {
+    for language in [Language::Vue, Language::Svelte] {
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Outline,
+            Mode::Comments,
+            Mode::Minimal,
+            Mode::Pseudo,
+        ] {
+            assert!(
+                language.mode_caveat(mode).is_none(),
+                "{language:?} {mode:?}"
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_sfc_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, language, source) in FIXTURES {
+        assert!(!has_errors(source, language), "{name} has parse errors");
+        let result = transform(source, language, Mode::Structure).unwrap();
+        assert!(result.len() < source.len(), "{name}:\n{result}");
+        assert!(!result.contains("<style"), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_sfc_all_fixtures_keep_every_script_tag() {
+    for (name, language, source) in FIXTURES {
+        let tags = source.matches("<script").count();
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Outline,
+        ] {
+            let result = transform(source, language, mode).unwrap();
+            assert_eq!(result.matches("<script").count(), tags, "{name} {mode:?}");
+            assert_eq!(result.matches("</script>").count(), tags, "{name} {mode:?}");
+        }
+    }
+}
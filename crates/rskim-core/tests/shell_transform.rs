@@ -0,0 +1,336 @@
+//! Shell transformation tests — verify function stripping, block collapsing and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{AstFormat, Language, Mode, transform};
+
+const DEPLOY_SH: &str = include_str!("../../../tests/fixtures/shell/deploy.sh");
+const LIB_SH: &str = include_str!("../../../tests/fixtures/shell/lib.sh");
+const BACKUP_BASH: &str = include_str!("../../../tests/fixtures/shell/backup.bash");
+const SETUP_ZSH: &str = include_str!("../../../tests/fixtures/shell/setup.zsh");
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_shell_language_detection() {
+    use std::path::Path;
+    for ext in ["sh", "bash", "zsh"] {
+        assert_eq!(rskim_core::detect_language(ext), Some(Language::Shell));
+    }
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("scripts/deploy.sh")),
+        Some(Language::Shell)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_shell_structure_strips_function_bodies() {
+    let result = transform(DEPLOY_SH, Language::Shell, Mode::Structure).unwrap();
+    assert_eq!(
+        result.matches("{...}").count(),
+        3,
+        "each function body should be replaced, got:\n{result}"
+    );
+    assert!(result.contains("log()"), "got:\n{result}");
+    assert!(result.contains("function build_image"), "got:\n{result}");
+    assert!(result.contains("push_image()"), "got:\n{result}");
+    assert!(
+        !result.contains("docker build"),
+        "function body should be stripped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_shell_structure_collapses_long_blocks() {
+    let result = transform(DEPLOY_SH, Language::Shell, Mode::Structure).unwrap();
+    assert!(
+        result.contains("case \"$ENVIRONMENT\" in ... esac"),
+        "long case should collapse, got:\n{result}"
+    );
+    assert!(
+        result.contains("if [ \"$ENVIRONMENT\" = \"production\" ]; then ... fi"),
+        "long if should collapse, got:\n{result}"
+    );
+    assert!(!result.contains("REPLICAS=3"), "got:\n{result}");
+    assert!(!result.contains("read -r -p"), "got:\n{result}");
+}
+
+#[test]
+fn test_shell_structure_keeps_short_blocks_and_top_level() {
+    let result = transform(DEPLOY_SH, Language::Shell, Mode::Structure).unwrap();
+    assert!(
+        result.contains("if [ ! -f Dockerfile ]; then\n    log error \"no Dockerfile\"\nfi"),
+        "short if should be kept, got:\n{result}"
+    );
+    assert!(result.starts_with("#!/usr/bin/env bash\n"));
+    assert!(result.contains("set -euo pipefail"));
+    assert!(result.contains("kubectl scale deployment"));
+}
+
+#[test]
+fn test_shell_structure_strips_subshell_bodies() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Structure).unwrap();
+    assert!(
+        result.contains("# Run in a subshell so the cd does not leak.\nin_dir()  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("cd \"$1\" || exit 1"), "got:\n{result}");
+}
+
+#[test]
+fn test_shell_structure_keeps_sourcing_and_doc_comments() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Structure).unwrap();
+    assert!(
+        result.contains(". \"$(dirname \"$0\")/env.sh\""),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("# Usage: die MESSAGE [CODE]\ndie()  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("readonly LOG_DIR=\"/var/log/orders\"\nexport RETRIES=3\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("until \"$@\""), "got:\n{result}");
+}
+
+#[test]
+fn test_shell_structure_keeps_top_level_loops_and_heredocs() {
+    let result = transform(BACKUP_BASH, Language::Shell, Mode::Structure).unwrap();
+    assert!(
+        result.contains("cleanup()  {...}\ntrap cleanup EXIT\n"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("archive()  {...}"), "got:\n{result}");
+    assert!(!result.contains("tar -czf"), "got:\n{result}");
+    assert!(
+        result.contains("done < <(find \"$DEST\" -name '*.tar.gz' -mtime +30)"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("cat > \"$DEST/README\" <<NOTE\nBackups of ${SOURCES[*]}, taken $STAMP.\n"),
+        "heredoc bodies are data and stay intact, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_shell_structure_zsh_function_keyword_forms() {
+    let result = transform(SETUP_ZSH, Language::Shell, Mode::Structure).unwrap();
+    assert!(
+        result.contains("function install_tools  {...}\n\nfunction configure_git()  {...}\n\nlink_dotfiles()  {...}\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("install_tools && configure_git && link_dotfiles\necho \"done\"\n"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Signatures and types modes
+// ============================================================================
+
+#[test]
+fn test_shell_signatures_lists_functions() {
+    let result = transform(DEPLOY_SH, Language::Shell, Mode::Signatures).unwrap();
+    assert!(result.contains("log()"), "got:\n{result}");
+    assert!(result.contains("function build_image"), "got:\n{result}");
+    assert!(result.contains("push_image()"), "got:\n{result}");
+    assert!(
+        !result.contains("kubectl"),
+        "top-level commands are not signatures, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_shell_signatures_subshell_function_has_no_body() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        ["die()", "retry()", "in_dir()", "require()"]
+    );
+}
+
+#[test]
+fn test_shell_signatures_keep_function_keyword() {
+    let result = transform(SETUP_ZSH, Language::Shell, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "function install_tools",
+            "function configure_git()",
+            "link_dotfiles()"
+        ]
+    );
+}
+
+#[test]
+fn test_shell_types_mode_has_caveat() {
+    assert!(Language::Shell.mode_caveat(Mode::Types).is_some());
+    assert!(Language::Shell.mode_caveat(Mode::Signatures).is_none());
+}
+
+// ============================================================================
+// Minimal mode
+// ============================================================================
+
+#[test]
+fn test_shell_minimal_keeps_shebang() {
+    let result = transform(DEPLOY_SH, Language::Shell, Mode::Minimal).unwrap();
+    assert!(
+        result.starts_with("#!/usr/bin/env bash\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        !result.contains("# Deploy the service"),
+        "non-doc comments should be stripped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_shell_minimal_keeps_body_comments() {
+    let result = transform(SETUP_ZSH, Language::Shell, Mode::Minimal).unwrap();
+    assert!(
+        result.contains("    # HACK: overwrite existing links without asking.\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("# TODO: detect"), "got:\n{result}");
+    let result = transform(LIB_SH, Language::Shell, Mode::Minimal).unwrap();
+    assert!(!result.contains("# Retry a command"), "got:\n{result}");
+    assert!(result.contains("        sleep \"$n\"\n"), "got:\n{result}");
+}
+
+// ============================================================================
+// Full mode (passthrough)
+// ============================================================================
+
+#[test]
+fn test_shell_full_mode_passthrough() {
+    let result = transform(BACKUP_BASH, Language::Shell, Mode::Full).unwrap();
+    assert_eq!(result, BACKUP_BASH);
+}
+
+// ============================================================================
+// Outline and view modes
+// ============================================================================
+
+#[test]
+fn test_shell_outline_lists_functions() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Outline).unwrap();
+    assert_eq!(result, "13: die\n19: retry\n31: in_dir\n37: require\n");
+    let result = transform(SETUP_ZSH, Language::Shell, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "7: function install_tools\n14: function configure_git\n19: link_dotfiles\n"
+    );
+}
+
+#[test]
+fn test_shell_comments_attribute_function_docs() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Comments).unwrap();
+    assert!(
+        result.contains(
+            "11: die\n# Print a message to stderr and exit.\n# Usage: die MESSAGE [CODE]\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("30: in_dir\n# Run in a subshell so the cd does not leak.\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_shell_todos() {
+    let result = transform(SETUP_ZSH, Language::Shell, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "4: TODO: detect the package manager instead of assuming brew.\n\
+         5: FIXME: fails when Xcode tools are missing.\n\
+         20: HACK: overwrite existing links without asking. (link_dotfiles)\n"
+    );
+}
+
+#[test]
+fn test_shell_constants_keep_top_level_assignments() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Constants).unwrap();
+    assert_eq!(
+        result,
+        "readonly LOG_DIR=\"/var/log/orders\"\nexport RETRIES=3\nDEFAULT_TIMEOUT=30\n"
+    );
+    let result = transform(BACKUP_BASH, Language::Shell, Mode::Constants).unwrap();
+    assert!(
+        result.starts_with("SOURCES=(/etc /home /srv)\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("local src"), "got:\n{result}");
+}
+
+#[test]
+fn test_shell_errors_list_failing_exits() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Errors).unwrap();
+    assert_eq!(
+        result,
+        "15: exit \"${2:-1}\" (die)\n32: cd \"$1\" || exit 1 (in_dir)\n"
+    );
+    let result = transform(SETUP_ZSH, Language::Shell, Mode::Errors).unwrap();
+    assert_eq!(result, "", "exit 0 and return are not errors");
+}
+
+#[test]
+fn test_shell_call_graph_skips_dynamic_commands() {
+    let result = transform(LIB_SH, Language::Shell, Mode::CallGraph).unwrap();
+    assert!(result.contains("retry -> die\n"), "got:\n{result}");
+    assert!(result.contains("in_dir -> cd\n"), "got:\n{result}");
+    assert!(
+        !result.contains("-> $"),
+        "\"$@\" is not a named command, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_shell_summary_counts_functions() {
+    let result = transform(LIB_SH, Language::Shell, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 39, functions: 4, classes: 0, types: 0,"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_shell_all_fixtures_parse_cleanly() {
+    for (name, source) in [
+        ("deploy.sh", DEPLOY_SH),
+        ("lib.sh", LIB_SH),
+        ("backup.bash", BACKUP_BASH),
+        ("setup.zsh", SETUP_ZSH),
+    ] {
+        let ast = rskim_core::dump_ast(source, Language::Shell, AstFormat::SExpression).unwrap();
+        assert!(!ast.contains("(ERROR"), "{name} has parse errors:\n{ast}");
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Full,
+            Mode::Minimal,
+            Mode::Outline,
+        ] {
+            let result = transform(source, Language::Shell, mode);
+            assert!(
+                result.is_ok(),
+                "Failed to transform {name} in {mode:?} mode: {:?}",
+                result.err()
+            );
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! Solidity transformation tests — verify contract structure, modifier handling and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{AstFormat, Language, Mode, transform};
+
+const VAULT: &str = include_str!("../../../tests/fixtures/solidity/vault.sol");
+const MATH: &str = include_str!("../../../tests/fixtures/solidity/math.sol");
+const TOKEN: &str = include_str!("../../../tests/fixtures/solidity/token.sol");
+const GOVERNOR: &str = include_str!("../../../tests/fixtures/solidity/governor.sol");
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_solidity_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("sol"), Some(Language::Solidity));
+    assert_eq!(
+        rskim_core::detect_language_from_path(Path::new("contracts/Vault.sol")),
+        Some(Language::Solidity)
+    );
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_solidity_structure_strips_function_bodies() {
+    let result = transform(VAULT, Language::Solidity, Mode::Structure).unwrap();
+    assert!(result.contains("{...}"), "got:\n{result}");
+    assert!(!result.contains("transferFrom"), "got:\n{result}");
+    assert!(!result.contains("return positions"), "got:\n{result}");
+    assert!(
+        !result.contains("token = token_;"),
+        "constructor body should be stripped, got:\n{result}"
+    );
+    assert!(
+        !result.contains("no ether"),
+        "receive body should be stripped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_solidity_structure_keeps_declarations() {
+    let result = transform(VAULT, Language::Solidity, Mode::Structure).unwrap();
+    for expected in [
+        "pragma solidity ^0.8.20;",
+        "interface IVault {",
+        "function balanceOf(address account) external view returns (uint256);",
+        "contract Vault is IVault {",
+        "struct Position {",
+        "enum Status { Open, Paused }",
+        "event Deposited(address indexed account, uint256 amount);",
+        "error NotOwner(address caller);",
+        "mapping(address => Position) private positions;",
+        "function setOwner(address next) external onlyOwner",
+    ] {
+        assert!(
+            result.contains(expected),
+            "missing {expected:?} in:\n{result}"
+        );
+    }
+}
+
+#[test]
+fn test_solidity_structure_keeps_modifier_bodies() {
+    let result = transform(VAULT, Language::Solidity, Mode::Structure).unwrap();
+    assert!(
+        result.contains("if (msg.sender != owner) revert NotOwner(msg.sender);"),
+        "modifier checks should be kept, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_solidity_structure_keeps_library_constants_and_errors() {
+    let result = transform(MATH, Language::Solidity, Mode::Structure).unwrap();
+    assert!(
+        result.contains("library FixedMath {\n    uint256 internal constant WAD = 1e18;\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("    error DivisionByZero();\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "    function divWad(uint256 a, uint256 b) internal pure returns (uint256)  {...}\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("HALF_WAD) / WAD"), "got:\n{result}");
+}
+
+#[test]
+fn test_solidity_structure_strips_fallback_and_keeps_mappings() {
+    let result = transform(TOKEN, Language::Solidity, Mode::Structure).unwrap();
+    assert!(
+        result.contains("    mapping(address => mapping(address => uint256)) public allowance;\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("    using FixedMath for uint256;\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("    fallback() external  {...}\n}"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("unchecked"), "got:\n{result}");
+}
+
+#[test]
+fn test_solidity_structure_keeps_parameterized_modifier_bodies() {
+    let result = transform(GOVERNOR, Language::Solidity, Mode::Structure).unwrap();
+    assert!(
+        result.contains(
+            "    modifier inState(uint256 id, State expected) {\n        require(proposals[id].state == expected, \"wrong state\");\n        _;\n    }"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "    function execute(uint256 id) external inState(id, State.Active)  {...}\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("abstract contract Ownable {"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Signatures mode
+// ============================================================================
+
+#[test]
+fn test_solidity_signatures_include_modifiers_and_events() {
+    let result = transform(VAULT, Language::Solidity, Mode::Signatures).unwrap();
+    for expected in [
+        "function deposit(uint256 amount) external",
+        "function setOwner(address next) external onlyOwner",
+        "constructor(IERC20 token_)",
+        "modifier onlyOwner()",
+        "event Deposited(address indexed account, uint256 amount)",
+        "error NotOwner(address caller)",
+    ] {
+        assert!(
+            result.contains(expected),
+            "missing {expected:?} in:\n{result}"
+        );
+    }
+    assert!(!result.contains("msg.sender != owner"), "got:\n{result}");
+}
+
+#[test]
+fn test_solidity_signatures_list_token_surface() {
+    let result = transform(TOKEN, Language::Solidity, Mode::Signatures).unwrap();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        [
+            "event Transfer(address indexed from, address indexed to, uint256 value);",
+            "event FeeChanged(uint256 previous, uint256 current);",
+            "error InsufficientBalance(uint256 available, uint256 required);",
+            "constructor(address treasury_, uint256 supply)",
+            "function transfer(address to, uint256 value) external returns (bool)",
+            "function approve(address spender, uint256 value) external returns (bool)",
+            "function setFee(uint256 next) external",
+            "fallback() external",
+        ]
+    );
+}
+
+#[test]
+fn test_solidity_signatures_keep_override_modifiers() {
+    let result = transform(GOVERNOR, Language::Solidity, Mode::Signatures).unwrap();
+    assert!(
+        result.contains("function transferOwnership(address next) public virtual onlyOwner\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with("function transferOwnership(address next) public override onlyOwner"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("modifier inState(uint256 id, State expected)\n"),
+        "got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Types mode
+// ============================================================================
+
+#[test]
+fn test_solidity_types_keep_contract_state() {
+    let result = transform(VAULT, Language::Solidity, Mode::Types).unwrap();
+    assert!(result.contains("interface IVault"), "got:\n{result}");
+    assert!(
+        result.contains("contract Vault is IVault"),
+        "got:\n{result}"
+    );
+    assert!(result.contains("struct Position"), "got:\n{result}");
+    assert!(result.contains("address public owner;"), "got:\n{result}");
+    assert!(result.contains("event Deposited"), "got:\n{result}");
+    assert!(!result.contains("transferFrom"), "got:\n{result}");
+}
+
+#[test]
+fn test_solidity_types_keep_library_and_abstract_contract() {
+    let result = transform(MATH, Language::Solidity, Mode::Types).unwrap();
+    assert_eq!(
+        result,
+        "library FixedMath {\n    uint256 internal constant WAD = 1e18;\n    \
+         uint256 internal constant HALF_WAD = WAD / 2;\n    error DivisionByZero();\n}"
+    );
+    let result = transform(GOVERNOR, Language::Solidity, Mode::Types).unwrap();
+    assert!(
+        result.contains("abstract contract Ownable {\n    address public owner;\n}"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("modifier"), "got:\n{result}");
+}
+
+// ============================================================================
+// Minimal mode
+// ============================================================================
+
+#[test]
+fn test_solidity_minimal_keeps_natspec() {
+    let result = transform(VAULT, Language::Solidity, Mode::Minimal).unwrap();
+    assert!(result.contains("/// @title Minimal token vault"));
+    assert!(result.contains("/// @notice Deposit `amount` tokens into the vault."));
+    assert!(!result.contains("// Internal bookkeeping note."));
+}
+
+#[test]
+fn test_solidity_minimal_keeps_block_natspec() {
+    let result = transform(TOKEN, Language::Solidity, Mode::Minimal).unwrap();
+    assert!(
+        result.contains("/**\n * @title Simple fee-charging token\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("        // TODO: exempt the treasury from fees.\n"),
+        "comment inside a function body should be kept, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Full mode (passthrough)
+// ============================================================================
+
+#[test]
+fn test_solidity_full_mode_passthrough() {
+    let result = transform(GOVERNOR, Language::Solidity, Mode::Full).unwrap();
+    assert_eq!(result, GOVERNOR);
+}
+
+// ============================================================================
+// Outline and view modes
+// ============================================================================
+
+#[test]
+fn test_solidity_outline_nests_contract_members() {
+    let result = transform(VAULT, Language::Solidity, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "7: interface IVault\n  8: function deposit(...)\n  9: function balanceOf(...)\n\
+         13: contract Vault\n  14: struct Position\n  19: enum Status\n  29: modifier onlyOwner()\n  \
+         34: constructor(...)\n  40: function deposit(...)\n  46: function balanceOf(...)\n  \
+         50: function setOwner(...)\n  54: receive()\n"
+    );
+}
+
+#[test]
+fn test_solidity_outline_lists_library_functions() {
+    let result = transform(MATH, Language::Solidity, Mode::Outline).unwrap();
+    assert_eq!(
+        result,
+        "6: library FixedMath\n  13: function mulWad(...)\n  17: function divWad(...)\n  22: function min(...)\n"
+    );
+}
+
+#[test]
+fn test_solidity_comments_attribute_natspec() {
+    let result = transform(MATH, Language::Solidity, Mode::Comments).unwrap();
+    assert!(
+        result.contains(
+            "12: FixedMath.mulWad\n/// @notice Multiply two WAD values, rounding half up.\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("4: FixedMath\n/// @title Fixed-point helpers\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_solidity_todos() {
+    let result = transform(TOKEN, Language::Solidity, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "41: TODO: exempt the treasury from fees. (FeeToken.transfer)\n"
+    );
+    let result = transform(GOVERNOR, Language::Solidity, Mode::Todos).unwrap();
+    assert_eq!(
+        result,
+        "4: FIXME: proposals can be executed twice if the call reverts late.\n"
+    );
+}
+
+#[test]
+fn test_solidity_constants_keep_constant_and_immutable_state() {
+    let result = transform(TOKEN, Language::Solidity, Mode::Constants).unwrap();
+    assert!(
+        result.contains("string public constant NAME = \"Fee Token\";\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("address public immutable treasury;"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("totalSupply"), "got:\n{result}");
+}
+
+#[test]
+fn test_solidity_errors_name_owning_modifier_and_fallback() {
+    let result = transform(TOKEN, Language::Solidity, Mode::Errors).unwrap();
+    assert_eq!(
+        result,
+        "27: error InsufficientBalance(uint256 available, uint256 required); (FeeToken)\n\
+         39: revert InsufficientBalance(available, value); (FeeToken.transfer)\n\
+         58: require(msg.sender == treasury, \"only treasury\"); (FeeToken.setFee)\n\
+         59: require(next <= MAX_FEE, \"fee too high\"); (FeeToken.setFee)\n\
+         65: revert(\"unknown call\"); (FeeToken.fallback)\n"
+    );
+    let result = transform(GOVERNOR, Language::Solidity, Mode::Errors).unwrap();
+    assert!(
+        result
+            .starts_with("15: require(msg.sender == owner, \"not owner\"); (Ownable.onlyOwner)\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_solidity_call_graph_attributes_calls_to_contract_members() {
+    let result = transform(GOVERNOR, Language::Solidity, Mode::CallGraph).unwrap();
+    assert!(
+        result.starts_with("Ownable.onlyOwner -> require\nGovernor.inState -> require\n"),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains("Governor.transferOwnership -> super.transferOwnership\n"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_solidity_summary_counts_contracts_as_classes() {
+    let result = transform(TOKEN, Language::Solidity, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 67, functions: 5, classes: 1, types: 0,"),
+        "`using FixedMath for uint256` is not a type, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_solidity_all_fixtures_parse_cleanly() {
+    for (name, source) in [
+        ("vault.sol", VAULT),
+        ("math.sol", MATH),
+        ("token.sol", TOKEN),
+        ("governor.sol", GOVERNOR),
+    ] {
+        let ast = rskim_core::dump_ast(source, Language::Solidity, AstFormat::SExpression).unwrap();
+        assert!(!ast.contains("(ERROR"), "{name} has parse errors:\n{ast}");
+        for mode in [
+            Mode::Structure,
+            Mode::Signatures,
+            Mode::Types,
+            Mode::Full,
+            Mode::Minimal,
+            Mode::Outline,
+        ] {
+            let result = transform(source, Language::Solidity, mode);
+            assert!(
+                result.is_ok(),
+                "Failed to transform {name} in {mode:?} mode: {:?}",
+                result.err()
+            );
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Unicode corpus tests — CJK identifiers, combining characters, and emoji in
+//! strings and comments, run through every language and mode
+//!
+//! Every transform slices the source by byte offsets, so a single mis-aligned
+//! offset would fail (or panic on) the whole file. These tests pin down that
+//! multi-byte text survives every mode intact.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)] // Unwrapping, expect and panics are acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, supported_languages, transform_with_line_map};
+
+const MODES: &[Mode] = &[
+    Mode::Structure,
+    Mode::Signatures,
+    Mode::Types,
+    Mode::Full,
+    Mode::Minimal,
+    Mode::Pseudo,
+];
+
+/// Sample source for `language`, plus text structure mode must keep verbatim
+/// where the grammar is known to accept Unicode identifiers.
+///
+/// The match is exhaustive so a new language cannot skip the corpus.
+fn sample(language: Language) -> (&'static str, Option<&'static str>) {
+    match language {
+        Language::TypeScript | Language::Tsx => (
+            "// 注释 with emoji 🚀 and cafe\u{301}\n\
+             export function 计算总数(项目: number[]): number {\n  \
+             const label = \"合計 🎉 e\u{301}\";\n  return 项目.length;\n}\n\
+             export interface 用户 { 名前: string; }\n",
+            Some("export function 计算总数(项目: number[]): number"),
+        ),
+        Language::JavaScript => (
+            "// 注释 🚀 cafe\u{301}\nfunction 计算总数(项目) {\n  \
+             const label = `合計 🎉 ${项目.length}`;\n  return label;\n}\n",
+            Some("function 计算总数(项目)"),
+        ),
+        Language::Python => (
+            "# 注释 🚀 cafe\u{301}\nclass 用户:\n    \"\"\"ドキュメント 🎉\"\"\"\n\n    \
+             def 获取(self, 名前: str) -> str:\n        return f\"こんにちは {名前} 👋\"\n",
+            Some("def 获取(self, 名前: str) -> str:"),
+        ),
+        Language::Rust => (
+            "/// ドキュメント 🚀 cafe\u{301}\npub fn 计算(值: u32) -> u32 {\n    \
+             let s = \"合計 🎉\";\n    值 + s.len() as u32\n}\n\
+             pub struct 用户 {\n    pub 名前: String,\n}\n",
+            Some("pub fn 计算(值: u32) -> u32"),
+        ),
+        Language::Go => (
+            "package main\n\n// 注释 🚀\nfunc 计算(值 int) int {\n\t\
+             s := \"合計 🎉 e\u{301}\"\n\t_ = s\n\treturn 值\n}\n\n\
+             type 用户 struct {\n\t名前 string\n}\n",
+            Some("func 计算(值 int) int"),
+        ),
+        Language::Java => (
+            "// 注释 🚀\npublic class 用户 {\n    public String 获取(String 名前) {\n        \
+             return \"こんにちは 👋 e\u{301}\" + 名前;\n    }\n}\n",
+            Some("public String 获取(String 名前)"),
+        ),
+        Language::C => (
+            "/* 注释 🚀 e\u{301} */\nint compute(int value) {\n    \
+             const char *s = \"合計 🎉\";\n    return value + (int)s[0];\n}\n",
+            Some("int compute(int value)"),
+        ),
+        Language::Cpp => (
+            "// 注释 🚀\nclass Greeter {\npublic:\n    std::string greet() {\n        \
+             return u8\"こんにちは 👋 e\u{301}\";\n    }\n};\n\
+             int compute(int value) {\n    return value; // 🎉\n}\n",
+            Some("int compute(int value)"),
+        ),
+        Language::Markdown => (
+            "# 标题 🚀\n\nParagraph with cafe\u{301}.\n\n## 二つ目 🎉\n\n\
+             ```python\ndef 获取():\n    return \"👋\"\n```\n",
+            Some("# 标题 🚀"),
+        ),
+        Language::Json => (
+            "{\"名前\": \"値 🚀\", \"e\u{301}\": [1, 2], \"入れ子\": {\"絵文字\": \"🎉\"}}\n",
+            None,
+        ),
+        Language::Yaml => ("# 🚀\n名前: 値 🎉\nリスト:\n  - e\u{301}\n  - 👋\n", None),
+        Language::Toml => ("# 🚀\n[\"設定\"]\n\"名前\" = \"値 🎉 e\u{301}\"\n", None),
+        Language::CSharp => (
+            "// 注释 🚀\npublic class 用户 {\n    public string 获取(string 名前) {\n        \
+             return $\"こんにちは {名前} 👋 e\u{301}\";\n    }\n}\n",
+            Some("public string 获取(string 名前)"),
+        ),
+        Language::Ruby => (
+            "# 注释 🚀\nclass 用户\n  def 挨拶(名前)\n    \
+             \"こんにちは #{名前} 👋 e\u{301}\"\n  end\nend\n",
+            None,
+        ),
+        Language::Sql => (
+            "-- 注释 🚀\nCREATE TABLE \"用户\" (id INT, \"名前\" TEXT);\n\
+             SELECT '合計 🎉 e\u{301}' FROM \"用户\";\n",
+            None,
+        ),
+        Language::Kotlin => (
+            "// 注释 🚀\nfun 计算(值: Int): Int {\n    val s = \"合計 🎉 e\u{301}\"\n    \
+             return 值 + s.length\n}\n",
+            None,
+        ),
+        Language::Swift => (
+            "// 注释 🚀\nfunc 计算(值: Int) -> Int {\n    let s = \"合計 🎉 e\u{301}\"\n    \
+             return 值 + s.count\n}\n",
+            None,
+        ),
+        Language::Vue => (
+            "<template>\n  <p>{{ 名前 }} 🚀</p>\n</template>\n\n<script setup lang=\"ts\">\n\
+             function 计算(值: number): number {\n  return 值 // 🎉 e\u{301}\n}\n</script>\n",
+            None,
+        ),
+        Language::Svelte => (
+            "<script>\n  function 计算(值) {\n    return `合計 🎉 ${值}`;\n  }\n</script>\n\n\
+             <h1>标题 🚀 e\u{301}</h1>\n",
+            None,
+        ),
+        Language::Xml => (
+            "<?xml version=\"1.0\"?>\n<!-- 🚀 -->\n<設定 名前=\"値 🎉\">\n  \
+             <項目>e\u{301}</項目>\n</設定>\n",
+            None,
+        ),
+        Language::Html => (
+            "<html>\n<body>\n<h1>标题 🚀</h1>\n<p>cafe\u{301} 🎉</p>\n</body>\n</html>\n",
+            None,
+        ),
+        Language::Hcl => (
+            "# 🚀\nresource \"aws_s3_bucket\" \"桶\" {\n  name = \"値 🎉 e\u{301}\"\n}\n",
+            Some("resource \"aws_s3_bucket\" \"桶\""),
+        ),
+        Language::Dockerfile => (
+            "# 🚀\nFROM alpine\nLABEL 名前=\"値 🎉\"\nRUN echo \"e\u{301}\" && echo 完了\n",
+            Some("FROM alpine"),
+        ),
+        Language::Shell => (
+            "#!/bin/sh\n# 🚀\ngreet() {\n  echo \"こんにちは 👋 e\u{301}\"\n}\n",
+            None,
+        ),
+        Language::Elixir => (
+            "defmodule Greeter do\n  # 🚀\n  def hello(名前) do\n    \
+             \"こんにちは #{名前} 👋 e\u{301}\"\n  end\nend\n",
+            None,
+        ),
+        Language::Dart => (
+            "// 🚀\nString greet(String 名前) {\n  return 'こんにちは $名前 👋 e\u{301}';\n}\n",
+            None,
+        ),
+        Language::Solidity => (
+            "// 🚀\ncontract Vault {\n    string public label = unicode\"金庫 🎉 e\u{301}\";\n\n    \
+             function deposit() public {\n        label = unicode\"入金 👋\";\n    }\n}\n",
+            None,
+        ),
+        Language::ObjectiveC => (
+            "// 🚀\n@interface Counter : NSObject\n- (NSString *)label;\n@end\n\n\
+             @implementation Counter\n- (NSString *)label {\n    \
+             return @\"合計 🎉 e\u{301}\";\n}\n@end\n",
+            None,
+        ),
+        Language::Groovy => (
+            "// 🚀\ndependencies {\n    implementation 'com.example:名前:1.0' // 🎉\n}\n\
+             def 挨拶(String 名前) {\n    \"こんにちは $名前 👋 e\u{301}\"\n}\n",
+            Some("def 挨拶(String 名前) {...}"),
+        ),
+        Language::Csv => (
+            "名前,値,備考\n合計,1,\"🚀, e\u{301}\"\n🎉,2,\n",
+            Some("名前,値,備考"),
+        ),
+        Language::Env => (
+            "# 🚀 e\u{301}\nGREETING=\"こんにちは 👋\"\nAPI_TOKEN=合計🎉\n",
+            Some("API_TOKEN=  # secret"),
+        ),
+        Language::Ini => (
+            "; 🚀 e\u{301}\n[挨拶]\n名前 = こんにちは 👋\n",
+            Some("  名前"),
+        ),
+        Language::Rst => (
+            ".. 🚀 e\u{301}\n\n挨拶 👋\n======\n\n.. note:: こんにちは\n",
+            Some("挨拶 👋"),
+        ),
+        Language::AsciiDoc => (
+            "// 🚀 e\u{301}\n= 挨拶 👋\n\n== 名前\n\nこんにちは\n",
+            Some("== 名前"),
+        ),
+        Language::Julia => (
+            "# 🚀\nmodule 幾何\n\nstruct 点\n    x::Float64\nend\n\n\
+             function 挨拶(名前)\n    return \"こんにちは $名前 👋 e\u{301}\"\nend\n\nend\n",
+            None,
+        ),
+        Language::Ocaml => (
+            "(* 注释 🚀 *)\ntype user = { name : string }\n\n\
+             let greet user =\n  \"こんにちは \" ^ user.name ^ \" 👋 e\u{301}\"\n",
+            None,
+        ),
+        Language::OcamlInterface => (
+            "(** 挨拶 🚀 e\u{301} *)\nval greet : string -> string\n",
+            None,
+        ),
+    }
+}
+
+#[test]
+fn test_unicode_corpus_every_language_and_mode_succeeds() {
+    for &language in supported_languages() {
+        let (source, _) = sample(language);
+        for &mode in MODES {
+            let config = TransformConfig::with_mode(mode).with_line_numbers(true);
+            let (output, _, line_map, _) = transform_with_line_map(source, language, &config)
+                .unwrap_or_else(|e| panic!("{language:?} {mode:?}: {e}"));
+            assert!(
+                !output.contains('\u{FFFD}'),
+                "{language:?} {mode:?}: lossy output:\n{output}"
+            );
+            if let Some(map) = line_map {
+                let lines = source.lines().count();
+                assert!(
+                    map.iter().all(|&line| line <= lines),
+                    "{language:?} {mode:?}: line map past end of source: {map:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_unicode_corpus_full_mode_is_byte_identical() {
+    // Redacted formats (`.env`) never pass their source through.
+    for &language in supported_languages().iter().filter(|l| !l.is_redacted()) {
+        let (source, _) = sample(language);
+        let config = TransformConfig::with_mode(Mode::Full);
+        let (output, _, _, _) = transform_with_line_map(source, language, &config).unwrap();
+        assert_eq!(output, source, "{language:?}");
+    }
+}
+
+#[test]
+fn test_unicode_corpus_structure_keeps_unicode_identifiers() {
+    for &language in supported_languages() {
+        let (source, keep) = sample(language);
+        let Some(keep) = keep else { continue };
+        let config = TransformConfig::with_mode(Mode::Structure);
+        let (output, _, _, _) = transform_with_line_map(source, language, &config).unwrap();
+        assert!(
+            output.contains(keep),
+            "{language:?}: expected {keep:?} in:\n{output}"
+        );
+    }
+}
+
+#[test]
+fn test_unicode_corpus_truncation_stays_on_char_boundaries() {
+    for &language in supported_languages() {
+        let (source, _) = sample(language);
+        for &mode in MODES {
+            for max_lines in 1..=3 {
+                let config = TransformConfig::with_mode(mode).with_max_lines(max_lines);
+                transform_with_line_map(source, language, &config)
+                    .unwrap_or_else(|e| panic!("{language:?} {mode:?} {max_lines}: {e}"));
+            }
+        }
+    }
+}
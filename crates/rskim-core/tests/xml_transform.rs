@@ -0,0 +1,411 @@
+//! XML transformation tests — verify element hierarchy extraction across fixtures and all modes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+
+use rskim_core::{Language, Mode, TransformConfig, transform, transform_with_quality};
+
+const POM_XML: &str = include_str!("../../../tests/fixtures/xml/pom.xml");
+const ANDROID_MANIFEST: &str = include_str!("../../../tests/fixtures/xml/AndroidManifest.xml");
+const ACTIVITY_MAIN: &str = include_str!("../../../tests/fixtures/xml/activity_main.xml");
+const ORDERS_CSPROJ: &str = include_str!("../../../tests/fixtures/xml/Orders.csproj");
+const INVOICE_XSL: &str = include_str!("../../../tests/fixtures/xml/invoice.xsl");
+const CATALOG_XML: &str = include_str!("../../../tests/fixtures/xml/catalog.xml");
+const BROKEN_XML: &str = include_str!("../../../tests/fixtures/xml/broken.xml");
+
+const FIXTURES: [(&str, &str); 6] = [
+    ("pom.xml", POM_XML),
+    ("AndroidManifest.xml", ANDROID_MANIFEST),
+    ("activity_main.xml", ACTIVITY_MAIN),
+    ("Orders.csproj", ORDERS_CSPROJ),
+    ("invoice.xsl", INVOICE_XSL),
+    ("catalog.xml", CATALOG_XML),
+];
+
+fn has_errors(source: &str) -> bool {
+    let config = TransformConfig::with_mode(Mode::Structure);
+    transform_with_quality(source, Language::Xml, &config)
+        .unwrap()
+        .1
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+#[test]
+fn test_xml_language_detection() {
+    use std::path::Path;
+    assert_eq!(rskim_core::detect_language("xml"), Some(Language::Xml));
+    assert_eq!(rskim_core::detect_language("xsd"), Some(Language::Xml));
+    for path in [
+        "app/src/main/AndroidManifest.xml",
+        "src/Orders/Orders.csproj",
+        "src/Orders.Tests/Orders.Tests.fsproj",
+        "Directory.Build.props",
+        "build/Custom.targets",
+        "xslt/invoice.xsl",
+    ] {
+        assert_eq!(
+            rskim_core::detect_language_from_path(Path::new(path)),
+            Some(Language::Xml),
+            "{path}"
+        );
+    }
+}
+
+// ============================================================================
+// Structure mode
+// ============================================================================
+
+#[test]
+fn test_pom_structure_keeps_element_hierarchy() {
+    let result = transform(POM_XML, Language::Xml, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<project xmlns=\"...\" xmlns:xsi=\"...\" xsi:schemaLocation=\"...\">\n  \
+             <modelVersion>...</modelVersion>\n"
+        ),
+        "root element and attribute names should be kept, got:\n{result}"
+    );
+    assert!(
+        result.contains("  <dependencies>\n    <dependency>\n      <groupId>...</groupId>\n"),
+        "nesting should be preserved, got:\n{result}"
+    );
+    assert!(
+        result.contains("<argLine>...</argLine>"),
+        "CDATA should collapse like text, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_pom_structure_collapses_values() {
+    let result = transform(POM_XML, Language::Xml, Mode::Structure).unwrap();
+    for value in [
+        "junit-jupiter",
+        "5.10.2",
+        "maven.apache.org",
+        "-Xmx512m",
+        "<?xml",
+    ] {
+        assert!(
+            !result.contains(value),
+            "{value} should be collapsed, got:\n{result}"
+        );
+    }
+    assert!(
+        !result.contains("Example Maven build"),
+        "comments should be dropped, got:\n{result}"
+    );
+}
+
+#[test]
+fn test_android_manifest_structure() {
+    let result = transform(ANDROID_MANIFEST, Language::Xml, Mode::Structure).unwrap();
+    assert!(
+        result.contains("  <uses-permission android:name=\"...\"/>\n"),
+        "self-closing elements should stay self-closing, got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "  <application android:allowBackup=\"...\" android:label=\"...\" android:theme=\"...\">\n"
+        ),
+        "multi-line opening tags should be joined, got:\n{result}"
+    );
+    assert!(
+        result.contains("        <action android:name=\"...\"/>\n"),
+        "deep nesting should be re-indented, got:\n{result}"
+    );
+}
+
+// ============================================================================
+// Android layouts
+// ============================================================================
+
+#[test]
+fn test_android_layout_keeps_qualified_view_names() {
+    let result = transform(ACTIVITY_MAIN, Language::Xml, Mode::Structure).unwrap();
+    assert!(
+        result.starts_with(
+            "<androidx.constraintlayout.widget.ConstraintLayout xmlns:android=\"...\""
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "  <androidx.recyclerview.widget.RecyclerView android:id=\"...\" android:layout_width=\"...\" \
+             android:layout_height=\"...\" tools:listitem=\"...\"/>\n"
+        ),
+        "got:\n{result}"
+    );
+    assert!(
+        result.ends_with(
+            "  <LinearLayout android:orientation=\"...\" android:layout_width=\"...\" \
+             android:layout_height=\"...\">\n    <Button android:id=\"...\" android:text=\"...\"/>\n    \
+             <Button android:id=\"...\" android:text=\"...\"/>\n  </LinearLayout>\n\
+             </androidx.constraintlayout.widget.ConstraintLayout>\n"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_android_layout_resource_values_do_not_leak() {
+    let result = transform(ACTIVITY_MAIN, Language::Xml, Mode::Structure).unwrap();
+    for value in [
+        "@+id/title",
+        "match_parent",
+        "@string",
+        "schemas.android.com",
+        "Header",
+    ] {
+        assert!(!result.contains(value), "{value} leaked:\n{result}");
+    }
+}
+
+// ============================================================================
+// MSBuild projects
+// ============================================================================
+
+#[test]
+fn test_csproj_structure() {
+    let result = transform(ORDERS_CSPROJ, Language::Xml, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "<Project Sdk=\"...\">
+  <PropertyGroup>
+    <TargetFramework>...</TargetFramework>
+    <Nullable>...</Nullable>
+    <ImplicitUsings>...</ImplicitUsings>
+    <RootNamespace>...</RootNamespace>
+  </PropertyGroup>
+  <PropertyGroup Condition=\"...\">
+    <TreatWarningsAsErrors>...</TreatWarningsAsErrors>
+  </PropertyGroup>
+  <ItemGroup>
+    <PackageReference Include=\"...\" Version=\"...\"/>
+    <PackageReference Include=\"...\" Version=\"...\"/>
+    <ProjectReference Include=\"...\"/>
+  </ItemGroup>
+  <Target Name=\"...\" AfterTargets=\"...\">
+    <Message Importance=\"...\" Text=\"...\"/>
+  </Target>
+</Project>
+"
+    );
+}
+
+#[test]
+fn test_csproj_msbuild_expressions_do_not_leak() {
+    let result = transform(ORDERS_CSPROJ, Language::Xml, Mode::Structure).unwrap();
+    for value in [
+        "$(Configuration)",
+        "Release",
+        "net8.0",
+        "EntityFrameworkCore",
+        "Acme.Shared",
+    ] {
+        assert!(!result.contains(value), "{value} leaked:\n{result}");
+    }
+}
+
+// ============================================================================
+// XSLT
+// ============================================================================
+
+#[test]
+fn test_xslt_literal_result_elements_nest_with_instructions() {
+    let result = transform(INVOICE_XSL, Language::Xml, Mode::Structure).unwrap();
+    assert!(
+        result.contains("        <h1>\n          <xsl:value-of select=\"...\"/>\n        </h1>\n"),
+        "mixed content keeps only child elements, got:\n{result}"
+    );
+    assert!(
+        result.contains(
+            "          <xsl:for-each select=\"...\">\n            <xsl:sort select=\"...\" \
+             data-type=\"...\" order=\"...\"/>\n            <tr>\n"
+        ),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_xslt_escaped_comparisons_in_attributes() {
+    assert!(INVOICE_XSL.contains("&lt; 1"));
+    let result = transform(INVOICE_XSL, Language::Xml, Mode::Structure).unwrap();
+    assert!(
+        result.contains("        <xsl:if test=\"...\">\n          <p>...</p>\n        </xsl:if>\n"),
+        "got:\n{result}"
+    );
+    assert!(!result.contains("count(line)"), "got:\n{result}");
+    assert!(!has_errors(INVOICE_XSL));
+}
+
+// ============================================================================
+// Prolog, DOCTYPE and CDATA
+// ============================================================================
+
+#[test]
+fn test_catalog_doctype_internal_subset_is_dropped() {
+    let result = transform(CATALOG_XML, Language::Xml, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "<catalog>
+  <book id=\"...\" lang=\"...\">
+    <title>...</title>
+    <author>...</author>
+    <price currency=\"...\">...</price>
+    <description>...</description>
+  </book>
+  <book id=\"...\">
+    <title>...</title>
+    <author/>
+    <price currency=\"...\">...</price>
+  </book>
+</catalog>
+"
+    );
+    assert!(!has_errors(CATALOG_XML));
+}
+
+#[test]
+fn test_catalog_commented_out_elements_and_cdata_markup_are_dropped() {
+    let result = transform(CATALOG_XML, Language::Xml, Mode::Structure).unwrap();
+    for dropped in ["draft", "<tags>", "xml-stylesheet", "ENTITY", "Acme Press"] {
+        assert!(!result.contains(dropped), "{dropped} kept:\n{result}");
+    }
+}
+
+#[test]
+fn test_xml_crlf_line_endings() {
+    let crlf = ANDROID_MANIFEST.replace('\n', "\r\n");
+    let result = transform(&crlf, Language::Xml, Mode::Structure).unwrap();
+    let expected = transform(ANDROID_MANIFEST, Language::Xml, Mode::Structure).unwrap();
+    assert_eq!(result, expected);
+    assert!(!has_errors(&crlf));
+}
+
+// ============================================================================
+// Parse errors
+// ============================================================================
+
+#[test]
+fn test_broken_mismatched_and_unclosed_tags_are_parse_errors() {
+    assert!(has_errors(BROKEN_XML));
+    let result = transform(BROKEN_XML, Language::Xml, Mode::Structure).unwrap();
+    assert_eq!(
+        result,
+        "<config>
+  <server>
+    <host>...</host>
+    <port>...</port>
+  </server>
+  <database>
+    <url>...</url>
+  </database>
+</config>
+"
+    );
+}
+
+#[test]
+fn test_xml_unterminated_comment_is_a_parse_error() {
+    assert!(has_errors("<a>\n  <!-- <b/>\n</a>\n"));
+    assert!(has_errors("<a><![CDATA[open</a>\n"));
+    assert!(!has_errors("<a><!-- fine --><![CDATA[ok]]></a>\n"));
+}
+
+// ============================================================================
+// Other modes
+// ============================================================================
+
+#[test]
+fn test_xml_signatures_matches_structure() {
+    let structure = transform(POM_XML, Language::Xml, Mode::Structure).unwrap();
+    let signatures = transform(POM_XML, Language::Xml, Mode::Signatures).unwrap();
+    assert_eq!(signatures, structure);
+}
+
+#[test]
+fn test_xml_minimal_and_full_are_passthrough() {
+    assert_eq!(
+        transform(POM_XML, Language::Xml, Mode::Full).unwrap(),
+        POM_XML
+    );
+    assert_eq!(
+        transform(POM_XML, Language::Xml, Mode::Minimal).unwrap(),
+        POM_XML
+    );
+}
+
+#[test]
+fn test_xml_pseudo_is_passthrough() {
+    let result = transform(INVOICE_XSL, Language::Xml, Mode::Pseudo).unwrap();
+    assert_eq!(result, INVOICE_XSL);
+}
+
+#[test]
+fn test_xml_outline_and_analysis_modes_fall_back_to_structure() {
+    let structure = transform(ORDERS_CSPROJ, Language::Xml, Mode::Structure).unwrap();
+    for mode in [
+        Mode::Types,
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Todos,
+        Mode::Errors,
+        Mode::Constants,
+    ] {
+        let result = transform(ORDERS_CSPROJ, Language::Xml, mode).unwrap();
+        assert_eq!(result, structure, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_xml_summary_reports_lines_and_tokens_only() {
+    let result = transform(ORDERS_CSPROJ, Language::Xml, Mode::Summary).unwrap();
+    assert!(
+        result.starts_with("lines: 24, functions: 0, classes: 0, types: 0, tokens: ~"),
+        "got:\n{result}"
+    );
+}
+
+#[test]
+fn test_xml_mode_caveats() {
+    for mode in [
+        Mode::Signatures,
+        Mode::Outline,
+        Mode::Comments,
+        Mode::Pseudo,
+    ] {
+        assert!(Language::Xml.mode_caveat(mode).is_some(), "{mode:?}");
+    }
+    assert!(Language::Xml.mode_caveat(Mode::Structure).is_none());
+}
+
+// ============================================================================
+// Cross-fixture tests
+// ============================================================================
+
+#[test]
+fn test_xml_all_fixtures_parse_cleanly_and_shrink() {
+    for (name, source) in FIXTURES {
+        assert!(!has_errors(source), "{name} has parse errors");
+        let result = transform(source, Language::Xml, Mode::Structure).unwrap();
+        assert!(!result.is_empty(), "{name}");
+        assert!(result.len() < source.len(), "{name}:\n{result}");
+    }
+}
+
+#[test]
+fn test_xml_all_fixtures_collapse_every_attribute_value() {
+    for (name, source) in FIXTURES {
+        let result = transform(source, Language::Xml, Mode::Structure).unwrap();
+        let values = result.matches("=\"").count();
+        assert_eq!(
+            result.matches("=\"...\"").count(),
+            values,
+            "{name}:\n{result}"
+        );
+        assert!(!result.contains("<?"), "{name}:\n{result}");
+        assert!(!result.contains("<!"), "{name}:\n{result}");
+    }
+}
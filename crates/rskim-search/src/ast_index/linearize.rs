@@ -118,6 +118,7 @@ pub struct LinearizeResult {
 static LANG_MAPS: LazyLock<HashMap<Language, Vec<Option<u16>>>> = LazyLock::new(|| {
     let ts_languages = [
         Language::TypeScript,
+        Language::Tsx,
         Language::JavaScript,
         Language::Python,
         Language::Rust,
@@ -214,7 +214,7 @@ pub fn vocab_len() -> usize {
 /// - The specific bigram is not in the table.
 #[must_use]
 pub fn ast_bigram_idf(lang: Language, bigram: AstBigram) -> f32 {
-    ast_bigram_weight(lang.name(), bigram.key()).unwrap_or(DEFAULT_AST_WEIGHT)
+    ast_bigram_weight(weight_table(lang), bigram.key()).unwrap_or(DEFAULT_AST_WEIGHT)
 }
 
 /// Look up the IDF weight for an [`AstTrigram`] in the per-language weight table.
@@ -225,13 +225,23 @@ pub fn ast_bigram_idf(lang: Language, bigram: AstBigram) -> f32 {
 /// - The specific trigram is not in the table.
 #[must_use]
 pub fn ast_trigram_idf(lang: Language, trigram: AstTrigram) -> f32 {
-    ast_trigram_weight(lang.name(), trigram.key()).unwrap_or(DEFAULT_AST_WEIGHT)
+    ast_trigram_weight(weight_table(lang), trigram.key()).unwrap_or(DEFAULT_AST_WEIGHT)
 }
 
 // ============================================================================
 // Private helpers
 // ============================================================================
 
+/// Name of the weight table for `lang`.
+///
+/// TSX shares TypeScript's node kinds, so it reuses TypeScript's table.
+fn weight_table(lang: Language) -> &'static str {
+    match lang {
+        Language::Tsx => Language::TypeScript.name(),
+        _ => lang.name(),
+    }
+}
+
 /// Format a [`NodeKindId`] using the vocabulary.
 ///
 /// - Known, non-empty string → write the string.
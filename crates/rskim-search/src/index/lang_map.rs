@@ -37,6 +37,27 @@ pub(crate) fn lang_to_id(lang: rskim_core::Language) -> u8 {
         rskim_core::Language::Toml => 14,
         rskim_core::Language::TypeScript => 15,
         rskim_core::Language::Yaml => 16,
+        rskim_core::Language::Vue => 17,
+        rskim_core::Language::Svelte => 18,
+        rskim_core::Language::Xml => 19,
+        rskim_core::Language::Html => 20,
+        rskim_core::Language::Hcl => 21,
+        rskim_core::Language::Dockerfile => 22,
+        rskim_core::Language::Shell => 23,
+        rskim_core::Language::Elixir => 24,
+        rskim_core::Language::Dart => 25,
+        rskim_core::Language::Solidity => 26,
+        rskim_core::Language::ObjectiveC => 27,
+        rskim_core::Language::Groovy => 28,
+        rskim_core::Language::Julia => 29,
+        rskim_core::Language::Ocaml => 30,
+        rskim_core::Language::OcamlInterface => 31,
+        rskim_core::Language::Csv => 32,
+        rskim_core::Language::Env => 33,
+        rskim_core::Language::Ini => 34,
+        rskim_core::Language::Rst => 35,
+        rskim_core::Language::AsciiDoc => 36,
+        rskim_core::Language::Tsx => 37,
     }
 }
 
@@ -66,6 +87,27 @@ pub(crate) fn lang_from_id(id: u8) -> Option<rskim_core::Language> {
         14 => Some(rskim_core::Language::Toml),
         15 => Some(rskim_core::Language::TypeScript),
         16 => Some(rskim_core::Language::Yaml),
+        17 => Some(rskim_core::Language::Vue),
+        18 => Some(rskim_core::Language::Svelte),
+        19 => Some(rskim_core::Language::Xml),
+        20 => Some(rskim_core::Language::Html),
+        21 => Some(rskim_core::Language::Hcl),
+        22 => Some(rskim_core::Language::Dockerfile),
+        23 => Some(rskim_core::Language::Shell),
+        24 => Some(rskim_core::Language::Elixir),
+        25 => Some(rskim_core::Language::Dart),
+        26 => Some(rskim_core::Language::Solidity),
+        27 => Some(rskim_core::Language::ObjectiveC),
+        28 => Some(rskim_core::Language::Groovy),
+        29 => Some(rskim_core::Language::Julia),
+        30 => Some(rskim_core::Language::Ocaml),
+        31 => Some(rskim_core::Language::OcamlInterface),
+        32 => Some(rskim_core::Language::Csv),
+        33 => Some(rskim_core::Language::Env),
+        34 => Some(rskim_core::Language::Ini),
+        35 => Some(rskim_core::Language::Rst),
+        36 => Some(rskim_core::Language::AsciiDoc),
+        37 => Some(rskim_core::Language::Tsx),
         _ => None,
     }
 }
@@ -24,6 +24,27 @@ fn test_lang_mapping_roundtrip() {
         rskim_core::Language::Toml,
         rskim_core::Language::TypeScript,
         rskim_core::Language::Yaml,
+        rskim_core::Language::Vue,
+        rskim_core::Language::Svelte,
+        rskim_core::Language::Xml,
+        rskim_core::Language::Html,
+        rskim_core::Language::Hcl,
+        rskim_core::Language::Dockerfile,
+        rskim_core::Language::Shell,
+        rskim_core::Language::Elixir,
+        rskim_core::Language::Dart,
+        rskim_core::Language::Solidity,
+        rskim_core::Language::ObjectiveC,
+        rskim_core::Language::Groovy,
+        rskim_core::Language::Julia,
+        rskim_core::Language::Ocaml,
+        rskim_core::Language::OcamlInterface,
+        rskim_core::Language::Csv,
+        rskim_core::Language::Env,
+        rskim_core::Language::Ini,
+        rskim_core::Language::Rst,
+        rskim_core::Language::AsciiDoc,
+        rskim_core::Language::Tsx,
     ];
     for lang in langs {
         let id = lang_to_id(lang);
@@ -39,7 +60,7 @@ fn test_lang_mapping_roundtrip() {
 #[test]
 fn test_lang_from_id_unknown() {
     assert_eq!(lang_from_id(200), None);
-    assert_eq!(lang_from_id(17), None);
+    assert_eq!(lang_from_id(38), None);
     assert_eq!(lang_from_id(255), None);
 }
 
@@ -63,6 +84,27 @@ fn test_lang_ids_unique() {
         rskim_core::Language::Toml,
         rskim_core::Language::TypeScript,
         rskim_core::Language::Yaml,
+        rskim_core::Language::Vue,
+        rskim_core::Language::Svelte,
+        rskim_core::Language::Xml,
+        rskim_core::Language::Html,
+        rskim_core::Language::Hcl,
+        rskim_core::Language::Dockerfile,
+        rskim_core::Language::Shell,
+        rskim_core::Language::Elixir,
+        rskim_core::Language::Dart,
+        rskim_core::Language::Solidity,
+        rskim_core::Language::ObjectiveC,
+        rskim_core::Language::Groovy,
+        rskim_core::Language::Julia,
+        rskim_core::Language::Ocaml,
+        rskim_core::Language::OcamlInterface,
+        rskim_core::Language::Csv,
+        rskim_core::Language::Env,
+        rskim_core::Language::Ini,
+        rskim_core::Language::Rst,
+        rskim_core::Language::AsciiDoc,
+        rskim_core::Language::Tsx,
     ];
     let mut ids: Vec<u8> = langs.iter().map(|&l| lang_to_id(l)).collect();
     let before = ids.len();
@@ -1,7 +1,8 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 //! Criterion benchmarks for token counting performance.
 //!
-//! Measures counting a ~100 KB input after counter initialisation.
+//! Measures counting a ~100 KB input after counter initialisation, plus a
+//! ~2 MB input counted serially vs. with parallel chunked encoding.
 //! Tracked baseline: relative regression guard (ADR-003 — no empirically-
 //! baseless absolute cap; the 25 ms ceiling in AC12 is a coarse sentinel).
 
@@ -30,6 +31,20 @@ fn bench_cl100k(c: &mut Criterion) {
     });
 }
 
+fn bench_cl100k_parallel(c: &mut Criterion) {
+    let input = make_100kb_input().repeat(20);
+    let counter = Counter::new(Encoding::Cl100k).expect("cl100k init");
+
+    let _ = counter.count(&input);
+
+    c.bench_function("cl100k_2mb_serial", |b| {
+        b.iter(|| counter.count(black_box(&input)));
+    });
+    c.bench_function("cl100k_2mb_parallel", |b| {
+        b.iter(|| counter.count_parallel(black_box(&input)));
+    });
+}
+
 fn bench_o200k(c: &mut Criterion) {
     let input = make_100kb_input();
     let counter = Counter::new(Encoding::O200k).expect("o200k init");
@@ -64,6 +79,7 @@ fn bench_heuristic(c: &mut Criterion) {
 criterion_group!(
     benches,
     bench_cl100k,
+    bench_cl100k_parallel,
     bench_o200k,
     bench_anthropic_offline,
     bench_heuristic,
@@ -0,0 +1,88 @@
+//! Boundary-safe chunking for parallel token counting.
+//!
+//! BPE encoders first split input with a pre-tokenisation regex and then merge
+//! bytes *within* each piece, so a split that falls on a piece boundary leaves
+//! the total count unchanged. For cl100k and o200k a newline followed by an
+//! ASCII alphanumeric byte is always such a boundary: every pattern that can
+//! consume a newline (`\s*[\r\n]+`, `[^\s\p{L}\p{N}]+[\r\n/]*`, `\s+`) stops
+//! before a letter or digit, and no pattern lets a letter-run start with a
+//! newline. Splitting only there keeps chunked counts identical to serial ones.
+//!
+//! # Usage
+//!
+//! ```
+//! use rskim_tokens::chunk::split_at_token_boundaries;
+//!
+//! let text = "fn a() {}\nfn b() {}\n";
+//! let chunks = split_at_token_boundaries(text, 4);
+//! assert_eq!(chunks, vec!["fn a() {}\n", "fn b() {}\n"]);
+//! assert_eq!(chunks.concat(), text);
+//! ```
+
+/// Split `text` into chunks of roughly `target_bytes` that never straddle a
+/// BPE pre-tokenisation boundary.
+///
+/// Each chunk ends just after a `\n` whose following byte is ASCII
+/// alphanumeric. Text without such a boundary is returned as a single chunk,
+/// so a chunk may exceed `target_bytes`. Concatenating the chunks always
+/// reproduces `text` exactly.
+#[must_use]
+pub fn split_at_token_boundaries(text: &str, target_bytes: usize) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let target = target_bytes.max(1);
+    let mut chunks = Vec::with_capacity(text.len() / target + 1);
+    let mut start = 0;
+
+    while text.len() - start > target {
+        // Search forward from the target size for the next safe split point.
+        let split = bytes[start + target..]
+            .windows(2)
+            .position(|w| w[0] == b'\n' && w[1].is_ascii_alphanumeric())
+            .map(|offset| start + target + offset + 1);
+
+        let Some(split) = split else {
+            break;
+        };
+        // `split` follows an ASCII '\n', so it is always a char boundary.
+        chunks.push(&text[start..split]);
+        start = split;
+    }
+
+    chunks.push(&text[start..]);
+    chunks
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod chunk_tests {
+    use super::*;
+
+    #[test]
+    fn short_text_is_a_single_chunk() {
+        assert_eq!(split_at_token_boundaries("abc\ndef", 64), vec!["abc\ndef"]);
+        assert_eq!(split_at_token_boundaries("", 64), vec![""]);
+    }
+
+    #[test]
+    fn never_splits_before_whitespace_or_punctuation() {
+        let text = "a\n  indented\n\nb\n/c\nd";
+        let chunks = split_at_token_boundaries(text, 1);
+        assert_eq!(chunks, vec!["a\n  indented\n\n", "b\n/c\n", "d"]);
+        assert_eq!(chunks.concat(), text);
+    }
+
+    #[test]
+    fn chunked_counts_match_serial_counts() {
+        let snippet = "pub fn run(items: &[Item]) -> usize {\n    items.len()\n}\n\n\
+                       // comment ✓ unicode\nconst X: &str = \"a\\nb\";\n";
+        let text = snippet.repeat(200);
+        for encoding in [crate::Encoding::Cl100k, crate::Encoding::O200k] {
+            let counter = crate::Counter::new(encoding).unwrap();
+            let chunked: usize = split_at_token_boundaries(&text, 512)
+                .iter()
+                .map(|c| counter.count(c))
+                .sum();
+            assert_eq!(chunked, counter.count(&text), "{encoding:?}");
+        }
+    }
+}
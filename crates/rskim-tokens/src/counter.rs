@@ -33,13 +33,21 @@
 
 use std::sync::OnceLock;
 
+use rayon::prelude::*;
 use tiktoken_rs::CoreBPE;
 
 use crate::{
     Encoding, Result, TokenError, anthropic_offline::count_anthropic_offline,
-    heuristic::count_heuristic,
+    chunk::split_at_token_boundaries, heuristic::count_heuristic,
 };
 
+/// Inputs smaller than this are counted serially by [`Counter::count_parallel`];
+/// below it, thread hand-off costs more than it saves.
+pub const PARALLEL_MIN_BYTES: usize = 256 * 1024;
+
+/// Target chunk size for [`Counter::count_parallel`].
+const PARALLEL_CHUNK_BYTES: usize = 64 * 1024;
+
 /// A constructed token counter that owns a single [`Encoding`].
 ///
 /// Use [`Counter::new`] to construct. Construction
@@ -175,6 +183,40 @@ impl Counter {
         }
     }
 
+    /// Count the tokens in `text`, encoding large inputs in parallel chunks.
+    ///
+    /// Inputs of at least [`PARALLEL_MIN_BYTES`] are split at BPE
+    /// pre-tokenisation boundaries (see [`crate::chunk`]) and the chunks are
+    /// encoded on the rayon pool, so the result is identical to
+    /// [`Counter::count`]. Smaller inputs are counted serially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rskim_tokens::{Counter, Encoding};
+    ///
+    /// let counter = Counter::new(Encoding::Cl100k)?;
+    /// let text = "fn main() {}\n".repeat(50_000);
+    /// assert_eq!(counter.count_parallel(&text), counter.count(&text));
+    /// # Ok::<(), rskim_tokens::TokenError>(())
+    /// ```
+    #[must_use]
+    pub fn count_parallel(&self, text: &str) -> usize {
+        if text.len() < PARALLEL_MIN_BYTES {
+            return self.count(text);
+        }
+        let chunks = split_at_token_boundaries(text, PARALLEL_CHUNK_BYTES);
+        match &self.inner {
+            CounterInner::Bpe(_, lock) => chunks.par_iter().map(|c| count_bpe(lock, c)).sum(),
+            // The uplift is applied once to the summed cl100k count so rounding
+            // matches the serial path.
+            CounterInner::AnthropicOffline(lock) => {
+                count_anthropic_offline(chunks.par_iter().map(|c| count_bpe(lock, c)).sum())
+            }
+            CounterInner::Heuristic => count_heuristic(text),
+        }
+    }
+
     /// Return a closure adapter that satisfies `Fn(&str) -> usize`.
     ///
     /// The returned closure borrows `self` and is suitable for use with
@@ -66,6 +66,7 @@
 #![deny(missing_docs)]
 
 pub mod anthropic_offline;
+pub mod chunk;
 pub mod counter;
 pub mod encoding;
 pub mod error;
@@ -1079,7 +1079,7 @@ pub(crate) fn record_with_counts(enabled: bool, record: TokenSavingsRecord) {
 /// Using the byte heuristic for oversized inputs is consistent with the approach
 /// in `cmd::execution::savings_decision` (applies ADR-001): token accuracy matters
 /// most for small, typical source files which are always below the cap.
-fn count_tokens_bounded(text: &str) -> usize {
+pub(crate) fn count_tokens_bounded(text: &str) -> usize {
     if text.len() > TOKEN_SIZE_CAP {
         return text.len() / 4;
     }
@@ -1106,12 +1106,30 @@ pub(crate) enum RawSource {
     Inline(String),
 }
 
+impl RawSource {
+    /// Token count of the raw text, or `None` when it cannot be read.
+    fn count_tokens(self) -> Option<usize> {
+        let text = match self {
+            // Best-effort: skip row on read or UTF-8 error.
+            // This also naturally rejects TOCTOU-grown files (size guard
+            // in read_source rejects anything over the 50 MB limit).
+            RawSource::Reread(p) => crate::process::read_source(&p).ok()?,
+            RawSource::Inline(s) => s,
+        };
+        Some(count_tokens_bounded(&text))
+    }
+}
+
 /// How token counts are obtained for a file-op row.
 pub(crate) enum FileCounts {
     /// `--show-stats` or count-carrying cache hit: counts already computed, no re-work.
     Known { raw: usize, compressed: usize },
     /// Plain run / cold cache: tokenize raw + compressed off the main thread.
     Tokenize { raw: RawSource, compressed: String },
+    /// Multi-file run: compressed already counted (with [`count_tokens_bounded`])
+    /// on the worker, since outputs are not kept once written; tokenize raw
+    /// off the main thread.
+    TokenizeRaw { raw: RawSource, compressed: usize },
 }
 
 /// Per-file data for a single analytics row.
@@ -1151,16 +1169,10 @@ pub(crate) fn record_file_ops(enabled: bool, rows: Vec<FileOpRow>, common: FileO
                 let (raw, comp) = match r.counts {
                     FileCounts::Known { raw, compressed } => (raw, compressed),
                     FileCounts::Tokenize { raw, compressed } => {
-                        let text = match raw {
-                            // Best-effort: skip row on read or UTF-8 error.
-                            // This also naturally rejects TOCTOU-grown files (size guard
-                            // in read_source rejects anything over the 50 MB limit).
-                            RawSource::Reread(p) => crate::process::read_source(&p).ok()?,
-                            RawSource::Inline(s) => s,
-                        };
-                        let raw_tok = count_tokens_bounded(&text);
-                        let comp_tok = count_tokens_bounded(&compressed);
-                        (raw_tok, comp_tok)
+                        (raw.count_tokens()?, count_tokens_bounded(&compressed))
+                    }
+                    FileCounts::TokenizeRaw { raw, compressed } => {
+                        (raw.count_tokens()?, compressed)
                     }
                 };
                 Some(TokenSavingsRecord {
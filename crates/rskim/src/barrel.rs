@@ -0,0 +1,427 @@
+//! Folder export views for TypeScript barrel files (`--barrel-exports`).
+//!
+//! Feature-folder codebases re-export each folder's public surface through an
+//! `index.ts` barrel, often as chains of `export * from './x'`. Reading the
+//! barrel alone shows only module paths. This module follows those chains to
+//! the files that declare each name and renders a comment section that is
+//! printed above the per-file output of a directory run:
+//!
+//! ```text
+//! // skim: folder exports
+//! // src/features/index.ts
+//! //   LoginForm   auth/LoginForm.tsx
+//! //   useSession  auth/session.ts
+//! ```
+//!
+//! Only relative specifiers are followed. Package re-exports are listed with
+//! their specifier, and relative specifiers that match no file are marked
+//! `(not found)` rather than dropped.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::{Component, Path, PathBuf};
+
+use rskim_core::{Language, Parser};
+use tree_sitter::Node;
+
+/// File names treated as barrels.
+const BARREL_NAMES: &[&str] = &["index.ts", "index.tsx"];
+
+/// Suffixes tried, in order, when resolving an extensionless specifier.
+const MODULE_SUFFIXES: &[&str] = &[".ts", ".tsx", ".d.ts", "/index.ts", "/index.tsx"];
+
+/// Where an exported name is declared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Origin {
+    File(PathBuf),
+    /// A specifier that was not followed: a package, or a relative path
+    /// matching no file.
+    Specifier(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Export {
+    name: String,
+    origin: Origin,
+}
+
+/// Render the folder export section for the barrels among `paths`.
+///
+/// Returns `None` when no path is a barrel, so directories without barrels
+/// print exactly what they printed before.
+pub(crate) fn render_folder_exports(paths: &[PathBuf]) -> Option<String> {
+    let mut barrels: Vec<&PathBuf> = paths.iter().filter(|path| is_barrel(path)).collect();
+    if barrels.is_empty() {
+        return None;
+    }
+    barrels.sort();
+
+    let mut resolver = Resolver::new()?;
+    let mut out = String::from("// skim: folder exports\n");
+    for barrel in barrels {
+        let exports = resolver.exports(&normalize(barrel));
+        let folder = normalize(barrel.parent().unwrap_or(Path::new("")));
+        let _ = writeln!(out, "// {}", barrel.display());
+        if exports.is_empty() {
+            out.push_str("//   (no exports)\n");
+            continue;
+        }
+        let width = exports.iter().map(|e| e.name.len()).max().unwrap_or(0);
+        for export in &exports {
+            let origin = match &export.origin {
+                Origin::File(path) => relative_to(path, &folder).display().to_string(),
+                Origin::Specifier(spec) if is_relative(spec) => format!("'{spec}' (not found)"),
+                Origin::Specifier(spec) => format!("'{spec}'"),
+            };
+            let _ = writeln!(out, "//   {:<width$}  {origin}", export.name);
+        }
+    }
+    out.push('\n');
+    Some(out)
+}
+
+fn is_barrel(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| BARREL_NAMES.contains(&name))
+}
+
+fn is_relative(spec: &str) -> bool {
+    spec.starts_with("./") || spec.starts_with("../")
+}
+
+/// Resolve a relative module specifier imported from `from`.
+fn resolve(from: &Path, spec: &str) -> Option<PathBuf> {
+    if !is_relative(spec) {
+        return None;
+    }
+    let base = normalize(&from.parent()?.join(spec));
+    let typescript = matches!(
+        base.extension().and_then(|ext| ext.to_str()),
+        Some("ts" | "tsx" | "mts" | "cts")
+    );
+    if typescript && base.is_file() {
+        return Some(base);
+    }
+    let base = base.to_str()?;
+    // ESM-style TypeScript names the emitted `.js` file.
+    let stem = base.strip_suffix(".js").unwrap_or(base);
+    MODULE_SUFFIXES
+        .iter()
+        .map(|suffix| PathBuf::from(format!("{stem}{suffix}")))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Lexically drop `.` and fold `..` components, so a chain that walks up and
+/// back down names each file by one path.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// `path` relative to the folder `base`, walking up with `..` where needed.
+fn relative_to(path: &Path, base: &Path) -> PathBuf {
+    let common = path
+        .components()
+        .zip(base.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut out = PathBuf::new();
+    for _ in base.components().skip(common) {
+        out.push("..");
+    }
+    out.extend(path.components().skip(common));
+    out
+}
+
+/// Export lists per module, memoized across barrels.
+struct Resolver {
+    typescript: Parser,
+    /// `.tsx` modules need the TSX grammar for their JSX.
+    tsx: Parser,
+    modules: HashMap<PathBuf, Vec<Export>>,
+    /// Modules being resolved, to cut `export *` cycles.
+    visiting: HashSet<PathBuf>,
+}
+
+impl Resolver {
+    fn new() -> Option<Self> {
+        Some(Self {
+            typescript: Parser::new(Language::TypeScript).ok()?,
+            tsx: Parser::new(Language::Tsx).ok()?,
+            modules: HashMap::new(),
+            visiting: HashSet::new(),
+        })
+    }
+
+    /// Every name exported by `file`, in source order.
+    fn exports(&mut self, file: &Path) -> Vec<Export> {
+        if let Some(exports) = self.modules.get(file) {
+            return exports.clone();
+        }
+        if !self.visiting.insert(file.to_path_buf()) {
+            return Vec::new();
+        }
+        let exports = self.read_exports(file);
+        self.visiting.remove(file);
+        self.modules.insert(file.to_path_buf(), exports.clone());
+        exports
+    }
+
+    fn read_exports(&mut self, file: &Path) -> Vec<Export> {
+        let Ok(source) = std::fs::read_to_string(file) else {
+            return Vec::new();
+        };
+        let parser = if file.extension().is_some_and(|ext| ext == "tsx") {
+            &mut self.tsx
+        } else {
+            &mut self.typescript
+        };
+        let Ok(tree) = parser.parse(&source) else {
+            return Vec::new();
+        };
+        let root = tree.root_node();
+        let mut cursor = root.walk();
+        let statements: Vec<Node> = root
+            .named_children(&mut cursor)
+            .filter(|node| node.kind() == "export_statement")
+            .collect();
+
+        let mut exports = Vec::new();
+        for statement in statements {
+            self.statement(file, statement, &source, &mut exports);
+        }
+        exports
+    }
+
+    fn statement(&mut self, file: &Path, statement: Node, source: &str, out: &mut Vec<Export>) {
+        let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("");
+        let Some(spec) = statement
+            .child_by_field_name("source")
+            .map(|node| text(node).trim_matches(|c| c == '"' || c == '\'' || c == '`'))
+        else {
+            local_exports(statement, source, &Origin::File(file.to_path_buf()), out);
+            return;
+        };
+
+        let target = resolve(file, spec);
+        let fallback = target
+            .clone()
+            .map_or_else(|| Origin::Specifier(spec.to_string()), Origin::File);
+        let mut cursor = statement.walk();
+        let children: Vec<Node> = statement.children(&mut cursor).collect();
+
+        if let Some(clause) = children.iter().find(|c| c.kind() == "export_clause") {
+            // `export { a, b as c } from './x'`: attribute each name to the
+            // file that declares it when `./x` is itself a barrel.
+            let upstream = target
+                .as_deref()
+                .map(|path| self.exports(path))
+                .unwrap_or_default();
+            for (name, alias) in specifiers(*clause, source) {
+                let origin = upstream
+                    .iter()
+                    .find(|export| export.name == name)
+                    .map_or_else(|| fallback.clone(), |export| export.origin.clone());
+                out.push(Export {
+                    name: alias.to_string(),
+                    origin,
+                });
+            }
+        } else if let Some(namespace) = children.iter().find(|c| c.kind() == "namespace_export") {
+            // `export * as ns from './x'`
+            if let Some(name) = namespace.named_child(0) {
+                out.push(Export {
+                    name: text(name).to_string(),
+                    origin: fallback,
+                });
+            }
+        } else {
+            // `export * from './x'` re-exports everything except the default.
+            match target {
+                Some(path) => out.extend(
+                    self.exports(&path)
+                        .into_iter()
+                        .filter(|export| export.name != "default"),
+                ),
+                None => out.push(Export {
+                    name: "*".to_string(),
+                    origin: fallback,
+                }),
+            }
+        }
+    }
+}
+
+/// Names exported by a statement without a `from` clause.
+fn local_exports(statement: Node, source: &str, origin: &Origin, out: &mut Vec<Export>) {
+    let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("");
+    let mut push = |name: &str| {
+        out.push(Export {
+            name: name.to_string(),
+            origin: origin.clone(),
+        })
+    };
+
+    let mut cursor = statement.walk();
+    let children: Vec<Node> = statement.children(&mut cursor).collect();
+    if children.iter().any(|c| c.kind() == "default") {
+        push("default");
+    } else if let Some(declaration) = statement.child_by_field_name("declaration") {
+        match declaration.kind() {
+            "lexical_declaration" | "variable_declaration" => {
+                let mut cursor = declaration.walk();
+                for declarator in declaration.named_children(&mut cursor) {
+                    if let Some(name) = declarator
+                        .child_by_field_name("name")
+                        .filter(|name| name.kind() == "identifier")
+                    {
+                        push(text(name));
+                    }
+                }
+            }
+            _ => {
+                if let Some(name) = declaration.child_by_field_name("name") {
+                    push(text(name));
+                }
+            }
+        }
+    } else if let Some(clause) = children.iter().find(|c| c.kind() == "export_clause") {
+        for (_, alias) in specifiers(*clause, source) {
+            push(alias);
+        }
+    }
+}
+
+/// `(name, exported as)` pairs of an `export { ... }` clause.
+fn specifiers<'src>(clause: Node, source: &'src str) -> Vec<(&'src str, &'src str)> {
+    let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("");
+    let mut cursor = clause.walk();
+    clause
+        .named_children(&mut cursor)
+        .filter(|node| node.kind() == "export_specifier")
+        .filter_map(|specifier| {
+            let name = text(specifier.child_by_field_name("name")?);
+            let alias = specifier.child_by_field_name("alias").map_or(name, text);
+            Some((name, alias))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) -> PathBuf {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_normalize_folds_parent_components() {
+        assert_eq!(
+            normalize(Path::new("src/a/./../b/index.ts")),
+            PathBuf::from("src/b/index.ts")
+        );
+        assert_eq!(normalize(Path::new("../x.ts")), PathBuf::from("../x.ts"));
+    }
+
+    #[test]
+    fn test_no_barrels_renders_nothing() {
+        let dir = TempDir::new().unwrap();
+        let file = write(dir.path(), "a.ts", "export const a = 1;\n");
+        assert_eq!(render_folder_exports(&[file]), None);
+    }
+
+    #[test]
+    fn test_star_chain_resolves_to_declaring_files() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let barrel = write(root, "index.ts", "export * from './auth';\n");
+        write(
+            root,
+            "auth/index.ts",
+            "export { LoginForm } from './LoginForm';\nexport * from './session.js';\n",
+        );
+        write(
+            root,
+            "auth/LoginForm.tsx",
+            "export function LoginForm() { return null; }\n",
+        );
+        write(
+            root,
+            "auth/session.ts",
+            "export const useSession = () => 1, SESSION_KEY = 'k';\nexport default 1;\n",
+        );
+
+        let out = render_folder_exports(&[barrel]).unwrap();
+        assert!(out.starts_with("// skim: folder exports\n"), "got:\n{out}");
+        assert!(
+            out.contains("LoginForm    auth/LoginForm.tsx"),
+            "got:\n{out}"
+        );
+        assert!(out.contains("useSession   auth/session.ts"), "got:\n{out}");
+        assert!(out.contains("SESSION_KEY  auth/session.ts"), "got:\n{out}");
+        assert!(
+            !out.contains("default"),
+            "export * skips the default export, got:\n{out}"
+        );
+    }
+
+    #[test]
+    fn test_aliases_namespaces_and_packages() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let barrel = write(
+            root,
+            "index.ts",
+            "export { Button as PrimaryButton } from './button';\n\
+             export * as icons from './icons';\n\
+             export * from 'shared-ui';\n\
+             export * from './missing';\n",
+        );
+        write(root, "button.ts", "export class Button {}\n");
+        write(root, "icons/index.ts", "export const Star = 1;\n");
+
+        let out = render_folder_exports(&[barrel]).unwrap();
+        assert!(out.contains("PrimaryButton  button.ts"), "got:\n{out}");
+        assert!(out.contains("icons          icons/index.ts"), "got:\n{out}");
+        assert!(out.contains("*              'shared-ui'\n"), "got:\n{out}");
+        assert!(out.contains("'./missing' (not found)"), "got:\n{out}");
+    }
+
+    #[test]
+    fn test_cycles_terminate() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let barrel = write(
+            root,
+            "a/index.ts",
+            "export * from '../b';\nexport const A = 1;\n",
+        );
+        write(
+            root,
+            "b/index.ts",
+            "export * from '../a';\nexport const B = 1;\n",
+        );
+
+        let out = render_folder_exports(&[barrel]).unwrap();
+        assert!(out.contains("B  ../b/index.ts"), "got:\n{out}");
+        assert!(out.contains("A  index.ts"), "got:\n{out}");
+    }
+}
@@ -6,6 +6,24 @@
 //! - Invalidation: File mtime change or mode change
 //! - Storage format: JSON with metadata
 //!
+//! # Key backends
+//!
+//! [`CacheKeys::Absolute`] (the default) identifies a source by its canonical
+//! path and mtime, so the same repo mounted at two paths never shares entries.
+//! [`CacheKeys::RepoRelative`] identifies it by its path below the enclosing
+//! git root (always `/`-separated) and a SHA-256 of its contents instead:
+//! entries stay valid across containers, worktrees and machines, at the cost
+//! of reading the source on every lookup. Files outside a git repository fall
+//! back to absolute keys.
+//!
+//! # Failure handling
+//!
+//! The cache is an optimisation: every failure degrades to a cache miss and
+//! never fails the transformation. Entries are written to a temporary file
+//! and renamed into place, so a full disk or a crash mid-write leaves no
+//! truncated entry behind. All directory and file access goes through the
+//! [`CacheFs`] seam so these paths are tested with injected faults.
+//!
 //! # Cache-directory resolution (PF-002 fix)
 //!
 //! All skim cache subsystems (parser cache, tee output, default analytics.db)
@@ -17,10 +35,12 @@ use rskim_core::Mode;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::fs;
-use std::path::{Path, PathBuf};
-use std::time::SystemTime;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
 
-use crate::cascade::TruncationOptions;
+use crate::cascade::{TransformFlags, TruncationOptions};
 
 // ============================================================================
 // Single-source-of-truth cache-root resolvers (fixes PF-002)
@@ -73,13 +93,111 @@ pub(crate) fn cache_root() -> Option<PathBuf> {
     cache_root_from(read_cache_dir_env())
 }
 
+// ============================================================================
+// Source identity
+// ============================================================================
+
+/// How cache entries identify their source file (`--cache-keys`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum CacheKeys {
+    /// Canonical absolute path + mtime.
+    #[default]
+    Absolute,
+    /// Path relative to the enclosing git root + content hash.
+    RepoRelative,
+}
+
+/// The part of a cache key that identifies the source file and its version.
+#[derive(Debug)]
+struct SourceStamp {
+    /// Canonical absolute path, or `/`-separated path below the git root.
+    location: String,
+    /// Canonical absolute path, recorded in entries so `verify-cache` can
+    /// tell when the source is gone.
+    canonical_path: PathBuf,
+    /// File modification time (seconds since UNIX epoch).
+    mtime_secs: u64,
+    /// Hex SHA-256 of the contents; set only for repo-relative stamps.
+    content_sha256: Option<String>,
+}
+
+impl SourceStamp {
+    fn of(path: &Path, keys: CacheKeys) -> Result<Self> {
+        let mtime = fs::metadata(path)?.modified()?;
+        let mtime_secs = mtime.duration_since(SystemTime::UNIX_EPOCH)?.as_secs();
+        let canonical_path = path.canonicalize()?;
+
+        if keys == CacheKeys::RepoRelative
+            && let Some(location) = repo_relative_path(&canonical_path)
+        {
+            let content_sha256 = format!("{:x}", Sha256::digest(fs::read(path)?));
+            return Ok(Self {
+                location,
+                canonical_path,
+                mtime_secs,
+                content_sha256: Some(content_sha256),
+            });
+        }
+
+        Ok(Self {
+            location: canonical_path.display().to_string(),
+            canonical_path,
+            mtime_secs,
+            content_sha256: None,
+        })
+    }
+
+    /// Leading key component. Absolute stamps keep the historical
+    /// `path|mtime` layout so existing entries stay valid.
+    fn key_fragment(&self) -> String {
+        match &self.content_sha256 {
+            Some(hash) => format!("repo:{}|sha256:{hash}", self.location),
+            None => format!("{}|{}", self.location, self.mtime_secs),
+        }
+    }
+
+    /// Whether an entry recorded with `mtime_secs` / `content_sha256` was
+    /// written for this version of the source.
+    fn matches(&self, mtime_secs: u64, content_sha256: Option<&str>) -> bool {
+        match &self.content_sha256 {
+            Some(hash) => content_sha256 == Some(hash.as_str()),
+            None => mtime_secs == self.mtime_secs,
+        }
+    }
+}
+
+/// `canonical_path` relative to its enclosing git root, `/`-separated.
+///
+/// `None` outside a repository or for non-UTF-8 components. A `.git` file
+/// (worktrees, submodules) marks a root just like a `.git` directory.
+fn repo_relative_path(canonical_path: &Path) -> Option<String> {
+    let root = canonical_path
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.join(".git").exists())?;
+    let parts = canonical_path
+        .strip_prefix(root)
+        .ok()?
+        .components()
+        .map(|component| match component {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(parts.join("/"))
+}
+
 /// Cache entry with metadata for validation.
 #[derive(Debug, Serialize, Deserialize)]
 struct CacheEntry {
-    /// Original file path (for debugging).
+    /// Canonical source path (older entries may hold the path as given).
     path: String,
     /// File modification time (seconds since UNIX epoch).
     mtime_secs: u64,
+    /// Source content hash; present only for repo-relative entries, which are
+    /// validated on it instead of `mtime_secs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_sha256: Option<String>,
     /// Transformation mode.
     mode: String,
     /// Cached transformed output.
@@ -118,6 +236,8 @@ pub(crate) struct CacheHit {
 pub(crate) struct CacheWriteParams<'a> {
     /// Path to the source file.
     pub(crate) path: &'a Path,
+    /// How the source file is identified in the key.
+    pub(crate) keys: CacheKeys,
     /// Transformation mode used for the cache key.
     pub(crate) mode: Mode,
     /// Transformed output to cache.
@@ -136,6 +256,82 @@ pub(crate) struct CacheWriteParams<'a> {
     ///
     /// Line-numbered and unnumbered outputs are cached separately because they differ.
     pub(crate) line_numbers: bool,
+    /// Opt-in transform toggles — part of cache key.
+    pub(crate) flags: TransformFlags,
+}
+
+// ============================================================================
+// Filesystem seam
+// ============================================================================
+
+/// Filesystem operations the cache performs inside its own directory.
+///
+/// The only production implementation is [`OsCacheFs`]. The trait lets tests
+/// inject the failures the cache must survive (a disk filling up mid-write,
+/// a read-only cache directory) that cannot be reproduced reliably on a real
+/// filesystem, where root ignores permission bits.
+pub(crate) trait CacheFs {
+    /// Create `dir` and its parents with owner-only permissions.
+    fn create_dir(&self, dir: &Path) -> io::Result<()>;
+
+    /// Read a cache file.
+    fn read(&self, file: &Path) -> io::Result<String>;
+
+    /// Create or truncate `file` with owner-only permissions and write `contents`.
+    fn write(&self, file: &Path, contents: &str) -> io::Result<()>;
+
+    /// Replace `to` with `from` atomically.
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+
+    /// Remove a cache file.
+    fn remove(&self, file: &Path) -> io::Result<()>;
+}
+
+/// [`CacheFs`] backed by `std::fs`.
+pub(crate) struct OsCacheFs;
+
+impl CacheFs for OsCacheFs {
+    fn create_dir(&self, dir: &Path) -> io::Result<()> {
+        #[cfg(unix)]
+        {
+            use std::fs::DirBuilder;
+            use std::os::unix::fs::DirBuilderExt;
+
+            let mut builder = DirBuilder::new();
+            builder.mode(0o700); // rwx------
+            builder.recursive(true);
+            builder.create(dir)
+        }
+
+        #[cfg(not(unix))]
+        {
+            fs::create_dir_all(dir)
+        }
+    }
+
+    fn read(&self, file: &Path) -> io::Result<String> {
+        fs::read_to_string(file)
+    }
+
+    fn write(&self, file: &Path, contents: &str) -> io::Result<()> {
+        fs::write(file, contents)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(file, fs::Permissions::from_mode(0o600))?;
+        }
+
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        fs::rename(from, to)
+    }
+
+    fn remove(&self, file: &Path) -> io::Result<()> {
+        fs::remove_file(file)
+    }
 }
 
 /// Returns the skim cache directory, creating it with owner-only permissions if it does not
@@ -145,116 +341,119 @@ pub(crate) struct CacheWriteParams<'a> {
 /// function — parser cache, tee output, and the default analytics.db path — relocate
 /// consistently when the env var is set. Fixes PF-002.
 pub(crate) fn get_cache_dir() -> Result<PathBuf> {
+    cache_dir_in(&OsCacheFs)
+}
+
+fn cache_dir_in(cache_fs: &dyn CacheFs) -> Result<PathBuf> {
     let cache_dir =
         cache_root().ok_or_else(|| anyhow::anyhow!("Failed to determine cache directory"))?;
-
-    #[cfg(unix)]
-    {
-        use std::fs::DirBuilder;
-        use std::os::unix::fs::DirBuilderExt;
-
-        let mut builder = DirBuilder::new();
-        builder.mode(0o700); // rwx------
-        builder.recursive(true);
-        builder.create(&cache_dir)?;
-    }
-
-    #[cfg(not(unix))]
-    {
-        fs::create_dir_all(&cache_dir)?;
-    }
-
+    cache_fs.create_dir(&cache_dir)?;
     Ok(cache_dir)
 }
 
-/// Generate cache key from file path, mtime, mode, truncation options, and line_numbers flag.
+/// Generate cache key from the source stamp, mode, truncation options, line_numbers flag,
+/// and transform flags.
 ///
-/// `line_numbers` is included in the key because line-numbered and unnumbered outputs
-/// differ in content and should be cached independently.
+/// `line_numbers` and `flags` are included in the key because they change the output
+/// content, so each combination must be cached independently.
 fn cache_key(
-    path: &Path,
-    mtime: SystemTime,
+    stamp: &SourceStamp,
     mode: Mode,
     trunc: &TruncationOptions,
     line_numbers: bool,
-) -> Result<String> {
-    let canonical_path = path.canonicalize()?;
-    let mtime_secs = mtime.duration_since(SystemTime::UNIX_EPOCH)?.as_secs();
-
+    flags: &TransformFlags,
+) -> String {
     let opt_str = |opt: Option<usize>| opt.map_or("none".to_string(), |n| n.to_string());
 
     let hash_input = format!(
-        "{}|{}|{:?}|{}|{}|{}|{}",
-        canonical_path.display(),
-        mtime_secs,
+        "{}|{:?}|{}|{}|{}|{}|{}",
+        stamp.key_fragment(),
         mode,
         opt_str(trunc.max_lines),
         opt_str(trunc.last_lines),
         opt_str(trunc.token_budget),
         line_numbers as u8,
+        flags.key_fragment(),
     );
 
     let mut hasher = Sha256::new();
     hasher.update(hash_input.as_bytes());
 
-    Ok(format!("{:x}", hasher.finalize()))
+    format!("{:x}", hasher.finalize())
 }
 
-/// Read cached output if valid (mtime matches).
+/// Read cached output if valid (mtime, or content hash for repo-relative keys, matches).
 ///
-/// Returns a [`CacheHit`] on cache hit, `None` on miss.
+/// Returns a [`CacheHit`] on cache hit, `None` on miss. Every failure (source
+/// gone, cache directory unusable, unreadable or corrupt entry) is a miss.
 pub(crate) fn read_cache(
     path: &Path,
+    keys: CacheKeys,
     mode: Mode,
     trunc: &TruncationOptions,
     line_numbers: bool,
+    flags: &TransformFlags,
 ) -> Option<CacheHit> {
-    let metadata = fs::metadata(path).ok()?;
-    let mtime = metadata.modified().ok()?;
+    read_cache_in(&OsCacheFs, path, keys, mode, trunc, line_numbers, flags)
+}
 
-    let key = cache_key(path, mtime, mode, trunc, line_numbers).ok()?;
-    let cache_file = get_cache_dir().ok()?.join(format!("{key}.json"));
+fn read_cache_in(
+    cache_fs: &dyn CacheFs,
+    path: &Path,
+    keys: CacheKeys,
+    mode: Mode,
+    trunc: &TruncationOptions,
+    line_numbers: bool,
+    flags: &TransformFlags,
+) -> Option<CacheHit> {
+    let stamp = SourceStamp::of(path, keys).ok()?;
 
-    let cache_content = fs::read_to_string(&cache_file).ok()?;
+    let key = cache_key(&stamp, mode, trunc, line_numbers, flags);
+    let cache_file = cache_dir_in(cache_fs).ok()?.join(format!("{key}.json"));
+
+    let cache_content = cache_fs.read(&cache_file).ok()?;
     let entry: CacheEntry = serde_json::from_str(&cache_content).ok()?;
 
-    // Belt-and-suspenders validation: verify mtime/mode match even though
-    // they are already encoded in the cache key hash (guards against collisions).
-    let mtime_secs = mtime.duration_since(SystemTime::UNIX_EPOCH).ok()?.as_secs();
+    // Belt-and-suspenders validation: verify source version/mode match even
+    // though they are already encoded in the cache key hash (guards against collisions).
     let mode_str = format!("{mode:?}");
 
-    if entry.mtime_secs == mtime_secs && entry.mode == mode_str {
+    if stamp.matches(entry.mtime_secs, entry.content_sha256.as_deref()) && entry.mode == mode_str {
         Some(CacheHit {
             content: entry.content,
             original_tokens: entry.original_tokens,
             transformed_tokens: entry.transformed_tokens,
         })
     } else {
-        // Stale entry: best-effort cleanup.
-        let _ = fs::remove_file(&cache_file);
+        // Stale entry: best-effort cleanup. A failed removal only means the
+        // entry is overwritten by the next write instead.
+        let _ = cache_fs.remove(&cache_file);
         None
     }
 }
 
 /// Write transformed output to cache.
 pub(crate) fn write_cache(params: &CacheWriteParams<'_>) -> Result<()> {
-    let metadata = fs::metadata(params.path)?;
-    let mtime = metadata.modified()?;
+    write_cache_in(&OsCacheFs, params)
+}
+
+fn write_cache_in(cache_fs: &dyn CacheFs, params: &CacheWriteParams<'_>) -> Result<()> {
+    let stamp = SourceStamp::of(params.path, params.keys)?;
 
     let key = cache_key(
-        params.path,
-        mtime,
+        &stamp,
         params.mode,
         &params.trunc,
         params.line_numbers,
-    )?;
-    let cache_file = get_cache_dir()?.join(format!("{key}.json"));
+        &params.flags,
+    );
+    let cache_file = cache_dir_in(cache_fs)?.join(format!("{key}.json"));
 
-    let mtime_secs = mtime.duration_since(SystemTime::UNIX_EPOCH)?.as_secs();
     let mode = params.mode;
     let entry = CacheEntry {
-        path: params.path.display().to_string(),
-        mtime_secs,
+        path: stamp.canonical_path.display().to_string(),
+        mtime_secs: stamp.mtime_secs,
+        content_sha256: stamp.content_sha256,
         mode: format!("{mode:?}"),
         content: params.content.to_string(),
         original_tokens: params.original_tokens,
@@ -263,15 +462,175 @@ pub(crate) fn write_cache(params: &CacheWriteParams<'_>) -> Result<()> {
         parse_tier: params.parse_tier.clone(),
     };
 
-    let json = serde_json::to_string(&entry)?;
-    fs::write(&cache_file, json)?;
+    write_private(cache_fs, &cache_file, &serde_json::to_string(&entry)?)
+}
+
+/// Source-file token count, cached independently of mode and options.
+///
+/// The original count only depends on the file contents, so one entry serves
+/// every mode/flag combination: switching `--mode` with `--show-stats` re-uses
+/// it instead of re-tokenizing the source.
+#[derive(Debug, Serialize, Deserialize)]
+struct SourceTokensEntry {
+    /// Canonical source path (absent in entries written before `verify-cache`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// File modification time (seconds since UNIX epoch).
+    mtime_secs: u64,
+    /// Source content hash (repo-relative entries only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_sha256: Option<String>,
+    /// Token count of the unmodified source.
+    original_tokens: usize,
+}
+
+/// Cache key for a file's source token count (source stamp only).
+fn source_tokens_key(stamp: &SourceStamp) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}|source-tokens", stamp.key_fragment()).as_bytes());
+
+    format!("{:x}", hasher.finalize())
+}
+
+/// Read the cached token count of a file's unmodified source, if still valid.
+pub(crate) fn read_original_tokens(path: &Path, keys: CacheKeys) -> Option<usize> {
+    let stamp = SourceStamp::of(path, keys).ok()?;
+    let key = source_tokens_key(&stamp);
+    let cache_file = get_cache_dir().ok()?.join(format!("{key}.json"));
+
+    let entry: SourceTokensEntry =
+        serde_json::from_str(&fs::read_to_string(&cache_file).ok()?).ok()?;
+
+    stamp
+        .matches(entry.mtime_secs, entry.content_sha256.as_deref())
+        .then_some(entry.original_tokens)
+}
+
+/// Cache the token count of a file's unmodified source.
+pub(crate) fn write_original_tokens(
+    path: &Path,
+    keys: CacheKeys,
+    original_tokens: usize,
+) -> Result<()> {
+    let stamp = SourceStamp::of(path, keys)?;
+    let key = source_tokens_key(&stamp);
+    let cache_file = get_cache_dir()?.join(format!("{key}.json"));
+
+    let entry = SourceTokensEntry {
+        path: Some(stamp.canonical_path.display().to_string()),
+        mtime_secs: stamp.mtime_secs,
+        content_sha256: stamp.content_sha256,
+        original_tokens,
+    };
+    write_private(&OsCacheFs, &cache_file, &serde_json::to_string(&entry)?)
+}
+
+/// Digest of the output last emitted for a file under one set of options
+/// (`--changed-only`).
+///
+/// Keyed without the source version, so the entry outlives edits to the file
+/// and the next run can compare its fresh output against it.
+#[derive(Debug, Serialize, Deserialize)]
+struct LastOutputEntry {
+    /// Canonical source path.
+    path: String,
+    /// Hex SHA-256 of the emitted output.
+    output_sha256: String,
+}
+
+/// Cache key for a file's last emitted output: everything [`cache_key`]
+/// covers except the source version.
+fn last_output_key(
+    stamp: &SourceStamp,
+    mode: Mode,
+    trunc: &TruncationOptions,
+    line_numbers: bool,
+    flags: &TransformFlags,
+) -> String {
+    let opt_str = |opt: Option<usize>| opt.map_or("none".to_string(), |n| n.to_string());
+
+    let hash_input = format!(
+        "{}|last-output|{:?}|{}|{}|{}|{}|{}",
+        stamp.location,
+        mode,
+        opt_str(trunc.max_lines),
+        opt_str(trunc.last_lines),
+        opt_str(trunc.token_budget),
+        line_numbers as u8,
+        flags.key_fragment(),
+    );
+
+    format!("{:x}", Sha256::digest(hash_input.as_bytes()))
+}
 
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        fs::set_permissions(&cache_file, fs::Permissions::from_mode(0o600))?;
+/// Record `output` as the latest output for `path` and report whether it is
+/// identical to the output recorded by the previous run with the same options.
+///
+/// A file seen for the first time, or whose entry cannot be read, counts as
+/// changed. Failing to record is ignored: the next run then reports the file
+/// as changed again, which is the safe direction.
+pub(crate) fn output_unchanged_since_last_run(
+    path: &Path,
+    keys: CacheKeys,
+    mode: Mode,
+    trunc: &TruncationOptions,
+    line_numbers: bool,
+    flags: &TransformFlags,
+    output: &str,
+) -> bool {
+    let Ok(stamp) = SourceStamp::of(path, keys) else {
+        return false;
+    };
+    let Ok(cache_dir) = get_cache_dir() else {
+        return false;
+    };
+    let key = last_output_key(&stamp, mode, trunc, line_numbers, flags);
+    let cache_file = cache_dir.join(format!("{key}.json"));
+    let output_sha256 = format!("{:x}", Sha256::digest(output.as_bytes()));
+
+    let previous = fs::read_to_string(&cache_file)
+        .ok()
+        .and_then(|json| serde_json::from_str::<LastOutputEntry>(&json).ok());
+    if previous.is_some_and(|entry| entry.output_sha256 == output_sha256) {
+        return true;
+    }
+
+    let entry = LastOutputEntry {
+        path: stamp.canonical_path.display().to_string(),
+        output_sha256,
+    };
+    if let Ok(json) = serde_json::to_string(&entry) {
+        let _ = write_private(&OsCacheFs, &cache_file, &json);
     }
+    false
+}
 
+/// Infix of in-flight entry names: `<key>.json.tmp-<pid>-<n>`.
+const TMP_MARKER: &str = ".tmp-";
+
+/// Write a cache file with owner-only permissions, atomically.
+///
+/// The entry is written to a per-process temporary file and renamed over
+/// `cache_file`, so readers never see a partial entry. On failure the
+/// temporary file is removed and the previous entry, if any, is left intact.
+fn write_private(cache_fs: &dyn CacheFs, cache_file: &Path, json: &str) -> Result<()> {
+    static NEXT_TMP: AtomicUsize = AtomicUsize::new(0);
+
+    let mut tmp_name = cache_file.as_os_str().to_owned();
+    tmp_name.push(format!(
+        "{TMP_MARKER}{}-{}",
+        std::process::id(),
+        NEXT_TMP.fetch_add(1, Ordering::Relaxed)
+    ));
+    let tmp_file = PathBuf::from(tmp_name);
+
+    let written = cache_fs
+        .write(&tmp_file, json)
+        .and_then(|()| cache_fs.rename(&tmp_file, cache_file));
+    if let Err(e) = written {
+        let _ = cache_fs.remove(&tmp_file);
+        return Err(anyhow::Error::new(e).context(format!("writing {}", cache_file.display())));
+    }
     Ok(())
 }
 
@@ -287,8 +646,13 @@ pub(crate) fn clear_cache() -> Result<()> {
         for entry in fs::read_dir(&cache_dir)? {
             let entry = entry?;
             let path = entry.path();
-            // Only remove JSON cache files; skip analytics.db and other non-cache files.
-            if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            // Only remove JSON cache files (and temporaries left by an interrupted
+            // write); skip analytics.db and other non-cache files.
+            let is_entry = path.extension().is_some_and(|ext| ext == "json")
+                || path
+                    .to_str()
+                    .is_some_and(|p| p.contains(&format!(".json{TMP_MARKER}")));
+            if path.is_file() && is_entry {
                 // Best-effort removal; ignore errors from concurrent access.
                 let _ = fs::remove_file(&path);
             }
@@ -298,12 +662,226 @@ pub(crate) fn clear_cache() -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// Maintenance (`skim verify-cache`)
+// ============================================================================
+
+/// Temporaries younger than this may belong to a write still in flight.
+const LEFTOVER_GRACE: Duration = Duration::from_secs(10 * 60);
+
+/// Options for [`verify_cache`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct VerifyOptions {
+    /// Delete flagged entries (otherwise only report them).
+    pub(crate) prune: bool,
+    /// Flag entries written before this time.
+    pub(crate) expire_before: Option<SystemTime>,
+}
+
+/// Entry count and on-disk size of one category.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct Tally {
+    pub(crate) entries: usize,
+    pub(crate) bytes: u64,
+}
+
+impl Tally {
+    fn add(&mut self, bytes: u64) {
+        self.entries += 1;
+        self.bytes += bytes;
+    }
+}
+
+/// Outcome of a cache scan. Every flagged entry lands in exactly one
+/// category, checked in field order.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct VerifyReport {
+    /// Every entry and temporary inspected.
+    pub(crate) scanned: Tally,
+    /// Not valid JSON, or not a recognised entry shape.
+    pub(crate) corrupt: Tally,
+    /// Temporaries left behind by an interrupted write.
+    pub(crate) leftover: Tally,
+    /// Absolute-key entries whose source file no longer exists.
+    pub(crate) orphaned: Tally,
+    /// Absolute-key entries whose source changed since they were written;
+    /// their key can never be looked up again.
+    pub(crate) stale: Tally,
+    /// Entries written before [`VerifyOptions::expire_before`].
+    pub(crate) expired: Tally,
+    /// Flagged entries actually deleted (always zero without `prune`).
+    pub(crate) removed: Tally,
+}
+
+impl VerifyReport {
+    /// Everything flagged, whether or not it was removed.
+    pub(crate) fn reclaimable(&self) -> Tally {
+        let flagged = [
+            self.corrupt,
+            self.leftover,
+            self.orphaned,
+            self.stale,
+            self.expired,
+        ];
+        Tally {
+            entries: flagged.iter().map(|t| t.entries).sum(),
+            bytes: flagged.iter().map(|t| t.bytes).sum(),
+        }
+    }
+}
+
+/// Scan the cache directory, flag unusable entries and optionally delete them.
+///
+/// A missing cache directory is an empty, healthy cache.
+pub(crate) fn verify_cache(options: &VerifyOptions) -> Result<VerifyReport> {
+    let cache_dir =
+        cache_root().ok_or_else(|| anyhow::anyhow!("Failed to determine cache directory"))?;
+    verify_cache_dir(&cache_dir, options, SystemTime::now())
+}
+
+fn verify_cache_dir(
+    cache_dir: &Path,
+    options: &VerifyOptions,
+    now: SystemTime,
+) -> Result<VerifyReport> {
+    let mut report = VerifyReport::default();
+    let entries = match fs::read_dir(cache_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(report),
+        Err(e) => {
+            return Err(anyhow::Error::new(e).context(format!("reading {}", cache_dir.display())));
+        }
+    };
+
+    for entry in entries {
+        let path = entry?.path();
+        // Entries vanishing mid-scan (concurrent --clear-cache) are skipped.
+        let Ok(meta) = fs::metadata(&path) else {
+            continue;
+        };
+        if !meta.is_file() {
+            continue;
+        }
+        let written = meta.modified().unwrap_or(now);
+
+        let is_tmp = path
+            .to_str()
+            .is_some_and(|p| p.contains(&format!(".json{TMP_MARKER}")));
+        let category = if is_tmp {
+            let age = now.duration_since(written).unwrap_or_default();
+            report.scanned.add(meta.len());
+            if age < LEFTOVER_GRACE {
+                continue;
+            }
+            &mut report.leftover
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            report.scanned.add(meta.len());
+            match inspect_entry(&path) {
+                Some(problem) => problem.category(&mut report),
+                None if options.expire_before.is_some_and(|cutoff| written < cutoff) => {
+                    &mut report.expired
+                }
+                None => continue,
+            }
+        } else {
+            // analytics.db, hook stamps and other non-cache files.
+            continue;
+        };
+        category.add(meta.len());
+
+        if options.prune && fs::remove_file(&path).is_ok() {
+            report.removed.add(meta.len());
+        }
+    }
+
+    Ok(report)
+}
+
+/// Why [`inspect_entry`] flagged an entry.
+enum EntryProblem {
+    Corrupt,
+    Orphaned,
+    Stale,
+}
+
+impl EntryProblem {
+    fn category(self, report: &mut VerifyReport) -> &mut Tally {
+        match self {
+            Self::Corrupt => &mut report.corrupt,
+            Self::Orphaned => &mut report.orphaned,
+            Self::Stale => &mut report.stale,
+        }
+    }
+}
+
+/// Validate one entry file against its source, `None` when it is usable.
+///
+/// Only absolute-key entries are checked against their source. Repo-relative
+/// entries are shared between checkouts, so a path missing or different on
+/// this machine says nothing about the others; only the TTL prunes them.
+/// Entries recording a relative path (written before paths were
+/// canonicalised) cannot be resolved and are kept.
+fn inspect_entry(path: &Path) -> Option<EntryProblem> {
+    let Ok(json) = fs::read_to_string(path) else {
+        return Some(EntryProblem::Corrupt);
+    };
+    // Try the richer shape first: a transform entry with token counts also
+    // parses as a source-tokens entry.
+    let (source, mtime_secs, content_sha256) =
+        if let Ok(entry) = serde_json::from_str::<CacheEntry>(&json) {
+            (Some(entry.path), entry.mtime_secs, entry.content_sha256)
+        } else if let Ok(entry) = serde_json::from_str::<SourceTokensEntry>(&json) {
+            (entry.path, entry.mtime_secs, entry.content_sha256)
+        } else if let Ok(entry) = serde_json::from_str::<LastOutputEntry>(&json) {
+            // Not tied to a source version: only a deleted source retires it.
+            return (!Path::new(&entry.path).exists()).then_some(EntryProblem::Orphaned);
+        } else {
+            return Some(EntryProblem::Corrupt);
+        };
+
+    if content_sha256.is_some() {
+        return None;
+    }
+    let source = PathBuf::from(source?);
+    if !source.is_absolute() {
+        return None;
+    }
+    let meta = match fs::metadata(&source) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Some(EntryProblem::Orphaned),
+        // Unreadable (permissions, stale mount): not evidence the source is gone.
+        Err(_) => return None,
+    };
+    let current = meta
+        .modified()
+        .ok()?
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .ok()?
+        .as_secs();
+    (current != mtime_secs).then_some(EntryProblem::Stale)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
+    const NO_FLAGS: TransformFlags = TransformFlags {
+        fenced_code: false,
+        template_threshold: None,
+        public_only: false,
+        module_doc: false,
+        python_stub: false,
+        dot_graph: false,
+        todo_bodies: false,
+        max_params: None,
+        wrap_width: None,
+        max_nesting: None,
+        body_statements: 0,
+        query: None,
+    };
+
     // ========================================================================
     // C2: single-source-of-truth contract
     // cache::cache_root() and cmd::resolve_cache_dir() (which delegates to
@@ -407,18 +985,17 @@ mod tests {
         write!(temp_file, "test content").unwrap();
         let path = temp_file.path();
 
-        let metadata = fs::metadata(path).unwrap();
-        let mtime = metadata.modified().unwrap();
+        let stamp = SourceStamp::of(path, CacheKeys::Absolute).unwrap();
 
         let default_trunc = TruncationOptions::default();
 
         // Same inputs should produce same key
-        let key1 = cache_key(path, mtime, Mode::Structure, &default_trunc, false).unwrap();
-        let key2 = cache_key(path, mtime, Mode::Structure, &default_trunc, false).unwrap();
+        let key1 = cache_key(&stamp, Mode::Structure, &default_trunc, false, &NO_FLAGS);
+        let key2 = cache_key(&stamp, Mode::Structure, &default_trunc, false, &NO_FLAGS);
         assert_eq!(key1, key2);
 
         // Different mode should produce different key
-        let key3 = cache_key(path, mtime, Mode::Signatures, &default_trunc, false).unwrap();
+        let key3 = cache_key(&stamp, Mode::Signatures, &default_trunc, false, &NO_FLAGS);
         assert_ne!(key1, key3);
 
         // Different max_lines should produce different key
@@ -426,11 +1003,11 @@ mod tests {
             max_lines: Some(50),
             ..Default::default()
         };
-        let key4 = cache_key(path, mtime, Mode::Structure, &trunc_max, false).unwrap();
+        let key4 = cache_key(&stamp, Mode::Structure, &trunc_max, false, &NO_FLAGS);
         assert_ne!(key1, key4);
 
         // Same max_lines should produce same key
-        let key5 = cache_key(path, mtime, Mode::Structure, &trunc_max, false).unwrap();
+        let key5 = cache_key(&stamp, Mode::Structure, &trunc_max, false, &NO_FLAGS);
         assert_eq!(key4, key5);
 
         // Different token_budget should produce different key
@@ -438,11 +1015,11 @@ mod tests {
             token_budget: Some(500),
             ..Default::default()
         };
-        let key6 = cache_key(path, mtime, Mode::Structure, &trunc_budget, false).unwrap();
+        let key6 = cache_key(&stamp, Mode::Structure, &trunc_budget, false, &NO_FLAGS);
         assert_ne!(key1, key6);
 
         // Same token_budget should produce same key
-        let key7 = cache_key(path, mtime, Mode::Structure, &trunc_budget, false).unwrap();
+        let key7 = cache_key(&stamp, Mode::Structure, &trunc_budget, false, &NO_FLAGS);
         assert_eq!(key6, key7);
 
         // Different max_lines + token_budget combination
@@ -451,7 +1028,7 @@ mod tests {
             token_budget: Some(500),
             ..Default::default()
         };
-        let key8 = cache_key(path, mtime, Mode::Structure, &trunc_both, false).unwrap();
+        let key8 = cache_key(&stamp, Mode::Structure, &trunc_both, false, &NO_FLAGS);
         assert_ne!(key4, key8);
         assert_ne!(key6, key8);
 
@@ -460,16 +1037,126 @@ mod tests {
             last_lines: Some(10),
             ..Default::default()
         };
-        let key9 = cache_key(path, mtime, Mode::Structure, &trunc_last, false).unwrap();
+        let key9 = cache_key(&stamp, Mode::Structure, &trunc_last, false, &NO_FLAGS);
         assert_ne!(key1, key9);
 
         // Same last_lines should produce same key
-        let key10 = cache_key(path, mtime, Mode::Structure, &trunc_last, false).unwrap();
+        let key10 = cache_key(&stamp, Mode::Structure, &trunc_last, false, &NO_FLAGS);
         assert_eq!(key9, key10);
 
         // Different line_numbers should produce different key
-        let key11 = cache_key(path, mtime, Mode::Structure, &default_trunc, true).unwrap();
+        let key11 = cache_key(&stamp, Mode::Structure, &default_trunc, true, &NO_FLAGS);
         assert_ne!(key1, key11);
+
+        // Different transform flags should produce different key
+        let fenced = TransformFlags {
+            fenced_code: true,
+            ..NO_FLAGS
+        };
+        let key12 = cache_key(&stamp, Mode::Structure, &default_trunc, false, &fenced);
+        assert_ne!(key1, key12);
+
+        let no_templates = TransformFlags {
+            template_threshold: Some(0),
+            ..NO_FLAGS
+        };
+        let key13 = cache_key(
+            &stamp,
+            Mode::Structure,
+            &default_trunc,
+            false,
+            &no_templates,
+        );
+        assert_ne!(key1, key13);
+
+        let public_only = TransformFlags {
+            public_only: true,
+            ..NO_FLAGS
+        };
+        let key14 = cache_key(&stamp, Mode::Structure, &default_trunc, false, &public_only);
+        assert_ne!(key1, key14);
+
+        let module_doc = TransformFlags {
+            module_doc: true,
+            ..NO_FLAGS
+        };
+        let key15 = cache_key(&stamp, Mode::Structure, &default_trunc, false, &module_doc);
+        assert_ne!(key1, key15);
+
+        let python_stub = TransformFlags {
+            python_stub: true,
+            ..NO_FLAGS
+        };
+        let key16 = cache_key(
+            &stamp,
+            Mode::Signatures,
+            &default_trunc,
+            false,
+            &python_stub,
+        );
+        let key17 = cache_key(&stamp, Mode::Signatures, &default_trunc, false, &NO_FLAGS);
+        assert_ne!(key16, key17);
+
+        let todo_bodies = TransformFlags {
+            todo_bodies: true,
+            ..NO_FLAGS
+        };
+        let key18 = cache_key(&stamp, Mode::Structure, &default_trunc, false, &todo_bodies);
+        assert_ne!(key1, key18);
+
+        let max_params = TransformFlags {
+            max_params: Some(8),
+            ..NO_FLAGS
+        };
+        let key19 = cache_key(&stamp, Mode::Signatures, &default_trunc, false, &max_params);
+        assert_ne!(key17, key19);
+
+        let wrap = TransformFlags {
+            wrap_width: Some(80),
+            ..NO_FLAGS
+        };
+        let key20 = cache_key(&stamp, Mode::Signatures, &default_trunc, false, &wrap);
+        assert_ne!(key17, key20);
+
+        let max_nesting = TransformFlags {
+            max_nesting: Some(1),
+            ..NO_FLAGS
+        };
+        let key21 = cache_key(&stamp, Mode::Structure, &default_trunc, false, &max_nesting);
+        assert_ne!(key1, key21);
+
+        let dot_graph = TransformFlags {
+            dot_graph: true,
+            ..NO_FLAGS
+        };
+        let key22 = cache_key(&stamp, Mode::CallGraph, &default_trunc, false, &dot_graph);
+        let key23 = cache_key(&stamp, Mode::CallGraph, &default_trunc, false, &NO_FLAGS);
+        assert_ne!(key22, key23);
+
+        let body_statements = TransformFlags {
+            body_statements: 2,
+            ..NO_FLAGS
+        };
+        let key24 = cache_key(
+            &stamp,
+            Mode::Structure,
+            &default_trunc,
+            false,
+            &body_statements,
+        );
+        assert_ne!(key1, key24);
+
+        let query = TransformFlags {
+            query: Some("(function_item) @f"),
+            ..NO_FLAGS
+        };
+        let other_query = TransformFlags {
+            query: Some("(struct_item) @s"),
+            ..NO_FLAGS
+        };
+        let key25 = cache_key(&stamp, Mode::Query, &default_trunc, false, &query);
+        let key26 = cache_key(&stamp, Mode::Query, &default_trunc, false, &other_query);
+        assert_ne!(key25, key26);
     }
 
     #[test]
@@ -480,12 +1167,23 @@ mod tests {
         let default_trunc = TruncationOptions::default();
 
         // Initially no cache
-        assert!(read_cache(&path, Mode::Structure, &default_trunc, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &default_trunc,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
 
         // Write to cache with token counts
         let content = "transformed output";
         write_cache(&CacheWriteParams {
             path: &path,
+            keys: CacheKeys::Absolute,
             mode: Mode::Structure,
             content,
             original_tokens: Some(100),
@@ -494,38 +1192,87 @@ mod tests {
             effective_mode: None,
             parse_tier: None,
             line_numbers: false,
+            flags: TransformFlags::default(),
         })
         .unwrap();
 
         // Read from cache
-        let hit = read_cache(&path, Mode::Structure, &default_trunc, false).unwrap();
+        let hit = read_cache(
+            &path,
+            CacheKeys::Absolute,
+            Mode::Structure,
+            &default_trunc,
+            false,
+            &NO_FLAGS,
+        )
+        .unwrap();
         assert_eq!(hit.content, content);
         assert_eq!(hit.original_tokens, Some(100));
         assert_eq!(hit.transformed_tokens, Some(50));
 
         // Different mode should not find cache
-        assert!(read_cache(&path, Mode::Signatures, &default_trunc, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Signatures,
+                &default_trunc,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
 
         // Different max_lines should not find cache
         let trunc_max = TruncationOptions {
             max_lines: Some(50),
             ..Default::default()
         };
-        assert!(read_cache(&path, Mode::Structure, &trunc_max, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &trunc_max,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
 
         // Different last_lines should not find cache
         let trunc_last = TruncationOptions {
             last_lines: Some(10),
             ..Default::default()
         };
-        assert!(read_cache(&path, Mode::Structure, &trunc_last, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &trunc_last,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
 
         // Different token_budget should not find cache
         let trunc_budget = TruncationOptions {
             token_budget: Some(500),
             ..Default::default()
         };
-        assert!(read_cache(&path, Mode::Structure, &trunc_budget, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &trunc_budget,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
     }
 
     #[test]
@@ -540,11 +1287,22 @@ mod tests {
         };
 
         // No cache initially
-        assert!(read_cache(&path, Mode::Structure, &trunc, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &trunc,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
 
         // Write with token_budget
         write_cache(&CacheWriteParams {
             path: &path,
+            keys: CacheKeys::Absolute,
             mode: Mode::Structure,
             content: "budget-transformed output",
             original_tokens: Some(200),
@@ -553,28 +1311,67 @@ mod tests {
             effective_mode: None,
             parse_tier: None,
             line_numbers: false,
+            flags: TransformFlags::default(),
         })
         .unwrap();
 
         // Read with same token_budget succeeds
-        let hit = read_cache(&path, Mode::Structure, &trunc, false).unwrap();
+        let hit = read_cache(
+            &path,
+            CacheKeys::Absolute,
+            Mode::Structure,
+            &trunc,
+            false,
+            &NO_FLAGS,
+        )
+        .unwrap();
         assert_eq!(hit.content, "budget-transformed output");
         assert_eq!(hit.original_tokens, Some(200));
         assert_eq!(hit.transformed_tokens, Some(80));
 
         // Read without token_budget misses (different cache key)
         let default_trunc = TruncationOptions::default();
-        assert!(read_cache(&path, Mode::Structure, &default_trunc, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &default_trunc,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
 
         // Read with different token_budget misses
         let trunc_1000 = TruncationOptions {
             token_budget: Some(1000),
             ..Default::default()
         };
-        assert!(read_cache(&path, Mode::Structure, &trunc_1000, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &trunc_1000,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
 
         // Read with same budget + different mode misses
-        assert!(read_cache(&path, Mode::Signatures, &trunc, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Signatures,
+                &trunc,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
     }
 
     #[test]
@@ -591,6 +1388,7 @@ mod tests {
         // Write with effective_mode set (simulates cascade escalation)
         write_cache(&CacheWriteParams {
             path: &path,
+            keys: CacheKeys::Absolute,
             mode: Mode::Structure,
             content: "escalated output",
             original_tokens: Some(150),
@@ -599,19 +1397,27 @@ mod tests {
             effective_mode: Some(Mode::Signatures),
             parse_tier: None,
             line_numbers: false,
+            flags: TransformFlags::default(),
         })
         .unwrap();
 
         // Read back succeeds (effective_mode is diagnostic-only, not part of CacheHit)
-        let hit = read_cache(&path, Mode::Structure, &trunc, false).unwrap();
+        let hit = read_cache(
+            &path,
+            CacheKeys::Absolute,
+            Mode::Structure,
+            &trunc,
+            false,
+            &NO_FLAGS,
+        )
+        .unwrap();
         assert_eq!(hit.content, "escalated output");
         assert_eq!(hit.original_tokens, Some(150));
         assert_eq!(hit.transformed_tokens, Some(60));
 
         // Verify the effective_mode field was serialized in the raw JSON
-        let metadata = fs::metadata(&path).unwrap();
-        let mtime = metadata.modified().unwrap();
-        let key = cache_key(&path, mtime, Mode::Structure, &trunc, false).unwrap();
+        let stamp = SourceStamp::of(&path, CacheKeys::Absolute).unwrap();
+        let key = cache_key(&stamp, Mode::Structure, &trunc, false, &NO_FLAGS);
         let cache_file = get_cache_dir().unwrap().join(format!("{key}.json"));
         let raw_json = fs::read_to_string(&cache_file).unwrap();
         let raw: serde_json::Value = serde_json::from_str(&raw_json).unwrap();
@@ -622,6 +1428,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_original_tokens_round_trip() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "source tokens content").unwrap();
+        let path = temp_file.path().to_path_buf();
+
+        assert_eq!(read_original_tokens(&path, CacheKeys::Absolute), None);
+
+        write_original_tokens(&path, CacheKeys::Absolute, 42).unwrap();
+        assert_eq!(read_original_tokens(&path, CacheKeys::Absolute), Some(42));
+
+        // Source counts are mode-independent and never collide with transform entries
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &TruncationOptions::default(),
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
+    }
+
+    // ========================================================================
+    // Repo-relative keys
+    // ========================================================================
+
+    /// A git checkout in a fresh temp dir with `src/lib.rs` holding `contents`.
+    fn checkout(contents: &str) -> (tempfile::TempDir, PathBuf) {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join(".git")).unwrap();
+        fs::create_dir(root.path().join("src")).unwrap();
+        let file = root.path().join("src").join("lib.rs");
+        fs::write(&file, contents).unwrap();
+        (root, file)
+    }
+
+    #[test]
+    fn test_repo_relative_path_is_slash_separated_below_git_root() {
+        let (root, file) = checkout("fn a() {}");
+        let canonical = file.canonicalize().unwrap();
+        assert_eq!(
+            repo_relative_path(&canonical).as_deref(),
+            Some("src/lib.rs")
+        );
+
+        // A `.git` file (worktree or submodule) marks a root too
+        let nested = root.path().join("src");
+        fs::write(nested.join(".git"), "gitdir: ../.git/worktrees/src").unwrap();
+        assert_eq!(repo_relative_path(&canonical).as_deref(), Some("lib.rs"));
+    }
+
+    #[test]
+    fn test_repo_relative_keys_match_across_mounts() {
+        let (_a, file_a) = checkout("fn shared() {}");
+        let (_b, file_b) = checkout("fn shared() {}");
+
+        let stamp_a = SourceStamp::of(&file_a, CacheKeys::RepoRelative).unwrap();
+        let stamp_b = SourceStamp::of(&file_b, CacheKeys::RepoRelative).unwrap();
+        assert_eq!(stamp_a.key_fragment(), stamp_b.key_fragment());
+
+        // Absolute keys keep the checkouts apart
+        let abs_a = SourceStamp::of(&file_a, CacheKeys::Absolute).unwrap();
+        let abs_b = SourceStamp::of(&file_b, CacheKeys::Absolute).unwrap();
+        assert_ne!(abs_a.key_fragment(), abs_b.key_fragment());
+
+        write_cache(&CacheWriteParams {
+            keys: CacheKeys::RepoRelative,
+            ..params(&file_a, "portable output")
+        })
+        .unwrap();
+        let trunc = TruncationOptions::default();
+        let hit = read_cache(
+            &file_b,
+            CacheKeys::RepoRelative,
+            Mode::Structure,
+            &trunc,
+            false,
+            &NO_FLAGS,
+        )
+        .unwrap();
+        assert_eq!(hit.content, "portable output");
+        assert!(
+            read_cache(
+                &file_b,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &trunc,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_repo_relative_keys_follow_content_not_mtime() {
+        let (_root, file) = checkout("fn before() {}");
+        let trunc = TruncationOptions::default();
+        write_cache(&CacheWriteParams {
+            keys: CacheKeys::RepoRelative,
+            ..params(&file, "before output")
+        })
+        .unwrap();
+
+        // Same contents, new mtime: still a hit
+        let mtime = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(mtime)
+            .unwrap();
+        let read = |keys| read_cache(&file, keys, Mode::Structure, &trunc, false, &NO_FLAGS);
+        assert_eq!(
+            read(CacheKeys::RepoRelative)
+                .map(|hit| hit.content)
+                .as_deref(),
+            Some("before output")
+        );
+
+        // New contents: miss
+        fs::write(&file, "fn after() {}").unwrap();
+        assert!(read(CacheKeys::RepoRelative).is_none());
+    }
+
+    #[test]
+    fn test_repo_relative_original_tokens_round_trip() {
+        let (_root, file) = checkout("fn counted() {}");
+        write_original_tokens(&file, CacheKeys::RepoRelative, 7).unwrap();
+        assert_eq!(
+            read_original_tokens(&file, CacheKeys::RepoRelative),
+            Some(7)
+        );
+        assert_eq!(read_original_tokens(&file, CacheKeys::Absolute), None);
+    }
+
     #[test]
     fn test_cache_invalidation_on_mtime_change() {
         use std::fs::File;
@@ -641,6 +1586,7 @@ mod tests {
         // Write to cache
         write_cache(&CacheWriteParams {
             path: &path,
+            keys: CacheKeys::Absolute,
             mode: Mode::Structure,
             content: "cached v1",
             original_tokens: None,
@@ -649,9 +1595,18 @@ mod tests {
             effective_mode: None,
             parse_tier: None,
             line_numbers: false,
+            flags: TransformFlags::default(),
         })
         .unwrap();
-        let hit = read_cache(&path, Mode::Structure, &default_trunc, false).unwrap();
+        let hit = read_cache(
+            &path,
+            CacheKeys::Absolute,
+            Mode::Structure,
+            &default_trunc,
+            false,
+            &NO_FLAGS,
+        )
+        .unwrap();
         assert_eq!(hit.content, "cached v1");
 
         // Sleep to ensure mtime resolution (some filesystems have 1-second resolution)
@@ -665,6 +1620,364 @@ mod tests {
         }
 
         // Cache should be invalidated (mtime changed)
-        assert!(read_cache(&path, Mode::Structure, &default_trunc, false).is_none());
+        assert!(
+            read_cache(
+                &path,
+                CacheKeys::Absolute,
+                Mode::Structure,
+                &default_trunc,
+                false,
+                &NO_FLAGS
+            )
+            .is_none()
+        );
+    }
+
+    // ========================================================================
+    // Fault injection through the CacheFs seam
+    // ========================================================================
+
+    /// [`CacheFs`] that delegates to [`OsCacheFs`] but fails selected operations.
+    #[derive(Default)]
+    struct FaultyFs {
+        /// Fail `create_dir` (read-only or unusable cache directory).
+        dir_error: Option<io::ErrorKind>,
+        /// Write half of the contents, then fail (disk filling up mid-write).
+        write_error: Option<io::ErrorKind>,
+        /// Fail `read` (entry not readable by this user).
+        read_error: Option<io::ErrorKind>,
+        /// Files passed to `remove`, in call order.
+        removed: std::cell::RefCell<Vec<PathBuf>>,
+    }
+
+    impl CacheFs for FaultyFs {
+        fn create_dir(&self, dir: &Path) -> io::Result<()> {
+            match self.dir_error {
+                Some(kind) => Err(kind.into()),
+                None => OsCacheFs.create_dir(dir),
+            }
+        }
+
+        fn read(&self, file: &Path) -> io::Result<String> {
+            match self.read_error {
+                Some(kind) => Err(kind.into()),
+                None => OsCacheFs.read(file),
+            }
+        }
+
+        fn write(&self, file: &Path, contents: &str) -> io::Result<()> {
+            match self.write_error {
+                Some(kind) => {
+                    OsCacheFs.write(file, &contents[..contents.len() / 2])?;
+                    Err(kind.into())
+                }
+                None => OsCacheFs.write(file, contents),
+            }
+        }
+
+        fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+            OsCacheFs.rename(from, to)
+        }
+
+        fn remove(&self, file: &Path) -> io::Result<()> {
+            self.removed.borrow_mut().push(file.to_path_buf());
+            OsCacheFs.remove(file)
+        }
+    }
+
+    fn params<'a>(path: &'a Path, content: &'a str) -> CacheWriteParams<'a> {
+        CacheWriteParams {
+            path,
+            keys: CacheKeys::Absolute,
+            mode: Mode::Structure,
+            content,
+            original_tokens: None,
+            transformed_tokens: None,
+            trunc: TruncationOptions::default(),
+            effective_mode: None,
+            parse_tier: None,
+            line_numbers: false,
+            flags: TransformFlags::default(),
+        }
+    }
+
+    fn read_structure(cache_fs: &dyn CacheFs, path: &Path) -> Option<String> {
+        let trunc = TruncationOptions::default();
+        read_cache_in(
+            cache_fs,
+            path,
+            CacheKeys::Absolute,
+            Mode::Structure,
+            &trunc,
+            false,
+            &NO_FLAGS,
+        )
+        .map(|hit| hit.content)
+    }
+
+    fn source_file() -> NamedTempFile {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "fault injection source").unwrap();
+        temp_file
+    }
+
+    #[test]
+    fn test_disk_full_mid_write_leaves_no_entry() {
+        let source = source_file();
+        let faulty = FaultyFs {
+            write_error: Some(io::ErrorKind::StorageFull),
+            ..Default::default()
+        };
+
+        let err = write_cache_in(&faulty, &params(source.path(), "output")).unwrap_err();
+
+        assert!(format!("{err:#}").contains("writing"), "{err:#}");
+        let removed = faulty.removed.borrow();
+        assert_eq!(removed.len(), 1, "the partial temp file must be removed");
+        assert!(removed[0].to_string_lossy().contains(".json.tmp-"));
+        assert!(!removed[0].exists());
+        assert_eq!(read_structure(&OsCacheFs, source.path()), None);
+    }
+
+    #[test]
+    fn test_disk_full_keeps_previous_entry() {
+        let source = source_file();
+        write_cache_in(&OsCacheFs, &params(source.path(), "previous")).unwrap();
+        let faulty = FaultyFs {
+            write_error: Some(io::ErrorKind::StorageFull),
+            ..Default::default()
+        };
+
+        assert!(write_cache_in(&faulty, &params(source.path(), "replacement")).is_err());
+        assert_eq!(
+            read_structure(&OsCacheFs, source.path()).as_deref(),
+            Some("previous")
+        );
+    }
+
+    #[test]
+    fn test_read_only_cache_dir_degrades_to_miss() {
+        let source = source_file();
+        let faulty = FaultyFs {
+            dir_error: Some(io::ErrorKind::PermissionDenied),
+            ..Default::default()
+        };
+
+        assert!(write_cache_in(&faulty, &params(source.path(), "output")).is_err());
+        assert_eq!(read_structure(&faulty, source.path()), None);
+        assert!(faulty.removed.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_entry_is_miss() {
+        let source = source_file();
+        write_cache_in(&OsCacheFs, &params(source.path(), "output")).unwrap();
+        let faulty = FaultyFs {
+            read_error: Some(io::ErrorKind::PermissionDenied),
+            ..Default::default()
+        };
+
+        assert_eq!(read_structure(&faulty, source.path()), None);
+        // An unreadable entry is not stale: it must not be deleted.
+        assert!(faulty.removed.borrow().is_empty());
+        assert_eq!(
+            read_structure(&OsCacheFs, source.path()).as_deref(),
+            Some("output")
+        );
+    }
+
+    #[test]
+    fn test_truncated_entry_is_miss() {
+        let source = source_file();
+        write_cache_in(&OsCacheFs, &params(source.path(), "output")).unwrap();
+        let stamp = SourceStamp::of(source.path(), CacheKeys::Absolute).unwrap();
+        let key = cache_key(
+            &stamp,
+            Mode::Structure,
+            &TruncationOptions::default(),
+            false,
+            &NO_FLAGS,
+        );
+        let entry = get_cache_dir().unwrap().join(format!("{key}.json"));
+        let json = fs::read_to_string(&entry).unwrap();
+        fs::write(&entry, &json[..json.len() / 2]).unwrap();
+
+        assert_eq!(read_structure(&OsCacheFs, source.path()), None);
+    }
+
+    #[test]
+    fn test_vanished_source_is_miss_and_write_error() {
+        let source = source_file();
+        let path = source.path().to_path_buf();
+        source.close().unwrap();
+
+        assert_eq!(read_structure(&OsCacheFs, &path), None);
+        assert!(write_cache_in(&OsCacheFs, &params(&path, "output")).is_err());
+    }
+
+    // ========================================================================
+    // verify-cache
+    // ========================================================================
+
+    fn entry_json(path: &str, mtime_secs: u64, content_sha256: Option<&str>) -> String {
+        serde_json::to_string(&CacheEntry {
+            path: path.to_string(),
+            mtime_secs,
+            content_sha256: content_sha256.map(str::to_string),
+            mode: "Structure".to_string(),
+            content: "output".to_string(),
+            original_tokens: None,
+            transformed_tokens: None,
+            effective_mode: None,
+            parse_tier: None,
+        })
+        .unwrap()
+    }
+
+    /// Cache directory with one entry per category plus entries that must be kept.
+    fn populated_cache() -> (tempfile::TempDir, tempfile::TempDir) {
+        let cache = tempfile::TempDir::new().unwrap();
+        let sources = tempfile::TempDir::new().unwrap();
+        let source = sources.path().join("lib.rs");
+        fs::write(&source, "fn a() {}").unwrap();
+        let stamp = SourceStamp::of(&source, CacheKeys::Absolute).unwrap();
+        let source = stamp.canonical_path.display().to_string();
+        let gone = sources.path().join("gone.rs").display().to_string();
+
+        let entries = [
+            ("valid", entry_json(&source, stamp.mtime_secs, None)),
+            ("stale", entry_json(&source, stamp.mtime_secs + 1, None)),
+            ("orphaned", entry_json(&gone, 1, None)),
+            ("corrupt", "{\"path\": \"/tr".to_string()),
+            ("relative", entry_json("src/gone.rs", 1, None)),
+            ("shared", entry_json(&gone, 1, Some("abc"))),
+            (
+                "tokens",
+                serde_json::to_string(&SourceTokensEntry {
+                    path: Some(gone),
+                    mtime_secs: 1,
+                    content_sha256: None,
+                    original_tokens: 7,
+                })
+                .unwrap(),
+            ),
+        ];
+        for (name, json) in entries {
+            fs::write(cache.path().join(format!("{name}.json")), json).unwrap();
+        }
+        fs::write(cache.path().join("analytics.db"), "not json").unwrap();
+        (cache, sources)
+    }
+
+    #[test]
+    fn test_verify_reports_without_removing() {
+        let (cache, _sources) = populated_cache();
+        let report =
+            verify_cache_dir(cache.path(), &VerifyOptions::default(), SystemTime::now()).unwrap();
+
+        assert_eq!(report.scanned.entries, 7);
+        assert_eq!(report.corrupt.entries, 1);
+        assert_eq!(report.orphaned.entries, 2, "transform and token entries");
+        assert_eq!(report.stale.entries, 1);
+        assert_eq!(report.expired.entries, 0);
+        assert_eq!(report.reclaimable().entries, 4);
+        assert!(report.reclaimable().bytes > 0);
+        assert_eq!(report.removed, Tally::default());
+        assert_eq!(fs::read_dir(cache.path()).unwrap().count(), 8);
+    }
+
+    #[test]
+    fn test_verify_prune_keeps_usable_and_unresolvable_entries() {
+        let (cache, _sources) = populated_cache();
+        let options = VerifyOptions {
+            prune: true,
+            expire_before: None,
+        };
+        let report = verify_cache_dir(cache.path(), &options, SystemTime::now()).unwrap();
+        assert_eq!(report.removed, report.reclaimable());
+
+        let mut left: Vec<String> = fs::read_dir(cache.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        left.sort();
+        assert_eq!(
+            left,
+            ["analytics.db", "relative.json", "shared.json", "valid.json"]
+        );
+    }
+
+    #[test]
+    fn test_verify_expires_old_entries_and_leftover_temporaries() {
+        let (cache, _sources) = populated_cache();
+        fs::write(cache.path().join(format!("valid.json{TMP_MARKER}1-0")), "{").unwrap();
+
+        let now = SystemTime::now();
+        let fresh = verify_cache_dir(cache.path(), &VerifyOptions::default(), now).unwrap();
+        assert_eq!(fresh.leftover.entries, 0, "in-flight write within grace");
+
+        let later = now + Duration::from_secs(24 * 3600);
+        let options = VerifyOptions {
+            prune: true,
+            expire_before: Some(later - Duration::from_secs(3600)),
+        };
+        let report = verify_cache_dir(cache.path(), &options, later).unwrap();
+        assert_eq!(report.leftover.entries, 1);
+        assert_eq!(report.expired.entries, 3, "valid, relative and shared");
+        assert_eq!(fs::read_dir(cache.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_verify_last_output_entries_ignore_source_version() {
+        let cache = tempfile::TempDir::new().unwrap();
+        let (_root, file) = checkout("fn edited() {}");
+        let entry = |path: &Path| {
+            serde_json::to_string(&LastOutputEntry {
+                path: path.display().to_string(),
+                output_sha256: "abc".to_string(),
+            })
+            .unwrap()
+        };
+        fs::write(cache.path().join("kept.json"), entry(&file)).unwrap();
+        fs::write(
+            cache.path().join("orphaned.json"),
+            entry(&file.with_file_name("gone.rs")),
+        )
+        .unwrap();
+
+        let report =
+            verify_cache_dir(cache.path(), &VerifyOptions::default(), SystemTime::now()).unwrap();
+        assert_eq!(report.scanned.entries, 2);
+        assert_eq!(report.corrupt.entries, 0);
+        assert_eq!(report.orphaned.entries, 1);
+        assert_eq!(report.stale.entries, 0);
+    }
+
+    #[test]
+    fn test_verify_missing_cache_dir_is_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let report = verify_cache_dir(
+            &dir.path().join("absent"),
+            &VerifyOptions::default(),
+            SystemTime::now(),
+        )
+        .unwrap();
+        assert_eq!(report.scanned, Tally::default());
+    }
+
+    #[test]
+    fn test_written_entries_record_canonical_path() {
+        let (_root, file) = checkout("fn recorded() {}");
+        write_original_tokens(&file, CacheKeys::Absolute, 3).unwrap();
+        let stamp = SourceStamp::of(&file, CacheKeys::Absolute).unwrap();
+        let entry = get_cache_dir()
+            .unwrap()
+            .join(format!("{}.json", source_tokens_key(&stamp)));
+        let entry: SourceTokensEntry =
+            serde_json::from_str(&fs::read_to_string(entry).unwrap()).unwrap();
+        assert_eq!(
+            entry.path.as_deref(),
+            Some(file.canonicalize().unwrap().to_str().unwrap())
+        );
     }
 }
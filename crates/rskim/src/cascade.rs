@@ -5,6 +5,7 @@
 //! fallback.
 
 use rskim_core::{Language, Mode, TransformConfig, truncate_to_token_budget};
+use sha2::{Digest, Sha256};
 
 use crate::tokens;
 
@@ -21,6 +22,111 @@ pub(crate) struct TruncationOptions {
     pub(crate) token_budget: Option<usize>,
 }
 
+/// Transform toggles that travel alongside the mode through the
+/// processing pipeline and cache key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct TransformFlags {
+    /// Skim language-tagged fenced code blocks in Markdown (`--fenced-code`).
+    pub(crate) fenced_code: bool,
+    /// Template literal collapse threshold override (`--template-threshold`).
+    ///
+    /// `None` keeps the library default; `Some(0)` disables collapsing.
+    pub(crate) template_threshold: Option<usize>,
+    /// Drop private declarations (`--public-only`).
+    pub(crate) public_only: bool,
+    /// Keep the file-level doc comment in signatures/types output (`--module-doc`).
+    pub(crate) module_doc: bool,
+    /// Emit Python signatures/types output as a `.pyi` stub (`--pyi`).
+    pub(crate) python_stub: bool,
+    /// Emit call-graph output as a Graphviz digraph (`--dot`).
+    pub(crate) dot_graph: bool,
+    /// Replace Rust function bodies with `{ todo!() }` (`--todo-bodies`).
+    pub(crate) todo_bodies: bool,
+    /// Elide signature parameter lists longer than this (`--max-params`).
+    pub(crate) max_params: Option<usize>,
+    /// Soft-wrap signature lines at this many display columns (`--wrap`).
+    pub(crate) wrap_width: Option<usize>,
+    /// Drop declarations nested deeper than this in structure mode
+    /// (`--max-nesting`).
+    pub(crate) max_nesting: Option<usize>,
+    /// Keep the first N statements of each body in structure mode
+    /// (`--body-statements`).
+    pub(crate) body_statements: usize,
+    /// Tree-sitter query run by query mode (`--query`).
+    ///
+    /// Read once at startup and leaked, so the flags stay `Copy`.
+    pub(crate) query: Option<&'static str>,
+}
+
+impl TransformFlags {
+    /// Apply the flags to an already-built `TransformConfig`.
+    pub(crate) fn apply(self, config: TransformConfig) -> TransformConfig {
+        let config = config
+            .with_fenced_code(self.fenced_code)
+            .with_public_only(self.public_only)
+            .with_module_doc(self.module_doc)
+            .with_python_stub(self.python_stub)
+            .with_dot_graph(self.dot_graph)
+            .with_todo_bodies(self.todo_bodies)
+            .with_body_statements(self.body_statements);
+        let config = match self.max_params {
+            Some(n) => config.with_max_params(n),
+            None => config,
+        };
+        let config = match self.wrap_width {
+            Some(columns) => config.with_wrap_width(columns),
+            None => config,
+        };
+        let config = match self.max_nesting {
+            Some(n) => config.with_max_nesting_kept(n),
+            None => config,
+        };
+        let config = match self.query {
+            Some(query) => config.with_query(query),
+            None => config,
+        };
+        match self.template_threshold {
+            Some(bytes) => config.with_template_literal_threshold((bytes > 0).then_some(bytes)),
+            None => config,
+        }
+    }
+
+    /// Compact, stable representation used as a cache key component.
+    pub(crate) fn key_fragment(self) -> String {
+        let threshold = self
+            .template_threshold
+            .map_or("default".to_string(), |n| n.to_string());
+        let max_params = self
+            .max_params
+            .map_or("none".to_string(), |n| n.to_string());
+        let wrap_width = self
+            .wrap_width
+            .map_or("none".to_string(), |n| n.to_string());
+        let max_nesting = self
+            .max_nesting
+            .map_or("none".to_string(), |n| n.to_string());
+        // The query text itself can be long; its hash identifies it.
+        let query = self.query.map_or("none".to_string(), |query| {
+            format!("{:x}", Sha256::digest(query.as_bytes()))
+        });
+        format!(
+            "fc{}:tt{}:po{}:md{}:pyi{}:dot{}:td{}:mp{}:wr{}:mn{}:bs{}:q{}",
+            self.fenced_code as u8,
+            threshold,
+            self.public_only as u8,
+            self.module_doc as u8,
+            self.python_stub as u8,
+            self.dot_graph as u8,
+            self.todo_bodies as u8,
+            max_params,
+            wrap_width,
+            max_nesting,
+            self.body_statements,
+            query
+        )
+    }
+}
+
 /// Error message when no transformation mode produces output.
 const NO_OUTPUT_MSG: &str = "Token budget cascade: no transformation mode produced output. \
     Ensure the file is in a supported language or specify --language.";
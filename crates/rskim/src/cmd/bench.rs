@@ -0,0 +1,524 @@
+//! Bench subcommand — criterion regression gate for CI.
+//!
+//! Wraps `cargo bench` and compares the criterion estimates it leaves behind
+//! against a committed baseline file. Criterion 0.5 itself always exits 0, even
+//! when it reports a regression, so CI had no hard performance gate beyond the
+//! single AC12 latency test. `skim bench --check --baseline <file>` exits
+//! non-zero when any benchmark's throughput drops by more than
+//! `--max-regression` percent.
+//!
+//! The baseline file is plain JSON keyed by criterion's `full_id`:
+//!
+//! ```text
+//! {"version":1,"benchmarks":{"cl100k_100kb":{"mean_ns":11245120.4}}}
+//! ```
+//!
+//! Create or refresh it with `skim bench --save <file>`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+use serde::{Deserialize, Serialize};
+
+use super::ux;
+
+/// Default allowed throughput drop before `--check` fails, in percent.
+const DEFAULT_MAX_REGRESSION_PCT: f64 = 10.0;
+
+/// Baseline file format version.
+const BASELINE_VERSION: u32 = 1;
+
+/// Criterion's sub-directory holding the most recent run's estimates.
+const CRITERION_RUN_DIR: &str = "new";
+
+/// Maximum directory depth searched below the criterion root.
+///
+/// Criterion nests results as `<group>/<function>/<value>/new/`, so 4 levels
+/// cover every benchmark id shape it produces.
+const MAX_SEARCH_DEPTH: usize = 4;
+
+// ============================================================================
+// Public entry point
+// ============================================================================
+
+/// Run the `skim bench` subcommand.
+pub(crate) fn run(
+    args: &[String],
+    _analytics: &crate::analytics::AnalyticsConfig,
+) -> anyhow::Result<ExitCode> {
+    if args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let opts = BenchOptions::parse(args)?;
+
+    if !opts.no_run {
+        let status = Command::new("cargo")
+            .arg("bench")
+            .args(&opts.cargo_args)
+            .status()
+            .map_err(|e| anyhow::anyhow!("failed to run cargo bench: {e}"))?;
+        if !status.success() {
+            anyhow::bail!("cargo bench failed ({status})");
+        }
+    }
+
+    let current = collect_estimates(&opts.criterion_dir)?;
+    if current.is_empty() {
+        anyhow::bail!(
+            "no criterion results found under {}\n\
+             Run `cargo bench` first or pass --criterion-dir",
+            opts.criterion_dir.display()
+        );
+    }
+
+    if let Some(path) = &opts.save {
+        write_baseline(path, &current)?;
+        println!(
+            "skim bench: saved {} benchmark(s) to {}",
+            current.len(),
+            path.display()
+        );
+    }
+
+    let Some(baseline_path) = &opts.baseline else {
+        return Ok(ExitCode::SUCCESS);
+    };
+    let baseline = read_baseline(baseline_path)?;
+    let comparisons = compare(&baseline, &current);
+    print_report(&comparisons, opts.max_regression_pct);
+
+    let regressed = comparisons
+        .iter()
+        .filter(|c| c.is_regression(opts.max_regression_pct))
+        .count();
+    if regressed > 0 {
+        eprintln!(
+            "skim bench: {regressed} benchmark(s) lost more than {}% throughput",
+            opts.max_regression_pct
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// ============================================================================
+// Flag parsing
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+struct BenchOptions {
+    baseline: Option<PathBuf>,
+    save: Option<PathBuf>,
+    max_regression_pct: f64,
+    criterion_dir: PathBuf,
+    no_run: bool,
+    /// Arguments after `--`, forwarded to `cargo bench`.
+    cargo_args: Vec<String>,
+}
+
+impl BenchOptions {
+    fn parse(args: &[String]) -> anyhow::Result<Self> {
+        let mut check = false;
+        let mut baseline = None;
+        let mut save = None;
+        let mut max_regression_pct = DEFAULT_MAX_REGRESSION_PCT;
+        let mut criterion_dir = None;
+        let mut no_run = false;
+        let mut cargo_args = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = |name: &str| {
+                inline
+                    .clone()
+                    .or_else(|| iter.next().cloned())
+                    .ok_or_else(|| anyhow::anyhow!("{name} requires a value"))
+            };
+            match flag {
+                "--check" => check = true,
+                "--no-run" => no_run = true,
+                "--baseline" => baseline = Some(PathBuf::from(value("--baseline")?)),
+                "--save" => save = Some(PathBuf::from(value("--save")?)),
+                "--criterion-dir" => {
+                    criterion_dir = Some(PathBuf::from(value("--criterion-dir")?));
+                }
+                "--max-regression" => {
+                    let raw = value("--max-regression")?;
+                    max_regression_pct = raw
+                        .trim_end_matches('%')
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|p| p.is_finite() && *p >= 0.0)
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("--max-regression expects a percentage, got '{raw}'")
+                        })?;
+                }
+                "--" => {
+                    cargo_args.extend(iter.by_ref().cloned());
+                }
+                other => anyhow::bail!(
+                    "unknown flag for skim bench: '{}'\nRun 'skim bench --help' for usage",
+                    super::sanitize_for_display(other)
+                ),
+            }
+        }
+
+        if check && baseline.is_none() {
+            anyhow::bail!("--check requires --baseline <FILE>");
+        }
+        if !check && baseline.is_some() {
+            anyhow::bail!("--baseline is only used with --check");
+        }
+        if !check && save.is_none() {
+            anyhow::bail!("nothing to do: pass --check --baseline <FILE> or --save <FILE>");
+        }
+
+        Ok(Self {
+            baseline,
+            save,
+            max_regression_pct,
+            criterion_dir: criterion_dir.unwrap_or_else(default_criterion_dir),
+            no_run,
+            cargo_args,
+        })
+    }
+}
+
+/// Criterion's output directory: `$CRITERION_HOME`, else `target/criterion`.
+fn default_criterion_dir() -> PathBuf {
+    std::env::var_os("CRITERION_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("target").join("criterion"))
+}
+
+// ============================================================================
+// Criterion results
+// ============================================================================
+
+#[derive(Deserialize)]
+struct CriterionBenchmark {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct CriterionEstimates {
+    mean: CriterionEstimate,
+}
+
+#[derive(Deserialize)]
+struct CriterionEstimate {
+    point_estimate: f64,
+}
+
+/// Collect `full_id → mean ns` for every benchmark under `root`.
+///
+/// A benchmark is any directory with a `new/benchmark.json` and
+/// `new/estimates.json` pair. Criterion's HTML `report` directories are skipped.
+fn collect_estimates(root: &Path) -> anyhow::Result<BTreeMap<String, f64>> {
+    let mut out = BTreeMap::new();
+    if !root.is_dir() {
+        return Ok(out);
+    }
+    collect_into(root, 0, &mut out)?;
+    Ok(out)
+}
+
+fn collect_into(dir: &Path, depth: usize, out: &mut BTreeMap<String, f64>) -> anyhow::Result<()> {
+    let run_dir = dir.join(CRITERION_RUN_DIR);
+    if run_dir.join("estimates.json").is_file() {
+        let meta: CriterionBenchmark =
+            serde_json::from_str(&std::fs::read_to_string(run_dir.join("benchmark.json"))?)?;
+        let estimates: CriterionEstimates =
+            serde_json::from_str(&std::fs::read_to_string(run_dir.join("estimates.json"))?)?;
+        out.insert(meta.full_id, estimates.mean.point_estimate);
+        return Ok(());
+    }
+    if depth >= MAX_SEARCH_DEPTH {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() && entry.file_name() != "report" {
+            collect_into(&entry.path(), depth + 1, out)?;
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Baseline file
+// ============================================================================
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    benchmarks: BTreeMap<String, BaselineEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineEntry {
+    mean_ns: f64,
+}
+
+fn read_baseline(path: &Path) -> anyhow::Result<BTreeMap<String, f64>> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("failed to read baseline {}: {e}", path.display()))?;
+    let file: BaselineFile = serde_json::from_str(&raw)
+        .map_err(|e| anyhow::anyhow!("invalid baseline {}: {e}", path.display()))?;
+    if file.version != BASELINE_VERSION {
+        anyhow::bail!(
+            "unsupported baseline version {} in {} (expected {BASELINE_VERSION})",
+            file.version,
+            path.display()
+        );
+    }
+    Ok(file
+        .benchmarks
+        .into_iter()
+        .map(|(id, entry)| (id, entry.mean_ns))
+        .collect())
+}
+
+fn write_baseline(path: &Path, current: &BTreeMap<String, f64>) -> anyhow::Result<()> {
+    let file = BaselineFile {
+        version: BASELINE_VERSION,
+        benchmarks: current
+            .iter()
+            .map(|(id, &mean_ns)| (id.clone(), BaselineEntry { mean_ns }))
+            .collect(),
+    };
+    let mut json = serde_json::to_string_pretty(&file)?;
+    json.push('\n');
+    std::fs::write(path, json)
+        .map_err(|e| anyhow::anyhow!("failed to write baseline {}: {e}", path.display()))
+}
+
+// ============================================================================
+// Comparison
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+struct Comparison {
+    id: String,
+    baseline_ns: f64,
+    /// `None` when the benchmark was not part of this run.
+    current_ns: Option<f64>,
+}
+
+impl Comparison {
+    /// Throughput change in percent; negative means slower than the baseline.
+    fn throughput_change_pct(&self) -> Option<f64> {
+        let current = self.current_ns?;
+        (current > 0.0).then(|| (self.baseline_ns / current - 1.0) * 100.0)
+    }
+
+    fn is_regression(&self, max_regression_pct: f64) -> bool {
+        self.throughput_change_pct()
+            .is_some_and(|pct| -pct > max_regression_pct)
+    }
+}
+
+/// Pair every baseline benchmark with its current estimate.
+///
+/// Benchmarks that only exist in the current run have nothing to regress
+/// against and are left out; save a new baseline to start tracking them.
+fn compare(baseline: &BTreeMap<String, f64>, current: &BTreeMap<String, f64>) -> Vec<Comparison> {
+    baseline
+        .iter()
+        .map(|(id, &baseline_ns)| Comparison {
+            id: id.clone(),
+            baseline_ns,
+            current_ns: current.get(id).copied(),
+        })
+        .collect()
+}
+
+fn print_report(comparisons: &[Comparison], max_regression_pct: f64) {
+    let width = comparisons.iter().map(|c| c.id.len()).max().unwrap_or(0);
+    for c in comparisons {
+        match (c.current_ns, c.throughput_change_pct()) {
+            (Some(current), Some(pct)) => println!(
+                "  {} {:<width$}  {:>10} → {:>10}  {:+.1}% throughput",
+                ux::check_mark(!c.is_regression(max_regression_pct)),
+                c.id,
+                format_ns(c.baseline_ns),
+                format_ns(current),
+                pct,
+            ),
+            _ => println!(
+                "  ? {:<width$}  {:>10}    (not run)",
+                c.id,
+                format_ns(c.baseline_ns)
+            ),
+        }
+    }
+}
+
+/// Format a nanosecond duration with a human-scale unit.
+fn format_ns(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.2} s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.2} ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.2} µs", ns / 1e3)
+    } else {
+        format!("{ns:.1} ns")
+    }
+}
+
+// ============================================================================
+// Help
+// ============================================================================
+
+fn print_help() {
+    println!("skim bench");
+    println!();
+    println!("  Run criterion benchmarks and gate on throughput regressions.");
+    println!();
+    println!("Usage: skim bench [FLAGS] [-- <CARGO BENCH ARGS>...]");
+    println!();
+    println!("FLAGS:");
+    println!(
+        "  --check                   Compare results against --baseline and fail on regression"
+    );
+    println!("  --baseline <FILE>         Baseline JSON written by --save");
+    println!(
+        "  --max-regression <PCT>    Allowed throughput drop in percent (default: {DEFAULT_MAX_REGRESSION_PCT})"
+    );
+    println!("  --save <FILE>             Write this run's results as a new baseline");
+    println!(
+        "  --criterion-dir <DIR>     Criterion output (default: $CRITERION_HOME or target/criterion)"
+    );
+    println!(
+        "  --no-run                  Reuse existing criterion results instead of running cargo bench"
+    );
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim bench --save bench-baseline.json -- -p rskim-tokens");
+    println!("  skim bench --check --baseline bench-baseline.json -- -p rskim-tokens");
+    println!("  skim bench --check --baseline bench-baseline.json --max-regression 5 --no-run");
+    println!();
+    println!("EXIT CODES:");
+    println!("  0  No benchmark regressed beyond the threshold");
+    println!("  1  At least one benchmark regressed, or cargo bench failed");
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| (*s).to_string()).collect()
+    }
+
+    fn write_criterion_result(root: &Path, dir: &str, full_id: &str, mean_ns: f64) {
+        let run_dir = root.join(dir).join(CRITERION_RUN_DIR);
+        std::fs::create_dir_all(&run_dir).unwrap();
+        std::fs::write(
+            run_dir.join("benchmark.json"),
+            format!(r#"{{"group_id":"g","function_id":null,"full_id":"{full_id}"}}"#),
+        )
+        .unwrap();
+        std::fs::write(
+            run_dir.join("estimates.json"),
+            format!(r#"{{"mean":{{"point_estimate":{mean_ns},"standard_error":1.0}}}}"#),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_parse_check_with_baseline_and_cargo_args() {
+        let opts = BenchOptions::parse(&args(&[
+            "--check",
+            "--baseline=base.json",
+            "--max-regression",
+            "5%",
+            "--",
+            "-p",
+            "rskim-tokens",
+        ]))
+        .unwrap();
+        assert_eq!(opts.baseline, Some(PathBuf::from("base.json")));
+        assert_eq!(opts.max_regression_pct, 5.0);
+        assert_eq!(opts.cargo_args, args(&["-p", "rskim-tokens"]));
+        assert!(!opts.no_run);
+    }
+
+    #[test]
+    fn test_parse_rejects_inconsistent_flags() {
+        assert!(BenchOptions::parse(&args(&["--check"])).is_err());
+        assert!(BenchOptions::parse(&args(&["--baseline", "b.json"])).is_err());
+        assert!(BenchOptions::parse(&args(&[])).is_err());
+        assert!(
+            BenchOptions::parse(&args(&["--save", "b.json", "--max-regression", "-3"])).is_err()
+        );
+        assert!(BenchOptions::parse(&args(&["--save", "b.json", "--bogus"])).is_err());
+    }
+
+    #[test]
+    fn test_collect_estimates_walks_nested_groups() {
+        let dir = tempfile::tempdir().unwrap();
+        write_criterion_result(dir.path(), "cl100k_100kb", "cl100k_100kb", 1_000.0);
+        write_criterion_result(dir.path(), "parse/rust", "parse/rust", 2_000.0);
+        std::fs::create_dir_all(dir.path().join("report")).unwrap();
+
+        let found = collect_estimates(dir.path()).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found["cl100k_100kb"], 1_000.0);
+        assert_eq!(found["parse/rust"], 2_000.0);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        let current = BTreeMap::from([("a".to_string(), 12.5), ("b".to_string(), 99.0)]);
+        write_baseline(&path, &current).unwrap();
+        assert_eq!(read_baseline(&path).unwrap(), current);
+    }
+
+    #[test]
+    fn test_regression_is_measured_as_throughput_drop() {
+        let baseline = BTreeMap::from([
+            ("faster".to_string(), 100.0),
+            ("slightly_slower".to_string(), 100.0),
+            ("much_slower".to_string(), 100.0),
+            ("missing".to_string(), 100.0),
+        ]);
+        let current = BTreeMap::from([
+            ("faster".to_string(), 50.0),
+            ("slightly_slower".to_string(), 105.0),
+            ("much_slower".to_string(), 125.0),
+        ]);
+        let comparisons = compare(&baseline, &current);
+        let regressed: Vec<&str> = comparisons
+            .iter()
+            .filter(|c| c.is_regression(10.0))
+            .map(|c| c.id.as_str())
+            .collect();
+        // 125 ns vs 100 ns is a 20% throughput drop; 105 ns is under 5%.
+        assert_eq!(regressed, vec!["much_slower"]);
+        let faster = comparisons.iter().find(|c| c.id == "faster").unwrap();
+        assert_eq!(faster.throughput_change_pct(), Some(100.0));
+    }
+
+    #[test]
+    fn test_format_ns_units() {
+        assert_eq!(format_ns(12.0), "12.0 ns");
+        assert_eq!(format_ns(1_500.0), "1.50 µs");
+        assert_eq!(format_ns(12_138_899.9), "12.14 ms");
+        assert_eq!(format_ns(2.5e9), "2.50 s");
+    }
+}
@@ -0,0 +1,457 @@
+//! Bench-cache subcommand — cold vs warm cache benchmark for a directory.
+//!
+//! Teams deciding whether to ship a shared cache (CI cache restore,
+//! `--cache-keys repo-relative`) need to know what it buys them on their own
+//! repository. `skim bench-cache <PATH>...` runs skim over the paths with an
+//! empty cache (cold), then again with the cache that run left behind (warm),
+//! and reports the speedup, the warm run's hit rate and what the cache costs
+//! on disk.
+//!
+//! Every run is a child `skim` process with `SKIM_CACHE_DIR` pointed at a
+//! fresh temporary directory, so the user's own cache is never flushed or
+//! polluted. Timings are wall-clock for the whole process, as an agent would
+//! see them; with `--runs N` each figure is the median of N cold/warm pairs.
+//! File and cache counts come from the child's `--report-usage=json`.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use super::verify_cache::format_size;
+use crate::schema::SchemaFormat;
+
+/// Cold/warm pairs run when `--runs` is not given.
+const DEFAULT_RUNS: usize = 3;
+
+/// Upper bound on `--runs`; each run re-processes the whole target.
+const MAX_RUNS: usize = 100;
+
+// ============================================================================
+// Public entry point
+// ============================================================================
+
+/// Run the `skim bench-cache` subcommand.
+pub(crate) fn run(
+    args: &[String],
+    _analytics: &crate::analytics::AnalyticsConfig,
+) -> anyhow::Result<ExitCode> {
+    if args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let opts = BenchCacheOptions::parse(args)?;
+    let exe = std::env::current_exe()
+        .map_err(|e| anyhow::anyhow!("cannot locate the skim executable: {e}"))?;
+
+    let mut cold = Vec::with_capacity(opts.runs);
+    let mut warm = Vec::with_capacity(opts.runs);
+    let mut last = None;
+    for _ in 0..opts.runs {
+        let cache_dir = tempfile::TempDir::new()
+            .map_err(|e| anyhow::anyhow!("cannot create a temporary cache directory: {e}"))?;
+        cold.push(run_skim(&exe, cache_dir.path(), &opts)?);
+        warm.push(run_skim(&exe, cache_dir.path(), &opts)?);
+        last = Some(cache_size(cache_dir.path())?);
+    }
+
+    let report = Report::new(&cold, &warm, last.unwrap_or_default(), opts.runs);
+    if report.lookups == 0 {
+        anyhow::bail!(
+            "the benchmarked runs made no cache lookups; \
+             drop --no-cache (or other cache-bypassing flags) from the skim arguments"
+        );
+    }
+
+    let mut stdout = io::stdout().lock();
+    if opts.json {
+        writeln!(stdout, "{}", serde_json::to_string_pretty(&report)?)?;
+    } else {
+        write_report(&mut stdout, &report)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// ============================================================================
+// Flag parsing
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+struct BenchCacheOptions {
+    /// Files, directories or globs to process; `.` when none are given.
+    paths: Vec<String>,
+    runs: usize,
+    json: bool,
+    /// Arguments after `--`, forwarded to every skim run.
+    skim_args: Vec<String>,
+}
+
+impl BenchCacheOptions {
+    fn parse(args: &[String]) -> anyhow::Result<Self> {
+        let mut paths = Vec::new();
+        let mut runs = DEFAULT_RUNS;
+        let mut json = false;
+        let mut skim_args = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = |name: &str| {
+                inline
+                    .clone()
+                    .or_else(|| iter.next().cloned())
+                    .ok_or_else(|| anyhow::anyhow!("{name} requires a value"))
+            };
+            match flag {
+                "--" => {
+                    skim_args.extend(iter.by_ref().cloned());
+                }
+                "--runs" => {
+                    let raw = value("--runs")?;
+                    runs = match raw.parse::<usize>() {
+                        Ok(n) if (1..=MAX_RUNS).contains(&n) => n,
+                        _ => anyhow::bail!(
+                            "--runs expects a number from 1 to {MAX_RUNS}, got '{}'",
+                            super::sanitize_for_display(&raw)
+                        ),
+                    };
+                }
+                "--format" => match value("--format")?.as_str() {
+                    "json" => json = true,
+                    "text" => json = false,
+                    other => anyhow::bail!(
+                        "--format expects 'text' or 'json', got '{}'",
+                        super::sanitize_for_display(other)
+                    ),
+                },
+                other if other.starts_with('-') => anyhow::bail!(
+                    "unknown argument for skim bench-cache: '{}'\nRun 'skim bench-cache --help' for usage",
+                    super::sanitize_for_display(other)
+                ),
+                _ => paths.push(arg.clone()),
+            }
+        }
+
+        if paths.is_empty() {
+            paths.push(".".to_string());
+        }
+        Ok(Self {
+            paths,
+            runs,
+            json,
+            skim_args,
+        })
+    }
+}
+
+// ============================================================================
+// Measurement
+// ============================================================================
+
+/// One skim process run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RunStats {
+    wall: Duration,
+    files: usize,
+    lookups: usize,
+    hits: usize,
+}
+
+/// Run skim over the target with `cache_dir` as its cache, discarding output.
+fn run_skim(exe: &Path, cache_dir: &Path, opts: &BenchCacheOptions) -> anyhow::Result<RunStats> {
+    let started = Instant::now();
+    let output = Command::new(exe)
+        .args(&opts.paths)
+        .args(&opts.skim_args)
+        .args(["--report-usage=json", "--disable-analytics"])
+        .env("SKIM_CACHE_DIR", cache_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| anyhow::anyhow!("failed to run skim: {e}"))?;
+    let wall = started.elapsed();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        let first_line = stderr.lines().find(|line| !line.trim().is_empty());
+        anyhow::bail!(
+            "skim failed ({}){}",
+            output.status,
+            first_line
+                .map(|line| format!(": {}", super::sanitize_for_display(line)))
+                .unwrap_or_default()
+        );
+    }
+    parse_usage(&stderr, wall)
+        .ok_or_else(|| anyhow::anyhow!("skim did not report its resource usage"))
+}
+
+/// Read the `--report-usage=json` object, the last JSON line on stderr.
+fn parse_usage(stderr: &str, wall: Duration) -> Option<RunStats> {
+    let usage: serde_json::Value = stderr
+        .lines()
+        .rev()
+        .find(|line| line.starts_with('{'))
+        .and_then(|line| serde_json::from_str(line).ok())?;
+    let count = |value: &serde_json::Value| value.as_u64().map(|n| n as usize);
+    Some(RunStats {
+        wall,
+        files: count(&usage["files"])?,
+        lookups: count(&usage["cache"]["lookups"])?,
+        hits: count(&usage["cache"]["hits"])?,
+    })
+}
+
+/// Entry count and total bytes of the files under `dir`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize)]
+struct CacheSize {
+    entries: usize,
+    bytes: u64,
+}
+
+fn cache_size(dir: &Path) -> anyhow::Result<CacheSize> {
+    let mut size = CacheSize::default();
+    let mut pending: Vec<PathBuf> = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                size.entries += 1;
+                size.bytes += metadata.len();
+            }
+        }
+    }
+    Ok(size)
+}
+
+// ============================================================================
+// Report
+// ============================================================================
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Report {
+    schema_version: u32,
+    runs: usize,
+    files: usize,
+    cold_ms: f64,
+    warm_ms: f64,
+    /// Cold median over warm median.
+    speedup: f64,
+    /// Cache hits and lookups summed over the warm runs.
+    hits: usize,
+    lookups: usize,
+    hit_ratio: f64,
+    cache: CacheSize,
+}
+
+impl Report {
+    fn new(cold: &[RunStats], warm: &[RunStats], cache: CacheSize, runs: usize) -> Self {
+        let cold_wall = median(cold.iter().map(|run| run.wall).collect());
+        let warm_wall = median(warm.iter().map(|run| run.wall).collect());
+        let hits = warm.iter().map(|run| run.hits).sum();
+        let lookups = warm.iter().map(|run| run.lookups).sum();
+        let round = |value: f64, scale: f64| (value * scale).round() / scale;
+        Self {
+            schema_version: SchemaFormat::BenchCache.version(),
+            runs,
+            files: cold.first().map_or(0, |run| run.files),
+            cold_ms: round(cold_wall.as_secs_f64() * 1_000.0, 10.0),
+            warm_ms: round(warm_wall.as_secs_f64() * 1_000.0, 10.0),
+            speedup: if warm_wall.is_zero() {
+                0.0
+            } else {
+                round(cold_wall.as_secs_f64() / warm_wall.as_secs_f64(), 100.0)
+            },
+            hits,
+            lookups,
+            hit_ratio: if lookups == 0 {
+                0.0
+            } else {
+                round(hits as f64 / lookups as f64, 1_000.0)
+            },
+            cache,
+        }
+    }
+}
+
+fn median(mut values: Vec<Duration>) -> Duration {
+    values.sort_unstable();
+    values.get(values.len() / 2).copied().unwrap_or_default()
+}
+
+fn write_report(out: &mut impl Write, report: &Report) -> io::Result<()> {
+    let per_file = if report.files == 0 {
+        0
+    } else {
+        report.cache.bytes / report.files as u64
+    };
+    writeln!(out, "  files     {:>10}", report.files)?;
+    writeln!(out, "  cold      {:>10}", format_ms(report.cold_ms))?;
+    writeln!(out, "  warm      {:>10}", format_ms(report.warm_ms))?;
+    writeln!(out, "  speedup   {:>10}", format!("{:.2}x", report.speedup))?;
+    writeln!(
+        out,
+        "  hit rate  {:>10}  ({}/{} lookups)",
+        format!("{:.1}%", report.hit_ratio * 100.0),
+        report.hits,
+        report.lookups
+    )?;
+    writeln!(
+        out,
+        "  cache     {:>10}  ({} entries, {}/file)",
+        format_size(report.cache.bytes),
+        report.cache.entries,
+        format_size(per_file)
+    )?;
+    writeln!(out)?;
+    let noun = if report.runs == 1 { "run" } else { "runs" };
+    writeln!(
+        out,
+        "Median of {} cold/warm {noun}; the warm run was {:.2}x faster",
+        report.runs, report.speedup
+    )
+}
+
+fn format_ms(ms: f64) -> String {
+    if ms >= 1_000.0 {
+        format!("{:.2} s", ms / 1_000.0)
+    } else {
+        format!("{ms:.1} ms")
+    }
+}
+
+// ============================================================================
+// Help
+// ============================================================================
+
+fn print_help() {
+    println!("skim bench-cache");
+    println!();
+    println!("  Measure what the output cache buys on a directory: run skim cold (empty cache)");
+    println!("  and warm (cache from the cold run), and report speedup, hit rate and cache size.");
+    println!();
+    println!("Usage: skim bench-cache [PATH]... [FLAGS] [-- <SKIM ARGS>...]");
+    println!();
+    println!("FLAGS:");
+    println!("  --runs <N>        Cold/warm pairs to run; figures are medians (default: 3)");
+    println!("  --format json     Output the report as JSON");
+    println!("  -- <SKIM ARGS>    Arguments passed to every skim run (e.g. -- --mode signatures)");
+    println!();
+    println!("  PATH defaults to the current directory. Each pair runs against a fresh");
+    println!("  temporary cache directory; your own cache is never read or modified.");
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim bench-cache src/                           Default (structure) mode");
+    println!("  skim bench-cache . --runs 5 -- --mode signatures");
+    println!("  skim bench-cache src/ --format json             For scripts and dashboards");
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn stats(wall_ms: u64, hits: usize) -> RunStats {
+        RunStats {
+            wall: Duration::from_millis(wall_ms),
+            files: 4,
+            lookups: 4,
+            hits,
+        }
+    }
+
+    #[test]
+    fn test_parse_paths_flags_and_forwarded_args() {
+        let opts = BenchCacheOptions::parse(&args(&[
+            "src/",
+            "--runs=5",
+            "--format",
+            "json",
+            "--",
+            "--mode",
+            "signatures",
+        ]))
+        .unwrap();
+        assert_eq!(
+            opts,
+            BenchCacheOptions {
+                paths: args(&["src/"]),
+                runs: 5,
+                json: true,
+                skim_args: args(&["--mode", "signatures"]),
+            }
+        );
+        assert_eq!(BenchCacheOptions::parse(&[]).unwrap().paths, args(&["."]));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_values() {
+        assert!(BenchCacheOptions::parse(&args(&["--runs", "0"])).is_err());
+        assert!(BenchCacheOptions::parse(&args(&["--runs", "many"])).is_err());
+        assert!(BenchCacheOptions::parse(&args(&["--format", "xml"])).is_err());
+        assert!(BenchCacheOptions::parse(&args(&["--mode", "types"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_usage_reads_the_last_json_line() {
+        let stderr = "[skim] warning: something\n\
+                      {\"wall_ms\":12,\"files\":3,\"cache\":{\"lookups\":3,\"hits\":2}}\n";
+        let wall = Duration::from_millis(20);
+        assert_eq!(
+            parse_usage(stderr, wall),
+            Some(RunStats {
+                wall,
+                files: 3,
+                lookups: 3,
+                hits: 2
+            })
+        );
+        assert_eq!(parse_usage("no report\n", wall), None);
+    }
+
+    #[test]
+    fn test_report_uses_medians_and_warm_hits() {
+        let cold = [stats(400, 0), stats(100, 0), stats(300, 0)];
+        let warm = [stats(50, 4), stats(90, 4), stats(60, 3)];
+        let cache = CacheSize {
+            entries: 4,
+            bytes: 8_192,
+        };
+        let report = Report::new(&cold, &warm, cache, 3);
+        assert_eq!(report.cold_ms, 300.0);
+        assert_eq!(report.warm_ms, 60.0);
+        assert_eq!(report.speedup, 5.0);
+        assert_eq!((report.hits, report.lookups), (11, 12));
+        assert_eq!(report.hit_ratio, 0.917);
+
+        let mut buf = Vec::new();
+        write_report(&mut buf, &report).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert!(out.contains("speedup        5.00x"), "{out}");
+        assert!(
+            out.contains("hit rate       91.7%  (11/12 lookups)"),
+            "{out}"
+        );
+        assert!(out.contains("8.0 KB  (4 entries, 2.0 KB/file)"), "{out}");
+
+        let value = serde_json::to_value(&report).unwrap();
+        crate::schema::check(&SchemaFormat::BenchCache.schema(), &value).unwrap();
+    }
+}
@@ -0,0 +1,372 @@
+//! Build subcommand — produce context packs declared in `.skim.toml` manifests.
+//!
+//! Prompt bundles are usually assembled by hand: a skim command line in a
+//! shell history or a CI script, rerun with slightly different flags each
+//! time. A pack manifest commits that command line as a reviewable file,
+//! conventionally `contextpack.skim.toml`:
+//!
+//! ```toml
+//! inputs = ["src/api/", "README.md"]
+//! output = "context.md"
+//! mode = "signatures"
+//! format = "markdown"
+//! tokens = 4000
+//! flags = ["--public-only"]
+//! ```
+//!
+//! `skim build [MANIFEST]...` produces each pack by running skim over the
+//! inputs and writing the result to `output` atomically. Paths are relative to
+//! the manifest's directory, and every build is `--hermetic` (no cache, no
+//! global ignore files, no `skim.toml` presets), so the output depends only on
+//! the manifest and the files it names. `--check` rebuilds without writing
+//! and fails when a committed output is stale.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+
+use serde::Deserialize;
+
+/// File name suffix of pack manifests, found by `skim build` with no
+/// arguments.
+const MANIFEST_SUFFIX: &str = ".skim.toml";
+
+/// Flags a manifest sets through its own keys, or that would redirect the
+/// build's output; not allowed in `flags`.
+const MANAGED_FLAGS: &[&str] = &["mode", "format", "tokens", "inputs-manifest", "output"];
+
+// ============================================================================
+// Public entry point
+// ============================================================================
+
+/// Run the `skim build` subcommand.
+pub(crate) fn run(
+    args: &[String],
+    _analytics: &crate::analytics::AnalyticsConfig,
+) -> anyhow::Result<ExitCode> {
+    if args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut check = false;
+    let mut manifests = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--check" => check = true,
+            other if other.starts_with('-') => anyhow::bail!(
+                "unknown argument for skim build: '{}'\nRun 'skim build --help' for usage",
+                super::sanitize_for_display(other)
+            ),
+            _ => manifests.push(PathBuf::from(arg)),
+        }
+    }
+    if manifests.is_empty() {
+        manifests = find_manifests(Path::new("."))?;
+        if manifests.is_empty() {
+            anyhow::bail!(
+                "no *{MANIFEST_SUFFIX} manifest in the current directory\n\
+                 Run 'skim build --help' for the manifest format"
+            );
+        }
+    }
+
+    let exe = std::env::current_exe()
+        .map_err(|e| anyhow::anyhow!("cannot locate the skim executable: {e}"))?;
+    let mut stale = 0;
+    let mut stdout = io::stdout().lock();
+    for path in &manifests {
+        let pack = Pack::load(path)?;
+        let bundle = pack
+            .build(&exe)
+            .map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))?;
+        let output = pack.output_path();
+        if check {
+            if fs::read(&output).is_ok_and(|current| current == bundle) {
+                writeln!(stdout, "{}: up to date", output.display())?;
+            } else {
+                writeln!(stdout, "{}: out of date", output.display())?;
+                stale += 1;
+            }
+        } else {
+            if let Some(parent) = output.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| anyhow::anyhow!("cannot create {}: {e}", parent.display()))?;
+            }
+            crate::pure::write_atomically(&output, &bundle)?;
+            writeln!(
+                stdout,
+                "{}: wrote {} bytes from {}",
+                output.display(),
+                bundle.len(),
+                path.display()
+            )?;
+        }
+    }
+
+    if stale > 0 {
+        eprintln!("skim build: {stale} pack(s) out of date; run 'skim build' to refresh them");
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The `*.skim.toml` files in `dir`, sorted.
+fn find_manifests(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut manifests = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_manifest = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(MANIFEST_SUFFIX));
+        if is_manifest && path.is_file() {
+            manifests.push(path);
+        }
+    }
+    manifests.sort();
+    Ok(manifests)
+}
+
+// ============================================================================
+// Manifest
+// ============================================================================
+
+/// A pack manifest, as written.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    /// Files, directories and globs to pack, in order.
+    inputs: Vec<String>,
+    /// Where the pack is written.
+    output: PathBuf,
+    /// Transformation mode (`--mode`); skim's default when absent.
+    mode: Option<String>,
+    /// Output format (`--format`): text, markdown or claude-xml.
+    format: Option<String>,
+    /// Per-file token budget (`--tokens`).
+    tokens: Option<usize>,
+    /// Further file-operation flags, one token each (`--max-params=3`).
+    #[serde(default)]
+    flags: Vec<String>,
+}
+
+/// A loaded manifest and the directory its paths are relative to.
+#[derive(Debug)]
+struct Pack {
+    manifest: Manifest,
+    dir: PathBuf,
+}
+
+impl Pack {
+    /// Read and check the manifest at `path`.
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("cannot read {}: {e}", path.display()))?;
+        let manifest = parse_manifest(&text)
+            .map_err(|e| anyhow::anyhow!("invalid {}: {e}", path.display()))?;
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let pack = Self { manifest, dir };
+        pack.check_output_outside_inputs()
+            .map_err(|e| anyhow::anyhow!("invalid {}: {e}", path.display()))?;
+        Ok(pack)
+    }
+
+    fn output_path(&self) -> PathBuf {
+        self.dir.join(&self.manifest.output)
+    }
+
+    /// Reject an output inside an input directory (or equal to an input
+    /// file): each build would pack the previous one.
+    fn check_output_outside_inputs(&self) -> anyhow::Result<()> {
+        let output = resolve(&self.output_path());
+        for input in &self.manifest.inputs {
+            let input_path = self.dir.join(input);
+            let Ok(input_path) = input_path.canonicalize() else {
+                continue; // globs and missing paths are left to skim
+            };
+            if output.starts_with(&input_path) {
+                anyhow::bail!(
+                    "output '{}' lies inside input '{}', so each build would pack the last one",
+                    self.manifest.output.display(),
+                    super::sanitize_for_display(input)
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The skim arguments that produce this pack.
+    fn skim_args(&self) -> Vec<String> {
+        let manifest = &self.manifest;
+        let mut args = vec!["--hermetic".to_string()];
+        if let Some(mode) = &manifest.mode {
+            args.push(format!("--mode={mode}"));
+        }
+        if let Some(format) = &manifest.format {
+            args.push(format!("--format={format}"));
+        }
+        if let Some(tokens) = manifest.tokens {
+            args.push(format!("--tokens={tokens}"));
+        }
+        args.extend(manifest.flags.iter().cloned());
+        args.push("--".to_string());
+        args.extend(manifest.inputs.iter().cloned());
+        args
+    }
+
+    /// Run skim from the manifest's directory and return the pack's bytes.
+    fn build(&self, exe: &Path) -> anyhow::Result<Vec<u8>> {
+        let output = Command::new(exe)
+            .args(self.skim_args())
+            .current_dir(&self.dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|e| anyhow::anyhow!("failed to run skim: {e}"))?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "building {} failed ({})",
+                self.manifest.output.display(),
+                output.status
+            );
+        }
+        Ok(output.stdout)
+    }
+}
+
+/// `path` with its longest existing prefix canonicalized, so a file that
+/// does not exist yet can be compared with existing directories.
+fn resolve(path: &Path) -> PathBuf {
+    for ancestor in path.ancestors() {
+        if let (Ok(real), Ok(rest)) = (ancestor.canonicalize(), path.strip_prefix(ancestor)) {
+            return real.join(rest);
+        }
+    }
+    path.to_path_buf()
+}
+
+/// Parse manifest text, checking what skim cannot check for us later.
+fn parse_manifest(text: &str) -> anyhow::Result<Manifest> {
+    let manifest: Manifest =
+        toml::from_str(text).map_err(|e| anyhow::anyhow!("{}", e.message()))?;
+    if manifest.inputs.is_empty() {
+        anyhow::bail!("'inputs' lists no files");
+    }
+    if manifest.inputs.iter().any(|input| input == "-") {
+        anyhow::bail!("'inputs' cannot read stdin ('-')");
+    }
+    if manifest.tokens == Some(0) {
+        anyhow::bail!("'tokens' must be positive");
+    }
+    for flag in &manifest.flags {
+        let display = super::sanitize_for_display(flag);
+        let Some(body) = flag.strip_prefix("--") else {
+            anyhow::bail!("flag '{display}' is not a long flag (write --name or --name=value)");
+        };
+        let long = body.split_once('=').map_or(body, |(long, _)| long);
+        if MANAGED_FLAGS.contains(&long) {
+            anyhow::bail!("flag '{display}' is set by the manifest's own keys or output path");
+        }
+    }
+    Ok(manifest)
+}
+
+// ============================================================================
+// Help
+// ============================================================================
+
+fn print_help() {
+    println!("skim build");
+    println!();
+    println!("  Produce the context packs declared in pack manifests (*{MANIFEST_SUFFIX}).");
+    println!();
+    println!("Usage: skim build [MANIFEST]... [--check]");
+    println!();
+    println!("FLAGS:");
+    println!("  --check           Rebuild without writing; fail if any output is out of date");
+    println!();
+    println!("  With no MANIFEST, builds every *{MANIFEST_SUFFIX} in the current directory.");
+    println!("  Paths in a manifest are relative to it. Builds are hermetic: no cache,");
+    println!("  no global ignore files and no skim.toml presets.");
+    println!();
+    println!("MANIFEST:");
+    println!("  inputs = [\"src/api/\", \"README.md\"]   Files, directories and globs (required)");
+    println!("  output = \"context.md\"                Where the pack is written (required)");
+    println!("  mode = \"signatures\"                  --mode (default: structure)");
+    println!("  format = \"markdown\"                  --format: text, markdown or claude-xml");
+    println!("  tokens = 4000                        --tokens per-file budget");
+    println!("  flags = [\"--public-only\"]            Other skim flags, one token each");
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim build                                   Build every manifest here");
+    println!("  skim build docs/contextpack.skim.toml        Build one pack");
+    println!("  skim build --check                           CI: verify committed packs");
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(text: &str) -> Pack {
+        Pack {
+            manifest: parse_manifest(text).unwrap(),
+            dir: PathBuf::from("."),
+        }
+    }
+
+    #[test]
+    fn test_manifest_keys_become_skim_args() {
+        let pack = pack(
+            "inputs = [\"src/\", \"-odd.rs\"]\noutput = \"ctx.md\"\nmode = \"signatures\"\n\
+             format = \"markdown\"\ntokens = 4000\nflags = [\"--public-only\"]\n",
+        );
+        assert_eq!(
+            pack.skim_args(),
+            [
+                "--hermetic",
+                "--mode=signatures",
+                "--format=markdown",
+                "--tokens=4000",
+                "--public-only",
+                "--",
+                "src/",
+                "-odd.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_manifest_rejects_typos_and_managed_flags() {
+        let err =
+            parse_manifest("inputs = [\"a.rs\"]\noutput = \"o\"\nmodes = \"types\"\n").unwrap_err();
+        assert!(err.to_string().contains("modes"), "{err}");
+        assert!(parse_manifest("inputs = []\noutput = \"o\"\n").is_err());
+        assert!(parse_manifest("inputs = [\"-\"]\noutput = \"o\"\n").is_err());
+        let err =
+            parse_manifest("inputs = [\"a.rs\"]\noutput = \"o\"\nflags = [\"--mode=full\"]\n")
+                .unwrap_err();
+        assert!(err.to_string().contains("manifest's own keys"), "{err}");
+        assert!(parse_manifest("inputs = [\"a.rs\"]\noutput = \"o\"\nflags = [\"-n\"]\n").is_err());
+    }
+
+    #[test]
+    fn test_output_inside_an_input_directory_is_rejected() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        let mut pack = pack("inputs = [\"src\"]\noutput = \"src/new/ctx.md\"\n");
+        pack.dir = dir.path().to_path_buf();
+        assert!(pack.check_output_outside_inputs().is_err());
+        pack.manifest.output = PathBuf::from("ctx.md");
+        assert!(pack.check_output_outside_inputs().is_ok());
+    }
+}
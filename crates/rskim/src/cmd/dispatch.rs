@@ -8,8 +8,9 @@ use std::io::{self, Write};
 use std::process::{Command, ExitCode};
 
 use super::{
-    KNOWN_SUBCOMMANDS, agents, build, completions, db, discover, file, git, heatmap, infra, init,
-    learn, lint, log, pkg, rewrite, sanitize_for_display, search, stats, test,
+    KNOWN_SUBCOMMANDS, agents, bench, bench_cache, build, completions, contextpack, db, discover,
+    explain, file, git, heatmap, infra, init, learn, lint, log, pkg, replay, rewrite,
+    sanitize_for_display, search, stats, summarize_deps, test, verify_cache,
 };
 
 // ============================================================================
@@ -586,16 +587,23 @@ pub(crate) fn dispatch(
     match subcommand {
         // Unchanged meta/utility
         "agents" => agents::run(args, analytics),
+        "bench" => bench::run(args, analytics),
+        "bench-cache" => bench_cache::run(args, analytics),
+        "build" => contextpack::run(args, analytics),
         "completions" => completions::run(args, analytics),
         "discover" => discover::run(args, analytics),
+        "explain" => explain::run(args, analytics),
         "git" => git::run(args, analytics),
         "heatmap" => heatmap::run(args, analytics),
         "init" => init::run(args, analytics),
         "learn" => learn::run(args, analytics),
         "log" => log::run(args, analytics),
+        "replay" => replay::run(args, analytics),
         "rewrite" => rewrite::run(args, analytics),
         "search" => search::run(args, analytics),
         "stats" => stats::run(args, analytics),
+        "summarize-deps" => summarize_deps::run(args, analytics),
+        "verify-cache" => verify_cache::run(args, analytics),
 
         // Multi-category dispatchers
         "cargo" => dispatch_cargo(args, analytics),
@@ -620,6 +628,8 @@ pub(crate) fn dispatch(
         "aws" | "curl" | "dig" | "docker" | "gh" | "kubectl" | "nslookup" | "terraform"
         | "wget" => infra::run(&prepend(subcommand, args), analytics),
         "mysql" | "psql" | "sqlite3" => db::run(&prepend(subcommand, args), analytics),
+        // `skim ls --dry-run` lists skim's own inputs instead of running ls.
+        "ls" if crate::list::is_dry_run(args) => crate::list::run_subcommand(args),
         "df" | "diff" | "du" | "env" | "find" | "grep" | "ls" | "printenv" | "ps" | "rg"
         | "tree" | "wc" => file::run(&prepend(subcommand, args), analytics),
 
@@ -0,0 +1,247 @@
+//! Explain subcommand — trace structure-mode decisions for one file.
+//!
+//! Prints the AST node kinds skim visited, which nodes matched as functions or
+//! type definitions, which replacements were planned and why, and which were
+//! dropped by overlap resolution. Answers "why did skim keep/drop X" without
+//! reading the transformer source.
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use rskim_core::{ExplainAction, ExplainReason, Explanation, Language, Mode, TransformConfig};
+
+// ============================================================================
+// Public entry point
+// ============================================================================
+
+/// Run the `skim explain` subcommand.
+pub(crate) fn run(
+    args: &[String],
+    _analytics: &crate::analytics::AnalyticsConfig,
+) -> anyhow::Result<ExitCode> {
+    if args.is_empty() || args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut file = None;
+    let mut lang = None;
+    let mut template_threshold = Some(TransformConfig::DEFAULT_TEMPLATE_LITERAL_THRESHOLD);
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name: &str| {
+            inline
+                .clone()
+                .or_else(|| iter.next().cloned())
+                .ok_or_else(|| anyhow::anyhow!("{name} requires a value"))
+        };
+        match flag {
+            "--lang" | "--language" | "-l" => {
+                let raw = value("--lang")?;
+                lang = Some(Language::from_fence_tag(&raw).ok_or_else(|| {
+                    anyhow::anyhow!("unknown language: '{}'", super::sanitize_for_display(&raw))
+                })?);
+            }
+            "--template-threshold" => {
+                let raw = value("--template-threshold")?;
+                let bytes: usize = raw.parse().map_err(|_| {
+                    anyhow::anyhow!("--template-threshold expects a byte count, got '{raw}'")
+                })?;
+                template_threshold = (bytes > 0).then_some(bytes);
+            }
+            other if other.starts_with('-') => anyhow::bail!(
+                "unknown flag for skim explain: '{}'\nRun 'skim explain --help' for usage",
+                super::sanitize_for_display(other)
+            ),
+            _ if file.is_some() => anyhow::bail!("skim explain takes exactly one file"),
+            _ => file = Some(arg.clone()),
+        }
+    }
+
+    let Some(file) = file else {
+        anyhow::bail!("missing <FILE>\nRun 'skim explain --help' for usage");
+    };
+    let path = Path::new(&file);
+    let language = match lang.or_else(|| Language::from_path(path)) {
+        Some(language) => language,
+        None => anyhow::bail!(
+            "cannot detect language for {}; pass --lang",
+            super::sanitize_for_display(&file)
+        ),
+    };
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("failed to read {}: {e}", path.display()))?;
+
+    let config = TransformConfig::with_mode(Mode::Structure)
+        .with_template_literal_threshold(template_threshold);
+    let trace = rskim_core::explain_structure(&source, language, &config)?;
+
+    let mut stdout = io::stdout().lock();
+    write_report(&mut stdout, &file, &trace)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+// ============================================================================
+// Report
+// ============================================================================
+
+fn write_report(out: &mut impl Write, file: &str, trace: &Explanation) -> io::Result<()> {
+    writeln!(
+        out,
+        "{file} ({}, structure mode){}",
+        trace.language.name(),
+        if trace.has_errors {
+            " — parse has syntax errors"
+        } else {
+            ""
+        }
+    )?;
+
+    let total: usize = trace.node_kinds.iter().map(|(_, n)| n).sum();
+    writeln!(out)?;
+    writeln!(
+        out,
+        "AST node kinds ({total} named nodes, {} kinds):",
+        trace.node_kinds.len()
+    )?;
+    let kind_width = trace
+        .node_kinds
+        .iter()
+        .map(|(k, _)| k.len())
+        .max()
+        .unwrap_or(0);
+    for (kind, count) in &trace.node_kinds {
+        writeln!(out, "  {kind:<kind_width$}  {count}")?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "Decisions:")?;
+    if trace.decisions.is_empty() {
+        writeln!(
+            out,
+            "  (no functions, types, long template literals, or script statements matched)"
+        )?;
+    }
+    let kind_width = trace
+        .decisions
+        .iter()
+        .map(|d| d.node_kind.len())
+        .max()
+        .unwrap_or(0);
+    let (mut replaced, mut skipped, mut kept) = (0, 0, 0);
+    for d in &trace.decisions {
+        let (start, end) = d.byte_range;
+        let detail = match d.action {
+            ExplainAction::Replaced {
+                reason,
+                replacement,
+            } => {
+                replaced += 1;
+                format!(
+                    "replaced [{start}..{end}) with {replacement:?} ({})",
+                    describe_reason(reason)
+                )
+            }
+            ExplainAction::SkippedOverlap {
+                reason,
+                covered_by_line,
+            } => {
+                skipped += 1;
+                format!(
+                    "skipped [{start}..{end}) ({}): inside replacement from line {covered_by_line}",
+                    describe_reason(reason)
+                )
+            }
+            ExplainAction::NoBody => {
+                kept += 1;
+                "kept: matched as function but has no body".to_string()
+            }
+            ExplainAction::KeptType => {
+                kept += 1;
+                "kept: type definition".to_string()
+            }
+        };
+        writeln!(
+            out,
+            "  L{:<5} {:<kind_width$}  {detail}",
+            d.line, d.node_kind
+        )?;
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Summary: {replaced} replaced, {skipped} skipped by overlap, {kept} kept verbatim"
+    )
+}
+
+fn describe_reason(reason: ExplainReason) -> String {
+    match reason {
+        ExplainReason::FunctionBody => "function body".to_string(),
+        ExplainReason::TemplateLiteral { threshold } => {
+            format!("template literal over {threshold} bytes")
+        }
+        ExplainReason::InitBody => "__init__ body; self attributes kept".to_string(),
+        ExplainReason::ScriptStatement => "top-level statement in script-style file".to_string(),
+        ExplainReason::LongBlock { max_lines } => {
+            format!("block over {max_lines} lines; header kept")
+        }
+    }
+}
+
+// ============================================================================
+// Help
+// ============================================================================
+
+fn print_help() {
+    println!("skim explain");
+    println!();
+    println!("  Trace the structure-mode decisions skim makes for one file.");
+    println!();
+    println!("Usage: skim explain [FLAGS] <FILE>");
+    println!();
+    println!("FLAGS:");
+    println!("  --lang <LANG>               Override language detection");
+    println!(
+        "  --template-threshold <BYTES> Collapse template literals longer than BYTES (0 = never)"
+    );
+    println!();
+    println!("OUTPUT:");
+    println!("  AST node kinds visited, then one line per function, type definition, or");
+    println!("  long template literal: replaced, skipped by overlap, or kept verbatim.");
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim explain src/app.ts");
+    println!("  skim explain --lang rust build.rs.in");
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_lists_kinds_decisions_and_summary() {
+        let source = "struct Point { x: i32 }\nfn main() {\n    let f = || { 1 };\n}\n";
+        let config = TransformConfig::with_mode(Mode::Structure);
+        let trace = rskim_core::explain_structure(source, Language::Rust, &config).unwrap();
+
+        let mut buf = Vec::new();
+        write_report(&mut buf, "main.rs", &trace).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+
+        assert!(out.starts_with("main.rs (Rust, structure mode)\n"), "{out}");
+        assert!(out.contains("function_item"), "{out}");
+        assert!(out.contains("kept: type definition"), "{out}");
+        assert!(out.contains("replaced ["), "{out}");
+        assert!(out.contains("Summary: 1 replaced"), "{out}");
+    }
+}
@@ -48,6 +48,8 @@ static RE_URL_CREDS: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"(://)[^:@/]+:[^@/]+(@)").unwrap());
 
 /// Exact set of well-known sensitive key names (uppercase).
+///
+/// Mirrored by the `.env` transform in `rskim-core`, which marks these keys.
 const SENSITIVE_EXACT: &[&str] = &[
     "AWS_ACCESS_KEY_ID",
     "AWS_SECRET_ACCESS_KEY",
@@ -68,6 +70,7 @@ const SENSITIVE_EXACT: &[&str] = &[
 /// `REGISTRY_KEY`, `PRIMARY_KEY`, and `SORT_KEY`. Specific compound suffixes
 /// (`_API_KEY`, `_SECRET_KEY`, `_PRIVATE_KEY`, `_ENCRYPTION_KEY`, `_SIGNING_KEY`,
 /// `_ACCESS_KEY`, `_HMAC_KEY`) are used instead to cover real crypto/signing secrets.
+/// Mirrored by the `.env` transform in `rskim-core`.
 const SENSITIVE_SUFFIXES: &[&str] = &[
     "_TOKEN",
     "_SECRET",
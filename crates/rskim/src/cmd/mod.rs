@@ -24,10 +24,14 @@
 //! transparent for everything else.
 
 mod agents;
+mod bench;
+mod bench_cache;
 pub(crate) mod build;
 mod completions;
+mod contextpack;
 mod db;
 mod discover;
+mod explain;
 mod file;
 mod git;
 mod heatmap;
@@ -40,13 +44,16 @@ mod learn;
 pub(crate) mod lint;
 mod log;
 mod pkg;
+mod replay;
 mod rewrite;
 mod search;
 mod session;
 pub(crate) mod session_sidecar;
 mod stats;
+mod summarize_deps;
 pub(crate) mod test;
 pub(crate) mod ux;
+mod verify_cache;
 
 // ============================================================================
 // Infrastructure submodules
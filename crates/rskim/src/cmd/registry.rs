@@ -22,73 +22,80 @@ use std::sync::LazyLock;
 /// `is_known_subcommand` can use `binary_search` — O(log n) instead of O(n).
 /// The `test_known_subcommands_are_sorted` test enforces this.
 pub(crate) const KNOWN_SUBCOMMANDS: &[&str] = &[
-    "agents",      // meta: skim management
-    "aws",         // infrastructure
-    "biome",       // linter
-    "black",       // linter
-    "cargo",       // multi-category dispatcher
-    "completions", // meta: skim management
-    "curl",        // infrastructure
-    "cypress",     // test runner
-    "df",          // file operations
-    "diff",        // file operations
-    "dig",         // infrastructure
-    "discover",    // meta: skim management
-    "docker",      // infrastructure
-    "dotnet",      // test runner / passthrough
-    "dprint",      // linter
-    "du",          // file operations
-    "env",         // file operations
-    "eslint",      // linter
-    "find",        // file operations
-    "gh",          // infrastructure
-    "git",         // multi-category dispatcher
-    "go",          // multi-category dispatcher
-    "gofmt",       // linter
-    "golangci",    // linter
-    "gradle",      // build tool
-    "gradlew",     // build tool
-    "grep",        // file operations
-    "heatmap",     // meta: skim management
-    "init",        // meta: skim management
-    "jest",        // test runner
-    "kubectl",     // infrastructure
-    "learn",       // meta: skim management
-    "log",         // meta: skim management (log compression, not a system tool)
-    "ls",          // file operations
-    "make",        // build tool
-    "mvn",         // build tool
-    "mvnw",        // build tool
-    "mypy",        // linter
-    "mysql",       // database
-    "npm",         // package manager
-    "nslookup",    // infrastructure
-    "oxlint",      // linter
-    "pip",         // package manager
-    "playwright",  // test runner
-    "pnpm",        // package manager
-    "prettier",    // linter
-    "printenv",    // file operations
-    "ps",          // file operations
-    "psql",        // database
-    "pytest",      // test runner
-    "rewrite",     // meta: skim management
-    "rg",          // file operations
-    "rubocop",     // linter
-    "ruff",        // linter
-    "rustfmt",     // linter
-    "search",      // meta: skim management
-    "sqlite3",     // database
-    "stats",       // meta: skim management
-    "swift",       // test runner / passthrough
-    "swiftlint",   // linter
-    "terraform",   // infrastructure
-    "tree",        // file operations
-    "tsc",         // build tool
-    "vitest",      // test runner
-    "wc",          // file operations
-    "wget",        // infrastructure
-    "yarn",        // package manager
+    "agents",         // meta: skim management
+    "aws",            // infrastructure
+    "bench",          // meta: skim management
+    "bench-cache",    // meta: skim management
+    "biome",          // linter
+    "black",          // linter
+    "build",          // meta: skim management
+    "cargo",          // multi-category dispatcher
+    "completions",    // meta: skim management
+    "curl",           // infrastructure
+    "cypress",        // test runner
+    "df",             // file operations
+    "diff",           // file operations
+    "dig",            // infrastructure
+    "discover",       // meta: skim management
+    "docker",         // infrastructure
+    "dotnet",         // test runner / passthrough
+    "dprint",         // linter
+    "du",             // file operations
+    "env",            // file operations
+    "eslint",         // linter
+    "explain",        // meta: skim management
+    "find",           // file operations
+    "gh",             // infrastructure
+    "git",            // multi-category dispatcher
+    "go",             // multi-category dispatcher
+    "gofmt",          // linter
+    "golangci",       // linter
+    "gradle",         // build tool
+    "gradlew",        // build tool
+    "grep",           // file operations
+    "heatmap",        // meta: skim management
+    "init",           // meta: skim management
+    "jest",           // test runner
+    "kubectl",        // infrastructure
+    "learn",          // meta: skim management
+    "log",            // meta: skim management (log compression, not a system tool)
+    "ls",             // file operations
+    "make",           // build tool
+    "mvn",            // build tool
+    "mvnw",           // build tool
+    "mypy",           // linter
+    "mysql",          // database
+    "npm",            // package manager
+    "nslookup",       // infrastructure
+    "oxlint",         // linter
+    "pip",            // package manager
+    "playwright",     // test runner
+    "pnpm",           // package manager
+    "prettier",       // linter
+    "printenv",       // file operations
+    "ps",             // file operations
+    "psql",           // database
+    "pytest",         // test runner
+    "replay",         // meta: skim management
+    "rewrite",        // meta: skim management
+    "rg",             // file operations
+    "rubocop",        // linter
+    "ruff",           // linter
+    "rustfmt",        // linter
+    "search",         // meta: skim management
+    "sqlite3",        // database
+    "stats",          // meta: skim management
+    "summarize-deps", // meta: skim management
+    "swift",          // test runner / passthrough
+    "swiftlint",      // linter
+    "terraform",      // infrastructure
+    "tree",           // file operations
+    "tsc",            // build tool
+    "verify-cache",   // meta: skim management
+    "vitest",         // test runner
+    "wc",             // file operations
+    "wget",           // infrastructure
+    "yarn",           // package manager
 ];
 
 /// Meta/management subcommands that belong to skim itself.
@@ -115,15 +122,22 @@ pub(crate) const KNOWN_SUBCOMMANDS: &[&str] = &[
 ///   created by `skim init --wrappers`.
 pub(crate) const META_SUBCOMMANDS: &[&str] = &[
     "agents",
+    "bench",
+    "bench-cache",
+    "build",
     "completions",
     "discover",
+    "explain",
     "heatmap",
     "init",
     "learn",
     "log",
+    "replay",
     "rewrite",
     "search",
     "stats",
+    "summarize-deps",
+    "verify-cache",
 ];
 
 /// Check whether `name` is a registered meta/management subcommand.
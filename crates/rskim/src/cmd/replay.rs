@@ -0,0 +1,237 @@
+//! Replay subcommand — re-run transforms recorded with `--record`.
+//!
+//! Each case file holds one core transform's source, language and config
+//! together with the output (or error) it produced when recorded. Replay runs
+//! the same entry point again and reports whether the result still matches,
+//! so a case attached to a bug report reproduces deterministically.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use crate::record::{self, CASE_VERSION, Case};
+
+// ============================================================================
+// Public entry point
+// ============================================================================
+
+/// Run the `skim replay` subcommand.
+pub(crate) fn run(
+    args: &[String],
+    _analytics: &crate::analytics::AnalyticsConfig,
+) -> anyhow::Result<ExitCode> {
+    if args.is_empty() || args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut show = false;
+    let mut targets = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--show" => show = true,
+            other if other.starts_with('-') => anyhow::bail!(
+                "unknown flag for skim replay: '{}'\nRun 'skim replay --help' for usage",
+                super::sanitize_for_display(other)
+            ),
+            _ => targets.push(PathBuf::from(arg)),
+        }
+    }
+
+    let mut cases = Vec::new();
+    for target in &targets {
+        collect_case_files(target, &mut cases)?;
+    }
+    if cases.is_empty() {
+        anyhow::bail!("no case files (*.json) found in the given paths");
+    }
+
+    let mut stdout = io::stdout().lock();
+    let mut failed = 0;
+    for path in &cases {
+        let case = load_case(path)?;
+        let replayed = record::replay(&case)?;
+        let outcome = Outcome::of(&case, &replayed);
+        failed += usize::from(outcome.is_failure());
+        writeln!(
+            stdout,
+            "{:<8} {} ({}, {} mode)",
+            outcome.label(),
+            path.display(),
+            case.path,
+            case.config.mode
+        )?;
+        if let Err(e) = &replayed {
+            writeln!(stdout, "  error: {e}")?;
+        }
+        if show && let Ok(output) = &replayed {
+            write!(stdout, "{output}")?;
+            if !output.ends_with('\n') {
+                writeln!(stdout)?;
+            }
+        }
+    }
+
+    writeln!(
+        stdout,
+        "\n{} replayed, {} matched, {failed} differed",
+        cases.len(),
+        cases.len() - failed
+    )?;
+    Ok(if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+// ============================================================================
+// Cases
+// ============================================================================
+
+/// Add `target` (a case file) or the `*.json` files directly inside it (a
+/// record directory) to `out`, in name order.
+fn collect_case_files(target: &Path, out: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    if !target.is_dir() {
+        out.push(target.to_path_buf());
+        return Ok(());
+    }
+    let mut found: Vec<PathBuf> = fs::read_dir(target)
+        .map_err(|e| anyhow::anyhow!("failed to read {}: {e}", target.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    found.sort();
+    out.extend(found);
+    Ok(())
+}
+
+fn load_case(path: &Path) -> anyhow::Result<Case> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("failed to read {}: {e}", path.display()))?;
+    let case: Case = serde_json::from_str(&json)
+        .map_err(|e| anyhow::anyhow!("{} is not a skim case file: {e}", path.display()))?;
+    if case.version != CASE_VERSION {
+        anyhow::bail!(
+            "{}: case format version {} is not supported (expected {CASE_VERSION})",
+            path.display(),
+            case.version
+        );
+    }
+    Ok(case)
+}
+
+/// How a replayed transform compares with its recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// Same output, or the same error.
+    Match,
+    /// Different output or error.
+    Differ,
+    /// The recorded run never finished (it crashed); now it does.
+    Finished,
+}
+
+impl Outcome {
+    fn of(case: &Case, replayed: &rskim_core::Result<String>) -> Self {
+        match (&case.output, &case.error, replayed) {
+            (None, None, _) => Self::Finished,
+            (Some(expected), _, Ok(actual)) if expected == actual => Self::Match,
+            (_, Some(expected), Err(actual)) if *expected == actual.to_string() => Self::Match,
+            _ => Self::Differ,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Match => "ok",
+            Self::Differ => "DIFFERS",
+            Self::Finished => "FINISHED",
+        }
+    }
+
+    /// A crash that no longer reproduces is reported but is not a failure.
+    fn is_failure(self) -> bool {
+        self == Self::Differ
+    }
+}
+
+// ============================================================================
+// Help
+// ============================================================================
+
+fn print_help() {
+    println!("skim replay");
+    println!();
+    println!("  Re-run transforms recorded with `skim --record <DIR>` and compare the results.");
+    println!();
+    println!("Usage: skim replay [FLAGS] <DIR|CASE.json>...");
+    println!();
+    println!("FLAGS:");
+    println!("  --show    Print each replayed output");
+    println!();
+    println!("OUTPUT:");
+    println!("  One line per case: ok (same output or error), DIFFERS, or FINISHED (the");
+    println!("  recorded run crashed before finishing). Exits non-zero if any case differs.");
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim src/ --record /tmp/skim-rec");
+    println!("  skim replay /tmp/skim-rec");
+    println!("  skim replay --show /tmp/skim-rec/3f2a9c1d04b7e618.json");
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(output: Option<&str>, error: Option<&str>) -> Case {
+        serde_json::from_value(serde_json::json!({
+            "version": CASE_VERSION,
+            "skim_version": "0.0.0",
+            "entry": "transform_with_config",
+            "path": "a.rs",
+            "language": "rust",
+            "config": {
+                "mode": "structure",
+                "preserve_comments": true,
+                "max_lines": null,
+                "last_lines": null,
+                "line_numbers": false,
+                "fenced_code": false,
+                "template_literal_threshold": 512,
+                "public_only": false,
+                "module_doc": false,
+            },
+            "source": "fn a() {}\n",
+            "output": output,
+            "error": error,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_outcome_compares_output() {
+        let recorded = case(Some("fn a() {}\n"), None);
+        assert_eq!(
+            Outcome::of(&recorded, &Ok("fn a() {}\n".to_string())),
+            Outcome::Match
+        );
+        assert_eq!(
+            Outcome::of(&recorded, &Ok("fn b() {}\n".to_string())),
+            Outcome::Differ
+        );
+    }
+
+    #[test]
+    fn test_outcome_of_unfinished_recording() {
+        let recorded = case(None, None);
+        let outcome = Outcome::of(&recorded, &Ok(String::new()));
+        assert_eq!(outcome, Outcome::Finished);
+        assert!(!outcome.is_failure());
+    }
+}
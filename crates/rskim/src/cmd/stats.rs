@@ -2,7 +2,7 @@
 //!
 //! Queries the analytics SQLite database and displays a summary of token
 //! savings across all skim invocations. Supports time filtering (`--since`),
-//! JSON output (`--format json`), verbose parse-quality output (`--verbose`),
+//! JSON output (`--format json`, schema: `skim --schema stats`), verbose parse-quality output (`--verbose`),
 //! and data clearing (`--clear`). Cost estimates are always shown.
 
 use std::io::{self, Write};
@@ -18,6 +18,7 @@ use crate::analytics::{
     AnalyticsDb, AnalyticsStore, OriginalCommandStats, PricingModel, SessionStats,
 };
 use crate::cmd::session::types::parse_duration_ago;
+use crate::schema::SchemaFormat;
 use crate::tokens;
 
 // ============================================================================
@@ -36,7 +37,7 @@ pub(crate) fn run(
 
     // Parse flags
     if args.iter().any(|a| a == "--cost") {
-        eprintln!("skim: --cost is deprecated; cost estimates are now always shown");
+        crate::deprecation::warn(crate::deprecation::STATS_COST_FLAG);
     }
     let clear = args.iter().any(|a| a == "--clear");
     let verbose = args
@@ -173,7 +174,8 @@ fn run_json(
         "tokens_saved": summary.tokens_saved,
     });
 
-    let root = serde_json::json!({
+    let mut root = serde_json::json!({
+        "schema_version": SchemaFormat::Stats.version(),
         "summary": {
             "invocations": summary.invocations,
             "raw_tokens": summary.raw_tokens,
@@ -196,6 +198,7 @@ fn run_json(
         },
         "cost_estimate": cost_estimate,
     });
+    crate::deprecation::attach(&mut root);
 
     writeln!(w, "{}", serde_json::to_string_pretty(&root)?)?;
     Ok(ExitCode::SUCCESS)
@@ -913,6 +916,7 @@ mod tests {
             parsed["cost_estimate"].is_object(),
             "cost_estimate should always be in JSON output"
         );
+        crate::schema::check(&SchemaFormat::Stats.schema(), &parsed).unwrap();
     }
 
     #[test]
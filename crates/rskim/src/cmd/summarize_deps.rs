@@ -0,0 +1,530 @@
+//! Summarize-deps subcommand — compact summaries of third-party packages.
+//!
+//! Dependency directories (`node_modules`, `site-packages`, `vendor`) are
+//! usually excluded from agent context because reading them costs more than
+//! they are worth. When an agent does need them, this prints each package's
+//! name, version and the signatures of its public entry point, capped per
+//! package, so a dependency costs a few hundred tokens instead of its source:
+//!
+//! ```text
+//! // package left-pad@1.3.0 (entry: index.d.ts)
+//! function leftPad(str: string | number, len: number, ch?: string | number): string;
+//! ```
+//!
+//! Layouts are recognised by their manifests: `package.json` directories
+//! (including `@scope/` directories) for npm, `*.dist-info` directories for
+//! Python, `Cargo.toml` directories for `cargo vendor`, and `modules.txt` for
+//! Go's `vendor/`.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use rskim_core::{Language, Mode, TransformConfig};
+
+/// Default cap on signature lines printed per package.
+const DEFAULT_MAX_LINES: usize = 40;
+
+// ============================================================================
+// Public entry point
+// ============================================================================
+
+/// Run the `skim summarize-deps` subcommand.
+pub(crate) fn run(
+    args: &[String],
+    _analytics: &crate::analytics::AnalyticsConfig,
+) -> anyhow::Result<ExitCode> {
+    if args.is_empty() || args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut dir = None;
+    let mut names = Vec::new();
+    let mut max_lines = DEFAULT_MAX_LINES;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        match flag {
+            "--max-lines" => {
+                let raw = inline
+                    .or_else(|| iter.next().cloned())
+                    .ok_or_else(|| anyhow::anyhow!("--max-lines requires a value"))?;
+                max_lines = raw.parse().ok().filter(|&n| n > 0).ok_or_else(|| {
+                    anyhow::anyhow!("--max-lines expects a positive line count, got '{raw}'")
+                })?;
+            }
+            other if other.starts_with('-') => anyhow::bail!(
+                "unknown flag for skim summarize-deps: '{}'\nRun 'skim summarize-deps --help' for usage",
+                super::sanitize_for_display(other)
+            ),
+            _ if dir.is_none() => dir = Some(PathBuf::from(arg)),
+            _ => names.push(arg.clone()),
+        }
+    }
+
+    let Some(dir) = dir else {
+        anyhow::bail!("missing <DIR>\nRun 'skim summarize-deps --help' for usage");
+    };
+    if !dir.is_dir() {
+        anyhow::bail!(
+            "{} is not a directory",
+            super::sanitize_for_display(&dir.display().to_string())
+        );
+    }
+
+    let mut packages = discover_packages(&dir);
+    if !names.is_empty() {
+        if let Some(missing) = names
+            .iter()
+            .find(|name| !packages.iter().any(|p| &p.name == *name))
+        {
+            anyhow::bail!(
+                "no package named '{}' in {}",
+                super::sanitize_for_display(missing),
+                dir.display()
+            );
+        }
+        packages.retain(|p| names.contains(&p.name));
+    }
+    if packages.is_empty() {
+        anyhow::bail!(
+            "no packages found in {} (expected node_modules, site-packages, or vendor)",
+            dir.display()
+        );
+    }
+
+    let mut stdout = io::stdout().lock();
+    for (idx, package) in packages.iter().enumerate() {
+        if idx > 0 {
+            writeln!(stdout)?;
+        }
+        write_package(&mut stdout, package, max_lines)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// ============================================================================
+// Discovery
+// ============================================================================
+
+/// One installed package and the files of its public entry point.
+#[derive(Debug, PartialEq, Eq)]
+struct Package {
+    name: String,
+    version: Option<String>,
+    /// Entry point as printed in the banner, relative to the package.
+    entry: Option<String>,
+    /// Files whose signatures summarize the package (several for Go).
+    files: Vec<PathBuf>,
+}
+
+/// Every package under `dir`, sorted by name.
+fn discover_packages(dir: &Path) -> Vec<Package> {
+    let mut packages = match std::fs::read_to_string(dir.join("modules.txt")) {
+        Ok(modules) => go_packages(dir, &modules),
+        Err(_) => child_dirs(dir)
+            .into_iter()
+            .flat_map(|child| {
+                let name = child.file_name().unwrap_or_default().to_string_lossy();
+                if name.starts_with('@') {
+                    child_dirs(&child)
+                        .into_iter()
+                        .filter_map(|scoped| npm_package(&scoped))
+                        .collect()
+                } else if name.ends_with(".dist-info") {
+                    python_package(dir, &child).into_iter().collect()
+                } else {
+                    npm_package(&child)
+                        .or_else(|| cargo_package(&child))
+                        .into_iter()
+                        .collect::<Vec<_>>()
+                }
+            })
+            .collect(),
+    };
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
+    packages
+}
+
+/// Subdirectories of `dir` (following symlinks, as pnpm links packages),
+/// sorted, skipping hidden ones such as `.bin` and `.pnpm`.
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_dir()
+                && !path
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// An npm package: prefers its type declarations (`types`, or a `.d.ts`
+/// beside the entry), which hold the signatures without the bodies.
+fn npm_package(dir: &Path) -> Option<Package> {
+    let manifest = crate::grouping::read_package_json(dir)?;
+    let field = |key: &str| manifest.get(key).and_then(serde_json::Value::as_str);
+    let name = field("name")?.to_string();
+    let version = field("version").map(str::to_string);
+
+    let main = crate::grouping::js_entry_point(&manifest).unwrap_or_else(|| "index.js".into());
+    let candidates = [
+        field("types")
+            .or_else(|| field("typings"))
+            .map(str::to_string),
+        Some(declaration_path(&main)),
+        Some(main.clone()),
+        Some(format!("{}/index.js", main.trim_end_matches(".js"))),
+    ];
+    let entry = candidates
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.trim_start_matches("./").to_string())
+        .find(|entry| dir.join(entry).is_file());
+    Some(Package {
+        name,
+        version,
+        files: entry.iter().map(|entry| dir.join(entry)).collect(),
+        entry,
+    })
+}
+
+/// `lib/index.js` → `lib/index.d.ts`
+fn declaration_path(entry: &str) -> String {
+    let stem = [".js", ".cjs", ".mjs"]
+        .iter()
+        .find_map(|ext| entry.strip_suffix(ext))
+        .unwrap_or(entry);
+    format!("{stem}.d.ts")
+}
+
+/// A Python distribution from its `*.dist-info` directory: name and version
+/// from `METADATA`, importable modules from `top_level.txt`.
+fn python_package(site_packages: &Path, dist_info: &Path) -> Option<Package> {
+    let metadata = std::fs::read_to_string(dist_info.join("METADATA")).ok()?;
+    let header = |key: &str| {
+        metadata
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix(':'))
+            .map(|value| value.trim().to_string())
+    };
+    let name = header("Name")?;
+    let version = header("Version");
+
+    let modules: Vec<String> = match std::fs::read_to_string(dist_info.join("top_level.txt")) {
+        Ok(top_level) => top_level
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Err(_) => vec![name.to_lowercase().replace('-', "_")],
+    };
+    let files: Vec<PathBuf> = modules
+        .iter()
+        .filter_map(|module| {
+            [
+                format!("{module}/__init__.pyi"),
+                format!("{module}/__init__.py"),
+                format!("{module}.pyi"),
+                format!("{module}.py"),
+            ]
+            .into_iter()
+            .map(|candidate| site_packages.join(candidate))
+            .find(|path| path.is_file())
+        })
+        .collect();
+    let entry = (!files.is_empty()).then(|| {
+        files
+            .iter()
+            .map(|path| relative_to(site_packages, path))
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+    Some(Package {
+        name,
+        version,
+        entry,
+        files,
+    })
+}
+
+/// A crate copied by `cargo vendor`: its library root.
+fn cargo_package(dir: &Path) -> Option<Package> {
+    let content = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let manifest: toml::Table = content.parse().ok()?;
+    let package = manifest.get("package")?;
+    let name = package.get("name")?.as_str()?.to_string();
+    let version = package
+        .get("version")
+        .and_then(toml::Value::as_str)
+        .map(str::to_string);
+    let lib = manifest
+        .get("lib")
+        .and_then(|lib| lib.get("path"))
+        .and_then(toml::Value::as_str)
+        .unwrap_or("src/lib.rs");
+    let entry = dir.join(lib).is_file().then(|| lib.to_string());
+    Some(Package {
+        name,
+        version,
+        files: entry.iter().map(|entry| dir.join(entry)).collect(),
+        entry,
+    })
+}
+
+/// Go modules listed in `vendor/modules.txt` (`# <module> <version>`): the
+/// non-test `.go` files of each module's root package.
+fn go_packages(vendor: &Path, modules: &str) -> Vec<Package> {
+    modules
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.strip_prefix("# ")?.split_whitespace();
+            let name = fields.next()?.to_string();
+            let version = fields.next().filter(|v| *v != "=>").map(str::to_string);
+            let dir = vendor.join(&name);
+            let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.is_file()
+                        && path.extension().is_some_and(|ext| ext == "go")
+                        && !path.to_string_lossy().ends_with("_test.go")
+                })
+                .collect();
+            files.sort();
+            let entry = (!files.is_empty()).then(|| format!("{name}/*.go"));
+            Some(Package {
+                name,
+                version,
+                entry,
+                files,
+            })
+        })
+        .collect()
+}
+
+fn relative_to(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+// ============================================================================
+// Output
+// ============================================================================
+
+/// Print the banner of `package` and at most `max_lines` signature lines.
+fn write_package(out: &mut impl Write, package: &Package, max_lines: usize) -> io::Result<()> {
+    let version = package
+        .version
+        .as_deref()
+        .map(|v| format!("@{v}"))
+        .unwrap_or_default();
+    let entry = package.entry.as_deref().map_or_else(
+        || "no entry point found".to_string(),
+        |e| format!("entry: {e}"),
+    );
+    writeln!(out, "// package {}{version} ({entry})", package.name)?;
+
+    let mut remaining = max_lines;
+    for file in &package.files {
+        if remaining == 0 {
+            writeln!(
+                out,
+                "// ... more signatures omitted (--max-lines {max_lines})"
+            )?;
+            break;
+        }
+        let Some(signatures) = signatures(file, remaining) else {
+            continue;
+        };
+        for line in signatures.lines().filter(|line| !line.trim().is_empty()) {
+            writeln!(out, "{line}")?;
+            remaining = remaining.saturating_sub(1);
+        }
+    }
+    Ok(())
+}
+
+/// Signatures of `file`, truncated to `max_lines`; `None` when the file is
+/// unreadable, too large, or not in a language skim transforms.
+fn signatures(file: &Path, max_lines: usize) -> Option<String> {
+    let language = Language::from_path(file)?;
+    let metadata = std::fs::metadata(file).ok()?;
+    if metadata.len() as usize > crate::process::MAX_INPUT_SIZE {
+        return None;
+    }
+    let source = std::fs::read_to_string(file).ok()?;
+    let config = TransformConfig::with_mode(Mode::Signatures).with_max_lines(max_lines);
+    rskim_core::transform_with_config(&source, language, &config).ok()
+}
+
+// ============================================================================
+// Help
+// ============================================================================
+
+fn print_help() {
+    println!("skim summarize-deps");
+    println!();
+    println!("  Summarize third-party packages as name, version and entry-point signatures.");
+    println!();
+    println!("Usage: skim summarize-deps [FLAGS] <DIR> [PACKAGE...]");
+    println!();
+    println!("ARGS:");
+    println!("  <DIR>          node_modules, site-packages, or a Cargo/Go vendor directory");
+    println!("  [PACKAGE...]   Only summarize these packages (default: all)");
+    println!();
+    println!("FLAGS:");
+    println!("  --max-lines <N>  Signature lines kept per package (default: {DEFAULT_MAX_LINES})");
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim summarize-deps node_modules react zod");
+    println!("  skim summarize-deps .venv/lib/python3.12/site-packages requests");
+    println!("  skim summarize-deps vendor --max-lines 20");
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_npm_packages_prefer_declarations_and_include_scopes() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "left-pad/package.json",
+            r#"{"name": "left-pad", "version": "1.3.0", "main": "index.js"}"#,
+        );
+        write(root, "left-pad/index.js", "module.exports = leftPad;\n");
+        write(
+            root,
+            "left-pad/index.d.ts",
+            "declare function leftPad(s: string): string;\n",
+        );
+        write(
+            root,
+            "@acme/utils/package.json",
+            r#"{"name": "@acme/utils", "exports": {".": {"import": "./dist/index.mjs"}}}"#,
+        );
+        write(
+            root,
+            "@acme/utils/dist/index.mjs",
+            "export function add(a, b) { return a + b; }\n",
+        );
+        write(root, ".bin/package.json", r#"{"name": "hidden"}"#);
+
+        let packages = discover_packages(root);
+        let names: Vec<&str> = packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["@acme/utils", "left-pad"]);
+        assert_eq!(packages[0].entry.as_deref(), Some("dist/index.mjs"));
+        assert_eq!(packages[1].entry.as_deref(), Some("index.d.ts"));
+        assert_eq!(packages[1].version.as_deref(), Some("1.3.0"));
+    }
+
+    #[test]
+    fn test_python_dist_info_uses_metadata_and_top_level() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "python_dateutil-2.9.0.dist-info/METADATA",
+            "Metadata-Version: 2.1\nName: python-dateutil\nVersion: 2.9.0\n\nName: not a header\n",
+        );
+        write(
+            root,
+            "python_dateutil-2.9.0.dist-info/top_level.txt",
+            "dateutil\n",
+        );
+        write(
+            root,
+            "dateutil/__init__.py",
+            "def parse(text):\n    return text\n",
+        );
+
+        let packages = discover_packages(root);
+        assert_eq!(
+            packages,
+            [Package {
+                name: "python-dateutil".into(),
+                version: Some("2.9.0".into()),
+                entry: Some("dateutil/__init__.py".into()),
+                files: vec![root.join("dateutil/__init__.py")],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_go_vendor_reads_modules_txt() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "modules.txt",
+            "# github.com/acme/log v1.2.0\n## explicit; go 1.21\ngithub.com/acme/log\n",
+        );
+        write(
+            root,
+            "github.com/acme/log/log.go",
+            "package log\n\nfunc Info(msg string) {}\n",
+        );
+        write(root, "github.com/acme/log/log_test.go", "package log\n");
+
+        let packages = discover_packages(root);
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].version.as_deref(), Some("v1.2.0"));
+        assert_eq!(packages[0].files, [root.join("github.com/acme/log/log.go")]);
+    }
+
+    #[test]
+    fn test_write_package_caps_signature_lines() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let source: String = (0..10)
+            .map(|i| format!("pub fn f{i}() -> u32 {{\n    {i}\n}}\n"))
+            .collect();
+        write(root, "src/lib.rs", &source);
+        let package = Package {
+            name: "many".into(),
+            version: Some("0.1.0".into()),
+            entry: Some("src/lib.rs".into()),
+            files: vec![root.join("src/lib.rs")],
+        };
+
+        let mut buf = Vec::new();
+        write_package(&mut buf, &package, 3).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert!(
+            out.starts_with("// package many@0.1.0 (entry: src/lib.rs)\n"),
+            "{out}"
+        );
+        assert!(out.lines().count() <= 4, "{out}");
+        assert!(!out.contains("f9"), "{out}");
+    }
+}
@@ -0,0 +1,266 @@
+//! Verify-cache subcommand — integrity check and pruning for the output cache.
+//!
+//! Scans the cache directory, flags corrupt entries, entries whose source file
+//! is gone or has changed, temporaries left by interrupted writes and, with
+//! `--older-than`, entries past a TTL. Flagged entries are removed unless
+//! `--dry-run` or `--check` is given. Designed to run unattended from cron or
+//! CI: `--check` exits 1 when anything is reclaimable.
+
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use serde::Serialize;
+
+use crate::cache::{self, Tally, VerifyOptions, VerifyReport};
+use crate::cmd::session::types::parse_duration_ago;
+use crate::schema::SchemaFormat;
+
+// ============================================================================
+// Public entry point
+// ============================================================================
+
+/// Run the `skim verify-cache` subcommand.
+pub(crate) fn run(
+    args: &[String],
+    _analytics: &crate::analytics::AnalyticsConfig,
+) -> anyhow::Result<ExitCode> {
+    if args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut dry_run = false;
+    let mut check = false;
+    let mut json = false;
+    let mut expire_before = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name: &str| {
+            inline
+                .clone()
+                .or_else(|| iter.next().cloned())
+                .ok_or_else(|| anyhow::anyhow!("{name} requires a value"))
+        };
+        match flag {
+            "--dry-run" => dry_run = true,
+            "--check" => check = true,
+            "--older-than" => expire_before = Some(parse_duration_ago(&value("--older-than")?)?),
+            "--format" => match value("--format")?.as_str() {
+                "json" => json = true,
+                "text" => json = false,
+                other => anyhow::bail!(
+                    "--format expects 'text' or 'json', got '{}'",
+                    super::sanitize_for_display(other)
+                ),
+            },
+            other => anyhow::bail!(
+                "unknown argument for skim verify-cache: '{}'\nRun 'skim verify-cache --help' for usage",
+                super::sanitize_for_display(other)
+            ),
+        }
+    }
+
+    let options = VerifyOptions {
+        prune: !(dry_run || check),
+        expire_before,
+    };
+    let report = cache::verify_cache(&options)?;
+
+    let mut stdout = io::stdout().lock();
+    if json {
+        writeln!(
+            stdout,
+            "{}",
+            serde_json::to_string_pretty(&JsonReport::new(&report))?
+        )?;
+    } else {
+        write_report(&mut stdout, &report, options.prune)?;
+    }
+
+    if check && report.reclaimable().entries > 0 {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// ============================================================================
+// Report
+// ============================================================================
+
+/// `--format json` document: the report under a `schema_version`.
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    report: &'a VerifyReport,
+}
+
+impl<'a> JsonReport<'a> {
+    fn new(report: &'a VerifyReport) -> Self {
+        Self {
+            schema_version: SchemaFormat::VerifyCache.version(),
+            report,
+        }
+    }
+}
+
+fn write_report(out: &mut impl Write, report: &VerifyReport, pruned: bool) -> io::Result<()> {
+    let rows = [
+        ("scanned", report.scanned),
+        ("corrupt", report.corrupt),
+        ("leftover", report.leftover),
+        ("orphaned", report.orphaned),
+        ("stale", report.stale),
+        ("expired", report.expired),
+    ];
+    for (label, tally) in rows {
+        writeln!(
+            out,
+            "  {label:<9} {:>7}  {:>9}",
+            tally.entries,
+            format_size(tally.bytes)
+        )?;
+    }
+
+    let reclaimable = report.reclaimable();
+    writeln!(out)?;
+    if reclaimable.entries == 0 {
+        writeln!(out, "Cache OK: nothing to reclaim")
+    } else if pruned {
+        writeln!(
+            out,
+            "Removed {} of {}",
+            describe(report.removed),
+            describe(reclaimable)
+        )
+    } else {
+        writeln!(
+            out,
+            "Reclaimable: {} (run without --dry-run/--check to remove)",
+            describe(reclaimable)
+        )
+    }
+}
+
+fn describe(tally: Tally) -> String {
+    let noun = if tally.entries == 1 {
+        "entry"
+    } else {
+        "entries"
+    };
+    format!("{} {noun} ({})", tally.entries, format_size(tally.bytes))
+}
+
+/// Format a byte count in human-readable form.
+pub(super) fn format_size(bytes: u64) -> String {
+    if bytes >= 1_024 * 1_024 {
+        format!("{:.1} MB", bytes as f64 / (1_024.0 * 1_024.0))
+    } else if bytes >= 1_024 {
+        format!("{:.1} KB", bytes as f64 / 1_024.0)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+// ============================================================================
+// Help
+// ============================================================================
+
+fn print_help() {
+    println!("skim verify-cache");
+    println!();
+    println!("  Check the output cache and remove entries that can never be used again.");
+    println!();
+    println!("Usage: skim verify-cache [FLAGS]");
+    println!();
+    println!("FLAGS:");
+    println!("  --dry-run               Report what would be removed, remove nothing");
+    println!("  --check                 Like --dry-run, but exit 1 if anything is reclaimable");
+    println!(
+        "  --older-than <DURATION> Also remove entries written before DURATION ago (7d, 24h, 4w)"
+    );
+    println!("  --format json           Output the report as JSON");
+    println!();
+    println!("FLAGGED ENTRIES:");
+    println!("  corrupt    not valid JSON, or not a skim cache entry");
+    println!("  leftover   temporary file from an interrupted write (older than 10 minutes)");
+    println!("  orphaned   source file no longer exists");
+    println!("  stale      source modified since the entry was written");
+    println!("  expired    older than --older-than");
+    println!();
+    println!("  Repo-relative entries (--cache-keys repo-relative) are shared between");
+    println!("  checkouts and are only removed when corrupt or expired.");
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim verify-cache                    Prune unusable entries");
+    println!("  skim verify-cache --older-than 30d   Also drop entries older than 30 days");
+    println!("  skim verify-cache --check            CI gate: fail if the cache needs pruning");
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(report: &VerifyReport, pruned: bool) -> String {
+        let mut buf = Vec::new();
+        write_report(&mut buf, report, pruned).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_report_healthy_cache() {
+        let report = VerifyReport {
+            scanned: Tally {
+                entries: 2,
+                bytes: 2048,
+            },
+            ..Default::default()
+        };
+        let out = render(&report, true);
+        assert!(out.contains("scanned         2     2.0 KB"), "{out}");
+        assert!(out.ends_with("Cache OK: nothing to reclaim\n"), "{out}");
+    }
+
+    #[test]
+    fn test_report_dry_run_and_prune_summaries() {
+        let stale = Tally {
+            entries: 1,
+            bytes: 10,
+        };
+        let mut report = VerifyReport {
+            scanned: stale,
+            stale,
+            ..Default::default()
+        };
+        assert!(
+            render(&report, false).contains("Reclaimable: 1 entry (10 B)"),
+            "{}",
+            render(&report, false)
+        );
+
+        report.removed = stale;
+        assert!(render(&report, true).contains("Removed 1 entry (10 B) of 1 entry (10 B)"));
+    }
+
+    #[test]
+    fn test_json_report_matches_schema() {
+        let report = VerifyReport {
+            scanned: Tally {
+                entries: 3,
+                bytes: 300,
+            },
+            ..Default::default()
+        };
+        let value = serde_json::to_value(JsonReport::new(&report)).unwrap();
+        assert_eq!(value["schema_version"], SchemaFormat::VerifyCache.version());
+        crate::schema::check(&SchemaFormat::VerifyCache.schema(), &value).unwrap();
+    }
+}
@@ -0,0 +1,794 @@
+//! File-level dependency graph of a directory (`--dep-graph`).
+//!
+//! Instead of each file's skimmed content, a directory run can print which of
+//! its files import which others. Import statements are read with
+//! tree-sitter and resolved against the files of the run only, so packages,
+//! the standard library and files outside the directory never appear:
+//!
+//! ```text
+//! src/lib.rs -> src/config.rs
+//! src/lib.rs -> src/run.rs
+//! src/run.rs -> src/config.rs
+//!
+//! // orphans: no imports to or from other files
+//! src/legacy.rs
+//! ```
+//!
+//! Imports are resolved for TypeScript/JavaScript (relative specifiers,
+//! `require`, `import()`), Python (absolute and relative imports), Rust
+//! (`mod` declarations and `crate::`/`super::`/`self::` paths), Go (packages
+//! under a `go.mod` module path), C/C++ (`#include "..."`) and Java
+//! (`import` declarations). Files in other languages can be import targets
+//! but import nothing themselves, and are never reported as orphans.
+
+use std::collections::{BTreeSet, HashMap};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+use ignore::WalkBuilder;
+use rskim_core::{Language, Parser};
+use tree_sitter::Node;
+
+use crate::multi::{self, IgnoreRules};
+use crate::process::MAX_INPUT_SIZE;
+
+/// Suffixes tried, in order, when resolving a relative JS/TS specifier.
+const MODULE_SUFFIXES: &[&str] = &[
+    "",
+    ".ts",
+    ".tsx",
+    ".d.ts",
+    ".js",
+    ".jsx",
+    ".mjs",
+    ".cjs",
+    "/index.ts",
+    "/index.tsx",
+    "/index.js",
+    "/index.jsx",
+];
+
+/// Print the dependency graph of the files under `dir` and exit without
+/// transforming them. With `dot`, the graph is a Graphviz digraph.
+pub(crate) fn run(dir: &Path, ignore: IgnoreRules, dot: bool) -> anyhow::Result<()> {
+    let index = Index::walk(dir, ignore);
+    if index.files.is_empty() {
+        anyhow::bail!("No supported files found in '{}'", dir.display());
+    }
+    let graph = Graph::build(dir, &index);
+
+    let mut stdout = io::stdout().lock();
+    if dot {
+        writeln!(stdout, "digraph deps {{")?;
+        for (from, to) in &graph.edges {
+            writeln!(stdout, "  {} -> {};", dot_id(from), dot_id(to))?;
+        }
+        for orphan in &graph.orphans {
+            writeln!(stdout, "  {};", dot_id(orphan))?;
+        }
+        writeln!(stdout, "}}")?;
+    } else {
+        for (from, to) in &graph.edges {
+            writeln!(stdout, "{} -> {}", from.display(), to.display())?;
+        }
+        if !graph.orphans.is_empty() {
+            if !graph.edges.is_empty() {
+                writeln!(stdout)?;
+            }
+            writeln!(stdout, "// orphans: no imports to or from other files")?;
+            for orphan in &graph.orphans {
+                writeln!(stdout, "{}", orphan.display())?;
+            }
+        }
+    }
+    stdout.flush()?;
+
+    eprintln!(
+        "[skim] {} file(s), {} import edge(s), {} orphan(s)",
+        index.files.len(),
+        graph.edges.len(),
+        graph.orphans.len()
+    );
+    Ok(())
+}
+
+/// The files of a run, by path relative to the walked directory.
+struct Index {
+    files: BTreeSet<PathBuf>,
+    /// Files by file name, for suffix lookups.
+    by_name: HashMap<String, Vec<PathBuf>>,
+    /// Files by parent directory, for Go packages and Java wildcards.
+    by_dir: HashMap<PathBuf, Vec<PathBuf>>,
+    /// Go module path prefixes and the directories they name.
+    go_modules: Vec<(String, PathBuf)>,
+}
+
+impl Index {
+    fn walk(dir: &Path, rules: IgnoreRules) -> Self {
+        let mut builder = WalkBuilder::new(dir);
+        multi::configure_walker(&mut builder, rules);
+        let mut files = BTreeSet::new();
+        let mut go_modules = Vec::new();
+        for entry in builder.build().filter_map(|entry| entry.ok()) {
+            if !entry.file_type().is_some_and(|ft| ft.is_file()) {
+                continue;
+            }
+            let Ok(rel) = entry.path().strip_prefix(dir) else {
+                continue;
+            };
+            let rel = normalize(rel);
+            if entry.file_name() == "go.mod" {
+                if let Some(module) = go_module(entry.path()) {
+                    go_modules.push((module, rel.parent().unwrap_or(Path::new("")).into()));
+                }
+                continue;
+            }
+            let small = entry
+                .metadata()
+                .is_ok_and(|meta| meta.len() as usize <= MAX_INPUT_SIZE);
+            if small && Language::from_path(&rel).is_some() {
+                files.insert(rel);
+            }
+        }
+        // A module declared above the walked directory names it by its path
+        // below the `go.mod`.
+        if go_modules.is_empty()
+            && let Ok(absolute) = dir.canonicalize()
+            && let Some((module, root)) = absolute.ancestors().find_map(|ancestor| {
+                go_module(&ancestor.join("go.mod")).map(|module| (module, ancestor))
+            })
+        {
+            let below = absolute.strip_prefix(root).unwrap_or(Path::new(""));
+            let prefix = below
+                .components()
+                .filter_map(|c| c.as_os_str().to_str())
+                .fold(module, |prefix, part| format!("{prefix}/{part}"));
+            go_modules.push((prefix, PathBuf::new()));
+        }
+
+        let mut by_name: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        let mut by_dir: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+        for file in &files {
+            if let Some(name) = file.file_name().and_then(|name| name.to_str()) {
+                by_name
+                    .entry(name.to_string())
+                    .or_default()
+                    .push(file.clone());
+            }
+            let parent = file.parent().unwrap_or(Path::new("")).to_path_buf();
+            by_dir.entry(parent).or_default().push(file.clone());
+        }
+        Self {
+            files,
+            by_name,
+            by_dir,
+            go_modules,
+        }
+    }
+
+    /// `path` if it is one of the files.
+    fn exact(&self, path: &Path) -> Option<PathBuf> {
+        self.files.contains(path).then(|| path.to_path_buf())
+    }
+
+    /// The shallowest file whose path ends with `suffix`.
+    fn by_suffix(&self, suffix: &Path) -> Option<PathBuf> {
+        let name = suffix.file_name()?.to_str()?;
+        self.by_name
+            .get(name)?
+            .iter()
+            .filter(|file| file.ends_with(suffix))
+            .min_by_key(|file| file.components().count())
+            .cloned()
+    }
+}
+
+/// The `module` path declared by the `go.mod` at `path`.
+fn go_module(path: &Path) -> Option<String> {
+    let text = std::fs::read_to_string(path).ok()?;
+    text.lines().find_map(|line| {
+        let module = line.trim().strip_prefix("module")?.trim();
+        Some(module.trim_matches('"').to_string()).filter(|m| !m.is_empty())
+    })
+}
+
+/// Import edges between the files of an [`Index`].
+struct Graph {
+    edges: BTreeSet<(PathBuf, PathBuf)>,
+    /// Files of a language with resolved imports that import no other file
+    /// and are imported by none.
+    orphans: Vec<PathBuf>,
+}
+
+impl Graph {
+    fn build(dir: &Path, index: &Index) -> Self {
+        let mut parsers: HashMap<Language, Parser> = HashMap::new();
+        let mut edges = BTreeSet::new();
+        let mut sources = Vec::new();
+        for file in &index.files {
+            let Some(language) = Language::from_path(file).filter(|l| resolves_imports(*l)) else {
+                continue;
+            };
+            sources.push(file);
+            let Ok(source) = std::fs::read_to_string(dir.join(file)) else {
+                continue;
+            };
+            let parser = match parsers.entry(language) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => match Parser::new(language) {
+                    Ok(parser) => entry.insert(parser),
+                    Err(_) => continue,
+                },
+            };
+            let Ok(tree) = parser.parse(&source) else {
+                continue;
+            };
+            let imports = Imports {
+                file,
+                language,
+                source: &source,
+                index,
+            };
+            for target in imports.targets(tree.root_node()) {
+                if &target != file {
+                    edges.insert((file.clone(), target));
+                }
+            }
+        }
+
+        let connected: BTreeSet<&PathBuf> = edges.iter().flat_map(|(a, b)| [a, b]).collect();
+        let orphans = sources
+            .into_iter()
+            .filter(|file| !connected.contains(file))
+            .cloned()
+            .collect();
+        Self { edges, orphans }
+    }
+}
+
+/// Whether imports are read from files of `language`.
+fn resolves_imports(language: Language) -> bool {
+    matches!(
+        language,
+        Language::TypeScript
+            | Language::Tsx
+            | Language::JavaScript
+            | Language::Python
+            | Language::Rust
+            | Language::Go
+            | Language::C
+            | Language::Cpp
+            | Language::Java
+    )
+}
+
+/// Import resolution for one file.
+struct Imports<'a> {
+    file: &'a Path,
+    language: Language,
+    source: &'a str,
+    index: &'a Index,
+}
+
+impl Imports<'_> {
+    /// Every file of the index that the tree under `root` imports.
+    fn targets(&self, root: Node) -> Vec<PathBuf> {
+        let mut targets = Vec::new();
+        let mut cursor = root.walk();
+        // Pre-order walk without recursion: require() and import() can sit
+        // arbitrarily deep.
+        loop {
+            self.node(cursor.node(), &mut targets);
+            if cursor.goto_first_child() {
+                continue;
+            }
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    return targets;
+                }
+            }
+        }
+    }
+
+    fn text(&self, node: Node) -> &str {
+        node.utf8_text(self.source.as_bytes()).unwrap_or("")
+    }
+
+    fn dir(&self) -> &Path {
+        self.file.parent().unwrap_or(Path::new(""))
+    }
+
+    fn node(&self, node: Node, out: &mut Vec<PathBuf>) {
+        let field = |name| node.child_by_field_name(name);
+        match (self.language, node.kind()) {
+            (
+                Language::TypeScript | Language::Tsx | Language::JavaScript,
+                "import_statement" | "export_statement",
+            ) => {
+                if let Some(spec) = field("source") {
+                    out.extend(self.module(unquote(self.text(spec))));
+                }
+            }
+            (Language::TypeScript | Language::Tsx | Language::JavaScript, "call_expression") => {
+                let is_import = field("function").is_some_and(|function| {
+                    function.kind() == "import" || self.text(function) == "require"
+                });
+                let spec = field("arguments")
+                    .and_then(|args| args.named_child(0))
+                    .filter(|arg| arg.kind() == "string");
+                if is_import && let Some(spec) = spec {
+                    out.extend(self.module(unquote(self.text(spec))));
+                }
+            }
+            (Language::Python, "import_statement") => {
+                let mut cursor = node.walk();
+                for name in node.children_by_field_name("name", &mut cursor) {
+                    let name = name.child_by_field_name("name").unwrap_or(name);
+                    out.extend(self.python(0, &dotted(self.text(name))));
+                }
+            }
+            (Language::Python, "import_from_statement") => self.python_from(node, out),
+            (Language::Rust, "mod_item") => {
+                let top_level = node.parent().is_some_and(|p| p.kind() == "source_file");
+                if top_level && field("body").is_none() {
+                    out.extend(field("name").and_then(|name| self.rust_mod(self.text(name))));
+                }
+            }
+            (Language::Rust, "use_declaration") => {
+                let mut paths = Vec::new();
+                if let Some(argument) = field("argument") {
+                    self.use_paths(argument, &[], &mut paths);
+                }
+                for path in paths {
+                    out.extend(self.rust_use(&path));
+                }
+            }
+            (Language::Go, "import_spec") => {
+                if let Some(path) = field("path") {
+                    out.extend(self.go_package(unquote(self.text(path))));
+                }
+            }
+            (Language::C | Language::Cpp, "preproc_include") => {
+                if let Some(path) = field("path").filter(|p| p.kind() == "string_literal") {
+                    let include = Path::new(unquote(self.text(path)));
+                    out.extend(
+                        self.index
+                            .exact(&normalize(&self.dir().join(include)))
+                            .or_else(|| self.index.by_suffix(&normalize(include))),
+                    );
+                }
+            }
+            (Language::Java, "import_declaration") => self.java(node, out),
+            _ => {}
+        }
+    }
+
+    /// A relative JS/TS specifier; packages are not followed.
+    fn module(&self, spec: &str) -> Option<PathBuf> {
+        if !(spec.starts_with("./") || spec.starts_with("../")) {
+            return None;
+        }
+        let base = normalize(&self.dir().join(spec));
+        let base = base.to_str()?;
+        // ESM-style TypeScript names the emitted `.js` file.
+        let stems = [base, base.strip_suffix(".js").unwrap_or(base)];
+        stems.iter().find_map(|stem| {
+            MODULE_SUFFIXES
+                .iter()
+                .find_map(|suffix| self.index.exact(Path::new(&format!("{stem}{suffix}"))))
+        })
+    }
+
+    /// `from <module> import <names>`: each name may be a submodule.
+    fn python_from(&self, node: Node, out: &mut Vec<PathBuf>) {
+        let Some(module) = node.child_by_field_name("module_name") else {
+            return;
+        };
+        let (level, parts) = if module.kind() == "relative_import" {
+            let mut cursor = module.walk();
+            let children: Vec<Node> = module.named_children(&mut cursor).collect();
+            let level = children
+                .iter()
+                .find(|c| c.kind() == "import_prefix")
+                .map_or(0, |prefix| self.text(*prefix).len());
+            let parts = children
+                .iter()
+                .find(|c| c.kind() == "dotted_name")
+                .map_or_else(Vec::new, |name| dotted(self.text(*name)));
+            (level, parts)
+        } else {
+            (0, dotted(self.text(module)))
+        };
+
+        let mut cursor = node.walk();
+        let names: Vec<Node> = node.children_by_field_name("name", &mut cursor).collect();
+        let mut package_used = names.is_empty();
+        for name in names {
+            let name = name.child_by_field_name("name").unwrap_or(name);
+            let mut submodule = parts.clone();
+            submodule.extend(dotted(self.text(name)));
+            match self.python(level, &submodule) {
+                Some(target) => out.push(target),
+                None => package_used = true,
+            }
+        }
+        if package_used {
+            out.extend(self.python(level, &parts));
+        }
+    }
+
+    /// The file of Python module `parts`, `level` dots up from this file
+    /// (`0` for an absolute import).
+    fn python(&self, level: usize, parts: &[String]) -> Option<PathBuf> {
+        if level == 0 && parts.is_empty() {
+            return None;
+        }
+        let mut base = PathBuf::new();
+        base.extend(parts);
+        let candidates = [base.with_extension("py"), base.join("__init__.py")];
+        if level == 0 {
+            return candidates
+                .iter()
+                .find_map(|candidate| self.index.by_suffix(candidate));
+        }
+        let mut dir = self.dir().to_path_buf();
+        for _ in 1..level {
+            dir.pop();
+        }
+        if parts.is_empty() {
+            return self.index.exact(&dir.join("__init__.py"));
+        }
+        candidates
+            .iter()
+            .find_map(|candidate| self.index.exact(&dir.join(candidate)))
+    }
+
+    /// The file `mod name;` loads.
+    fn rust_mod(&self, name: &str) -> Option<PathBuf> {
+        let stem = self.file.file_stem()?.to_str()?;
+        let dir = if matches!(stem, "mod" | "lib" | "main") {
+            self.dir().to_path_buf()
+        } else {
+            self.dir().join(stem)
+        };
+        self.index
+            .exact(&dir.join(format!("{name}.rs")))
+            .or_else(|| self.index.exact(&dir.join(name).join("mod.rs")))
+    }
+
+    /// Flatten a `use` tree into its paths, each prefixed by `prefix`.
+    fn use_paths(&self, node: Node, prefix: &[String], out: &mut Vec<Vec<String>>) {
+        let joined = |path: Node| {
+            let mut full = prefix.to_vec();
+            full.extend(self.text(path).split("::").map(|s| s.trim().to_string()));
+            full
+        };
+        match node.kind() {
+            "scoped_use_list" => {
+                let prefix = match node.child_by_field_name("path") {
+                    Some(path) => joined(path),
+                    None => prefix.to_vec(),
+                };
+                if let Some(list) = node.child_by_field_name("list") {
+                    self.use_paths(list, &prefix, out);
+                }
+            }
+            "use_list" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    self.use_paths(child, prefix, out);
+                }
+            }
+            "use_as_clause" => {
+                if let Some(path) = node.child_by_field_name("path") {
+                    out.push(joined(path));
+                }
+            }
+            "use_wildcard" => {
+                if let Some(path) = node.named_child(0) {
+                    out.push(joined(path));
+                }
+            }
+            "self" => out.push(prefix.to_vec()),
+            _ => out.push(joined(node)),
+        }
+    }
+
+    /// The file defining the longest module prefix of a `crate::`,
+    /// `super::` or `self::` path.
+    fn rust_use(&self, path: &[String]) -> Option<PathBuf> {
+        let (root, module) = self.rust_module()?;
+        let mut absolute = match path.first()?.as_str() {
+            "crate" => Vec::new(),
+            "self" | "super" => module,
+            _ => return None,
+        };
+        let mut rest = &path[1..];
+        if path[0] == "super" {
+            absolute.pop();
+        }
+        while rest.first().is_some_and(|part| part == "super") {
+            absolute.pop();
+            rest = &rest[1..];
+        }
+        absolute.extend(rest.iter().cloned());
+
+        (0..=absolute.len()).rev().find_map(|len| {
+            let parts = &absolute[..len];
+            if parts.is_empty() {
+                return ["lib.rs", "main.rs"]
+                    .iter()
+                    .find_map(|name| self.index.exact(&root.join(name)));
+            }
+            let mut dir = root.clone();
+            dir.extend(&parts[..len - 1]);
+            self.index
+                .exact(&dir.join(format!("{}.rs", parts[len - 1])))
+                .or_else(|| self.index.exact(&dir.join(&parts[len - 1]).join("mod.rs")))
+        })
+    }
+
+    /// The crate root directory (holding `lib.rs` or `main.rs`) above this
+    /// file, and this file's module path below it.
+    fn rust_module(&self) -> Option<(PathBuf, Vec<String>)> {
+        let root = self.file.ancestors().skip(1).find(|dir| {
+            ["lib.rs", "main.rs"]
+                .iter()
+                .any(|name| self.index.files.contains(&dir.join(name)))
+        })?;
+        let mut module: Vec<String> = self
+            .file
+            .strip_prefix(root)
+            .ok()?
+            .with_extension("")
+            .components()
+            .filter_map(|c| c.as_os_str().to_str().map(str::to_string))
+            .collect();
+        if module.last().is_some_and(|last| last == "mod")
+            || (module.len() == 1 && matches!(module[0].as_str(), "lib" | "main"))
+        {
+            module.pop();
+        }
+        Some((root.to_path_buf(), module))
+    }
+
+    /// The files of the Go package at `import`, if it is under a module of
+    /// the run. Test files are left out.
+    fn go_package(&self, import: &str) -> Vec<PathBuf> {
+        let dir = self
+            .index
+            .go_modules
+            .iter()
+            .filter_map(|(prefix, root)| {
+                let rest = if import == prefix {
+                    ""
+                } else {
+                    import.strip_prefix(prefix.as_str())?.strip_prefix('/')?
+                };
+                Some((prefix.len(), normalize(&root.join(rest))))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, dir)| dir);
+        let Some(files) = dir.and_then(|dir| self.index.by_dir.get(&dir)) else {
+            return Vec::new();
+        };
+        files
+            .iter()
+            .filter(|file| {
+                file.extension().is_some_and(|ext| ext == "go")
+                    && !file.to_string_lossy().ends_with("_test.go")
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// `import a.b.C;`, `import a.b.*;` and `import static a.b.C.f;`.
+    fn java(&self, node: Node, out: &mut Vec<PathBuf>) {
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.named_children(&mut cursor).collect();
+        let Some(name) = children
+            .iter()
+            .find(|c| matches!(c.kind(), "scoped_identifier" | "identifier"))
+        else {
+            return;
+        };
+        let parts = dotted(self.text(*name));
+        if children.iter().any(|c| c.kind() == "asterisk") {
+            let mut suffix = PathBuf::new();
+            suffix.extend(&parts);
+            for (dir, files) in &self.index.by_dir {
+                if dir.ends_with(&suffix) {
+                    out.extend(
+                        files
+                            .iter()
+                            .filter(|file| file.extension().is_some_and(|ext| ext == "java"))
+                            .cloned(),
+                    );
+                }
+            }
+            return;
+        }
+        // A static import names a member; its class is one part shorter.
+        out.extend((1..=parts.len()).rev().find_map(|len| {
+            let mut suffix = PathBuf::new();
+            suffix.extend(&parts[..len]);
+            self.index.by_suffix(&suffix.with_extension("java"))
+        }));
+    }
+}
+
+/// `a.b.c` as its parts.
+fn dotted(name: &str) -> Vec<String> {
+    name.split('.')
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// A string literal's contents.
+fn unquote(literal: &str) -> &str {
+    literal.trim_matches(|c| matches!(c, '"' | '\'' | '`'))
+}
+
+/// Lexically drop `.` and fold `..` components.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// `path` as a quoted DOT identifier.
+fn dot_id(path: &Path) -> String {
+    let path = path.display().to_string();
+    format!("\"{}\"", path.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+    }
+
+    fn edges(root: &Path) -> Vec<String> {
+        let index = Index::walk(root, IgnoreRules::Off);
+        Graph::build(root, &index)
+            .edges
+            .iter()
+            .map(|(from, to)| format!("{} -> {}", from.display(), to.display()))
+            .collect()
+    }
+
+    #[test]
+    fn test_typescript_and_python_imports() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "web/app.ts",
+            "import { a } from './a.js';\nimport 'lodash';\nconst b = require('../lib/b');\n",
+        );
+        write(
+            root,
+            "web/a.ts",
+            "export const a = await import('./lazy');\n",
+        );
+        write(root, "web/lazy/index.tsx", "export default 1;\n");
+        write(root, "lib/b.js", "module.exports = 1;\n");
+        write(
+            root,
+            "pkg/main.py",
+            "import os\nfrom . import util\nfrom .models import User\nimport pkg.models\n",
+        );
+        write(root, "pkg/__init__.py", "");
+        write(root, "pkg/util.py", "");
+        write(root, "pkg/models.py", "class User: pass\n");
+
+        assert_eq!(
+            edges(root),
+            [
+                "pkg/main.py -> pkg/models.py",
+                "pkg/main.py -> pkg/util.py",
+                "web/a.ts -> web/lazy/index.tsx",
+                "web/app.ts -> lib/b.js",
+                "web/app.ts -> web/a.ts",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rust_mods_and_use_paths() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "src/lib.rs",
+            "mod config;\nmod cmd;\npub struct Shared;\n",
+        );
+        write(root, "src/config.rs", "use crate::Shared;\n");
+        write(
+            root,
+            "src/cmd/mod.rs",
+            "mod run;\nuse super::config::{self, Config};\n",
+        );
+        write(
+            root,
+            "src/cmd/run.rs",
+            "use super::super::config::*;\nuse std::fs;\n",
+        );
+        write(root, "src/legacy.rs", "fn old() {}\n");
+
+        assert_eq!(
+            edges(root),
+            [
+                "src/cmd/mod.rs -> src/cmd/run.rs",
+                "src/cmd/mod.rs -> src/config.rs",
+                "src/cmd/run.rs -> src/config.rs",
+                "src/config.rs -> src/lib.rs",
+                "src/lib.rs -> src/cmd/mod.rs",
+                "src/lib.rs -> src/config.rs",
+            ]
+        );
+        let index = Index::walk(root, IgnoreRules::Off);
+        assert_eq!(
+            Graph::build(root, &index).orphans,
+            [PathBuf::from("src/legacy.rs")]
+        );
+    }
+
+    #[test]
+    fn test_go_c_and_java_imports() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(root, "go.mod", "module example.com/app\n\ngo 1.22\n");
+        write(
+            root,
+            "main.go",
+            "package main\nimport (\n  \"fmt\"\n  \"example.com/app/internal/config\"\n)\n",
+        );
+        write(root, "internal/config/config.go", "package config\n");
+        write(root, "internal/config/config_test.go", "package config\n");
+        write(
+            root,
+            "c/main.c",
+            "#include \"util.h\"\n#include <stdio.h>\n",
+        );
+        write(root, "c/include/util.h", "int util(void);\n");
+        write(
+            root,
+            "java/com/acme/App.java",
+            "import com.acme.model.User;\nimport static com.acme.util.Strings.trim;\nclass App {}\n",
+        );
+        write(root, "java/com/acme/model/User.java", "class User {}\n");
+        write(
+            root,
+            "java/com/acme/util/Strings.java",
+            "class Strings {}\n",
+        );
+
+        assert_eq!(
+            edges(root),
+            [
+                "c/main.c -> c/include/util.h",
+                "java/com/acme/App.java -> java/com/acme/model/User.java",
+                "java/com/acme/App.java -> java/com/acme/util/Strings.java",
+                "main.go -> internal/config/config.go",
+            ]
+        );
+    }
+}
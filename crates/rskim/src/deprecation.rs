@@ -0,0 +1,138 @@
+//! Versioned deprecation notices.
+//!
+//! Every deprecated flag or behavior is declared once here as a
+//! [`Deprecation`] with a stable id, the version that deprecated it, and the version that will
+//! remove it. [`warn`] prints a notice on stderr the first time a deprecation
+//! is hit in a run:
+//!
+//! ```text
+//! [skim] deprecated (force-flag): --force has no effect; drop it (since 2.10.0, removal in 3.0.0)
+//! ```
+//!
+//! Commands that print JSON call [`attach`] so the same notices appear under
+//! a top-level `"deprecations"` key. Wrapper scripts and agent tool
+//! definitions can match on the id instead of the message text, which may
+//! change.
+
+use std::sync::Mutex;
+
+/// One deprecated flag or behavior.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Deprecation {
+    /// Stable identifier, safe to match on.
+    pub(crate) id: &'static str,
+    /// Version that deprecated it.
+    pub(crate) since: &'static str,
+    /// Version that will remove it.
+    pub(crate) removal: &'static str,
+    /// What changed and what to do instead.
+    pub(crate) message: &'static str,
+}
+
+/// `--force` on the main command: accepted but never had an effect.
+pub(crate) const FORCE_FLAG: &Deprecation = &Deprecation {
+    id: "force-flag",
+    since: "2.10.0",
+    removal: "3.0.0",
+    message: "--force has no effect; drop it",
+};
+
+/// `skim stats --cost`: cost estimates are always shown now.
+pub(crate) const STATS_COST_FLAG: &Deprecation = &Deprecation {
+    id: "stats-cost-flag",
+    since: "2.4.1",
+    removal: "3.0.0",
+    message: "skim stats --cost has no effect; cost estimates are always shown",
+};
+
+/// Deprecations hit so far in this run, in the order they were first hit.
+static EMITTED: Mutex<Vec<&'static Deprecation>> = Mutex::new(Vec::new());
+
+/// Record that `deprecation` was hit and print its notice on stderr.
+///
+/// Each deprecation is printed at most once per run.
+pub(crate) fn warn(deprecation: &'static Deprecation) {
+    // A poisoned lock only means another thread panicked mid-push; the list is
+    // still usable.
+    let mut emitted = EMITTED.lock().unwrap_or_else(|e| e.into_inner());
+    if emitted.iter().any(|d| d.id == deprecation.id) {
+        return;
+    }
+    emitted.push(deprecation);
+    eprintln!("{}", notice(deprecation));
+}
+
+/// The stderr line for `deprecation`.
+fn notice(deprecation: &Deprecation) -> String {
+    format!(
+        "[skim] deprecated ({}): {} (since {}, removal in {})",
+        deprecation.id, deprecation.message, deprecation.since, deprecation.removal
+    )
+}
+
+/// Add a `"deprecations"` array to a JSON object when any were hit this run.
+///
+/// Leaves `value` untouched when nothing was hit or it is not an object, so
+/// JSON output only changes shape for runs that use deprecated features.
+pub(crate) fn attach(value: &mut serde_json::Value) {
+    let emitted = EMITTED.lock().unwrap_or_else(|e| e.into_inner());
+    attach_list(value, &emitted);
+}
+
+fn attach_list(value: &mut serde_json::Value, emitted: &[&Deprecation]) {
+    if emitted.is_empty() {
+        return;
+    }
+    if let serde_json::Value::Object(map) = value {
+        let list = emitted
+            .iter()
+            .map(|d| {
+                serde_json::json!({
+                    "id": d.id,
+                    "since": d.since,
+                    "removal": d.removal,
+                    "message": d.message,
+                })
+            })
+            .collect();
+        map.insert("deprecations".to_string(), serde_json::Value::Array(list));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deprecation_ids_unique() {
+        let all = [FORCE_FLAG, STATS_COST_FLAG];
+        let mut ids: Vec<&str> = all.iter().map(|d| d.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), all.len());
+    }
+
+    #[test]
+    fn test_notice_format() {
+        assert_eq!(
+            notice(FORCE_FLAG),
+            "[skim] deprecated (force-flag): --force has no effect; drop it \
+             (since 2.10.0, removal in 3.0.0)"
+        );
+    }
+
+    #[test]
+    fn test_attach_list() {
+        let mut value = serde_json::json!({"summary": {}});
+        attach_list(&mut value, &[]);
+        assert!(value.get("deprecations").is_none());
+
+        attach_list(&mut value, &[STATS_COST_FLAG]);
+        assert_eq!(value["deprecations"][0]["id"], "stats-cost-flag");
+        assert_eq!(value["deprecations"][0]["removal"], "3.0.0");
+
+        let mut array = serde_json::json!([1, 2]);
+        attach_list(&mut array, &[STATS_COST_FLAG]);
+        assert_eq!(array, serde_json::json!([1, 2]));
+    }
+}
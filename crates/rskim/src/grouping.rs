@@ -0,0 +1,887 @@
+//! Group directory output by build unit (`--group-by`).
+//!
+//! A flat, path-sorted list of files hides the unit agents actually reason
+//! about: in a Cargo workspace that unit is the crate, in a JS monorepo the
+//! package. This module reads the workspace members (`Cargo.toml`, or
+//! `package.json` workspaces / `pnpm-workspace.yaml`), reorders the files of
+//! a directory run so each unit's files are contiguous, and describes the
+//! resulting groups. [`crate::multi`] prints one banner per group above its
+//! files:
+//!
+//! ```text
+//! // crate rskim-core (crates/rskim-core): 14 file(s)
+//!
+//! // crates/rskim-core/src/lib.rs
+//! ```
+//!
+//! Java output can instead be grouped by each file's `package` declaration,
+//! since generated source sets put one logical package in several
+//! directories. JS and Java packages carry a short summary below the
+//! banner:
+//!
+//! ```text
+//! // package @acme/ui@1.4.0 (packages/ui): 9 file(s)
+//! // entry: src/index.ts
+//! // dependencies: react, @acme/utils
+//!
+//! // package com.acme.billing: 6 file(s)
+//! // types: 4 classes, 1 interface, 1 enum
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use rskim_core::Language;
+
+/// Build unit that directory output is grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GroupBy {
+    /// Cargo crates, from the workspace members in `Cargo.toml`.
+    Crate,
+    /// JS/TS packages, from `package.json` workspaces or
+    /// `pnpm-workspace.yaml`.
+    Package,
+    /// Java packages, from the `package` declaration of each `.java` file.
+    JavaPackage,
+}
+
+impl GroupBy {
+    /// Label of the group holding files that fall under no unit.
+    fn outside_label(self) -> &'static str {
+        match self {
+            Self::Crate => "outside any crate",
+            Self::Package => "outside any package",
+            Self::JavaPackage => "outside any Java package",
+        }
+    }
+}
+
+/// A run of consecutive files that belong to one unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Group {
+    /// Banner text naming the unit, e.g. `crate rskim (crates/rskim)`.
+    pub(crate) label: String,
+    /// Summary lines printed below the banner, e.g. `entry: src/index.ts`.
+    pub(crate) details: Vec<String>,
+    /// Number of files in the group.
+    pub(crate) len: usize,
+}
+
+/// A unit and the directory its files live under.
+#[derive(Debug, Clone)]
+struct Unit {
+    label: String,
+    details: Vec<String>,
+    dir: PathBuf,
+}
+
+/// Reorder `paths` found under `dir` into contiguous groups by `kind`.
+///
+/// Groups follow the units' directory order (package name order for Java
+/// packages); files keep their walk order within a group. Files under no
+/// unit come last, in one group.
+pub(crate) fn group_paths(
+    kind: GroupBy,
+    dir: &Path,
+    paths: Vec<PathBuf>,
+) -> anyhow::Result<(Vec<PathBuf>, Vec<Group>)> {
+    let root = dir
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("--group-by: cannot resolve {}: {e}", dir.display()))?;
+    let mut units = match kind {
+        GroupBy::Crate => cargo_crates(&root)?,
+        GroupBy::Package => js_packages(&root)?,
+        GroupBy::JavaPackage => return Ok(java_package_groups(dir, paths)),
+    };
+    units.sort_by(|a, b| a.dir.cmp(&b.dir));
+
+    let mut buckets: Vec<Vec<PathBuf>> = vec![Vec::new(); units.len() + 1];
+    for path in paths {
+        let resolved = match path.strip_prefix(dir) {
+            Ok(rel) => root.join(rel),
+            Err(_) => path.clone(),
+        };
+        let slot = owning_unit(&units, &resolved).unwrap_or(units.len());
+        buckets[slot].push(path);
+    }
+
+    let mut ordered = Vec::new();
+    let mut groups = Vec::new();
+    let outside = Unit {
+        label: kind.outside_label().to_string(),
+        details: Vec::new(),
+        dir: PathBuf::new(),
+    };
+    for (unit, bucket) in units.into_iter().chain([outside]).zip(buckets) {
+        if bucket.is_empty() {
+            continue;
+        }
+        groups.push(Group {
+            label: unit.label,
+            details: unit.details,
+            len: bucket.len(),
+        });
+        ordered.extend(bucket);
+    }
+    Ok((ordered, groups))
+}
+
+/// Index of the unit whose directory most closely contains `path`.
+///
+/// The deepest match wins, so a member nested inside a root package keeps
+/// its own files.
+fn owning_unit(units: &[Unit], path: &Path) -> Option<usize> {
+    units
+        .iter()
+        .enumerate()
+        .filter(|(_, unit)| path.starts_with(&unit.dir))
+        .max_by_key(|(_, unit)| unit.dir.components().count())
+        .map(|(idx, _)| idx)
+}
+
+/// Crates of the Cargo workspace containing `dir`.
+///
+/// Searches `dir` and its ancestors for a `Cargo.toml` with a `[workspace]`
+/// table. Without one, the nearest package manifest is the only crate.
+fn cargo_crates(dir: &Path) -> anyhow::Result<Vec<Unit>> {
+    let mut nearest_package = None;
+    for ancestor in dir.ancestors() {
+        let Some(manifest) = read_manifest(ancestor) else {
+            continue;
+        };
+        if let Some(workspace) = manifest.get("workspace") {
+            return Ok(workspace_crates(ancestor, &manifest, workspace));
+        }
+        if nearest_package.is_none() && manifest.get("package").is_some() {
+            nearest_package = Some(ancestor.to_path_buf());
+        }
+    }
+    match nearest_package {
+        Some(package_dir) => Ok(package_unit(&package_dir, &package_dir)
+            .into_iter()
+            .collect()),
+        None => anyhow::bail!(
+            "--group-by crate: no Cargo.toml found in {} or its parents",
+            dir.display()
+        ),
+    }
+}
+
+/// Member crates listed by a workspace manifest at `root`.
+///
+/// A root manifest that also has a `[package]` table counts as a crate.
+fn workspace_crates(root: &Path, manifest: &toml::Table, workspace: &toml::Value) -> Vec<Unit> {
+    let patterns = |key: &str| -> Vec<&str> {
+        workspace
+            .get(key)
+            .and_then(toml::Value::as_array)
+            .map(|values| values.iter().filter_map(toml::Value::as_str).collect())
+            .unwrap_or_default()
+    };
+    let excluded: Vec<PathBuf> = patterns("exclude")
+        .into_iter()
+        .map(|pattern| root.join(pattern))
+        .collect();
+
+    let mut dirs: Vec<PathBuf> = patterns("members")
+        .into_iter()
+        .flat_map(|pattern| expand_member(root, pattern, "Cargo.toml"))
+        .filter(|dir| !excluded.contains(dir))
+        .collect();
+    if manifest.get("package").is_some() {
+        dirs.push(root.to_path_buf());
+    }
+    dirs.sort();
+    dirs.dedup();
+
+    dirs.iter()
+        .filter_map(|dir| package_unit(root, dir))
+        .collect()
+}
+
+/// Directories under `root` matching a `members` entry such as `crates/*`.
+///
+/// Each path component may be a glob, and `**` matches any number of
+/// directories; only directories containing `manifest` are kept.
+fn expand_member(root: &Path, pattern: &str, manifest: &str) -> Vec<PathBuf> {
+    let mut dirs = vec![root.to_path_buf()];
+    for component in Path::new(pattern).components() {
+        let Component::Normal(segment) = component else {
+            continue;
+        };
+        let segment = segment.to_string_lossy();
+        if segment == "**" {
+            dirs = dirs.iter().flat_map(|dir| descendants(dir)).collect();
+            continue;
+        }
+        let Ok(glob) = Glob::new(&segment) else {
+            return Vec::new();
+        };
+        let matcher = glob.compile_matcher();
+        dirs = dirs
+            .iter()
+            .flat_map(|dir| match std::fs::read_dir(dir) {
+                Ok(entries) => entries
+                    .filter_map(Result::ok)
+                    .filter(|entry| entry.file_type().is_ok_and(|ft| ft.is_dir()))
+                    .filter(|entry| matcher.is_match(entry.file_name()))
+                    .map(|entry| entry.path())
+                    .collect(),
+                Err(_) => Vec::new(),
+            })
+            .collect();
+    }
+    dirs.retain(|dir| dir.join(manifest).is_file());
+    dirs
+}
+
+/// `dir` and every directory below it, skipping `node_modules` and hidden
+/// directories.
+fn descendants(dir: &Path) -> Vec<PathBuf> {
+    let mut found = vec![dir.to_path_buf()];
+    let mut idx = 0;
+    while idx < found.len() {
+        if let Ok(entries) = std::fs::read_dir(&found[idx]) {
+            let children: Vec<PathBuf> = entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.file_type().is_ok_and(|ft| ft.is_dir()))
+                .filter(|entry| {
+                    let name = entry.file_name();
+                    let name = name.to_string_lossy();
+                    name != "node_modules" && !name.starts_with('.')
+                })
+                .map(|entry| entry.path())
+                .collect();
+            found.extend(children);
+        }
+        idx += 1;
+    }
+    found
+}
+
+/// The crate at `dir`, labelled with its package name and its path relative
+/// to `root`.
+///
+/// Falls back to the directory name when the manifest has no package name.
+fn package_unit(root: &Path, dir: &Path) -> Option<Unit> {
+    let manifest = read_manifest(dir)?;
+    let name = manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(toml::Value::as_str)
+        .map(str::to_string)
+        .or_else(|| Some(dir.file_name()?.to_string_lossy().into_owned()))?;
+    let rel = relative(root, dir);
+    Some(Unit {
+        label: format!("crate {name} ({rel})"),
+        details: Vec::new(),
+        dir: dir.to_path_buf(),
+    })
+}
+
+/// `dir` relative to `root` for labels; `.` for the root itself.
+fn relative(root: &Path, dir: &Path) -> String {
+    match dir.strip_prefix(root) {
+        Ok(rel) if rel.as_os_str().is_empty() => ".".to_string(),
+        Ok(rel) => rel.display().to_string(),
+        Err(_) => dir.display().to_string(),
+    }
+}
+
+/// Parse `dir/Cargo.toml`, or `None` when it is missing or malformed.
+fn read_manifest(dir: &Path) -> Option<toml::Table> {
+    let content = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    content.parse().ok()
+}
+
+/// Packages of the JS workspace containing `dir`.
+///
+/// Searches `dir` and its ancestors for a `pnpm-workspace.yaml` or a
+/// `package.json` with a `workspaces` field. Without one, the nearest
+/// `package.json` is the only package. The workspace root itself is not a
+/// package: its files (lockfiles, shared configs) are grouped as outside.
+fn js_packages(dir: &Path) -> anyhow::Result<Vec<Unit>> {
+    let mut nearest_package = None;
+    for ancestor in dir.ancestors() {
+        if let Some(patterns) = js_workspace_patterns(ancestor) {
+            return Ok(js_workspace_packages(ancestor, &patterns));
+        }
+        if nearest_package.is_none() && ancestor.join("package.json").is_file() {
+            nearest_package = Some(ancestor.to_path_buf());
+        }
+    }
+    match nearest_package {
+        Some(package_dir) => Ok(js_package_unit(&package_dir, &package_dir)
+            .into_iter()
+            .collect()),
+        None => anyhow::bail!(
+            "--group-by package: no package.json found in {} or its parents",
+            dir.display()
+        ),
+    }
+}
+
+/// Workspace patterns declared at `dir`, if it is a workspace root.
+///
+/// `pnpm-workspace.yaml` wins over `package.json`; `workspaces` may be an
+/// array or Yarn's `{ "packages": [...] }` form.
+fn js_workspace_patterns(dir: &Path) -> Option<Vec<String>> {
+    if let Ok(content) = std::fs::read_to_string(dir.join("pnpm-workspace.yaml")) {
+        return Some(pnpm_packages(&content));
+    }
+    let manifest = read_package_json(dir)?;
+    let workspaces = manifest.get("workspaces")?;
+    let patterns = workspaces
+        .as_array()
+        .or_else(|| workspaces.get("packages")?.as_array())?;
+    Some(
+        patterns
+            .iter()
+            .filter_map(serde_json::Value::as_str)
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Entries of the top-level `packages:` list in `pnpm-workspace.yaml`.
+///
+/// Only the block-list form pnpm documents is read, which avoids a YAML
+/// dependency for one key.
+fn pnpm_packages(content: &str) -> Vec<String> {
+    let mut patterns = Vec::new();
+    let mut in_packages = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+        if in_packages && let Some(item) = trimmed.strip_prefix('-') {
+            let item = item.split(" #").next().unwrap_or_default().trim();
+            patterns.push(item.trim_matches(['"', '\'']).to_string());
+        }
+    }
+    patterns
+}
+
+/// Member packages matched by workspace `patterns` under `root`.
+///
+/// Patterns starting with `!` exclude matching directories.
+fn js_workspace_packages(root: &Path, patterns: &[String]) -> Vec<Unit> {
+    let mut excluded = GlobSetBuilder::new();
+    for pattern in patterns.iter().filter_map(|p| p.strip_prefix('!')) {
+        if let Ok(glob) = Glob::new(pattern.trim_start_matches("./")) {
+            excluded.add(glob);
+        }
+    }
+    let excluded = excluded.build().unwrap_or_else(|_| GlobSet::empty());
+
+    let mut dirs: Vec<PathBuf> = patterns
+        .iter()
+        .filter(|pattern| !pattern.starts_with('!'))
+        .flat_map(|pattern| expand_member(root, pattern, "package.json"))
+        .filter(|dir| dir != root)
+        .filter(|dir| {
+            !dir.strip_prefix(root)
+                .is_ok_and(|rel| excluded.is_match(rel))
+        })
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+
+    dirs.iter()
+        .filter_map(|dir| js_package_unit(root, dir))
+        .collect()
+}
+
+/// The package at `dir`, labelled with its name, version and path relative
+/// to `root`, with its entry point and dependencies as details.
+///
+/// Falls back to the directory name when the manifest has no name.
+fn js_package_unit(root: &Path, dir: &Path) -> Option<Unit> {
+    let manifest = read_package_json(dir)?;
+    let field = |key: &str| manifest.get(key).and_then(serde_json::Value::as_str);
+    let name = field("name")
+        .map(str::to_string)
+        .or_else(|| Some(dir.file_name()?.to_string_lossy().into_owned()))?;
+    let version = field("version")
+        .map(|v| format!("@{v}"))
+        .unwrap_or_default();
+
+    let mut details = Vec::new();
+    if let Some(entry) = js_entry_point(&manifest) {
+        details.push(format!("entry: {entry}"));
+    }
+    if let Some(deps) = manifest
+        .get("dependencies")
+        .and_then(serde_json::Value::as_object)
+        .filter(|deps| !deps.is_empty())
+    {
+        let names: Vec<&str> = deps.keys().map(String::as_str).collect();
+        details.push(format!("dependencies: {}", names.join(", ")));
+    }
+
+    Some(Unit {
+        label: format!("package {name}{version} ({})", relative(root, dir)),
+        details,
+        dir: dir.to_path_buf(),
+    })
+}
+
+/// The file a package resolves to: the `.` export when `exports` is present,
+/// else `main`, else `module`.
+pub(crate) fn js_entry_point(manifest: &serde_json::Value) -> Option<String> {
+    let mut export = manifest.get("exports");
+    if let Some(dot) = export.and_then(|exports| exports.get(".")) {
+        export = Some(dot);
+    }
+    // Conditional exports: take the first of the usual conditions.
+    while let Some(conditions) = export.and_then(serde_json::Value::as_object) {
+        export = ["import", "require", "default", "node"]
+            .iter()
+            .find_map(|key| conditions.get(*key));
+    }
+    export
+        .and_then(serde_json::Value::as_str)
+        .or_else(|| manifest.get("main")?.as_str())
+        .or_else(|| manifest.get("module")?.as_str())
+        .map(|entry| entry.trim_start_matches("./").to_string())
+}
+
+/// Parse `dir/package.json`, or `None` when it is missing or malformed.
+pub(crate) fn read_package_json(dir: &Path) -> Option<serde_json::Value> {
+    let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Label of the group of Java files without a `package` declaration.
+const DEFAULT_JAVA_PACKAGE: &str = "default package";
+
+/// Java files sharing one `package` declaration.
+#[derive(Debug, Default)]
+struct JavaPackage {
+    files: Vec<PathBuf>,
+    dirs: BTreeSet<PathBuf>,
+    classes: usize,
+    interfaces: usize,
+    enums: usize,
+}
+
+impl JavaPackage {
+    /// Count the top-level type declarations of one file.
+    fn add_types(&mut self, source: &str) {
+        let Ok(symbols) = rskim_core::symbols(source, Language::Java) else {
+            return;
+        };
+        for symbol in symbols.iter().filter(|symbol| symbol.depth == 0) {
+            match symbol.kind {
+                "class_declaration" => self.classes += 1,
+                "interface_declaration" => self.interfaces += 1,
+                "enum_declaration" => self.enums += 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// Summary lines: type counts, and the directories the package spans
+    /// when there is more than one.
+    fn details(&self, root: &Path) -> Vec<String> {
+        let counts: Vec<String> = [
+            (self.classes, "class", "classes"),
+            (self.interfaces, "interface", "interfaces"),
+            (self.enums, "enum", "enums"),
+        ]
+        .into_iter()
+        .filter(|(n, _, _)| *n > 0)
+        .map(|(n, one, many)| format!("{n} {}", if n == 1 { one } else { many }))
+        .collect();
+
+        let mut details = Vec::new();
+        if !counts.is_empty() {
+            details.push(format!("types: {}", counts.join(", ")));
+        }
+        if self.dirs.len() > 1 {
+            let dirs: Vec<String> = self.dirs.iter().map(|dir| relative(root, dir)).collect();
+            details.push(format!("directories: {}", dirs.join(", ")));
+        }
+        details
+    }
+}
+
+/// Group `paths` found under `root` by the `package` declaration of each
+/// Java file, ordered by package name.
+///
+/// Java files without a declaration form the default package, which comes
+/// first; other files (and unreadable ones) come last.
+fn java_package_groups(root: &Path, paths: Vec<PathBuf>) -> (Vec<PathBuf>, Vec<Group>) {
+    let mut packages: BTreeMap<String, JavaPackage> = BTreeMap::new();
+    let mut outside = Vec::new();
+    for path in paths {
+        let source = (Language::from_path(&path) == Some(Language::Java))
+            .then(|| std::fs::read_to_string(&path).ok())
+            .flatten();
+        let Some(source) = source else {
+            outside.push(path);
+            continue;
+        };
+        let name = java_package_name(&source).unwrap_or_default();
+        let package = packages.entry(name.to_string()).or_default();
+        package.add_types(&source);
+        if let Some(parent) = path.parent() {
+            package.dirs.insert(parent.to_path_buf());
+        }
+        package.files.push(path);
+    }
+
+    let mut ordered = Vec::new();
+    let mut groups = Vec::new();
+    for (name, package) in packages {
+        let label = if name.is_empty() {
+            DEFAULT_JAVA_PACKAGE.to_string()
+        } else {
+            format!("package {name}")
+        };
+        groups.push(Group {
+            label,
+            details: package.details(root),
+            len: package.files.len(),
+        });
+        ordered.extend(package.files);
+    }
+    if !outside.is_empty() {
+        groups.push(Group {
+            label: GroupBy::JavaPackage.outside_label().to_string(),
+            details: Vec::new(),
+            len: outside.len(),
+        });
+        ordered.extend(outside);
+    }
+    (ordered, groups)
+}
+
+/// The name in a Java file's `package com.acme.api;` declaration.
+///
+/// Only lines starting with `package` are considered, so license headers
+/// and Javadoc mentioning a package are skipped.
+fn java_package_name(source: &str) -> Option<&str> {
+    source.lines().find_map(|line| {
+        let name = line.trim().strip_prefix("package")?;
+        if !name.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let name = name.trim().strip_suffix(';')?.trim_end();
+        (!name.is_empty()).then_some(name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn workspace() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\", \"tools/gen\"]\nexclude = [\"crates/skip\"]\n",
+        );
+        write(
+            root,
+            "crates/core/Cargo.toml",
+            "[package]\nname = \"acme-core\"\n",
+        );
+        write(
+            root,
+            "crates/cli/Cargo.toml",
+            "[package]\nname = \"acme-cli\"\n",
+        );
+        write(
+            root,
+            "crates/skip/Cargo.toml",
+            "[package]\nname = \"skip\"\n",
+        );
+        write(root, "tools/gen/Cargo.toml", "[package]\nname = \"gen\"\n");
+        dir
+    }
+
+    #[test]
+    fn test_group_paths_orders_files_by_crate() {
+        let dir = workspace();
+        let root = dir.path();
+        let paths = vec![
+            root.join("tools/gen/src/main.rs"),
+            root.join("crates/core/src/lib.rs"),
+            root.join("build.rs"),
+            root.join("crates/cli/src/main.rs"),
+            root.join("crates/core/src/util.rs"),
+            root.join("crates/skip/src/lib.rs"),
+        ];
+
+        let (ordered, groups) = group_paths(GroupBy::Crate, root, paths).unwrap();
+
+        assert_eq!(
+            ordered,
+            vec![
+                root.join("crates/cli/src/main.rs"),
+                root.join("crates/core/src/lib.rs"),
+                root.join("crates/core/src/util.rs"),
+                root.join("tools/gen/src/main.rs"),
+                root.join("build.rs"),
+                root.join("crates/skip/src/lib.rs"),
+            ]
+        );
+        let summary: Vec<(&str, usize)> = groups
+            .iter()
+            .map(|group| (group.label.as_str(), group.len))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("crate acme-cli (crates/cli)", 1),
+                ("crate acme-core (crates/core)", 2),
+                ("crate gen (tools/gen)", 1),
+                ("outside any crate", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_paths_from_member_directory_uses_workspace() {
+        let dir = workspace();
+        let member = dir.path().join("crates/core");
+        let paths = vec![member.join("src/lib.rs")];
+
+        let (_, groups) = group_paths(GroupBy::Crate, &member, paths).unwrap();
+
+        assert_eq!(groups[0].label, "crate acme-core (crates/core)");
+    }
+
+    #[test]
+    fn test_root_package_keeps_files_outside_members() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "Cargo.toml",
+            "[package]\nname = \"app\"\n\n[workspace]\nmembers = [\"macros\"]\n",
+        );
+        write(
+            root,
+            "macros/Cargo.toml",
+            "[package]\nname = \"app-macros\"\n",
+        );
+        let paths = vec![root.join("macros/src/lib.rs"), root.join("src/main.rs")];
+
+        let (_, groups) = group_paths(GroupBy::Crate, root, paths).unwrap();
+
+        let labels: Vec<&str> = groups.iter().map(|group| group.label.as_str()).collect();
+        assert_eq!(labels, vec!["crate app (.)", "crate app-macros (macros)"]);
+    }
+
+    #[test]
+    fn test_group_paths_without_manifest_is_error() {
+        let dir = TempDir::new().unwrap();
+        let err = group_paths(GroupBy::Crate, dir.path(), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("no Cargo.toml found"), "{err}");
+    }
+
+    fn js_workspace() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "package.json",
+            r#"{"name": "monorepo", "private": true, "workspaces": ["packages/*", "!packages/legacy"]}"#,
+        );
+        write(
+            root,
+            "packages/ui/package.json",
+            r#"{"name": "@acme/ui", "version": "1.4.0", "main": "./dist/index.js",
+                "exports": {".": {"import": "./src/index.ts"}},
+                "dependencies": {"react": "^18.0.0", "@acme/utils": "workspace:*"}}"#,
+        );
+        write(
+            root,
+            "packages/utils/package.json",
+            r#"{"name": "@acme/utils", "main": "lib/index.js"}"#,
+        );
+        write(
+            root,
+            "packages/legacy/package.json",
+            r#"{"name": "legacy"}"#,
+        );
+        dir
+    }
+
+    #[test]
+    fn test_group_paths_orders_files_by_js_package() {
+        let dir = js_workspace();
+        let root = dir.path();
+        let paths = vec![
+            root.join("packages/utils/lib/index.js"),
+            root.join("turbo.json"),
+            root.join("packages/ui/src/index.ts"),
+            root.join("packages/legacy/index.js"),
+        ];
+
+        let (ordered, groups) = group_paths(GroupBy::Package, root, paths).unwrap();
+
+        assert_eq!(
+            ordered,
+            vec![
+                root.join("packages/ui/src/index.ts"),
+                root.join("packages/utils/lib/index.js"),
+                root.join("turbo.json"),
+                root.join("packages/legacy/index.js"),
+            ]
+        );
+        assert_eq!(
+            groups,
+            vec![
+                Group {
+                    label: "package @acme/ui@1.4.0 (packages/ui)".to_string(),
+                    details: vec![
+                        "entry: src/index.ts".to_string(),
+                        "dependencies: react, @acme/utils".to_string(),
+                    ],
+                    len: 1,
+                },
+                Group {
+                    label: "package @acme/utils (packages/utils)".to_string(),
+                    details: vec!["entry: lib/index.js".to_string()],
+                    len: 1,
+                },
+                Group {
+                    label: "outside any package".to_string(),
+                    details: Vec::new(),
+                    len: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pnpm_workspace_yaml_lists_packages() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "pnpm-workspace.yaml",
+            "# workspace\npackages:\n  - 'apps/**'\n  - \"libs/core\" # shared\ncatalog:\n  react: ^18\n",
+        );
+        write(root, "apps/web/package.json", r#"{"name": "web"}"#);
+        write(root, "apps/nested/api/package.json", r#"{"name": "api"}"#);
+        write(root, "libs/core/package.json", r#"{"name": "core"}"#);
+        write(
+            root,
+            "apps/web/node_modules/dep/package.json",
+            r#"{"name": "dep"}"#,
+        );
+
+        let (_, groups) = group_paths(GroupBy::Package, root, Vec::new()).unwrap();
+        assert!(groups.is_empty());
+        let labels: Vec<String> = js_packages(root)
+            .unwrap()
+            .into_iter()
+            .map(|unit| unit.label)
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                "package api (apps/nested/api)",
+                "package web (apps/web)",
+                "package core (libs/core)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_paths_without_package_json_is_error() {
+        let dir = TempDir::new().unwrap();
+        let err = group_paths(GroupBy::Package, dir.path(), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("no package.json found"), "{err}");
+    }
+
+    #[test]
+    fn test_java_packages_follow_declarations_not_directories() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "src/main/java/com/acme/Billing.java",
+            "/* package not.this; */\npackage com.acme;\n\npublic class Billing {\n    enum State { OPEN }\n}\n",
+        );
+        write(
+            root,
+            "build/generated/com/acme/Invoice.java",
+            "package com.acme;\npublic interface Invoice {}\nenum Kind { A }\n",
+        );
+        write(root, "src/main/java/Main.java", "class Main {}\n");
+        write(
+            root,
+            "src/main/java/com/acme/api/Api.java",
+            "package com.acme.api ;\nclass Api {}\n",
+        );
+        let paths = vec![
+            root.join("README.md"),
+            root.join("src/main/java/com/acme/api/Api.java"),
+            root.join("src/main/java/com/acme/Billing.java"),
+            root.join("build/generated/com/acme/Invoice.java"),
+            root.join("src/main/java/Main.java"),
+        ];
+
+        let (ordered, groups) = group_paths(GroupBy::JavaPackage, root, paths).unwrap();
+
+        assert_eq!(
+            ordered,
+            vec![
+                root.join("src/main/java/Main.java"),
+                root.join("src/main/java/com/acme/Billing.java"),
+                root.join("build/generated/com/acme/Invoice.java"),
+                root.join("src/main/java/com/acme/api/Api.java"),
+                root.join("README.md"),
+            ]
+        );
+        assert_eq!(
+            groups,
+            vec![
+                Group {
+                    label: "default package".to_string(),
+                    details: vec!["types: 1 class".to_string()],
+                    len: 1,
+                },
+                Group {
+                    label: "package com.acme".to_string(),
+                    details: vec![
+                        "types: 1 class, 1 interface, 1 enum".to_string(),
+                        "directories: build/generated/com/acme, src/main/java/com/acme".to_string(),
+                    ],
+                    len: 2,
+                },
+                Group {
+                    label: "package com.acme.api".to_string(),
+                    details: vec!["types: 1 class".to_string()],
+                    len: 1,
+                },
+                Group {
+                    label: "outside any Java package".to_string(),
+                    details: Vec::new(),
+                    len: 1,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,361 @@
+//! Dry file listing (`--list-files` and `skim ls --dry-run`).
+//!
+//! Resolves the inputs the way a real run would (directory walks, globs,
+//! ignore rules under `--no-ignore`/`--hermetic`, language detection, the
+//! input size limit) and prints one line per file instead of transforming
+//! it. Only file metadata is read. Use it to see why a file was or wasn't
+//! included:
+//!
+//! ```text
+//! Rust             2104  src/main.rs
+//! JSON         73400320  fixtures/huge.json  (skipped: larger than the 50 MB input limit)
+//! -                 512  src/notes.txt  (skipped: unsupported file type)
+//! ```
+//!
+//! A summary goes to stderr, including how many files the ignore rules kept
+//! out of directory walks and globs.
+//!
+//! Without `--dry-run`, `skim ls` compresses the output of the real `ls`
+//! (see `cmd::file::ls`); the flag routes it here instead.
+
+use std::collections::HashSet;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::process::ExitCode;
+
+use ignore::WalkBuilder;
+use rskim_core::Language;
+
+use crate::multi::{self, IgnoreRules};
+use crate::process::MAX_INPUT_SIZE;
+
+/// Why a resolved file would not be transformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Skip {
+    /// Found by a directory walk, which only keeps detectable languages.
+    UnsupportedType,
+    /// Named directly or matched by a glob, with no language detected and
+    /// no `--language` given.
+    UnknownLanguage,
+    /// Larger than [`MAX_INPUT_SIZE`].
+    TooLarge,
+    /// Named directly but missing.
+    NotFound,
+}
+
+impl Skip {
+    fn reason(self) -> String {
+        match self {
+            Self::UnsupportedType => "unsupported file type".to_string(),
+            Self::UnknownLanguage => "no language detected (pass --language)".to_string(),
+            Self::TooLarge => format!(
+                "larger than the {} MB input limit",
+                MAX_INPUT_SIZE / 1024 / 1024
+            ),
+            Self::NotFound => "file not found".to_string(),
+        }
+    }
+}
+
+/// A path an input resolved to, before classification.
+struct Candidate {
+    path: PathBuf,
+    /// Found by walking a directory (rather than named or globbed).
+    walked: bool,
+}
+
+/// One line of the listing.
+#[derive(Debug)]
+struct Listed {
+    path: PathBuf,
+    language: Option<Language>,
+    size: Option<u64>,
+    skip: Option<Skip>,
+}
+
+/// Print the files `inputs` resolve to and exit without transforming.
+pub(crate) fn run(
+    inputs: &[String],
+    ignore: IgnoreRules,
+    explicit_lang: Option<Language>,
+) -> anyhow::Result<()> {
+    let mut candidates = Vec::new();
+    let mut excluded = 0;
+    for input in inputs {
+        excluded += resolve(input, ignore, &mut candidates)?;
+    }
+    candidates.sort_by(|a, b| a.path.cmp(&b.path));
+    candidates.dedup_by(|a, b| a.path == b.path);
+
+    let listed: Vec<Listed> = candidates
+        .into_iter()
+        .map(|candidate| classify(candidate, explicit_lang))
+        .collect();
+
+    let mut stdout = io::stdout().lock();
+    for entry in &listed {
+        let language = entry.language.map_or("-", Language::name);
+        let size = entry
+            .size
+            .map_or_else(|| "-".to_string(), |s| s.to_string());
+        write!(
+            stdout,
+            "{language:<10} {size:>10}  {}",
+            entry.path.display()
+        )?;
+        if let Some(skip) = entry.skip {
+            write!(stdout, "  (skipped: {})", skip.reason())?;
+        }
+        writeln!(stdout)?;
+    }
+    stdout.flush()?;
+
+    let skipped = listed.iter().filter(|e| e.skip.is_some()).count();
+    let processed = listed.len() - skipped;
+    let mut summary = format!("[skim] {processed} file(s) would be processed, {skipped} skipped");
+    if excluded > 0 {
+        summary.push_str(&format!(
+            "; {excluded} more excluded by ignore rules (--no-ignore to include)"
+        ));
+    }
+    eprintln!("{summary}");
+    Ok(())
+}
+
+/// Whether `skim ls` was asked for the dry listing rather than `ls` output.
+pub(crate) fn is_dry_run(args: &[String]) -> bool {
+    args.iter().any(|a| a == "--dry-run")
+}
+
+/// Run `skim ls --dry-run [FLAGS] [INPUTS...]`.
+///
+/// Takes the discovery flags of a normal run; inputs default to the
+/// current directory.
+pub(crate) fn run_subcommand(args: &[String]) -> anyhow::Result<ExitCode> {
+    if args.iter().any(|a| matches!(a.as_str(), "--help" | "-h")) {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut inputs = Vec::new();
+    let (mut no_ignore, mut hermetic) = (false, false);
+    let mut lang = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        match flag {
+            "--dry-run" => {}
+            "--no-ignore" => no_ignore = true,
+            "--hermetic" => hermetic = true,
+            "--language" | "--lang" | "-l" => {
+                let raw = inline
+                    .or_else(|| iter.next().cloned())
+                    .ok_or_else(|| anyhow::anyhow!("--language requires a value"))?;
+                lang = Some(Language::from_fence_tag(&raw).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "unknown language: '{}'",
+                        crate::cmd::sanitize_for_display(&raw)
+                    )
+                })?);
+            }
+            "-" => anyhow::bail!(
+                "skim ls --dry-run cannot list stdin ('-')\n\
+                 It lists files on disk; pass files, directories, or globs."
+            ),
+            other if other.starts_with('-') => anyhow::bail!(
+                "unknown flag for skim ls --dry-run: '{}'\nRun 'skim ls --dry-run --help' for usage",
+                crate::cmd::sanitize_for_display(other)
+            ),
+            _ => inputs.push(arg.clone()),
+        }
+    }
+    if inputs.is_empty() {
+        inputs.push(".".to_string());
+    }
+
+    let ignore = match (no_ignore, hermetic) {
+        (true, _) => IgnoreRules::Off,
+        (false, true) => IgnoreRules::InTree,
+        (false, false) => IgnoreRules::Standard,
+    };
+    run(&inputs, ignore, lang)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_help() {
+    println!("skim ls --dry-run");
+    println!();
+    println!("  List the files a skim run would process, without reading them.");
+    println!();
+    println!("Usage: skim ls --dry-run [FLAGS] [INPUTS...]");
+    println!();
+    println!("FLAGS:");
+    println!("  --no-ignore          Include files excluded by .gitignore and hidden files");
+    println!("  --hermetic           Only apply ignore rules from inside the walked tree");
+    println!("  --language <LANG>    Language for files with no detectable extension");
+    println!();
+    println!("OUTPUT:");
+    println!("  One line per file: detected language, size in bytes, path, and the reason");
+    println!("  it would be skipped. A summary on stderr counts files the ignore rules");
+    println!("  excluded. INPUTS default to the current directory.");
+    println!();
+    println!("EXAMPLES:");
+    println!("  skim ls --dry-run");
+    println!("  skim ls --dry-run src/ 'tests/**/*.py' --no-ignore");
+    println!();
+    println!("Without --dry-run, skim ls runs ls and compresses its output.");
+}
+
+/// Add the files `input` resolves to, returning how many more the ignore
+/// rules excluded.
+fn resolve(
+    input: &str,
+    ignore: IgnoreRules,
+    candidates: &mut Vec<Candidate>,
+) -> anyhow::Result<usize> {
+    let path = Path::new(input);
+    let (found, walked) = if path.is_dir() {
+        (walk(path, ignore), true)
+    } else if multi::has_glob_pattern(input) {
+        let matched = match multi::expand_glob_to_paths(input, ignore) {
+            Ok(matched) => matched,
+            // Nothing matched: still count what the ignore rules hid.
+            Err(_) if ignore != IgnoreRules::Off => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        (matched, false)
+    } else {
+        candidates.push(Candidate {
+            path: path.to_path_buf(),
+            walked: false,
+        });
+        return Ok(0);
+    };
+
+    let excluded = if ignore == IgnoreRules::Off {
+        0
+    } else {
+        let kept: HashSet<&PathBuf> = found.iter().collect();
+        let all = if walked {
+            walk(path, IgnoreRules::Off)
+        } else {
+            multi::expand_glob_to_paths(input, IgnoreRules::Off)?
+        };
+        all.iter()
+            .filter(|p| !kept.contains(p) && !in_git_dir(p))
+            .count()
+    };
+    if found.is_empty() && excluded == 0 {
+        anyhow::bail!("No files found: '{input}'");
+    }
+
+    candidates.extend(found.into_iter().map(|path| Candidate { path, walked }));
+    Ok(excluded)
+}
+
+/// Every file under `dir` that `rules` let through, whatever its type.
+fn walk(dir: &Path, rules: IgnoreRules) -> Vec<PathBuf> {
+    let mut builder = WalkBuilder::new(dir);
+    multi::configure_walker(&mut builder, rules);
+    builder
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// Whether `path` is inside a `.git` directory, which `--no-ignore` walks
+/// but nobody means to skim.
+fn in_git_dir(path: &Path) -> bool {
+    path.components()
+        .any(|c| matches!(c, Component::Normal(name) if name == ".git"))
+}
+
+/// Work out the language, size, and skip reason for one file, mirroring the
+/// checks `process_file` makes before reading it.
+fn classify(candidate: Candidate, explicit_lang: Option<Language>) -> Listed {
+    let Candidate { path, walked } = candidate;
+    let detected = Language::from_path(&path);
+    let language = if walked && detected.is_none() {
+        None
+    } else {
+        explicit_lang.or(detected)
+    };
+    let size = std::fs::metadata(&path).ok().map(|meta| meta.len());
+
+    let skip = if size.is_none() {
+        Some(Skip::NotFound)
+    } else if language.is_none() {
+        Some(if walked {
+            Skip::UnsupportedType
+        } else {
+            Skip::UnknownLanguage
+        })
+    } else if size.is_some_and(|s| s > MAX_INPUT_SIZE as u64) {
+        Some(Skip::TooLarge)
+    } else {
+        None
+    };
+
+    Listed {
+        path,
+        language,
+        size,
+        skip,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn candidate(path: PathBuf, walked: bool) -> Candidate {
+        Candidate { path, walked }
+    }
+
+    #[test]
+    fn test_classify_detects_language_and_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.rs");
+        fs::write(&path, "fn a() {}\n").unwrap();
+        let listed = classify(candidate(path, true), None);
+        assert_eq!(listed.language, Some(Language::Rust));
+        assert_eq!(listed.size, Some(10));
+        assert_eq!(listed.skip, None);
+    }
+
+    #[test]
+    fn test_classify_skip_reasons() {
+        let dir = tempfile::tempdir().unwrap();
+        let notes = dir.path().join("notes.txt");
+        fs::write(&notes, "hello\n").unwrap();
+
+        let walked = classify(candidate(notes.clone(), true), Some(Language::Python));
+        assert_eq!(walked.skip, Some(Skip::UnsupportedType));
+        let named = classify(candidate(notes.clone(), false), None);
+        assert_eq!(named.skip, Some(Skip::UnknownLanguage));
+        let forced = classify(candidate(notes, false), Some(Language::Python));
+        assert_eq!(forced.language, Some(Language::Python));
+        assert_eq!(forced.skip, None);
+
+        let missing = classify(candidate(dir.path().join("gone.rs"), false), None);
+        assert_eq!(missing.skip, Some(Skip::NotFound));
+    }
+
+    #[test]
+    fn test_is_dry_run() {
+        assert!(is_dry_run(&["src/".to_string(), "--dry-run".to_string()]));
+        assert!(!is_dry_run(&["-la".to_string()]));
+    }
+
+    #[test]
+    fn test_in_git_dir() {
+        assert!(in_git_dir(Path::new("repo/.git/HEAD")));
+        assert!(!in_git_dir(Path::new("repo/.github/ci.yml")));
+    }
+}
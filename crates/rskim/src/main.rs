@@ -10,19 +10,32 @@
 //! - File-based caching with mtime invalidation
 
 mod analytics;
+mod barrel;
 mod cache;
 mod cascade;
 mod cmd;
 mod debug;
+mod depgraph;
+mod deprecation;
 mod format;
+mod grouping;
+mod list;
 mod multi;
 mod output;
+mod pick;
+mod preset;
 mod process;
+mod pure;
+mod record;
+mod reorder;
 mod runner;
+mod sample;
+mod schema;
 mod tokens;
+mod usage;
 
 use clap::Parser;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 use rskim_core::{Language, Mode};
@@ -74,6 +87,17 @@ fn is_flag_with_value(flag: &str) -> bool {
             | "--format"
             | "--blast-radius"
             | "--session-id"
+            | "--template-threshold"
+            | "--template"
+            | "--inputs-manifest"
+            | "--output"
+            | "--record"
+            | "--separator"
+            | "--preset"
+            | "--sample"
+            | "--sample-percent"
+            | "--sample-seed"
+            | "--schema"
     )
 }
 
@@ -176,6 +200,12 @@ const MAX_MAX_LINES: usize = 1_000_000;
 /// Maximum value for --tokens to prevent unreasonable values
 const MAX_TOKEN_BUDGET: usize = 10_000_000;
 
+/// Narrowest --wrap width: below this continuation indents eat most of each line
+const MIN_WRAP_COLUMNS: usize = 20;
+
+/// Widest --wrap width; anything wider never wraps a real signature
+const MAX_WRAP_COLUMNS: usize = 1_000;
+
 /// skim - Smart code reader for AI agents
 ///
 /// Transform source code by stripping implementation details while
@@ -195,6 +225,9 @@ const MAX_TOKEN_BUDGET: usize = 10_000_000;
     skim '*.{js,ts}' --no-header             Process multiple files without headers\n  \
     skim . --jobs 8                          Process current directory with 8 threads\n  \
     skim file.ts --no-cache                  Disable caching for pure transformation\n  \
+    skim file.rs --format ast                Dump the parsed AST with byte spans\n  \
+    skim src/ --format markdown              Markdown bundle with per-symbol anchors\n  \
+    skim src/ --format claude-xml            <documents> bundle for long-context prompts\n  \
     skim --clear-cache                       Clear all cached files\n\n\
 SUBCOMMANDS:\n  \
     cargo <test|build|clippy|nextest|audit>  Cargo subcommand compression\n  \
@@ -209,6 +242,7 @@ SUBCOMMANDS:\n  \
     heatmap                                  Git history risk/coupling analysis\n  \
     log                                      Log output compression\n  \
     agents                                   Show detected AI agents\n  \
+    build [MANIFEST]...                      Build context packs from *.skim.toml manifests\n  \
     completions <SHELL>                      Generate shell completions\n  \
     discover                                 Identify missed optimizations\n  \
     init                                     Initialize skim configuration\n  \
@@ -224,13 +258,29 @@ struct Args {
 
     /// Transformation mode
     #[arg(short, long, value_enum, default_value = "structure")]
-    #[arg(help = "Transformation mode: structure, signatures, types, full, minimal, or pseudo")]
+    #[arg(
+        help = "Transformation mode: structure (s), signatures (sig), docs (d), public-api (api), types (t), full (f), minimal, pseudo, comments (c), outline (o), call-graph (cg), todos (todo), summary (sum), constants (const), errors (err), or query (q)"
+    )]
     mode: ModeArg,
 
+    /// Apply a named bundle of flags.
+    ///
+    /// Built in: `review` (`--mode=structure --changed-only --show-stats`)
+    /// and `pack` (`--format=markdown --tokens=2000`). A `[presets]` table in
+    /// the nearest `skim.toml` adds presets or redefines these. Flags given on
+    /// the command line win over the preset's. Consumed pre-clap by
+    /// `preset::expand`; the field exists so clap accepts the flag.
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Apply a named flag bundle: review, pack, or a [presets] entry from skim.toml"
+    )]
+    preset: Option<String>,
+
     /// Override language detection (required for stdin unless --filename is given)
     #[arg(short, long, alias = "lang", value_enum)]
     #[arg(
-        help = "Programming language: typescript, javascript, python, rust, go, java, c, cpp, csharp, ruby, sql, kotlin, swift, markdown, json, yaml, toml (or use --filename for auto-detection from stdin)"
+        help = "Programming language: typescript, javascript, python, rust, go, java, c, cpp, csharp, ruby, sql, kotlin, swift, markdown, json, yaml, toml, vue, svelte, xml, html, hcl, dockerfile, shell, elixir, dart, solidity, objc, groovy, julia, ocaml, ocaml-interface, csv, env, ini, rst, asciidoc (or use --filename for auto-detection from stdin)"
     )]
     language: Option<LanguageArg>,
 
@@ -242,7 +292,8 @@ struct Args {
     /// Deprecated: accepted for backward compatibility but has no effect.
     ///
     /// This flag was dead code (never referenced in logic) and will be
-    /// removed in a future major version. Hidden from --help output.
+    /// removed in 3.0.0 (see `deprecation::FORCE_FLAG`). Hidden from --help
+    /// output.
     #[arg(long, hide = true)]
     _force: bool,
 
@@ -250,7 +301,25 @@ struct Args {
     #[arg(long, help = "Don't print file path headers for multi-file output")]
     no_header: bool,
 
-    /// Number of parallel jobs (default: number of CPUs)
+    /// Line printed between files when headers are disabled.
+    ///
+    /// Lets downstream tools split the stream back into files without the
+    /// path headers. `\f`, `\n`, `\t`, `\0` and `\\` are unescaped, so
+    /// `--separator '\f'` separates files with a form feed.
+    #[arg(
+        long,
+        value_name = "SEP",
+        requires = "no_header",
+        allow_hyphen_values = true,
+        help = "Print SEP on its own line between files (requires --no-header; \\f, \\n, \\t, \\0 are unescaped)"
+    )]
+    separator: Option<String>,
+
+    /// Number of parallel jobs.
+    ///
+    /// Defaults to a workload-based level: all CPUs when most inputs are large
+    /// source files, fewer when the batch is dominated by small or data files
+    /// (IO-bound). Set SKIM_DEBUG=1 to see the chosen value.
     #[arg(
         short,
         long,
@@ -270,14 +339,155 @@ struct Args {
     #[arg(long, help = "Disable caching of transformed output")]
     no_cache: bool,
 
+    /// How cache entries identify source files.
+    ///
+    /// `absolute` (default) keys on the canonical path and mtime.
+    /// `repo-relative` keys on the path below the git root and a hash of the
+    /// contents, so checkouts mounted at different paths share one cache.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "KEYS",
+        help = "Cache key backend: absolute (default) or repo-relative (portable across mounts)"
+    )]
+    cache_keys: Option<CacheKeysArg>,
+
+    /// Cache every mode when a file is transformed.
+    ///
+    /// On a cache miss the file is parsed once and transformed in every mode;
+    /// each result is cached, so a later run with a different `--mode` (same
+    /// other flags) is a cache hit.
+    #[arg(
+        long,
+        help = "On a cache miss, transform and cache every mode from a single parse"
+    )]
+    precompute_modes: bool,
+
+    /// Only print files whose output changed since the last run.
+    ///
+    /// Each file's output is compared with the output recorded by the previous
+    /// run with the same mode and flags; files that match are reduced to a
+    /// one-line `// <path> (unchanged)` entry. Every file is recorded for the
+    /// next run, so periodic refreshes print only what moved.
+    #[arg(
+        long,
+        help = "In multi-file runs, print a one-line 'unchanged' entry for files whose output matches the last run"
+    )]
+    changed_only: bool,
+
+    /// Write each file's output as soon as it is processed instead of in
+    /// input order.
+    ///
+    /// Ordered output waits for earlier files before writing later ones, so a
+    /// slow file near the start of a huge run delays everything behind it.
+    /// With this flag the first finished file is written first; every section
+    /// keeps its `// <path>` header so consumers can tell the files apart.
+    #[arg(
+        long,
+        help = "In multi-file runs, write each file as soon as it finishes (with headers) instead of in input order"
+    )]
+    stream_unordered: bool,
+
+    /// Fail when a directory argument has no supported files.
+    ///
+    /// By default such a directory is skipped and listed in the run summary
+    /// on stderr while the other arguments are processed; a run left with
+    /// nothing to process still fails.
+    #[arg(
+        long,
+        help = "Fail when any directory argument has no supported files instead of skipping it"
+    )]
+    fail_if_empty: bool,
+
+    /// Skim a stratified sample of at most N files instead of all of them.
+    ///
+    /// For a quick structural overview of a repository too large to skim in
+    /// full. Files are stratified by language and size so the sample keeps
+    /// the mix of the whole; every stratum is represented when N allows. The
+    /// seed is reported on stderr so the sample can be repeated.
+    #[arg(
+        long,
+        value_name = "N",
+        help = "In multi-file runs, skim a sample of N files stratified by language and size"
+    )]
+    sample: Option<usize>,
+
+    /// Like --sample, sized as a percentage of the files (rounded up).
+    #[arg(
+        long,
+        value_name = "P",
+        help = "In multi-file runs, skim a stratified sample of P percent of the files"
+    )]
+    sample_percent: Option<f64>,
+
+    /// Seed for --sample / --sample-percent.
+    ///
+    /// The same seed over the same files picks the same sample. Without it
+    /// each run draws a new seed and prints it.
+    #[arg(
+        long,
+        value_name = "SEED",
+        help = "Seed for --sample/--sample-percent, to repeat an earlier sample"
+    )]
+    sample_seed: Option<u64>,
+
     /// Clear the entire cache directory (~/.cache/skim/)
     #[arg(long, help = "Clear all cached files and exit")]
     clear_cache: bool,
 
+    /// Touch nothing outside the given paths.
+    ///
+    /// Implies --no-cache and --disable-analytics, skips the session sidecar
+    /// lookup, and limits ignore rules to `.gitignore`/`.ignore` files inside
+    /// the walked tree (no global gitignore, `.git/info/exclude`, or parent
+    /// directories). Also enabled by `SKIM_HERMETIC=1`. Consumed pre-clap by
+    /// `hermetic_requested`; the field exists so clap accepts the flag.
+    #[arg(
+        long,
+        help = "Read and write nothing outside the given paths (no cache, analytics, or global ignore files)"
+    )]
+    hermetic: bool,
+
+    /// Pure mode: read input paths from FILE instead of discovering them.
+    ///
+    /// One path per line (`#` comments and blank lines ignored). Implies
+    /// --hermetic; requires --output. No directories are walked, no globs are
+    /// expanded, and any unreadable entry fails the whole run. Prints
+    /// `sha256:<hex>` of the inputs on stdout, so build systems (Bazel, Nix)
+    /// can wrap skim as a cacheable action.
+    #[arg(
+        long,
+        value_name = "FILE",
+        requires = "output",
+        help = "Pure mode: transform exactly the files listed in FILE (implies --hermetic, requires --output)"
+    )]
+    inputs_manifest: Option<PathBuf>,
+
+    /// Output path for --inputs-manifest; written atomically.
+    #[arg(
+        long,
+        value_name = "PATH",
+        requires = "inputs_manifest",
+        help = "Write --inputs-manifest output to PATH"
+    )]
+    output: Option<PathBuf>,
+
     /// Show token count statistics (output to stderr)
     #[arg(long, help = "Show token reduction statistics")]
     show_stats: bool,
 
+    /// Show estimated token statistics without running the tokenizer.
+    ///
+    /// Implies --show-stats. Counts are derived from byte length with a
+    /// per-language ratio and printed with a `~` prefix and an "estimated"
+    /// label. Useful for planning runs over large trees where exact counts
+    /// dominate runtime.
+    #[arg(
+        long,
+        help = "Show estimated token statistics (fast, implies --show-stats)"
+    )]
+    stats_fast: bool,
+
     /// Maximum output lines (AST-aware smart truncation)
     ///
     /// Truncates output to at most N lines using priority-based selection.
@@ -325,6 +535,354 @@ struct Args {
     )]
     line_numbers: bool,
 
+    /// Skim language-tagged fenced code blocks in Markdown.
+    ///
+    /// In structure, signatures and types modes, fenced blocks tagged with a
+    /// supported language (```rust, ```python, ...) are transformed in the same
+    /// mode and kept under the heading they appear beneath. Untagged blocks and
+    /// unsupported languages are dropped as before.
+    #[arg(
+        long,
+        help = "Skim language-tagged fenced code blocks in Markdown (kept under their heading)"
+    )]
+    fenced_code: bool,
+
+    /// Collapse template literals longer than N bytes in structure mode.
+    ///
+    /// Large literals in top-level constants (HTML emails, GraphQL queries)
+    /// are replaced with a `...` placeholder. Defaults to 512 bytes; pass 0
+    /// to keep every literal intact.
+    #[arg(
+        long,
+        value_name = "BYTES",
+        help = "Collapse template literals longer than BYTES in structure mode (0 = never)"
+    )]
+    template_threshold: Option<usize>,
+
+    /// Keep only public declarations.
+    ///
+    /// In structure, signatures and types modes, declarations the language
+    /// marks as private are dropped: items without `pub` (Rust), `_names`
+    /// (Python), lowercase identifiers (Go), `private` members (Java, C#,
+    /// Kotlin, Swift, TS), non-exported top-level declarations in TS/JS
+    /// modules, and file-scope `static` functions (C/C++).
+    #[arg(
+        long,
+        help = "Keep only public declarations (structure, signatures and types modes)"
+    )]
+    public_only: bool,
+
+    /// Keep the file-level doc comment above signatures/types output.
+    ///
+    /// Rust `//!` lines, a Python module docstring, a Go package comment, or a
+    /// `/** @fileoverview */` block is copied verbatim at the top. Structure
+    /// mode already keeps it.
+    #[arg(
+        long,
+        help = "Keep the file-level doc comment (//!, module docstring, @fileoverview) in signatures/types output"
+    )]
+    module_doc: bool,
+
+    /// Emit Python signatures/types output as a `.pyi` stub.
+    ///
+    /// Classes keep their nested members, `def` headers end in `...`, and
+    /// annotated variables drop their values, so the output is a stub file
+    /// type checkers accept. Non-Python files are unaffected.
+    #[arg(
+        long,
+        help = "Emit Python signatures/types output as a valid .pyi stub (classes nested, bodies as ...)"
+    )]
+    pyi: bool,
+
+    /// Emit call-graph or dependency-graph output as a Graphviz DOT digraph.
+    ///
+    /// With `--mode call-graph`, each file becomes a `digraph calls { ... }`
+    /// of quoted `"caller" -> "callee";` edges; with `--dep-graph`, the
+    /// directory becomes one `digraph deps { ... }`. Ready for `dot -Tsvg`.
+    #[arg(
+        long,
+        help = "Emit --mode call-graph or --dep-graph output as a Graphviz DOT digraph instead of -> lines"
+    )]
+    dot: bool,
+
+    /// Replace Rust function bodies with `{ todo!() }` in structure mode.
+    ///
+    /// `use` declarations, attributes and derives are already kept, so the
+    /// skeleton usually still passes `cargo check` on its own. Non-Rust files
+    /// keep `{...}`.
+    #[arg(
+        long,
+        help = "Replace Rust function bodies with { todo!() } so the structure output still compiles"
+    )]
+    todo_bodies: bool,
+
+    /// Elide parameter lists longer than N in signatures mode.
+    ///
+    /// A signature with more than N parameters prints `(... 12 params)` in
+    /// place of its parameter list. Template `symbols` entries keep the full
+    /// list in `parameters`.
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Replace parameter lists longer than N with (... K params) in signatures mode"
+    )]
+    max_params: Option<usize>,
+
+    /// Soft-wrap signature lines wider than COLS display columns.
+    ///
+    /// Widths are measured per Unicode display width (CJK and emoji count
+    /// two columns). Continuation lines are indented four columns past the
+    /// original line.
+    #[arg(
+        long,
+        value_name = "COLS",
+        help = "Soft-wrap signature lines at COLS display columns in signatures mode"
+    )]
+    wrap: Option<usize>,
+
+    /// Drop declarations nested deeper than N levels in structure mode.
+    ///
+    /// A declaration inside more than N others (members of nested classes,
+    /// inner closures, local classes, items of nested modules) is left out
+    /// entirely. 0 keeps only top-level declarations; 1 adds class members
+    /// and `impl` methods. A dial between structure and signatures for
+    /// deeply nested code.
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Drop declarations nested more than N levels deep in structure mode"
+    )]
+    max_nesting: Option<usize>,
+
+    /// Keep the first N statements of each function body in structure mode.
+    ///
+    /// Instead of `{...}`, bodies keep their opening statements (guard
+    /// clauses, early returns, local type hints) and the rest becomes `...`
+    /// (`todo!()` in Rust with --todo-bodies). Bodies with no more than N
+    /// statements are kept whole; comments do not count.
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Keep the first N statements of each function body in structure mode"
+    )]
+    body_statements: Option<usize>,
+
+    /// Tree-sitter query file run by `--mode query`.
+    ///
+    /// The query is written for one grammar (`(function_item name:
+    /// (identifier) @name)` for Rust); files of other languages fail with an
+    /// invalid query error. Captures named with a leading `_` only feed
+    /// predicates such as `#eq?` and are not printed.
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Tree-sitter query (.scm) whose captured nodes --mode query prints"
+    )]
+    query: Option<PathBuf>,
+
+    /// Print the names exported by each `index.ts` barrel above a directory's
+    /// output.
+    ///
+    /// `export * from './x'` chains are followed to the files that declare
+    /// each name, so the section shows what every folder exports and where
+    /// it comes from.
+    #[arg(
+        long,
+        help = "List what each index.ts barrel exports (following export * chains) above directory output"
+    )]
+    barrel_exports: bool,
+
+    /// Group directory output by build unit instead of one flat list.
+    ///
+    /// `crate` reads the Cargo workspace members from `Cargo.toml` and prints
+    /// a banner with each crate's name, path and file count (plus token
+    /// totals with `--show-stats`) above its files. `package` does the same
+    /// for `package.json` workspaces and `pnpm-workspace.yaml`, adding each
+    /// package's version, entry point and dependencies below its banner.
+    /// `java-package` groups Java files by their `package` declaration and
+    /// lists each package's class, interface and enum counts.
+    #[arg(
+        long,
+        value_name = "UNIT",
+        help = "Group directory output by UNIT (crate: Cargo workspace members, package: JS workspace packages, java-package: Java package declarations) with per-group banners"
+    )]
+    group_by: Option<GroupByArg>,
+
+    /// Print the directory's file-level dependency graph instead of its
+    /// files.
+    ///
+    /// Import statements are resolved between the files of the directory
+    /// (TypeScript/JavaScript, Python, Rust, Go, C/C++, Java); each line is
+    /// an `importer -> imported` edge, followed by the orphans, files that
+    /// import no other file and are imported by none. Add `--dot` for a
+    /// Graphviz digraph.
+    #[arg(
+        long,
+        help = "Print the directory's file-level import graph (edges, then orphans) instead of file contents"
+    )]
+    dep_graph: bool,
+
+    /// Output format.
+    ///
+    /// `ast` prints an indented tree-sitter S-expression of the parsed file with
+    /// byte spans; `ast-json` prints the same tree as JSON. Dumps contain node
+    /// kinds, field names, and spans but no source text, so they can be attached
+    /// to bug reports. The mode is ignored; dumps are never cached.
+    ///
+    /// `ctags` lists every symbol as an extended ctags line (name, file,
+    /// line, kind, enclosing scope) after the tag-file pseudo-tags; `symbols`
+    /// lists them as `file:line:kind:name`. Listings feed editors, fuzzy
+    /// finders and indexers; the mode is ignored and they are never cached.
+    ///
+    /// `markdown` wraps each file's transformed output in a `## <path>`
+    /// section, split into fenced blocks under `### <path>#<symbol>` headings
+    /// so the bundle can be deep-linked and diffed by anchor.
+    ///
+    /// `claude-xml` wraps every file in a `<document index="N" path="...">`
+    /// tag under one `<documents>` root, after an `<index>` listing all files:
+    /// the layout recommended for long-context prompts.
+    #[arg(
+        long,
+        value_enum,
+        default_value = "text",
+        help = "Output format: text (transformed source), markdown (sections with per-symbol anchors), claude-xml (<document> tags with an index), ast (S-expression dump), ast-json, ctags, or symbols (file:line:kind:name)"
+    )]
+    format: FormatArg,
+
+    /// Render results through a template file instead of per-file sections.
+    ///
+    /// The template is a Handlebars subset (`{{var}}`, `{{#each}}`, `{{#if}}`,
+    /// `{{#unless}}`) over `mode`, `files` (path, language, content, symbols,
+    /// token counts) and `stats`, so a bundle can be emitted in an exact
+    /// prompt layout without post-processing. Unknown variables are errors.
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Render results through a Handlebars-style template (variables: mode, files, symbols, stats)"
+    )]
+    template: Option<PathBuf>,
+
+    /// Print one JSON object per file with the core's transform result.
+    ///
+    /// Each line carries the path, language and mode plus `content`,
+    /// `original_tokens`, `transformed_tokens` and `duration_ms` (time spent
+    /// in the core transform, fractional milliseconds), so benchmark
+    /// harnesses need not time skim from outside. Bypasses the cache so every
+    /// file is transformed and timed.
+    #[arg(
+        long,
+        help = "Print one JSON line per file: content, token counts, and transform duration_ms"
+    )]
+    detailed_json: bool,
+
+    /// Re-parse each transformed file with its own grammar and check for
+    /// syntax errors the transform introduced.
+    ///
+    /// Errors already in the source are not counted, and the `{...}` body
+    /// placeholder is read as an empty block. `fail` (the default) fails the
+    /// file; `warn` prints a warning on stderr and keeps the output. Files
+    /// without a tree-sitter grammar (JSON, YAML, TOML, scanner-based
+    /// formats) are not checked. Bypasses the cache so every output is
+    /// checked.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "ACTION",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "fail",
+        help = "Re-parse the output and fail (or warn) on syntax errors the transform introduced"
+    )]
+    validate_output: Option<process::ValidateOutput>,
+
+    /// Tidy each transformed file before printing it.
+    ///
+    /// The built-in formatter strips trailing whitespace, the extra space
+    /// before `{...}` placeholders and runs of blank lines. A `[formatters]`
+    /// table in the nearest `skim.toml` maps languages to external commands
+    /// (`rust = ["rustfmt", "--emit", "stdout"]`) that read the output on
+    /// stdin; a failing command falls back to the built-in formatter.
+    /// Bypasses the cache so every output is formatted.
+    #[arg(
+        long,
+        help = "Tidy output whitespace, or run the skim.toml [formatters] command for the language"
+    )]
+    format_output: bool,
+
+    /// Pick symbols to keep in full from an interactive list.
+    ///
+    /// Lists the file's symbols on stderr and reads picks from stdin
+    /// (numbers, `/filter`, `*`, enter to finish), then prints the skimmed
+    /// file with the picked declarations' full source spliced back in.
+    /// Requires a single file and a terminal on stdin and stdout.
+    #[arg(
+        long,
+        help = "Interactively pick symbols to keep in full (single file, terminal only)"
+    )]
+    pick: bool,
+
+    /// Record every transform into DIR for `skim replay`.
+    ///
+    /// Each call into the transformer writes a JSON case (source, language,
+    /// config, output or error) named by a hash of its inputs. Recording
+    /// bypasses the cache so every file is actually transformed.
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Record each transform (input, config, output) into DIR for 'skim replay'"
+    )]
+    record: Option<PathBuf>,
+
+    /// List the files a run would process, without reading or transforming them.
+    ///
+    /// Applies the same discovery rules (directory walks, globs, ignore
+    /// rules, language detection, the input size limit) and prints each
+    /// file's detected language and size, or why it would be skipped.
+    #[arg(
+        long,
+        help = "List the files that would be processed, with detected languages and sizes, and exit"
+    )]
+    list_files: bool,
+
+    /// Append an integrity footer after all output.
+    ///
+    /// The last line reports the file count, the token count, the byte count
+    /// and a SHA-256 of everything above it, so consumers of bundles passed
+    /// through copy-paste or chat uploads can detect truncation.
+    #[arg(
+        long,
+        help = "Append a footer line with file count, tokens, bytes and a SHA-256 of the output"
+    )]
+    footer: bool,
+
+    /// Report resource usage on stderr when the run finishes.
+    ///
+    /// Wall time, CPU time, peak RSS, files/sec and the cache hit ratio, as
+    /// one human-readable line or (`--report-usage=json`) one JSON object.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "text",
+        help = "Report wall/CPU time, peak RSS, files/sec and cache hit ratio on stderr (text or json)"
+    )]
+    report_usage: Option<usage::UsageFormat>,
+
+    /// Print the JSON Schema of a machine-readable output format and exit.
+    ///
+    /// Every document in those formats carries a top-level `schema_version`
+    /// matching the printed schema's, bumped on incompatible changes.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        conflicts_with = "files",
+        help = "Print the JSON Schema for detailed-json, ast-json, usage, stats, verify-cache or bench-cache output and exit"
+    )]
+    schema: Option<schema::SchemaFormat>,
+
     /// Disable analytics recording for this invocation
     #[arg(long, help = "Disable analytics recording")]
     disable_analytics: bool,
@@ -359,13 +917,47 @@ pub(crate) fn file_operation_command() -> clap::Command {
 /// Mode argument (clap value_enum wrapper)
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 enum ModeArg {
+    #[value(alias = "s")]
     Structure,
+    #[value(alias = "sig")]
     Signatures,
+    /// Docs mode — signatures with their doc comments and docstrings
+    #[value(alias = "d")]
+    Docs,
+    /// Public-API mode — exported signatures and type definitions only
+    #[value(alias = "api")]
+    PublicApi,
+    #[value(alias = "t")]
     Types,
+    #[value(alias = "f")]
     Full,
     Minimal,
     /// Pseudo mode — strips syntactic noise (types, decorators) while preserving logic and visibility
     Pseudo,
+    /// Comments mode — comments and docstrings only, each under its owning symbol (`42: Config.validate`)
+    #[value(alias = "c")]
+    Comments,
+    /// Outline mode — indented declaration tree with start line numbers (`42: fn run(...)`)
+    #[value(alias = "o")]
+    Outline,
+    /// Call-graph mode — `caller -> callee` edges between functions, no bodies
+    #[value(alias = "cg")]
+    CallGraph,
+    /// Todos mode — TODO/FIXME/HACK/XXX comment lines with their owning symbol (`42: TODO: ... (Config)`)
+    #[value(alias = "todo")]
+    Todos,
+    /// Summary mode — one line of counts per file (lines, functions, classes, types, tokens)
+    #[value(alias = "sum")]
+    Summary,
+    /// Constants mode — constant, enum and config declarations with their values
+    #[value(alias = "const")]
+    Constants,
+    /// Errors mode — error type declarations and throw/raise/`Err` sites with their owning symbol
+    #[value(alias = "err")]
+    Errors,
+    /// Query mode — text of the nodes captured by the tree-sitter query given with `--query`
+    #[value(alias = "q")]
+    Query,
 }
 
 impl From<ModeArg> for Mode {
@@ -373,10 +965,99 @@ impl From<ModeArg> for Mode {
         match arg {
             ModeArg::Structure => Mode::Structure,
             ModeArg::Signatures => Mode::Signatures,
+            ModeArg::Docs => Mode::Docs,
+            ModeArg::PublicApi => Mode::PublicApi,
             ModeArg::Types => Mode::Types,
             ModeArg::Full => Mode::Full,
             ModeArg::Minimal => Mode::Minimal,
             ModeArg::Pseudo => Mode::Pseudo,
+            ModeArg::Comments => Mode::Comments,
+            ModeArg::Outline => Mode::Outline,
+            ModeArg::CallGraph => Mode::CallGraph,
+            ModeArg::Todos => Mode::Todos,
+            ModeArg::Summary => Mode::Summary,
+            ModeArg::Constants => Mode::Constants,
+            ModeArg::Errors => Mode::Errors,
+            ModeArg::Query => Mode::Query,
+        }
+    }
+}
+
+/// Cache key backend argument (clap value_enum wrapper)
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum CacheKeysArg {
+    /// Canonical absolute path + mtime
+    Absolute,
+    /// Path relative to the git root + content hash
+    RepoRelative,
+}
+
+impl From<CacheKeysArg> for cache::CacheKeys {
+    fn from(arg: CacheKeysArg) -> Self {
+        match arg {
+            CacheKeysArg::Absolute => cache::CacheKeys::Absolute,
+            CacheKeysArg::RepoRelative => cache::CacheKeys::RepoRelative,
+        }
+    }
+}
+
+/// Grouping unit argument (clap value_enum wrapper)
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum GroupByArg {
+    /// Cargo workspace members
+    Crate,
+    /// package.json / pnpm workspace packages
+    Package,
+    /// Java `package` declarations
+    JavaPackage,
+}
+
+impl From<GroupByArg> for grouping::GroupBy {
+    fn from(arg: GroupByArg) -> Self {
+        match arg {
+            GroupByArg::Crate => grouping::GroupBy::Crate,
+            GroupByArg::Package => grouping::GroupBy::Package,
+            GroupByArg::JavaPackage => grouping::GroupBy::JavaPackage,
+        }
+    }
+}
+
+/// Output format argument (clap value_enum wrapper)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum FormatArg {
+    /// Transformed source (default)
+    Text,
+    /// Transformed source as Markdown sections with per-symbol anchors
+    Markdown,
+    /// Transformed source wrapped in `<document>` tags with an index
+    #[value(name = "claude-xml")]
+    ClaudeXml,
+    /// Sanitized tree-sitter S-expression with byte spans
+    Ast,
+    /// Sanitized AST as JSON
+    AstJson,
+    /// Symbols as extended ctags lines
+    Ctags,
+    /// Symbols as `file:line:kind:name` lines
+    Symbols,
+}
+
+impl FormatArg {
+    /// The AST dump format, or `None` for regular transformed output.
+    fn ast_format(self) -> Option<rskim_core::AstFormat> {
+        match self {
+            FormatArg::Ast => Some(rskim_core::AstFormat::SExpression),
+            FormatArg::AstJson => Some(rskim_core::AstFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// The symbol listing format, or `None` for regular transformed output.
+    fn tag_format(self) -> Option<output::tags::TagFormat> {
+        match self {
+            FormatArg::Ctags => Some(output::tags::TagFormat::Ctags),
+            FormatArg::Symbols => Some(output::tags::TagFormat::Symbols),
+            _ => None,
         }
     }
 }
@@ -386,6 +1067,7 @@ impl From<ModeArg> for Mode {
 enum LanguageArg {
     #[value(name = "typescript", alias = "ts")]
     TypeScript,
+    Tsx,
     #[value(name = "javascript", alias = "js")]
     JavaScript,
     #[value(alias = "py")]
@@ -411,12 +1093,48 @@ enum LanguageArg {
     #[value(alias = "kt")]
     Kotlin,
     Swift,
+    Vue,
+    Svelte,
+    Xml,
+    Html,
+    #[value(alias = "tf", alias = "terraform")]
+    Hcl,
+    #[value(alias = "docker")]
+    Dockerfile,
+    #[value(alias = "sh", alias = "bash", alias = "zsh")]
+    Shell,
+    #[value(alias = "ex")]
+    Elixir,
+    Dart,
+    #[value(alias = "sol")]
+    Solidity,
+    #[value(name = "objc", alias = "objective-c")]
+    ObjectiveC,
+    #[value(alias = "gradle")]
+    Groovy,
+    #[value(alias = "jl")]
+    Julia,
+    #[value(alias = "ml")]
+    Ocaml,
+    #[value(name = "ocaml-interface", alias = "mli")]
+    OcamlInterface,
+    #[value(alias = "tsv")]
+    Csv,
+    #[value(alias = "dotenv")]
+    Env,
+    #[value(aliases = ["cfg", "conf"])]
+    Ini,
+    #[value(alias = "restructuredtext")]
+    Rst,
+    #[value(alias = "adoc")]
+    AsciiDoc,
 }
 
 impl From<LanguageArg> for Language {
     fn from(arg: LanguageArg) -> Self {
         match arg {
             LanguageArg::TypeScript => Language::TypeScript,
+            LanguageArg::Tsx => Language::Tsx,
             LanguageArg::JavaScript => Language::JavaScript,
             LanguageArg::Python => Language::Python,
             LanguageArg::Rust => Language::Rust,
@@ -433,6 +1151,26 @@ impl From<LanguageArg> for Language {
             LanguageArg::Sql => Language::Sql,
             LanguageArg::Kotlin => Language::Kotlin,
             LanguageArg::Swift => Language::Swift,
+            LanguageArg::Vue => Language::Vue,
+            LanguageArg::Svelte => Language::Svelte,
+            LanguageArg::Xml => Language::Xml,
+            LanguageArg::Html => Language::Html,
+            LanguageArg::Hcl => Language::Hcl,
+            LanguageArg::Dockerfile => Language::Dockerfile,
+            LanguageArg::Shell => Language::Shell,
+            LanguageArg::Elixir => Language::Elixir,
+            LanguageArg::Dart => Language::Dart,
+            LanguageArg::Solidity => Language::Solidity,
+            LanguageArg::ObjectiveC => Language::ObjectiveC,
+            LanguageArg::Groovy => Language::Groovy,
+            LanguageArg::Julia => Language::Julia,
+            LanguageArg::Ocaml => Language::Ocaml,
+            LanguageArg::OcamlInterface => Language::OcamlInterface,
+            LanguageArg::Csv => Language::Csv,
+            LanguageArg::Env => Language::Env,
+            LanguageArg::Ini => Language::Ini,
+            LanguageArg::Rst => Language::Rst,
+            LanguageArg::AsciiDoc => Language::AsciiDoc,
         }
     }
 }
@@ -503,6 +1241,457 @@ fn validate_args(args: &Args) -> anyhow::Result<()> {
         );
     }
 
+    if args.format == FormatArg::Markdown && args.line_numbers {
+        anyhow::bail!(
+            "--line-numbers cannot be combined with --format markdown\n\
+             Markdown sections already anchor each symbol; drop --line-numbers or use --format text."
+        );
+    }
+
+    if args.format_output {
+        let conflicting = [
+            (args.line_numbers, "--line-numbers"),
+            (args.format == FormatArg::Markdown, "--format markdown"),
+            (args.format.ast_format().is_some(), "--format ast/ast-json"),
+            (args.format.tag_format().is_some(), "--format ctags/symbols"),
+        ];
+        if let Some((_, flag)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "--format-output cannot be combined with {flag}\n\
+                 Formatting moves output lines, so they no longer line up with source lines; \
+                 drop {flag} or --format-output."
+            );
+        }
+    }
+
+    if args.validate_output.is_some()
+        && matches!(
+            args.mode,
+            ModeArg::Outline
+                | ModeArg::Comments
+                | ModeArg::CallGraph
+                | ModeArg::Todos
+                | ModeArg::Summary
+                | ModeArg::Constants
+                | ModeArg::Errors
+                | ModeArg::Query
+        )
+    {
+        let mode = Mode::from(args.mode).name();
+        anyhow::bail!(
+            "--validate-output cannot be combined with --mode {mode}\n\
+             Outline, comments, call-graph, todos, summary and errors output are listings, \
+             query output is captured fragments, and constants output lifts class members \
+             out of their classes: none of them is source code that can be re-parsed."
+        );
+    }
+
+    if args.format.ast_format().is_some() {
+        let conflicting = [
+            (args.max_lines.is_some(), "--max-lines"),
+            (args.last_lines.is_some(), "--last-lines"),
+            (args.tokens.is_some(), "--tokens"),
+            (args.line_numbers, "--line-numbers"),
+            (args.show_stats || args.stats_fast, "--show-stats"),
+            (args.validate_output.is_some(), "--validate-output"),
+        ];
+        if let Some((_, flag)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "{flag} cannot be combined with --format ast/ast-json\n\
+                 AST dumps show the whole parse tree; drop {flag} or use --format text."
+            );
+        }
+    }
+
+    if args.format.tag_format().is_some() {
+        let conflicting = [
+            (args.max_lines.is_some(), "--max-lines"),
+            (args.last_lines.is_some(), "--last-lines"),
+            (args.tokens.is_some(), "--tokens"),
+            (args.line_numbers, "--line-numbers"),
+            (args.show_stats || args.stats_fast, "--show-stats"),
+            (args.validate_output.is_some(), "--validate-output"),
+            (args.separator.is_some(), "--separator"),
+        ];
+        if let Some((_, flag)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "{flag} cannot be combined with --format ctags/symbols\n\
+                 Symbol listings are one line per declaration; drop {flag} or use --format text."
+            );
+        }
+    }
+
+    if args.public_only
+        && !matches!(
+            args.mode,
+            ModeArg::Structure
+                | ModeArg::Signatures
+                | ModeArg::Docs
+                | ModeArg::PublicApi
+                | ModeArg::Types
+                | ModeArg::Outline
+        )
+    {
+        anyhow::bail!(
+            "--public-only requires --mode structure, signatures, docs, types or outline\n\
+             Other modes keep every line of the file and have no declarations to drop."
+        );
+    }
+
+    if args.pyi && !matches!(args.mode, ModeArg::Signatures | ModeArg::Types) {
+        anyhow::bail!(
+            "--pyi requires --mode signatures or types\n\
+             Stubs hold declarations only; structure mode already keeps the file's layout."
+        );
+    }
+
+    if args.dot && !(matches!(args.mode, ModeArg::CallGraph) || args.dep_graph) {
+        anyhow::bail!(
+            "--dot requires --mode call-graph or --dep-graph\n\
+             Only call graphs and dependency graphs are graphs."
+        );
+    }
+
+    if args.todo_bodies && !matches!(args.mode, ModeArg::Structure) {
+        anyhow::bail!(
+            "--todo-bodies requires --mode structure\n\
+             Only structure mode replaces function bodies."
+        );
+    }
+
+    if args.max_nesting.is_some() && !matches!(args.mode, ModeArg::Structure) {
+        anyhow::bail!(
+            "--max-nesting requires --mode structure\n\
+             Other modes do not keep nested declarations in place."
+        );
+    }
+
+    if args.body_statements.is_some() && !matches!(args.mode, ModeArg::Structure) {
+        anyhow::bail!(
+            "--body-statements requires --mode structure\n\
+             Only structure mode replaces function bodies."
+        );
+    }
+
+    match (&args.query, args.mode) {
+        (Some(_), mode) if !matches!(mode, ModeArg::Query) => anyhow::bail!(
+            "--query requires --mode query\n\
+             Other modes do not run tree-sitter queries."
+        ),
+        (None, ModeArg::Query) => anyhow::bail!(
+            "--mode query requires --query <FILE>\n\
+             Write a tree-sitter query (.scm) for the files' grammar and pass its path."
+        ),
+        _ => {}
+    }
+
+    if args.max_params.is_some()
+        && !matches!(
+            args.mode,
+            ModeArg::Signatures | ModeArg::Docs | ModeArg::PublicApi
+        )
+    {
+        anyhow::bail!(
+            "--max-params requires --mode signatures, docs or public-api\n\
+             Other modes keep parameter lists as part of the source."
+        );
+    }
+
+    if let Some(columns) = args.wrap {
+        if !matches!(
+            args.mode,
+            ModeArg::Signatures | ModeArg::Docs | ModeArg::PublicApi
+        ) {
+            anyhow::bail!(
+                "--wrap requires --mode signatures, docs or public-api\n\
+                 Other modes keep the source's own line breaks."
+            );
+        }
+        if !(MIN_WRAP_COLUMNS..=MAX_WRAP_COLUMNS).contains(&columns) {
+            anyhow::bail!(
+                "--wrap must be between {MIN_WRAP_COLUMNS} and {MAX_WRAP_COLUMNS} columns (got {columns})"
+            );
+        }
+    }
+
+    if args.barrel_exports {
+        if !(args.files.len() == 1 && std::path::Path::new(&args.files[0]).is_dir()) {
+            anyhow::bail!(
+                "--barrel-exports requires a single directory argument\n\
+                 Barrels are resolved across the folder being skimmed."
+            );
+        }
+        if args.format != FormatArg::Text || args.template.is_some() {
+            anyhow::bail!(
+                "--barrel-exports requires --format text without --template\n\
+                 The export section is written as comments above the file sections."
+            );
+        }
+    }
+
+    if args.group_by.is_some() {
+        if !(args.files.len() == 1 && std::path::Path::new(&args.files[0]).is_dir()) {
+            anyhow::bail!(
+                "--group-by requires a single directory argument\n\
+                 Groups are found across the folder being skimmed."
+            );
+        }
+        if args.format != FormatArg::Text || args.template.is_some() {
+            anyhow::bail!(
+                "--group-by requires --format text without --template\n\
+                 Group banners are written between the file sections."
+            );
+        }
+    }
+
+    if args.dep_graph {
+        if !(args.files.len() == 1 && std::path::Path::new(&args.files[0]).is_dir()) {
+            anyhow::bail!(
+                "--dep-graph requires a single directory argument\n\
+                 Imports are resolved between the files of the folder being skimmed."
+            );
+        }
+        let conflicting = [
+            (args.format != FormatArg::Text, "--format"),
+            (args.template.is_some(), "--template"),
+            (args.detailed_json, "--detailed-json"),
+            (args.list_files, "--list-files"),
+            (args.barrel_exports, "--barrel-exports"),
+            (args.group_by.is_some(), "--group-by"),
+        ];
+        if let Some((_, what)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "--dep-graph cannot be combined with {what}\n\
+                 The graph replaces the file contents; use --dot for Graphviz output."
+            );
+        }
+    }
+
+    if args.cache_keys.is_some() && (args.no_cache || args.clear_cache) {
+        anyhow::bail!(
+            "--cache-keys cannot be combined with --no-cache or --clear-cache\n\
+             It only selects how cache entries are keyed; drop it when the cache is off."
+        );
+    }
+
+    if args.precompute_modes {
+        let conflicting = [
+            (args.no_cache, "--no-cache"),
+            (args.clear_cache, "--clear-cache"),
+            (args.record.is_some(), "--record"),
+            (args.tokens.is_some(), "--tokens"),
+            (
+                matches!(
+                    args.format,
+                    FormatArg::Markdown
+                        | FormatArg::Ast
+                        | FormatArg::AstJson
+                        | FormatArg::Ctags
+                        | FormatArg::Symbols
+                ),
+                "--format markdown/ast/ast-json/ctags/symbols",
+            ),
+        ];
+        if let Some((_, what)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "--precompute-modes cannot be combined with {what}\n\
+                 Modes are only precomputed into the cache, and {what} bypasses it \
+                 or picks the mode itself."
+            );
+        }
+    }
+
+    if args.changed_only {
+        let multi_file = args.files.len() > 1
+            || args
+                .files
+                .first()
+                .is_some_and(|f| multi::has_glob_pattern(f) || std::path::Path::new(f).is_dir());
+        if !multi_file {
+            anyhow::bail!(
+                "--changed-only requires a directory, glob, or several files\n\
+                 A single file is always printed in full."
+            );
+        }
+        let conflicting = [
+            (args.no_cache, "--no-cache"),
+            (args.clear_cache, "--clear-cache"),
+            (args.record.is_some(), "--record"),
+            (args.template.is_some(), "--template"),
+            (
+                matches!(
+                    args.format,
+                    FormatArg::Markdown
+                        | FormatArg::ClaudeXml
+                        | FormatArg::Ast
+                        | FormatArg::AstJson
+                        | FormatArg::Ctags
+                        | FormatArg::Symbols
+                ),
+                "--format markdown/claude-xml/ast/ast-json/ctags/symbols",
+            ),
+        ];
+        if let Some((_, what)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "--changed-only cannot be combined with {what}\n\
+                 The previous run's output is remembered in the cache, and file \
+                 sections are replaced line by line."
+            );
+        }
+    }
+
+    if args.stream_unordered {
+        let conflicting = [
+            (args.no_header, "--no-header"),
+            (args.footer, "--footer"),
+            (args.group_by.is_some(), "--group-by"),
+            (args.template.is_some(), "--template"),
+            (args.format == FormatArg::ClaudeXml, "--format claude-xml"),
+        ];
+        if let Some((_, what)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "--stream-unordered cannot be combined with {what}\n\
+                 Files are written the moment they finish, in any order, so each \
+                 one needs its path header and nothing can wait for the whole run."
+            );
+        }
+    }
+
+    if args.detailed_json {
+        let conflicting = [
+            (args.format != FormatArg::Text, "--format"),
+            (args.template.is_some(), "--template"),
+            (args.group_by.is_some(), "--group-by"),
+            (args.barrel_exports, "--barrel-exports"),
+            (args.changed_only, "--changed-only"),
+            (args.footer, "--footer"),
+            (args.show_stats, "--show-stats"),
+            (args.pick, "--pick"),
+            (args.list_files, "--list-files"),
+            (args.inputs_manifest.is_some(), "--inputs-manifest"),
+        ];
+        if let Some((_, what)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "--detailed-json cannot be combined with {what}\n\
+                 Each file is printed as one JSON object with its own token counts; \
+                 drop {what} or --detailed-json."
+            );
+        }
+    }
+
+    if args.sample.is_some() || args.sample_percent.is_some() {
+        if args.sample.is_some() && args.sample_percent.is_some() {
+            anyhow::bail!("--sample and --sample-percent cannot be combined; pick one sample size");
+        }
+        if args.sample == Some(0) {
+            anyhow::bail!("--sample must be at least 1");
+        }
+        if args
+            .sample_percent
+            .is_some_and(|percent| !(percent > 0.0 && percent <= 100.0))
+        {
+            anyhow::bail!("--sample-percent must be greater than 0 and at most 100");
+        }
+        let multi_file = args.files.len() > 1
+            || args
+                .files
+                .first()
+                .is_some_and(|f| multi::has_glob_pattern(f) || std::path::Path::new(f).is_dir());
+        if !multi_file {
+            anyhow::bail!(
+                "--sample requires a directory, glob, or several files\n\
+                 A single file or stdin has nothing to sample from."
+            );
+        }
+        let conflicting = [
+            (args.inputs_manifest.is_some(), "--inputs-manifest"),
+            (args.list_files, "--list-files"),
+            (args.pick, "--pick"),
+        ];
+        if let Some((_, what)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!("--sample cannot be combined with {what}");
+        }
+    } else if args.sample_seed.is_some() {
+        anyhow::bail!("--sample-seed requires --sample or --sample-percent");
+    }
+
+    if args.inputs_manifest.is_some() {
+        let conflicting = [
+            (!args.files.is_empty(), "FILE arguments"),
+            (args.no_ignore, "--no-ignore"),
+            (args.clear_cache, "--clear-cache"),
+            (args.template.is_some(), "--template"),
+        ];
+        if let Some((_, what)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "{what} cannot be combined with --inputs-manifest\n\
+                 Pure mode reads exactly the files listed in the manifest; list every input there."
+            );
+        }
+    }
+
+    if args.template.is_some() && args.format != FormatArg::Text {
+        anyhow::bail!(
+            "--template cannot be combined with --format\n\
+             The template decides the layout; drop --format."
+        );
+    }
+
+    if args.template.is_some() && args.files.iter().any(|f| f == "-") {
+        anyhow::bail!(
+            "--template cannot be used with stdin ('-')\n\
+             Templates describe files by path; pass the files directly."
+        );
+    }
+
+    if args.separator.is_some() && (args.template.is_some() || args.format == FormatArg::ClaudeXml)
+    {
+        anyhow::bail!(
+            "--separator cannot be combined with --template or --format claude-xml\n\
+             Those formats render all files as one document with their own structure."
+        );
+    }
+
+    if args.list_files {
+        if args.files.iter().any(|f| f == "-") {
+            anyhow::bail!(
+                "--list-files cannot be used with stdin ('-')\n\
+                 It lists files on disk; pass files, directories, or globs."
+            );
+        }
+        if args.inputs_manifest.is_some() || args.pick {
+            anyhow::bail!(
+                "--list-files cannot be combined with --inputs-manifest or --pick\n\
+                 A manifest already lists its files, and --pick takes a single file."
+            );
+        }
+    }
+
+    if args.pick {
+        if args.files.len() != 1 || args.files[0] == "-" {
+            anyhow::bail!(
+                "--pick takes exactly one file\n\
+                 The picker lists one file's symbols; pass a single file path."
+            );
+        }
+        let conflicting = [
+            (args.inputs_manifest.is_some(), "--inputs-manifest"),
+            (args.template.is_some(), "--template"),
+            (args.format != FormatArg::Text, "--format"),
+            (args.max_lines.is_some(), "--max-lines"),
+            (args.last_lines.is_some(), "--last-lines"),
+            (args.tokens.is_some(), "--tokens"),
+            (args.line_numbers, "--line-numbers"),
+            (args.footer, "--footer"),
+        ];
+        if let Some((_, flag)) = conflicting.iter().find(|(set, _)| *set) {
+            anyhow::bail!(
+                "{flag} cannot be combined with --pick\n\
+                 Picked output splices whole declarations into the skimmed file; drop {flag}."
+            );
+        }
+    }
+
     // --filename is only valid when the single argument is '-' (stdin)
     if args.filename.is_some() && !(args.files.len() == 1 && args.files[0] == "-") {
         anyhow::bail!(
@@ -582,6 +1771,18 @@ fn detect_argv0_dispatch() -> Option<(String, Vec<String>)> {
     }
 }
 
+/// Whether hermetic mode was requested via `--hermetic`, `SKIM_HERMETIC`, or
+/// pure mode (`--inputs-manifest`, which implies it).
+///
+/// A truthy `SKIM_HERMETIC` is `1`, `true`, or `yes` (case-insensitive),
+/// matching `SKIM_DEBUG` and `SKIM_DISABLE_ANALYTICS`.
+fn hermetic_requested() -> bool {
+    std::env::args().any(|a| {
+        a == "--hermetic" || a == "--inputs-manifest" || a.starts_with("--inputs-manifest=")
+    }) || std::env::var("SKIM_HERMETIC")
+        .is_ok_and(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+}
+
 /// Extract and validate `--session-id=VALUE` from a command-line argument iterator.
 ///
 /// Returns `Some(value)` when exactly one `--session-id=VALUE` argument is present
@@ -760,7 +1961,10 @@ fn main() -> ExitCode {
 
     // Read analytics config from env + CLI flag once at the system boundary.
     // Thread the struct down to all callers — no per-call env reads.
-    let cli_disable_analytics = std::env::args().any(|a| a == "--disable-analytics");
+    // Hermetic mode gates work done before routing (session sidecar lookup,
+    // analytics), so it is resolved here rather than from parsed args.
+    let hermetic = hermetic_requested();
+    let cli_disable_analytics = hermetic || std::env::args().any(|a| a == "--disable-analytics");
     // Resolution priority (skew-proof, #1.1):
     //   1. Sidecar (out-of-band, written by the hook) — preferred path.
     //      Resolves via ancestry walk so even a child two levels deep finds it.
@@ -770,17 +1974,21 @@ fn main() -> ExitCode {
     //      it is never the primary path. Flag injection was removed from the
     //      hook (#1.1 / fix/rewrite-compression-batch) to prevent version-skew
     //      hard-failures ("unexpected argument --session-id" on older binaries).
-    let session_id = {
-        let dir = cmd::resolve_cache_dir();
-        dir.as_deref()
-            .and_then(cmd::session_sidecar::read_session_id)
-    }
-    .or_else(|| {
-        std::env::var("SKIM_SESSION_ID")
-            .ok()
-            .filter(|s| analytics::is_safe_session_id(s))
-    })
-    .or_else(|| parse_session_id(std::env::args()));
+    let session_id = if hermetic {
+        None
+    } else {
+        {
+            let dir = cmd::resolve_cache_dir();
+            dir.as_deref()
+                .and_then(cmd::session_sidecar::read_session_id)
+        }
+        .or_else(|| {
+            std::env::var("SKIM_SESSION_ID")
+                .ok()
+                .filter(|s| analytics::is_safe_session_id(s))
+        })
+        .or_else(|| parse_session_id(std::env::args()))
+    };
     let analytics = analytics::AnalyticsConfig::from_process(cli_disable_analytics, session_id);
 
     // Mark the thread-spawn boundary.  Any code below this line may spawn
@@ -808,7 +2016,9 @@ fn main() -> ExitCode {
         }
     } else {
         match resolve_invocation() {
-            Invocation::FileOperation => run_file_operation(&analytics).map(|()| ExitCode::SUCCESS),
+            Invocation::FileOperation => {
+                run_file_operation(&analytics, hermetic).map(|()| ExitCode::SUCCESS)
+            }
             Invocation::Subcommand { name, args } => cmd::dispatch(&name, &args, &analytics),
         }
     };
@@ -835,9 +2045,47 @@ fn main() -> ExitCode {
 /// - 0 args → usage error
 /// - 1 arg  → `process_single_arg` (stdin, directory, glob, or single file)
 /// - N args → explicit multi-file list (no stdin mixing allowed)
-fn run_file_operation(analytics: &analytics::AnalyticsConfig) -> anyhow::Result<()> {
-    let args = Args::parse();
+fn run_file_operation(
+    analytics: &analytics::AnalyticsConfig,
+    hermetic: bool,
+) -> anyhow::Result<()> {
+    let started = std::time::Instant::now();
+    let args = Args::parse_from(preset::expand(std::env::args().collect(), hermetic)?);
     validate_args(&args)?;
+    if args._force {
+        deprecation::warn(deprecation::FORCE_FLAG);
+    }
+
+    if hermetic && args.changed_only {
+        anyhow::bail!(
+            "--changed-only cannot be used in hermetic mode\n\
+             The previous run's output is remembered in the cache directory, which hermetic runs never touch."
+        );
+    }
+
+    if hermetic && args.clear_cache {
+        anyhow::bail!(
+            "--clear-cache cannot be used in hermetic mode\n\
+             Hermetic runs never touch the cache directory; unset SKIM_HERMETIC or drop --hermetic."
+        );
+    }
+
+    if let Some(dir) = &args.record {
+        record::enable(dir)?;
+    }
+
+    if args.format_output {
+        output::reformat::configure(hermetic)?;
+    }
+
+    if let Some(format) = args.schema {
+        use std::io::Write;
+
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &format.schema())?;
+        writeln!(stdout)?;
+        return Ok(());
+    }
 
     if args.clear_cache {
         cache::clear_cache()?;
@@ -845,7 +2093,7 @@ fn run_file_operation(analytics: &analytics::AnalyticsConfig) -> anyhow::Result<
         return Ok(());
     }
 
-    if args.files.is_empty() {
+    if args.files.is_empty() && args.inputs_manifest.is_none() {
         anyhow::bail!(
             "FILE argument is required\n\
              Usage: skim <FILE|DIR|GLOB> [--mode structure|signatures|types|full|minimal|pseudo]\n\
@@ -853,32 +2101,137 @@ fn run_file_operation(analytics: &analytics::AnalyticsConfig) -> anyhow::Result<
         );
     }
 
+    let query = args.query.as_deref().map(read_query).transpose()?;
     let process_options = process::ProcessOptions {
         mode: Mode::from(args.mode),
         explicit_lang: args.language.map(Language::from),
-        use_cache: !args.no_cache,
-        show_stats: args.show_stats,
+        // Markdown sections embed the path as typed, so they are not cached.
+        // Recording must see every transform, so it bypasses the cache too,
+        // as does --detailed-json, which times every transform, and
+        // --validate-output and --format-output, which post-process it.
+        use_cache: !args.no_cache
+            && !hermetic
+            && args.format != FormatArg::Markdown
+            && !args.detailed_json
+            && args.validate_output.is_none()
+            && !args.format_output
+            && !record::is_enabled(),
+        cache_keys: args.cache_keys.map(Into::into).unwrap_or_default(),
+        precompute_modes: args.precompute_modes,
+        // --detailed-json reports counts per file instead of on stderr.
+        show_stats: args.show_stats || args.stats_fast || args.detailed_json,
+        stats_fast: args.stats_fast,
         trunc: cascade::TruncationOptions {
             max_lines: args.max_lines,
             last_lines: args.last_lines,
             token_budget: args.tokens,
         },
         line_numbers: args.line_numbers,
+        flags: cascade::TransformFlags {
+            fenced_code: args.fenced_code,
+            template_threshold: args.template_threshold,
+            public_only: args.public_only,
+            module_doc: args.module_doc,
+            python_stub: args.pyi,
+            dot_graph: args.dot,
+            todo_bodies: args.todo_bodies,
+            max_params: args.max_params,
+            wrap_width: args.wrap,
+            max_nesting: args.max_nesting,
+            body_statements: args.body_statements.unwrap_or(0),
+            query,
+        },
+        ast_format: args.format.ast_format(),
+        tag_format: args.format.tag_format(),
+        markdown: args.format == FormatArg::Markdown,
+        validate_output: args.validate_output,
+        format_output: args.format_output,
     };
 
     let multi_options = multi::MultiFileOptions {
         process: process_options,
         no_header: args.no_header,
+        separator: args.separator.as_deref().map(multi::unescape_separator),
         jobs: args.jobs,
-        no_ignore: args.no_ignore,
-        analytics_enabled: analytics.enabled,
+        ignore: match (args.no_ignore, hermetic) {
+            (true, _) => multi::IgnoreRules::Off,
+            (false, true) => multi::IgnoreRules::InTree,
+            (false, false) => multi::IgnoreRules::Standard,
+        },
+        // AST dumps and symbol listings are not compressions; keep them out
+        // of token analytics.
+        analytics_enabled: analytics.enabled
+            && process_options.ast_format.is_none()
+            && process_options.tag_format.is_none(),
         session_id: analytics.session_id.clone(),
+        template: args
+            .template
+            .as_deref()
+            .map(output::template::Template::load)
+            .transpose()?,
+        claude_xml: args.format == FormatArg::ClaudeXml,
+        footer: args.footer,
+        barrel_exports: args.barrel_exports,
+        changed_only: args.changed_only,
+        stream_unordered: args.stream_unordered,
+        fail_if_empty: args.fail_if_empty,
+        detailed_json: args.detailed_json,
+        sample: args
+            .sample
+            .map(sample::SampleSize::Files)
+            .or(args.sample_percent.map(sample::SampleSize::Percent))
+            .map(|size| sample::Sampling::new(size, args.sample_seed)),
+        group_by: args.group_by.map(Into::into),
     };
 
+    let result = run_inputs(&args, analytics, process_options, multi_options);
+    if let Some(format) = args.report_usage {
+        // Reported for failed runs too: a run that died halfway still used
+        // the time and memory.
+        usage::report(started, format);
+    }
+    result
+}
+
+/// Read the `--query` file once; leaked so `TransformFlags` stays `Copy`.
+fn read_query(path: &Path) -> anyhow::Result<&'static str> {
+    let query = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("--query: cannot read {}: {e}", path.display()))?;
+    Ok(Box::leak(query.into_boxed_str()))
+}
+
+/// Route the parsed inputs to the matching processor.
+fn run_inputs(
+    args: &Args,
+    analytics: &analytics::AnalyticsConfig,
+    process_options: process::ProcessOptions,
+    multi_options: multi::MultiFileOptions,
+) -> anyhow::Result<()> {
+    if args.dep_graph {
+        return depgraph::run(Path::new(&args.files[0]), multi_options.ignore, args.dot);
+    }
+
+    if args.list_files {
+        return list::run(
+            &args.files,
+            multi_options.ignore,
+            process_options.explicit_lang,
+        );
+    }
+
+    if let (Some(manifest), Some(output)) = (&args.inputs_manifest, &args.output) {
+        return pure::run_pure(manifest, output, &multi_options);
+    }
+
+    if args.format == FormatArg::Ctags {
+        // Pseudo-tags open the tag file once, ahead of every file's lines.
+        print!("{}", output::tags::CTAGS_HEADER);
+    }
+
     if args.files.len() == 1 {
         return process_single_arg(
             &args.files[0],
-            &args,
+            args,
             analytics,
             process_options,
             multi_options,
@@ -925,10 +2278,27 @@ fn process_single_arg(
     let mode_str = format!("{:?}", Mode::from(args.mode)).to_lowercase();
 
     if file == "-" {
-        let result = process::process_stdin(process_options, args.filename.as_deref())?;
-        process::write_result_and_stats(&result, args.show_stats)?;
+        let mut result = process::process_stdin(process_options, args.filename.as_deref())?;
+        if multi_options.claude_xml {
+            // Labelled like Markdown sections: the filename hint, else `stdin`.
+            let bundle = output::claude_xml::render_documents(&[output::claude_xml::Document {
+                path: args.filename.clone().unwrap_or_else(|| "stdin".to_string()),
+                language: result.language,
+                content: &result.output,
+            }]);
+            result.output = bundle;
+        }
+        if args.detailed_json {
+            let label = Path::new(args.filename.as_deref().unwrap_or("-"));
+            write_detailed_json(label, process_options.mode, &result)?;
+        } else {
+            process::write_result_and_stats(&result, process_options.show_stats)?;
+        }
+        if multi_options.footer {
+            write_footer(&result.output)?;
+        }
         record_file_analytics(
-            analytics.enabled,
+            multi_options.analytics_enabled,
             result,
             "skim -",
             mode_str,
@@ -941,6 +2311,10 @@ fn process_single_arg(
 
     let path = PathBuf::from(file);
 
+    if args.pick {
+        return pick::run(&path, process_options);
+    }
+
     if path.is_dir() {
         return multi::process_directory(&path, multi_options);
     }
@@ -949,11 +2323,23 @@ fn process_single_arg(
         return multi::process_glob(file, multi_options);
     }
 
+    // Bundles always render through the multi-file path, even for one file.
+    if multi_options.is_bundled() {
+        return multi::process_explicit_files(&[file.to_string()], multi_options);
+    }
+
     let result = process::process_file(&path, process_options)?;
-    process::write_result_and_stats(&result, args.show_stats)?;
+    if args.detailed_json {
+        write_detailed_json(&path, process_options.mode, &result)?;
+    } else {
+        process::write_result_and_stats(&result, process_options.show_stats)?;
+    }
+    if multi_options.footer {
+        write_footer(&result.output)?;
+    }
     let cmd = format!("skim {file}");
     record_file_analytics(
-        analytics.enabled,
+        multi_options.analytics_enabled,
         result,
         &cmd,
         mode_str,
@@ -964,6 +2350,30 @@ fn process_single_arg(
     Ok(())
 }
 
+/// Print the `--detailed-json` record for a single file's (or stdin's) result.
+fn write_detailed_json(
+    label: &Path,
+    mode: Mode,
+    result: &process::ProcessResult,
+) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(output::detailed_json::render(label, mode, result).as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Print the `--footer` line for a single file's (or stdin's) output.
+fn write_footer(output: &str) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(output::footer::render(output, 1).as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
+
 /// Record token analytics for file operations (single file or stdin).
 ///
 /// Takes `result` by value so `output` and `stdin_raw` can be moved into the
@@ -983,7 +2393,7 @@ fn record_file_analytics(
     // Determine counts variant: Known when both token counts are already computed
     // (i.e. --show-stats ran, or a count-carrying cache hit); Tokenize otherwise.
     let counts = match (result.original_tokens, result.transformed_tokens) {
-        (Some(raw), Some(comp)) => {
+        (Some(raw), Some(comp)) if !result.tokens_estimated => {
             // AC F5: counts in hand — no re-read, no double work.
             analytics::FileCounts::Known {
                 raw,
@@ -1162,6 +2572,11 @@ mod tests {
         "--format",
         "--blast-radius",
         "--session-id",
+        "--template-threshold",
+        "--preset",
+        "--sample",
+        "--sample-percent",
+        "--sample-seed",
     ];
 
     /// Ensure every value-consuming flag (non-boolean, non-positional) in `Args`
@@ -1189,6 +2604,8 @@ mod tests {
             "--no-cache",
             "--clear-cache",
             "--show-stats",
+            "--stats-fast",
+            "--fenced-code",
             "--disable-analytics",
             "--debug",
         ];
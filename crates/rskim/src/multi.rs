@@ -3,26 +3,164 @@
 //! Orchestrates [`crate::process::process_file`] over multiple inputs using rayon
 //! for parallelism. Uses the `ignore` crate (from ripgrep) for directory walking,
 //! which respects `.gitignore`, `.ignore`, and `.git/info/exclude` by default.
+//! Each file runs under [`crate::process::isolate_panics`], so a panic while
+//! transforming one file fails that file only.
 
 use globset::GlobBuilder;
 use ignore::WalkBuilder;
 use rayon::prelude::*;
 use std::io::{self, BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 
 use rskim_core::Language;
 
-use crate::process::{ProcessOptions, process_file, report_token_stats};
+use crate::grouping::{Group, GroupBy};
+use crate::output::claude_xml::{Document, render_documents};
+use crate::output::footer;
+use crate::output::template::{self, Template};
+use crate::process::{
+    ProcessOptions, ProcessResult, isolate_panics, process_file, report_estimated_token_stats,
+    report_token_stats,
+};
+use crate::reorder::Reorder;
+use crate::sample::Sampling;
 
 /// Options for multi-file processing
 #[derive(Debug, Clone)]
 pub(crate) struct MultiFileOptions {
     pub(crate) process: ProcessOptions,
     pub(crate) no_header: bool,
+    /// Line written between files in place of headers (`--separator`),
+    /// already unescaped.
+    pub(crate) separator: Option<String>,
     pub(crate) jobs: Option<usize>,
-    pub(crate) no_ignore: bool,
+    pub(crate) ignore: IgnoreRules,
     pub(crate) analytics_enabled: bool,
     pub(crate) session_id: Option<String>,
+    /// Render all results through this template instead of per-file sections
+    /// (`--template`).
+    pub(crate) template: Option<Template>,
+    /// Wrap all results in one `<documents>` bundle (`--format claude-xml`).
+    pub(crate) claude_xml: bool,
+    /// Append an integrity footer after all output (`--footer`).
+    pub(crate) footer: bool,
+    /// List what each `index.ts` barrel exports above directory output
+    /// (`--barrel-exports`).
+    pub(crate) barrel_exports: bool,
+    /// Replace the output of files whose output matches the previous run's
+    /// with a one-line `unchanged` entry (`--changed-only`).
+    pub(crate) changed_only: bool,
+    /// Write each file as soon as it is processed rather than in input order
+    /// (`--stream-unordered`).
+    pub(crate) stream_unordered: bool,
+    /// Fail when any directory argument has no supported files, instead of
+    /// skipping it (`--fail-if-empty`).
+    pub(crate) fail_if_empty: bool,
+    /// Print each file as one JSON record instead of its output
+    /// (`--detailed-json`).
+    pub(crate) detailed_json: bool,
+    /// Keep only a stratified sample of the collected files (`--sample`,
+    /// `--sample-percent`).
+    pub(crate) sample: Option<Sampling>,
+    /// Group directory output by build unit, with a banner above each group
+    /// (`--group-by`).
+    pub(crate) group_by: Option<GroupBy>,
+}
+
+impl MultiFileOptions {
+    /// Whether results are rendered together once every file is processed,
+    /// rather than written file by file.
+    pub(crate) fn is_bundled(&self) -> bool {
+        self.template.is_some() || self.claude_xml
+    }
+}
+
+/// Which ignore rules the directory walker applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IgnoreRules {
+    /// `.gitignore` and `.ignore` files, hidden files, the global gitignore,
+    /// `.git/info/exclude`, and ignore files in parent directories (default).
+    Standard,
+    /// Only `.gitignore`/`.ignore` files and hidden-file rules inside the
+    /// walked tree; nothing above it or in the user's git config (`--hermetic`).
+    InTree,
+    /// No rules: every file, including hidden ones (`--no-ignore`).
+    Off,
+}
+
+/// Source files at least this large are CPU-bound to parse and transform.
+const HEAVY_FILE_BYTES: u64 = 16 * 1024;
+
+/// Maximum number of input files whose metadata is sampled by [`default_jobs`].
+const JOBS_SAMPLE_SIZE: usize = 256;
+
+/// Concurrency cap for IO-bound batches (mostly small files or data formats).
+///
+/// Beyond a handful of threads, small reads contend on the storage device
+/// rather than the CPU; on spinning or networked storage more threads only
+/// add seek and round-trip overhead.
+const IO_BOUND_JOBS: usize = 4;
+
+/// Per-file cost class used by the default `--jobs` heuristic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CostClass {
+    /// Small file or serde-based data format: dominated by IO.
+    Light,
+    /// Tree-sitter source file large enough that parsing dominates.
+    Heavy,
+}
+
+/// Classify a file by language and size.
+fn cost_class(path: &Path, len: u64) -> CostClass {
+    let tree_sitter_source = Language::from_path(path).is_some_and(|lang| !lang.is_serde_based());
+    if tree_sitter_source && len >= HEAVY_FILE_BYTES {
+        CostClass::Heavy
+    } else {
+        CostClass::Light
+    }
+}
+
+/// Pick a concurrency level from the share of heavy files in a sample.
+///
+/// - Mostly heavy (≥ 50%): CPU-bound, use every CPU.
+/// - Mixed (≥ 10%): leave headroom for IO, use three quarters of the CPUs.
+/// - Mostly light: IO-bound, cap at [`IO_BOUND_JOBS`].
+fn jobs_for_sample(heavy: usize, sampled: usize, cpus: usize) -> usize {
+    let cpus = cpus.max(1);
+    if sampled == 0 || heavy * 2 >= sampled {
+        cpus
+    } else if heavy * 10 >= sampled {
+        (cpus * 3 / 4).max(1)
+    } else {
+        cpus.min(IO_BOUND_JOBS)
+    }
+}
+
+/// Default concurrency when `--jobs` is not given.
+///
+/// Samples up to [`JOBS_SAMPLE_SIZE`] evenly spaced inputs (metadata only, no
+/// reads) and sizes the pool by their cost class, never exceeding the number
+/// of files. Full-CPU defaults oversubscribe IO-bound batches of small files.
+pub(crate) fn default_jobs(paths: &[PathBuf]) -> usize {
+    let cpus = std::thread::available_parallelism().map_or(1, usize::from);
+    let step = paths.len().div_ceil(JOBS_SAMPLE_SIZE).max(1);
+
+    let (heavy, sampled) = paths
+        .iter()
+        .step_by(step)
+        .filter_map(|path| Some(cost_class(path, std::fs::metadata(path).ok()?.len())))
+        .fold((0, 0), |(heavy, sampled), class| {
+            (heavy + usize::from(class == CostClass::Heavy), sampled + 1)
+        });
+
+    let jobs = jobs_for_sample(heavy, sampled, cpus)
+        .min(paths.len())
+        .max(1);
+    crate::debug_log!(
+        "[skim] jobs: {jobs} (sampled {sampled} file(s), {heavy} heavy, {cpus} CPU(s))"
+    );
+    jobs
 }
 
 /// Glob metacharacters recognised by skim.
@@ -71,18 +209,17 @@ fn validate_glob_pattern(pattern: &str) -> anyhow::Result<()> {
 
 /// Configure an `ignore::WalkBuilder` with gitignore/hidden-file settings.
 ///
-/// When `no_ignore` is false (default), the walker respects `.gitignore`,
-/// global gitignore, `.git/info/exclude`, `.ignore` files, and skips hidden
-/// files/directories. When true, all ignore rules are disabled.
-fn configure_walker(builder: &mut WalkBuilder, no_ignore: bool) {
-    let respect_ignore = !no_ignore;
+/// See [`IgnoreRules`] for which files each setting reads.
+pub(crate) fn configure_walker(builder: &mut WalkBuilder, rules: IgnoreRules) {
+    let respect_ignore = rules != IgnoreRules::Off;
+    let outside_tree = rules == IgnoreRules::Standard;
     builder
         .hidden(respect_ignore)
         .git_ignore(respect_ignore)
-        .git_global(respect_ignore)
-        .git_exclude(respect_ignore)
+        .git_global(outside_tree)
+        .git_exclude(outside_tree)
         .ignore(respect_ignore)
-        .parents(respect_ignore)
+        .parents(outside_tree)
         .require_git(false)
         .follow_links(false)
         .sort_by_file_path(|a, b| a.cmp(b));
@@ -166,9 +303,170 @@ fn glob_walk_root(pattern: &str) -> (&str, &str) {
     }
 }
 
+/// How per-file outputs are joined into one stream.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SectionLayout<'a> {
+    /// Print a `// <path>` header above each file.
+    headers: bool,
+    /// Line printed between files instead of a header (`--separator`).
+    separator: Option<&'a str>,
+    /// Separate files with a blank line even without headers.
+    blank_between: bool,
+}
+
+impl<'a> SectionLayout<'a> {
+    /// Layout for `file_count` files under `options`.
+    ///
+    /// Headers appear for more than one file unless `--no-header` is set.
+    /// Markdown sections carry their own `## <path>` heading, so they get
+    /// only the blank-line separator. Symbol listings name the file on every
+    /// line, so they get neither. A `--separator` line replaces both.
+    pub(crate) fn new(options: &'a MultiFileOptions, file_count: usize) -> Self {
+        let markdown = options.process.markdown;
+        let tags = options.process.tag_format.is_some();
+        Self {
+            headers: !options.no_header && !markdown && !tags && file_count > 1,
+            separator: options.separator.as_deref(),
+            blank_between: markdown,
+        }
+    }
+
+    /// Write the output of the `idx`-th file.
+    pub(crate) fn write(
+        self,
+        writer: &mut impl Write,
+        idx: usize,
+        path: &Path,
+        output: &str,
+    ) -> io::Result<()> {
+        if self.headers {
+            if idx > 0 {
+                writeln!(writer)?;
+            }
+            writeln!(writer, "// {}", path.display())?;
+        } else {
+            self.write_separator(writer, idx)?;
+        }
+        write!(writer, "{output}")
+    }
+
+    /// Write the banner that opens a group (`--group-by`) before the
+    /// `idx`-th file, followed by the group's summary lines.
+    ///
+    /// Files are then written with their position inside the group, so the
+    /// first one follows the banner without a separator of its own.
+    pub(crate) fn write_group(
+        self,
+        writer: &mut impl Write,
+        idx: usize,
+        banner: &str,
+        details: &[String],
+    ) -> io::Result<()> {
+        if self.headers {
+            if idx > 0 {
+                writeln!(writer)?;
+            }
+        } else {
+            self.write_separator(writer, idx)?;
+        }
+        writeln!(writer, "// {banner}")?;
+        for detail in details {
+            writeln!(writer, "// {detail}")?;
+        }
+        if self.headers {
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Write the one-line entry standing in for the `idx`-th file when its
+    /// output matches the previous run (`--changed-only`).
+    ///
+    /// The line names the file even when headers are off.
+    pub(crate) fn write_unchanged(
+        self,
+        writer: &mut impl Write,
+        idx: usize,
+        path: &Path,
+    ) -> io::Result<()> {
+        if self.headers {
+            if idx > 0 {
+                writeln!(writer)?;
+            }
+        } else {
+            self.write_separator(writer, idx)?;
+        }
+        writeln!(writer, "// {} (unchanged)", path.display())
+    }
+
+    /// Separator written above the `idx`-th file when headers are off.
+    fn write_separator(self, writer: &mut impl Write, idx: usize) -> io::Result<()> {
+        if idx == 0 {
+            return Ok(());
+        }
+        match self.separator {
+            Some(separator) => writeln!(writer, "{separator}"),
+            None if self.blank_between => writeln!(writer),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Interpret the escapes `\f`, `\n`, `\t`, `\0` and `\\` in a `--separator`
+/// argument, so control characters can be passed from any shell.
+///
+/// Any other backslash is kept literally.
+pub(crate) fn unescape_separator(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('f') => out.push('\x0c'),
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('0') => out.push('\0'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Render the results of a bundled run (`--template` or `--format claude-xml`)
+/// in one piece, in output order.
+pub(crate) fn render_bundle(
+    options: &MultiFileOptions,
+    bundle: &[(&Path, &ProcessResult)],
+) -> anyhow::Result<String> {
+    if let Some(template) = &options.template {
+        let files = bundle
+            .iter()
+            .map(|(path, result)| template.file_entry(path, result))
+            .collect();
+        return template.render(&template::context(options.process.mode, files));
+    }
+    let docs: Vec<Document<'_>> = bundle
+        .iter()
+        .map(|(path, result)| Document {
+            path: path.display().to_string(),
+            language: result.language,
+            content: &result.output,
+        })
+        .collect();
+    Ok(render_documents(&docs))
+}
+
 /// Format a hint about `--no-ignore` when gitignore filtering is active.
-fn no_ignore_hint(no_ignore: bool) -> &'static str {
-    if no_ignore {
+fn no_ignore_hint(rules: IgnoreRules) -> &'static str {
+    if rules == IgnoreRules::Off {
         ""
     } else {
         "\nHint: Files may be excluded by .gitignore. Use --no-ignore to include all files."
@@ -180,81 +478,144 @@ fn no_ignore_hint(no_ignore: bool) -> &'static str {
 /// Used by glob, directory, and explicit multi-file inputs. Handles parallel
 /// execution, error aggregation, and accumulated token statistics.
 ///
+/// Files are written in input order once all are processed, or as each one
+/// finishes with `--stream-unordered`.
+///
+/// `preamble` is written before the first file section. `groups` splits
+/// `paths`, in order, into runs that each get a banner; it is empty when
+/// output is not grouped. `empty_dirs` lists directory arguments skipped for
+/// having no supported files; they are reported with the run summary.
+///
 /// Precondition: `paths` must be non-empty. Callers should validate and
 /// produce a descriptive error (with `--no-ignore` hint) before calling.
-fn process_files(paths: Vec<PathBuf>, options: MultiFileOptions) -> anyhow::Result<()> {
+fn process_files(
+    paths: Vec<PathBuf>,
+    groups: Vec<Group>,
+    preamble: Option<String>,
+    empty_dirs: &[PathBuf],
+    options: MultiFileOptions,
+) -> anyhow::Result<()> {
     debug_assert!(
         !paths.is_empty(),
         "BUG: process_files called with empty paths"
     );
-    let process_options = options.process;
 
-    let results: Vec<_> = if let Some(num_jobs) = options.jobs {
-        rayon::ThreadPoolBuilder::new()
-            .num_threads(num_jobs)
-            .build()?
-            .install(|| {
-                paths
-                    .par_iter()
-                    .map(|path| (path, process_file(path, process_options)))
-                    .collect()
-            })
-    } else {
-        paths
-            .par_iter()
-            .map(|path| (path, process_file(path, process_options)))
-            .collect()
-    };
+    // `--jobs` wins; otherwise size the pool to the workload (see `default_jobs`).
+    let num_jobs = options.jobs.unwrap_or_else(|| default_jobs(&paths));
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_jobs)
+        .build()?;
 
     let stdout = io::stdout();
     let mut writer = BufWriter::new(stdout.lock());
+    // The footer checksums everything above it, so buffer the output first.
+    let mut footer_buf = options.footer.then(Vec::new);
+    let mut out: &mut dyn Write = match &mut footer_buf {
+        Some(buf) => buf,
+        None => &mut writer,
+    };
 
-    let mut success_count = 0;
-    let mut error_count = 0;
-    let mut guardrail_count = 0usize;
-    let mut total_original_tokens = 0usize;
-    let mut total_transformed_tokens = 0usize;
+    let mut totals = RunTotals::default();
+    let layout = SectionLayout::new(&options, paths.len());
 
-    let show_headers = !options.no_header && paths.len() > 1;
+    if let Some(preamble) = &preamble {
+        out.write_all(preamble.as_bytes())?;
+    }
 
-    for (idx, (path, result)) in results.iter().enumerate() {
-        match result {
-            Ok(process_result) => {
-                if show_headers {
-                    if idx > 0 {
-                        writeln!(writer)?;
-                    }
-                    writeln!(writer, "// {}", path.display())?;
+    // Analytics rows are built on the workers; outputs are dropped once written.
+    let mut rows = Vec::new();
+    if options.stream_unordered {
+        // Neither bundles nor groups are allowed here (see `--stream-unordered`
+        // validation), so each file is written on its own as it arrives.
+        process_unordered(&pool, &paths, &options, |idx, outcome| {
+            let Outcome {
+                path,
+                result,
+                unchanged,
+                analytics,
+            } = outcome;
+            if let Ok(process_result) = &result {
+                if options.detailed_json {
+                    write_detailed_json(&mut out, path, &options, process_result)?;
+                } else if unchanged {
+                    layout.write_unchanged(&mut out, idx, path)?;
+                } else {
+                    layout.write(&mut out, idx, path, &process_result.output)?;
                 }
-
-                write!(writer, "{}", process_result.output)?;
-                success_count += 1;
-
-                if process_result.guardrail_triggered {
-                    guardrail_count += 1;
+                out.flush()?;
+            }
+            totals.record(path, &result);
+            rows.extend(analytics);
+            Ok(())
+        })?;
+    } else {
+        let mut bundle = Vec::new();
+        let mut group_start = 0;
+        process_ordered(
+            &pool,
+            &paths,
+            &options,
+            &groups,
+            &mut out,
+            |out, idx, group, outcome| {
+                if let Some((group, banner)) = group {
+                    layout.write_group(out, idx, &banner, &group.details)?;
+                    group_start = idx;
                 }
-
-                if let (Some(orig), Some(trans)) = (
-                    process_result.original_tokens,
-                    process_result.transformed_tokens,
-                ) {
-                    total_original_tokens += orig;
-                    total_transformed_tokens += trans;
+                // Position inside the current group; the plain index when ungrouped.
+                let position = idx - group_start;
+
+                let Outcome {
+                    path,
+                    result,
+                    unchanged,
+                    analytics,
+                } = outcome;
+                totals.record(path, &result);
+                rows.extend(analytics);
+                let Ok(process_result) = result else {
+                    return Ok(());
+                };
+                if options.is_bundled() {
+                    bundle.push((path.as_path(), process_result));
+                } else if options.detailed_json {
+                    write_detailed_json(out, path, &options, &process_result)?;
+                } else if unchanged {
+                    layout.write_unchanged(out, position, path)?;
+                } else {
+                    layout.write(out, position, path, &process_result.output)?;
                 }
-            }
-            Err(e) => {
-                eprintln!("Error processing {}: {}", path.display(), e);
-                error_count += 1;
-            }
+                Ok(())
+            },
+        )?;
+        if options.is_bundled() && totals.success_count > 0 {
+            let bundle: Vec<(&Path, &ProcessResult)> = bundle
+                .iter()
+                .map(|(path, result)| (*path, result))
+                .collect();
+            out.write_all(render_bundle(&options, &bundle)?.as_bytes())?;
         }
     }
-
-    writer.flush()?;
+    let RunTotals {
+        success_count,
+        error_count,
+        guardrail_count,
+        original_tokens: total_original_tokens,
+        transformed_tokens: total_transformed_tokens,
+        any_estimated,
+    } = totals;
 
     if success_count == 0 {
         anyhow::bail!("All {} file(s) failed to process", error_count);
     }
 
+    if let Some(buf) = footer_buf {
+        let content = String::from_utf8_lossy(&buf);
+        writer.write_all(&buf)?;
+        writer.write_all(footer::render(&content, success_count).as_bytes())?;
+    }
+    writer.flush()?;
+
     if error_count > 0 {
         eprintln!(
             "\nProcessed {} file(s) successfully, {} failed",
@@ -262,6 +623,24 @@ fn process_files(paths: Vec<PathBuf>, options: MultiFileOptions) -> anyhow::Resu
         );
     }
 
+    if !empty_dirs.is_empty() {
+        let names: Vec<String> = empty_dirs
+            .iter()
+            .map(|dir| format!("'{}'", dir.display()))
+            .collect();
+        eprintln!(
+            "Skipped {} {} with no supported files: {}{}",
+            empty_dirs.len(),
+            if empty_dirs.len() == 1 {
+                "directory"
+            } else {
+                "directories"
+            },
+            names.join(", "),
+            no_ignore_hint(options.ignore)
+        );
+    }
+
     if guardrail_count > 0 {
         let total = success_count + error_count;
         eprintln!(
@@ -270,9 +649,14 @@ fn process_files(paths: Vec<PathBuf>, options: MultiFileOptions) -> anyhow::Resu
         );
     }
 
-    if options.process.show_stats && total_original_tokens > 0 {
+    if options.process.show_stats && !options.detailed_json && total_original_tokens > 0 {
         let suffix = format!(" across {} file(s)", success_count);
-        report_token_stats(
+        let report = if any_estimated {
+            report_estimated_token_stats
+        } else {
+            report_token_stats
+        };
+        report(
             Some(total_original_tokens),
             Some(total_transformed_tokens),
             &suffix,
@@ -282,8 +666,7 @@ fn process_files(paths: Vec<PathBuf>, options: MultiFileOptions) -> anyhow::Resu
     // Record per-file analytics rows (Phase A2 — fixes PF-001 for multi/glob/dir).
     //
     // Capture project_path and common fields on the MAIN thread before any
-    // background spawn. Consume `results` by value so output Strings MOVE into
-    // the rows without cloning.
+    // background spawn. Rows were built by the workers (see `analytics_row`).
     //
     // Behaviour change (intentional): N per-file rows are emitted for BOTH plain
     // and --show-stats, replacing the single aggregate row.  Err entries (files
@@ -295,38 +678,6 @@ fn process_files(paths: Vec<PathBuf>, options: MultiFileOptions) -> anyhow::Resu
             .to_string();
         let mode = format!("{:?}", options.process.mode).to_lowercase();
 
-        let rows: Vec<crate::analytics::FileOpRow> = results
-            .into_iter()
-            .filter_map(|(path, result)| {
-                let pr = result.ok()?; // skip Err entries
-                let counts = match (pr.original_tokens, pr.transformed_tokens) {
-                    (Some(raw), Some(comp)) => {
-                        // --show-stats (or count-carrying cache hit): counts already known.
-                        crate::analytics::FileCounts::Known {
-                            raw,
-                            compressed: comp,
-                        }
-                    }
-                    _ => {
-                        // Plain run / cold cache: tokenize off the main thread.
-                        // F14: if the file is deleted/changed between here and the
-                        // background re-read, read_source returns Err → row silently
-                        // skipped; sibling rows still recorded.
-                        crate::analytics::FileCounts::Tokenize {
-                            raw: crate::analytics::RawSource::Reread(path.clone()),
-                            compressed: pr.output,
-                        }
-                    }
-                };
-                Some(crate::analytics::FileOpRow {
-                    counts,
-                    original_cmd: format!("skim {}", path.display()),
-                    language: pr.language.map(|l| l.as_str().to_string()),
-                    parse_tier: pr.parse_tier.map(str::to_string),
-                })
-            })
-            .collect();
-
         let common = crate::analytics::FileOpCommon {
             mode: Some(mode),
             project_path: cwd,
@@ -339,6 +690,284 @@ fn process_files(paths: Vec<PathBuf>, options: MultiFileOptions) -> anyhow::Resu
     Ok(())
 }
 
+/// Write the `--detailed-json` record of one file; records need no headers
+/// or separators.
+fn write_detailed_json(
+    out: &mut impl Write,
+    path: &Path,
+    options: &MultiFileOptions,
+    result: &ProcessResult,
+) -> io::Result<()> {
+    let record = crate::output::detailed_json::render(path, options.process.mode, result);
+    out.write_all(record.as_bytes())
+}
+
+/// A processed file of a multi-file run.
+struct Outcome<'a> {
+    path: &'a PathBuf,
+    result: anyhow::Result<ProcessResult>,
+    /// Whether the output matches the previous `--changed-only` run.
+    unchanged: bool,
+    /// The file's analytics row, when analytics are on and it succeeded.
+    analytics: Option<crate::analytics::FileOpRow>,
+}
+
+/// Process one file of a multi-file run.
+fn process_one<'a>(path: &'a PathBuf, options: &MultiFileOptions) -> Outcome<'a> {
+    let result = isolate_panics(|| process_file(path, options.process));
+    let unchanged = options.changed_only
+        && result
+            .as_ref()
+            .is_ok_and(|result| output_unchanged(path, &options.process, result));
+    let analytics = match &result {
+        Ok(result) if options.analytics_enabled => Some(analytics_row(path, result)),
+        _ => None,
+    };
+    Outcome {
+        path,
+        result,
+        unchanged,
+        analytics,
+    }
+}
+
+/// The analytics row for one processed file.
+fn analytics_row(path: &Path, result: &ProcessResult) -> crate::analytics::FileOpRow {
+    let counts = match (result.original_tokens, result.transformed_tokens) {
+        (Some(raw), Some(compressed)) if !result.tokens_estimated => {
+            // --show-stats (or count-carrying cache hit): counts already known.
+            crate::analytics::FileCounts::Known { raw, compressed }
+        }
+        _ => {
+            // Plain run / cold cache: the output is not kept once written, so
+            // count it here; the source is re-read and tokenized off the main
+            // thread. F14: if the file is deleted/changed before the background
+            // re-read, read_source returns Err → row silently skipped; sibling
+            // rows still recorded.
+            crate::analytics::FileCounts::TokenizeRaw {
+                raw: crate::analytics::RawSource::Reread(path.to_path_buf()),
+                compressed: crate::analytics::count_tokens_bounded(&result.output),
+            }
+        }
+    };
+    crate::analytics::FileOpRow {
+        counts,
+        original_cmd: format!("skim {}", path.display()),
+        language: result.language.map(|l| l.as_str().to_string()),
+        parse_tier: result.parse_tier.map(str::to_string),
+    }
+}
+
+/// Counters accumulated over the files of a multi-file run.
+#[derive(Debug, Default)]
+struct RunTotals {
+    success_count: usize,
+    error_count: usize,
+    guardrail_count: usize,
+    original_tokens: usize,
+    transformed_tokens: usize,
+    any_estimated: bool,
+}
+
+impl RunTotals {
+    /// Count one file, reporting its error on stderr if it failed.
+    fn record(&mut self, path: &Path, result: &anyhow::Result<ProcessResult>) {
+        match result {
+            Ok(process_result) => {
+                self.success_count += 1;
+                if process_result.guardrail_triggered {
+                    self.guardrail_count += 1;
+                }
+                if let (Some(orig), Some(trans)) = (
+                    process_result.original_tokens,
+                    process_result.transformed_tokens,
+                ) {
+                    self.original_tokens += orig;
+                    self.transformed_tokens += trans;
+                    self.any_estimated |= process_result.tokens_estimated;
+                }
+            }
+            Err(e) => {
+                eprintln!("Error processing {}: {}", path.display(), e);
+                self.error_count += 1;
+            }
+        }
+    }
+}
+
+/// Process `paths` on `pool`, handing the receiving end of a bounded queue of
+/// `(index, outcome)` pairs, in completion order, to `receive` on the calling
+/// thread.
+///
+/// Files are started roughly in input order. Workers block while the queue
+/// is full, so a consumer that falls behind (a pager, a network pipe) holds
+/// processing back instead of letting outcomes pile up in memory. Once
+/// `receive` returns, files not yet started are skipped.
+fn with_workers<'a, R>(
+    pool: &rayon::ThreadPool,
+    paths: &'a [PathBuf],
+    options: &MultiFileOptions,
+    receive: impl FnOnce(mpsc::Receiver<(usize, Outcome<'a>)>) -> R,
+) -> R {
+    let (tx, rx) = mpsc::sync_channel(pool.current_num_threads() * 2);
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            // A send fails only once the receiver is gone; stop taking files.
+            let _ = pool.install(|| {
+                paths
+                    .iter()
+                    .enumerate()
+                    .par_bridge()
+                    .try_for_each_with(tx, |tx, (idx, path)| {
+                        tx.send((idx, process_one(path, options))).map_err(drop)
+                    })
+            });
+        });
+        receive(rx)
+    })
+}
+
+/// Process `paths` on `pool`, handing each outcome to `emit` on the calling
+/// thread as soon as its file finishes (`--stream-unordered`).
+///
+/// `emit` receives outcomes in completion order, numbered from 0. When
+/// `emit` fails, files not yet started are skipped and the error is returned
+/// once the pool has stopped.
+fn process_unordered<'a>(
+    pool: &rayon::ThreadPool,
+    paths: &'a [PathBuf],
+    options: &MultiFileOptions,
+    mut emit: impl FnMut(usize, Outcome<'a>) -> io::Result<()>,
+) -> io::Result<()> {
+    with_workers(pool, paths, options, |rx| {
+        for (idx, (_, outcome)) in rx.into_iter().enumerate() {
+            emit(idx, outcome)?;
+        }
+        Ok(())
+    })
+}
+
+/// Pending output held in memory while ordered output waits on an earlier
+/// file; beyond it, outputs are spilled to a temporary file (see
+/// [`Reorder`]).
+const PENDING_OUTPUT_BUDGET: usize = 64 * 1024 * 1024;
+
+/// Process `paths` on `pool`, handing each outcome to `emit` on the calling
+/// thread in input order, as soon as it and every file before it are done.
+///
+/// `emit` writes to `out`, which is flushed whenever delivery waits on a
+/// file. At the first file of each of `groups`, `emit` also gets the group
+/// and its banner, once every member is done. Bundled runs render from all
+/// outputs at the end anyway, so they are never spilled.
+fn process_ordered<'a, W: Write>(
+    pool: &rayon::ThreadPool,
+    paths: &'a [PathBuf],
+    options: &MultiFileOptions,
+    groups: &[Group],
+    out: &mut W,
+    mut emit: impl FnMut(&mut W, usize, Option<(&Group, String)>, Outcome<'a>) -> io::Result<()>,
+) -> io::Result<()> {
+    let budget = if options.is_bundled() {
+        usize::MAX
+    } else {
+        PENDING_OUTPUT_BUDGET
+    };
+    let mut pending = Reorder::new(budget);
+    let mut groups = groups.iter().peekable();
+    let mut next_group_at = 0;
+    let mut delivered = 0;
+    with_workers(pool, paths, options, |rx| {
+        loop {
+            let (idx, mut outcome) = match rx.try_recv() {
+                Ok(received) => received,
+                Err(mpsc::TryRecvError::Empty) => {
+                    // About to wait: let the consumer see what is written.
+                    out.flush()?;
+                    match rx.recv() {
+                        Ok(received) => received,
+                        Err(mpsc::RecvError) => break,
+                    }
+                }
+                Err(mpsc::TryRecvError::Disconnected) => break,
+            };
+            let output = match &mut outcome.result {
+                Ok(result) => std::mem::take(&mut result.output),
+                Err(_) => String::new(),
+            };
+            pending.insert(idx, outcome, output)?;
+
+            loop {
+                let ready = pending.ready();
+                let mut group = None;
+                if delivered == next_group_at
+                    && let Some(&next) = groups.peek()
+                {
+                    if ready < next.len {
+                        break;
+                    }
+                    group = Some((next, group_banner(next, pending.peek(next.len))));
+                    next_group_at += next.len;
+                    groups.next();
+                }
+                let Some((mut outcome, output)) = pending.pop()? else {
+                    break;
+                };
+                if let Ok(result) = &mut outcome.result {
+                    result.output = output;
+                }
+                emit(out, delivered, group, outcome)?;
+                delivered += 1;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Banner line for `group`: its label, file count and, when token counts
+/// are known (`--show-stats`), the group's token totals.
+fn group_banner<'o, 'p: 'o>(
+    group: &Group,
+    members: impl Iterator<Item = &'o Outcome<'p>>,
+) -> String {
+    let mut banner = format!("{}: {} file(s)", group.label, group.len);
+    let mut original = 0;
+    let mut transformed = 0;
+    let mut estimated = false;
+    for Outcome { result, .. } in members {
+        if let Ok(result) = result
+            && let (Some(orig), Some(trans)) = (result.original_tokens, result.transformed_tokens)
+        {
+            original += orig;
+            transformed += trans;
+            estimated |= result.tokens_estimated;
+        }
+    }
+    if original > 0 {
+        let stats = if estimated {
+            crate::tokens::TokenStats::estimated(original, transformed)
+        } else {
+            crate::tokens::TokenStats::new(original, transformed)
+        };
+        banner.push_str(", ");
+        banner.push_str(&stats.format());
+    }
+    banner
+}
+
+/// Whether `result` matches the output of `path` from the previous
+/// `--changed-only` run; records it for the next run either way.
+fn output_unchanged(path: &Path, options: &ProcessOptions, result: &ProcessResult) -> bool {
+    crate::cache::output_unchanged_since_last_run(
+        path,
+        options.cache_keys,
+        options.mode,
+        &options.trunc,
+        options.line_numbers,
+        &options.flags,
+        &result.output,
+    )
+}
+
 /// Process a list of explicitly specified file arguments.
 ///
 /// Each argument may be:
@@ -358,27 +987,29 @@ pub(crate) fn process_explicit_files(
         "BUG: process_explicit_files called with empty args"
     );
 
-    let no_ignore = options.no_ignore;
+    let ignore = options.ignore;
     let mut paths: Vec<PathBuf> = Vec::new();
     let mut errors: Vec<String> = Vec::new();
+    let mut empty_dirs: Vec<PathBuf> = Vec::new();
 
     for arg in args {
         if has_glob_pattern(arg) {
             // Glob expansion — validate then walk
-            match expand_glob_to_paths(arg, no_ignore) {
+            match expand_glob_to_paths(arg, ignore) {
                 Ok(mut matched) => paths.append(&mut matched),
                 Err(e) => errors.push(format!("{arg}: {e}")),
             }
         } else {
             let path = PathBuf::from(arg);
             if path.is_dir() {
-                let mut dir_files = collect_files_from_directory(&path, no_ignore);
+                let mut dir_files = collect_files_from_directory(&path, ignore);
                 if dir_files.is_empty() {
-                    errors.push(format!(
-                        "No files found in directory '{}'{}",
-                        path.display(),
-                        no_ignore_hint(no_ignore)
-                    ));
+                    if options.fail_if_empty {
+                        anyhow::bail!("{}", empty_dir_message(&path, ignore));
+                    }
+                    // Skipped and listed with the summary, unless nothing
+                    // else is left to process.
+                    empty_dirs.push(path);
                 } else {
                     paths.append(&mut dir_files);
                 }
@@ -390,15 +1021,14 @@ pub(crate) fn process_explicit_files(
         }
     }
 
-    if !errors.is_empty() && paths.is_empty() {
+    if paths.is_empty() && (!errors.is_empty() || !empty_dirs.is_empty()) {
         // All arguments failed — report the first error as the primary message,
         // then list the rest.
-        let mut msg = errors.remove(0);
-        for e in errors {
-            msg.push('\n');
-            msg.push_str(&e);
-        }
-        anyhow::bail!("{}", msg);
+        let messages: Vec<String> = errors
+            .into_iter()
+            .chain(empty_dirs.iter().map(|dir| empty_dir_message(dir, ignore)))
+            .collect();
+        anyhow::bail!("{}", messages.join("\n"));
     }
 
     // Partial failures: warn on stderr but continue with resolved paths.
@@ -409,7 +1039,7 @@ pub(crate) fn process_explicit_files(
     if paths.is_empty() {
         anyhow::bail!(
             "No files found for the given arguments{}",
-            no_ignore_hint(no_ignore)
+            no_ignore_hint(ignore)
         );
     }
 
@@ -418,7 +1048,17 @@ pub(crate) fn process_explicit_files(
     paths.sort();
     paths.dedup();
 
-    process_files(paths, options)
+    let paths = apply_sample(paths, &options);
+    process_files(paths, Vec::new(), None, &empty_dirs, options)
+}
+
+/// Error for a directory argument without supported files.
+fn empty_dir_message(dir: &Path, ignore: IgnoreRules) -> String {
+    format!(
+        "No files found in directory '{}'{}",
+        dir.display(),
+        no_ignore_hint(ignore)
+    )
 }
 
 /// Expand a glob pattern to a list of matching paths.
@@ -426,7 +1066,10 @@ pub(crate) fn process_explicit_files(
 /// Separated from [`process_glob`] so it can be used within
 /// [`process_explicit_files`] without going through the full single-glob
 /// pipeline (which calls `process_files` directly).
-fn expand_glob_to_paths(pattern: &str, no_ignore: bool) -> anyhow::Result<Vec<PathBuf>> {
+pub(crate) fn expand_glob_to_paths(
+    pattern: &str,
+    ignore: IgnoreRules,
+) -> anyhow::Result<Vec<PathBuf>> {
     validate_glob_pattern(pattern)?;
 
     let (walk_root, glob_pattern) = glob_walk_root(pattern);
@@ -438,7 +1081,7 @@ fn expand_glob_to_paths(pattern: &str, no_ignore: bool) -> anyhow::Result<Vec<Pa
     let matcher = glob.compile_matcher();
 
     let mut builder = WalkBuilder::new(walk_root);
-    configure_walker(&mut builder, no_ignore);
+    configure_walker(&mut builder, ignore);
 
     let paths: Vec<PathBuf> = builder
         .build()
@@ -458,7 +1101,7 @@ fn expand_glob_to_paths(pattern: &str, no_ignore: bool) -> anyhow::Result<Vec<Pa
         anyhow::bail!(
             "No files found: pattern '{}'{}",
             pattern,
-            no_ignore_hint(no_ignore)
+            no_ignore_hint(ignore)
         );
     }
 
@@ -473,8 +1116,9 @@ fn expand_glob_to_paths(pattern: &str, no_ignore: bool) -> anyhow::Result<Vec<Pa
 /// gitignore rules are applied *before* glob matching, so gitignored files
 /// are excluded even when the glob would otherwise match them.
 pub(crate) fn process_glob(pattern: &str, options: MultiFileOptions) -> anyhow::Result<()> {
-    let paths = expand_glob_to_paths(pattern, options.no_ignore)?;
-    process_files(paths, options)
+    let paths = expand_glob_to_paths(pattern, options.ignore)?;
+    let paths = apply_sample(paths, &options);
+    process_files(paths, Vec::new(), None, &[], options)
 }
 
 /// Collect all supported files from a directory recursively.
@@ -487,9 +1131,9 @@ pub(crate) fn process_glob(pattern: &str, options: MultiFileOptions) -> anyhow::
 /// intentionally dropped via `filter_map(|e| e.ok())`. A single
 /// unreadable file should not abort traversal of an entire directory
 /// tree -- this matches ripgrep/fd behavior.
-fn collect_files_from_directory(dir: &Path, no_ignore: bool) -> Vec<PathBuf> {
+fn collect_files_from_directory(dir: &Path, ignore: IgnoreRules) -> Vec<PathBuf> {
     let mut builder = WalkBuilder::new(dir);
-    configure_walker(&mut builder, no_ignore);
+    configure_walker(&mut builder, ignore);
 
     builder
         .build()
@@ -500,25 +1144,85 @@ fn collect_files_from_directory(dir: &Path, no_ignore: bool) -> Vec<PathBuf> {
         .collect()
 }
 
+/// Reduce `paths` to the `--sample` / `--sample-percent` sample, reporting
+/// its size and seed on stderr so the run can be repeated.
+fn apply_sample(paths: Vec<PathBuf>, options: &MultiFileOptions) -> Vec<PathBuf> {
+    let Some(sampling) = &options.sample else {
+        return paths;
+    };
+    let total = paths.len();
+    let paths = crate::sample::sample(paths, sampling);
+    eprintln!(
+        "Sampled {} of {total} files (seed {}; repeat with --sample-seed {})",
+        paths.len(),
+        sampling.seed,
+        sampling.seed
+    );
+    paths
+}
+
 /// Process all supported files in a directory recursively
 pub(crate) fn process_directory(dir: &Path, options: MultiFileOptions) -> anyhow::Result<()> {
-    let paths = collect_files_from_directory(dir, options.no_ignore);
+    let paths = collect_files_from_directory(dir, options.ignore);
 
     if paths.is_empty() {
         anyhow::bail!(
             "No files found: directory '{}'{}",
             dir.display(),
-            no_ignore_hint(options.no_ignore)
+            no_ignore_hint(options.ignore)
         );
     }
 
-    process_files(paths, options)
+    let paths = apply_sample(paths, &options);
+    let (paths, groups) = match options.group_by {
+        Some(kind) => crate::grouping::group_paths(kind, dir, paths)?,
+        None => (paths, Vec::new()),
+    };
+    let preamble = options
+        .barrel_exports
+        .then(|| crate::barrel::render_folder_exports(&paths))
+        .flatten();
+    process_files(paths, groups, preamble, &[], options)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cost_class() {
+        assert_eq!(cost_class(Path::new("a.rs"), 64 * 1024), CostClass::Heavy);
+        assert_eq!(cost_class(Path::new("a.rs"), 512), CostClass::Light);
+        // Data formats are IO-bound regardless of size
+        assert_eq!(cost_class(Path::new("a.json"), 1 << 20), CostClass::Light);
+        assert_eq!(
+            cost_class(Path::new("a.unknown"), 1 << 20),
+            CostClass::Light
+        );
+    }
+
+    #[test]
+    fn test_jobs_for_sample() {
+        // CPU-bound: all CPUs
+        assert_eq!(jobs_for_sample(60, 100, 16), 16);
+        // Mixed: three quarters
+        assert_eq!(jobs_for_sample(20, 100, 16), 12);
+        // IO-bound: capped
+        assert_eq!(jobs_for_sample(1, 100, 16), IO_BOUND_JOBS);
+        assert_eq!(jobs_for_sample(0, 100, 2), 2);
+        // Nothing sampled (all metadata failed): fall back to all CPUs
+        assert_eq!(jobs_for_sample(0, 0, 8), 8);
+        assert_eq!(jobs_for_sample(0, 0, 0), 1);
+    }
+
+    #[test]
+    fn test_default_jobs_never_exceeds_file_count() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("a.ts");
+        std::fs::write(&path, "export const a = 1;\n").unwrap();
+        assert_eq!(default_jobs(&[path]), 1);
+    }
+
     #[test]
     fn test_has_glob_pattern() {
         assert!(has_glob_pattern("*.ts"));
@@ -668,4 +1372,18 @@ mod tests {
         // "/src/*.rs" -> ("/src", "*.rs")
         assert_eq!(glob_walk_root("/src/*.rs"), ("/src", "*.rs"));
     }
+
+    // ========================================================================
+    // unescape_separator
+    // ========================================================================
+
+    #[test]
+    fn test_unescape_separator() {
+        assert_eq!(unescape_separator(r"\f"), "\x0c");
+        assert_eq!(unescape_separator(r"--- \t ---"), "--- \t ---");
+        assert_eq!(unescape_separator(r"a\\b"), r"a\b");
+        // Unknown escapes and a trailing backslash stay literal
+        assert_eq!(unescape_separator(r"\x\"), r"\x\");
+        assert_eq!(unescape_separator("===="), "====");
+    }
 }
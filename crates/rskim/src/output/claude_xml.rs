@@ -0,0 +1,137 @@
+//! XML document bundle for `--format claude-xml`.
+//!
+//! Long-context prompts work best when each source is wrapped in its own
+//! tagged document. The bundle opens with an `<index>` of every file, then
+//! wraps each file's transformed output in a `<document>` with the same
+//! 1-based index, so a model can refer back to files by index or path:
+//!
+//! ```text
+//! <documents>
+//! <index>
+//! <file index="1" path="src/lib.rs" language="rust"/>
+//! </index>
+//! <document index="1" path="src/lib.rs">
+//! pub fn parse(input: &str) -> Config { /* ... */ }
+//! </document>
+//! </documents>
+//! ```
+//!
+//! Attribute values are XML-escaped. Document contents are not: models read
+//! them verbatim, and escaping every `<` and `&` in source code costs tokens.
+
+use rskim_core::Language;
+
+/// One file in the bundle.
+pub(crate) struct Document<'a> {
+    /// Path label as shown to the model.
+    pub(crate) path: String,
+    pub(crate) language: Option<Language>,
+    /// Transformed output.
+    pub(crate) content: &'a str,
+}
+
+/// Render `docs` as a `<documents>` bundle with a leading index.
+pub(crate) fn render_documents(docs: &[Document<'_>]) -> String {
+    let content_len: usize = docs.iter().map(|d| d.content.len()).sum();
+    let mut out = String::with_capacity(content_len + docs.len() * 96 + 64);
+
+    out.push_str("<documents>\n<index>\n");
+    for (idx, doc) in docs.iter().enumerate() {
+        out.push_str(&format!(
+            "<file index=\"{}\" path=\"{}\"",
+            idx + 1,
+            escape_attr(&doc.path)
+        ));
+        if let Some(language) = doc.language {
+            out.push_str(&format!(" language=\"{}\"", language.as_str()));
+        }
+        out.push_str("/>\n");
+    }
+    out.push_str("</index>\n");
+
+    for (idx, doc) in docs.iter().enumerate() {
+        out.push_str(&format!(
+            "<document index=\"{}\" path=\"{}\">\n",
+            idx + 1,
+            escape_attr(&doc.path)
+        ));
+        out.push_str(doc.content);
+        if !doc.content.is_empty() && !doc.content.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str("</document>\n");
+    }
+    out.push_str("</documents>\n");
+    out
+}
+
+/// Escape `value` for use inside a double-quoted XML attribute.
+fn escape_attr(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_documents_index_and_bodies() {
+        let docs = [
+            Document {
+                path: "src/a.rs".to_string(),
+                language: Some(Language::Rust),
+                content: "fn a() { /* ... */ }\n",
+            },
+            Document {
+                path: "notes".to_string(),
+                language: None,
+                content: "no trailing newline",
+            },
+        ];
+        assert_eq!(
+            render_documents(&docs),
+            "<documents>\n<index>\n\
+             <file index=\"1\" path=\"src/a.rs\" language=\"rust\"/>\n\
+             <file index=\"2\" path=\"notes\"/>\n\
+             </index>\n\
+             <document index=\"1\" path=\"src/a.rs\">\nfn a() { /* ... */ }\n</document>\n\
+             <document index=\"2\" path=\"notes\">\nno trailing newline\n</document>\n\
+             </documents>\n"
+        );
+    }
+
+    #[test]
+    fn test_paths_are_escaped_but_contents_are_not() {
+        let docs = [Document {
+            path: "a&b/\"q\".ts".to_string(),
+            language: Some(Language::TypeScript),
+            content: "const x = a < b && c;\n",
+        }];
+        let out = render_documents(&docs);
+        assert!(out.contains("path=\"a&amp;b/&quot;q&quot;.ts\""), "{out}");
+        assert!(out.contains("const x = a < b && c;\n"), "{out}");
+    }
+
+    #[test]
+    fn test_empty_document() {
+        let docs = [Document {
+            path: "empty.py".to_string(),
+            language: Some(Language::Python),
+            content: "",
+        }];
+        assert!(
+            render_documents(&docs)
+                .contains("<document index=\"1\" path=\"empty.py\">\n</document>\n")
+        );
+    }
+}
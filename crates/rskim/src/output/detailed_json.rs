@@ -0,0 +1,104 @@
+//! Per-file JSON records for `--detailed-json`.
+//!
+//! Benchmark harnesses and dashboards want the transform's own numbers rather
+//! than wall-clock timing of the whole process. Each file becomes one line of
+//! JSON carrying the fields of the core's `TransformResult` (`content`,
+//! `original_tokens`, `transformed_tokens`, `duration_ms`) plus the path,
+//! language and mode that identify it (schema: `skim --schema detailed-json`):
+//!
+//! ```text
+//! {"schema_version":1,"path":"src/lib.rs","language":"rust","mode":"structure","content":"...","original_tokens":812,"transformed_tokens":143,"tokens_estimated":false,"duration_ms":0.384}
+//! ```
+//!
+//! `duration_ms` is the time spent in the core transform (cascade included),
+//! in fractional milliseconds; it excludes reading the file, the output
+//! guardrail and token counting. Multi-file runs print one line per file.
+
+use std::path::Path;
+
+use rskim_core::Mode;
+use serde_json::json;
+
+use crate::process::ProcessResult;
+use crate::schema::SchemaFormat;
+
+/// Render `result` as one JSON line (with trailing newline).
+///
+/// `path` is the label shown for the input: the file path, or the
+/// `--filename` hint / `-` for stdin.
+pub(crate) fn render(path: &Path, mode: Mode, result: &ProcessResult) -> String {
+    let record = json!({
+        "schema_version": SchemaFormat::DetailedJson.version(),
+        "path": path.display().to_string(),
+        "language": result.language.map(|language| language.as_str()),
+        "mode": mode.name(),
+        "content": result.output,
+        "original_tokens": result.original_tokens,
+        "transformed_tokens": result.transformed_tokens,
+        "tokens_estimated": result.tokens_estimated,
+        "duration_ms": result
+            .transform_duration
+            .map(|duration| duration.as_secs_f64() * 1000.0),
+    });
+    format!("{record}\n")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+    use rskim_core::Language;
+    use std::time::Duration;
+
+    fn result() -> ProcessResult {
+        ProcessResult {
+            output: "fn main() { /* ... */ }\n".to_string(),
+            original_tokens: Some(20),
+            transformed_tokens: Some(8),
+            tokens_estimated: false,
+            guardrail_triggered: false,
+            parse_tier: Some("full"),
+            language: Some(Language::Rust),
+            stdin_raw: None,
+            transform_duration: Some(Duration::from_micros(1_500)),
+        }
+    }
+
+    #[test]
+    fn test_render_is_one_json_line() {
+        let line = render(Path::new("src/main.rs"), Mode::Structure, &result());
+        assert!(line.ends_with('\n'));
+        assert_eq!(
+            line.matches('\n').count(),
+            1,
+            "content newlines must be escaped"
+        );
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["path"], "src/main.rs");
+        assert_eq!(value["language"], "rust");
+        assert_eq!(value["mode"], "structure");
+        assert_eq!(value["content"], "fn main() { /* ... */ }\n");
+        assert_eq!(value["original_tokens"], 20);
+        assert_eq!(value["transformed_tokens"], 8);
+        assert_eq!(value["duration_ms"], 1.5);
+        crate::schema::check(&SchemaFormat::DetailedJson.schema(), &value).unwrap();
+    }
+
+    #[test]
+    fn test_render_unknown_values_are_null() {
+        let result = ProcessResult {
+            original_tokens: None,
+            transformed_tokens: None,
+            language: None,
+            transform_duration: None,
+            ..result()
+        };
+        let value: serde_json::Value =
+            serde_json::from_str(&render(Path::new("-"), Mode::Types, &result)).unwrap();
+        assert!(value["language"].is_null());
+        assert!(value["original_tokens"].is_null());
+        assert!(value["duration_ms"].is_null());
+        crate::schema::check(&SchemaFormat::DetailedJson.schema(), &value).unwrap();
+    }
+}
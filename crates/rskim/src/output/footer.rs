@@ -0,0 +1,70 @@
+//! Integrity footer for `--footer`.
+//!
+//! Bundles pasted into chats or uploaded through lossy channels get truncated
+//! without anyone noticing. The footer is a single last line summarising what
+//! was emitted before it:
+//!
+//! ```text
+//! [skim-footer] files=3 tokens=1204 bytes=4817 sha256=9f86d081884c7d65...
+//! ```
+//!
+//! `bytes` and `sha256` cover every byte of output above the footer line, so
+//! a consumer can drop the last line, hash the rest, and compare. `tokens` is
+//! the cl100k count of the same content (`?` if counting failed).
+
+use sha2::{Digest, Sha256};
+
+use crate::tokens;
+
+/// Prefix that starts the footer line.
+const FOOTER_PREFIX: &str = "[skim-footer]";
+
+/// Render the footer for `content` produced from `files` input files.
+///
+/// The result is appended directly after `content`. If `content` does not end
+/// with a newline, the footer starts with one, and that newline is counted as
+/// part of the covered output.
+pub(crate) fn render(content: &str, files: usize) -> String {
+    let separator = if content.is_empty() || content.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    hasher.update(separator.as_bytes());
+    let tokens = tokens::count_tokens(content)
+        .map(|n| n.to_string())
+        .unwrap_or_else(|_| "?".to_string());
+    format!(
+        "{separator}{FOOTER_PREFIX} files={files} tokens={tokens} bytes={} sha256={:x}\n",
+        content.len() + separator.len(),
+        hasher.finalize()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_footer_reports_counts_and_checksum() {
+        let footer = render("fn a() {}\n", 1);
+        assert!(
+            footer.starts_with("[skim-footer] files=1 tokens="),
+            "{footer}"
+        );
+        assert!(footer.contains(" bytes=10 "), "{footer}");
+        assert!(footer.ends_with('\n'), "{footer}");
+        let hash = footer.trim_end().rsplit_once("sha256=").unwrap().1;
+        assert_eq!(hash, format!("{:x}", Sha256::digest(b"fn a() {}\n")));
+    }
+
+    #[test]
+    fn test_footer_starts_own_line_and_covers_added_newline() {
+        let footer = render("no newline", 1);
+        assert!(footer.starts_with("\n[skim-footer]"), "{footer}");
+        assert!(footer.contains(" bytes=11 "), "{footer}");
+        assert!(render("", 0).starts_with("[skim-footer] files=0"));
+    }
+}
@@ -0,0 +1,185 @@
+//! Markdown bundle rendering for `--format markdown`.
+//!
+//! Each file becomes a `## <path>` section. Its transformed output is split
+//! into one fenced block per top-level symbol, each under a
+//! `### <path>#<symbol>` heading, so chat responses and docs can deep-link
+//! into a bundle and two bundles diff section by section.
+//!
+//! Output lines are assigned to symbols through the source line map: a line
+//! belongs to the top-level symbol whose source range contains it. Lines
+//! outside every symbol (imports, top-level statements) are kept in untitled
+//! blocks in their original position. Anchors repeat the symbol name; a name
+//! seen twice in one file gets a `-2`, `-3`, ... suffix in source order.
+
+use rskim_core::{Language, Symbol};
+
+/// Render one file's transformed `output` as a Markdown section.
+///
+/// `line_map` maps each output line to its 1-indexed source line (`0` for
+/// markers). Without a map, or for files without symbols, the whole output
+/// is a single block under the file heading.
+pub(crate) fn render_markdown(
+    label: &str,
+    language: Option<Language>,
+    source: &str,
+    output: &str,
+    line_map: Option<&[usize]>,
+) -> String {
+    let tag = language.map_or("", Language::as_str);
+    let fence = fence_for(output);
+    let mut out = format!("## {label}\n");
+
+    let symbols: Vec<Symbol> = language
+        .and_then(|lang| rskim_core::symbols(source, lang).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|s| s.depth == 0)
+        .collect();
+    let Some(line_map) = line_map.filter(|_| !symbols.is_empty()) else {
+        push_block(&mut out, &fence, tag, output.lines());
+        return out;
+    };
+
+    let anchors = anchors(&symbols);
+    let owner_of = |source_line: usize| {
+        symbols
+            .iter()
+            .position(|s| (s.start_line..=s.end_line).contains(&source_line))
+    };
+
+    // Group consecutive output lines by owning symbol. Marker lines (source
+    // line 0) stay with the group they appear in.
+    let mut groups: Vec<(Option<usize>, Vec<&str>)> = Vec::new();
+    for (idx, line) in output.lines().enumerate() {
+        let source_line = line_map.get(idx).copied().unwrap_or(0);
+        let owner = match source_line {
+            0 => groups.last().and_then(|(owner, _)| *owner),
+            n => owner_of(n),
+        };
+        match groups.last_mut() {
+            Some((current, lines)) if *current == owner => lines.push(line),
+            _ => groups.push((owner, vec![line])),
+        }
+    }
+
+    let mut last_titled = None;
+    for (owner, lines) in groups {
+        if lines.iter().all(|l| l.trim().is_empty()) {
+            continue;
+        }
+        if let Some(idx) = owner
+            && last_titled != Some(idx)
+        {
+            out.push_str(&format!("\n### {label}#{}\n", anchors[idx]));
+            last_titled = Some(idx);
+        }
+        push_block(
+            &mut out,
+            &fence,
+            tag,
+            trim_blank_edges(&lines).iter().copied(),
+        );
+    }
+    out
+}
+
+/// Unique anchor per symbol: its name, suffixed `-N` from the second use on.
+fn anchors(symbols: &[Symbol]) -> Vec<String> {
+    let mut seen: Vec<(&str, usize)> = Vec::new();
+    symbols
+        .iter()
+        .map(
+            |s| match seen.iter_mut().find(|(name, _)| *name == s.name) {
+                Some((_, count)) => {
+                    *count += 1;
+                    format!("{}-{count}", s.name)
+                }
+                None => {
+                    seen.push((&s.name, 1));
+                    s.name.clone()
+                }
+            },
+        )
+        .collect()
+}
+
+/// A backtick fence longer than any backtick run inside `text`.
+fn fence_for(text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat((longest + 1).max(3))
+}
+
+fn trim_blank_edges<'a>(lines: &'a [&'a str]) -> &'a [&'a str] {
+    let start = lines.iter().position(|l| !l.trim().is_empty()).unwrap_or(0);
+    let end = lines
+        .iter()
+        .rposition(|l| !l.trim().is_empty())
+        .map_or(start, |i| i + 1);
+    &lines[start..end]
+}
+
+fn push_block<'a>(out: &mut String, fence: &str, tag: &str, lines: impl Iterator<Item = &'a str>) {
+    out.push('\n');
+    out.push_str(fence);
+    out.push_str(tag);
+    out.push('\n');
+    for line in lines {
+        out.push_str(line);
+        out.push('\n');
+    }
+    out.push_str(fence);
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_per_top_level_symbol() {
+        let source =
+            "use std::io;\n\npub fn parse_config() -> u8 {\n    1\n}\n\npub struct Config;\n";
+        let output = "use std::io;\n\npub fn parse_config() -> u8 {...}\n\npub struct Config;\n";
+        let map = [1, 2, 3, 6, 7];
+        let rendered = render_markdown(
+            "src/config.rs",
+            Some(Language::Rust),
+            source,
+            output,
+            Some(&map),
+        );
+        assert_eq!(
+            rendered,
+            "## src/config.rs\n\n```rust\nuse std::io;\n```\n\n\
+             ### src/config.rs#parse_config\n\n```rust\npub fn parse_config() -> u8 {...}\n```\n\n\
+             ### src/config.rs#Config\n\n```rust\npub struct Config;\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_names_get_numbered_anchors() {
+        let source = "struct A;\nimpl A {\n    fn new() -> A { A }\n}\n";
+        let output = "struct A;\nimpl A {\n    fn new() -> A {...}\n}\n";
+        let rendered = render_markdown(
+            "a.rs",
+            Some(Language::Rust),
+            source,
+            output,
+            Some(&[1, 2, 3, 4]),
+        );
+        assert!(rendered.contains("### a.rs#A\n"));
+        assert!(rendered.contains("### a.rs#A-2\n"));
+    }
+
+    #[test]
+    fn test_without_line_map_renders_single_block() {
+        let rendered = render_markdown("data.json", Some(Language::Json), "{}", "{}\n", None);
+        assert_eq!(rendered, "## data.json\n\n```json\n{}\n```\n");
+    }
+
+    #[test]
+    fn test_fence_outgrows_backticks_in_output() {
+        assert_eq!(fence_for("const s = `a`;"), "```");
+        assert_eq!(fence_for("```ts\n```"), "````");
+    }
+}
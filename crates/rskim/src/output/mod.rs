@@ -8,8 +8,15 @@
 #![allow(dead_code)]
 
 pub(crate) mod canonical;
+pub(crate) mod claude_xml;
+pub(crate) mod detailed_json;
+pub(crate) mod footer;
 pub(crate) mod guardrail;
+pub(crate) mod markdown;
+pub(crate) mod reformat;
+pub(crate) mod tags;
 pub(crate) mod tee;
+pub(crate) mod template;
 
 use std::io::{self, Write};
 
@@ -103,25 +110,24 @@ impl<T: serde::Serialize> ParseResult<T> {
     /// - `Full(result)` -> direct JSON serialization (no envelope -- preserves existing behavior)
     /// - `Degraded(result, warnings)` -> `{"tier":"degraded","warnings":[...],"result":{...}}`
     /// - `Passthrough(raw)` -> `{"tier":"passthrough","raw":"..."}`
+    ///
+    /// Deprecations hit during the run are added as a top-level
+    /// `"deprecations"` array (see [`crate::deprecation::attach`]).
     pub(crate) fn to_json_envelope(&self) -> serde_json::Result<String> {
-        match self {
-            ParseResult::Full(inner) => serde_json::to_string(inner),
-            ParseResult::Degraded(inner, warnings) => {
-                let val = serde_json::json!({
-                    "tier": "degraded",
-                    "warnings": warnings,
-                    "result": inner,
-                });
-                serde_json::to_string(&val)
-            }
-            ParseResult::Passthrough(raw) => {
-                let val = serde_json::json!({
-                    "tier": "passthrough",
-                    "raw": raw,
-                });
-                serde_json::to_string(&val)
-            }
-        }
+        let mut val = match self {
+            ParseResult::Full(inner) => serde_json::to_value(inner)?,
+            ParseResult::Degraded(inner, warnings) => serde_json::json!({
+                "tier": "degraded",
+                "warnings": warnings,
+                "result": inner,
+            }),
+            ParseResult::Passthrough(raw) => serde_json::json!({
+                "tier": "passthrough",
+                "raw": raw,
+            }),
+        };
+        crate::deprecation::attach(&mut val);
+        serde_json::to_string(&val)
     }
 }
 
@@ -0,0 +1,245 @@
+//! Post-formatting of transformed output (`--format-output`).
+//!
+//! Stripping bodies leaves whitespace behind: trailing spaces, runs of blank
+//! lines where whole blocks used to be, and an extra space before every
+//! `{...}` placeholder (`fn run()  {...}`). All of it costs tokens and makes
+//! the output look broken. `--format-output` tidies each file after the
+//! transform, with either
+//!
+//! - an external formatter configured for the file's language in the
+//!   `[formatters]` table of the nearest `skim.toml`, which gets the output
+//!   on stdin and prints the formatted text on stdout:
+//!
+//!   ```toml
+//!   [formatters]
+//!   rust = ["rustfmt", "--emit", "stdout"]
+//!   typescript = ["prettier", "--stdin-filepath", "{path}"]
+//!   ```
+//!
+//!   `{path}` in an argument is replaced by the file's path (`-` or the
+//!   `--filename` hint for stdin); or
+//! - the built-in formatter, which only touches whitespace: it strips
+//!   trailing whitespace, drops the placeholder's extra space, collapses runs
+//!   of blank lines into one, and trims blank lines at both ends.
+//!
+//! External formatters parse their input, and `{...}` placeholders are rarely
+//! valid syntax, so a formatter that fails (non-zero exit, or not installed)
+//! is reported on stderr and the built-in formatter is used instead.
+//! Formatters are only read from `skim.toml` when `--format-output` is given,
+//! and never in hermetic runs.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+
+use rskim_core::Language;
+use serde::Deserialize;
+
+/// External formatter commands by language name, set once at startup.
+static FORMATTERS: OnceLock<BTreeMap<String, Vec<String>>> = OnceLock::new();
+
+/// Placeholder the transform leaves for stripped bodies.
+const BODY_PLACEHOLDER: &str = "{...}";
+
+/// The subset of `skim.toml` read here; other tables are ignored.
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    #[serde(default)]
+    formatters: BTreeMap<String, Vec<String>>,
+}
+
+/// Load the `[formatters]` table of the nearest `skim.toml`.
+///
+/// Call once in the file-operation path, before any file is formatted.
+/// Hermetic runs read no `skim.toml` and use the built-in formatter only.
+pub(crate) fn configure(hermetic: bool) -> anyhow::Result<()> {
+    let config = if hermetic {
+        None
+    } else {
+        std::env::current_dir()
+            .ok()
+            .and_then(|dir| crate::preset::find_config(&dir))
+    };
+    let formatters = match config {
+        Some(path) => load_formatters(&path)?,
+        None => BTreeMap::new(),
+    };
+    // A second call would only repeat the same table; ignore it.
+    let _ = FORMATTERS.set(formatters);
+    Ok(())
+}
+
+/// Read and check the `[formatters]` table of the config file at `path`.
+fn load_formatters(path: &Path) -> anyhow::Result<BTreeMap<String, Vec<String>>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("cannot read {}: {e}", path.display()))?;
+    let config: Config = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("invalid {}: {}", path.display(), e.message()))?;
+    for (language, command) in &config.formatters {
+        if !rskim_core::supported_languages()
+            .iter()
+            .any(|known| known.as_str() == language)
+        {
+            anyhow::bail!(
+                "invalid {}: [formatters] has unknown language '{}'",
+                path.display(),
+                crate::cmd::sanitize_for_display(language)
+            );
+        }
+        if command.is_empty() {
+            anyhow::bail!(
+                "invalid {}: [formatters] command for '{language}' is empty",
+                path.display()
+            );
+        }
+    }
+    Ok(config.formatters)
+}
+
+/// Format `output`, the transformed text of the file labelled `path`.
+///
+/// Uses the external formatter configured for `language` when there is one
+/// and it succeeds; otherwise the built-in formatter. External failures are
+/// reported to `warnings`.
+pub(crate) fn reformat(
+    output: &str,
+    path: &Path,
+    language: Option<Language>,
+    warnings: &mut impl Write,
+) -> anyhow::Result<String> {
+    let command = language.and_then(|language| FORMATTERS.get()?.get(language.as_str()));
+    if let Some(command) = command {
+        match run_external(command, output, path) {
+            Ok(formatted) => return Ok(formatted),
+            Err(reason) => writeln!(
+                warnings,
+                "[skim] warning: formatter '{}' failed for {}: {reason}; using built-in formatting",
+                command[0],
+                path.display()
+            )?,
+        }
+    }
+    Ok(builtin(output))
+}
+
+/// Pipe `input` through `command`, returning its stdout, or why it failed.
+fn run_external(command: &[String], input: &str, path: &Path) -> Result<String, String> {
+    let label = path.display().to_string();
+    let mut child = Command::new(&command[0])
+        .args(command[1..].iter().map(|arg| arg.replace("{path}", &label)))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    // Write from a separate thread: a formatter that starts printing before
+    // it has read everything would otherwise deadlock on a full pipe.
+    let mut stdin = child.stdin.take().ok_or("stdin not captured")?;
+    let input = input.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    // A formatter may exit without reading its input; that is reported by
+    // the exit status, not the broken pipe.
+    let _ = writer.join();
+
+    let status = output.status;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !status.success() {
+        let first_line = stderr.lines().find(|line| !line.trim().is_empty());
+        return Err(match first_line {
+            Some(line) => format!("{status}: {}", crate::cmd::sanitize_for_display(line)),
+            None => status.to_string(),
+        });
+    }
+    String::from_utf8(output.stdout).map_err(|_| "output is not UTF-8".to_string())
+}
+
+/// Whitespace-only tidy-up of transformed output.
+///
+/// Strips trailing whitespace, removes the extra space the body placeholder
+/// brings with it, collapses runs of blank lines into one, and trims blank
+/// lines at both ends. Non-empty output ends with a newline.
+pub(crate) fn builtin(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut pending_blank = false;
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            pending_blank = !out.is_empty();
+            continue;
+        }
+        if pending_blank {
+            out.push('\n');
+            pending_blank = false;
+        }
+        tighten_placeholders(line, &mut out);
+        out.push('\n');
+    }
+    out
+}
+
+/// Append `line` to `out`, dropping one space from every run of two or more
+/// whitespace characters before `{...}`: the placeholder is inserted with a
+/// leading space where the body started, after the source's own whitespace.
+fn tighten_placeholders(line: &str, out: &mut String) {
+    let mut rest = line;
+    while let Some(idx) = rest.find(BODY_PLACEHOLDER) {
+        let before = &rest[..idx];
+        let kept = before.trim_end_matches(' ');
+        let spaces = before.len() - kept.len();
+        out.push_str(kept);
+        out.push_str(&" ".repeat(if spaces >= 2 { spaces - 1 } else { spaces }));
+        out.push_str(BODY_PLACEHOLDER);
+        rest = &rest[idx + BODY_PLACEHOLDER.len()..];
+    }
+    out.push_str(rest);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_collapses_blank_runs_and_trailing_whitespace() {
+        let text = "\n\nimport a;   \n\n\n\nclass A {\n\t\n}\n\n\n";
+        assert_eq!(builtin(text), "import a;\n\nclass A {\n\n}\n");
+        assert_eq!(builtin("\n \n"), "");
+    }
+
+    #[test]
+    fn test_builtin_drops_the_placeholder_space() {
+        assert_eq!(builtin("fn top()  {...}\n"), "fn top() {...}\n");
+        assert_eq!(builtin("def f(x):\n     {...}\n"), "def f(x):\n    {...}\n");
+        // Already tight, or not preceded by whitespace: unchanged.
+        assert_eq!(builtin("f() {...}; g(){...}\n"), "f() {...}; g(){...}\n");
+    }
+
+    #[test]
+    fn test_run_external_reports_failures() {
+        let missing = ["skim-no-such-formatter".to_string()];
+        assert!(run_external(&missing, "x", Path::new("a.rs")).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_external_pipes_and_substitutes_path() {
+        let command: Vec<String> = ["sh", "-c", "tr a-z A-Z; echo {path}"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        assert_eq!(
+            run_external(&command, "fn a() {...}\n", Path::new("src/a.rs")).unwrap(),
+            "FN A() {...}\nsrc/a.rs\n"
+        );
+        let failing: Vec<String> = ["sh", "-c", "echo 'bad input' >&2; exit 3"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        let err = run_external(&failing, "", Path::new("a.rs")).unwrap_err();
+        assert!(err.contains("bad input"), "{err}");
+    }
+}
@@ -0,0 +1,175 @@
+//! Symbol listings for `--format ctags` and `--format symbols`.
+//!
+//! Editors, fuzzy finders and code indexers want one line per declaration,
+//! not transformed source. Both formats list the symbols [`rskim_core::symbols`]
+//! finds, in source order. `ctags` writes extended ctags lines that Vim,
+//! Emacs and most tag-aware tools read directly (fields are tab-separated):
+//!
+//! ```text
+//! validate<TAB>src/config.rs<TAB>9;"<TAB>kind:method<TAB>line:9<TAB>impl:Config
+//! ```
+//!
+//! `symbols` writes a compact `file:line:kind:name` line per symbol, with the
+//! name qualified by its enclosing symbols, for grep-like pipelines:
+//!
+//! ```text
+//! src/config.rs:9:method:Config.validate
+//! ```
+//!
+//! Kinds are ctags-style names (`function`, `method`, `class`, `struct`, ...)
+//! rather than tree-sitter node kinds; a function declared inside a class,
+//! struct, impl, trait or interface is a `method`.
+
+use rskim_core::{Language, Symbol};
+
+/// Which listing to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TagFormat {
+    /// Extended ctags lines (`--format ctags`)
+    Ctags,
+    /// `file:line:kind:name` lines (`--format symbols`)
+    Symbols,
+}
+
+/// Pseudo-tags opening a `--format ctags` run, written once before the
+/// first file. Lines follow source order, so the file is marked unsorted
+/// and readers fall back to a linear search.
+pub(crate) const CTAGS_HEADER: &str = "!_TAG_FILE_FORMAT\t2\t/extended format/\n\
+                                       !_TAG_FILE_SORTED\t0\t/0=unsorted, 1=sorted, 2=foldcase/\n\
+                                       !_TAG_PROGRAM_NAME\tskim\t//\n";
+
+/// List the symbols of `source` in `format`, labelled with `path`.
+pub(crate) fn render(
+    path: &str,
+    source: &str,
+    language: Language,
+    format: TagFormat,
+) -> anyhow::Result<String> {
+    let symbols = rskim_core::symbols(source, language)?;
+    let mut out = String::new();
+    for (idx, symbol) in symbols.iter().enumerate() {
+        let parent = parent(&symbols, idx);
+        let kind = kind_name(symbol, parent);
+        match format {
+            TagFormat::Symbols => {
+                out.push_str(&format!(
+                    "{path}:{}:{kind}:{}\n",
+                    symbol.start_line, symbol.name
+                ));
+            }
+            TagFormat::Ctags => {
+                let (scope, name) = symbol
+                    .name
+                    .rsplit_once('.')
+                    .unwrap_or(("", symbol.name.as_str()));
+                out.push_str(&format!(
+                    "{name}\t{path}\t{line};\"\tkind:{kind}\tline:{line}",
+                    line = symbol.start_line
+                ));
+                if let Some(parent) = parent {
+                    out.push_str(&format!("\t{}:{scope}", kind_name(parent, None)));
+                }
+                out.push('\n');
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// The symbol enclosing `symbols[idx]`: symbols come in pre-order, so it is
+/// the nearest earlier one a level up.
+fn parent(symbols: &[Symbol], idx: usize) -> Option<&Symbol> {
+    let depth = symbols[idx].depth.checked_sub(1)?;
+    symbols[..idx]
+        .iter()
+        .rev()
+        .find(|symbol| symbol.depth == depth)
+}
+
+/// The ctags-style kind of `symbol`, declared inside `parent`.
+fn kind_name(symbol: &Symbol, parent: Option<&Symbol>) -> &'static str {
+    let kind = match symbol.kind {
+        "function_declaration"
+        | "function_item"
+        | "function_definition"
+        | "function_signature_item"
+        | "function_signature"
+        | "generator_function_declaration"
+        | "arrow_function"
+        | "function_expression"
+        | "function"
+        | "generator_function" => "function",
+        "method_declaration"
+        | "method_definition"
+        | "method"
+        | "singleton_method"
+        | "constructor_declaration"
+        | "secondary_constructor"
+        | "init_declaration"
+        | "deinit_declaration"
+        | "anonymous_initializer" => "method",
+        "declaration" | "field_declaration" => "prototype",
+        "class_declaration"
+        | "abstract_class_declaration"
+        | "class_definition"
+        | "class_specifier"
+        | "class"
+        | "record_declaration"
+        | "object_declaration" => "class",
+        "struct_item" | "struct_specifier" | "struct_declaration" => "struct",
+        "union_specifier" => "union",
+        "enum_item" | "enum_declaration" | "enum_specifier" => "enum",
+        "interface_declaration" | "protocol_declaration" => "interface",
+        "trait_item" => "trait",
+        "impl_item" => "impl",
+        "mod_item" | "module" | "module_declaration" => "module",
+        "namespace_definition" | "namespace_declaration" => "namespace",
+        "create_table" => "table",
+        _ => "type",
+    };
+    let in_type = parent.is_some_and(|parent| {
+        matches!(
+            kind_name(parent, None),
+            "class" | "struct" | "impl" | "trait" | "interface" | "enum"
+        )
+    });
+    if kind == "function" && in_type {
+        "method"
+    } else {
+        kind
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "pub struct Config;\n\nimpl Config {\n    \
+                          pub fn validate(&self) {}\n}\n\nfn main() {}\n";
+
+    #[test]
+    fn test_symbols_lines_are_qualified() {
+        let out = render("src/main.rs", SOURCE, Language::Rust, TagFormat::Symbols).unwrap();
+        assert_eq!(
+            out,
+            "src/main.rs:1:struct:Config\n\
+             src/main.rs:3:impl:Config\n\
+             src/main.rs:4:method:Config.validate\n\
+             src/main.rs:7:function:main\n"
+        );
+    }
+
+    #[test]
+    fn test_ctags_lines_carry_kind_and_scope() {
+        let out = render("src/main.rs", SOURCE, Language::Rust, TagFormat::Ctags).unwrap();
+        assert_eq!(
+            out.lines().nth(2).unwrap(),
+            "validate\tsrc/main.rs\t4;\"\tkind:method\tline:4\timpl:Config"
+        );
+        assert_eq!(
+            out.lines().nth(3).unwrap(),
+            "main\tsrc/main.rs\t7;\"\tkind:function\tline:7"
+        );
+    }
+}
@@ -0,0 +1,771 @@
+//! Output templating for `--template`.
+//!
+//! Renders the results of a multi-file run through a user-supplied template,
+//! so teams can emit their exact prompt layout (custom headers, XML tags,
+//! per-file metadata) without a post-processing script.
+//!
+//! # Syntax
+//!
+//! A Handlebars subset, evaluated against a JSON context:
+//!
+//! - `{{path}}` / `{{{path}}}` — insert a value. Nothing is HTML-escaped.
+//! - `{{#each list}}...{{else}}...{{/each}}` — repeat for each item; the
+//!   `{{else}}` branch renders when the list is empty. Inside, `{{this}}` is
+//!   the item and `{{@index}}`, `{{@first}}`, `{{@last}}` describe its position.
+//! - `{{#if path}}...{{else}}...{{/if}}` and `{{#unless path}}...{{/unless}}`
+//!   — `false`, `null`, `0`, `""` and `[]` are falsy.
+//! - `{{! note }}` / `{{!-- note --}}` — comments.
+//! - `{{~` and `~}}` trim whitespace before or after a tag. Block tags and
+//!   comments alone on their line remove the whole line, as in Handlebars.
+//!
+//! Names resolve in the innermost scope first, then in enclosing ones;
+//! `this.name` looks only at the current item and `@root.name` only at the
+//! top level. Unlike Handlebars, a name that resolves nowhere is an error,
+//! so a typo fails the run instead of silently rendering nothing.
+//!
+//! # Context
+//!
+//! ```text
+//! mode                       transformation mode ("structure", ...)
+//! files[]                    processed files, in output order
+//!   path                     path as discovered
+//!   language                 "rust", "typescript", ... or null
+//!   content                  transformed output
+//!   original_tokens          token counts (null without --show-stats)
+//!   transformed_tokens
+//!   symbols[]                declarations (Markdown and data files have none)
+//!     name kind start_line end_line depth public
+//! stats
+//!   files                    number of files
+//!   original_tokens          totals (null without --show-stats)
+//!   transformed_tokens
+//! ```
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use serde_json::{Value, json};
+
+use crate::process::ProcessResult;
+
+/// A parsed `--template` file.
+#[derive(Debug, Clone)]
+pub(crate) struct Template {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Var {
+        path: String,
+        line: usize,
+    },
+    Block {
+        kind: BlockKind,
+        path: String,
+        line: usize,
+        body: Vec<Node>,
+        inverse: Vec<Node>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockKind {
+    Each,
+    If,
+    Unless,
+}
+
+impl BlockKind {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "each" => Some(Self::Each),
+            "if" => Some(Self::If),
+            "unless" => Some(Self::Unless),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Each => "each",
+            Self::If => "if",
+            Self::Unless => "unless",
+        }
+    }
+}
+
+/// A lexed template token.
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Tag {
+        tag: Tag,
+        line: usize,
+        trim_before: bool,
+        trim_after: bool,
+    },
+}
+
+#[derive(Debug)]
+enum Tag {
+    Comment,
+    Var(String),
+    Open(BlockKind, String),
+    Else,
+    Close(String),
+}
+
+impl Tag {
+    /// Whether the tag removes its line when it stands alone on it.
+    fn is_standalone_kind(&self) -> bool {
+        !matches!(self, Tag::Var(_))
+    }
+}
+
+impl Template {
+    /// Read and parse the template at `path`.
+    pub(crate) fn load(path: &Path) -> anyhow::Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("failed to read template {}: {e}", path.display()))?;
+        Self::parse(&source).map_err(|e| anyhow::anyhow!("template {}: {e}", path.display()))
+    }
+
+    /// Parse template source.
+    pub(crate) fn parse(source: &str) -> anyhow::Result<Self> {
+        let mut tokens = lex(source)?;
+        strip_standalone_lines(&mut tokens);
+        apply_trim_markers(&mut tokens);
+        Ok(Self {
+            nodes: build_tree(tokens)?,
+        })
+    }
+
+    /// Render the template against `context`.
+    pub(crate) fn render(&self, context: &Value) -> anyhow::Result<String> {
+        let mut out = String::new();
+        let mut scopes = vec![Scope {
+            value: context,
+            position: None,
+        }];
+        render_nodes(&self.nodes, &mut scopes, &mut out)?;
+        Ok(out)
+    }
+
+    /// The context entry for one processed file.
+    ///
+    /// Symbols are extracted only when the template mentions `symbols`, since
+    /// that re-reads and re-parses the source.
+    pub(crate) fn file_entry(&self, path: &Path, result: &ProcessResult) -> Value {
+        let symbols: Vec<Value> = match result.language {
+            Some(language) if mentions(&self.nodes, "symbols") => crate::process::read_source(path)
+                .ok()
+                .and_then(|source| rskim_core::symbols(&source, language).ok())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|s| {
+                    json!({
+                        "name": s.name,
+                        "kind": s.kind,
+                        "start_line": s.start_line,
+                        "end_line": s.end_line,
+                        "depth": s.depth,
+                        "public": s.public,
+                        "parameters": s.parameters,
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        json!({
+            "path": path.display().to_string(),
+            "language": result.language.map(rskim_core::Language::as_str),
+            "content": result.output,
+            "original_tokens": result.original_tokens,
+            "transformed_tokens": result.transformed_tokens,
+            "symbols": symbols,
+        })
+    }
+}
+
+/// Build the top-level template context from per-file entries.
+pub(crate) fn context(mode: rskim_core::Mode, files: Vec<Value>) -> Value {
+    let total = |key: &str| {
+        files
+            .iter()
+            .filter_map(|f| f[key].as_u64())
+            .reduce(|a, b| a + b)
+    };
+    let stats = json!({
+        "files": files.len(),
+        "original_tokens": total("original_tokens"),
+        "transformed_tokens": total("transformed_tokens"),
+    });
+    json!({
+        "mode": mode.name(),
+        "files": files,
+        "stats": stats,
+    })
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Split `source` into text and tag tokens.
+fn lex(source: &str) -> anyhow::Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    let mut line = 1;
+
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            tokens.push(Token::Text(rest[..start].to_string()));
+        }
+        line += rest[..start].matches('\n').count();
+        let tag_src = &rest[start..];
+
+        let triple = tag_src.starts_with("{{{");
+        let delim = if triple { 3 } else { 2 };
+        let body = &tag_src[delim..];
+        let end = if triple {
+            body.find("}}}")
+        } else if body.trim_start_matches('~').starts_with("!--") {
+            comment_end(body)
+        } else {
+            body.find("}}")
+        };
+        let Some(end) = end else {
+            anyhow::bail!("line {line}: unclosed '{}'", &tag_src[..delim]);
+        };
+        let consumed = delim + end + delim;
+
+        let inner = &body[..end];
+        let trim_before = inner.starts_with('~');
+        let inner = inner.strip_prefix('~').unwrap_or(inner);
+        let trim_after = inner.ends_with('~');
+        let inner = inner.strip_suffix('~').unwrap_or(inner);
+
+        tokens.push(Token::Tag {
+            tag: parse_tag(inner.trim(), line)?,
+            line,
+            trim_before,
+            trim_after,
+        });
+        line += tag_src[..consumed].matches('\n').count();
+        rest = &tag_src[consumed..];
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+    Ok(tokens)
+}
+
+/// Offset of the `}}` closing a `{{!-- ... --}}` comment within `body` (the
+/// text after `{{`), so the comment may itself contain `}}`.
+fn comment_end(body: &str) -> Option<usize> {
+    let start = body.find("!--")? + 3;
+    body[start..]
+        .match_indices("}}")
+        .map(|(i, _)| start + i)
+        .find(|&i| body[start..i].trim_end_matches('~').ends_with("--"))
+}
+
+/// Classify the trimmed contents of a `{{...}}` tag.
+fn parse_tag(inner: &str, line: usize) -> anyhow::Result<Tag> {
+    if inner.starts_with('!') {
+        return Ok(Tag::Comment);
+    }
+    if let Some(open) = inner.strip_prefix('#') {
+        let mut parts = open.split_whitespace();
+        let helper = parts.next().unwrap_or_default();
+        let Some(kind) = BlockKind::parse(helper) else {
+            anyhow::bail!(
+                "line {line}: unknown block helper '#{helper}' (supported: #each, #if, #unless)"
+            );
+        };
+        let args: Vec<&str> = parts.collect();
+        let [path] = args.as_slice() else {
+            anyhow::bail!("line {line}: '{{{{#{helper}}}}}' takes exactly one argument");
+        };
+        return Ok(Tag::Open(kind, (*path).to_string()));
+    }
+    if let Some(close) = inner.strip_prefix('/') {
+        return Ok(Tag::Close(close.trim().to_string()));
+    }
+    if inner == "else" {
+        return Ok(Tag::Else);
+    }
+    if inner.starts_with('>') {
+        anyhow::bail!("line {line}: partials ('{{{{>') are not supported");
+    }
+    if inner.is_empty() {
+        anyhow::bail!("line {line}: empty tag");
+    }
+    if inner.contains(char::is_whitespace) {
+        anyhow::bail!(
+            "line {line}: '{{{{{inner}}}}}' looks like a helper call; only variables and \
+             #each/#if/#unless are supported"
+        );
+    }
+    Ok(Tag::Var(inner.to_string()))
+}
+
+/// Remove the lines of block tags and comments that stand alone on a line.
+///
+/// Decided on the original text first, then applied, so two standalone tags
+/// on consecutive lines are both removed.
+fn strip_standalone_lines(tokens: &mut [Token]) {
+    let is_blank = |s: &str| s.chars().all(|c| c == ' ' || c == '\t');
+    let standalone: Vec<usize> = (0..tokens.len())
+        .filter(|&i| {
+            let Token::Tag { tag, .. } = &tokens[i] else {
+                return false;
+            };
+            if !tag.is_standalone_kind() {
+                return false;
+            }
+            let starts_line = match i.checked_sub(1).map(|p| &tokens[p]) {
+                None => true,
+                Some(Token::Text(text)) => match text.rfind('\n') {
+                    Some(nl) => is_blank(&text[nl + 1..]),
+                    None => i == 1 && is_blank(text),
+                },
+                Some(Token::Tag { .. }) => false,
+            };
+            let ends_line = match tokens.get(i + 1) {
+                None => true,
+                Some(Token::Text(text)) => match text.find('\n') {
+                    Some(nl) => is_blank(text[..nl].trim_end_matches('\r')),
+                    None => i + 2 == tokens.len() && is_blank(text),
+                },
+                Some(Token::Tag { .. }) => false,
+            };
+            starts_line && ends_line
+        })
+        .collect();
+
+    for i in standalone {
+        if let Some(Token::Text(text)) = i.checked_sub(1).map(|p| &mut tokens[p]) {
+            text.truncate(text.trim_end_matches([' ', '\t']).len());
+        }
+        if let Some(Token::Text(text)) = tokens.get_mut(i + 1) {
+            let after = text.trim_start_matches([' ', '\t']);
+            let after = after
+                .strip_prefix("\r\n")
+                .or_else(|| after.strip_prefix('\n'))
+                .unwrap_or(after);
+            *text = after.to_string();
+        }
+    }
+}
+
+/// Apply `{{~` / `~}}` whitespace trimming to neighbouring text.
+fn apply_trim_markers(tokens: &mut [Token]) {
+    for i in 0..tokens.len() {
+        let Token::Tag {
+            trim_before,
+            trim_after,
+            ..
+        } = tokens[i]
+        else {
+            continue;
+        };
+        if trim_before && let Some(Token::Text(text)) = i.checked_sub(1).map(|p| &mut tokens[p]) {
+            text.truncate(text.trim_end().len());
+        }
+        if trim_after && let Some(Token::Text(text)) = tokens.get_mut(i + 1) {
+            *text = text.trim_start().to_string();
+        }
+    }
+}
+
+/// An open block while building the tree.
+struct Frame {
+    kind: BlockKind,
+    path: String,
+    line: usize,
+    body: Vec<Node>,
+    inverse: Option<Vec<Node>>,
+}
+
+impl Frame {
+    fn active(&mut self) -> &mut Vec<Node> {
+        self.inverse.as_mut().unwrap_or(&mut self.body)
+    }
+}
+
+/// Nest tokens into a node tree, checking that blocks are balanced.
+fn build_tree(tokens: Vec<Token>) -> anyhow::Result<Vec<Node>> {
+    let mut root: Vec<Node> = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+
+    for token in tokens {
+        let (tag, line) = match token {
+            Token::Text(text) => {
+                if !text.is_empty() {
+                    active(&mut root, &mut stack).push(Node::Text(text));
+                }
+                continue;
+            }
+            Token::Tag { tag, line, .. } => (tag, line),
+        };
+        match tag {
+            Tag::Comment => {}
+            Tag::Var(path) => active(&mut root, &mut stack).push(Node::Var { path, line }),
+            Tag::Open(kind, path) => stack.push(Frame {
+                kind,
+                path,
+                line,
+                body: Vec::new(),
+                inverse: None,
+            }),
+            Tag::Else => match stack.last_mut() {
+                Some(frame) if frame.inverse.is_none() => frame.inverse = Some(Vec::new()),
+                Some(_) => anyhow::bail!("line {line}: second '{{{{else}}}}' in one block"),
+                None => anyhow::bail!("line {line}: '{{{{else}}}}' outside a block"),
+            },
+            Tag::Close(name) => {
+                let Some(frame) = stack.pop() else {
+                    anyhow::bail!("line {line}: '{{{{/{name}}}}}' closes nothing");
+                };
+                if name != frame.kind.name() {
+                    anyhow::bail!(
+                        "line {line}: '{{{{/{name}}}}}' does not close '{{{{#{} {}}}}}' \
+                         opened on line {}",
+                        frame.kind.name(),
+                        frame.path,
+                        frame.line
+                    );
+                }
+                let node = Node::Block {
+                    kind: frame.kind,
+                    path: frame.path,
+                    line: frame.line,
+                    body: frame.body,
+                    inverse: frame.inverse.unwrap_or_default(),
+                };
+                active(&mut root, &mut stack).push(node);
+            }
+        }
+    }
+
+    if let Some(frame) = stack.last() {
+        anyhow::bail!(
+            "line {}: '{{{{#{} {}}}}}' is never closed",
+            frame.line,
+            frame.kind.name(),
+            frame.path
+        );
+    }
+    Ok(root)
+}
+
+/// The node list new nodes are appended to.
+fn active<'a>(root: &'a mut Vec<Node>, stack: &'a mut [Frame]) -> &'a mut Vec<Node> {
+    match stack.last_mut() {
+        Some(frame) => frame.active(),
+        None => root,
+    }
+}
+
+/// Whether any variable or block path in `nodes` has a `segment` component.
+fn mentions(nodes: &[Node], segment: &str) -> bool {
+    nodes.iter().any(|node| match node {
+        Node::Text(_) => false,
+        Node::Var { path, .. } => path.split('.').any(|s| s == segment),
+        Node::Block {
+            path,
+            body,
+            inverse,
+            ..
+        } => {
+            path.split('.').any(|s| s == segment)
+                || mentions(body, segment)
+                || mentions(inverse, segment)
+        }
+    })
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// One level of `{{#each}}` nesting (or the root context).
+struct Scope<'a> {
+    value: &'a Value,
+    /// `(index, len)` of the current item inside `{{#each}}`.
+    position: Option<(usize, usize)>,
+}
+
+fn render_nodes<'a>(
+    nodes: &[Node],
+    scopes: &mut Vec<Scope<'a>>,
+    out: &mut String,
+) -> anyhow::Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var { path, line } => match lookup(scopes, path, *line)?.as_ref() {
+                Value::Null => {}
+                Value::String(s) => out.push_str(s),
+                Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+                Value::Number(n) => out.push_str(&n.to_string()),
+                Value::Array(_) | Value::Object(_) => anyhow::bail!(
+                    "template line {line}: '{path}' is a list or object; \
+                     iterate it with '{{{{#each {path}}}}}' or pick a field"
+                ),
+            },
+            Node::Block {
+                kind: kind @ (BlockKind::If | BlockKind::Unless),
+                path,
+                line,
+                body,
+                inverse,
+            } => {
+                let truthy = is_truthy(lookup(scopes, path, *line)?.as_ref());
+                let branch = if truthy == (*kind == BlockKind::If) {
+                    body
+                } else {
+                    inverse
+                };
+                render_nodes(branch, scopes, out)?;
+            }
+            Node::Block {
+                kind: BlockKind::Each,
+                path,
+                line,
+                body,
+                inverse,
+            } => {
+                let items: &[Value] = match lookup(scopes, path, *line)? {
+                    Cow::Borrowed(Value::Array(items)) => items,
+                    Cow::Borrowed(Value::Null) => &[],
+                    _ => anyhow::bail!("template line {line}: '{{{{#each {path}}}}}' needs a list"),
+                };
+                if items.is_empty() {
+                    render_nodes(inverse, scopes, out)?;
+                }
+                for (idx, item) in items.iter().enumerate() {
+                    scopes.push(Scope {
+                        value: item,
+                        position: Some((idx, items.len())),
+                    });
+                    let rendered = render_nodes(body, scopes, out);
+                    scopes.pop();
+                    rendered?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Resolve `path` against the scope stack.
+fn lookup<'a>(scopes: &[Scope<'a>], path: &str, line: usize) -> anyhow::Result<Cow<'a, Value>> {
+    let unknown = || anyhow::anyhow!("template line {line}: unknown variable '{path}'");
+    let position = || scopes.iter().rev().find_map(|s| s.position);
+
+    match path {
+        "this" | "." => {
+            return scopes
+                .last()
+                .map(|s| Cow::Borrowed(s.value))
+                .ok_or_else(unknown);
+        }
+        "@index" => {
+            return position()
+                .map(|(i, _)| Cow::Owned(json!(i)))
+                .ok_or_else(unknown);
+        }
+        "@first" => {
+            return position()
+                .map(|(i, _)| Cow::Owned(json!(i == 0)))
+                .ok_or_else(unknown);
+        }
+        "@last" => {
+            return position()
+                .map(|(i, len)| Cow::Owned(json!(i + 1 == len)))
+                .ok_or_else(unknown);
+        }
+        _ => {}
+    }
+
+    let (candidates, rest): (Vec<&Scope<'a>>, &str) =
+        if let Some(rest) = path.strip_prefix("@root.") {
+            (scopes.first().into_iter().collect(), rest)
+        } else if let Some(rest) = path.strip_prefix("this.") {
+            (scopes.last().into_iter().collect(), rest)
+        } else {
+            (scopes.iter().rev().collect(), path)
+        };
+
+    let mut segments = rest.split('.');
+    let head = segments.next().unwrap_or_default();
+    let mut value = candidates
+        .into_iter()
+        .find_map(|scope| scope.value.get(head))
+        .ok_or_else(unknown)?;
+    for segment in segments {
+        value = value.get(segment).ok_or_else(unknown)?;
+    }
+    Ok(Cow::Borrowed(value))
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, context: &Value) -> String {
+        Template::parse(template).unwrap().render(context).unwrap()
+    }
+
+    #[test]
+    fn test_variables_and_nested_paths() {
+        let ctx = json!({"mode": "structure", "stats": {"files": 2, "original_tokens": null}});
+        assert_eq!(
+            render(
+                "{{mode}}: {{ stats.files }} files{{stats.original_tokens}}",
+                &ctx
+            ),
+            "structure: 2 files"
+        );
+        assert_eq!(render("{{{mode}}}", &json!({"mode": "<a&b>"})), "<a&b>");
+    }
+
+    #[test]
+    fn test_each_exposes_item_position_and_outer_scope() {
+        let ctx = json!({"mode": "types", "files": [{"path": "a.rs"}, {"path": "b.rs"}]});
+        assert_eq!(
+            render(
+                "{{#each files}}{{@index}}:{{path}}:{{mode}}{{#unless @last}},{{/unless}}{{/each}}",
+                &ctx
+            ),
+            "0:a.rs:types,1:b.rs:types"
+        );
+        assert_eq!(
+            render(
+                "{{#each files}}x{{else}}none{{/each}}",
+                &json!({"files": []})
+            ),
+            "none"
+        );
+    }
+
+    #[test]
+    fn test_if_else_truthiness() {
+        let t = "{{#if v}}yes{{else}}no{{/if}}";
+        for (v, expected) in [
+            (json!(true), "yes"),
+            (json!(0), "no"),
+            (json!(""), "no"),
+            (json!([]), "no"),
+            (json!(null), "no"),
+            (json!("x"), "yes"),
+        ] {
+            assert_eq!(render(t, &json!({ "v": v })), expected, "v = {v}");
+        }
+    }
+
+    #[test]
+    fn test_standalone_block_lines_are_removed() {
+        let template = "<files>\n  {{#each files}}\n  <file path=\"{{path}}\">\n  {{/each}}\n{{! done }}\n</files>\n";
+        let ctx = json!({"files": [{"path": "a"}, {"path": "b"}]});
+        assert_eq!(
+            render(template, &ctx),
+            "<files>\n  <file path=\"a\">\n  <file path=\"b\">\n</files>\n"
+        );
+    }
+
+    #[test]
+    fn test_trim_markers() {
+        let ctx = json!({"xs": [1, 2]});
+        assert_eq!(
+            render("[ {{~#each xs~}} {{this}} {{~/each~}} ]", &ctx),
+            "[12]"
+        );
+        assert_eq!(render("a {{~!-- gone --~}} b", &ctx), "ab");
+    }
+
+    #[test]
+    fn test_unknown_variable_is_an_error() {
+        let template = Template::parse("ok\n{{#each files}}{{pth}}{{/each}}").unwrap();
+        let err = template
+            .render(&json!({"files": [{"path": "a"}]}))
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("line 2: unknown variable 'pth'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_line() {
+        for (source, needle) in [
+            (
+                "{{#each files}}\n{{/if}}",
+                "line 2: '{{/if}}' does not close",
+            ),
+            ("\n\n{{#if x}}", "line 3: '{{#if x}}' is never closed"),
+            ("{{#with x}}{{/with}}", "unknown block helper '#with'"),
+            ("{{> partial}}", "partials"),
+            ("{{upper path}}", "helper call"),
+            ("{{else}}", "outside a block"),
+            ("{{oops", "unclosed"),
+        ] {
+            let err = Template::parse(source).unwrap_err().to_string();
+            assert!(err.contains(needle), "{source:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_list_values_cannot_be_printed() {
+        let err = Template::parse("{{files}}")
+            .unwrap()
+            .render(&json!({"files": []}))
+            .unwrap_err();
+        assert!(err.to_string().contains("#each files"), "{err}");
+    }
+
+    #[test]
+    fn test_mentions_symbols() {
+        let with =
+            Template::parse("{{#each files}}{{#each symbols}}{{name}}{{/each}}{{/each}}").unwrap();
+        let without = Template::parse("{{#each files}}{{content}}{{/each}}").unwrap();
+        assert!(mentions(&with.nodes, "symbols"));
+        assert!(!mentions(&without.nodes, "symbols"));
+    }
+
+    #[test]
+    fn test_context_totals_tokens() {
+        let ctx = context(
+            rskim_core::Mode::Structure,
+            vec![
+                json!({"original_tokens": 10, "transformed_tokens": 4}),
+                json!({"original_tokens": 6, "transformed_tokens": 2}),
+            ],
+        );
+        assert_eq!(ctx["stats"]["files"], 2);
+        assert_eq!(ctx["stats"]["original_tokens"], 16);
+        assert_eq!(ctx["stats"]["transformed_tokens"], 6);
+        assert_eq!(ctx["mode"], "structure");
+
+        let ctx = context(
+            rskim_core::Mode::Types,
+            vec![json!({"original_tokens": null})],
+        );
+        assert!(ctx["stats"]["original_tokens"].is_null());
+    }
+}
@@ -0,0 +1,338 @@
+//! Interactive symbol picker (`--pick`).
+//!
+//! Hand-crafting a focused prompt usually means "skim this file, but keep
+//! these two functions intact". `--pick` lists the file's symbols (the same
+//! index `--format markdown` and `--template` use), lets the user choose which
+//! to keep in full, and prints the skimmed file with those declarations'
+//! original source spliced back in place.
+//!
+//! # Interaction
+//!
+//! The list and prompt go to stderr and commands are read line by line from
+//! stdin, so no terminal raw mode is needed:
+//!
+//! ```text
+//!   1 [ ] Config
+//!   2 [x] Config.validate
+//!   3 [ ] parse
+//! pick> /cfgval
+//! ```
+//!
+//! - `2 5-7` toggles symbols by number (numbers never change while filtering)
+//! - `/text` narrows the list to fuzzy matches of `text`; `/` alone clears it
+//! - `*` toggles every listed symbol
+//! - an empty line (or end of input) finishes and prints the result
+//!
+//! Both stdin and stdout must be terminals: the picker is a hand tool, and
+//! a pipeline has nobody to answer the prompt.
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+use rskim_core::{Symbol, detect_language_from_path};
+
+use crate::cascade;
+use crate::process::{self, ProcessOptions};
+use crate::record::transform_with_line_map;
+
+/// Run the picker on `path` and print the assembled output.
+pub(crate) fn run(path: &Path, options: ProcessOptions) -> anyhow::Result<()> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        anyhow::bail!(
+            "--pick needs an interactive terminal on stdin and stdout\n\
+             To skim non-interactively, drop --pick."
+        );
+    }
+    if !path.is_file() {
+        anyhow::bail!(
+            "--pick takes a single file, not a directory or glob: {}",
+            path.display()
+        );
+    }
+
+    let source = process::read_source(path)?;
+    let language = options
+        .explicit_lang
+        .or_else(|| detect_language_from_path(path))
+        .ok_or_else(|| anyhow::anyhow!("Language detection failed and no --language specified"))?;
+    let symbols = rskim_core::symbols(&source, language)?;
+    if symbols.is_empty() {
+        anyhow::bail!(
+            "--pick found no symbols in {}\n\
+             Only files with declarations (functions, types, classes) can be picked from.",
+            path.display()
+        );
+    }
+
+    let config = options.flags.apply(cascade::build_config_with_opts(
+        options.mode,
+        &options.trunc,
+        true,
+    ));
+    let (skimmed, _has_errors, line_map, _degraded) =
+        transform_with_line_map(&source, path, language, &config)?;
+    let line_map = line_map.unwrap_or_default();
+
+    let picked = pick_symbols(&symbols, &mut io::stdin().lock(), &mut io::stderr().lock())?;
+    let ranges: Vec<(usize, usize)> = symbols
+        .iter()
+        .zip(&picked)
+        .filter(|(_, picked)| **picked)
+        .map(|(symbol, _)| (symbol.start_line, symbol.end_line))
+        .collect();
+
+    let output = splice(&source, &skimmed, &line_map, &ranges);
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(output.as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Run the picker dialogue; returns which of `symbols` were picked.
+fn pick_symbols(
+    symbols: &[Symbol],
+    input: &mut impl BufRead,
+    ui: &mut impl Write,
+) -> anyhow::Result<Vec<bool>> {
+    let mut picked = vec![false; symbols.len()];
+    let mut filter = String::new();
+
+    loop {
+        let visible: Vec<usize> = (0..symbols.len())
+            .filter(|&i| fuzzy_match(&filter, &symbols[i].name))
+            .collect();
+        for &i in &visible {
+            let mark = if picked[i] { 'x' } else { ' ' };
+            let indent = "  ".repeat(symbols[i].depth);
+            writeln!(ui, "{:>3} [{mark}] {indent}{}", i + 1, symbols[i].name)?;
+        }
+        if visible.is_empty() {
+            writeln!(ui, "    (no symbols match '{filter}')")?;
+        }
+        write!(
+            ui,
+            "pick [numbers, /filter, * = all listed, enter = done]> "
+        )?;
+        ui.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(ui)?;
+            break;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some(query) = line.strip_prefix('/') {
+            filter = query.trim().to_string();
+        } else if line == "*" {
+            for &i in &visible {
+                picked[i] = !picked[i];
+            }
+        } else {
+            match parse_selection(line, symbols.len()) {
+                Ok(numbers) => {
+                    for n in numbers {
+                        picked[n - 1] = !picked[n - 1];
+                    }
+                }
+                Err(message) => writeln!(ui, "    {message}")?,
+            }
+        }
+    }
+
+    Ok(picked)
+}
+
+/// Whether every character of `query` appears in `name` in order, ignoring case.
+fn fuzzy_match(query: &str, name: &str) -> bool {
+    let mut chars = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !c.is_whitespace())
+        .all(|q| chars.any(|c| c == q))
+}
+
+/// Parse `"1 3, 5-7"` into 1-based symbol numbers, each within `1..=count`.
+fn parse_selection(line: &str, count: usize) -> Result<Vec<usize>, String> {
+    let parse_number = |token: &str| -> Result<usize, String> {
+        match token.trim().parse::<usize>() {
+            Ok(n) if (1..=count).contains(&n) => Ok(n),
+            _ => Err(format!("not a symbol number (1-{count}): '{token}'")),
+        }
+    };
+
+    let mut numbers = Vec::new();
+    for token in line
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+    {
+        match token.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_number(first)?, parse_number(last)?);
+                if first > last {
+                    return Err(format!("empty range: '{token}'"));
+                }
+                numbers.extend(first..=last);
+            }
+            None => numbers.push(parse_number(token)?),
+        }
+    }
+    Ok(numbers)
+}
+
+/// Replace the skimmed rendering of each picked line range with its source.
+///
+/// `line_map` maps each `skimmed` line to its 1-indexed source line (`0` for
+/// lines with no source, such as omission markers). Each range is emitted
+/// verbatim once, where its first skimmed line was; skimmed lines inside a
+/// range are dropped. A range with no skimmed lines at all (a declaration the
+/// mode left out) is emitted before the first line that follows it.
+fn splice(source: &str, skimmed: &str, line_map: &[usize], ranges: &[(usize, usize)]) -> String {
+    let source_lines: Vec<&str> = source.lines().collect();
+    let ranges = merge_ranges(ranges);
+    let mut next_range = 0;
+    // Last source line already printed verbatim.
+    let mut emitted_until = 0;
+    let mut swallowing = false;
+    let mut out = String::with_capacity(skimmed.len());
+
+    let emit_range = |out: &mut String, (start, end): (usize, usize)| {
+        for line in source_lines.iter().take(end).skip(start - 1) {
+            out.push_str(line);
+            out.push('\n');
+        }
+    };
+
+    for (i, line) in skimmed.lines().enumerate() {
+        let src = line_map.get(i).copied().unwrap_or(0);
+        if src == 0 {
+            if !swallowing {
+                out.push_str(line);
+                out.push('\n');
+            }
+            continue;
+        }
+        while next_range < ranges.len() && ranges[next_range].0 <= src {
+            emit_range(&mut out, ranges[next_range]);
+            emitted_until = ranges[next_range].1;
+            next_range += 1;
+        }
+        swallowing = src <= emitted_until;
+        if !swallowing {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    for &range in &ranges[next_range..] {
+        emit_range(&mut out, range);
+    }
+    out
+}
+
+/// Sort ranges and merge overlapping ones, so nested picks print once.
+fn merge_ranges(ranges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(sorted.len());
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str, start_line: usize, end_line: usize) -> Symbol {
+        Symbol {
+            name: name.to_string(),
+            kind: "function_item",
+            start_line,
+            end_line,
+            depth: 0,
+            public: true,
+            parameters: None,
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_match_is_ordered_subsequence() {
+        assert!(fuzzy_match("cfgval", "Config.validate"));
+        assert!(fuzzy_match("", "anything"));
+        assert!(!fuzzy_match("valcfg", "Config.validate"));
+    }
+
+    #[test]
+    fn test_parse_selection_numbers_and_ranges() {
+        assert_eq!(parse_selection("1 3, 5-7", 7), Ok(vec![1, 3, 5, 6, 7]));
+        assert!(parse_selection("0", 3).is_err());
+        assert!(parse_selection("4", 3).is_err());
+        assert!(parse_selection("3-1", 3).is_err());
+        assert!(parse_selection("abc", 3).is_err());
+    }
+
+    #[test]
+    fn test_pick_symbols_toggles_filters_and_finishes() {
+        let symbols = [
+            symbol("parse", 1, 3),
+            symbol("render", 5, 7),
+            symbol("run", 9, 9),
+        ];
+        let mut input = "/r\n*\n/\n2\n\n".as_bytes();
+        let mut ui = Vec::new();
+        let picked = pick_symbols(&symbols, &mut input, &mut ui).unwrap();
+        // `*` toggled the matches of "r" (all three), then `2` un-picked render.
+        assert_eq!(picked, [true, false, true]);
+    }
+
+    #[test]
+    fn test_pick_symbols_reports_bad_input_and_stops_at_eof() {
+        let symbols = [symbol("parse", 1, 3)];
+        let mut input = "9\n".as_bytes();
+        let mut ui = Vec::new();
+        let picked = pick_symbols(&symbols, &mut input, &mut ui).unwrap();
+        assert_eq!(picked, [false]);
+        assert!(
+            String::from_utf8(ui)
+                .unwrap()
+                .contains("not a symbol number")
+        );
+    }
+
+    #[test]
+    fn test_splice_replaces_picked_range_with_source() {
+        let source = "fn a() {\n    1\n}\n\nfn b() {\n    2\n}\n";
+        let skimmed = "fn a() { /* ... */ }\n\nfn b() { /* ... */ }\n";
+        let map = [1, 4, 5];
+        assert_eq!(
+            splice(source, skimmed, &map, &[(5, 7)]),
+            "fn a() { /* ... */ }\n\nfn b() {\n    2\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_splice_emits_unmapped_range_in_order() {
+        let source = "fn a() {}\nfn hidden() {}\nfn c() {}\n";
+        let skimmed = "fn a() {}\nfn c() {}\n";
+        assert_eq!(
+            splice(source, skimmed, &[1, 3], &[(2, 2)]),
+            "fn a() {}\nfn hidden() {}\nfn c() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_ranges_collapses_nested_picks() {
+        assert_eq!(
+            merge_ranges(&[(5, 9), (1, 10), (12, 13)]),
+            [(1, 10), (12, 13)]
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! Named flag bundles (`--preset NAME`).
+//!
+//! A preset expands into ordinary file-operation flags before clap parses the
+//! command line, so each flag it sets behaves exactly as if typed; flags given
+//! explicitly win over the preset's. Two presets are built in, and a
+//! `[presets]` table in the nearest `skim.toml` adds more or redefines them:
+//!
+//! ```toml
+//! [presets]
+//! api = ["--mode=signatures", "--public-only"]
+//! ```
+//!
+//! Preset flags are long flags, one token each (`--tokens=4000`, not
+//! `--tokens 4000`), so they can be matched against the explicit ones.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use clap::parser::ValueSource;
+use serde::Deserialize;
+
+/// Project configuration file searched for in the working directory and its
+/// ancestors.
+pub(crate) const CONFIG_FILE: &str = "skim.toml";
+
+/// Built-in presets, overridable from `skim.toml`.
+const BUILTIN_PRESETS: &[(&str, &[&str])] = &[
+    // Prompt bundle: Markdown sections, each file cascaded into a budget.
+    ("pack", &["--format=markdown", "--tokens=2000"]),
+    // Periodic code review of a tree: what moved since the last run, and
+    // how much the skim saved.
+    (
+        "review",
+        &["--mode=structure", "--changed-only", "--show-stats"],
+    ),
+];
+
+/// The subset of `skim.toml` read here; other tables are ignored.
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    #[serde(default)]
+    presets: BTreeMap<String, Vec<String>>,
+}
+
+/// Expand `--preset NAME` in `argv` (including the binary name).
+///
+/// The preset's flags are inserted right after the binary name, minus any
+/// flag the command line sets itself. Hermetic runs read no `skim.toml` and
+/// see only the built-in presets. `argv` is returned unchanged when it has no
+/// `--preset`.
+pub(crate) fn expand(argv: Vec<String>, hermetic: bool) -> anyhow::Result<Vec<String>> {
+    let Some(name) = requested_preset(&argv) else {
+        return Ok(argv);
+    };
+
+    let config = if hermetic {
+        None
+    } else {
+        std::env::current_dir()
+            .ok()
+            .and_then(|dir| find_config(&dir))
+    };
+    let user_presets = match &config {
+        Some(path) => load_presets(path)?,
+        None => BTreeMap::new(),
+    };
+
+    let (flags, origin): (Vec<String>, String) = match user_presets.get(name) {
+        Some(flags) => (flags.clone(), config_origin(config.as_deref())),
+        None => match BUILTIN_PRESETS.iter().find(|(builtin, _)| *builtin == name) {
+            Some((_, flags)) => (
+                flags.iter().map(|flag| (*flag).to_string()).collect(),
+                "built-in".to_string(),
+            ),
+            None => {
+                let mut available: Vec<&str> = BUILTIN_PRESETS
+                    .iter()
+                    .map(|(builtin, _)| *builtin)
+                    .collect();
+                available.extend(user_presets.keys().map(String::as_str));
+                available.sort_unstable();
+                available.dedup();
+                anyhow::bail!(
+                    "unknown preset '{}'\nAvailable presets: {}",
+                    crate::cmd::sanitize_for_display(name),
+                    available.join(", ")
+                );
+            }
+        },
+    };
+
+    let command = crate::file_operation_command();
+    // Help and version requests are answered by the real parse; without
+    // matches there is nothing to merge.
+    let Ok(explicit) = command
+        .clone()
+        .ignore_errors(true)
+        .try_get_matches_from(&argv)
+    else {
+        return Ok(argv);
+    };
+
+    let mut inserted = Vec::with_capacity(flags.len());
+    for flag in flags {
+        let arg = preset_arg(&command, &flag).map_err(|reason| {
+            anyhow::anyhow!(
+                "preset '{}' ({origin}): {reason}",
+                crate::cmd::sanitize_for_display(name)
+            )
+        })?;
+        if explicit.value_source(arg.get_id().as_str()) != Some(ValueSource::CommandLine) {
+            inserted.push(flag);
+        }
+    }
+
+    let mut expanded = Vec::with_capacity(argv.len() + inserted.len());
+    let mut argv = argv.into_iter();
+    expanded.extend(argv.next());
+    expanded.extend(inserted);
+    expanded.extend(argv);
+    Ok(expanded)
+}
+
+/// The `--preset` value, if any, before a `--` separator.
+fn requested_preset(argv: &[String]) -> Option<&str> {
+    let mut args = argv.iter().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            return None;
+        }
+        if arg == "--preset" {
+            return args.next().map(String::as_str);
+        }
+        if let Some(name) = arg.strip_prefix("--preset=") {
+            return Some(name);
+        }
+    }
+    None
+}
+
+/// The nearest `skim.toml` in `start` or one of its ancestors.
+pub(crate) fn find_config(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Read the `[presets]` table of the config file at `path`.
+fn load_presets(path: &Path) -> anyhow::Result<BTreeMap<String, Vec<String>>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("cannot read {}: {e}", path.display()))?;
+    let config: Config = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("invalid {}: {}", path.display(), e.message()))?;
+    Ok(config.presets)
+}
+
+fn config_origin(path: Option<&Path>) -> String {
+    path.map_or_else(
+        || CONFIG_FILE.to_string(),
+        |path| path.display().to_string(),
+    )
+}
+
+/// The file-operation argument a preset flag sets, or why the flag is not
+/// usable in a preset.
+fn preset_arg<'a>(command: &'a clap::Command, flag: &str) -> Result<&'a clap::Arg, String> {
+    let display = crate::cmd::sanitize_for_display(flag);
+    let Some(body) = flag.strip_prefix("--") else {
+        return Err(format!("'{display}' is not a long flag"));
+    };
+    let (long, value) = match body.split_once('=') {
+        Some((long, value)) => (long, Some(value)),
+        None => (body, None),
+    };
+    if long == "preset" {
+        return Err("presets cannot include --preset".to_string());
+    }
+    let arg = command
+        .get_arguments()
+        .find(|arg| {
+            arg.get_long() == Some(long) || arg.get_all_aliases().is_some_and(|a| a.contains(&long))
+        })
+        .ok_or_else(|| format!("'{display}' is not a skim flag"))?;
+    if value.is_none() && arg.get_action().takes_values() {
+        return Err(format!(
+            "'{display}' needs its value in the same token (--{long}=VALUE)"
+        ));
+    }
+    Ok(arg)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn argv(args: &[&str]) -> Vec<String> {
+        std::iter::once("skim")
+            .chain(args.iter().copied())
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_no_preset_leaves_argv_unchanged() {
+        let args = argv(&["src/", "--mode", "signatures"]);
+        assert_eq!(expand(args.clone(), true).unwrap(), args);
+    }
+
+    #[test]
+    fn test_builtin_preset_is_inserted_after_binary_name() {
+        let expanded = expand(argv(&["src/", "--preset", "review"]), true).unwrap();
+        assert_eq!(
+            expanded,
+            argv(&[
+                "--mode=structure",
+                "--changed-only",
+                "--show-stats",
+                "src/",
+                "--preset",
+                "review"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_explicit_flags_win_over_preset() {
+        let expanded = expand(argv(&["src/", "--preset=pack", "--tokens", "500"]), true).unwrap();
+        assert_eq!(
+            expanded,
+            argv(&[
+                "--format=markdown",
+                "src/",
+                "--preset=pack",
+                "--tokens",
+                "500"
+            ])
+        );
+
+        let expanded = expand(argv(&["src/", "-m", "types", "--preset=review"]), true).unwrap();
+        assert!(!expanded.contains(&"--mode=structure".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_preset_lists_available() {
+        let err = expand(argv(&["src/", "--preset", "nope"]), true).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("unknown preset 'nope'"), "{message}");
+        assert!(message.contains("pack, review"), "{message}");
+    }
+
+    #[test]
+    fn test_preset_after_double_dash_is_a_file() {
+        let args = argv(&["--", "--preset=review"]);
+        assert_eq!(expand(args.clone(), true).unwrap(), args);
+    }
+
+    #[test]
+    fn test_preset_flags_are_validated() {
+        let command = crate::file_operation_command();
+        assert!(preset_arg(&command, "--public-only").is_ok());
+        assert!(preset_arg(&command, "--lang=rust").is_ok());
+        assert!(preset_arg(&command, "-m").is_err());
+        assert!(preset_arg(&command, "--no-such-flag").is_err());
+        assert!(preset_arg(&command, "--tokens").is_err());
+        assert!(preset_arg(&command, "--preset=pack").is_err());
+    }
+
+    #[test]
+    fn test_find_config_walks_up() {
+        let root = tempfile::TempDir::new().unwrap();
+        let nested = root.path().join("a/b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.path().join(CONFIG_FILE), "[presets]\n").unwrap();
+        assert_eq!(find_config(&nested), Some(root.path().join(CONFIG_FILE)));
+    }
+
+    #[test]
+    fn test_load_presets_ignores_other_tables() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(CONFIG_FILE);
+        std::fs::write(
+            &path,
+            "[other]\nkey = 1\n\n[presets]\napi = [\"--mode=signatures\", \"--public-only\"]\n",
+        )
+        .unwrap();
+        let presets = load_presets(&path).unwrap();
+        assert_eq!(presets["api"], ["--mode=signatures", "--public-only"]);
+
+        std::fs::write(&path, "[presets]\napi = \"--mode=signatures\"\n").unwrap();
+        assert!(load_presets(&path).is_err());
+    }
+}
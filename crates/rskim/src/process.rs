@@ -6,16 +6,21 @@
 use std::fs;
 use std::io::{self, BufWriter, Read, Write};
 use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use rskim_core::{
-    Language, Mode, TransformConfig, detect_language_from_path, transform_auto_with_config,
-    transform_with_config, transform_with_line_map,
+    AstFormat, Language, Mode, SkimError, TransformConfig, detect_language_from_path,
 };
 
-use crate::{cache, cascade, cascade::TruncationOptions, tokens};
+use crate::cache::CacheKeys;
+use crate::cascade::{TransformFlags, TruncationOptions};
+use crate::output::tags::{self, TagFormat};
+use crate::record::{transform_auto_with_config, transform_with_config, transform_with_line_map};
+use crate::{cache, cascade, tokens, usage};
 
 /// Maximum input size to prevent memory exhaustion (50MB)
-const MAX_INPUT_SIZE: usize = 50 * 1024 * 1024;
+pub(crate) const MAX_INPUT_SIZE: usize = 50 * 1024 * 1024;
 
 /// Options for processing a single file
 #[derive(Debug, Clone, Copy)]
@@ -26,12 +31,40 @@ pub(crate) struct ProcessOptions {
     pub(crate) explicit_lang: Option<Language>,
     /// Whether to use cache
     pub(crate) use_cache: bool,
+    /// How cache entries identify source files (`--cache-keys`)
+    pub(crate) cache_keys: CacheKeys,
+    /// On a cache miss, also transform and cache every other mode (`--precompute-modes`)
+    pub(crate) precompute_modes: bool,
     /// Whether to compute token statistics (for --show-stats)
     pub(crate) show_stats: bool,
+    /// Estimate token statistics from byte length instead of tokenizing (`--stats-fast`)
+    pub(crate) stats_fast: bool,
     /// Truncation options (max_lines, last_lines, token_budget)
     pub(crate) trunc: TruncationOptions,
     /// Whether to annotate output with source line numbers (`--line-numbers` / `-n`)
     pub(crate) line_numbers: bool,
+    /// Transform toggles (`--fenced-code`, `--template-threshold`)
+    pub(crate) flags: TransformFlags,
+    /// Emit an AST dump instead of transforming (`--format ast|ast-json`)
+    pub(crate) ast_format: Option<AstFormat>,
+    /// List symbols instead of transforming (`--format ctags|symbols`)
+    pub(crate) tag_format: Option<TagFormat>,
+    /// Render output as a Markdown section with per-symbol anchors (`--format markdown`)
+    pub(crate) markdown: bool,
+    /// Re-parse the output and report syntax errors it introduced (`--validate-output`)
+    pub(crate) validate_output: Option<ValidateOutput>,
+    /// Tidy the output with the built-in or a configured formatter (`--format-output`)
+    pub(crate) format_output: bool,
+}
+
+/// What `--validate-output` does when the output has syntax errors the
+/// source did not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ValidateOutput {
+    /// Print a warning on stderr and keep the output
+    Warn,
+    /// Fail the file
+    Fail,
 }
 
 /// Result of processing a file
@@ -44,6 +77,11 @@ pub(crate) struct ProcessResult {
     pub(crate) original_tokens: Option<usize>,
     /// Transformed token count (if computed)
     pub(crate) transformed_tokens: Option<usize>,
+    /// Whether the token counts are `--stats-fast` estimates.
+    ///
+    /// Estimated counts are for display only: analytics re-tokenizes instead of
+    /// recording them, and they are never written to the cache.
+    pub(crate) tokens_estimated: bool,
     /// Whether the output guardrail was triggered (compressed > raw)
     pub(crate) guardrail_triggered: bool,
     /// Parse quality tier: "full", "degraded", or "passthrough".
@@ -62,10 +100,15 @@ pub(crate) struct ProcessResult {
     pub(crate) language: Option<Language>,
     /// Raw stdin buffer retained for background tokenization.
     ///
-    /// `Some(buffer)` only from `process_stdin` when `!show_stats` (stdin
-    /// cannot be re-read; the buffer must be kept).  All other constructors
+    /// `Some(buffer)` only from `process_stdin` when exact counts were not
+    /// computed (`!show_stats` or `--stats-fast`; stdin cannot be re-read, the
+    /// buffer must be kept).  All other constructors
     /// set this to `None` (files can be re-read from disk).
     pub(crate) stdin_raw: Option<String>,
+    /// Time spent in the core transform (`--detailed-json`).
+    ///
+    /// `None` for cache hits and AST dumps, where nothing was transformed.
+    pub(crate) transform_duration: Option<Duration>,
 }
 
 /// Determine the parse quality tier from the mode, parse-error flag, and degraded flag.
@@ -116,6 +159,64 @@ pub(crate) fn apply_line_numbers(
     }
 }
 
+/// Render `output` as a Markdown section headed by `label` (`--format markdown`).
+///
+/// Runs at the same point as [`apply_line_numbers`] and uses the same map:
+/// the identity map when the guardrail emitted raw source, otherwise the map
+/// computed by the transform. Without a map the section is a single block.
+pub(crate) fn apply_markdown(
+    output: String,
+    label: &str,
+    language: Option<Language>,
+    source: &str,
+    guardrail_triggered: bool,
+    computed_map: Option<Vec<usize>>,
+) -> String {
+    let map = if guardrail_triggered {
+        Some(crate::format::identity_line_map(&output))
+    } else {
+        computed_map
+    };
+    crate::output::markdown::render_markdown(label, language, source, &output, map.as_deref())
+}
+
+/// Count tokens for a file's source and transformed output.
+///
+/// When `cached_original` is available the source is not re-tokenized (the
+/// source count is identical across modes). Otherwise the source is counted and,
+/// with `use_cache`, stored for later runs. Returns `(None, None)` on failure.
+fn count_file_token_pair(
+    path: &Path,
+    source: &str,
+    transformed: &str,
+    cached_original: Option<usize>,
+    use_cache: bool,
+    cache_keys: CacheKeys,
+) -> (Option<usize>, Option<usize>) {
+    if let Some(original) = cached_original {
+        return pair_with_original(original, transformed);
+    }
+    let pair = count_token_pair(source, transformed);
+    if use_cache && let Some(original) = pair.0 {
+        // Non-fatal: a failed write only costs a recount next time.
+        if let Err(e) = cache::write_original_tokens(path, cache_keys, original) {
+            crate::debug_log!(
+                "[skim] notice: token count not cached for {}: {e:#}",
+                path.display()
+            );
+        }
+    }
+    pair
+}
+
+/// Pair a known source token count with a fresh count of `transformed`.
+fn pair_with_original(original: usize, transformed: &str) -> (Option<usize>, Option<usize>) {
+    match tokens::count_tokens(transformed) {
+        Ok(trans) => (Some(original), Some(trans)),
+        Err(_) => (None, None),
+    }
+}
+
 /// Count tokens for both original and transformed text, returning `(None, None)` on failure.
 ///
 /// Centralises the paired token-counting pattern used across the processing pipeline.
@@ -144,6 +245,18 @@ pub(crate) fn report_token_stats(
     }
 }
 
+/// [`report_token_stats`] for `--stats-fast` byte-length estimates.
+pub(crate) fn report_estimated_token_stats(
+    original_tokens: Option<usize>,
+    transformed_tokens: Option<usize>,
+    suffix: &str,
+) {
+    if let (Some(orig), Some(trans)) = (original_tokens, transformed_tokens) {
+        let stats = tokens::TokenStats::estimated(orig, trans);
+        eprintln!("\n[skim] {}{}", stats.format(), suffix);
+    }
+}
+
 /// Write a single-input result to stdout and optionally report token stats to stderr.
 ///
 /// Used by both `process_stdin` and the single-file path in `main()`.
@@ -158,7 +271,12 @@ pub(crate) fn write_result_and_stats(
     writer.flush()?;
 
     if show_stats {
-        report_token_stats(result.original_tokens, result.transformed_tokens, "");
+        let report = if result.tokens_estimated {
+            report_estimated_token_stats
+        } else {
+            report_token_stats
+        };
+        report(result.original_tokens, result.transformed_tokens, "");
     }
 
     Ok(())
@@ -177,8 +295,16 @@ fn try_cached_result(
         return Ok(None);
     }
 
-    let Some(hit) = cache::read_cache(path, options.mode, &options.trunc, options.line_numbers)
-    else {
+    let hit = cache::read_cache(
+        path,
+        options.cache_keys,
+        options.mode,
+        &options.trunc,
+        options.line_numbers,
+        &options.flags,
+    );
+    usage::record_cache_lookup(hit.is_some());
+    let Some(hit) = hit else {
         return Ok(None);
     };
 
@@ -186,27 +312,51 @@ fn try_cached_result(
     // file and count tokens for both source and output -- but only when
     // --show-stats is active. Analytics background threads handle their own
     // token counting, so we don't erode cache speedup for analytics alone.
-    let needs_recount = hit.original_tokens.is_none() && options.show_stats;
-    let (orig_tokens, trans_tokens) = if needs_recount {
-        let contents = read_and_validate(path)?;
-        count_token_pair(&contents, &hit.content)
-    } else {
-        (hit.original_tokens, hit.transformed_tokens)
-    };
-
     // Effective language for a cache hit: explicit override wins, else detect from path.
     let cache_lang = options
         .explicit_lang
         .or_else(|| detect_language_from_path(path));
 
+    // Exact counts stored in the entry are free, so they win even under --stats-fast.
+    let needs_recount = hit.original_tokens.is_none() && options.show_stats;
+    let tokens_estimated = needs_recount && options.stats_fast;
+    let (orig_tokens, trans_tokens) = if tokens_estimated {
+        // Estimation only needs the source length: no re-read.
+        let source_len = fs::metadata(path)?.len() as usize;
+        (
+            Some(tokens::estimate_tokens_from_len(source_len, cache_lang)),
+            Some(tokens::estimate_tokens(&hit.content, cache_lang)),
+        )
+    } else if needs_recount {
+        // The source count is mode-independent: reuse it and skip the re-read.
+        match cache::read_original_tokens(path, options.cache_keys) {
+            Some(original) => pair_with_original(original, &hit.content),
+            None => {
+                let contents = read_and_validate(path)?;
+                count_file_token_pair(
+                    path,
+                    &contents,
+                    &hit.content,
+                    None,
+                    true,
+                    options.cache_keys,
+                )
+            }
+        }
+    } else {
+        (hit.original_tokens, hit.transformed_tokens)
+    };
+
     Ok(Some(ProcessResult {
         output: hit.content,
         original_tokens: orig_tokens,
         transformed_tokens: trans_tokens,
+        tokens_estimated,
         guardrail_triggered: false,
         parse_tier: None, // tier was not recorded at cache-write time
         language: cache_lang,
         stdin_raw: None,
+        transform_duration: None,
     }))
 }
 
@@ -267,6 +417,7 @@ fn run_transform(
     let explicit_lang = options.explicit_lang;
     // Non-line-number transform closure (used for cascade mode selection)
     let transform_file = |config: &TransformConfig| -> anyhow::Result<Option<String>> {
+        let config = &options.flags.apply(config.clone());
         // Try auto-detection first; fall back to explicit language if provided.
         let auto_result = transform_auto_with_config(contents, path, config);
         if let Ok(output) = auto_result {
@@ -275,7 +426,9 @@ fn run_transform(
         let Some(language) = explicit_lang else {
             return Err(auto_result.unwrap_err().into());
         };
-        Ok(Some(transform_with_config(contents, language, config)?))
+        Ok(Some(transform_with_config(
+            contents, path, language, config,
+        )?))
     };
 
     match options.trunc.token_budget {
@@ -303,9 +456,13 @@ fn run_transform(
             // If line numbers requested, re-run the selected mode WITH line_numbers.
             // Use the re-run output directly as the final output (avoids double transform).
             let (final_output, line_map) = if options.line_numbers {
-                let config = cascade::build_config_with_opts(mode, &options.trunc, true);
+                let config = options.flags.apply(cascade::build_config_with_opts(
+                    mode,
+                    &options.trunc,
+                    true,
+                ));
                 let (rerun_output, _has_errors, map, _degraded) =
-                    transform_with_line_map(contents, language, &config)?;
+                    transform_with_line_map(contents, path, language, &config)?;
                 (rerun_output, map)
             } else {
                 (output, None)
@@ -318,13 +475,13 @@ fn run_transform(
 
             // Use transform_with_line_map when we can identify the language
             if let Some(lang) = language {
-                let config = cascade::build_config_with_opts(
+                let config = options.flags.apply(cascade::build_config_with_opts(
                     options.mode,
                     &options.trunc,
                     options.line_numbers,
-                );
+                ));
                 let (output, has_errors, line_map, degraded) =
-                    transform_with_line_map(contents, lang, &config)?;
+                    transform_with_line_map(contents, path, lang, &config)?;
                 Ok((output, options.mode, has_errors, line_map, degraded))
             } else {
                 // Language detection failed — try auto-detect via path extension.
@@ -339,6 +496,51 @@ fn run_transform(
     }
 }
 
+/// Build the result for `--format ast`: a sanitized AST dump of `source`.
+///
+/// Dumps are never cached, counted, or guarded: they are a debugging view of
+/// the parse, not a compression of the file.
+fn ast_dump_result(
+    source: &str,
+    language: Language,
+    format: AstFormat,
+) -> anyhow::Result<ProcessResult> {
+    Ok(ProcessResult {
+        output: rskim_core::dump_ast(source, language, format)?,
+        original_tokens: None,
+        transformed_tokens: None,
+        tokens_estimated: false,
+        guardrail_triggered: false,
+        parse_tier: None,
+        language: Some(language),
+        stdin_raw: None,
+        transform_duration: None,
+    })
+}
+
+/// Build the result for `--format ctags|symbols`: the symbols of `source`,
+/// one line each, labelled with `path`.
+///
+/// Like AST dumps, listings are never cached, counted, or guarded.
+fn tags_result(
+    path: &str,
+    source: &str,
+    language: Language,
+    format: TagFormat,
+) -> anyhow::Result<ProcessResult> {
+    Ok(ProcessResult {
+        output: tags::render(path, source, language, format)?,
+        original_tokens: None,
+        transformed_tokens: None,
+        tokens_estimated: false,
+        guardrail_triggered: false,
+        parse_tier: None,
+        language: Some(language),
+        stdin_raw: None,
+        transform_duration: None,
+    })
+}
+
 /// Process stdin input and return transformed content with optional token statistics.
 ///
 /// Reads from stdin with a size limit, resolves the language from `--language` or
@@ -363,12 +565,14 @@ pub(crate) fn process_stdin(
     }
 
     let filename_lang = filename_hint.and_then(|f| Language::from_path(Path::new(f)));
+    // Label for `--record` cases.
+    let label = Path::new(filename_hint.unwrap_or("-"));
 
     let language = options.explicit_lang.or(filename_lang).ok_or_else(|| {
         if let Some(fname) = filename_hint {
             anyhow::anyhow!(
                 "Language detection failed: unrecognized filename '{}'\n\
-                 Supported extensions: .ts, .tsx, .js, .jsx, .cjs, .mjs, .py, .rs, .go, .java, .c, .h, .cpp, .hpp, .cxx, .cc, .md, .json, .yaml, .yml, .toml\n\
+                 Supported extensions: .ts, .tsx, .js, .jsx, .cjs, .mjs, .py, .rs, .go, .java, .c, .h, .cpp, .hpp, .cxx, .cc, .md, .json, .yaml, .yml, .toml, .vue, .svelte\n\
                  Hint: use --language to specify the language explicitly\n\
                  Example: cat file | skim - --language=typescript",
                 fname
@@ -382,38 +586,59 @@ pub(crate) fn process_stdin(
         }
     })?;
 
-    let (transformed, stdin_has_errors, stdin_line_map, stdin_degraded) = match options
-        .trunc
-        .token_budget
-    {
-        Some(budget) => {
-            // AC-10: Cascade mode selection without line numbers, then re-run with line numbers
-            let (output, mode) = cascade::cascade_for_token_budget(
-                options.mode,
-                &options.trunc,
-                budget,
-                language,
-                |config| Ok(Some(transform_with_config(&buffer, language, config)?)),
-            )?;
-            // Use the re-run output directly as the final output (avoids double transform).
-            let (cascade_output, line_map) = if options.line_numbers {
-                let config = cascade::build_config_with_opts(mode, &options.trunc, true);
-                let (rerun, _errs, map, _degraded) =
-                    transform_with_line_map(&buffer, language, &config)?;
-                (rerun, map)
-            } else {
-                (output, None)
-            };
-            (cascade_output, false, line_map, false) // cascade path: degraded signal N/A
-        }
-        None => {
-            let config =
-                cascade::build_config_with_opts(options.mode, &options.trunc, options.line_numbers);
-            let (output, has_errors, line_map, degraded) =
-                transform_with_line_map(&buffer, language, &config)?;
-            (output, has_errors, line_map, degraded)
-        }
-    };
+    if let Some(format) = options.ast_format {
+        return ast_dump_result(&buffer, language, format);
+    }
+    if let Some(format) = options.tag_format {
+        // Labelled like Markdown sections: the filename hint, else `stdin`.
+        return tags_result(filename_hint.unwrap_or("stdin"), &buffer, language, format);
+    }
+    warn_mode_caveat(language, options.mode);
+
+    let started = Instant::now();
+    let (transformed, stdin_has_errors, stdin_line_map, stdin_degraded) =
+        match options.trunc.token_budget {
+            Some(budget) => {
+                // AC-10: Cascade mode selection without line numbers, then re-run with line numbers
+                let (output, mode) = cascade::cascade_for_token_budget(
+                    options.mode,
+                    &options.trunc,
+                    budget,
+                    language,
+                    |config| {
+                        let config = options.flags.apply(config.clone());
+                        Ok(Some(transform_with_config(
+                            &buffer, label, language, &config,
+                        )?))
+                    },
+                )?;
+                // Use the re-run output directly as the final output (avoids double transform).
+                let (cascade_output, line_map) = if options.line_numbers || options.markdown {
+                    let config = options.flags.apply(cascade::build_config_with_opts(
+                        mode,
+                        &options.trunc,
+                        true,
+                    ));
+                    let (rerun, _errs, map, _degraded) =
+                        transform_with_line_map(&buffer, label, language, &config)?;
+                    (rerun, map)
+                } else {
+                    (output, None)
+                };
+                (cascade_output, false, line_map, false) // cascade path: degraded signal N/A
+            }
+            None => {
+                let config = options.flags.apply(cascade::build_config_with_opts(
+                    options.mode,
+                    &options.trunc,
+                    options.line_numbers || options.markdown,
+                ));
+                let (output, has_errors, line_map, degraded) =
+                    transform_with_line_map(&buffer, label, language, &config)?;
+                (output, has_errors, line_map, degraded)
+            }
+        };
+    let transform_duration = started.elapsed();
 
     // Emit notice when SKIM_DEBUG=1 and the transform degraded to passthrough due to a
     // structural safety cap. The notice goes to stderr to avoid polluting stdout output.
@@ -435,39 +660,79 @@ pub(crate) fn process_stdin(
     // Apply output guardrail: if compressed output is larger than raw, emit raw instead.
     // Same protection as process_file; token counting happens after so stats reflect
     // the final output. Guardrail comparison uses UN-annotated output.
-    let (final_output, guardrail_triggered) =
-        if options.mode != Mode::Full && options.trunc.token_budget.is_none() {
-            let outcome = crate::output::guardrail::apply_to_stderr(buffer.clone(), transformed)?;
-            let triggered = outcome.was_triggered();
-            (outcome.into_output(), triggered)
-        } else {
-            (transformed, false)
-        };
+    // Redacted formats (.env) never fall back to raw.
+    let (final_output, guardrail_triggered) = if options.mode != Mode::Full
+        && options.trunc.token_budget.is_none()
+        && !language.is_redacted()
+    {
+        let outcome = crate::output::guardrail::apply_to_stderr(buffer.clone(), transformed)?;
+        let triggered = outcome.was_triggered();
+        (outcome.into_output(), triggered)
+    } else {
+        (transformed, false)
+    };
+
+    if let Some(action) = options.validate_output
+        && !guardrail_triggered
+    {
+        check_output(
+            label,
+            &buffer,
+            &final_output,
+            language,
+            action,
+            &mut io::stderr(),
+        )?;
+    }
+    let final_output = if options.format_output && !guardrail_triggered {
+        crate::output::reformat::reformat(&final_output, label, Some(language), &mut io::stderr())?
+    } else {
+        final_output
+    };
 
     // Apply line number formatting AFTER guardrail, BEFORE token stats.
-    let final_output = apply_line_numbers(
-        final_output,
-        options.line_numbers,
-        guardrail_triggered,
-        stdin_line_map,
-    );
+    let final_output = if options.markdown {
+        apply_markdown(
+            final_output,
+            filename_hint.unwrap_or("stdin"),
+            Some(language),
+            &buffer,
+            guardrail_triggered,
+            stdin_line_map,
+        )
+    } else {
+        apply_line_numbers(
+            final_output,
+            options.line_numbers,
+            guardrail_triggered,
+            stdin_line_map,
+        )
+    };
 
     // Only pay the tiktoken BPE cost on the main thread when --show-stats
     // is set. Analytics background threads compute their own token counts.
-    let (orig_tokens, trans_tokens) = if options.show_stats {
+    let tokens_estimated = options.show_stats && options.stats_fast;
+    let (orig_tokens, trans_tokens) = if tokens_estimated {
+        (
+            Some(tokens::estimate_tokens(&buffer, Some(language))),
+            Some(tokens::estimate_tokens(&final_output, Some(language))),
+        )
+    } else if options.show_stats {
         count_token_pair(&buffer, &final_output)
     } else {
         (None, None)
     };
 
     // Retain the raw buffer for analytics background tokenization only when
-    // counts are not already known (i.e. !show_stats). Stdin cannot be re-read,
-    // so the buffer must travel with the result.
+    // exact counts are not already known (!show_stats, or --stats-fast
+    // estimates). Stdin cannot be re-read, so the buffer must travel with the
+    // result.
     //
-    // Invariant: stdin_raw is Some iff !show_stats; orig_tokens/trans_tokens are
-    // Some iff show_stats (when the tokenizer is available). These two conditions
-    // are mutually exclusive by construction: show_stats drives count_token_pair
-    // above, and its negation drives stdin_raw here.
+    // Invariant: stdin_raw is Some iff exact counts were not computed;
+    // orig_tokens/trans_tokens are Some iff show_stats (when the tokenizer is
+    // available). Without --stats-fast these two conditions are mutually
+    // exclusive by construction: show_stats drives count_token_pair above, and
+    // its negation drives stdin_raw here.
     //
     // The assert pins the always-guaranteed half: if we are NOT running show_stats,
     // counts must be None (we never computed them). The reverse (show_stats → Some)
@@ -478,32 +743,158 @@ pub(crate) fn process_stdin(
          token counts must not be present when show_stats is false \
          (stdin_raw invariant violated)"
     );
-    let stdin_raw = if !options.show_stats {
+    let stdin_raw = if !options.show_stats || tokens_estimated {
         Some(buffer)
     } else {
         None
     };
 
+    usage::record_files(1);
     Ok(ProcessResult {
         output: final_output,
         original_tokens: orig_tokens,
         transformed_tokens: trans_tokens,
+        tokens_estimated,
         guardrail_triggered,
         parse_tier,
         language: Some(language),
         stdin_raw,
+        transform_duration: Some(transform_duration),
     })
 }
 
+/// [`warn_mode_caveat`] for the language `path` is transformed as.
+fn warn_mode_caveat_for(path: &Path, options: &ProcessOptions) {
+    if let Some(language) = options
+        .explicit_lang
+        .or_else(|| detect_language_from_path(path))
+    {
+        warn_mode_caveat(language, options.mode);
+    }
+}
+
+/// Warn on stderr when `mode` is ignored or nearly empty for `language`.
+///
+/// Emitted at most once per language per run so multi-file and glob runs
+/// do not repeat the same note for every file.
+fn warn_mode_caveat(language: Language, mode: Mode) {
+    static WARNED: Mutex<Vec<Language>> = Mutex::new(Vec::new());
+
+    let Some(caveat) = language.mode_caveat(mode) else {
+        return;
+    };
+    // A poisoned lock only means another thread panicked mid-push; the list is
+    // still usable.
+    let mut warned = WARNED.lock().unwrap_or_else(|e| e.into_inner());
+    if warned.contains(&language) {
+        return;
+    }
+    warned.push(language);
+    eprintln!(
+        "[skim] warning: --mode {} on {}: {caveat}",
+        mode.name(),
+        language.name()
+    );
+}
+
 /// Process a single file and return transformed content with optional token statistics.
 pub(crate) fn process_file(path: &Path, options: ProcessOptions) -> anyhow::Result<ProcessResult> {
-    if let Some(result) = try_cached_result(path, &options)? {
-        return Ok(result);
+    if options.ast_format.is_none() && options.tag_format.is_none() {
+        // Warn before the cache lookup so cache hits report caveats too.
+        warn_mode_caveat_for(path, &options);
+        if let Some(result) = try_cached_result(path, &options)? {
+            usage::record_files(1);
+            return Ok(result);
+        }
     }
 
     let contents = read_and_validate(path)?;
-    let (result, mode_used, has_errors, line_map, degraded) =
-        run_transform(&contents, path, &options)?;
+    let result = process_contents(path, contents, options)?;
+    usage::record_files(1);
+    Ok(result)
+}
+
+/// Run one file's processing, turning a panic into an error for that file.
+///
+/// Grammar upgrades occasionally bring a panic on some input. Batch paths run
+/// each file under this boundary so the file is reported as failed, with the
+/// panic message, instead of the panic aborting a multi-thousand-file run.
+pub(crate) fn isolate_panics(
+    process: impl FnOnce() -> anyhow::Result<ProcessResult>,
+) -> anyhow::Result<ProcessResult> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(process)).unwrap_or_else(|payload| {
+        Err(anyhow::anyhow!(
+            "panicked while processing: {}",
+            panic_message(payload.as_ref())
+        ))
+    })
+}
+
+/// The message of a caught panic (`panic!` payloads are `&str` or `String`).
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload")
+}
+
+/// Transform already-read `contents` of `path`, bypassing the cache lookup.
+///
+/// The second half of [`process_file`], split out so callers that must
+/// transform exactly the bytes they read (`--inputs-manifest` hashes them
+/// first) do not read the file twice. The cache is still written when
+/// `options.use_cache` is set.
+pub(crate) fn process_contents(
+    path: &Path,
+    contents: String,
+    options: ProcessOptions,
+) -> anyhow::Result<ProcessResult> {
+    if options.ast_format.is_some() || options.tag_format.is_some() {
+        let language = options
+            .explicit_lang
+            .or_else(|| detect_language_from_path(path))
+            .ok_or_else(|| SkimError::UnsupportedLanguage(path.to_path_buf()))?;
+        if let Some(format) = options.ast_format {
+            return ast_dump_result(&contents, language, format);
+        }
+        if let Some(format) = options.tag_format {
+            let label = path.display().to_string();
+            return tags_result(&label, &contents, language, format);
+        }
+    }
+
+    // No-op when `process_file` already warned for this language.
+    warn_mode_caveat_for(path, &options);
+
+    // Markdown sections are split by source line, so they need the line map too.
+    let transform_options = ProcessOptions {
+        line_numbers: options.line_numbers || options.markdown,
+        ..options
+    };
+    // Effective language for markdown, analytics and estimates: explicit
+    // override wins, else detect from path.
+    let effective_lang = options
+        .explicit_lang
+        .or_else(|| detect_language_from_path(path));
+
+    // `--precompute-modes` shares the parse between the requested mode and the
+    // others; cascade runs pick their own mode, so they transform alone.
+    let precompute_lang = effective_lang.filter(|_| {
+        options.precompute_modes && options.use_cache && options.trunc.token_budget.is_none()
+    });
+    let started = Instant::now();
+    let (transformed, precomputed) = match precompute_lang
+        .and_then(|language| transform_all_modes(&contents, language, &transform_options).ok())
+    {
+        Some(all_modes) => all_modes,
+        None => (
+            run_transform(&contents, path, &transform_options)?,
+            Vec::new(),
+        ),
+    };
+    let transform_duration = started.elapsed();
+    let (result, mode_used, has_errors, line_map, degraded) = transformed;
 
     // Emit notice when SKIM_DEBUG=1 and the transform degraded to passthrough due to a
     // structural safety cap. The notice goes to stderr to avoid polluting stdout output.
@@ -519,31 +910,38 @@ pub(crate) fn process_file(path: &Path, options: ProcessOptions) -> anyhow::Resu
     // but the parse tier reflects the transformation, not the final selection).
     let parse_tier = Some(parse_tier_from(options.mode, has_errors, degraded));
 
-    // Apply output guardrail: if compressed output is larger than raw, emit raw instead.
-    // Token counting happens AFTER this decision so stats reflect the final output.
-    // Guardrail comparison uses UN-annotated output (before line number formatting).
-    let (final_output, guardrail_triggered) =
-        if options.mode != Mode::Full && options.trunc.token_budget.is_none() {
-            let outcome = crate::output::guardrail::apply_to_stderr(contents.clone(), result)?;
-            let triggered = outcome.was_triggered();
-            (outcome.into_output(), triggered)
-        } else {
-            (result, false)
-        };
-
-    // Apply line number formatting AFTER guardrail, BEFORE cache write and token stats.
-    // AC-12: Cache key includes line_numbers (handled in cache::read_cache/write_cache).
-    let final_output = apply_line_numbers(
-        final_output,
-        options.line_numbers,
-        guardrail_triggered,
+    let (final_output, guardrail_triggered) = finish_output(
+        path,
+        &contents,
+        &options,
+        options.mode,
+        result,
         line_map,
-    );
+        &mut io::stderr(),
+    )?;
 
     // Only pay the tiktoken BPE cost on the main thread when --show-stats
     // is set. Analytics background threads compute their own token counts.
-    let (orig_tokens, trans_tokens) = if options.show_stats {
-        count_token_pair(&contents, &final_output)
+
+    let tokens_estimated = options.show_stats && options.stats_fast;
+    let (orig_tokens, trans_tokens) = if tokens_estimated {
+        (
+            Some(tokens::estimate_tokens(&contents, effective_lang)),
+            Some(tokens::estimate_tokens(&final_output, effective_lang)),
+        )
+    } else if options.show_stats {
+        let cached_original = options
+            .use_cache
+            .then(|| cache::read_original_tokens(path, options.cache_keys))
+            .flatten();
+        count_file_token_pair(
+            path,
+            &contents,
+            &final_output,
+            cached_original,
+            options.use_cache,
+            options.cache_keys,
+        )
     } else {
         (None, None)
     };
@@ -552,35 +950,239 @@ pub(crate) fn process_file(path: &Path, options: ProcessOptions) -> anyhow::Resu
     // Cache write failures are non-fatal; don't fail the transformation.
     if options.use_cache {
         let effective_mode = (mode_used != options.mode).then_some(mode_used);
-        let _ = cache::write_cache(&cache::CacheWriteParams {
+        let written = cache::write_cache(&cache::CacheWriteParams {
             path,
+            keys: options.cache_keys,
             mode: options.mode,
             content: &final_output,
-            original_tokens: orig_tokens,
-            transformed_tokens: trans_tokens,
+            // Estimates are display-only; never persist them as counts.
+            original_tokens: orig_tokens.filter(|_| !tokens_estimated),
+            transformed_tokens: trans_tokens.filter(|_| !tokens_estimated),
             trunc: options.trunc,
             effective_mode,
             parse_tier: parse_tier.map(str::to_string),
             line_numbers: options.line_numbers,
+            flags: options.flags,
         });
+        if let Err(e) = written {
+            crate::debug_log!(
+                "[skim] notice: output not cached for {}: {e:#}",
+                path.display()
+            );
+        }
     }
 
-    // Effective language for analytics: explicit override wins, else detect from path.
-    let effective_lang = options
-        .explicit_lang
-        .or_else(|| detect_language_from_path(path));
+    for (mode, (output, has_errors, line_map, degraded)) in precomputed {
+        cache_precomputed_mode(
+            path,
+            &contents,
+            &options,
+            mode,
+            output,
+            parse_tier_from(mode, has_errors, degraded),
+            line_map,
+        );
+    }
 
     Ok(ProcessResult {
         output: final_output,
         original_tokens: orig_tokens,
         transformed_tokens: trans_tokens,
+        tokens_estimated,
         guardrail_triggered,
         parse_tier,
         language: effective_lang,
         stdin_raw: None,
+        transform_duration: Some(transform_duration),
     })
 }
 
+/// Output guardrail, then line numbers or Markdown sections: everything
+/// between the core transform of `mode` and the output that is cached.
+///
+/// Returns the final output and whether the guardrail swapped in the raw
+/// source; guardrail warnings go to `warnings`.
+fn finish_output(
+    path: &Path,
+    contents: &str,
+    options: &ProcessOptions,
+    mode: Mode,
+    result: String,
+    line_map: Option<Vec<usize>>,
+    warnings: &mut impl Write,
+) -> anyhow::Result<(String, bool)> {
+    // Apply output guardrail: if compressed output is larger than raw, emit raw instead.
+    // Token counting happens AFTER this decision so stats reflect the final output.
+    // Guardrail comparison uses UN-annotated output (before line number formatting).
+    // Redacted formats (.env) never fall back to raw.
+    let effective_lang = options
+        .explicit_lang
+        .or_else(|| detect_language_from_path(path));
+    let redacted = effective_lang.is_some_and(Language::is_redacted);
+    let (final_output, guardrail_triggered) =
+        if mode != Mode::Full && options.trunc.token_budget.is_none() && !redacted {
+            let outcome = crate::output::guardrail::apply(contents.to_string(), result, warnings)?;
+            let triggered = outcome.was_triggered();
+            (outcome.into_output(), triggered)
+        } else {
+            (result, false)
+        };
+
+    // Validate the core output, before annotations; raw fallbacks are the
+    // source itself and need no check.
+    if let (Some(action), Some(language)) = (options.validate_output, effective_lang)
+        && !guardrail_triggered
+    {
+        check_output(path, contents, &final_output, language, action, warnings)?;
+    }
+    let final_output = if options.format_output && !guardrail_triggered {
+        crate::output::reformat::reformat(&final_output, path, effective_lang, warnings)?
+    } else {
+        final_output
+    };
+
+    // Apply line number formatting AFTER guardrail, BEFORE cache write and token stats.
+    // AC-12: Cache key includes line_numbers (handled in cache::read_cache/write_cache).
+    let final_output = if options.markdown {
+        apply_markdown(
+            final_output,
+            &path.display().to_string(),
+            effective_lang,
+            contents,
+            guardrail_triggered,
+            line_map,
+        )
+    } else {
+        apply_line_numbers(
+            final_output,
+            options.line_numbers,
+            guardrail_triggered,
+            line_map,
+        )
+    };
+    Ok((final_output, guardrail_triggered))
+}
+
+/// `--validate-output`: re-parse `output` and act on syntax errors that
+/// `source` did not have.
+///
+/// Languages without a tree-sitter grammar have no validator and pass.
+fn check_output(
+    path: &Path,
+    source: &str,
+    output: &str,
+    language: Language,
+    action: ValidateOutput,
+    warnings: &mut impl Write,
+) -> anyhow::Result<()> {
+    let Some(report) = rskim_core::validate_output(source, output, language)? else {
+        return Ok(());
+    };
+    let introduced = report.introduced_errors();
+    if introduced == 0 {
+        return Ok(());
+    }
+    let problem = format!(
+        "output has {introduced} syntax error(s) not in the source (first at output line {})",
+        report.first_error_line.unwrap_or(1)
+    );
+    match action {
+        ValidateOutput::Fail => anyhow::bail!("{problem}"),
+        ValidateOutput::Warn => {
+            writeln!(warnings, "[skim] warning: {}: {problem}", path.display())?;
+            Ok(())
+        }
+    }
+}
+
+/// Core output of one mode from [`transform_all_modes`]:
+/// `(output, has_errors, source_line_map, degraded)`.
+type ModeOutput = (String, bool, Option<Vec<usize>>, bool);
+
+/// Transform `contents` in the requested mode and every other mode, parsing once.
+///
+/// Returns the requested mode in [`run_transform`]'s shape, plus the other
+/// modes for [`cache_precomputed_mode`]. Errors when any mode fails, so the
+/// caller can fall back to transforming the requested mode alone.
+fn transform_all_modes(
+    contents: &str,
+    language: Language,
+    options: &ProcessOptions,
+) -> anyhow::Result<(RunTransformOutput, Vec<(Mode, ModeOutput)>)> {
+    // Query mode runs only the query given with --query, which requires
+    // --mode query: there is nothing to precompute for it otherwise.
+    let modes: Vec<Mode> = std::iter::once(options.mode)
+        .chain(
+            Mode::ALL
+                .into_iter()
+                .filter(|&mode| mode != options.mode && mode != Mode::Query),
+        )
+        .collect();
+    let configs: Vec<TransformConfig> = modes
+        .iter()
+        .map(|&mode| {
+            options.flags.apply(cascade::build_config_with_opts(
+                mode,
+                &options.trunc,
+                options.line_numbers,
+            ))
+        })
+        .collect();
+    let outputs = rskim_core::transform_many_with_line_map(contents, language, &configs)?;
+
+    let mut others: Vec<(Mode, ModeOutput)> = modes.into_iter().zip(outputs).collect();
+    let (mode, (output, has_errors, line_map, degraded)) = others.remove(0);
+    Ok(((output, mode, has_errors, line_map, degraded), others))
+}
+
+/// Finish and cache one mode computed by `--precompute-modes`.
+///
+/// Mirrors the requested mode's path so a later run with `--mode <mode>` hits
+/// an identical entry. Guardrail warnings are dropped (nothing is printed for
+/// a mode nobody asked for) and token counts are left to that later run.
+fn cache_precomputed_mode(
+    path: &Path,
+    contents: &str,
+    options: &ProcessOptions,
+    mode: Mode,
+    output: String,
+    parse_tier: &str,
+    line_map: Option<Vec<usize>>,
+) {
+    let finished = finish_output(
+        path,
+        contents,
+        options,
+        mode,
+        output,
+        line_map,
+        &mut io::sink(),
+    );
+    let Ok((content, _guardrail_triggered)) = finished else {
+        return;
+    };
+    let written = cache::write_cache(&cache::CacheWriteParams {
+        path,
+        keys: options.cache_keys,
+        mode,
+        content: &content,
+        original_tokens: None,
+        transformed_tokens: None,
+        trunc: options.trunc,
+        effective_mode: None,
+        parse_tier: Some(parse_tier.to_string()),
+        line_numbers: options.line_numbers,
+        flags: options.flags,
+    });
+    if let Err(e) = written {
+        crate::debug_log!(
+            "[skim] notice: {} output not cached for {}: {e:#}",
+            mode.name(),
+            path.display()
+        );
+    }
+}
+
 /// Read a file and validate it doesn't exceed the maximum input size.
 ///
 /// Public thin wrapper over `read_and_validate` for use by the background
@@ -598,6 +1200,27 @@ mod tests {
     // count_token_pair tests
     // ========================================================================
 
+    // ========================================================================
+    // isolate_panics tests
+    // ========================================================================
+
+    #[test]
+    fn isolate_panics_turns_panic_into_error() {
+        let err = isolate_panics(|| panic!("grammar exploded on line {}", 7)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "panicked while processing: grammar exploded on line 7"
+        );
+        let err = isolate_panics(|| panic!("static message")).unwrap_err();
+        assert!(err.to_string().ends_with("static message"), "{err}");
+    }
+
+    #[test]
+    fn isolate_panics_passes_results_through() {
+        let err = isolate_panics(|| Err(anyhow::anyhow!("plain failure"))).unwrap_err();
+        assert_eq!(err.to_string(), "plain failure");
+    }
+
     #[test]
     fn count_token_pair_returns_some_for_valid_input() {
         let (orig, trans) = count_token_pair("hello world", "hello");
@@ -632,13 +1255,14 @@ mod tests {
         // Should be a no-op when tokens are None
         report_token_stats(None, None, "");
         report_token_stats(Some(100), None, "");
-        report_token_stats(None, Some(50), "");
+        report_estimated_token_stats(None, Some(50), "");
     }
 
     #[test]
     fn report_token_stats_does_not_panic_with_valid_values() {
         // Should write to stderr without panicking
         report_token_stats(Some(1000), Some(200), " (test)");
+        report_estimated_token_stats(Some(1000), Some(200), " (test)");
     }
 
     // ========================================================================
@@ -758,4 +1382,55 @@ mod tests {
             "content must survive annotation"
         );
     }
+
+    // ========================================================================
+    // Source files failing mid-run
+    // ========================================================================
+
+    fn text_options(use_cache: bool) -> ProcessOptions {
+        ProcessOptions {
+            mode: Mode::Structure,
+            explicit_lang: None,
+            use_cache,
+            cache_keys: CacheKeys::default(),
+            precompute_modes: false,
+            show_stats: false,
+            stats_fast: false,
+            trunc: TruncationOptions::default(),
+            line_numbers: false,
+            flags: TransformFlags::default(),
+            ast_format: None,
+            tag_format: None,
+            markdown: false,
+            validate_output: None,
+            format_output: false,
+        }
+    }
+
+    /// A file listed by the walker but deleted before it is processed fails
+    /// that file only, with the OS error, and leaves nothing in the cache.
+    #[test]
+    fn process_file_reports_vanished_file_as_error() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("gone.ts");
+        fs::write(&path, "function gone() { return 1; }").unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let err = process_file(&path, text_options(true)).unwrap_err();
+        let not_found = err
+            .downcast_ref::<io::Error>()
+            .is_some_and(|e| e.kind() == io::ErrorKind::NotFound);
+        assert!(not_found, "expected NotFound, got: {err:#}");
+    }
+
+    /// A file that is unreadable (invalid UTF-8 stands in for permission
+    /// errors, which root ignores) is an error, not a silent empty output.
+    #[test]
+    fn process_file_reports_unreadable_file_as_error() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("binary.ts");
+        fs::write(&path, b"function f() {}\xff\xfe").unwrap();
+
+        assert!(process_file(&path, text_options(false)).is_err());
+    }
 }
@@ -0,0 +1,226 @@
+//! Pure mode (`--inputs-manifest`): skim as a cacheable build action.
+//!
+//! Build systems such as Bazel and Nix run tools as actions whose outputs are
+//! a function of their declared inputs only. In pure mode the input files come
+//! from a manifest instead of discovery, the combined output is written to
+//! `--output`, and a content hash of the inputs is printed on stdout so the
+//! action can be keyed or verified.
+//!
+//! Pure mode implies `--hermetic` (no cache, analytics, or session lookup). It
+//! never walks directories, expands globs, or applies ignore rules: every entry
+//! is read exactly as listed, and any entry that cannot be read or transformed
+//! fails the run before the output is written. The output is byte-identical to
+//! `skim --no-cache <inputs...>` with the inputs in manifest order.
+//!
+//! # Manifest format
+//!
+//! One path per line, absolute or relative to the working directory. Blank
+//! lines and lines starting with `#` are ignored; surrounding whitespace is trimmed.
+//!
+//! # Inputs hash
+//!
+//! `sha256:<hex>` over each entry in manifest order: the path as written, a NUL
+//! byte, the content length in decimal, a NUL byte, then the content. Only the
+//! inputs are hashed — flags and the skim version are part of the action's
+//! command line, which build systems key separately.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::multi::{self, MultiFileOptions, SectionLayout};
+use crate::output::footer;
+use crate::process::{self, report_estimated_token_stats, report_token_stats};
+
+/// Run pure mode: transform the files listed in `manifest` into `output` and
+/// print the inputs hash.
+pub(crate) fn run_pure(
+    manifest: &Path,
+    output: &Path,
+    options: &MultiFileOptions,
+) -> anyhow::Result<()> {
+    let text = fs::read_to_string(manifest).map_err(|e| {
+        anyhow::anyhow!("failed to read inputs manifest {}: {e}", manifest.display())
+    })?;
+    let paths = parse_manifest(&text)?;
+    if paths.is_empty() {
+        anyhow::bail!("inputs manifest {} lists no files", manifest.display());
+    }
+
+    let inputs = paths
+        .into_iter()
+        .map(|path| {
+            if path.is_dir() {
+                anyhow::bail!(
+                    "'{}' is a directory\n\
+                     --inputs-manifest performs no discovery; list each file.",
+                    path.display()
+                );
+            }
+            let contents = process::read_source(&path)
+                .map_err(|e| anyhow::anyhow!("{}: {e:#}", path.display()))?;
+            Ok((path, contents))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let hash = inputs_hash(&inputs);
+
+    let paths: Vec<PathBuf> = inputs.iter().map(|(path, _)| path.clone()).collect();
+    let num_jobs = options.jobs.unwrap_or_else(|| multi::default_jobs(&paths));
+    let process_options = options.process;
+    let results = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_jobs)
+        .build()?
+        .install(|| {
+            inputs
+                .into_par_iter()
+                .map(|(path, contents)| {
+                    process::isolate_panics(|| {
+                        process::process_contents(&path, contents, process_options)
+                    })
+                    .map_err(|e| anyhow::anyhow!("{}: {e:#}", path.display()))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        })?;
+    crate::usage::record_files(results.len());
+
+    let layout = SectionLayout::new(options, paths.len());
+    let mut rendered = Vec::new();
+    let (mut original_tokens, mut transformed_tokens, mut estimated) = (0, 0, false);
+    for (idx, (path, result)) in paths.iter().zip(&results).enumerate() {
+        if !options.is_bundled() {
+            layout.write(&mut rendered, idx, path, &result.output)?;
+        }
+        if let (Some(orig), Some(trans)) = (result.original_tokens, result.transformed_tokens) {
+            original_tokens += orig;
+            transformed_tokens += trans;
+            estimated |= result.tokens_estimated;
+        }
+    }
+    if options.is_bundled() {
+        let bundle: Vec<_> = paths.iter().map(PathBuf::as_path).zip(&results).collect();
+        rendered = multi::render_bundle(options, &bundle)?.into_bytes();
+    }
+    if options.footer {
+        let footer = footer::render(&String::from_utf8_lossy(&rendered), results.len());
+        rendered.extend_from_slice(footer.as_bytes());
+    }
+    write_atomically(output, &rendered)?;
+
+    println!("sha256:{hash}");
+    if process_options.show_stats && original_tokens > 0 {
+        let suffix = format!(" across {} file(s)", results.len());
+        let report = if estimated {
+            report_estimated_token_stats
+        } else {
+            report_token_stats
+        };
+        report(Some(original_tokens), Some(transformed_tokens), &suffix);
+    }
+    Ok(())
+}
+
+/// Parse manifest text into input paths, in order.
+///
+/// Rejects stdin (`-`) and duplicate entries: both would make the output
+/// depend on something other than the listed files.
+fn parse_manifest(text: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        let entry = line.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+        if entry == "-" {
+            anyhow::bail!(
+                "inputs manifest line {}: stdin ('-') is not a file",
+                idx + 1
+            );
+        }
+        let path = PathBuf::from(entry);
+        if paths.contains(&path) {
+            anyhow::bail!(
+                "inputs manifest line {}: '{entry}' is listed twice",
+                idx + 1
+            );
+        }
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Hash the inputs as described in the module docs.
+fn inputs_hash(inputs: &[(PathBuf, String)]) -> String {
+    let mut hasher = Sha256::new();
+    for (path, contents) in inputs {
+        hasher.update(path.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(contents.len().to_string().as_bytes());
+        hasher.update([0]);
+        hasher.update(contents.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Write `bytes` to `path` via a sibling temp file and a rename, so a failed
+/// run never leaves a partial output for the build system to pick up.
+pub(crate) fn write_atomically(path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".tmp-{}", std::process::id()));
+    let tmp = PathBuf::from(tmp);
+
+    let written = fs::write(&tmp, bytes).and_then(|()| fs::rename(&tmp, path));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp);
+        anyhow::bail!("failed to write output {}: {e}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest_skips_blanks_and_comments() {
+        let paths = parse_manifest("# inputs\nsrc/a.rs\r\n\n  src/b.ts  \n").unwrap();
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("src/a.rs"), PathBuf::from("src/b.ts")]
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_rejects_duplicates_and_stdin() {
+        let err = parse_manifest("a.rs\nb.rs\na.rs\n").unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{err}");
+        assert!(parse_manifest("-\n").is_err());
+    }
+
+    #[test]
+    fn test_inputs_hash_covers_paths_contents_and_order() {
+        let a = (PathBuf::from("a.rs"), "fn a() {}".to_string());
+        let b = (PathBuf::from("b.rs"), "fn b() {}".to_string());
+        let base = inputs_hash(&[a.clone(), b.clone()]);
+
+        assert_eq!(base, inputs_hash(&[a.clone(), b.clone()]));
+        assert_ne!(base, inputs_hash(&[b.clone(), a.clone()]));
+        assert_ne!(
+            base,
+            inputs_hash(&[(PathBuf::from("c.rs"), a.1.clone()), b.clone()])
+        );
+        assert_ne!(
+            base,
+            inputs_hash(&[(a.0.clone(), "fn a() { 1 }".to_string()), b])
+        );
+    }
+
+    #[test]
+    fn test_inputs_hash_separates_entries() {
+        // Moving bytes between a path and its contents must change the hash.
+        let joined = inputs_hash(&[(PathBuf::from("ab"), "c".to_string())]);
+        let split = inputs_hash(&[(PathBuf::from("a"), "bc".to_string())]);
+        assert_ne!(joined, split);
+    }
+}
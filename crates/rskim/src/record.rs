@@ -0,0 +1,425 @@
+//! Transform recording (`--record <DIR>`) for `skim replay`.
+//!
+//! Bug reports about skim output are hard to act on without the exact input
+//! and configuration, and crashes on servers or in MCP sessions rarely come
+//! with either. With `--record`, every call into the core transformer writes a
+//! self-contained case file to the record directory: the source, the language,
+//! the full `TransformConfig`, and the output or error it produced.
+//! `skim replay <DIR>` runs each case again and compares.
+//!
+//! # Case files
+//!
+//! One JSON file per transform, named by a hash of its inputs, so repeating
+//! the same transform overwrites the same case. The case is written before the
+//! transform runs and rewritten with the outcome afterwards: a transform that
+//! crashes the process leaves a case with neither `output` nor `error`, which
+//! is exactly the reproduction needed.
+//!
+//! Only the core transform is recorded. Caching, guardrails, line-number
+//! formatting and output layout happen in the CLI and are not part of a case;
+//! recording bypasses the cache so every file is transformed.
+//!
+//! Files whose values skim redacts (`.env`, see
+//! [`Language::is_redacted`]) are never recorded: a case holds the raw
+//! source, and case files are meant to be attached to bug reports.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use rskim_core::{Language, Mode, TransformConfig};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Case file format version; bumped on incompatible changes.
+pub(crate) const CASE_VERSION: u32 = 1;
+
+/// Record directory, set once at startup when `--record` is given.
+static RECORD_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Start recording transforms into `dir`, creating it if needed.
+///
+/// Call once in the file-operation path before any transform runs.
+pub(crate) fn enable(dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)
+        .map_err(|e| anyhow::anyhow!("--record: cannot create {}: {e}", dir.display()))?;
+    // A second call would only repeat the same directory; ignore it.
+    let _ = RECORD_DIR.set(dir.to_path_buf());
+    Ok(())
+}
+
+/// Whether `--record` is active for this process.
+pub(crate) fn is_enabled() -> bool {
+    RECORD_DIR.get().is_some()
+}
+
+/// Core entry point a case was recorded from; replay calls the same one.
+///
+/// Variant names mirror the `rskim_core` functions they stand for.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Entry {
+    TransformWithConfig,
+    TransformWithLineMap,
+    TransformAutoWithConfig,
+}
+
+/// Serialisable copy of a [`TransformConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct RecordedConfig {
+    pub(crate) mode: String,
+    pub(crate) preserve_comments: bool,
+    pub(crate) max_lines: Option<usize>,
+    pub(crate) last_lines: Option<usize>,
+    pub(crate) line_numbers: bool,
+    pub(crate) fenced_code: bool,
+    pub(crate) template_literal_threshold: Option<usize>,
+    pub(crate) public_only: bool,
+    pub(crate) module_doc: bool,
+    #[serde(default)]
+    pub(crate) python_stub: bool,
+    #[serde(default)]
+    pub(crate) dot_graph: bool,
+    #[serde(default)]
+    pub(crate) todo_bodies: bool,
+    #[serde(default)]
+    pub(crate) max_params: Option<usize>,
+    #[serde(default)]
+    pub(crate) wrap_width: Option<usize>,
+    #[serde(default)]
+    pub(crate) max_nesting_kept: Option<usize>,
+    #[serde(default)]
+    pub(crate) body_statements: usize,
+    #[serde(default)]
+    pub(crate) query: Option<String>,
+}
+
+impl From<&TransformConfig> for RecordedConfig {
+    fn from(config: &TransformConfig) -> Self {
+        Self {
+            mode: config.mode.name().to_string(),
+            preserve_comments: config.preserve_comments,
+            max_lines: config.max_lines,
+            last_lines: config.last_lines,
+            line_numbers: config.line_numbers,
+            fenced_code: config.fenced_code,
+            template_literal_threshold: config.template_literal_threshold,
+            public_only: config.public_only,
+            module_doc: config.module_doc,
+            python_stub: config.python_stub,
+            dot_graph: config.dot_graph,
+            todo_bodies: config.todo_bodies,
+            max_params: config.max_params,
+            wrap_width: config.wrap_width,
+            max_nesting_kept: config.max_nesting_kept,
+            body_statements: config.body_statements,
+            query: config.query.clone(),
+        }
+    }
+}
+
+impl RecordedConfig {
+    /// Rebuild the `TransformConfig` this was recorded from.
+    pub(crate) fn to_config(&self) -> anyhow::Result<TransformConfig> {
+        let mode = Mode::parse(&self.mode)
+            .ok_or_else(|| anyhow::anyhow!("unknown mode '{}'", self.mode))?;
+        let mut config = TransformConfig::with_mode(mode)
+            .preserve_comments(self.preserve_comments)
+            .with_line_numbers(self.line_numbers)
+            .with_fenced_code(self.fenced_code)
+            .with_template_literal_threshold(self.template_literal_threshold)
+            .with_public_only(self.public_only)
+            .with_module_doc(self.module_doc)
+            .with_python_stub(self.python_stub)
+            .with_dot_graph(self.dot_graph)
+            .with_todo_bodies(self.todo_bodies)
+            .with_body_statements(self.body_statements);
+        config.max_lines = self.max_lines;
+        config.last_lines = self.last_lines;
+        config.max_params = self.max_params;
+        config.wrap_width = self.wrap_width;
+        config.max_nesting_kept = self.max_nesting_kept;
+        config.query = self.query.clone();
+        Ok(config)
+    }
+}
+
+/// One recorded transform.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Case {
+    pub(crate) version: u32,
+    /// Version of skim that recorded the case.
+    pub(crate) skim_version: String,
+    pub(crate) entry: Entry,
+    /// Path as given to skim (`-` or the `--filename` hint for stdin).
+    pub(crate) path: String,
+    /// Language passed to the core, or `None` when it was detected from `path`.
+    pub(crate) language: Option<String>,
+    pub(crate) config: RecordedConfig,
+    pub(crate) source: String,
+    /// Transformed output, when the transform succeeded.
+    pub(crate) output: Option<String>,
+    /// Error message, when the transform failed.
+    pub(crate) error: Option<String>,
+}
+
+impl Case {
+    fn new(
+        entry: Entry,
+        path: &Path,
+        language: Option<Language>,
+        config: &TransformConfig,
+        source: &str,
+    ) -> Self {
+        Self {
+            version: CASE_VERSION,
+            skim_version: env!("CARGO_PKG_VERSION").to_string(),
+            entry,
+            path: path.display().to_string(),
+            language: language.map(|l| l.as_str().to_string()),
+            config: RecordedConfig::from(config),
+            source: source.to_string(),
+            output: None,
+            error: None,
+        }
+    }
+
+    /// Language the case was recorded with, if one was passed explicitly.
+    pub(crate) fn language(&self) -> anyhow::Result<Option<Language>> {
+        self.language
+            .as_deref()
+            .map(|name| {
+                rskim_core::supported_languages()
+                    .iter()
+                    .copied()
+                    .find(|l| l.as_str() == name)
+                    .ok_or_else(|| anyhow::anyhow!("unknown language '{name}'"))
+            })
+            .transpose()
+    }
+
+    /// Case file name: a hash of everything that determines the output.
+    fn file_name(&self) -> String {
+        let mut hasher = Sha256::new();
+        for part in [
+            serde_json::to_string(&self.entry).unwrap_or_default(),
+            self.path.clone(),
+            self.language.clone().unwrap_or_default(),
+            serde_json::to_string(&self.config).unwrap_or_default(),
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        hasher.update(self.source.as_bytes());
+        let hex = format!("{:x}", hasher.finalize());
+        format!("{}.json", &hex[..16])
+    }
+}
+
+/// Write `case` into the record directory, warning on failure.
+///
+/// A recording failure must not fail the skim run it is observing.
+fn save(dir: &Path, name: &str, case: &Case) {
+    let result = serde_json::to_string_pretty(case)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| fs::write(dir.join(name), json + "\n").map_err(anyhow::Error::from));
+    if let Err(e) = result {
+        eprintln!(
+            "[skim] warning: --record: failed to write {}: {e}",
+            dir.join(name).display()
+        );
+    }
+}
+
+/// Run `transform`, recording it as a case when `--record` is active.
+fn recorded<T>(
+    entry: Entry,
+    path: &Path,
+    language: Option<Language>,
+    config: &TransformConfig,
+    source: &str,
+    transform: impl FnOnce() -> rskim_core::Result<T>,
+    output: impl FnOnce(&T) -> &str,
+) -> rskim_core::Result<T> {
+    let Some(dir) = RECORD_DIR.get() else {
+        return transform();
+    };
+    let redacted = language
+        .or_else(|| rskim_core::detect_language_from_path(path))
+        .is_some_and(Language::is_redacted);
+    if redacted {
+        eprintln!(
+            "[skim] warning: --record: not recording {}: its values are redacted",
+            path.display()
+        );
+        return transform();
+    }
+
+    let mut case = Case::new(entry, path, language, config, source);
+    let name = case.file_name();
+    save(dir, &name, &case);
+    let result = transform();
+    match &result {
+        Ok(value) => case.output = Some(output(value).to_string()),
+        Err(e) => case.error = Some(e.to_string()),
+    }
+    save(dir, &name, &case);
+    result
+}
+
+/// [`rskim_core::transform_with_config`], recorded under `path`.
+pub(crate) fn transform_with_config(
+    source: &str,
+    path: &Path,
+    language: Language,
+    config: &TransformConfig,
+) -> rskim_core::Result<String> {
+    recorded(
+        Entry::TransformWithConfig,
+        path,
+        Some(language),
+        config,
+        source,
+        || rskim_core::transform_with_config(source, language, config),
+        String::as_str,
+    )
+}
+
+/// Output of [`rskim_core::transform_with_line_map`].
+type LineMapOutput = (String, bool, Option<Vec<usize>>, bool);
+
+/// [`rskim_core::transform_with_line_map`], recorded under `path`.
+pub(crate) fn transform_with_line_map(
+    source: &str,
+    path: &Path,
+    language: Language,
+    config: &TransformConfig,
+) -> rskim_core::Result<LineMapOutput> {
+    recorded(
+        Entry::TransformWithLineMap,
+        path,
+        Some(language),
+        config,
+        source,
+        || rskim_core::transform_with_line_map(source, language, config),
+        |(output, ..)| output.as_str(),
+    )
+}
+
+/// [`rskim_core::transform_auto_with_config`], recorded.
+pub(crate) fn transform_auto_with_config(
+    source: &str,
+    path: &Path,
+    config: &TransformConfig,
+) -> rskim_core::Result<String> {
+    recorded(
+        Entry::TransformAutoWithConfig,
+        path,
+        None,
+        config,
+        source,
+        || rskim_core::transform_auto_with_config(source, path, config),
+        String::as_str,
+    )
+}
+
+/// Re-run a recorded case through the same core entry point.
+pub(crate) fn replay(case: &Case) -> anyhow::Result<rskim_core::Result<String>> {
+    let config = case.config.to_config()?;
+    let language = case.language()?;
+    let source = case.source.as_str();
+    Ok(match (case.entry, language) {
+        (Entry::TransformAutoWithConfig, _) => {
+            rskim_core::transform_auto_with_config(source, Path::new(&case.path), &config)
+        }
+        (Entry::TransformWithConfig, Some(language)) => {
+            rskim_core::transform_with_config(source, language, &config)
+        }
+        (Entry::TransformWithLineMap, Some(language)) => {
+            rskim_core::transform_with_line_map(source, language, &config).map(|(out, ..)| out)
+        }
+        (_, None) => anyhow::bail!("case has no language"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorded_config_round_trips() {
+        let mut config = TransformConfig::with_mode(Mode::Signatures)
+            .with_public_only(true)
+            .with_template_literal_threshold(None)
+            .with_line_numbers(true);
+        config.max_lines = Some(40);
+        let restored = RecordedConfig::from(&config).to_config().unwrap();
+        assert_eq!(
+            RecordedConfig::from(&restored),
+            RecordedConfig::from(&config)
+        );
+        assert_eq!(restored.mode, Mode::Signatures);
+        assert_eq!(restored.max_lines, Some(40));
+    }
+
+    #[test]
+    fn test_case_file_name_depends_on_inputs_only() {
+        let config = TransformConfig::with_mode(Mode::Structure);
+        let path = Path::new("a.rs");
+        let mut a = Case::new(
+            Entry::TransformWithConfig,
+            path,
+            Some(Language::Rust),
+            &config,
+            "fn a() {}\n",
+        );
+        let b = Case::new(
+            Entry::TransformWithConfig,
+            path,
+            Some(Language::Rust),
+            &config,
+            "fn b() {}\n",
+        );
+        let before = a.file_name();
+        a.output = Some("fn a() {}\n".to_string());
+        assert_eq!(a.file_name(), before);
+        assert_ne!(b.file_name(), before);
+    }
+
+    #[test]
+    fn test_replay_reproduces_output() {
+        let config = TransformConfig::with_mode(Mode::Structure);
+        let source = "fn main() {\n    println!(\"hi\");\n}\n";
+        let mut case = Case::new(
+            Entry::TransformWithLineMap,
+            Path::new("main.rs"),
+            Some(Language::Rust),
+            &config,
+            source,
+        );
+        case.output =
+            Some(rskim_core::transform_with_config(source, Language::Rust, &config).unwrap());
+        let replayed = replay(&case).unwrap().unwrap();
+        assert_eq!(Some(replayed), case.output);
+    }
+
+    #[test]
+    fn test_case_json_round_trips() {
+        let case = Case::new(
+            Entry::TransformAutoWithConfig,
+            Path::new("x.py"),
+            None,
+            &TransformConfig::default(),
+            "def f(): pass\n",
+        );
+        let json = serde_json::to_string(&case).unwrap();
+        assert!(
+            json.contains("\"entry\":\"transform_auto_with_config\""),
+            "{json}"
+        );
+        let parsed: Case = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.source, case.source);
+        assert_eq!(parsed.language().unwrap(), None);
+    }
+}
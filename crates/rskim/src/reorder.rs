@@ -0,0 +1,196 @@
+//! In-order delivery of out-of-order results with bounded memory.
+//!
+//! Multi-file runs transform files in parallel but print them in input order.
+//! Files that finish before an earlier one wait in a [`Reorder`] buffer until
+//! their turn. When one slow file holds up a huge run, or stdout drains slowly
+//! (a pager, a network pipe), that buffer can grow toward the size of the
+//! whole bundle; past its memory budget, waiting texts are spilled to an
+//! anonymous temporary file and read back when they are delivered. Memory
+//! stays at roughly the budget plus the largest single text, whatever the
+//! size of the run.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+/// A waiting text, in memory or in the spill file.
+#[derive(Debug)]
+enum Text {
+    Memory(String),
+    Spilled { offset: u64, len: usize },
+}
+
+/// Buffers items that arrive out of order, each with a text, and hands them
+/// back in index order starting from 0.
+#[derive(Debug)]
+pub(crate) struct Reorder<T> {
+    /// Index of the next item to deliver.
+    next: usize,
+    pending: BTreeMap<usize, (T, Text)>,
+    /// Bytes of pending text held in memory.
+    in_memory: usize,
+    budget: usize,
+    /// Spill file, created on first use; deleted by the OS when dropped.
+    spill: Option<File>,
+    /// Bytes written to the spill file.
+    spilled: u64,
+    /// Pending items whose text is in the spill file.
+    spilled_items: usize,
+}
+
+impl<T> Reorder<T> {
+    /// A buffer keeping at most `budget` bytes of pending text in memory.
+    pub(crate) fn new(budget: usize) -> Self {
+        Self {
+            next: 0,
+            pending: BTreeMap::new(),
+            in_memory: 0,
+            budget,
+            spill: None,
+            spilled: 0,
+            spilled_items: 0,
+        }
+    }
+
+    /// Add item `idx` with its text, spilling the text if the memory budget
+    /// is spent.
+    pub(crate) fn insert(&mut self, idx: usize, item: T, text: String) -> io::Result<()> {
+        debug_assert!(idx >= self.next, "BUG: item {idx} was already delivered");
+        let text = if self.in_memory + text.len() <= self.budget || text.is_empty() {
+            self.in_memory += text.len();
+            Text::Memory(text)
+        } else {
+            self.spill_text(&text)?
+        };
+        self.pending.insert(idx, (item, text));
+        Ok(())
+    }
+
+    fn spill_text(&mut self, text: &str) -> io::Result<Text> {
+        let file = match &mut self.spill {
+            Some(file) => file,
+            None => self.spill.insert(tempfile::tempfile()?),
+        };
+        file.seek(SeekFrom::Start(self.spilled))?;
+        file.write_all(text.as_bytes())?;
+        let offset = self.spilled;
+        self.spilled += text.len() as u64;
+        self.spilled_items += 1;
+        Ok(Text::Spilled {
+            offset,
+            len: text.len(),
+        })
+    }
+
+    /// Number of items ready for delivery: the run of consecutive indices
+    /// starting at the next one.
+    pub(crate) fn ready(&self) -> usize {
+        self.pending
+            .keys()
+            .zip(self.next..)
+            .take_while(|(idx, expected)| *idx == expected)
+            .count()
+    }
+
+    /// The next `count` items, without their texts; `count` must not exceed
+    /// [`ready`](Self::ready).
+    pub(crate) fn peek(&self, count: usize) -> impl Iterator<Item = &T> {
+        self.pending.values().take(count).map(|(item, _)| item)
+    }
+
+    /// Take the next item and its text, if it has arrived.
+    pub(crate) fn pop(&mut self) -> io::Result<Option<(T, String)>> {
+        let Some((item, text)) = self.pending.remove(&self.next) else {
+            return Ok(None);
+        };
+        self.next += 1;
+        let text = match text {
+            Text::Memory(text) => {
+                self.in_memory -= text.len();
+                text
+            }
+            Text::Spilled { offset, len } => {
+                let file = self
+                    .spill
+                    .as_mut()
+                    .ok_or_else(|| io::Error::other("spill file missing"))?;
+                file.seek(SeekFrom::Start(offset))?;
+                let mut bytes = vec![0; len];
+                file.read_exact(&mut bytes)?;
+                self.spilled_items -= 1;
+                // Nothing left in the file: start over instead of growing it.
+                if self.spilled_items == 0 {
+                    file.set_len(0)?;
+                    self.spilled = 0;
+                }
+                String::from_utf8(bytes)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            }
+        };
+        Ok(Some((item, text)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn drain(reorder: &mut Reorder<usize>) -> Vec<(usize, String)> {
+        let mut out = Vec::new();
+        while let Some(entry) = reorder.pop().unwrap() {
+            out.push(entry);
+        }
+        out
+    }
+
+    #[test]
+    fn test_delivers_in_index_order() {
+        let mut reorder = Reorder::new(usize::MAX);
+        reorder.insert(2, 2, "c".into()).unwrap();
+        reorder.insert(1, 1, "b".into()).unwrap();
+        assert_eq!(reorder.ready(), 0);
+        assert!(reorder.pop().unwrap().is_none());
+
+        reorder.insert(0, 0, "a".into()).unwrap();
+        assert_eq!(reorder.ready(), 3);
+        assert_eq!(reorder.peek(2).copied().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(
+            drain(&mut reorder),
+            [(0, "a".into()), (1, "b".into()), (2, "c".into())]
+        );
+    }
+
+    #[test]
+    fn test_ready_stops_at_the_first_gap() {
+        let mut reorder = Reorder::new(usize::MAX);
+        for idx in [0, 1, 3] {
+            reorder.insert(idx, idx, String::new()).unwrap();
+        }
+        assert_eq!(reorder.ready(), 2);
+        drain(&mut reorder);
+        assert_eq!(reorder.ready(), 0);
+        reorder.insert(2, 2, String::new()).unwrap();
+        assert_eq!(reorder.ready(), 2);
+    }
+
+    #[test]
+    fn test_texts_over_budget_spill_and_read_back() {
+        let mut reorder = Reorder::new(8);
+        let texts = ["first 🦀", "second text", "third", "fourth text, longer"];
+        // Arrive in reverse, so everything waits on item 0.
+        for (idx, text) in texts.iter().enumerate().rev() {
+            reorder.insert(idx, idx, text.to_string()).unwrap();
+        }
+        assert!(reorder.in_memory <= 8);
+        assert!(reorder.spilled > 0);
+
+        let delivered: Vec<String> = drain(&mut reorder)
+            .into_iter()
+            .map(|(_, text)| text)
+            .collect();
+        assert_eq!(delivered, texts);
+        assert_eq!(reorder.in_memory, 0);
+        assert_eq!(reorder.spilled, 0, "an empty spill file is reused");
+    }
+}
@@ -0,0 +1,221 @@
+//! Stratified file sampling (`--sample`, `--sample-percent`).
+//!
+//! Skimming every file of a huge repository can be slower, and larger, than a
+//! quick structural overview needs. Sampling keeps a subset that still looks
+//! like the whole: files are split into strata by language and size bucket,
+//! every stratum keeps at least one file when the sample is large enough, and
+//! the rest of the sample is shared out in proportion to stratum size.
+//!
+//! Which files of a stratum are kept depends only on the seed and the paths,
+//! so a sample can be repeated exactly with `--sample-seed`. Without one,
+//! each run draws a fresh seed and reports it on stderr.
+
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+
+use rskim_core::Language;
+use sha2::{Digest, Sha256};
+
+/// Upper bounds (exclusive) of the size buckets files are stratified by.
+/// Larger files fall into one last bucket.
+const SIZE_BUCKETS: &[u64] = &[4 * 1024, 16 * 1024, 64 * 1024];
+
+/// How many files a sample keeps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SampleSize {
+    /// At most this many files (`--sample N`).
+    Files(usize),
+    /// This percentage of the files, rounded up (`--sample-percent P`).
+    Percent(f64),
+}
+
+impl SampleSize {
+    /// Number of files to keep out of `total`; at least one while there are
+    /// any.
+    fn target(self, total: usize) -> usize {
+        let target = match self {
+            Self::Files(n) => n,
+            Self::Percent(percent) => (total as f64 * percent / 100.0).ceil() as usize,
+        };
+        target.clamp(usize::from(total > 0), total)
+    }
+}
+
+/// Sampling settings for a multi-file run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Sampling {
+    pub(crate) size: SampleSize,
+    pub(crate) seed: u64,
+}
+
+impl Sampling {
+    /// Settings with `seed`, or a fresh random seed when none is given.
+    pub(crate) fn new(size: SampleSize, seed: Option<u64>) -> Self {
+        let seed = seed.unwrap_or_else(|| {
+            std::collections::hash_map::RandomState::new()
+                .build_hasher()
+                .finish()
+        });
+        Self { size, seed }
+    }
+}
+
+/// Keep a stratified sample of `paths`, in their original order.
+pub(crate) fn sample(paths: Vec<PathBuf>, sampling: &Sampling) -> Vec<PathBuf> {
+    let target = sampling.size.target(paths.len());
+    if target >= paths.len() {
+        return paths;
+    }
+
+    let mut strata: BTreeMap<(&'static str, usize), Vec<usize>> = BTreeMap::new();
+    for (idx, path) in paths.iter().enumerate() {
+        strata.entry(stratum(path)).or_default().push(idx);
+    }
+    let mut strata: Vec<Vec<usize>> = strata.into_values().collect();
+    let sizes: Vec<usize> = strata.iter().map(Vec::len).collect();
+
+    let mut keep = vec![false; paths.len()];
+    for (members, quota) in strata.iter_mut().zip(allocate(&sizes, target)) {
+        members.sort_by_cached_key(|&idx| rank(sampling.seed, &paths[idx]));
+        for &idx in &members[..quota] {
+            keep[idx] = true;
+        }
+    }
+
+    paths
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(path, keep)| keep.then_some(path))
+        .collect()
+}
+
+/// The stratum of `path`: its language and size bucket.
+fn stratum(path: &Path) -> (&'static str, usize) {
+    let language = Language::from_path(path).map_or("other", Language::as_str);
+    let len = std::fs::metadata(path).map_or(0, |meta| meta.len());
+    let bucket = SIZE_BUCKETS
+        .iter()
+        .position(|&bound| len < bound)
+        .unwrap_or(SIZE_BUCKETS.len());
+    (language, bucket)
+}
+
+/// Seeded sort key of `path` inside its stratum.
+///
+/// SHA-256 rather than `DefaultHasher`, whose output may change between
+/// Rust releases: a recorded seed must pick the same files on any build.
+fn rank(seed: u64, path: &Path) -> [u8; 8] {
+    let digest = Sha256::new()
+        .chain_update(seed.to_le_bytes())
+        .chain_update(path.as_os_str().as_encoded_bytes())
+        .finalize();
+    let mut key = [0; 8];
+    key.copy_from_slice(&digest[..8]);
+    key
+}
+
+/// Split `target` files across strata of the given `sizes`.
+///
+/// With at least one file per stratum available, each stratum keeps one and
+/// the remainder is shared in proportion to the files left in each (largest
+/// remainder first, ties to the earlier stratum). With fewer, the largest
+/// strata keep one file each. Requires `target` below the total.
+fn allocate(sizes: &[usize], target: usize) -> Vec<usize> {
+    let mut quotas = vec![0; sizes.len()];
+    if target < sizes.len() {
+        let mut largest: Vec<usize> = (0..sizes.len()).collect();
+        largest.sort_by_key(|&i| std::cmp::Reverse(sizes[i]));
+        for &i in &largest[..target] {
+            quotas[i] = 1;
+        }
+        return quotas;
+    }
+
+    let spare = target - sizes.len();
+    let weights: Vec<u128> = sizes.iter().map(|&size| (size - 1) as u128).collect();
+    let total_weight: u128 = weights.iter().sum();
+    let mut remainders = Vec::with_capacity(sizes.len());
+    let mut assigned = 0;
+    for (i, &weight) in weights.iter().enumerate() {
+        let share = spare as u128 * weight;
+        let whole = (share / total_weight) as usize;
+        quotas[i] = 1 + whole;
+        assigned += whole;
+        remainders.push((share % total_weight, i));
+    }
+    remainders.sort_by_key(|&(remainder, i)| (std::cmp::Reverse(remainder), i));
+    for &(_, i) in &remainders[..spare - assigned] {
+        quotas[i] += 1;
+    }
+    quotas
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+
+    fn files(names: &[&str]) -> Vec<PathBuf> {
+        names.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn test_target_rounds_percent_up_and_clamps() {
+        assert_eq!(SampleSize::Files(5).target(3), 3);
+        assert_eq!(SampleSize::Files(0).target(3), 1);
+        assert_eq!(SampleSize::Percent(10.0).target(11), 2);
+        assert_eq!(SampleSize::Percent(100.0).target(7), 7);
+        assert_eq!(SampleSize::Files(5).target(0), 0);
+    }
+
+    #[test]
+    fn test_allocate_covers_every_stratum_then_shares_proportionally() {
+        assert_eq!(allocate(&[91, 10, 1], 12), vec![9, 2, 1]);
+        assert_eq!(allocate(&[5, 5], 4), vec![2, 2]);
+        // Fewer files than strata: the largest strata win, ties to the first.
+        assert_eq!(allocate(&[2, 9, 2], 2), vec![1, 1, 0]);
+        let quotas = allocate(&[7, 13, 29, 3], 17);
+        assert_eq!(quotas.iter().sum::<usize>(), 17);
+        assert!(
+            quotas
+                .iter()
+                .zip([7, 13, 29, 3])
+                .all(|(q, s)| *q >= 1 && *q <= s)
+        );
+    }
+
+    #[test]
+    fn test_sample_is_stratified_deterministic_and_ordered() {
+        // Missing files land in the smallest size bucket, so strata are by
+        // language here.
+        let paths = files(&[
+            "a.rs", "b.rs", "c.rs", "d.rs", "e.rs", "f.rs", "g.py", "h.py", "i.md",
+        ]);
+        let sampling = Sampling::new(SampleSize::Files(3), Some(7));
+        let picked = sample(paths.clone(), &sampling);
+        assert_eq!(picked.len(), 3);
+        for ext in ["rs", "py", "md"] {
+            assert_eq!(
+                picked
+                    .iter()
+                    .filter(|p| p.extension().unwrap() == ext)
+                    .count(),
+                1,
+                "{picked:?}"
+            );
+        }
+        assert!(picked.windows(2).all(|w| w[0] < w[1]), "input order kept");
+        assert_eq!(sample(paths.clone(), &sampling), picked);
+
+        let all_seeds: std::collections::BTreeSet<Vec<PathBuf>> = (0..20)
+            .map(|seed| {
+                sample(
+                    paths.clone(),
+                    &Sampling::new(SampleSize::Files(3), Some(seed)),
+                )
+            })
+            .collect();
+        assert!(all_seeds.len() > 1, "the seed changes the sample");
+    }
+}
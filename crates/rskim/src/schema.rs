@@ -0,0 +1,488 @@
+//! JSON Schemas for skim's machine-readable output (`--schema <FORMAT>`).
+//!
+//! Every JSON document skim prints for other programs carries a top-level
+//! `schema_version`, and `skim --schema <FORMAT>` prints the JSON Schema
+//! (draft 2020-12) that version conforms to, so integrators can validate
+//! output or generate typed clients from it:
+//!
+//! | Format          | Emitted by                              |
+//! |-----------------|-----------------------------------------|
+//! | `detailed-json` | `--detailed-json` (one record per line) |
+//! | `ast-json`      | `--format ast-json`                     |
+//! | `usage`         | `--report-usage=json` (stderr)          |
+//! | `stats`         | `skim stats --format json`              |
+//! | `verify-cache`  | `skim verify-cache --format json`       |
+//! | `bench-cache`   | `skim bench-cache --format json`        |
+//!
+//! Each format is versioned on its own. Adding an optional field keeps the
+//! version; removing, renaming or retyping a field bumps it.
+
+use rskim_core::Mode;
+use serde_json::{Value, json};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A machine-readable output format with a published schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum SchemaFormat {
+    /// `--detailed-json` per-file records
+    DetailedJson,
+    /// `--format ast-json` parse trees
+    AstJson,
+    /// `--report-usage=json` resource usage
+    Usage,
+    /// `skim stats --format json` analytics
+    Stats,
+    /// `skim verify-cache --format json` cache scan
+    VerifyCache,
+    /// `skim bench-cache --format json` cold/warm benchmark
+    BenchCache,
+}
+
+impl SchemaFormat {
+    /// The `schema_version` this build emits for the format.
+    pub(crate) fn version(self) -> u32 {
+        match self {
+            SchemaFormat::DetailedJson
+            | SchemaFormat::Usage
+            | SchemaFormat::Stats
+            | SchemaFormat::VerifyCache
+            | SchemaFormat::BenchCache => 1,
+            SchemaFormat::AstJson => rskim_core::AST_JSON_SCHEMA_VERSION,
+        }
+    }
+
+    /// The JSON Schema for the format's current version.
+    pub(crate) fn schema(self) -> Value {
+        let (title, mut schema) = match self {
+            SchemaFormat::DetailedJson => ("skim --detailed-json record", detailed_json()),
+            SchemaFormat::AstJson => ("skim --format ast-json tree", ast_json()),
+            SchemaFormat::Usage => ("skim --report-usage=json summary", usage()),
+            SchemaFormat::Stats => ("skim stats --format json report", stats()),
+            SchemaFormat::VerifyCache => ("skim verify-cache --format json report", verify_cache()),
+            SchemaFormat::BenchCache => ("skim bench-cache --format json report", bench_cache()),
+        };
+        // Header keys first, so printed schemas read top-down.
+        let mut root = json!({
+            "$schema": DRAFT,
+            "title": title,
+        });
+        if let (Value::Object(root), Value::Object(body)) = (&mut root, &mut schema) {
+            root.append(body);
+        }
+        if let Some(properties) = root["properties"].as_object_mut() {
+            let mut versioned = serde_json::Map::from_iter([(
+                "schema_version".to_string(),
+                json!({
+                    "const": self.version(),
+                    "description": "Schema version of this document; bumped on incompatible changes.",
+                }),
+            )]);
+            versioned.append(properties);
+            *properties = versioned;
+        }
+        if let Some(required) = root["required"].as_array_mut() {
+            required.insert(0, json!("schema_version"));
+        }
+        root
+    }
+}
+
+/// An object schema that rejects unknown keys.
+fn object(required: &[&str], properties: Value) -> Value {
+    json!({
+        "type": "object",
+        "required": required,
+        "additionalProperties": false,
+        "properties": properties,
+    })
+}
+
+fn count() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn optional_count() -> Value {
+    json!({ "type": ["integer", "null"], "minimum": 0 })
+}
+
+fn detailed_json() -> Value {
+    let modes: Vec<&str> = Mode::ALL.iter().map(|mode| mode.name()).collect();
+    let mut schema = object(
+        &[
+            "path",
+            "language",
+            "mode",
+            "content",
+            "original_tokens",
+            "transformed_tokens",
+            "tokens_estimated",
+            "duration_ms",
+        ],
+        json!({
+            "path": {
+                "type": "string",
+                "description": "File path, or the --filename hint / '-' for stdin.",
+            },
+            "language": { "type": ["string", "null"] },
+            "mode": { "enum": modes },
+            "content": { "type": "string" },
+            "original_tokens": optional_count(),
+            "transformed_tokens": optional_count(),
+            "tokens_estimated": {
+                "type": "boolean",
+                "description": "Whether the token counts are --stats-fast estimates.",
+            },
+            "duration_ms": {
+                "type": ["number", "null"],
+                "minimum": 0,
+                "description": "Time spent in the core transform, in fractional milliseconds.",
+            },
+        }),
+    );
+    schema["description"] = json!("One line per file (JSON Lines).");
+    schema
+}
+
+/// Properties of one AST node, shared by the root and `$defs/node`.
+fn ast_node_properties() -> Value {
+    let position = json!({
+        "type": "array",
+        "items": count(),
+        "minItems": 2,
+        "maxItems": 2,
+        "description": "[row, column], 0-indexed.",
+    });
+    json!({
+        "kind": { "type": "string" },
+        "field": { "type": "string" },
+        "start_byte": count(),
+        "end_byte": count(),
+        "start": position,
+        "end": position,
+        "missing": {
+            "const": true,
+            "description": "Present on MISSING nodes inserted by error recovery.",
+        },
+        "children": { "type": "array", "items": { "$ref": "#/$defs/node" } },
+    })
+}
+
+fn ast_json() -> Value {
+    let required = ["kind", "start_byte", "end_byte", "start", "end"];
+    let mut schema = object(&required, ast_node_properties());
+    schema["$defs"] = json!({ "node": object(&required, ast_node_properties()) });
+    schema
+}
+
+fn usage() -> Value {
+    object(
+        &[
+            "wall_ms",
+            "cpu_ms",
+            "peak_rss_bytes",
+            "files",
+            "files_per_sec",
+            "cache",
+        ],
+        json!({
+            "wall_ms": count(),
+            "cpu_ms": optional_count(),
+            "peak_rss_bytes": optional_count(),
+            "files": count(),
+            "files_per_sec": { "type": "number", "minimum": 0 },
+            "cache": object(
+                &["lookups", "hits", "hit_ratio"],
+                json!({
+                    "lookups": count(),
+                    "hits": count(),
+                    "hit_ratio": {
+                        "type": ["number", "null"],
+                        "minimum": 0,
+                        "maximum": 1,
+                        "description": "null when the cache was not used.",
+                    },
+                }),
+            ),
+        }),
+    )
+}
+
+fn stats() -> Value {
+    let number = json!({ "type": "number" });
+    let breakdown = |key: &str, extra: &[&str]| {
+        let mut required = vec![key, "tokens_saved", "avg_savings_pct"];
+        required.extend_from_slice(extra);
+        let mut properties = json!({
+            key: { "type": "string" },
+            "tokens_saved": count(),
+            "avg_savings_pct": number,
+        });
+        for field in extra {
+            properties[*field] = if field.starts_with("avg_") {
+                number.clone()
+            } else {
+                count()
+            };
+        }
+        json!({ "type": "array", "items": object(&required, properties) })
+    };
+    object(
+        &[
+            "summary",
+            "daily",
+            "by_command",
+            "by_language",
+            "by_mode",
+            "tier_distribution",
+            "by_original_cmd",
+            "session_stats",
+            "cost_estimate",
+        ],
+        json!({
+            "summary": object(
+                &[
+                    "invocations",
+                    "raw_tokens",
+                    "compressed_tokens",
+                    "tokens_saved",
+                    "avg_savings_pct",
+                    "weighted_savings_pct",
+                ],
+                json!({
+                    "invocations": count(),
+                    "raw_tokens": count(),
+                    "compressed_tokens": count(),
+                    "tokens_saved": count(),
+                    "avg_savings_pct": number,
+                    "weighted_savings_pct": number,
+                }),
+            ),
+            "daily": breakdown("date", &["invocations"]),
+            "by_command": breakdown("type", &["invocations", "avg_duration_ms"]),
+            "by_language": breakdown("language", &["files"]),
+            "by_mode": breakdown("mode", &["files"]),
+            "tier_distribution": object(
+                &["full_pct", "degraded_pct", "passthrough_pct"],
+                json!({
+                    "full_pct": number,
+                    "degraded_pct": number,
+                    "passthrough_pct": number,
+                }),
+            ),
+            "by_original_cmd": breakdown("original_cmd", &["invocations", "avg_duration_ms"]),
+            "session_stats": object(
+                &[
+                    "distinct_sessions",
+                    "total_tokens_saved",
+                    "avg_tokens_per_session",
+                    "untagged_invocations",
+                ],
+                json!({
+                    "distinct_sessions": count(),
+                    "total_tokens_saved": count(),
+                    "avg_tokens_per_session": number,
+                    "untagged_invocations": count(),
+                }),
+            ),
+            "cost_estimate": object(
+                &["tier", "input_cost_per_mtok", "estimated_savings_usd", "tokens_saved"],
+                json!({
+                    "tier": { "type": "string" },
+                    "input_cost_per_mtok": number,
+                    "estimated_savings_usd": number,
+                    "tokens_saved": count(),
+                }),
+            ),
+            "deprecations": {
+                "type": "array",
+                "description": "Present only when the run used deprecated features.",
+                "items": object(
+                    &["id", "since", "removal", "message"],
+                    json!({
+                        "id": { "type": "string" },
+                        "since": { "type": "string" },
+                        "removal": { "type": "string" },
+                        "message": { "type": "string" },
+                    }),
+                ),
+            },
+        }),
+    )
+}
+
+/// Entry count and on-disk size of a group of cache files.
+fn tally() -> Value {
+    object(
+        &["entries", "bytes"],
+        json!({ "entries": count(), "bytes": count() }),
+    )
+}
+
+fn verify_cache() -> Value {
+    let categories = [
+        "scanned", "corrupt", "leftover", "orphaned", "stale", "expired", "removed",
+    ];
+    let properties = categories
+        .iter()
+        .map(|category| (category.to_string(), tally()))
+        .collect();
+    object(&categories, Value::Object(properties))
+}
+
+fn bench_cache() -> Value {
+    let milliseconds = json!({ "type": "number", "minimum": 0 });
+    object(
+        &[
+            "runs",
+            "files",
+            "cold_ms",
+            "warm_ms",
+            "speedup",
+            "hits",
+            "lookups",
+            "hit_ratio",
+            "cache",
+        ],
+        json!({
+            "runs": count(),
+            "files": count(),
+            "cold_ms": milliseconds,
+            "warm_ms": milliseconds,
+            "speedup": {
+                "type": "number",
+                "minimum": 0,
+                "description": "Cold median over warm median.",
+            },
+            "hits": count(),
+            "lookups": count(),
+            "hit_ratio": { "type": "number", "minimum": 0, "maximum": 1 },
+            "cache": tally(),
+        }),
+    )
+}
+
+/// Check `value` against `schema`, returning the first mismatch.
+///
+/// Covers the keywords the schemas above use, so emitter tests can assert
+/// that their output matches the published schema.
+#[cfg(test)]
+pub(crate) fn check(schema: &Value, value: &Value) -> Result<(), String> {
+    check_at(schema, schema, value, "$")
+}
+
+#[cfg(test)]
+fn check_at(root: &Value, schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if let Some(reference) = schema["$ref"].as_str() {
+        let name = reference.trim_start_matches("#/$defs/");
+        return check_at(root, &root["$defs"][name], value, path);
+    }
+    if let Some(expected) = schema.get("const")
+        && value != expected
+    {
+        return Err(format!("{path}: expected {expected}, got {value}"));
+    }
+    if let Some(allowed) = schema["enum"].as_array()
+        && !allowed.contains(value)
+    {
+        return Err(format!("{path}: {value} is not one of {allowed:?}"));
+    }
+    if let Some(types) = schema.get("type") {
+        let types: Vec<&str> = match types {
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            other => other.as_str().into_iter().collect(),
+        };
+        let matches = |ty: &str| match ty {
+            "null" => value.is_null(),
+            "boolean" => value.is_boolean(),
+            "integer" => value.is_u64() || value.is_i64(),
+            "number" => value.is_number(),
+            "string" => value.is_string(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => false,
+        };
+        if !types.iter().any(|ty| matches(ty)) {
+            return Err(format!("{path}: {value} is not {types:?}"));
+        }
+    }
+    if let Value::Object(map) = value {
+        for key in schema["required"].as_array().into_iter().flatten() {
+            let key = key.as_str().unwrap_or_default();
+            if !map.contains_key(key) {
+                return Err(format!("{path}: missing required '{key}'"));
+            }
+        }
+        for (key, child) in map {
+            let child_path = format!("{path}.{key}");
+            match schema["properties"].get(key) {
+                Some(child_schema) => check_at(root, child_schema, child, &child_path)?,
+                None if schema["additionalProperties"] == false => {
+                    return Err(format!("{child_path}: unexpected key"));
+                }
+                None => {}
+            }
+        }
+    }
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            check_at(root, item_schema, item, &format!("{path}[{i}]"))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Unwrapping/expect is acceptable in tests
+mod tests {
+    use super::*;
+    use clap::ValueEnum;
+    use rskim_core::{AstFormat, Language};
+
+    #[test]
+    fn test_every_schema_requires_its_version() {
+        for format in SchemaFormat::value_variants() {
+            let schema = format.schema();
+            assert_eq!(schema["$schema"], DRAFT);
+            assert_eq!(schema["required"][0], "schema_version");
+            assert_eq!(
+                schema["properties"]["schema_version"]["const"],
+                format.version()
+            );
+            assert!(check(&schema, &json!({})).is_err(), "{format:?}");
+        }
+    }
+
+    #[test]
+    fn test_ast_json_dump_matches_schema() {
+        let dump = rskim_core::dump_ast(
+            "def f(x):\n    return x +\n",
+            Language::Python,
+            AstFormat::Json,
+        )
+        .unwrap();
+        let value: Value = serde_json::from_str(&dump).unwrap();
+        check(&SchemaFormat::AstJson.schema(), &value).unwrap();
+    }
+
+    #[test]
+    fn test_check_reports_mismatches() {
+        let schema = SchemaFormat::Usage.schema();
+        let mut value = json!({
+            "schema_version": 1,
+            "wall_ms": 10,
+            "cpu_ms": null,
+            "peak_rss_bytes": null,
+            "files": 1,
+            "files_per_sec": 100.0,
+            "cache": { "lookups": 0, "hits": 0, "hit_ratio": null },
+        });
+        check(&schema, &value).unwrap();
+
+        value["cache"]["hits"] = json!("none");
+        assert!(check(&schema, &value).unwrap_err().contains("$.cache.hits"));
+        value["cache"]["hits"] = json!(0);
+        value["extra"] = json!(true);
+        assert!(check(&schema, &value).unwrap_err().contains("unexpected"));
+    }
+}
@@ -13,6 +13,7 @@
 //! that do not belong in the library API.
 
 use anyhow::Result;
+use rskim_core::Language;
 use rskim_tokens::{Counter, Encoding};
 use std::sync::OnceLock;
 
@@ -56,7 +57,9 @@ fn build_counter_with_fallback() -> Counter {
 /// Count tokens in text using cl100k_base encoding (GPT-3.5-turbo, GPT-4).
 ///
 /// Delegates to [`rskim_tokens::Counter`] with `Encoding::Cl100k`, preserving
-/// `encode_with_special_tokens` semantics (constraint 13 / AC3).
+/// `encode_with_special_tokens` semantics (constraint 13 / AC3). Large inputs
+/// are encoded in parallel boundary-safe chunks via `Counter::count_parallel`,
+/// which yields the same count as a serial pass.
 ///
 /// # Frozen signature (AC15)
 ///
@@ -64,7 +67,33 @@ fn build_counter_with_fallback() -> Counter {
 /// frozen. All existing call sites handle `Err` with their current patterns and
 /// require no changes.
 pub(crate) fn count_tokens(text: &str) -> Result<usize> {
-    Ok(get_counter().count(text))
+    Ok(get_counter().count_parallel(text))
+}
+
+/// Average UTF-8 bytes per cl100k token when the language is unknown.
+const DEFAULT_BYTES_PER_TOKEN: f64 = 3.6;
+
+/// Average UTF-8 bytes per cl100k token for `language` (`--stats-fast`).
+///
+/// The per-language table lives in the core, which summary mode also
+/// estimates with; `test_estimate_tokens_close_to_exact_on_fixtures` guards
+/// the calibration.
+fn bytes_per_token(language: Option<Language>) -> f64 {
+    language.map_or(DEFAULT_BYTES_PER_TOKEN, Language::bytes_per_token)
+}
+
+/// Estimate the cl100k token count of `text` from its byte length.
+///
+/// Used by `--stats-fast` in place of [`count_tokens`]: it never runs the BPE
+/// encoder, so it costs one division instead of a full tokenization pass.
+/// Results must be labelled as estimates wherever they are displayed.
+pub(crate) fn estimate_tokens(text: &str, language: Option<Language>) -> usize {
+    estimate_tokens_from_len(text.len(), language)
+}
+
+/// Byte-length form of [`estimate_tokens`], for sources not held in memory.
+pub(crate) fn estimate_tokens_from_len(byte_len: usize, language: Option<Language>) -> usize {
+    (byte_len as f64 / bytes_per_token(language)).ceil() as usize
 }
 
 /// Statistics for token reduction
@@ -74,6 +103,8 @@ pub(crate) struct TokenStats {
     pub(crate) original: usize,
     /// Transformed token count
     pub(crate) transformed: usize,
+    /// Whether the counts are `--stats-fast` estimates rather than exact counts
+    pub(crate) estimated: bool,
 }
 
 impl TokenStats {
@@ -82,6 +113,15 @@ impl TokenStats {
         Self {
             original,
             transformed,
+            estimated: false,
+        }
+    }
+
+    /// Create token stats from estimated counts (`--stats-fast`)
+    pub(crate) fn estimated(original: usize, transformed: usize) -> Self {
+        Self {
+            estimated: true,
+            ..Self::new(original, transformed)
         }
     }
 
@@ -94,7 +134,17 @@ impl TokenStats {
     }
 
     /// Format stats for display
+    ///
+    /// Estimated stats are prefixed with `~` and labelled `estimated`.
     pub(crate) fn format(&self) -> String {
+        if self.estimated {
+            return format!(
+                "~{} tokens → ~{} tokens ({:.1}% reduction, estimated)",
+                format_number(self.original),
+                format_number(self.transformed),
+                self.reduction_percentage()
+            );
+        }
         format!(
             "{} tokens → {} tokens ({:.1}% reduction)",
             format_number(self.original),
@@ -152,4 +202,51 @@ mod tests {
         assert!(formatted.contains("200"));
         assert!(formatted.contains("80.0%"));
     }
+
+    #[test]
+    fn test_estimated_stats_format_is_labelled() {
+        let formatted = TokenStats::estimated(1000, 200).format();
+        assert!(formatted.contains("~1,000 tokens"));
+        assert!(formatted.contains("~200 tokens"));
+        assert!(formatted.contains("estimated"));
+    }
+
+    #[test]
+    fn test_estimate_tokens_uses_language_ratio() {
+        assert_eq!(estimate_tokens("", None), 0);
+        assert_eq!(estimate_tokens_from_len(360, None), 100);
+        assert!(
+            estimate_tokens_from_len(1200, Some(Language::Json))
+                > estimate_tokens_from_len(1200, Some(Language::Java))
+        );
+    }
+
+    /// Calibration guard: estimates stay within 35% of exact cl100k counts on
+    /// real fixtures.
+    #[test]
+    fn test_estimate_tokens_close_to_exact_on_fixtures() {
+        let fixtures: &[(&str, Language)] = &[
+            (
+                include_str!("../../../tests/fixtures/typescript/simple.ts"),
+                Language::TypeScript,
+            ),
+            (
+                include_str!("../../../tests/fixtures/rust/simple.rs"),
+                Language::Rust,
+            ),
+            (
+                include_str!("../../../tests/fixtures/python/simple.py"),
+                Language::Python,
+            ),
+        ];
+        for (source, language) in fixtures {
+            let exact = count_tokens(source).unwrap() as f64;
+            let estimate = estimate_tokens(source, Some(*language)) as f64;
+            let error = (estimate - exact).abs() / exact;
+            assert!(
+                error < 0.35,
+                "{language:?}: estimate {estimate} vs exact {exact}"
+            );
+        }
+    }
 }
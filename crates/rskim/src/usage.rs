@@ -0,0 +1,228 @@
+//! Per-invocation resource usage (`--report-usage`).
+//!
+//! Counts processed files and cache lookups in process-wide atomics while the
+//! run proceeds, then reads wall time, CPU time and peak RSS at the end and
+//! prints one summary line on stderr:
+//!
+//! ```text
+//! [skim] usage: 0.84s wall, 2.91s cpu, 41.3 MB peak RSS, 212 file(s) (252.4 files/s), cache 180/212 hits (84.9%)
+//! ```
+//!
+//! `--report-usage=json` prints the same figures as a single JSON object
+//! instead (schema: `skim --schema usage`). CPU time and peak RSS come from `getrusage(2)` and are `null` in
+//! JSON (omitted from the line) on platforms without it.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::schema::SchemaFormat;
+
+/// Files transformed (or served from cache) this run.
+static FILES: AtomicUsize = AtomicUsize::new(0);
+/// Cache lookups made this run.
+static CACHE_LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+/// Cache lookups that returned an entry.
+static CACHE_HITS: AtomicUsize = AtomicUsize::new(0);
+
+/// Output format for `--report-usage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum UsageFormat {
+    /// One human-readable line
+    Text,
+    /// One JSON object
+    Json,
+}
+
+/// Count processed files.
+pub(crate) fn record_files(count: usize) {
+    FILES.fetch_add(count, Ordering::Relaxed);
+}
+
+/// Count one cache lookup.
+pub(crate) fn record_cache_lookup(hit: bool) {
+    CACHE_LOOKUPS.fetch_add(1, Ordering::Relaxed);
+    if hit {
+        CACHE_HITS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Resource usage of the whole run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Usage {
+    wall: Duration,
+    /// User plus system CPU time, all threads.
+    cpu: Option<Duration>,
+    peak_rss_bytes: Option<u64>,
+    files: usize,
+    cache_lookups: usize,
+    cache_hits: usize,
+}
+
+impl Usage {
+    fn files_per_sec(&self) -> f64 {
+        let secs = self.wall.as_secs_f64();
+        if secs > 0.0 {
+            self.files as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    /// Share of cache lookups that hit, or `None` when the cache was not used.
+    fn cache_hit_ratio(&self) -> Option<f64> {
+        (self.cache_lookups > 0).then(|| self.cache_hits as f64 / self.cache_lookups as f64)
+    }
+
+    fn to_text(self) -> String {
+        let mut parts = vec![format!("{:.2}s wall", self.wall.as_secs_f64())];
+        if let Some(cpu) = self.cpu {
+            parts.push(format!("{:.2}s cpu", cpu.as_secs_f64()));
+        }
+        if let Some(rss) = self.peak_rss_bytes {
+            parts.push(format!("{:.1} MB peak RSS", rss as f64 / 1024.0 / 1024.0));
+        }
+        parts.push(format!(
+            "{} file(s) ({:.1} files/s)",
+            self.files,
+            self.files_per_sec()
+        ));
+        parts.push(match self.cache_hit_ratio() {
+            Some(ratio) => format!(
+                "cache {}/{} hits ({:.1}%)",
+                self.cache_hits,
+                self.cache_lookups,
+                ratio * 100.0
+            ),
+            None => "cache not used".to_string(),
+        });
+        format!("[skim] usage: {}", parts.join(", "))
+    }
+
+    fn to_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "schema_version": SchemaFormat::Usage.version(),
+            "wall_ms": self.wall.as_millis() as u64,
+            "cpu_ms": self.cpu.map(|cpu| cpu.as_millis() as u64),
+            "peak_rss_bytes": self.peak_rss_bytes,
+            "files": self.files,
+            "files_per_sec": (self.files_per_sec() * 10.0).round() / 10.0,
+            "cache": {
+                "lookups": self.cache_lookups,
+                "hits": self.cache_hits,
+                "hit_ratio": self.cache_hit_ratio().map(|r| (r * 1000.0).round() / 1000.0),
+            },
+        })
+    }
+}
+
+/// Print the usage summary for a run that started at `started`.
+pub(crate) fn report(started: Instant, format: UsageFormat) {
+    let (cpu, peak_rss_bytes) = rusage();
+    let usage = Usage {
+        wall: started.elapsed(),
+        cpu,
+        peak_rss_bytes,
+        files: FILES.load(Ordering::Relaxed),
+        cache_lookups: CACHE_LOOKUPS.load(Ordering::Relaxed),
+        cache_hits: CACHE_HITS.load(Ordering::Relaxed),
+    };
+    match format {
+        UsageFormat::Text => eprintln!("{}", usage.to_text()),
+        UsageFormat::Json => eprintln!("{}", usage.to_json()),
+    }
+}
+
+/// CPU time and peak RSS of this process from `getrusage(RUSAGE_SELF)`.
+#[cfg(unix)]
+fn rusage() -> (Option<Duration>, Option<u64>) {
+    // SAFETY: `rusage` is a plain C struct; zero-initialising it is valid, and
+    // getrusage only writes into the buffer we pass.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return (None, None);
+    }
+    let timeval = |tv: libc::timeval| {
+        Duration::from_secs(tv.tv_sec.max(0) as u64)
+            + Duration::from_micros(tv.tv_usec.max(0) as u64)
+    };
+    let cpu = timeval(usage.ru_utime) + timeval(usage.ru_stime);
+    // ru_maxrss is in bytes on macOS and kilobytes everywhere else.
+    let max_rss = usage.ru_maxrss.max(0) as u64;
+    let peak_rss = if cfg!(target_os = "macos") {
+        max_rss
+    } else {
+        max_rss * 1024
+    };
+    (Some(cpu), Some(peak_rss))
+}
+
+#[cfg(not(unix))]
+fn rusage() -> (Option<Duration>, Option<u64>) {
+    (None, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Usage {
+        Usage {
+            wall: Duration::from_millis(2000),
+            cpu: Some(Duration::from_millis(3500)),
+            peak_rss_bytes: Some(50 * 1024 * 1024),
+            files: 100,
+            cache_lookups: 100,
+            cache_hits: 75,
+        }
+    }
+
+    #[test]
+    fn test_usage_text() {
+        assert_eq!(
+            sample().to_text(),
+            "[skim] usage: 2.00s wall, 3.50s cpu, 50.0 MB peak RSS, 100 file(s) (50.0 files/s), \
+             cache 75/100 hits (75.0%)"
+        );
+    }
+
+    #[test]
+    fn test_usage_text_without_rusage_or_cache() {
+        let usage = Usage {
+            cpu: None,
+            peak_rss_bytes: None,
+            cache_lookups: 0,
+            cache_hits: 0,
+            ..sample()
+        };
+        assert_eq!(
+            usage.to_text(),
+            "[skim] usage: 2.00s wall, 100 file(s) (50.0 files/s), cache not used"
+        );
+    }
+
+    #[test]
+    fn test_usage_json() {
+        let json = sample().to_json();
+        assert_eq!(json["wall_ms"], 2000);
+        assert_eq!(json["cpu_ms"], 3500);
+        assert_eq!(json["peak_rss_bytes"], 50 * 1024 * 1024);
+        assert_eq!(json["files_per_sec"], 50.0);
+        assert_eq!(json["cache"]["hit_ratio"], 0.75);
+        crate::schema::check(&SchemaFormat::Usage.schema(), &json).unwrap();
+
+        let uncached = Usage {
+            cache_lookups: 0,
+            cache_hits: 0,
+            ..sample()
+        };
+        assert!(uncached.to_json()["cache"]["hit_ratio"].is_null());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rusage_reports_values() {
+        let (cpu, rss) = rusage();
+        assert!(cpu.is_some());
+        assert!(rss.is_some_and(|r| r > 0));
+    }
+}
@@ -116,6 +116,35 @@ fn test_cli_full_mode() {
         .stdout(predicate::str::contains(content));
 }
 
+#[test]
+fn test_cli_template_threshold() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("query.ts");
+    fs::write(
+        &file_path,
+        "export const QUERY = `query GetUser { user { id name email } }`;\n",
+    )
+    .unwrap();
+
+    // Below the default threshold: kept verbatim
+    common::skim()
+        .arg(&file_path)
+        .arg("--no-cache")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("query GetUser"));
+
+    common::skim()
+        .arg(&file_path)
+        .arg("--no-cache")
+        .arg("--template-threshold")
+        .arg("10")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export const QUERY = `...`;"))
+        .stdout(predicate::str::contains("query GetUser").not());
+}
+
 // ============================================================================
 // Language Detection Tests
 // ============================================================================
@@ -844,3 +873,17 @@ fn test_cli_pseudo_mode_stdin() {
         // `export` is preserved as API surface (A4 contract)
         .stdout(predicate::str::contains("export"));
 }
+
+#[test]
+fn test_cli_force_flag_reports_deprecation() {
+    common::skim()
+        .args(["-", "--lang=typescript", "--force"])
+        .write_stdin("function f() { return 1; }\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function f()"))
+        .stderr(predicate::str::contains(
+            "[skim] deprecated (force-flag): --force has no effect",
+        ))
+        .stderr(predicate::str::contains("removal in 3.0.0"));
+}
@@ -0,0 +1,68 @@
+//! Integration tests for `--format ast` / `--format ast-json` AST dumps.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+#[test]
+fn test_format_ast_dumps_sexpression_without_source_text() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("lib.rs");
+    fs::write(&file, "fn secret() {}\n").unwrap();
+
+    common::skim()
+        .arg(&file)
+        .args(["--format", "ast", "--no-cache"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("(source_file [0..15]"))
+        .stdout(predicate::str::contains("name: (identifier [3..9])"))
+        .stdout(predicate::str::contains("secret").not());
+}
+
+#[test]
+fn test_format_ast_json_from_stdin() {
+    let output = common::skim()
+        .args(["-", "--lang", "python", "--format", "ast-json"])
+        .write_stdin("x = 1\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(value["kind"], "module");
+    assert_eq!(value["start"], serde_json::json!([0, 0]));
+    assert!(value["children"].is_array());
+}
+
+#[test]
+fn test_format_ast_rejects_truncation_flags() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("lib.rs");
+    fs::write(&file, "fn a() {}\n").unwrap();
+
+    common::skim()
+        .arg(&file)
+        .args(["--format", "ast", "--max-lines", "3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--max-lines cannot be combined with --format ast",
+        ));
+}
+
+#[test]
+fn test_format_ast_rejects_serde_languages() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("data.json");
+    fs::write(&file, "{\"a\": 1}\n").unwrap();
+
+    common::skim()
+        .arg(&file)
+        .args(["--format", "ast"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("does not use tree-sitter"));
+}
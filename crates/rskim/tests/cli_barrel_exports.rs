@@ -0,0 +1,65 @@
+//! Integration tests for `--barrel-exports`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+fn feature_folder() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("auth")).unwrap();
+    fs::write(root.join("index.ts"), "export * from './auth';\n").unwrap();
+    fs::write(root.join("auth/index.ts"), "export * from './session';\n").unwrap();
+    fs::write(
+        root.join("auth/session.ts"),
+        "export function useSession() {\n  return null;\n}\n",
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn test_barrel_exports_section_precedes_files() {
+    let dir = feature_folder();
+    let output = common::skim()
+        .arg(dir.path())
+        .args(["--barrel-exports", "--no-cache"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("// skim: folder exports\n"),
+        "got:\n{stdout}"
+    );
+    assert!(
+        stdout.contains("useSession  auth/session.ts"),
+        "got:\n{stdout}"
+    );
+    assert!(stdout.contains("function useSession()"), "got:\n{stdout}");
+}
+
+#[test]
+fn test_barrel_exports_off_by_default() {
+    let dir = feature_folder();
+    common::skim()
+        .arg(dir.path())
+        .arg("--no-cache")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("skim: folder exports").not());
+}
+
+#[test]
+fn test_barrel_exports_requires_directory() {
+    let dir = feature_folder();
+    common::skim()
+        .arg(dir.path().join("index.ts"))
+        .arg("--barrel-exports")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--barrel-exports requires a single directory argument",
+        ));
+}
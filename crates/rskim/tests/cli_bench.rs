@@ -0,0 +1,126 @@
+//! Integration tests for `skim bench` (criterion regression gate).
+//!
+//! All tests use `--no-run` against a synthetic criterion directory so they
+//! never invoke `cargo bench`.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn write_result(root: &Path, id: &str, mean_ns: f64) {
+    let run_dir = root.join(id).join("new");
+    fs::create_dir_all(&run_dir).unwrap();
+    fs::write(
+        run_dir.join("benchmark.json"),
+        format!(r#"{{"group_id":"{id}","function_id":null,"full_id":"{id}"}}"#),
+    )
+    .unwrap();
+    fs::write(
+        run_dir.join("estimates.json"),
+        format!(r#"{{"mean":{{"point_estimate":{mean_ns}}}}}"#),
+    )
+    .unwrap();
+}
+
+fn write_baseline(path: &Path, mean_ns: f64) {
+    fs::write(
+        path,
+        format!(r#"{{"version":1,"benchmarks":{{"cl100k_100kb":{{"mean_ns":{mean_ns}}}}}}}"#),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_bench_check_passes_within_threshold() {
+    let dir = TempDir::new().unwrap();
+    let criterion = dir.path().join("criterion");
+    write_result(&criterion, "cl100k_100kb", 1_050_000.0);
+    let baseline = dir.path().join("baseline.json");
+    write_baseline(&baseline, 1_000_000.0);
+
+    common::skim()
+        .args(["bench", "--check", "--no-run"])
+        .arg("--baseline")
+        .arg(&baseline)
+        .arg("--criterion-dir")
+        .arg(&criterion)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cl100k_100kb"))
+        .stdout(predicate::str::contains("throughput"));
+}
+
+#[test]
+fn test_bench_check_fails_on_regression() {
+    let dir = TempDir::new().unwrap();
+    let criterion = dir.path().join("criterion");
+    write_result(&criterion, "cl100k_100kb", 1_500_000.0);
+    let baseline = dir.path().join("baseline.json");
+    write_baseline(&baseline, 1_000_000.0);
+
+    common::skim()
+        .args(["bench", "--check", "--no-run", "--max-regression", "10"])
+        .arg("--baseline")
+        .arg(&baseline)
+        .arg("--criterion-dir")
+        .arg(&criterion)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "1 benchmark(s) lost more than 10%",
+        ));
+}
+
+#[test]
+fn test_bench_save_then_check_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let criterion = dir.path().join("criterion");
+    write_result(&criterion, "cl100k_100kb", 1_000_000.0);
+    let baseline = dir.path().join("baseline.json");
+
+    common::skim()
+        .args(["bench", "--no-run", "--save"])
+        .arg(&baseline)
+        .arg("--criterion-dir")
+        .arg(&criterion)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("saved 1 benchmark(s)"));
+
+    common::skim()
+        .args(["bench", "--check", "--no-run"])
+        .arg("--baseline")
+        .arg(&baseline)
+        .arg("--criterion-dir")
+        .arg(&criterion)
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_bench_without_results_fails_loudly() {
+    let dir = TempDir::new().unwrap();
+    let baseline = dir.path().join("baseline.json");
+    write_baseline(&baseline, 1_000_000.0);
+
+    common::skim()
+        .args(["bench", "--check", "--no-run"])
+        .arg("--baseline")
+        .arg(&baseline)
+        .arg("--criterion-dir")
+        .arg(dir.path().join("missing"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no criterion results found"));
+}
+
+#[test]
+fn test_bench_help() {
+    common::skim()
+        .args(["bench", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--max-regression"));
+}
@@ -0,0 +1,80 @@
+//! Integration tests for `skim bench-cache` (cold vs warm cache benchmark).
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+fn source_dir() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("a.ts"),
+        "function a(): number { return 1; }\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("b.py"), "def b():\n    return 2\n").unwrap();
+    dir
+}
+
+#[test]
+fn test_bench_cache_json_report_leaves_user_cache_alone() {
+    let src = source_dir();
+    let user_cache = TempDir::new().unwrap();
+
+    let output = common::skim()
+        .env("SKIM_CACHE_DIR", user_cache.path())
+        .arg("bench-cache")
+        .arg(src.path())
+        .args([
+            "--runs",
+            "1",
+            "--format",
+            "json",
+            "--",
+            "--mode",
+            "signatures",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["schema_version"], 1);
+    assert_eq!(report["runs"], 1);
+    assert_eq!(report["files"], 2);
+    assert_eq!(report["hits"], 2);
+    assert_eq!(report["hit_ratio"], 1.0);
+    assert!(report["cache"]["entries"].as_u64().unwrap() >= 2);
+    assert!(report["cache"]["bytes"].as_u64().unwrap() > 0);
+
+    assert_eq!(fs::read_dir(user_cache.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_bench_cache_text_report() {
+    let src = source_dir();
+    common::skim()
+        .arg("bench-cache")
+        .arg(src.path())
+        .args(["--runs", "2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hit rate"))
+        .stdout(predicate::str::contains("(4/4 lookups)"))
+        .stdout(predicate::str::contains("Median of 2 cold/warm runs"));
+}
+
+#[test]
+fn test_bench_cache_rejects_uncached_runs() {
+    let src = source_dir();
+    common::skim()
+        .arg("bench-cache")
+        .arg(src.path())
+        .args(["--runs", "1", "--", "--no-cache"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no cache lookups"));
+}
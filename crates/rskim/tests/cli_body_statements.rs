@@ -0,0 +1,38 @@
+//! Integration tests for `--body-statements`.
+
+use predicates::prelude::*;
+mod common;
+
+const SOURCE: &str = "def load(path):\n    if not path:\n        raise ValueError(path)\n    text = read(path)\n    return parse(text)\n";
+
+#[test]
+fn test_body_statements_keeps_leading_statements() {
+    common::skim()
+        .args([
+            "-",
+            "--language=python",
+            "--body-statements=1",
+            "--no-cache",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout("def load(path):\n    if not path:\n        raise ValueError(path)\n    ...\n");
+}
+
+#[test]
+fn test_body_statements_requires_structure_mode() {
+    common::skim()
+        .args([
+            "-",
+            "--language=python",
+            "--mode=signatures",
+            "--body-statements=1",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--body-statements requires --mode structure",
+        ));
+}
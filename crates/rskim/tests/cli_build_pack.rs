@@ -0,0 +1,105 @@
+//! Integration tests for `skim build` (context packs from `.skim.toml` manifests).
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const MANIFEST: &str = "inputs = [\"src/\"]\noutput = \"out/context.md\"\nmode = \"signatures\"\n\
+                        format = \"markdown\"\nflags = [\"--public-only\"]\n";
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    fs::write(
+        dir.path().join("src/lib.rs"),
+        "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n\nfn helper() {}\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("contextpack.skim.toml"), MANIFEST).unwrap();
+    dir
+}
+
+#[test]
+fn test_build_writes_the_same_bundle_as_the_equivalent_command() {
+    let dir = project();
+    common::skim()
+        .current_dir(dir.path())
+        .arg("build")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("context.md: wrote"));
+
+    let expected = common::skim()
+        .current_dir(dir.path())
+        .args([
+            "src/",
+            "--no-cache",
+            "--mode=signatures",
+            "--format=markdown",
+            "--public-only",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let built = fs::read(dir.path().join("out/context.md")).unwrap();
+    assert_eq!(built, expected);
+    assert!(!String::from_utf8(built).unwrap().contains("helper"));
+}
+
+#[test]
+fn test_check_fails_once_an_input_changes() {
+    let dir = project();
+    let manifest = dir.path().join("contextpack.skim.toml");
+    common::skim()
+        .arg("build")
+        .arg(&manifest)
+        .assert()
+        .success();
+    common::skim()
+        .args(["build", "--check"])
+        .arg(&manifest)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("up to date"));
+
+    fs::write(dir.path().join("src/extra.rs"), "pub fn extra() {}\n").unwrap();
+    common::skim()
+        .args(["build", "--check"])
+        .arg(&manifest)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("out of date"));
+    assert!(
+        !fs::read_to_string(dir.path().join("out/context.md"))
+            .unwrap()
+            .contains("extra"),
+        "--check must not rewrite the pack"
+    );
+}
+
+#[test]
+fn test_build_rejects_bad_manifests() {
+    let dir = project();
+    fs::write(
+        dir.path().join("contextpack.skim.toml"),
+        "inputs = [\".\"]\noutput = \"context.md\"\n",
+    )
+    .unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("lies inside input"));
+
+    let empty = TempDir::new().unwrap();
+    common::skim()
+        .current_dir(empty.path())
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no *.skim.toml manifest"));
+}
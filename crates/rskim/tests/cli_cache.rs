@@ -501,3 +501,150 @@ fn test_cache_stats_computed_on_hit_when_missing() {
         .success()
         .stderr(predicate::str::contains("[skim]"));
 }
+
+// ============================================================================
+// --cache-keys repo-relative
+// ============================================================================
+
+/// A git checkout at a fresh path with `src/app.ts`.
+fn checkout() -> (TempDir, std::path::PathBuf) {
+    let root = TempDir::new().unwrap();
+    fs::create_dir(root.path().join(".git")).unwrap();
+    fs::create_dir(root.path().join("src")).unwrap();
+    let file = root.path().join("src").join("app.ts");
+    fs::write(
+        &file,
+        "export function add(a: number, b: number) { return a + b; }",
+    )
+    .unwrap();
+    (root, file)
+}
+
+fn cache_entries(cache_dir: &std::path::Path) -> usize {
+    fs::read_dir(cache_dir)
+        .unwrap()
+        .filter(|e| {
+            e.as_ref()
+                .unwrap()
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "json")
+        })
+        .count()
+}
+
+#[test]
+fn test_repo_relative_cache_shared_across_checkouts() {
+    let cache_dir = TempDir::new().unwrap();
+    let (_a, file_a) = checkout();
+    let (_b, file_b) = checkout();
+
+    let run = |file: &std::path::Path, keys: &str| {
+        common::skim()
+            .env("SKIM_CACHE_DIR", cache_dir.path())
+            .arg(file)
+            .args(["--cache-keys", keys])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone()
+    };
+
+    let first = run(&file_a, "repo-relative");
+    let entries = cache_entries(cache_dir.path());
+    assert!(entries > 0, "first run should write a cache entry");
+
+    // The second checkout hits the first checkout's entry
+    assert_eq!(run(&file_b, "repo-relative"), first);
+    assert_eq!(cache_entries(cache_dir.path()), entries);
+
+    // Absolute keys keep the checkouts apart
+    run(&file_b, "absolute");
+    assert!(cache_entries(cache_dir.path()) > entries);
+}
+
+#[test]
+fn test_cache_keys_with_no_cache_is_error() {
+    let (_root, file) = checkout();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "--cache-keys", "repo-relative"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--cache-keys cannot be combined with --no-cache",
+        ));
+}
+
+// ============================================================================
+// --precompute-modes
+// ============================================================================
+
+#[test]
+fn test_precompute_modes_makes_other_modes_cache_hits() {
+    let cache_dir = TempDir::new().unwrap();
+    let src_dir = TempDir::new().unwrap();
+    let file = src_dir.path().join("shapes.ts");
+    fs::write(
+        &file,
+        "/** Area. */\nexport function area(r: number): number {\n  // circle\n  return Math.PI * r * r;\n}\nexport interface Shape { r: number }\n",
+    )
+    .unwrap();
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .arg(&file)
+        .args(["--precompute-modes", "--line-numbers"])
+        .assert()
+        .success();
+    assert_eq!(cache_entries(cache_dir.path()), 15, "one entry per mode");
+
+    for mode in [
+        "signatures",
+        "types",
+        "minimal",
+        "pseudo",
+        "full",
+        "outline",
+        "docs",
+        "public-api",
+        "comments",
+        "call-graph",
+        "todos",
+        "summary",
+        "constants",
+        "errors",
+    ] {
+        let uncached = common::skim()
+            .arg(&file)
+            .args(["--no-cache", "--line-numbers", "--mode", mode])
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+        common::skim()
+            .env("SKIM_CACHE_DIR", cache_dir.path())
+            .arg(&file)
+            .args(["--line-numbers", "--mode", mode, "--report-usage=json"])
+            .assert()
+            .success()
+            .stdout(uncached)
+            .stderr(predicate::str::contains(r#""hits":1"#));
+    }
+    assert_eq!(cache_entries(cache_dir.path()), 15);
+}
+
+#[test]
+fn test_precompute_modes_with_tokens_is_error() {
+    let (_root, file) = checkout();
+    common::skim()
+        .arg(&file)
+        .args(["--precompute-modes", "--tokens", "100"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--precompute-modes cannot be combined with --tokens",
+        ));
+}
@@ -0,0 +1,69 @@
+//! Integration tests for `--mode call-graph`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const APP_PY: &str = "class App:\n    def start(self):\n        cfg = load(\"x\")\n        \
+                      self.render(cfg)\n\n    def render(self, cfg):\n        \
+                      print(cfg)\n\nApp().start()\n";
+
+#[test]
+fn test_call_graph_lists_caller_callee_edges() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("app.py");
+    fs::write(&file, APP_PY).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "-m", "cg"])
+        .assert()
+        .success()
+        .stdout(
+            "App.start -> load\nApp.start -> self.render\nApp.render -> print\n\
+             (top level) -> start\n(top level) -> App\n",
+        );
+}
+
+#[test]
+fn test_call_graph_dot_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("app.py");
+    fs::write(&file, APP_PY).unwrap();
+    common::skim()
+        .arg(&file)
+        .args([
+            "--no-cache",
+            "--mode",
+            "call-graph",
+            "--dot",
+            "--line-numbers",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "digraph calls {\n3\t  \"App.start\" -> \"load\";\n",
+        ))
+        .stdout(predicate::str::ends_with("}\n"));
+}
+
+#[test]
+fn test_dot_requires_call_graph_mode() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("app.py");
+    fs::write(&file, APP_PY).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "outline", "--dot"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--dot requires --mode call-graph"));
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "call-graph", "--validate-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--validate-output cannot be combined with --mode call-graph",
+        ));
+}
@@ -0,0 +1,120 @@
+//! Integration tests for `--changed-only`.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("a.ts"),
+        "export function a(): number {\n  return 1;\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("b.ts"),
+        "export function b(): number {\n  return 2;\n}\n",
+    )
+    .unwrap();
+    dir
+}
+
+/// Rewrite `path` and move its mtime forward so the cache sees the edit even
+/// within the same second.
+fn edit(path: &Path, content: &str) {
+    fs::write(path, content).unwrap();
+    filetime::set_file_mtime(path, filetime::FileTime::from_unix_time(2_000_000_000, 0)).unwrap();
+}
+
+fn run(cache_dir: &Path, dir: &Path) -> String {
+    let output = common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir)
+        .arg(dir)
+        .arg("--changed-only")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_changed_only_first_run_prints_everything() {
+    let cache_dir = TempDir::new().unwrap();
+    let dir = project();
+    let stdout = run(cache_dir.path(), dir.path());
+    assert!(stdout.contains("function a()"), "got:\n{stdout}");
+    assert!(stdout.contains("function b()"), "got:\n{stdout}");
+    assert!(!stdout.contains("(unchanged)"), "got:\n{stdout}");
+}
+
+#[test]
+fn test_changed_only_reduces_unchanged_files_to_one_line() {
+    let cache_dir = TempDir::new().unwrap();
+    let dir = project();
+    run(cache_dir.path(), dir.path());
+
+    // Body-only edit: the structure output of a.ts does not change.
+    edit(
+        &dir.path().join("a.ts"),
+        "export function a(): number {\n  return 10;\n}\n",
+    );
+    edit(
+        &dir.path().join("b.ts"),
+        "export function b(x: number): number {\n  return x;\n}\n",
+    );
+
+    let stdout = run(cache_dir.path(), dir.path());
+    let a = dir.path().join("a.ts");
+    assert!(
+        stdout.contains(&format!("// {} (unchanged)\n", a.display())),
+        "got:\n{stdout}"
+    );
+    assert!(!stdout.contains("function a()"), "got:\n{stdout}");
+    assert!(stdout.contains("function b(x: number)"), "got:\n{stdout}");
+
+    let stdout = run(cache_dir.path(), dir.path());
+    assert_eq!(stdout.matches("(unchanged)").count(), 2, "got:\n{stdout}");
+}
+
+#[test]
+fn test_changed_only_tracks_modes_separately() {
+    let cache_dir = TempDir::new().unwrap();
+    let dir = project();
+    run(cache_dir.path(), dir.path());
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .arg(dir.path())
+        .args(["--changed-only", "--mode", "signatures"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(unchanged)").not());
+}
+
+#[test]
+fn test_changed_only_single_file_is_error() {
+    let dir = project();
+    common::skim()
+        .arg(dir.path().join("a.ts"))
+        .arg("--changed-only")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--changed-only requires a directory, glob, or several files",
+        ));
+}
+
+#[test]
+fn test_changed_only_with_no_cache_is_error() {
+    let dir = project();
+    common::skim()
+        .arg(dir.path())
+        .args(["--changed-only", "--no-cache"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--changed-only cannot be combined with --no-cache",
+        ));
+}
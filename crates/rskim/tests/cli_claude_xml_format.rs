@@ -0,0 +1,103 @@
+//! Integration tests for `--format claude-xml`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const SOURCE: &str = "pub fn parse_config(path: &str) -> String {\n    path.to_string()\n}\n";
+
+#[test]
+fn test_claude_xml_wraps_files_with_index() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("alpha.ts"),
+        "function alpha() { return 1; }\n",
+    )
+    .unwrap();
+    fs::write(temp.path().join("beta.py"), "def beta():\n    return 2\n").unwrap();
+
+    let output = common::skim()
+        .current_dir(temp.path())
+        .args(["alpha.ts", "beta.py", "--no-cache", "--format=claude-xml"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+
+    assert!(
+        stdout.starts_with(
+            "<documents>\n<index>\n\
+             <file index=\"1\" path=\"alpha.ts\" language=\"typescript\"/>\n"
+        ),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("<file index=\"2\" path=\"beta.py\" language=\"python\"/>\n</index>\n"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("<document index=\"1\" path=\"alpha.ts\">\nfunction alpha()"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("<document index=\"2\" path=\"beta.py\">\ndef beta():"),
+        "{stdout}"
+    );
+    assert!(stdout.ends_with("</document>\n</documents>\n"), "{stdout}");
+    assert!(
+        !stdout.contains("// alpha.ts"),
+        "no default headers: {stdout}"
+    );
+    assert!(
+        !stdout.contains("return 1"),
+        "bodies are still skimmed: {stdout}"
+    );
+}
+
+#[test]
+fn test_claude_xml_single_file_is_wrapped() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("config.rs"), SOURCE).unwrap();
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["config.rs", "--no-cache", "--format", "claude-xml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "<documents>\n<index>\n<file index=\"1\" path=\"config.rs\" language=\"rust\"/>\n</index>\n\
+             <document index=\"1\" path=\"config.rs\">\npub fn parse_config(path: &str) -> String",
+        ));
+}
+
+#[test]
+fn test_claude_xml_stdin_uses_filename_label() {
+    common::skim()
+        .args(["-", "--filename=src/config.rs", "--format=claude-xml"])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "<document index=\"1\" path=\"src/config.rs\">\n",
+        ))
+        .stdout(predicate::str::ends_with("</document>\n</documents>\n"));
+}
+
+#[test]
+fn test_claude_xml_rejects_template() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("config.rs"), SOURCE).unwrap();
+    fs::write(temp.path().join("t.hbs"), "{{mode}}").unwrap();
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["config.rs", "--format=claude-xml", "--template", "t.hbs"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--template cannot be combined with --format",
+        ));
+}
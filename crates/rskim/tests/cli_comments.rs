@@ -0,0 +1,54 @@
+//! Integration tests for `--mode comments`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const CONFIG_RS: &str = "//! Config loading.\n\n/// Configuration read from disk.\npub struct Config {\n    \
+                         path: String,\n}\n\nimpl Config {\n    fn validate(&self) -> bool {\n        \
+                         // TODO: reject symlinks\n        !self.path.is_empty()\n    }\n}\n";
+
+#[test]
+fn test_comments_lists_blocks_under_their_owners() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("config.rs");
+    fs::write(&file, CONFIG_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "-m", "c"])
+        .assert()
+        .success()
+        .stdout(
+            "1:\n//! Config loading.\n\n3: Config\n/// Configuration read from disk.\n\n\
+             10: Config.validate\n// TODO: reject symlinks\n",
+        );
+}
+
+#[test]
+fn test_comments_line_numbers_point_at_source_lines() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("config.rs");
+    fs::write(&file, CONFIG_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "--mode", "comments", "--line-numbers"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("10\t// TODO: reject symlinks"));
+}
+
+#[test]
+fn test_comments_rejects_validate_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("config.rs");
+    fs::write(&file, CONFIG_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "comments", "--validate-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--validate-output cannot be combined with --mode comments",
+        ));
+}
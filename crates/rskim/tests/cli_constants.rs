@@ -0,0 +1,40 @@
+//! Integration tests for `--mode constants`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const CONFIG_PY: &str = "import os\n\nTIMEOUT = 30\nREGIONS = [\"eu\", \"us\"]\n\n\
+                         def main():\n    retries = 3\n";
+
+#[test]
+fn test_constants_keeps_values_and_passes_data_files_through() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("config.py"), CONFIG_PY).unwrap();
+    fs::write(dir.path().join("app.json"), "{\"port\": 8080}\n").unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["config.py", "app.json", "--no-cache", "-m", "const"])
+        .assert()
+        .success()
+        .stdout(
+            "// app.json\n{\"port\": 8080}\n\n\
+             // config.py\nTIMEOUT = 30\nREGIONS = [\"eu\", \"us\"]\n",
+        );
+}
+
+#[test]
+fn test_constants_rejects_validate_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("config.py");
+    fs::write(&file, CONFIG_PY).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "constants", "--validate-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--validate-output cannot be combined with --mode constants",
+        ));
+}
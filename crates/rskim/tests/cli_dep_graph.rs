@@ -0,0 +1,70 @@
+//! Integration tests for `--dep-graph`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("lib")).unwrap();
+    fs::write(
+        root.join("app.ts"),
+        "import { load } from './lib/config';\nimport React from 'react';\n",
+    )
+    .unwrap();
+    fs::write(root.join("lib/config.ts"), "export const load = () => 1;\n").unwrap();
+    fs::write(root.join("legacy.ts"), "export const old = 1;\n").unwrap();
+    fs::write(root.join("data.json"), "{}\n").unwrap();
+    dir
+}
+
+#[test]
+fn test_dep_graph_prints_edges_then_orphans() {
+    let dir = project();
+    common::skim()
+        .arg(dir.path())
+        .arg("--dep-graph")
+        .assert()
+        .success()
+        .stdout(
+            "app.ts -> lib/config.ts\n\n\
+             // orphans: no imports to or from other files\nlegacy.ts\n",
+        )
+        .stderr(predicate::str::contains(
+            "4 file(s), 1 import edge(s), 1 orphan(s)",
+        ));
+}
+
+#[test]
+fn test_dep_graph_dot_output() {
+    let dir = project();
+    common::skim()
+        .arg(dir.path())
+        .args(["--dep-graph", "--dot"])
+        .assert()
+        .success()
+        .stdout("digraph deps {\n  \"app.ts\" -> \"lib/config.ts\";\n  \"legacy.ts\";\n}\n");
+}
+
+#[test]
+fn test_dep_graph_requires_a_directory() {
+    let dir = project();
+    common::skim()
+        .arg(dir.path().join("app.ts"))
+        .arg("--dep-graph")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--dep-graph requires a single directory argument",
+        ));
+    common::skim()
+        .arg(dir.path())
+        .args(["--dep-graph", "--format", "markdown"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--dep-graph cannot be combined with --format",
+        ));
+}
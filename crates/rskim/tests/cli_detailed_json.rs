@@ -0,0 +1,106 @@
+//! Integration tests for `--detailed-json`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const SOURCE: &str =
+    "type Id = string;\n\nexport function load(id: Id): string {\n  return id;\n}\n";
+
+fn records(stdout: &[u8]) -> Vec<serde_json::Value> {
+    String::from_utf8_lossy(stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_single_file_prints_one_record() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("a.ts");
+    fs::write(&file, SOURCE).unwrap();
+
+    let output = common::skim()
+        .arg(&file)
+        .args(["--detailed-json", "-m", "signatures"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let records = records(&output.stdout);
+    assert_eq!(records.len(), 1);
+    let record = &records[0];
+    assert_eq!(record["path"], file.display().to_string());
+    assert_eq!(record["language"], "typescript");
+    assert_eq!(record["mode"], "signatures");
+    assert!(
+        record["content"]
+            .as_str()
+            .unwrap()
+            .contains("function load(id: Id): string")
+    );
+    assert!(record["original_tokens"].as_u64().unwrap() > 0);
+    assert!(record["transformed_tokens"].as_u64().unwrap() > 0);
+    assert!(record["duration_ms"].as_f64().unwrap() >= 0.0);
+}
+
+#[test]
+fn test_directory_prints_one_record_per_file_without_headers() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.ts"), SOURCE).unwrap();
+    fs::write(dir.path().join("b.py"), "def f(x):\n    return x\n").unwrap();
+
+    let output = common::skim()
+        .arg(dir.path())
+        .arg("--detailed-json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("// "), "no path headers: {stdout}");
+    let mut languages: Vec<String> = records(&output.stdout)
+        .iter()
+        .map(|record| record["language"].as_str().unwrap().to_string())
+        .collect();
+    languages.sort();
+    assert_eq!(languages, ["python", "typescript"]);
+}
+
+#[test]
+fn test_stdin_uses_filename_hint_or_dash() {
+    let output = common::skim()
+        .args(["-", "--language", "typescript", "--detailed-json"])
+        .write_stdin(SOURCE)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(records(&output.stdout)[0]["path"], "-");
+
+    let output = common::skim()
+        .args(["-", "--filename", "src/a.ts", "--detailed-json"])
+        .write_stdin(SOURCE)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(records(&output.stdout)[0]["path"], "src/a.ts");
+}
+
+#[test]
+fn test_detailed_json_rejects_conflicting_flags() {
+    common::skim()
+        .args([
+            "-",
+            "--language",
+            "typescript",
+            "--detailed-json",
+            "--show-stats",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--detailed-json cannot be combined with --show-stats",
+        ));
+}
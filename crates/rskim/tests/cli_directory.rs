@@ -63,6 +63,44 @@ fn test_directory_mixed_languages() {
     assert!(stdout.contains("rust_func"));
 }
 
+#[test]
+fn test_directory_detects_dockerfile_by_name() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("Dockerfile"),
+        "FROM alpine\nRUN apk add curl \\\n    && rm -rf /var/cache/apk/*\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
+
+    common::skim()
+        .arg(temp_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Dockerfile"))
+        .stdout(predicate::str::contains("RUN apk add ... && rm -rf ..."));
+}
+
+#[test]
+fn test_directory_summarizes_csv_files() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("cities.tsv"),
+        "city\tpopulation\nOslo\t709000\nBergen\t291000\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
+
+    common::skim()
+        .arg(temp_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "# population: integer\n# 2 rows\n",
+        ))
+        .stdout(predicate::str::contains("Oslo").not());
+}
+
 #[test]
 fn test_directory_recursive() {
     let temp_dir = TempDir::new().unwrap();
@@ -150,6 +188,41 @@ fn test_directory_only_unsupported_files() {
         .stderr(predicate::str::contains("No files found"));
 }
 
+#[test]
+fn test_directory_without_supported_files_is_skipped_beside_other_inputs() {
+    let temp_dir = TempDir::new().unwrap();
+    let src = temp_dir.path().join("src");
+    let assets = temp_dir.path().join("assets");
+    fs::create_dir_all(&src).unwrap();
+    fs::create_dir_all(&assets).unwrap();
+    fs::write(src.join("main.ts"), "function main() { return 1; }").unwrap();
+    fs::write(assets.join("logo.bin"), "binary").unwrap();
+
+    common::skim()
+        .arg(&src)
+        .arg(&assets)
+        .arg("--no-cache")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function main()"))
+        .stderr(predicate::str::contains(format!(
+            "Skipped 1 directory with no supported files: '{}'",
+            assets.display()
+        )))
+        .stderr(predicate::str::contains("Warning").not());
+
+    common::skim()
+        .arg(&src)
+        .arg(&assets)
+        .args(["--no-cache", "--fail-if-empty"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "No files found in directory '{}'",
+            assets.display()
+        )));
+}
+
 #[test]
 fn test_directory_with_modes() {
     let temp_dir = TempDir::new().unwrap();
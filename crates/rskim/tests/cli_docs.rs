@@ -0,0 +1,40 @@
+//! Integration tests for `--mode docs`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+#[test]
+fn test_docs_keeps_doc_comments_with_signatures() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("server.go");
+    fs::write(
+        &file,
+        "package main\n\n// Run starts the server.\n// It blocks.\nfunc Run(addr string) error {\n\
+         \treturn nil\n}\n\n// unrelated\n\nfunc helper() {}\n",
+    )
+    .unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "-m", "d"])
+        .assert()
+        .success()
+        .stdout(
+            "// Run starts the server.\n// It blocks.\nfunc Run(addr string) error\nfunc helper()",
+        );
+}
+
+#[test]
+fn test_docs_warns_for_languages_without_doc_comments() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("tool.rb");
+    fs::write(&file, "# Greets.\ndef greet(name)\n  puts name\nend\n").unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "--mode", "docs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("def greet(name)"))
+        .stderr(predicate::str::contains("docs mode keeps signatures only"));
+}
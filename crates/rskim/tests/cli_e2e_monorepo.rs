@@ -0,0 +1,381 @@
+//! End-to-end tests running the real binary against a synthetic monorepo.
+//!
+//! Feature tests (`cli_directory.rs`, `cli_glob.rs`, `cli_cache.rs`, ...) each
+//! exercise one behavior on a minimal tree. These tests build one realistic
+//! tree and assert how the behaviors combine: walk order and headers across
+//! nested directories, ignore rules, symlinks, files that fail to read,
+//! aggregated stats, exit codes, and cache reuse between runs.
+//!
+//! ## Fixture layout
+//!
+//! ```text
+//! .gitignore                      dist/ and node_modules/
+//! .hidden/secret.ts               hidden: skipped unless --no-ignore
+//! crates/core/src/lib.rs
+//! docs/guide.md
+//! notes.txt                       unsupported extension: never listed
+//! packages/web/dist/bundle.js     ignored: skipped unless --no-ignore
+//! packages/web/node_modules/x.js  ignored: skipped unless --no-ignore
+//! packages/web/src/app.ts
+//! packages/web/src/util.js
+//! services/api/broken.py          invalid UTF-8: fails to process
+//! services/api/handler.py
+//! services/api/main.go
+//! linked.ts -> packages/web/src/app.ts   (unix) symlinks are not followed
+//! linked_dir -> packages/web/src         (unix)
+//! ```
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+mod common;
+
+/// Files that process successfully with default ignore rules, in walk order.
+const PROCESSED: &[&str] = &[
+    "crates/core/src/lib.rs",
+    "docs/guide.md",
+    "packages/web/src/app.ts",
+    "packages/web/src/util.js",
+    "services/api/handler.py",
+    "services/api/main.go",
+];
+
+const BROKEN: &str = "services/api/broken.py";
+
+fn write(root: &Path, rel: &str, contents: &[u8]) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+/// Build the monorepo described in the module docs.
+fn monorepo() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+
+    write(root, ".gitignore", b"dist/\nnode_modules/\n");
+    write(
+        root,
+        ".hidden/secret.ts",
+        b"export function hiddenSecret(): string {\n  return 'x';\n}\n",
+    );
+    write(
+        root,
+        "crates/core/src/lib.rs",
+        b"pub fn core_entry(input: &str) -> usize {\n    input.len()\n}\n",
+    );
+    write(root, "docs/guide.md", b"# Guide\n\nRead the guide.\n");
+    write(root, "notes.txt", b"unsupported notes\n");
+    write(
+        root,
+        "packages/web/dist/bundle.js",
+        b"function bundledOutput() {\n  return 1;\n}\n",
+    );
+    write(
+        root,
+        "packages/web/node_modules/x.js",
+        b"function vendoredModule() {\n  return 1;\n}\n",
+    );
+    write(
+        root,
+        "packages/web/src/app.ts",
+        b"export function render(view: string): string {\n  return view.trim();\n}\n",
+    );
+    write(
+        root,
+        "packages/web/src/util.js",
+        b"function formatDate(d) {\n  return d.toISOString();\n}\n",
+    );
+    write(root, BROKEN, b"def broken():\n    return '\xff\xfe'\n");
+    write(
+        root,
+        "services/api/handler.py",
+        b"def handle(request):\n    return request.body\n",
+    );
+    write(
+        root,
+        "services/api/main.go",
+        b"package main\n\nfunc serveAPI() error {\n\treturn nil\n}\n",
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::symlink;
+        symlink(root.join("packages/web/src/app.ts"), root.join("linked.ts")).unwrap();
+        symlink(root.join("packages/web/src"), root.join("linked_dir")).unwrap();
+    }
+
+    temp
+}
+
+/// Multi-file header line for `rel` under `root`.
+fn header(root: &Path, rel: &str) -> String {
+    format!("// {}", root.join(rel).display())
+}
+
+/// Header lines in the order they appear in `stdout`.
+fn headers(stdout: &str) -> Vec<&str> {
+    stdout.lines().filter(|l| l.starts_with("// /")).collect()
+}
+
+fn run(cmd: &mut assert_cmd::Command) -> (String, String) {
+    let output = cmd.output().unwrap();
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+// ============================================================================
+// Ordering, headers, and exclusions
+// ============================================================================
+
+#[test]
+fn test_monorepo_headers_follow_sorted_walk_order() {
+    let repo = monorepo();
+    let root = repo.path();
+
+    let (stdout, _) = run(common::skim().arg(root).arg("--no-cache"));
+
+    let expected: Vec<String> = PROCESSED.iter().map(|rel| header(root, rel)).collect();
+    assert_eq!(headers(&stdout), expected, "stdout:\n{stdout}");
+}
+
+#[test]
+fn test_monorepo_separates_files_with_blank_line() {
+    let repo = monorepo();
+    let root = repo.path();
+
+    let (stdout, _) = run(common::skim().arg(root).arg("--no-cache"));
+
+    for rel in &PROCESSED[1..] {
+        let needle = format!("\n\n{}\n", header(root, rel));
+        assert!(stdout.contains(&needle), "missing separator before {rel}");
+    }
+    assert!(stdout.starts_with(&header(root, PROCESSED[0])));
+}
+
+#[test]
+fn test_monorepo_skips_ignored_hidden_unsupported_and_symlinked_files() {
+    let repo = monorepo();
+
+    let (stdout, _) = run(common::skim().arg(repo.path()).arg("--no-cache"));
+
+    for absent in [
+        "bundledOutput",
+        "vendoredModule",
+        "hiddenSecret",
+        "unsupported notes",
+        "linked",
+    ] {
+        assert!(!stdout.contains(absent), "{absent} leaked into:\n{stdout}");
+    }
+    assert_eq!(
+        stdout.matches("function render").count(),
+        1,
+        "symlinks must not duplicate app.ts:\n{stdout}"
+    );
+}
+
+#[test]
+fn test_monorepo_no_ignore_adds_ignored_and_hidden_but_not_symlinks() {
+    let repo = monorepo();
+
+    let (stdout, _) = run(common::skim()
+        .arg(repo.path())
+        .args(["--no-cache", "--no-ignore"]));
+
+    assert!(stdout.contains("bundledOutput"));
+    assert!(stdout.contains("vendoredModule"));
+    assert!(stdout.contains("hiddenSecret"));
+    assert!(!stdout.contains("unsupported notes"));
+    assert_eq!(stdout.matches("function render").count(), 1);
+}
+
+#[test]
+fn test_monorepo_no_header_drops_headers_only() {
+    let repo = monorepo();
+    let root = repo.path();
+
+    let (stdout, _) = run(common::skim().arg(root).args(["--no-cache", "--no-header"]));
+
+    assert!(headers(&stdout).is_empty(), "stdout:\n{stdout}");
+    for symbol in ["core_entry", "render", "formatDate", "handle", "serveAPI"] {
+        assert!(stdout.contains(symbol), "{symbol} missing:\n{stdout}");
+    }
+}
+
+#[test]
+fn test_monorepo_glob_spans_nested_directories() {
+    let repo = monorepo();
+    let root = repo.path();
+    let pattern = format!("{}/**/*.{{ts,js}}", root.display());
+
+    let (stdout, _) = run(common::skim().arg(&pattern).arg("--no-cache"));
+
+    assert_eq!(
+        headers(&stdout),
+        vec![
+            header(root, "packages/web/src/app.ts"),
+            header(root, "packages/web/src/util.js"),
+        ],
+        "stdout:\n{stdout}"
+    );
+}
+
+// ============================================================================
+// Failures and exit codes
+// ============================================================================
+
+#[test]
+fn test_monorepo_bad_file_is_reported_without_failing_run() {
+    let repo = monorepo();
+    let root = repo.path();
+
+    common::skim()
+        .arg(root)
+        .arg("--no-cache")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(format!(
+            "Error processing {}",
+            root.join(BROKEN).display()
+        )))
+        .stderr(predicate::str::contains(format!(
+            "Processed {} file(s) successfully, 1 failed",
+            PROCESSED.len()
+        )));
+}
+
+#[test]
+fn test_monorepo_all_files_failing_exits_nonzero() {
+    let repo = monorepo();
+    let root = repo.path();
+    let pattern = format!("{}/services/**/broken.py", root.display());
+
+    common::skim()
+        .arg(&pattern)
+        .arg("--no-cache")
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("All 1 file(s) failed to process"));
+}
+
+#[test]
+fn test_monorepo_single_bad_file_exits_nonzero() {
+    let repo = monorepo();
+
+    common::skim()
+        .arg(repo.path().join(BROKEN))
+        .arg("--no-cache")
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty());
+}
+
+// ============================================================================
+// Stats
+// ============================================================================
+
+#[test]
+fn test_monorepo_stats_aggregate_successful_files_only() {
+    let repo = monorepo();
+
+    common::skim()
+        .arg(repo.path())
+        .args(["--no-cache", "--show-stats"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(format!(
+            "across {} file(s)",
+            PROCESSED.len()
+        )));
+}
+
+#[test]
+fn test_monorepo_stats_stay_off_stdout() {
+    let repo = monorepo();
+
+    let (plain, _) = run(common::skim().arg(repo.path()).arg("--no-cache"));
+    let (with_stats, _) = run(common::skim()
+        .arg(repo.path())
+        .args(["--no-cache", "--show-stats"]));
+
+    assert_eq!(plain, with_stats);
+}
+
+// ============================================================================
+// Cache reuse
+// ============================================================================
+
+fn cache_entries(cache_dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(cache_dir)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect()
+}
+
+/// Rewrite every cached output so a cache hit is visible on stdout.
+fn poison_cache(cache_dir: &Path, from: &str, to: &str) {
+    for entry in cache_entries(cache_dir) {
+        let text = fs::read_to_string(&entry).unwrap();
+        fs::write(&entry, text.replace(from, to)).unwrap();
+    }
+}
+
+#[test]
+fn test_monorepo_second_run_is_served_from_cache() {
+    let repo = monorepo();
+    let cache = TempDir::new().unwrap();
+
+    let (first, _) = run(common::skim()
+        .env("SKIM_CACHE_DIR", cache.path())
+        .arg(repo.path()));
+    assert!(!cache_entries(cache.path()).is_empty());
+
+    poison_cache(cache.path(), "function render", "function renderFromCache");
+    let (second, _) = run(common::skim()
+        .env("SKIM_CACHE_DIR", cache.path())
+        .arg(repo.path()));
+
+    assert!(second.contains("function renderFromCache"), "{second}");
+    assert_eq!(second.replace("renderFromCache", "render"), first);
+}
+
+#[test]
+fn test_monorepo_edited_file_bypasses_stale_cache_entry() {
+    let repo = monorepo();
+    let root = repo.path();
+    let cache = TempDir::new().unwrap();
+
+    run(common::skim().env("SKIM_CACHE_DIR", cache.path()).arg(root));
+    poison_cache(cache.path(), "function render", "function renderFromCache");
+
+    let app = root.join("packages/web/src/app.ts");
+    filetime::set_file_mtime(&app, filetime::FileTime::from_unix_time(2_000_000_000, 0)).unwrap();
+    let (stdout, _) = run(common::skim().env("SKIM_CACHE_DIR", cache.path()).arg(root));
+
+    assert!(stdout.contains("function render"));
+    assert!(!stdout.contains("renderFromCache"), "{stdout}");
+}
+
+#[test]
+fn test_monorepo_cache_is_keyed_by_mode() {
+    let repo = monorepo();
+    let cache = TempDir::new().unwrap();
+
+    run(common::skim()
+        .env("SKIM_CACHE_DIR", cache.path())
+        .arg(repo.path()));
+    poison_cache(cache.path(), "function render", "function renderFromCache");
+    let (stdout, _) = run(common::skim()
+        .env("SKIM_CACHE_DIR", cache.path())
+        .arg(repo.path())
+        .arg("--mode=signatures"));
+
+    assert!(!stdout.contains("renderFromCache"), "{stdout}");
+}
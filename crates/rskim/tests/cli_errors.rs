@@ -0,0 +1,43 @@
+//! Integration tests for `--mode errors`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const LIB_RS: &str = "#[derive(Debug)]\npub enum LoadError {\n    Missing,\n}\n\n\
+                      pub fn load(path: &str) -> Result<(), LoadError> {\n    \
+                      if path.is_empty() {\n        return Err(LoadError::Missing);\n    }\n    \
+                      Ok(())\n}\n";
+const APP_PY: &str = "class App:\n    def run(self):\n        raise NotImplementedError\n";
+
+#[test]
+fn test_errors_lists_types_and_sites_per_file() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("lib.rs"), LIB_RS).unwrap();
+    fs::write(dir.path().join("app.py"), APP_PY).unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["app.py", "lib.rs", "--no-cache", "-m", "err"])
+        .assert()
+        .success()
+        .stdout(
+            "// app.py\n3: raise NotImplementedError (App.run)\n\n\
+             // lib.rs\n2: pub enum LoadError\n8: return Err(LoadError::Missing); (load)\n",
+        );
+}
+
+#[test]
+fn test_errors_rejects_validate_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("lib.rs");
+    fs::write(&file, LIB_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "errors", "--validate-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--validate-output cannot be combined with --mode errors",
+        ));
+}
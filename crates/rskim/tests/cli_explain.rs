@@ -0,0 +1,68 @@
+//! Integration tests for `skim explain` (structure-mode decision trace).
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+#[test]
+fn test_explain_traces_replacements_and_overlaps() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("app.ts");
+    fs::write(
+        &file,
+        "interface User { id: number }\n\
+         function load(u: User) {\n  const pick = () => { return u.id; };\n  return pick();\n}\n",
+    )
+    .unwrap();
+
+    common::skim()
+        .arg("explain")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(TypeScript, structure mode)"))
+        .stdout(predicate::str::contains("AST node kinds"))
+        .stdout(predicate::str::contains("kept: type definition"))
+        .stdout(predicate::str::contains("inside replacement from line 2"))
+        .stdout(predicate::str::contains(
+            "Summary: 1 replaced, 1 skipped by overlap, 1 kept verbatim",
+        ));
+}
+
+#[test]
+fn test_explain_lang_override() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("script.txt");
+    fs::write(&file, "def run():\n    return 1\n").unwrap();
+
+    common::skim()
+        .args(["explain", "--lang", "python"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function_definition"));
+}
+
+#[test]
+fn test_explain_rejects_languages_without_body_replacement() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("data.json");
+    fs::write(&file, "{\"a\": 1}\n").unwrap();
+
+    common::skim()
+        .arg("explain")
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("nothing to explain"));
+}
+
+#[test]
+fn test_explain_help() {
+    common::skim()
+        .args(["explain", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Usage: skim explain"));
+}
@@ -0,0 +1,74 @@
+//! Integration tests for the `--footer` integrity line.
+
+use predicates::prelude::*;
+use sha2::{Digest, Sha256};
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("a.rs"),
+        "pub fn alpha() -> u32 {\n    1\n}\n",
+    )
+    .unwrap();
+    fs::write(temp.path().join("b.py"), "def beta():\n    return 2\n").unwrap();
+    temp
+}
+
+/// Split stdout into (content, footer line) and check the checksum.
+fn verify(stdout: &str) -> String {
+    let body = stdout.strip_suffix('\n').unwrap();
+    let (content, footer) = body.rsplit_once('\n').map_or(("", body), |(c, f)| (c, f));
+    let content = if content.is_empty() {
+        String::new()
+    } else {
+        format!("{content}\n")
+    };
+    assert!(footer.starts_with("[skim-footer] "), "{stdout}");
+    assert!(
+        footer.contains(&format!(" bytes={} ", content.len())),
+        "{footer}"
+    );
+    let hash = format!("{:x}", Sha256::digest(content.as_bytes()));
+    assert!(footer.ends_with(&format!("sha256={hash}")), "{footer}");
+    footer.to_string()
+}
+
+#[test]
+fn test_footer_on_single_file() {
+    let temp = project();
+    let out = common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "--footer"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(out.get_output().stdout.clone()).unwrap();
+    assert!(stdout.starts_with("pub fn alpha() -> u32"), "{stdout}");
+    assert!(verify(&stdout).contains("files=1 "));
+}
+
+#[test]
+fn test_footer_covers_multi_file_output() {
+    let temp = project();
+    let out = common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "b.py", "--footer"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(out.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("// a.rs"), "{stdout}");
+    assert!(verify(&stdout).contains("files=2 "));
+}
+
+#[test]
+fn test_no_footer_by_default() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[skim-footer]").not());
+}
@@ -0,0 +1,84 @@
+//! Integration tests for `--format-output`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const SOURCE: &str = "use std::io;\n\n\n\nfn run() {\n    io::stdin();\n}\n";
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.rs"), SOURCE).unwrap();
+    dir
+}
+
+#[test]
+fn test_builtin_formatter_tidies_whitespace() {
+    let dir = project();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.rs", "--format-output"])
+        .assert()
+        .success()
+        .stdout("use std::io;\n\nfn run() {...}\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_skim_toml_formatter_runs_with_fallback() {
+    let dir = project();
+    fs::write(
+        dir.path().join("skim.toml"),
+        "[formatters]\nrust = [\"sh\", \"-c\", \"tr a-z A-Z\"]\n",
+    )
+    .unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.rs", "--format-output"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("FN RUN()"));
+
+    fs::write(
+        dir.path().join("skim.toml"),
+        "[formatters]\nrust = [\"sh\", \"-c\", \"echo 'expected item' >&2; exit 1\"]\n",
+    )
+    .unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.rs", "--format-output"])
+        .assert()
+        .success()
+        .stdout("use std::io;\n\nfn run() {...}\n")
+        .stderr(predicate::str::contains(
+            "formatter 'sh' failed for a.rs: exit status: 1: expected item; using built-in formatting",
+        ));
+}
+
+#[test]
+fn test_invalid_formatters_and_conflicts_fail() {
+    let dir = project();
+    fs::write(
+        dir.path().join("skim.toml"),
+        "[formatters]\ncobol = [\"cobfmt\"]\n",
+    )
+    .unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.rs", "--format-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "[formatters] has unknown language 'cobol'",
+        ));
+
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.rs", "--format-output", "--line-numbers"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--format-output cannot be combined with --line-numbers",
+        ));
+}
@@ -0,0 +1,199 @@
+//! Integration tests for `--group-by`.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn write(root: &Path, rel: &str, content: &str) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn workspace() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"crates/*\"]\n",
+    );
+    write(root, "crates/app/Cargo.toml", "[package]\nname = \"app\"\n");
+    write(
+        root,
+        "crates/app/src/main.rs",
+        "fn main() {\n    run();\n}\n",
+    );
+    write(
+        root,
+        "crates/lib/Cargo.toml",
+        "[package]\nname = \"app-lib\"\n",
+    );
+    write(
+        root,
+        "crates/lib/src/lib.rs",
+        "pub fn run() {\n    println!(\"hi\");\n}\n",
+    );
+    write(root, "build.rs", "fn build() {}\n");
+    dir
+}
+
+#[test]
+fn test_group_by_crate_prints_banner_above_each_crate() {
+    let dir = workspace();
+    let output = common::skim()
+        .arg(dir.path())
+        .args(["--group-by", "crate", "--no-cache"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    let app = stdout.find("// crate app (crates/app): 2 file(s)\n");
+    let lib = stdout.find("// crate app-lib (crates/lib): 2 file(s)\n");
+    let outside = stdout.find("// outside any crate: 2 file(s)\n");
+    assert!(
+        app < lib && lib < outside && app.is_some(),
+        "got:\n{stdout}"
+    );
+    let main_rs = stdout.find("fn main()").unwrap();
+    let lib_rs = stdout.find("pub fn run()").unwrap();
+    assert!(
+        app.unwrap() < main_rs && main_rs < lib.unwrap(),
+        "got:\n{stdout}"
+    );
+    assert!(
+        lib.unwrap() < lib_rs && lib_rs < outside.unwrap(),
+        "got:\n{stdout}"
+    );
+}
+
+#[test]
+fn test_group_by_crate_banner_carries_token_totals_with_stats() {
+    let dir = workspace();
+    common::skim()
+        .arg(dir.path())
+        .args(["--group-by", "crate", "--no-cache", "--show-stats"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                r"// crate app \(crates/app\): 2 file\(s\), \d+ tokens → \d+ tokens",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_group_by_requires_directory() {
+    let dir = workspace();
+    common::skim()
+        .arg(dir.path().join("build.rs"))
+        .args(["--group-by", "crate"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--group-by requires a single directory argument",
+        ));
+}
+
+#[test]
+fn test_group_by_crate_without_cargo_manifest_is_error() {
+    let dir = TempDir::new().unwrap();
+    write(dir.path(), "main.ts", "export const a = 1;\n");
+    common::skim()
+        .arg(dir.path())
+        .args(["--group-by", "crate", "--no-cache"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no Cargo.toml found"));
+}
+
+#[test]
+fn test_group_by_package_prints_package_summaries() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "package.json",
+        r#"{"private": true, "workspaces": ["packages/*"]}"#,
+    );
+    write(
+        root,
+        "packages/web/package.json",
+        r#"{"name": "@acme/web", "version": "0.3.0", "main": "src/index.ts", "dependencies": {"@acme/shared": "*"}}"#,
+    );
+    write(
+        root,
+        "packages/web/src/index.ts",
+        "export function render(): string {\n  return \"ok\";\n}\n",
+    );
+    write(
+        root,
+        "packages/shared/package.json",
+        r#"{"name": "@acme/shared"}"#,
+    );
+    write(
+        root,
+        "packages/shared/index.ts",
+        "export const VERSION = 1;\n",
+    );
+
+    let output = common::skim()
+        .arg(root)
+        .args(["--group-by", "package", "--no-cache"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(
+        stdout.contains(
+            "// package @acme/web@0.3.0 (packages/web): 2 file(s)\n\
+             // entry: src/index.ts\n\
+             // dependencies: @acme/shared\n"
+        ),
+        "got:\n{stdout}"
+    );
+    let shared = stdout
+        .find("// package @acme/shared (packages/shared): 2 file(s)\n")
+        .unwrap();
+    let web = stdout.find("// package @acme/web").unwrap();
+    let outside = stdout.find("// outside any package: 1 file(s)\n").unwrap();
+    assert!(shared < web && web < outside, "got:\n{stdout}");
+}
+
+#[test]
+fn test_group_by_java_package_prints_type_counts() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "src/main/java/com/acme/Order.java",
+        "package com.acme;\n\npublic class Order {\n    public int total() {\n        return 1;\n    }\n}\n",
+    );
+    write(
+        root,
+        "target/generated-sources/com/acme/Status.java",
+        "package com.acme;\n\npublic enum Status { OPEN, PAID }\n",
+    );
+
+    let output = common::skim()
+        .arg(root)
+        .args(["--group-by", "java-package", "--no-cache", "--no-ignore"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(
+        stdout.contains(
+            "// package com.acme: 2 file(s)\n\
+             // types: 1 class, 1 enum\n\
+             // directories: src/main/java/com/acme, target/generated-sources/com/acme\n"
+        ),
+        "got:\n{stdout}"
+    );
+}
@@ -119,3 +119,30 @@ fn test_guardrail_triggers_when_output_inflates() {
         .success()
         .stderr(predicate::str::contains("[skim:guardrail]"));
 }
+
+#[test]
+fn test_guardrail_never_emits_raw_env_values() {
+    // `A_TOKEN=1` (10 bytes) becomes `A_TOKEN=  # secret` (19 bytes), so the
+    // redacted listing is larger than the raw file. The guardrail must not
+    // fall back to the raw values.
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join(".env");
+    let mut source = String::new();
+    for i in 0..40 {
+        source.push_str(&format!("K{i}_TOKEN={i}\n"));
+    }
+    assert!(source.len() >= 256);
+    std::fs::write(&file, &source).unwrap();
+
+    for mode in ["structure", "full", "minimal"] {
+        skim_cmd()
+            .arg(file.to_str().unwrap())
+            .arg(format!("--mode={mode}"))
+            .arg("--no-cache")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("K7_TOKEN=  # secret\n"))
+            .stdout(predicate::str::contains("K7_TOKEN=7").not())
+            .stderr(predicate::str::contains("[skim:guardrail]").not());
+    }
+}
@@ -0,0 +1,135 @@
+//! Integration tests for `--hermetic` / `SKIM_HERMETIC`.
+//!
+//! A hermetic run must not read or write anything outside the paths it is
+//! given: no cache entries, no analytics rows, and no ignore files from
+//! parent directories.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn write(root: &Path, rel: &str, contents: &str) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn entries(dir: &Path) -> usize {
+    fs::read_dir(dir).map_or(0, |d| d.count())
+}
+
+#[test]
+fn test_hermetic_flag_writes_no_cache_entries() {
+    let src = TempDir::new().unwrap();
+    let cache = TempDir::new().unwrap();
+    write(src.path(), "a.ts", "function a() { return 1; }\n");
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache.path())
+        .arg(src.path().join("a.ts"))
+        .arg("--hermetic")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function a"));
+
+    assert_eq!(entries(cache.path()), 0);
+}
+
+#[test]
+fn test_hermetic_env_var_writes_no_cache_entries() {
+    let src = TempDir::new().unwrap();
+    let cache = TempDir::new().unwrap();
+    write(src.path(), "a.ts", "function a() { return 1; }\n");
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache.path())
+        .env("SKIM_HERMETIC", "1")
+        .arg(src.path().join("a.ts"))
+        .assert()
+        .success();
+
+    assert_eq!(entries(cache.path()), 0);
+}
+
+#[test]
+fn test_hermetic_records_no_analytics() {
+    let src = TempDir::new().unwrap();
+    let db_dir = TempDir::new().unwrap();
+    let db = db_dir.path().join("analytics.db");
+    write(src.path(), "a.ts", "function a() { return 1; }\n");
+
+    common::skim_with_analytics(&db)
+        .arg(src.path().join("a.ts"))
+        .args(["--hermetic", "--no-cache"])
+        .assert()
+        .success();
+
+    assert!(!db.exists(), "hermetic run created {}", db.display());
+}
+
+#[test]
+fn test_hermetic_ignores_gitignore_above_target() {
+    let root = TempDir::new().unwrap();
+    write(root.path(), ".gitignore", "*.generated.ts\n");
+    write(
+        root.path(),
+        "pkg/a.generated.ts",
+        "function generated() {}\n",
+    );
+    write(root.path(), "pkg/b.ts", "function handwritten() {}\n");
+    let target = root.path().join("pkg");
+
+    common::skim()
+        .arg(&target)
+        .arg("--no-cache")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("generated").not());
+
+    common::skim()
+        .arg(&target)
+        .arg("--hermetic")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function generated"))
+        .stdout(predicate::str::contains("function handwritten"));
+}
+
+#[test]
+fn test_hermetic_keeps_gitignore_inside_target() {
+    let root = TempDir::new().unwrap();
+    write(root.path(), "pkg/.gitignore", "*.generated.ts\n");
+    write(
+        root.path(),
+        "pkg/a.generated.ts",
+        "function generated() {}\n",
+    );
+    write(root.path(), "pkg/b.ts", "function handwritten() {}\n");
+
+    common::skim()
+        .arg(root.path().join("pkg"))
+        .arg("--hermetic")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("generated").not())
+        .stdout(predicate::str::contains("function handwritten"));
+}
+
+#[test]
+fn test_hermetic_rejects_clear_cache() {
+    let cache = TempDir::new().unwrap();
+    write(cache.path(), "keep.json", "{}");
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache.path())
+        .args(["--clear-cache", "--hermetic"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--clear-cache cannot be used in hermetic mode",
+        ));
+
+    assert!(cache.path().join("keep.json").exists());
+}
@@ -0,0 +1,122 @@
+//! IO failure handling as seen from the CLI.
+//!
+//! The cache is an optimisation: an unusable cache directory must never fail
+//! a run, and a source file that cannot be read must fail only itself. Faults
+//! that need injection (disk full mid-write, unreadable entries) are covered
+//! by the `CacheFs` unit tests in `cache.rs`.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+const SOURCE: &str = "export function keep(x: number): number {\n  return x * 2;\n}\n";
+
+fn write_source(dir: &Path, name: &str) -> std::path::PathBuf {
+    let path = dir.join(name);
+    fs::write(&path, SOURCE).unwrap();
+    path
+}
+
+#[test]
+fn test_unusable_cache_dir_does_not_fail_the_run() {
+    let temp = TempDir::new().unwrap();
+    let file = write_source(temp.path(), "keep.ts");
+    // A regular file where the cache directory should be: mkdir fails even
+    // for root, unlike permission bits.
+    let blocker = temp.path().join("not-a-dir");
+    fs::write(&blocker, "").unwrap();
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", &blocker)
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function keep"));
+}
+
+#[test]
+fn test_unusable_cache_dir_is_reported_in_debug_mode() {
+    let temp = TempDir::new().unwrap();
+    let file = write_source(temp.path(), "keep.ts");
+    let blocker = temp.path().join("not-a-dir");
+    fs::write(&blocker, "").unwrap();
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", &blocker)
+        .arg(&file)
+        .arg("--debug")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("output not cached"));
+}
+
+#[test]
+fn test_unusable_cache_dir_is_silent_by_default() {
+    let temp = TempDir::new().unwrap();
+    let file = write_source(temp.path(), "keep.ts");
+    let blocker = temp.path().join("not-a-dir");
+    fs::write(&blocker, "").unwrap();
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", &blocker)
+        .env_remove("SKIM_DEBUG")
+        .arg(&file)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("not cached").not());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_permission_denied_file_fails_only_itself() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp = TempDir::new().unwrap();
+    let readable = write_source(temp.path(), "a.ts");
+    let locked = write_source(temp.path(), "b.ts");
+    fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+    if fs::read(&locked).is_ok() {
+        // Running as root: permission bits are not enforced.
+        return;
+    }
+
+    common::skim()
+        .arg(&readable)
+        .arg(&locked)
+        .arg("--no-cache")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "// {}",
+            readable.display()
+        )))
+        .stderr(predicate::str::contains(format!(
+            "Error processing {}",
+            locked.display()
+        )))
+        .stderr(predicate::str::contains("1 failed"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_read_only_cache_dir_does_not_fail_the_run() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp = TempDir::new().unwrap();
+    let file = write_source(temp.path(), "keep.ts");
+    let cache = temp.path().join("cache");
+    fs::create_dir(&cache).unwrap();
+    fs::set_permissions(&cache, fs::Permissions::from_mode(0o500)).unwrap();
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", &cache)
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function keep"));
+
+    // Restore write permission so TempDir can clean up.
+    fs::set_permissions(&cache, fs::Permissions::from_mode(0o700)).unwrap();
+}
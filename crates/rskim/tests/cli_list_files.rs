@@ -0,0 +1,122 @@
+//! Integration tests for `--list-files` and `skim ls --dry-run` (dry listing
+//! of resolved inputs).
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir(temp.path().join("src")).unwrap();
+    fs::write(temp.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+    fs::write(temp.path().join("src/notes.txt"), "notes\n").unwrap();
+    fs::create_dir(temp.path().join("build")).unwrap();
+    fs::write(temp.path().join("build/gen.rs"), "fn gen() {}\n").unwrap();
+    fs::write(temp.path().join(".gitignore"), "build/\n").unwrap();
+    temp
+}
+
+#[test]
+fn test_list_files_shows_languages_and_skips() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args([".", "--list-files"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"Rust\s+13  ./src/main.rs\n").unwrap())
+        .stdout(predicate::str::contains(
+            "./src/notes.txt  (skipped: unsupported file type)",
+        ))
+        .stdout(predicate::str::contains("gen.rs").not())
+        .stdout(predicate::str::contains("fn main").not())
+        .stderr(predicate::str::contains(
+            "1 file(s) would be processed, 1 skipped; 2 more excluded by ignore rules",
+        ));
+}
+
+#[test]
+fn test_list_files_no_ignore_includes_ignored() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args([".", "--list-files", "--no-ignore"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("./build/gen.rs"))
+        .stderr(predicate::str::contains("excluded").not());
+}
+
+#[test]
+fn test_list_files_explicit_file_without_language() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["src/notes.txt", "missing.rs", "--list-files"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "src/notes.txt  (skipped: no language detected (pass --language))",
+        ))
+        .stdout(predicate::str::contains(
+            "missing.rs  (skipped: file not found)",
+        ));
+}
+
+#[test]
+fn test_list_files_rejects_stdin() {
+    common::skim()
+        .args(["-", "--list-files"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--list-files cannot be used with stdin",
+        ));
+}
+
+#[test]
+fn test_ls_dry_run_defaults_to_current_directory() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["ls", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"Rust\s+13  ./src/main.rs\n").unwrap())
+        .stdout(predicate::str::contains("gen.rs").not())
+        .stderr(predicate::str::contains(
+            "1 file(s) would be processed, 1 skipped; 2 more excluded by ignore rules",
+        ));
+}
+
+#[test]
+fn test_ls_dry_run_takes_discovery_flags() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["ls", "--dry-run", "build", "--no-ignore"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"Rust\s+12  build/gen.rs\n").unwrap());
+    common::skim()
+        .current_dir(temp.path())
+        .args(["ls", "--dry-run", "src/notes.txt", "--language=python"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"Python\s+6  src/notes.txt\n").unwrap())
+        .stderr(predicate::str::contains(
+            "1 file(s) would be processed, 0 skipped",
+        ));
+}
+
+#[test]
+fn test_ls_dry_run_rejects_unknown_flag() {
+    common::skim()
+        .args(["ls", "--dry-run", "-la"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown flag for skim ls --dry-run: '-la'",
+        ));
+}
@@ -446,3 +446,76 @@ fn test_markdown_extension_variations() {
             .stdout(predicate::str::contains("# Test Header"));
     }
 }
+
+// ============================================================================
+// Fenced Code Blocks (--fenced-code)
+// ============================================================================
+
+const FENCED_DOC: &str = r#"# Guide
+
+Intro text.
+
+## Usage
+
+```rust
+pub fn connect(url: &str) -> Result<Client, Error> {
+    let client = Client::new(url);
+    client.handshake()?;
+    Ok(client)
+}
+```
+
+## Notes
+
+```
+untagged block
+```
+"#;
+
+#[test]
+fn test_markdown_fenced_code_skimmed_under_heading() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("guide.md");
+    fs::write(&file_path, FENCED_DOC).unwrap();
+
+    let output = common::skim()
+        .arg(&file_path)
+        .arg("--mode")
+        .arg("signatures")
+        .arg("--fenced-code")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8(output).unwrap();
+    assert!(stdout.contains("```rust"), "got:\n{stdout}");
+    assert!(
+        stdout.contains("pub fn connect(url: &str) -> Result<Client, Error>"),
+        "got:\n{stdout}"
+    );
+    assert!(!stdout.contains("client.handshake()"), "got:\n{stdout}");
+    assert!(!stdout.contains("untagged block"), "got:\n{stdout}");
+
+    let usage = stdout.find("## Usage").unwrap();
+    let fence = stdout.find("```rust").unwrap();
+    let notes = stdout.find("## Notes").unwrap();
+    assert!(usage < fence && fence < notes, "got:\n{stdout}");
+}
+
+#[test]
+fn test_markdown_fenced_code_off_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("guide.md");
+    fs::write(&file_path, FENCED_DOC).unwrap();
+
+    common::skim()
+        .arg(&file_path)
+        .arg("--mode")
+        .arg("signatures")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## Usage"))
+        .stdout(predicate::str::contains("```rust").not());
+}
@@ -0,0 +1,92 @@
+//! Integration tests for `--format markdown`.
+//!
+//! Markdown input files are covered in `cli_markdown.rs`; this file covers
+//! the Markdown *output* format.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const SOURCE: &str = "use std::io;\n\n\
+pub fn parse_config(path: &str) -> io::Result<String> {\n    std::fs::read_to_string(path)\n}\n\n\
+pub struct Config {\n    pub name: String,\n}\n";
+
+#[test]
+fn test_markdown_format_anchors_each_symbol() {
+    common::skim()
+        .args([
+            "-",
+            "--filename=src/config.rs",
+            "--mode=structure",
+            "--format=markdown",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("## src/config.rs\n"))
+        .stdout(predicate::str::contains(
+            "### src/config.rs#parse_config\n\n```rust\npub fn parse_config(path: &str) -> io::Result<String>",
+        ))
+        .stdout(predicate::str::contains("### src/config.rs#Config\n"));
+}
+
+#[test]
+fn test_markdown_format_stdin_without_filename_uses_stdin_label() {
+    common::skim()
+        .args(["-", "--language=rust", "--format=markdown"])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("### stdin#parse_config\n"));
+}
+
+#[test]
+fn test_markdown_format_replaces_multi_file_headers() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("alpha.ts"),
+        "function alpha() { return 1; }\n",
+    )
+    .unwrap();
+    fs::write(
+        temp.path().join("beta.ts"),
+        "function beta() { return 2; }\n",
+    )
+    .unwrap();
+
+    let alpha = temp.path().join("alpha.ts");
+    common::skim()
+        .arg(&alpha)
+        .arg(temp.path().join("beta.ts"))
+        .arg("--format=markdown")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "## {}\n",
+            alpha.display()
+        )))
+        .stdout(predicate::str::contains(format!(
+            "### {}#alpha\n",
+            alpha.display()
+        )))
+        .stdout(predicate::str::contains("#beta\n"))
+        .stdout(predicate::str::contains("// ").not());
+}
+
+#[test]
+fn test_markdown_format_rejects_line_numbers() {
+    common::skim()
+        .args([
+            "-",
+            "--language=rust",
+            "--format=markdown",
+            "--line-numbers",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--line-numbers cannot be combined with --format markdown",
+        ));
+}
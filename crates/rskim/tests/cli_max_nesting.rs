@@ -0,0 +1,35 @@
+//! Integration tests for `--max-nesting`.
+
+use predicates::prelude::*;
+mod common;
+
+const SOURCE: &str = "public class Outer {\n    public void a() {}\n\n    static class Inner {\n        void b() {}\n    }\n}\n";
+
+#[test]
+fn test_max_nesting_drops_deep_declarations() {
+    common::skim()
+        .args(["-", "--language=java", "--max-nesting=1", "--no-cache"])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("public void a()"))
+        .stdout(predicate::str::contains("static class Inner {"))
+        .stdout(predicate::str::contains("void b()").not());
+}
+
+#[test]
+fn test_max_nesting_requires_structure_mode() {
+    common::skim()
+        .args([
+            "-",
+            "--language=java",
+            "--mode=signatures",
+            "--max-nesting=1",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--max-nesting requires --mode structure",
+        ));
+}
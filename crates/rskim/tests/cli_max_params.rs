@@ -0,0 +1,35 @@
+//! Integration tests for `--max-params`.
+
+use predicates::prelude::*;
+mod common;
+
+const SOURCE: &str = "def request(method, url, params, data, headers, cookies, files, auth):\n    pass\n\ndef ping(host):\n    pass\n";
+
+#[test]
+fn test_max_params_elides_long_lists() {
+    common::skim()
+        .args([
+            "-",
+            "--language=python",
+            "--mode=signatures",
+            "--max-params=4",
+            "--no-cache",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("def request(... 8 params):"))
+        .stdout(predicate::str::contains("def ping(host):"));
+}
+
+#[test]
+fn test_max_params_requires_signatures_mode() {
+    common::skim()
+        .args(["-", "--language=python", "--max-params=4"])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--max-params requires --mode signatures",
+        ));
+}
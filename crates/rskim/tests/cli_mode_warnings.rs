@@ -0,0 +1,67 @@
+//! Integration tests for no-op mode warnings.
+//!
+//! Modes that are ignored or nearly empty for a language (types on
+//! JavaScript, signatures on JSON, minimal on Markdown) print a stderr
+//! warning; stdout is unaffected.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+#[test]
+fn test_types_mode_on_javascript_warns() {
+    common::skim()
+        .args(["-", "--language=javascript", "--mode=types"])
+        .write_stdin("function add(a, b) { return a + b; }\nclass Point {}\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "[skim] warning: --mode types on JavaScript",
+        ))
+        .stderr(predicate::str::contains("use --mode signatures"));
+}
+
+#[test]
+fn test_signatures_mode_on_json_warns() {
+    common::skim()
+        .args(["-", "--language=json", "--mode=signatures"])
+        .write_stdin(r#"{"name": "skim", "version": 1}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("name"))
+        .stderr(predicate::str::contains("use --mode structure"));
+}
+
+#[test]
+fn test_supported_mode_does_not_warn() {
+    common::skim()
+        .args(["-", "--language=typescript", "--mode=types"])
+        .write_stdin("type Id = string;\nfunction f(): Id { return ''; }\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("warning").not());
+}
+
+#[test]
+fn test_warning_printed_once_per_language() {
+    let dir = TempDir::new().unwrap();
+    for name in ["a.json", "b.json", "c.json"] {
+        fs::write(dir.path().join(name), r#"{"key": 1}"#).unwrap();
+    }
+
+    let output = common::skim()
+        .args(["*.json", "--mode=types", "--no-cache"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(
+        stderr
+            .matches("[skim] warning: --mode types on JSON")
+            .count(),
+        1,
+        "{stderr}"
+    );
+}
@@ -0,0 +1,35 @@
+//! Integration tests for `--module-doc`.
+
+use predicates::prelude::*;
+mod common;
+
+const SOURCE: &str =
+    "//! Session analytics.\n\npub fn record(event: &str) {\n    let _ = event;\n}\n";
+
+#[test]
+fn test_module_doc_flag_keeps_header_in_signatures() {
+    common::skim()
+        .args([
+            "-",
+            "--language=rust",
+            "--mode=signatures",
+            "--module-doc",
+            "--no-cache",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "//! Session analytics.\npub fn record(event: &str)",
+        ));
+}
+
+#[test]
+fn test_signatures_drop_header_without_flag() {
+    common::skim()
+        .args(["-", "--language=rust", "--mode=signatures", "--no-cache"])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Session analytics").not());
+}
@@ -250,3 +250,85 @@ fn test_multi_file_mix_plain_and_glob() {
                 .or(predicate::str::contains("function glob2")),
         );
 }
+
+#[test]
+fn test_multi_file_output_keeps_input_order_across_many_files() {
+    // Enough files for the workers to finish well out of order.
+    let temp = TempDir::new().unwrap();
+    let paths: Vec<_> = (0..300)
+        .map(|i| {
+            let path = temp.path().join(format!("f{i:03}.ts"));
+            let body = "    x += 1;\n".repeat(i % 7 * 40);
+            fs::write(&path, format!("function f{i}(x: number) {{\n{body}}}\n")).unwrap();
+            path
+        })
+        .collect();
+
+    let output = common::skim()
+        .args(&paths)
+        .args(["--no-cache", "--jobs", "8", "--mode", "signatures"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8_lossy(&output);
+    let headers: Vec<&str> = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("// "))
+        .collect();
+    let expected: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    assert_eq!(headers, expected);
+}
+
+// ============================================================================
+// --stream-unordered
+// ============================================================================
+
+#[test]
+fn test_multi_file_stream_unordered_writes_every_file_with_its_header() {
+    let temp = TempDir::new().unwrap();
+    let names = ["a.ts", "b.ts", "c.ts", "d.ts"];
+    for name in names {
+        let func = name.trim_end_matches(".ts");
+        fs::write(
+            temp.path().join(name),
+            format!("function {func}() {{ return 1; }}\n"),
+        )
+        .unwrap();
+    }
+
+    let output = common::skim()
+        .args(names.map(|name| temp.path().join(name)))
+        .args(["--stream-unordered", "--no-cache", "--jobs", "4"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8_lossy(&output);
+    for name in names {
+        let func = name.trim_end_matches(".ts");
+        let header = format!("// {}\nfunction {func}()", temp.path().join(name).display());
+        assert!(stdout.contains(&header), "missing {header:?} in:\n{stdout}");
+    }
+}
+
+#[test]
+fn test_multi_file_stream_unordered_rejects_no_header() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("a.ts"), "function a() {}").unwrap();
+    fs::write(temp.path().join("b.ts"), "function b() {}").unwrap();
+
+    common::skim()
+        .arg(temp.path().join("a.ts"))
+        .arg(temp.path().join("b.ts"))
+        .args(["--stream-unordered", "--no-header"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--stream-unordered cannot be combined with --no-header",
+        ));
+}
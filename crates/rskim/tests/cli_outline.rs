@@ -0,0 +1,55 @@
+//! Integration tests for `--mode outline`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const CONFIG_RS: &str = "pub struct Config {\n    path: String,\n}\n\nimpl Config {\n    \
+                         pub fn new(path: &str) -> Self {\n        Config { path: path.into() }\n    \
+                         }\n\n    fn validate(&self) -> bool {\n        !self.path.is_empty()\n    \
+                         }\n}\n";
+
+#[test]
+fn test_outline_lists_declarations_with_line_numbers() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("config.rs");
+    fs::write(&file, CONFIG_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "-m", "o"])
+        .assert()
+        .success()
+        .stdout(
+            "1: pub struct Config\n5: impl Config\n  6: pub fn new(...)\n  10: fn validate(...)\n",
+        );
+}
+
+#[test]
+fn test_outline_public_only_drops_private_methods() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("config.rs");
+    fs::write(&file, CONFIG_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "--mode", "outline", "--public-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("6: pub fn new(...)"))
+        .stdout(predicate::str::contains("validate").not());
+}
+
+#[test]
+fn test_outline_rejects_validate_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("config.rs");
+    fs::write(&file, CONFIG_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "outline", "--validate-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--validate-output cannot be combined with --mode outline",
+        ));
+}
@@ -0,0 +1,68 @@
+//! Integration tests for `--pick`.
+//!
+//! The picker itself needs a terminal, which the test harness cannot provide,
+//! so these cover the guards around it. The dialogue and splicing are unit
+//! tested in `src/pick.rs`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("a.rs"),
+        "pub fn alpha() -> u32 {\n    1\n}\n",
+    )
+    .unwrap();
+    fs::write(temp.path().join("b.rs"), "fn beta() {}\n").unwrap();
+    temp
+}
+
+#[test]
+fn test_pick_requires_a_terminal() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "--pick"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--pick needs an interactive terminal",
+        ));
+}
+
+#[test]
+fn test_pick_takes_exactly_one_file() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "b.rs", "--pick"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--pick takes exactly one file"));
+}
+
+#[test]
+fn test_pick_rejects_stdin() {
+    common::skim()
+        .args(["-", "--pick"])
+        .write_stdin("fn a() {}\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--pick takes exactly one file"));
+}
+
+#[test]
+fn test_pick_rejects_truncation_flags() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "--pick", "--max-lines", "5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--max-lines cannot be combined with --pick",
+        ));
+}
@@ -0,0 +1,100 @@
+//! Integration tests for mode aliases and `--preset`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const SOURCE: &str =
+    "type Id = string;\n\nexport function load(id: Id): string {\n  return id;\n}\n";
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.ts"), SOURCE).unwrap();
+    dir
+}
+
+#[test]
+fn test_mode_aliases_match_full_names() {
+    let dir = project();
+    for (alias, mode) in [
+        ("s", "structure"),
+        ("sig", "signatures"),
+        ("t", "types"),
+        ("f", "full"),
+    ] {
+        let expected = common::skim()
+            .arg(dir.path().join("a.ts"))
+            .args(["--no-cache", "-m", mode])
+            .output()
+            .unwrap();
+        common::skim()
+            .arg(dir.path().join("a.ts"))
+            .args(["--no-cache", "-m", alias])
+            .assert()
+            .success()
+            .stdout(expected.stdout);
+    }
+}
+
+#[test]
+fn test_builtin_pack_preset_renders_markdown() {
+    let dir = project();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.ts", "--preset", "pack"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## a.ts"));
+}
+
+#[test]
+fn test_skim_toml_preset_and_explicit_override() {
+    let dir = project();
+    fs::write(
+        dir.path().join("skim.toml"),
+        "[presets]\napi = [\"--mode=signatures\", \"--show-stats\"]\n",
+    )
+    .unwrap();
+
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.ts", "--no-cache", "--preset=api"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("function load(id: Id): string"))
+        .stdout(predicate::str::contains("type Id").not())
+        .stderr(predicate::str::contains("tokens"));
+
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.ts", "--no-cache", "--preset=api", "--mode", "types"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("type Id = string;"))
+        .stdout(predicate::str::contains("function load").not());
+}
+
+#[test]
+fn test_unknown_preset_and_invalid_preset_flags_fail() {
+    let dir = project();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.ts", "--preset", "nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown preset 'nope'"))
+        .stderr(predicate::str::contains("pack, review"));
+
+    fs::write(
+        dir.path().join("skim.toml"),
+        "[presets]\nbad = [\"--tokens\", \"500\"]\n",
+    )
+    .unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["a.ts", "--preset", "bad"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--tokens=VALUE"));
+}
@@ -0,0 +1,47 @@
+//! Integration tests for `--mode public-api`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+#[test]
+fn test_public_api_keeps_public_types_and_methods() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("Account.java");
+    fs::write(
+        &file,
+        "public class Account {\n    private int balance;\n    public String owner;\n\n\
+         public void deposit(int amount) {\n        balance += amount;\n    }\n\n\
+         private void audit() {}\n}\n",
+    )
+    .unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "-m", "api"])
+        .assert()
+        .success()
+        .stdout(
+            "public class Account {\n    public String owner;\n}\npublic void deposit(int amount)",
+        );
+}
+
+#[test]
+fn test_public_api_line_numbers_follow_source_order() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("lib.rs");
+    fs::write(
+        &file,
+        "fn helper() {}\n\npub fn run() {}\n\npub enum Level {\n    Low,\n}\n",
+    )
+    .unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--no-cache", "--mode", "public-api", "--line-numbers"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "3\tpub fn run()\n5\tpub enum Level {",
+        ))
+        .stdout(predicate::str::contains("helper").not());
+}
@@ -0,0 +1,63 @@
+//! Integration tests for `--public-only`.
+//!
+//! Private declarations are dropped in structure, signatures and types mode;
+//! other modes reject the flag.
+
+use predicates::prelude::*;
+mod common;
+
+const RUST_SOURCE: &str = "/// Entry point.\npub fn run() -> u8 {\n    helper()\n}\n\n\
+                           /// Internal.\nfn helper() -> u8 {\n    1\n}\n";
+
+#[test]
+fn test_public_only_structure_drops_private_items() {
+    common::skim()
+        .args(["-", "--language=rust", "--public-only", "--no-cache"])
+        .write_stdin(RUST_SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pub fn run() -> u8"))
+        .stdout(predicate::str::contains("helper()").not())
+        .stdout(predicate::str::contains("Internal.").not());
+}
+
+#[test]
+fn test_public_only_signatures_python() {
+    common::skim()
+        .args([
+            "-",
+            "--language=python",
+            "--mode=signatures",
+            "--public-only",
+            "--no-cache",
+        ])
+        .write_stdin(
+            "def load(path):\n    return _read(path)\n\ndef _read(path):\n    return open(path)\n",
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("def load(path):"))
+        .stdout(predicate::str::contains("_read(path):").not());
+}
+
+#[test]
+fn test_without_flag_private_items_are_kept() {
+    common::skim()
+        .args(["-", "--language=rust", "--mode=signatures", "--no-cache"])
+        .write_stdin(RUST_SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fn helper() -> u8"));
+}
+
+#[test]
+fn test_public_only_rejected_for_full_mode() {
+    common::skim()
+        .args(["-", "--language=rust", "--mode=full", "--public-only"])
+        .write_stdin(RUST_SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--public-only requires --mode structure, signatures, docs, types or outline",
+        ));
+}
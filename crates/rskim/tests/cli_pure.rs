@@ -0,0 +1,179 @@
+//! Integration tests for pure mode (`--inputs-manifest` / `--output`).
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn write(root: &Path, rel: &str, contents: &str) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+/// A tree with two sources, a manifest listing both, and a cache directory.
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "src/a.ts",
+        "export function alpha(x: number): number {\n  return x + 1;\n}\n",
+    );
+    write(
+        temp.path(),
+        "src/b.py",
+        "def beta(name):\n    return name.upper()\n",
+    );
+    write(
+        temp.path(),
+        "inputs.txt",
+        "# sources\nsrc/a.ts\n\nsrc/b.py\n",
+    );
+    temp
+}
+
+fn stdout_of(cmd: &mut assert_cmd::Command) -> String {
+    String::from_utf8(cmd.assert().success().get_output().stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_pure_writes_output_and_prints_inputs_hash() {
+    let temp = project();
+
+    let stdout = stdout_of(common::skim().current_dir(temp.path()).args([
+        "--inputs-manifest",
+        "inputs.txt",
+        "--output",
+        "map.txt",
+    ]));
+
+    assert!(stdout.starts_with("sha256:"), "stdout: {stdout}");
+    assert_eq!(stdout.trim_end().len(), "sha256:".len() + 64);
+    let map = fs::read_to_string(temp.path().join("map.txt")).unwrap();
+    assert!(map.contains("// src/a.ts\n"), "{map}");
+    assert!(map.contains("function alpha(x: number): number"), "{map}");
+    assert!(map.contains("// src/b.py\n"), "{map}");
+    assert!(!map.contains("x + 1"), "{map}");
+}
+
+#[test]
+fn test_pure_output_matches_explicit_file_run() {
+    let temp = project();
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["--inputs-manifest", "inputs.txt", "--output", "map.txt"])
+        .assert()
+        .success();
+    let explicit = stdout_of(common::skim().current_dir(temp.path()).args([
+        "src/a.ts",
+        "src/b.py",
+        "--no-cache",
+    ]));
+
+    assert_eq!(
+        fs::read_to_string(temp.path().join("map.txt")).unwrap(),
+        explicit
+    );
+}
+
+#[test]
+fn test_pure_hash_is_stable_and_tracks_contents() {
+    let temp = project();
+    let run = || {
+        stdout_of(common::skim().current_dir(temp.path()).args([
+            "--inputs-manifest",
+            "inputs.txt",
+            "--output",
+            "map.txt",
+        ]))
+    };
+
+    let first = run();
+    assert_eq!(run(), first);
+
+    // A body-only edit leaves the skimmed output unchanged but not the hash.
+    write(
+        temp.path(),
+        "src/a.ts",
+        "export function alpha(x: number): number {\n  return x + 2;\n}\n",
+    );
+    assert_ne!(run(), first);
+}
+
+#[test]
+fn test_pure_is_hermetic() {
+    let temp = project();
+    let cache = TempDir::new().unwrap();
+
+    common::skim()
+        .current_dir(temp.path())
+        .env("SKIM_CACHE_DIR", cache.path())
+        .args(["--inputs-manifest", "inputs.txt", "--output", "map.txt"])
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_dir(cache.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_pure_missing_input_fails_without_output() {
+    let temp = project();
+    write(temp.path(), "inputs.txt", "src/a.ts\nsrc/missing.rs\n");
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["--inputs-manifest", "inputs.txt", "--output", "map.txt"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("src/missing.rs"));
+
+    assert!(!temp.path().join("map.txt").exists());
+}
+
+#[test]
+fn test_pure_rejects_directories() {
+    let temp = project();
+    write(temp.path(), "inputs.txt", "src\n");
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["--inputs-manifest", "inputs.txt", "--output", "map.txt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("performs no discovery"));
+}
+
+#[test]
+fn test_pure_rejects_file_arguments() {
+    let temp = project();
+
+    common::skim()
+        .current_dir(temp.path())
+        .args([
+            "src/a.ts",
+            "--inputs-manifest",
+            "inputs.txt",
+            "--output",
+            "map.txt",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "FILE arguments cannot be combined with --inputs-manifest",
+        ));
+}
+
+#[test]
+fn test_pure_requires_output() {
+    let temp = project();
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["--inputs-manifest", "inputs.txt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--output"));
+}
@@ -0,0 +1,36 @@
+//! Integration tests for `--pyi`.
+
+use predicates::prelude::*;
+mod common;
+
+const SOURCE: &str = "class Invoice:\n    total: int = 0\n\n    def pay(self, amount: int) -> bool:\n        return True\n";
+
+#[test]
+fn test_pyi_flag_emits_stub() {
+    common::skim()
+        .args([
+            "-",
+            "--language=python",
+            "--mode=signatures",
+            "--pyi",
+            "--no-cache",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "class Invoice:\n    total: int\n    def pay(self, amount: int) -> bool: ...",
+        ));
+}
+
+#[test]
+fn test_pyi_requires_signatures_or_types_mode() {
+    common::skim()
+        .args(["-", "--language=python", "--mode=structure", "--pyi"])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--pyi requires --mode signatures or types",
+        ));
+}
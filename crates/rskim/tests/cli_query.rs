@@ -0,0 +1,62 @@
+//! Integration tests for `--mode query` and `--query`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const LIB_RS: &str = "pub fn load() {}\nfn private() {}\n\npub fn save(x: u8) {\n}\n";
+const PUBLIC_FNS: &str = "; Public functions\n\
+                          (function_item (visibility_modifier) name: (identifier) @name)\n";
+
+#[test]
+fn test_query_prints_captured_nodes() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("lib.rs"), LIB_RS).unwrap();
+    fs::write(dir.path().join("public.scm"), PUBLIC_FNS).unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["lib.rs", "--no-cache", "-m", "q", "--query", "public.scm"])
+        .assert()
+        .success()
+        .stdout("load\n\nsave\n");
+}
+
+#[test]
+fn test_query_for_another_grammar_fails() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("app.py"), "def main():\n    pass\n").unwrap();
+    fs::write(dir.path().join("public.scm"), PUBLIC_FNS).unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args([
+            "app.py",
+            "--no-cache",
+            "--mode",
+            "query",
+            "--query",
+            "public.scm",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid query for Python"));
+}
+
+#[test]
+fn test_query_mode_and_flag_require_each_other() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("lib.rs"), LIB_RS).unwrap();
+    fs::write(dir.path().join("public.scm"), PUBLIC_FNS).unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["lib.rs", "--mode", "query"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--mode query requires --query"));
+    common::skim()
+        .current_dir(dir.path())
+        .args(["lib.rs", "--query", "public.scm"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--query requires --mode query"));
+}
@@ -0,0 +1,146 @@
+//! Integration tests for `--record` and `skim replay`.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+mod common;
+
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("a.rs"),
+        "pub fn alpha() -> u32 {\n    1 + 1\n}\n",
+    )
+    .unwrap();
+    temp
+}
+
+fn case_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn test_record_writes_one_case_per_transform() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "--record", "rec"])
+        .assert()
+        .success();
+
+    let cases = case_files(&temp.path().join("rec"));
+    assert_eq!(cases.len(), 1, "{cases:?}");
+    let case: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&cases[0]).unwrap()).unwrap();
+    assert_eq!(case["path"], "a.rs");
+    assert_eq!(case["language"], "rust");
+    assert_eq!(case["config"]["mode"], "structure");
+    assert!(case["source"].as_str().unwrap().contains("1 + 1"));
+    assert!(
+        case["output"]
+            .as_str()
+            .unwrap()
+            .contains("pub fn alpha() -> u32")
+    );
+    assert!(case["error"].is_null());
+}
+
+#[test]
+fn test_record_never_writes_env_values() {
+    let temp = project();
+    fs::write(
+        temp.path().join(".env"),
+        "PORT=8080\nSECRET_TOKEN=hunter2-do-not-leak\n",
+    )
+    .unwrap();
+    for mode in ["structure", "full"] {
+        common::skim()
+            .current_dir(temp.path())
+            .args([".env", "a.rs", "--record", "rec", "--mode", mode])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("hunter2").not())
+            .stderr(predicate::str::contains("not recording .env"));
+    }
+
+    let cases = case_files(&temp.path().join("rec"));
+    assert_eq!(cases.len(), 2, "only a.rs is recorded: {cases:?}");
+    for case in cases {
+        let text = fs::read_to_string(&case).unwrap();
+        assert!(!text.contains("hunter2"), "{}: {text}", case.display());
+        assert!(!text.contains("SECRET_TOKEN"), "{}: {text}", case.display());
+    }
+}
+
+#[test]
+fn test_replay_matches_recording() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "--record", "rec", "--mode", "signatures"])
+        .assert()
+        .success();
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["replay", "rec"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ok "))
+        .stdout(predicate::str::contains("signatures mode"))
+        .stdout(predicate::str::contains(
+            "1 replayed, 1 matched, 0 differed",
+        ));
+}
+
+#[test]
+fn test_replay_reports_changed_output() {
+    let temp = project();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["a.rs", "--record", "rec"])
+        .assert()
+        .success();
+
+    let path = case_files(&temp.path().join("rec")).remove(0);
+    let mut case: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    case["output"] = "something else\n".into();
+    fs::write(&path, case.to_string()).unwrap();
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["replay", "--show"])
+        .arg(&path)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("DIFFERS"))
+        .stdout(predicate::str::contains("pub fn alpha() -> u32"));
+}
+
+#[test]
+fn test_replay_rejects_non_case_files() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("junk.json"), "{}").unwrap();
+    common::skim()
+        .current_dir(temp.path())
+        .args(["replay", "junk.json"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a skim case file"));
+}
+
+#[test]
+fn test_replay_help() {
+    common::skim()
+        .args(["replay", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Usage: skim replay"));
+}
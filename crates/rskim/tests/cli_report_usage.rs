@@ -0,0 +1,67 @@
+//! Integration tests for `--report-usage`.
+//!
+//! The summary goes to stderr after the run, so stdout stays the transformed
+//! output.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+#[test]
+fn test_report_usage_text_line() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.rs"), "fn a() { 1 }\n").unwrap();
+    fs::write(dir.path().join("b.rs"), "fn b() { 2 }\n").unwrap();
+
+    common::skim()
+        .args([".", "--no-cache", "--report-usage"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fn a()"))
+        .stdout(predicate::str::contains("[skim] usage").not())
+        .stderr(predicate::str::contains("[skim] usage: "))
+        .stderr(predicate::str::contains("s wall"))
+        .stderr(predicate::str::contains("2 file(s)"))
+        .stderr(predicate::str::contains("cache not used"));
+}
+
+#[test]
+fn test_report_usage_json_counts_cache_hits() {
+    let dir = TempDir::new().unwrap();
+    let cache = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.py"), "def a():\n    return 1\n").unwrap();
+
+    let run = || {
+        common::skim()
+            .args(["a.py", "--report-usage=json"])
+            .current_dir(dir.path())
+            .env("SKIM_CACHE_DIR", cache.path())
+            .output()
+            .unwrap()
+    };
+    run();
+    let output = run();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let line = stderr
+        .lines()
+        .find(|l| l.starts_with('{'))
+        .unwrap_or_else(|| panic!("no JSON usage line in stderr: {stderr}"));
+    let json: serde_json::Value = serde_json::from_str(line).unwrap();
+    assert_eq!(json["files"], 1);
+    assert_eq!(json["cache"]["lookups"], 1);
+    assert_eq!(json["cache"]["hits"], 1);
+    assert!(json["wall_ms"].is_u64());
+}
+
+#[test]
+fn test_report_usage_rejects_unknown_format() {
+    common::skim()
+        .args(["-", "--lang=rust", "--report-usage=yaml"])
+        .write_stdin("fn a() {}\n")
+        .assert()
+        .failure();
+}
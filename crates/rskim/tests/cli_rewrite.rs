@@ -584,7 +584,7 @@ fn test_rewrite_tail_with_count() {
 #[test]
 fn test_rewrite_tail_non_code_rejected() {
     common::skim()
-        .args(["rewrite", "tail", "-20", "data.csv"])
+        .args(["rewrite", "tail", "-20", "data.parquet"])
         .assert()
         .failure();
 }
@@ -592,7 +592,7 @@ fn test_rewrite_tail_non_code_rejected() {
 #[test]
 fn test_rewrite_cat_non_code_rejected() {
     common::skim()
-        .args(["rewrite", "cat", "data.csv"])
+        .args(["rewrite", "cat", "data.parquet"])
         .assert()
         .failure();
 }
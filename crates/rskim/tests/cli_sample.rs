@@ -0,0 +1,113 @@
+//! Integration tests for `--sample`, `--sample-percent` and `--sample-seed`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+/// Twelve Rust files, six Python files and one Markdown file.
+fn repo() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for i in 0..12 {
+        fs::write(
+            dir.path().join(format!("m{i:02}.rs")),
+            format!("fn f{i}() {{}}\n"),
+        )
+        .unwrap();
+    }
+    for i in 0..6 {
+        fs::write(
+            dir.path().join(format!("s{i}.py")),
+            format!("def g{i}():\n    pass\n"),
+        )
+        .unwrap();
+    }
+    fs::write(dir.path().join("README.md"), "# Readme\n").unwrap();
+    dir
+}
+
+fn headers(stdout: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(stdout)
+        .lines()
+        .filter(|line| line.starts_with("// "))
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn test_sample_keeps_every_language_and_reports_seed() {
+    let dir = repo();
+    let output = common::skim()
+        .arg(dir.path())
+        .args(["--no-cache", "--sample", "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let headers = headers(&output.stdout);
+    assert_eq!(headers.len(), 4, "{headers:?}");
+    for ext in [".rs", ".py", ".md"] {
+        assert!(headers.iter().any(|h| h.ends_with(ext)), "{headers:?}");
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Sampled 4 of 19 files (seed "), "{stderr}");
+}
+
+#[test]
+fn test_sample_seed_repeats_the_sample() {
+    let dir = repo();
+    let run = |seed: &str| {
+        common::skim()
+            .arg(dir.path())
+            .args([
+                "--no-cache",
+                "--sample-percent",
+                "25",
+                "--sample-seed",
+                seed,
+            ])
+            .output()
+            .unwrap()
+    };
+    let first = run("42");
+    assert!(first.status.success());
+    assert_eq!(headers(&first.stdout).len(), 5);
+    assert_eq!(run("42").stdout, first.stdout);
+    assert!(
+        String::from_utf8_lossy(&first.stderr).contains("--sample-seed 42"),
+        "seed is echoed"
+    );
+}
+
+#[test]
+fn test_sample_rejects_invalid_use() {
+    let dir = repo();
+    common::skim()
+        .arg(dir.path().join("m00.rs"))
+        .args(["--sample", "2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--sample requires a directory, glob, or several files",
+        ));
+    common::skim()
+        .arg(dir.path())
+        .args(["--sample", "2", "--sample-percent", "10"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be combined"));
+    common::skim()
+        .arg(dir.path())
+        .args(["--sample-percent", "150"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("at most 100"));
+    common::skim()
+        .arg(dir.path())
+        .args(["--sample-seed", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--sample-seed requires --sample or --sample-percent",
+        ));
+}
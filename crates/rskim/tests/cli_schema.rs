@@ -0,0 +1,111 @@
+//! Integration tests for `--schema` (JSON Schemas of machine-readable output).
+
+use predicates::prelude::*;
+mod common;
+
+fn print_schema(format: &str) -> serde_json::Value {
+    let output = common::skim()
+        .args(["--schema", format])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+#[test]
+fn test_schema_version_matches_emitted_documents() {
+    let output = common::skim()
+        .args(["-", "--lang", "python", "--detailed-json"])
+        .write_stdin("x = 1\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let record: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let schema = print_schema("detailed-json");
+    assert_eq!(
+        record["schema_version"],
+        schema["properties"]["schema_version"]["const"]
+    );
+    let properties = schema["properties"].as_object().unwrap();
+    for key in record.as_object().unwrap().keys() {
+        assert!(properties.contains_key(key), "{key} missing from schema");
+    }
+
+    let output = common::skim()
+        .args(["-", "--lang", "python", "--format", "ast-json"])
+        .write_stdin("x = 1\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let tree: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        tree["schema_version"],
+        print_schema("ast-json")["properties"]["schema_version"]["const"]
+    );
+}
+
+#[test]
+fn test_cache_schemas_match_emitted_reports() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let output = common::skim()
+        .env("SKIM_CACHE_DIR", dir.path())
+        .args(["verify-cache", "--dry-run", "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let schema = print_schema("verify-cache");
+    assert_eq!(
+        report["schema_version"],
+        schema["properties"]["schema_version"]["const"]
+    );
+    let mut required: Vec<&str> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|key| key.as_str().unwrap())
+        .collect();
+    let mut keys: Vec<&str> = report
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    required.sort_unstable();
+    keys.sort_unstable();
+    assert_eq!(keys, required);
+
+    let schema = print_schema("bench-cache");
+    assert_eq!(schema["title"], "skim bench-cache --format json report");
+    assert_eq!(schema["properties"]["schema_version"]["const"], 1);
+}
+
+#[test]
+fn test_schema_value_is_not_taken_for_a_subcommand() {
+    // `stats` is also a subcommand name; as a --schema value it is a format.
+    let schema = print_schema("stats");
+    assert_eq!(schema["title"], "skim stats --format json report");
+    assert!(schema["required"].as_array().unwrap().len() > 1);
+}
+
+#[test]
+fn test_schema_rejects_files_and_unknown_formats() {
+    common::skim()
+        .args(["--schema", "usage", "main.rs"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+    common::skim()
+        .args(["--schema", "yaml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("detailed-json"));
+}
@@ -0,0 +1,77 @@
+//! Integration tests for `--separator` between files under `--no-header`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("a.rs"), "fn alpha() {}\n").unwrap();
+    fs::write(temp.path().join("b.rs"), "fn beta() {}\n").unwrap();
+    temp
+}
+
+#[test]
+fn test_separator_between_files() {
+    let temp = project();
+    common::skim()
+        .arg(temp.path())
+        .args(["--no-header", "--separator", r"\f", "--mode", "full"])
+        .assert()
+        .success()
+        .stdout("fn alpha() {}\n\x0c\nfn beta() {}\n");
+}
+
+#[test]
+fn test_separator_custom_string() {
+    let temp = project();
+    common::skim()
+        .arg(temp.path())
+        .args(["--no-header", "--separator", "-----", "--mode", "full"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "fn alpha() {}\n-----\nfn beta() {}\n",
+        ))
+        .stdout(predicate::str::contains("// ").not());
+}
+
+#[test]
+fn test_separator_not_printed_for_single_file() {
+    let temp = project();
+    common::skim()
+        .arg(temp.path().join("a.rs"))
+        .args(["--no-header", "--separator", "-----", "--mode", "full"])
+        .assert()
+        .success()
+        .stdout("fn alpha() {}\n");
+}
+
+#[test]
+fn test_separator_requires_no_header() {
+    let temp = project();
+    common::skim()
+        .arg(temp.path())
+        .args(["--separator", "-----"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--no-header"));
+}
+
+#[test]
+fn test_separator_rejected_with_claude_xml() {
+    let temp = project();
+    common::skim()
+        .arg(temp.path())
+        .args([
+            "--no-header",
+            "--separator",
+            "-----",
+            "--format",
+            "claude-xml",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--separator cannot be combined"));
+}
@@ -169,6 +169,39 @@ fn test_stats_json_always_includes_cost_estimate() {
     );
 }
 
+#[test]
+fn test_stats_cost_flag_reports_deprecation() {
+    let db = NamedTempFile::new().unwrap();
+    let output = skim_stats_cmd(&db)
+        .args(["--cost", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("[skim] deprecated (stats-cost-flag)"),
+        "stderr should carry the deprecation notice, got: {stderr}"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(String::from_utf8(output.stdout).unwrap().trim()).unwrap();
+    assert_eq!(json["deprecations"][0]["id"], "stats-cost-flag");
+    assert_eq!(json["deprecations"][0]["removal"], "3.0.0");
+}
+
+#[test]
+fn test_stats_json_omits_deprecations_when_none_hit() {
+    let db = NamedTempFile::new().unwrap();
+    let output = skim_stats_cmd(&db)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_str(String::from_utf8(output.stdout).unwrap().trim()).unwrap();
+    assert!(json.get("deprecations").is_none());
+}
+
 // ============================================================================
 // --verbose: Parse Quality section
 // ============================================================================
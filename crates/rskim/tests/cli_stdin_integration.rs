@@ -50,6 +50,21 @@ fn test_stdin_mode_and_stats_combined() {
         .stderr(predicate::str::contains("tokens"));
 }
 
+#[test]
+fn test_stdin_stats_fast_labels_estimate() {
+    let input = "fn add(a: i32, b: i32) -> i32 { a + b }\nfn sub(x: i32, y: i32) -> i32 { x - y }";
+
+    // --stats-fast implies --show-stats and labels the numbers as estimates
+    common::skim()
+        .args(["-", "--lang=rust", "--mode=signatures", "--stats-fast"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fn add(a: i32, b: i32) -> i32"))
+        .stderr(predicate::str::contains("~"))
+        .stderr(predicate::str::contains("estimated"));
+}
+
 #[test]
 fn test_stdin_tokens_and_stats_combined() {
     let input = "fn add(a: i32, b: i32) -> i32 { a + b }";
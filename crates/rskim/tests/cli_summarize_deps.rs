@@ -0,0 +1,105 @@
+//! Integration tests for `skim summarize-deps` (dependency directory summaries).
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn write(root: &Path, rel: &str, content: &str) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn node_modules() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "left-pad/package.json",
+        r#"{"name": "left-pad", "version": "1.3.0", "main": "index.js", "types": "index.d.ts"}"#,
+    );
+    write(
+        root,
+        "left-pad/index.js",
+        "module.exports = function leftPad(str, len) {\n  return str.padStart(len);\n};\n",
+    );
+    write(
+        root,
+        "left-pad/index.d.ts",
+        "declare function leftPad(str: string, len: number): string;\nexport = leftPad;\n",
+    );
+    write(
+        root,
+        "tiny-math/package.json",
+        r#"{"name": "tiny-math", "version": "0.2.0", "main": "lib/index.js"}"#,
+    );
+    write(
+        root,
+        "tiny-math/lib/index.js",
+        "export function add(a, b) {\n  const sum = a + b;\n  return sum;\n}\n",
+    );
+    dir
+}
+
+#[test]
+fn test_summarize_deps_prints_name_version_and_signatures() {
+    let dir = node_modules();
+    common::skim()
+        .arg("summarize-deps")
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "// package left-pad@1.3.0 (entry: index.d.ts)\n\
+             function leftPad(str: string, len: number): string;\n",
+        ))
+        .stdout(predicate::str::contains(
+            "// package tiny-math@0.2.0 (entry: lib/index.js)\n",
+        ))
+        .stdout(predicate::str::contains("function add(a, b)"))
+        .stdout(predicate::str::contains("const sum").not())
+        .stdout(predicate::str::contains("padStart").not());
+}
+
+#[test]
+fn test_summarize_deps_filters_by_package_name() {
+    let dir = node_modules();
+    common::skim()
+        .arg("summarize-deps")
+        .arg(dir.path())
+        .arg("tiny-math")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("tiny-math@0.2.0"))
+        .stdout(predicate::str::contains("left-pad").not());
+
+    common::skim()
+        .arg("summarize-deps")
+        .arg(dir.path())
+        .arg("missing-pkg")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no package named 'missing-pkg'"));
+}
+
+#[test]
+fn test_summarize_deps_rejects_directory_without_packages() {
+    let dir = TempDir::new().unwrap();
+    common::skim()
+        .arg("summarize-deps")
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no packages found"));
+}
+
+#[test]
+fn test_summarize_deps_help() {
+    common::skim()
+        .args(["summarize-deps", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Usage: skim summarize-deps"));
+}
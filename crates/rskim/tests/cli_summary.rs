@@ -0,0 +1,41 @@
+//! Integration tests for `--mode summary`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+#[test]
+fn test_summary_prints_one_counts_line_per_file() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("app.py"),
+        "class App:\n    def run(self):\n        pass\n\ndef main():\n    App().run()\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("config.json"), "{\"debug\": true}\n").unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["app.py", "config.json", "--no-cache", "-m", "sum"])
+        .assert()
+        .success()
+        .stdout(
+            "// app.py\nlines: 6, functions: 2, classes: 1, types: 0, tokens: ~19\n\n\
+             // config.json\nlines: 1, functions: 0, classes: 0, types: 0, tokens: ~6\n",
+        );
+}
+
+#[test]
+fn test_summary_rejects_validate_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("app.py");
+    fs::write(&file, "def main():\n    pass\n").unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "summary", "--validate-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--validate-output cannot be combined with --mode summary",
+        ));
+}
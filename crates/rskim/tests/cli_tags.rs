@@ -0,0 +1,64 @@
+//! Integration tests for `--format ctags` and `--format symbols`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const MAIN_RS: &str = "pub struct Config;\n\nimpl Config {\n    \
+                       pub fn validate(&self) {}\n}\n\nfn main() {}\n";
+const APP_PY: &str = "class App:\n    def run(self):\n        pass\n";
+
+#[test]
+fn test_symbols_lists_every_file_without_headers() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("main.rs"), MAIN_RS).unwrap();
+    fs::write(dir.path().join("app.py"), APP_PY).unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["app.py", "main.rs", "--format", "symbols"])
+        .assert()
+        .success()
+        .stdout(
+            "app.py:1:class:App\napp.py:2:method:App.run\n\
+             main.rs:1:struct:Config\nmain.rs:3:impl:Config\n\
+             main.rs:4:method:Config.validate\nmain.rs:7:function:main\n",
+        );
+}
+
+#[test]
+fn test_ctags_writes_pseudo_tags_once() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("main.rs"), MAIN_RS).unwrap();
+    fs::write(dir.path().join("app.py"), APP_PY).unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["app.py", "main.rs", "--format", "ctags"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "!_TAG_FILE_FORMAT\t2\t/extended format/\n\
+             !_TAG_FILE_SORTED\t0\t/0=unsorted, 1=sorted, 2=foldcase/\n\
+             !_TAG_PROGRAM_NAME\tskim\t//\n\
+             App\tapp.py\t1;\"\tkind:class\tline:1\n",
+        ))
+        .stdout(predicate::str::contains(
+            "validate\tmain.rs\t4;\"\tkind:method\tline:4\timpl:Config\n",
+        ))
+        .stdout(predicate::str::contains("!_TAG_FILE_FORMAT").count(1));
+}
+
+#[test]
+fn test_tag_formats_reject_truncation() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("main.rs");
+    fs::write(&file, MAIN_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--format", "symbols", "--tokens", "100"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--tokens cannot be combined with --format ctags/symbols",
+        ));
+}
@@ -0,0 +1,167 @@
+//! Integration tests for `--template` output rendering.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn write(root: &Path, rel: &str, contents: &str) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+/// A tree with two sources.
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "src/a.ts",
+        "export function alpha(x: number): number {\n  return x + 1;\n}\n",
+    );
+    write(
+        temp.path(),
+        "src/b.py",
+        "def beta(name):\n    return name.upper()\n",
+    );
+    temp
+}
+
+fn stdout_of(cmd: &mut assert_cmd::Command) -> String {
+    String::from_utf8(cmd.assert().success().get_output().stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_template_renders_files_in_custom_layout() {
+    let temp = project();
+    write(
+        temp.path(),
+        "bundle.hbs",
+        "<documents mode=\"{{mode}}\" count=\"{{stats.files}}\">\n\
+         {{#each files}}\n\
+         <document index=\"{{@index}}\" source=\"{{path}}\" lang=\"{{language}}\">\n\
+         {{content}}\
+         </document>\n\
+         {{/each}}\n\
+         </documents>\n",
+    );
+
+    let stdout = stdout_of(common::skim().current_dir(temp.path()).args([
+        "src/a.ts",
+        "src/b.py",
+        "--no-cache",
+        "--template",
+        "bundle.hbs",
+    ]));
+
+    assert!(
+        stdout.starts_with("<documents mode=\"structure\" count=\"2\">\n"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains(
+            "<document index=\"0\" source=\"src/a.ts\" lang=\"typescript\">\n\
+             export function alpha(x: number): number"
+        ),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("<document index=\"1\" source=\"src/b.py\" lang=\"python\">\n"),
+        "{stdout}"
+    );
+    assert!(stdout.ends_with("</document>\n</documents>\n"), "{stdout}");
+    assert!(
+        !stdout.contains("// src/a.ts"),
+        "no default headers: {stdout}"
+    );
+    assert!(
+        !stdout.contains("x + 1"),
+        "bodies are still skimmed: {stdout}"
+    );
+}
+
+#[test]
+fn test_template_exposes_symbols() {
+    let temp = project();
+    write(
+        temp.path(),
+        "symbols.hbs",
+        "{{#each files}}{{path}}:{{#each symbols}} {{name}}@{{start_line}}{{/each}}\n{{/each}}",
+    );
+
+    let stdout = stdout_of(common::skim().current_dir(temp.path()).args([
+        "src",
+        "--no-cache",
+        "--template",
+        "symbols.hbs",
+    ]));
+
+    assert!(stdout.contains("src/a.ts: alpha@1\n"), "{stdout}");
+    assert!(stdout.contains("src/b.py: beta@1\n"), "{stdout}");
+}
+
+#[test]
+fn test_template_single_file_and_stats() {
+    let temp = project();
+    write(
+        temp.path(),
+        "stats.hbs",
+        "{{stats.files}} file(s), {{stats.original_tokens}} -> {{stats.transformed_tokens}} tokens\n",
+    );
+
+    let stdout = stdout_of(common::skim().current_dir(temp.path()).args([
+        "src/a.ts",
+        "--no-cache",
+        "--show-stats",
+        "--template",
+        "stats.hbs",
+    ]));
+
+    assert!(stdout.starts_with("1 file(s), "), "{stdout}");
+    assert!(!stdout.contains(",  ->"), "counts are filled in: {stdout}");
+}
+
+#[test]
+fn test_template_unknown_variable_fails() {
+    let temp = project();
+    write(temp.path(), "typo.hbs", "{{#each files}}{{pth}}{{/each}}");
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["src/a.ts", "--no-cache", "--template", "typo.hbs"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("unknown variable 'pth'"));
+}
+
+#[test]
+fn test_template_syntax_error_fails_before_processing() {
+    let temp = project();
+    write(temp.path(), "broken.hbs", "{{#each files}}\n{{path}}\n");
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["src/a.ts", "--template", "broken.hbs"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("broken.hbs"))
+        .stderr(predicate::str::contains("is never closed"));
+}
+
+#[test]
+fn test_template_rejects_stdin() {
+    let temp = project();
+    write(temp.path(), "t.hbs", "{{mode}}");
+
+    common::skim()
+        .current_dir(temp.path())
+        .args(["-", "--language", "rust", "--template", "t.hbs"])
+        .write_stdin("fn main() {}\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--template cannot be used with stdin",
+        ));
+}
@@ -0,0 +1,28 @@
+//! Integration tests for `--todo-bodies`.
+
+use predicates::prelude::*;
+mod common;
+
+const SOURCE: &str = "pub fn area(w: u32, h: u32) -> u32 {\n    w * h\n}\n";
+
+#[test]
+fn test_todo_bodies_flag_replaces_rust_bodies() {
+    common::skim()
+        .args(["-", "--language=rust", "--todo-bodies", "--no-cache"])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout("pub fn area(w: u32, h: u32) -> u32  { todo!() }\n");
+}
+
+#[test]
+fn test_todo_bodies_requires_structure_mode() {
+    common::skim()
+        .args(["-", "--language=rust", "--mode=signatures", "--todo-bodies"])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--todo-bodies requires --mode structure",
+        ));
+}
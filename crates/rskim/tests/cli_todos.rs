@@ -0,0 +1,41 @@
+//! Integration tests for `--mode todos`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+const LIB_RS: &str = "// TODO: split this file\n\npub fn load() {\n    \
+                      // FIXME(io): handle EOF\n}\n";
+const APP_PY: &str = "class App:\n    def run(self):\n        pass  # HACK: no-op\n";
+
+#[test]
+fn test_todos_lists_markers_per_file() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("lib.rs"), LIB_RS).unwrap();
+    fs::write(dir.path().join("app.py"), APP_PY).unwrap();
+    common::skim()
+        .current_dir(dir.path())
+        .args(["app.py", "lib.rs", "--no-cache", "-m", "todo"])
+        .assert()
+        .success()
+        .stdout(
+            "// app.py\n3: HACK: no-op (App.run)\n\n\
+             // lib.rs\n1: TODO: split this file\n4: FIXME(io): handle EOF (load)\n",
+        );
+}
+
+#[test]
+fn test_todos_rejects_validate_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("lib.rs");
+    fs::write(&file, LIB_RS).unwrap();
+    common::skim()
+        .arg(&file)
+        .args(["--mode", "todos", "--validate-output"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--validate-output cannot be combined with --mode todos",
+        ));
+}
@@ -0,0 +1,76 @@
+//! Integration tests for `--validate-output`.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+mod common;
+
+/// A shell `if` long enough for structure mode to collapse it onto one line,
+/// where the closing `fi` reads as an argument of `...`.
+const COLLAPSED_IF: &str = "if [ -n \"$1\" ]; then\n  a\n  b\n  c\n  d\n  e\n  f\n  g\n  h\nfi\n";
+
+#[test]
+fn test_valid_output_passes() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("a.ts");
+    fs::write(&file, "export function f(a: number) {\n  return a;\n}\n").unwrap();
+
+    common::skim()
+        .arg(&file)
+        .arg("--validate-output")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export function f(a: number)"))
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_introduced_errors_fail_the_file_by_default() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("deploy.sh");
+    fs::write(&file, COLLAPSED_IF).unwrap();
+    fs::write(dir.path().join("ok.py"), "def f(x):\n    return x\n").unwrap();
+
+    common::skim()
+        .arg(&file)
+        .arg("--validate-output")
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "output has 1 syntax error(s) not in the source (first at output line 2)",
+        ));
+
+    // In a multi-file run only the broken file is left out.
+    common::skim()
+        .arg(dir.path())
+        .arg("--validate-output")
+        .assert()
+        .stdout(predicate::str::contains("def f(x):"))
+        .stdout(predicate::str::contains("then ... fi").not())
+        .stderr(predicate::str::contains(
+            "deploy.sh: output has 1 syntax error(s) not in the source",
+        ));
+}
+
+#[test]
+fn test_warn_keeps_output() {
+    common::skim()
+        .args(["-", "--language", "shell", "--validate-output=warn"])
+        .write_stdin(COLLAPSED_IF)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("then ... fi"))
+        .stderr(predicate::str::contains(
+            "[skim] warning: -: output has 1 syntax error(s) not in the source",
+        ));
+}
+
+#[test]
+fn test_source_errors_are_not_reported() {
+    common::skim()
+        .args(["-", "--language", "rust", "--validate-output"])
+        .write_stdin("fn broken( {\n    1\n}\n")
+        .assert()
+        .success();
+}
@@ -0,0 +1,138 @@
+//! Integration tests for `skim verify-cache` (cache integrity and pruning).
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+mod common;
+
+fn json_entries(cache_dir: &Path) -> usize {
+    fs::read_dir(cache_dir)
+        .unwrap()
+        .filter(|e| {
+            e.as_ref()
+                .unwrap()
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "json")
+        })
+        .count()
+}
+
+/// Cache one file, then delete it and add a corrupt entry next to it.
+fn cache_with_orphan_and_corrupt_entry() -> TempDir {
+    let cache_dir = TempDir::new().unwrap();
+    let src_dir = TempDir::new().unwrap();
+    let file = src_dir.path().join("gone.ts");
+    fs::write(&file, "function gone(): number { return 1; }\n").unwrap();
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .arg(&file)
+        .assert()
+        .success();
+    fs::remove_file(&file).unwrap();
+    fs::write(cache_dir.path().join("corrupt.json"), "{\"path\": ").unwrap();
+    cache_dir
+}
+
+#[test]
+fn test_verify_cache_check_fails_without_removing() {
+    let cache_dir = cache_with_orphan_and_corrupt_entry();
+    let before = json_entries(cache_dir.path());
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .args(["verify-cache", "--check"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_match(r"corrupt\s+1").unwrap())
+        .stdout(predicate::str::is_match(r"orphaned\s+[1-9]").unwrap())
+        .stdout(predicate::str::contains("Reclaimable:"));
+
+    assert_eq!(json_entries(cache_dir.path()), before);
+}
+
+#[test]
+fn test_verify_cache_prunes_then_reports_clean() {
+    let cache_dir = cache_with_orphan_and_corrupt_entry();
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .arg("verify-cache")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed"));
+    assert_eq!(json_entries(cache_dir.path()), 0);
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .args(["verify-cache", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Cache OK"));
+}
+
+#[test]
+fn test_verify_cache_keeps_entries_for_live_sources() {
+    let cache_dir = TempDir::new().unwrap();
+    let src_dir = TempDir::new().unwrap();
+    let file = src_dir.path().join("live.ts");
+    fs::write(&file, "function live(): number { return 1; }\n").unwrap();
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .arg(&file)
+        .assert()
+        .success();
+    let before = json_entries(cache_dir.path());
+    assert!(before > 0);
+
+    common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .arg("verify-cache")
+        .assert()
+        .success();
+    assert_eq!(json_entries(cache_dir.path()), before);
+}
+
+#[test]
+fn test_verify_cache_json_report() {
+    let cache_dir = cache_with_orphan_and_corrupt_entry();
+
+    let output = common::skim()
+        .env("SKIM_CACHE_DIR", cache_dir.path())
+        .args(["verify-cache", "--dry-run", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["schema_version"], 1);
+    assert_eq!(report["corrupt"]["entries"], 1);
+    assert_eq!(report["removed"]["entries"], 0);
+    assert!(report["scanned"]["bytes"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn test_verify_cache_missing_dir_is_clean() {
+    let dir = TempDir::new().unwrap();
+    common::skim()
+        .env("SKIM_CACHE_DIR", dir.path().join("never-created"))
+        .args(["verify-cache", "--check", "--older-than", "7d"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Cache OK"));
+}
+
+#[test]
+fn test_verify_cache_rejects_bad_arguments() {
+    common::skim()
+        .args(["verify-cache", "--older-than", "soon"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid duration format"));
+    common::skim()
+        .args(["verify-cache", "--prune-all"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown argument"));
+}
@@ -0,0 +1,49 @@
+//! Integration tests for `--wrap`.
+
+use predicates::prelude::*;
+mod common;
+
+const SOURCE: &str =
+    "def request(method, url, params, data, headers, cookies, files, auth):\n    pass\n";
+
+#[test]
+fn test_wrap_soft_wraps_signature_lines() {
+    common::skim()
+        .args([
+            "-",
+            "--language=python",
+            "--mode=signatures",
+            "--wrap=40",
+            "--no-cache",
+        ])
+        .write_stdin(SOURCE)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "def request(method, url, params, data,\n    headers, cookies, files, auth):",
+        ));
+}
+
+#[test]
+fn test_wrap_requires_signatures_mode() {
+    common::skim()
+        .args(["-", "--language=python", "--wrap=40"])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--wrap requires --mode signatures",
+        ));
+}
+
+#[test]
+fn test_wrap_rejects_narrow_widths() {
+    common::skim()
+        .args(["-", "--language=python", "--mode=signatures", "--wrap=5"])
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--wrap must be between 20 and 1000",
+        ));
+}
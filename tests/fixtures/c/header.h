@@ -0,0 +1,20 @@
+// FIXTURE: C header with prototypes only
+// TESTS: Prototype extraction in signatures mode
+
+#ifndef BUFFER_H
+#define BUFFER_H
+
+#include <stddef.h>
+
+typedef struct Buffer Buffer;
+
+typedef void (*buffer_free_fn)(void *ptr);
+
+extern int buffer_debug;
+
+Buffer *buffer_new(size_t capacity);
+int buffer_append(Buffer *buf, const char *data, size_t len);
+void buffer_free(Buffer *buf);
+static inline size_t buffer_len(const Buffer *buf);
+
+#endif
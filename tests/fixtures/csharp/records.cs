@@ -0,0 +1,36 @@
+/**
+ * FIXTURE: C# records, properties, and delegates
+ * TESTS: Record extraction and property retention
+ */
+
+namespace MyApp.Orders
+{
+    public record Money(decimal Amount, string Currency);
+
+    public record Order
+    {
+        public Guid Id { get; init; }
+        public List<Money> Lines { get; init; } = new();
+
+        public Money Total()
+        {
+            return new Money(Lines.Sum(l => l.Amount), "USD");
+        }
+    }
+
+    public class OrderService
+    {
+        private readonly ILogger _logger;
+        public int Pending { get; private set; }
+
+        ~OrderService()
+        {
+            _logger.LogInformation("disposed");
+        }
+
+        public void Submit(Order order)
+        {
+            Pending += 1;
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2024 The Example Authors.
+// Use of this source code is governed by a BSD-style license.
+
+/// Utilities for formatting durations.
+///
+/// ```dart
+/// print(formatDuration(const Duration(minutes: 90))); // 1h 30m
+/// ```
+library duration_format;
+
+/* Shared separator between units. */
+const separator = ' ';
+
+/// Formats [duration] as hours and minutes.
+String formatDuration(Duration duration) {
+  // Whole hours first, then the remaining minutes.
+  final hours = duration.inHours;
+  final minutes = duration.inMinutes.remainder(60);
+  if (hours == 0) {
+    return '${minutes}m';
+  }
+  return '${hours}h$separator${minutes}m';
+}
+
+/**
+ * Legacy block doc comment.
+ */
+int legacyMinutes(Duration duration) => duration.inMinutes;
+
+// TODO: support seconds.
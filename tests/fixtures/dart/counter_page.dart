@@ -0,0 +1,58 @@
+import 'package:flutter/material.dart';
+
+/// Signature for counter change callbacks.
+typedef CounterChanged = void Function(int value);
+
+// Default step used by the page.
+const int defaultStep = 1;
+
+/// A page that shows a counter.
+class CounterPage extends StatefulWidget {
+  const CounterPage({super.key, required this.title, this.step = defaultStep});
+
+  final String title;
+  final int step;
+
+  @override
+  State<CounterPage> createState() => _CounterPageState();
+}
+
+class _CounterPageState extends State<CounterPage> {
+  int _count = 0;
+
+  void _increment() {
+    setState(() {
+      _count += widget.step;
+    });
+  }
+
+  int get count => _count;
+
+  @override
+  Widget build(BuildContext context) {
+    return Scaffold(
+      appBar: AppBar(title: Text(widget.title)),
+      body: Center(child: Text('$_count')),
+      floatingActionButton: FloatingActionButton(
+        onPressed: _increment,
+        child: const Icon(Icons.add),
+      ),
+    );
+  }
+}
+
+mixin Logging {
+  void log(String message) {
+    print('[log] $message');
+  }
+}
+
+extension CountFormatting on int {
+  String get label => this == 1 ? '1 tap' : '$this taps';
+}
+
+enum CounterMode { up, down }
+
+String formatCount(int count) {
+  return 'Count: $count';
+}
@@ -0,0 +1,58 @@
+/// Data models for the todo list.
+library todo.models;
+
+import 'dart:convert';
+
+/// A single todo item.
+class Todo {
+  Todo({required this.id, required this.title, this.done = false});
+
+  /// Builds a todo from decoded JSON.
+  factory Todo.fromJson(Map<String, dynamic> json) {
+    return Todo(
+      id: json['id'] as int,
+      title: json['title'] as String,
+      done: json['done'] as bool? ?? false,
+    );
+  }
+
+  Todo.placeholder() : this(id: 0, title: 'Untitled');
+
+  final int id;
+  final String title;
+  bool done;
+
+  String get summary => done ? '[x] $title' : '[ ] $title';
+
+  set completed(bool value) {
+    done = value;
+  }
+
+  Map<String, dynamic> toJson() {
+    return {'id': id, 'title': title, 'done': done};
+  }
+
+  @override
+  String toString() => jsonEncode(toJson());
+}
+
+/// Something that can be stored by id.
+abstract class Repository<T> {
+  T? find(int id);
+
+  void save(T item);
+}
+
+abstract class Filter {
+  const Filter();
+}
+
+class ShowAll extends Filter {
+  const ShowAll();
+}
+
+class ShowDone extends Filter {
+  const ShowDone(this.limit);
+
+  final int limit;
+}
@@ -0,0 +1,54 @@
+import 'dart:async';
+
+import 'models.dart';
+
+/// Loads and saves todos over the network.
+class TodoService implements Repository<Todo> {
+  TodoService(this._client);
+
+  final HttpClient _client;
+  final Map<int, Todo> _cache = {};
+
+  @override
+  Todo? find(int id) {
+    return _cache[id];
+  }
+
+  @override
+  void save(Todo item) {
+    _cache[item.id] = item;
+  }
+
+  /// Fetches every todo, filling the cache.
+  Future<List<Todo>> fetchAll() async {
+    final response = await _client.get('/todos');
+    final todos = (response as List).map((e) => Todo.fromJson(e)).toList();
+    for (final todo in todos) {
+      _cache[todo.id] = todo;
+    }
+    return todos;
+  }
+
+  /// Emits each todo as it changes.
+  Stream<Todo> watch(int id) async* {
+    while (true) {
+      await Future.delayed(const Duration(seconds: 1));
+      final todo = _cache[id];
+      if (todo != null) {
+        yield todo;
+      }
+    }
+  }
+
+  static Future<TodoService> connect(String baseUrl) async {
+    final client = await HttpClient.open(baseUrl);
+    return TodoService(client);
+  }
+}
+
+abstract class HttpClient {
+  Future<Object?> get(String path);
+
+  static Future<HttpClient> open(String baseUrl) =>
+      throw UnimplementedError('open $baseUrl');
+}
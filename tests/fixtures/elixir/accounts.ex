@@ -0,0 +1,43 @@
+defmodule MyApp.Accounts do
+  @moduledoc """
+  User account management.
+  """
+
+  alias MyApp.Repo
+  alias MyApp.Accounts.User
+
+  # Accounts older than this are purged by the nightly job.
+  @retention_days 365
+
+  @doc "Fetch a user by id."
+  @spec get_user(integer()) :: {:ok, User.t()} | {:error, :not_found}
+  def get_user(id) when is_integer(id) do
+    case Repo.get(User, id) do
+      nil -> {:error, :not_found}
+      user -> {:ok, user}
+    end
+  end
+
+  def create_user(attrs \\ %{}) do
+    %User{}
+    |> User.changeset(attrs)
+    |> Repo.insert()
+  end
+
+  def active?(%User{status: status}), do: status == :active
+
+  defmacro with_user(id, do: block) do
+    quote do
+      case get_user(unquote(id)) do
+        {:ok, var!(user)} -> unquote(block)
+        error -> error
+      end
+    end
+  end
+
+  defp expired?(user) do
+    # Compare against the retention window
+    days = Date.diff(Date.utc_today(), user.inserted_at)
+    days > @retention_days
+  end
+end
@@ -0,0 +1,28 @@
+defmodule MyApp.AccountsTest do
+  use ExUnit.Case, async: true
+
+  alias MyApp.Accounts
+
+  setup do
+    user = %Accounts.User{name: "Ada", email: "ada@example.com"}
+    {:ok, user: user}
+  end
+
+  describe "get_user/1" do
+    test "returns the user", %{user: user} do
+      assert {:ok, ^user} = Accounts.get_user(user.id)
+    end
+
+    test "rejects a non-integer id" do
+      assert_raise FunctionClauseError, fn -> Accounts.get_user("1") end
+    end
+  end
+
+  describe "active?/1" do
+    test "is true for active users", %{user: user} do
+      assert Accounts.active?(user)
+    end
+  end
+
+  defp with_status(user, status), do: %{user | status: status}
+end
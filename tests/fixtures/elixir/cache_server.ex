@@ -0,0 +1,62 @@
+defmodule MyApp.CacheServer do
+  @moduledoc "A small TTL cache backed by a GenServer."
+
+  use GenServer
+  require Logger
+
+  # TODO: make the sweep interval configurable.
+  @sweep_interval :timer.seconds(30)
+  @default_ttl 300
+
+  ## Client API
+
+  def start_link(opts \\ []) do
+    GenServer.start_link(__MODULE__, opts, name: Keyword.get(opts, :name, __MODULE__))
+  end
+
+  def put(server \\ __MODULE__, key, value, ttl \\ @default_ttl) do
+    GenServer.cast(server, {:put, key, value, ttl})
+  end
+
+  def fetch!(server \\ __MODULE__, key) do
+    case GenServer.call(server, {:get, key}) do
+      {:ok, value} -> value
+      :error -> raise KeyError, key: key
+    end
+  end
+
+  ## Server callbacks
+
+  @impl true
+  def init(_opts) do
+    schedule_sweep()
+    {:ok, %{}}
+  end
+
+  @impl true
+  def handle_call({:get, key}, _from, state) do
+    case Map.fetch(state, key) do
+      {:ok, {value, _expires}} -> {:reply, {:ok, value}, state}
+      :error -> {:reply, :error, state}
+    end
+  end
+
+  @impl true
+  def handle_cast({:put, key, value, ttl}, state) do
+    expires = System.monotonic_time(:second) + ttl
+    {:noreply, Map.put(state, key, {value, expires})}
+  end
+
+  @impl true
+  def handle_info(:sweep, state) do
+    # FIXME: this walks the whole map on every sweep.
+    now = System.monotonic_time(:second)
+    Logger.debug("sweeping #{map_size(state)} entries")
+    schedule_sweep()
+    {:noreply, Map.reject(state, fn {_key, {_value, expires}} -> expires < now end)}
+  end
+
+  defp schedule_sweep do
+    Process.send_after(self(), :sweep, @sweep_interval)
+  end
+end
@@ -0,0 +1,48 @@
+defmodule MyApp.Accounts.User do
+  @moduledoc """
+  The user schema and its changesets.
+  """
+
+  use Ecto.Schema
+  import Ecto.Changeset
+
+  @type t :: %__MODULE__{
+          name: String.t(),
+          email: String.t(),
+          status: :active | :disabled
+        }
+
+  @required [:name, :email]
+  @email_format ~r/^[^@\s]+@[^@\s]+$/
+
+  schema "users" do
+    field :name, :string
+    field :email, :string
+    field :status, Ecto.Enum, values: [:active, :disabled], default: :active
+
+    timestamps()
+  end
+
+  @doc "Validate attributes for a new or updated user."
+  @spec changeset(t(), map()) :: Ecto.Changeset.t()
+  def changeset(user, attrs) do
+    user
+    |> cast(attrs, @required ++ [:status])
+    |> validate_required(@required)
+    |> validate_format(:email, @email_format)
+    |> unique_constraint(:email)
+  end
+
+  def display_name(%__MODULE__{name: name}), do: name
+end
+
+defprotocol MyApp.Accounts.Describable do
+  @doc "A one-line description for logs."
+  def describe(value)
+end
+
+defimpl MyApp.Accounts.Describable, for: MyApp.Accounts.User do
+  def describe(user) do
+    "#{user.name} <#{user.email}>"
+  end
+end
@@ -0,0 +1,62 @@
+package com.example.release
+
+import groovy.transform.CompileStatic
+import java.time.LocalDate
+
+/**
+ * A tagged release and the notes published with it.
+ */
+@CompileStatic
+class Release implements Comparable<Release> {
+    static final String TAG_PREFIX = 'v'
+
+    String version
+    Channel channel = Channel.STABLE
+    LocalDate date = LocalDate.now()
+
+    Release(String version) {
+        this.version = version
+    }
+
+    String getTag() {
+        return "${TAG_PREFIX}${version}"
+    }
+
+    // TODO: compare pre-release suffixes too
+    @Override
+    int compareTo(Release other) {
+        def mine = version.tokenize('.')*.toInteger()
+        def theirs = other.version.tokenize('.')*.toInteger()
+        return [mine, theirs].transpose().findResult { a, b -> a <=> b ?: null } ?: 0
+    }
+
+    static Release parse(String text) throws IllegalArgumentException {
+        if (!text.startsWith(TAG_PREFIX)) {
+            throw new IllegalArgumentException("not a tag: $text")
+        }
+        new Release(text.substring(1))
+    }
+}
+
+enum Channel {
+    STABLE('stable'),
+    BETA('beta'),
+    NIGHTLY('nightly')
+
+    final String label
+
+    Channel(String label) {
+        this.label = label
+    }
+}
+
+interface Publisher {
+    void publish(Release release)
+}
+
+trait Announcing {
+    String announcement(Release release) {
+        """Released ${release.version} {${release.channel.label}}
+on ${release.date}"""
+    }
+}
@@ -0,0 +1,39 @@
+package com.example.release
+
+import spock.lang.Specification
+import spock.lang.Unroll
+
+class ReleaseSpec extends Specification {
+
+    def "parses a tag into a release"() {
+        when:
+        def release = Release.parse('v1.4.0')
+
+        then:
+        release.version == '1.4.0'
+        release.tag == 'v1.4.0'
+    }
+
+    @Unroll
+    def "orders #a before #b"() {
+        expect:
+        new Release(a) < new Release(b)
+
+        where:
+        a       | b
+        '1.2.0' | '1.10.0'
+        '1.4.0' | '2.0.0'
+    }
+
+    def 'rejects untagged versions'() {
+        when:
+        Release.parse('1.4.0')
+
+        then:
+        thrown(IllegalArgumentException)
+    }
+
+    private static Release release(String version) {
+        new Release(version)
+    }
+}
@@ -0,0 +1,6 @@
+resource "aws_s3_bucket" "logs" {
+  bucket = "acme-logs"
+  acl    = "private
+
+resource "aws_s3_bucket" "assets" {
+  bucket = "acme-assets"
@@ -0,0 +1,36 @@
+packer {
+  required_plugins {
+    amazon = {
+      version = ">= 1.2.0"
+      source  = "github.com/hashicorp/amazon"
+    }
+  }
+}
+
+source "amazon-ebs" "ubuntu" {
+  ami_name      = "acme-orders-{{timestamp}}"
+  instance_type = "t3.small"
+  region        = var.region
+  source_ami_filter {
+    filters = {
+      name = "ubuntu/images/*ubuntu-jammy-22.04-amd64-server-*"
+    }
+    most_recent = true
+    owners      = ["099720109477"]
+  }
+  ssh_username = "ubuntu"
+}
+
+build {
+  name    = "orders"
+  sources = ["source.amazon-ebs.ubuntu"]
+
+  provisioner "shell" {
+    inline = [
+      "sudo apt-get update",
+      "sudo apt-get install -y nginx",
+    ]
+  }
+
+  post-processor "manifest" {}
+}
@@ -0,0 +1,101 @@
+# Web tier for the staging environment
+
+terraform {
+  required_version = ">= 1.5"
+
+  required_providers {
+    aws = {
+      source  = "hashicorp/aws"
+      version = "~> 5.0"
+    }
+  }
+
+  backend "s3" {
+    bucket = "acme-terraform-state"
+    key    = "staging/web.tfstate"
+    region = "us-east-1"
+  }
+}
+
+provider "aws" {
+  region = var.region
+}
+
+variable "region" {
+  type        = string
+  default     = "us-east-1"
+  description = "AWS region to deploy into"
+}
+
+variable "instance_count" {
+  type    = number
+  default = 2
+}
+
+locals {
+  name_prefix = "web-${var.environment}"
+  common_tags = merge(var.tags, {
+    Environment = var.environment
+    ManagedBy   = "terraform"
+  })
+}
+
+data "aws_ami" "ubuntu" {
+  most_recent = true
+  owners      = ["099720109477"]
+
+  filter {
+    name   = "name"
+    values = ["ubuntu/images/hvm-ssd/ubuntu-jammy-22.04-amd64-server-*"]
+  }
+}
+
+resource "aws_instance" "web" {
+  count         = var.instance_count
+  ami           = data.aws_ami.ubuntu.id
+  instance_type = "t3.micro"
+
+  user_data = <<-EOT
+    #!/bin/bash
+    echo "hello from ${local.name_prefix}" > /var/www/index.html
+  EOT
+
+  root_block_device {
+    volume_size = 20
+    encrypted   = true
+  }
+
+  lifecycle {
+    create_before_destroy = true
+  }
+
+  tags = merge(local.common_tags, { Name = "${local.name_prefix}-${count.index}" })
+}
+
+/*
+ * Security group allowing HTTP in.
+ */
+resource "aws_security_group" "web" {
+  name = "${local.name_prefix}-sg"
+
+  dynamic "ingress" {
+    for_each = [80, 443]
+    content {
+      from_port   = ingress.value
+      to_port     = ingress.value
+      protocol    = "tcp"
+      cidr_blocks = ["0.0.0.0/0"]
+    }
+  }
+}
+
+module "monitoring" {
+  source = "./modules/monitoring"
+
+  instance_ids = aws_instance.web[*].id
+}
+
+output "public_ips" {
+  value       = [for i in aws_instance.web : i.public_ip]
+  description = "Public IPs of the web instances"
+}
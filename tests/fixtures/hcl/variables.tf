@@ -0,0 +1,38 @@
+variable "environment" {
+  type        = string
+  description = "Deployment environment"
+
+  validation {
+    condition     = contains(["staging", "production"], var.environment)
+    error_message = "Environment must be staging or production."
+  }
+}
+
+variable "services" {
+  type = map(object({
+    port     = number
+    replicas = optional(number, 1)
+  }))
+}
+
+locals {
+  ports = { for name, svc in var.services : name => svc.port }
+  motd  = "%{ if var.environment == "production" }LIVE%{ else }test%{ endif }"
+  ratio = var.replicas / 2
+}
+
+moved {
+  from = aws_instance.web
+  to   = aws_instance.app
+}
+
+check "health" {
+  data "http" "site" {
+    url = "https://${local.domain}/health"
+  }
+
+  assert {
+    condition     = data.http.site.status_code == 200
+    error_message = "Site is down"
+  }
+}
@@ -0,0 +1,36 @@
+job "web" {
+  datacenters = ["dc1"]
+  type        = "service"
+
+  group "app" {
+    count = 3
+
+    network {
+      port "http" {
+        to = 8080
+      }
+    }
+
+    task "server" {
+      driver = "docker"
+
+      config {
+        image = "acme/orders:2.1.0"
+        ports = ["http"]
+      }
+
+      template {
+        destination = "local/app.env"
+        data        = <<EOH
+DATABASE_URL={{ key "orders/database_url" }}
+{{ range service "redis" }}REDIS={{ .Address }}:{{ .Port }}{{ end }}
+EOH
+      }
+
+      resources {
+        cpu    = 500
+        memory = 256
+      }
+    }
+  }
+}
@@ -0,0 +1,25 @@
+import React, { useState } from "react";
+
+export function Button({ label, onClick }) {
+  return (
+    <button className="btn" onClick={onClick}>
+      {label}
+    </button>
+  );
+}
+
+export const Counter = ({ start }) => {
+  const [count, setCount] = useState(start);
+  return (
+    <div>
+      <span>{count}</span>
+      <Button label="+" onClick={() => setCount(count + 1)} />
+    </div>
+  );
+};
+
+export class Panel extends React.Component {
+  render() {
+    return <section><h2>{this.props.title}</h2>{this.props.children}</section>;
+  }
+}
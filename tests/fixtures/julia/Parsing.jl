@@ -0,0 +1,61 @@
+module Parsing
+
+export Token, ParseError, tokenize
+
+@enum TokenKind NUMBER IDENT OPERATOR
+
+primitive type Tag8 8 end
+
+abstract type AbstractToken end
+
+"""
+A single lexical token with its source offset.
+"""
+struct Token <: AbstractToken
+    kind::TokenKind
+    text::String
+    offset::Int
+
+    function Token(kind, text, offset)
+        offset >= 1 || throw(DomainError(offset, "offsets are 1-based"))
+        new(kind, text, offset)
+    end
+end
+
+struct ParseError <: Exception
+    msg::String
+    offset::Int
+end
+
+Base.showerror(io::IO, e::ParseError) = print(io, "parse error at ", e.offset, ": ", e.msg)
+
+const Tokens = Vector{Token}
+
+module Lexer
+
+# FIXME: operators longer than one character
+isoperator(c::Char) = c in "+-*/"
+
+function classify(c::Char)
+    isdigit(c) && return :number
+    isletter(c) && return :ident
+    return isoperator(c) ? :operator : :other
+end
+
+end # module Lexer
+
+function tokenize(src::AbstractString)::Tokens
+    tokens = Token[]
+    for (i, c) in enumerate(src)
+        kind = Lexer.classify(c)
+        kind === :other && throw(ParseError("unexpected $(repr(c))", i))
+        push!(tokens, Token(kind === :number ? NUMBER : kind === :ident ? IDENT : OPERATOR, string(c), i))
+    end
+    return tokens
+end
+
+macro tok_str(s)
+    :(tokenize($s))
+end
+
+end # module Parsing
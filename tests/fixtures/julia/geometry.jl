@@ -0,0 +1,54 @@
+# Geometry primitives for the shape solver
+module Geometry
+
+using LinearAlgebra
+export Shape, Circle, Rectangle, area, perimeter
+
+"""
+    Shape
+
+Supertype of every closed 2D shape.
+"""
+abstract type Shape end
+
+struct Circle <: Shape
+    radius::Float64
+end
+
+mutable struct Rectangle{T<:Real} <: Shape
+    width::T
+    height::T
+end
+
+"""
+    area(s::Shape)
+
+Return the enclosed area of `s`.
+"""
+function area(c::Circle)
+    # πr²
+    r = c.radius
+    return π * r^2
+end
+
+function area(r::Rectangle)
+    return r.width * r.height
+end
+
+function perimeter(r::Rectangle{T}) where {T}
+    total = zero(T)
+    for side in (r.width, r.height)
+        total += 2side
+    end
+    return total
+end
+
+macro check(expr)
+    quote
+        $(esc(expr)) || error("check failed")
+    end
+end
+
+scale(c::Circle, k) = Circle(c.radius * k)
+
+end # module
@@ -0,0 +1,24 @@
+using Test
+
+include("../src/stats.jl")
+
+# Shared fixture for every testset below
+const SAMPLE = [1.0, 2.0, 3.0, 4.0, 100.0]
+
+@testset "trimmed_mean" begin
+    @test trimmed_mean(SAMPLE; trim=0.2) ≈ 3.0
+    @test_throws ArgumentError trimmed_mean(SAMPLE; trim=0.5)
+end
+
+@testset "variance" begin
+    @test variance([1, 1, 1]) == 0
+    @testset "uncorrected" begin
+        @test variance([1, 3], false) == 1
+    end
+end
+
+function approx_all(xs, ys; atol=1e-8)
+    all(isapprox.(xs, ys; atol=atol))
+end
+
+@test approx_all(clamp01.([-1.0, 0.5, 2.0]), [0.0, 0.5, 1.0])
@@ -0,0 +1,45 @@
+#=
+Summary statistics over numeric vectors.
+Kept dependency-free so it loads in the REPL instantly.
+=#
+
+const DEFAULT_TRIM = 0.1
+const MAX_SAMPLES = 1_000_000
+
+"""
+    trimmed_mean(xs; trim=DEFAULT_TRIM)
+
+Mean of `xs` after dropping the `trim` fraction from each tail.
+"""
+function trimmed_mean(xs::AbstractVector{<:Real}; trim::Real=DEFAULT_TRIM)
+    0 <= trim < 0.5 || throw(ArgumentError("trim must be in [0, 0.5)"))
+    sorted = sort(xs)
+    k = floor(Int, trim * length(sorted))
+    return sum(sorted[k+1:end-k]) / (length(sorted) - 2k)
+end
+
+# TODO: switch to a streaming algorithm for large inputs
+function variance(xs, corrected::Bool=true)
+    n = length(xs)
+    n > MAX_SAMPLES && error("too many samples: $n")
+    m = sum(xs) / n
+    return sum(x -> (x - m)^2, xs) / (corrected ? n - 1 : n)
+end
+
+@inline function clamp01(x::T) where {T<:AbstractFloat}
+    return min(max(x, zero(T)), one(T))
+end
+
+zscore(x, m, s) = (x - m) / s
+
+function describe(io::IO, xs)
+    try
+        println(io, "mean: ", trimmed_mean(xs))
+    catch err
+        err isa ArgumentError || rethrow()
+        println(io, "mean: n/a")
+    end
+    foreach(xs) do x
+        print(io, zscore(x, 0, 1), ' ')
+    end
+end
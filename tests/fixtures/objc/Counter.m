@@ -0,0 +1,53 @@
+#import <Foundation/Foundation.h>
+
+/// Receives counter change notifications.
+@protocol CounterDelegate <NSObject>
+- (void)counterDidChange:(NSInteger)value;
+@optional
+- (BOOL)counterShouldReset;
+@end
+
+// Internal note: keep in sync with the Swift wrapper.
+@interface Counter : NSObject
+@property (nonatomic, readonly) NSInteger value;
+@property (nonatomic, weak) id<CounterDelegate> delegate;
+- (instancetype)initWithStep:(NSInteger)step;
+- (void)incrementBy:(NSInteger)amount;
++ (Counter *)sharedCounter;
+@end
+
+typedef NS_ENUM(NSInteger, CounterMode) {
+    CounterModeUp,
+    CounterModeDown,
+};
+
+static NSInteger clampValue(NSInteger value) {
+    return value < 0 ? 0 : value;
+}
+
+@implementation Counter {
+    NSInteger _step;
+}
+
+- (instancetype)initWithStep:(NSInteger)step {
+    self = [super init];
+    if (self) {
+        _step = step;
+    }
+    return self;
+}
+
+- (void)incrementBy:(NSInteger)amount {
+    _value = clampValue(_value + amount * _step);
+    [self.delegate counterDidChange:_value];
+}
+
++ (Counter *)sharedCounter {
+    static Counter *shared = nil;
+    if (!shared) {
+        shared = [[Counter alloc] initWithStep:1];
+    }
+    return shared;
+}
+
+@end
@@ -0,0 +1,35 @@
+#import <Foundation/Foundation.h>
+
+// FIXME: transliterate non-Latin scripts instead of dropping them.
+
+@interface NSString (Slug)
+- (NSString *)slugString;
++ (NSString *)slugFromComponents:(NSArray<NSString *> *)components;
+@end
+
+@implementation NSString (Slug)
+
+/// Lowercase, hyphen-separated form suitable for URLs.
+- (NSString *)slugString {
+    NSCharacterSet *allowed = [NSCharacterSet alphanumericCharacterSet];
+    NSMutableArray *words = [NSMutableArray array];
+    for (NSString *word in [self.lowercaseString componentsSeparatedByCharactersInSet:allowed.invertedSet]) {
+        if (word.length > 0) {
+            [words addObject:word];
+        }
+    }
+    return [words componentsJoinedByString:@"-"];
+}
+
++ (NSString *)slugFromComponents:(NSArray<NSString *> *)components {
+    return [[components componentsJoinedByString:@" "] slugString];
+}
+
+@end
+
+int main(int argc, const char *argv[]) {
+    @autoreleasepool {
+        NSLog(@"%@", [@"Hello, World!" slugString]);
+    }
+    return 0;
+}
@@ -0,0 +1,26 @@
+#import <Foundation/Foundation.h>
+
+extern NSString *const NetworkClientErrorDomain;
+
+/// Error codes in `NetworkClientErrorDomain`.
+typedef enum {
+    NetworkClientErrorTimeout = 1,
+    NetworkClientErrorBadResponse,
+} NetworkClientError;
+
+typedef void (^NetworkCompletion)(NSData *data, NSError *error);
+
+/// A minimal JSON-over-HTTP client.
+@interface NetworkClient : NSObject
+
+@property (nonatomic, copy, readonly) NSURL *baseURL;
+@property (nonatomic, assign) NSTimeInterval timeout;
+
+- (instancetype)initWithBaseURL:(NSURL *)baseURL NS_DESIGNATED_INITIALIZER;
+- (instancetype)init NS_UNAVAILABLE;
+
+/// Fetch `path` relative to the base URL.
+- (void)getPath:(NSString *)path completion:(NetworkCompletion)completion;
+- (void)postPath:(NSString *)path body:(NSDictionary *)body completion:(NetworkCompletion)completion;
+
+@end
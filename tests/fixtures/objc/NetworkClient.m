@@ -0,0 +1,62 @@
+#import "NetworkClient.h"
+
+NSString *const NetworkClientErrorDomain = @"NetworkClientErrorDomain";
+static const NSTimeInterval kDefaultTimeout = 30.0;
+
+@interface NetworkClient ()
+@property (nonatomic, strong) NSURLSession *session;
+- (NSURLRequest *)requestForPath:(NSString *)path method:(NSString *)method;
+@end
+
+@implementation NetworkClient
+
+- (instancetype)initWithBaseURL:(NSURL *)baseURL {
+    self = [super init];
+    if (self) {
+        _baseURL = [baseURL copy];
+        _timeout = kDefaultTimeout;
+        _session = [NSURLSession sharedSession];
+    }
+    return self;
+}
+
+- (instancetype)init {
+    @throw [NSException exceptionWithName:NSInternalInconsistencyException
+                                   reason:@"Use initWithBaseURL:"
+                                 userInfo:nil];
+}
+
+- (void)getPath:(NSString *)path completion:(NetworkCompletion)completion {
+    NSURLRequest *request = [self requestForPath:path method:@"GET"];
+    // TODO: retry once on timeout.
+    [[self.session dataTaskWithRequest:request
+                     completionHandler:^(NSData *data, NSURLResponse *response, NSError *error) {
+        completion(data, error);
+    }] resume];
+}
+
+- (void)postPath:(NSString *)path body:(NSDictionary *)body completion:(NetworkCompletion)completion {
+    NSError *error = nil;
+    NSData *json = [NSJSONSerialization dataWithJSONObject:body options:0 error:&error];
+    if (!json) {
+        completion(nil, error);
+        return;
+    }
+    NSMutableURLRequest *request = [[self requestForPath:path method:@"POST"] mutableCopy];
+    request.HTTPBody = json;
+    [[self.session dataTaskWithRequest:request completionHandler:^(NSData *data, NSURLResponse *response, NSError *err) {
+        completion(data, err);
+    }] resume];
+}
+
+#pragma mark - Private
+
+- (NSURLRequest *)requestForPath:(NSString *)path method:(NSString *)method {
+    NSURL *url = [self.baseURL URLByAppendingPathComponent:path];
+    NSMutableURLRequest *request = [NSMutableURLRequest requestWithURL:url];
+    request.HTTPMethod = method;
+    request.timeoutInterval = self.timeout;
+    return request;
+}
+
+@end
@@ -0,0 +1,8 @@
+let ok x = x + 1
+
+let broken y =
+  match y with
+  | Some z -> z +
+  | None ->
+
+let after = 3
@@ -0,0 +1,37 @@
+(* Expression evaluator with a GADT *)
+
+type _ expr =
+  | Int : int -> int expr
+  | Bool : bool -> bool expr
+  | Add : int expr * int expr -> int expr
+  | If : bool expr * 'a expr * 'a expr -> 'a expr
+[@@deriving show]
+
+type token = [ `Num of int | `Plus | `Lparen | `Rparen ]
+
+let rec eval : type a. a expr -> a = function
+  | Int n -> n
+  | Bool b -> b
+  | Add (a, b) -> eval a + eval b
+  | If (c, t, e) -> if eval c then eval t else eval e
+
+let ( >>= ) opt f = match opt with Some x -> f x | None -> None
+
+let tokenize (input : string) : token list =
+  let buf = Buffer.create 8 in
+  ignore buf;
+  String.fold_right
+    (fun c acc -> match c with '+' -> `Plus :: acc | _ -> acc)
+    input []
+
+class counter ~start = object (self)
+  val mutable count = start
+
+  method incr = count <- count + 1; self#get
+
+  method get = count
+end
+
+let () =
+  let result = eval (If (Bool true, Int 1, Add (Int 2, Int 3))) in
+  Printf.printf "%d\n" result
@@ -0,0 +1,39 @@
+(** Binary min-heap functor. *)
+
+module type ORDERED = sig
+  type t
+
+  val compare : t -> t -> int
+end
+
+module Make (Ord : ORDERED) = struct
+  type elt = Ord.t
+
+  type t = { mutable data : elt array; mutable size : int }
+
+  let create ?(capacity = 16) ~dummy () =
+    { data = Array.make capacity dummy; size = 0 }
+
+  let is_empty heap = heap.size = 0
+
+  let swap heap i j =
+    let tmp = heap.data.(i) in
+    heap.data.(i) <- heap.data.(j);
+    heap.data.(j) <- tmp
+
+  let rec sift_up heap i =
+    let parent = (i - 1) / 2 in
+    if i > 0 && Ord.compare heap.data.(i) heap.data.(parent) < 0 then begin
+      swap heap i parent;
+      sift_up heap parent
+    end
+
+  let push heap x =
+    if heap.size = Array.length heap.data then
+      heap.data <- Array.append heap.data heap.data;
+    heap.data.(heap.size) <- x;
+    heap.size <- heap.size + 1;
+    sift_up heap (heap.size - 1)
+end
+
+module IntHeap = Make (Int)
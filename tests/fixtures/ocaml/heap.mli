@@ -0,0 +1,15 @@
+(** Binary min-heap functor. *)
+
+module type ORDERED = sig
+  type t
+
+  val compare : t -> t -> int
+end
+
+module Make (Ord : ORDERED) : sig
+  type t
+
+  val create : ?capacity:int -> dummy:Ord.t -> unit -> t
+  val is_empty : t -> bool
+  val push : t -> Ord.t -> unit
+end
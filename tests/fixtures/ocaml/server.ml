@@ -0,0 +1,30 @@
+open Lwt.Syntax
+
+module Config : sig
+  type t = { port : int; root : string }
+
+  val load : string -> t
+end = struct
+  type t = { port : int; root : string }
+
+  let load path =
+    let ic = open_in path in
+    let port = int_of_string (input_line ic) in
+    close_in ic;
+    { port; root = Filename.dirname path }
+end
+
+let max_body_size = 1_048_576
+
+let handle_request (conn : Unix.file_descr) =
+  let* body = Lwt_io.read (Lwt_io.of_fd ~mode:Lwt_io.input (Lwt_unix.of_unix_file_descr conn)) in
+  if String.length body > max_body_size then Lwt.return_error `Too_large
+  else Lwt.return_ok (String.uppercase_ascii body)
+
+let serve ~(config : Config.t) =
+  let%lwt () = Lwt_io.printlf "listening on %d" config.port in
+  Lwt.return_unit
+
+let main () =
+  let config = Config.load "server.conf" in
+  Lwt_main.run (serve ~config)
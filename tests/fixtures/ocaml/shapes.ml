@@ -0,0 +1,39 @@
+(* Geometry helpers for the layout engine *)
+
+open Printf
+
+type point = { x : float; y : float }
+
+type shape =
+  | Circle of point * float
+  | Rect of point * point
+
+exception Degenerate of string
+
+module type SHAPE = sig
+  val area : shape -> float
+end
+
+let pi = 4.0 *. atan 1.0
+
+(** [area s] is the enclosed area of [s]. *)
+let area = function
+  | Circle (_, r) -> pi *. r *. r
+  | Rect (a, b) -> abs_float ((b.x -. a.x) *. (b.y -. a.y))
+
+let rec perimeter shape =
+  match shape with
+  (* corners are not rounded *)
+  | Circle (_, r) -> 2.0 *. pi *. r
+  | Rect (a, b) -> 2.0 *. (abs_float (b.x -. a.x) +. abs_float (b.y -. a.y))
+
+and describe shape =
+  sprintf "shape with perimeter %.2f" (perimeter shape)
+
+module Scale = struct
+  let by factor = function
+    | Circle (c, r) -> Circle (c, r *. factor)
+    | Rect _ as r -> r
+end
+
+external hypot : float -> float -> float = "caml_hypot_float" "caml_hypot"
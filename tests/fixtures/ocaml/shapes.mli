@@ -0,0 +1,13 @@
+(** Geometry helpers for the layout engine *)
+
+type point = { x : float; y : float }
+
+type shape =
+  | Circle of point * float
+  | Rect of point * point
+
+val area : shape -> float
+(** [area s] is the enclosed area of [s]. *)
+
+val perimeter : shape -> float
+val describe : shape -> string
@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+set -o errexit -o nounset
+
+SOURCES=(/etc /home /srv)
+DEST="${BACKUP_DEST:-/mnt/backup}"
+STAMP="$(date +%Y%m%d)"
+
+cleanup() {
+    rm -f "$DEST/.lock"
+}
+trap cleanup EXIT
+
+archive() {
+    local src="$1"
+    local name
+    name="$(basename "$src")"
+    tar -czf "$DEST/$name-$STAMP.tar.gz" -C "$(dirname "$src")" "$name"
+}
+
+for src in "${SOURCES[@]}"; do
+    if [[ -d "$src" ]]; then
+        echo "archiving $src"
+        archive "$src"
+    else
+        echo "skipping $src" >&2
+    fi
+done
+
+while read -r old; do
+    rm -- "$old"
+done < <(find "$DEST" -name '*.tar.gz' -mtime +30)
+
+cat > "$DEST/README" <<NOTE
+Backups of ${SOURCES[*]}, taken $STAMP.
+Restore with: tar -xzf FILE -C /
+NOTE
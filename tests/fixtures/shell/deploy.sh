@@ -0,0 +1,56 @@
+#!/usr/bin/env bash
+# Deploy the service to the selected environment.
+set -euo pipefail
+
+readonly APP_NAME="orders"
+ENVIRONMENT="${1:-staging}"
+
+log() {
+    local level="$1"
+    shift
+    printf '[%s] %s\n' "$level" "$*" >&2
+}
+
+function build_image {
+    log info "building ${APP_NAME}"
+    docker build -t "${APP_NAME}:latest" .
+    docker tag "${APP_NAME}:latest" "registry.local/${APP_NAME}:latest"
+}
+
+push_image() {
+    docker push "registry.local/${APP_NAME}:latest"
+}
+
+if [ ! -f Dockerfile ]; then
+    log error "no Dockerfile"
+fi
+
+case "$ENVIRONMENT" in
+    staging)
+        REPLICAS=1
+        CLUSTER="stage-eu"
+        ;;
+    production)
+        REPLICAS=3
+        CLUSTER="prod-eu"
+        ;;
+    *)
+        log error "unknown environment: $ENVIRONMENT"
+        exit 1
+        ;;
+esac
+
+if [ "$ENVIRONMENT" = "production" ]; then
+    log warn "deploying to production"
+    read -r -p "Continue? [y/N] " answer
+    if [ "$answer" != "y" ]; then
+        log info "aborted"
+        exit 0
+    fi
+    build_image
+    push_image
+else
+    build_image
+fi
+
+kubectl scale deployment "$APP_NAME" --replicas="$REPLICAS" --context "$CLUSTER"
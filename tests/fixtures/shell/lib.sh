@@ -0,0 +1,39 @@
+#!/bin/sh
+# Shared helpers, sourced by the other scripts.
+
+# shellcheck source=/dev/null
+. "$(dirname "$0")/env.sh"
+
+readonly LOG_DIR="/var/log/orders"
+export RETRIES=3
+DEFAULT_TIMEOUT=30
+
+# Print a message to stderr and exit.
+# Usage: die MESSAGE [CODE]
+die() {
+    echo "error: $1" >&2
+    exit "${2:-1}"
+}
+
+# Retry a command until it succeeds.
+retry() {
+    n=0
+    until "$@"; do
+        n=$((n + 1))
+        if [ "$n" -ge "$RETRIES" ]; then
+            die "giving up after $n attempts: $*"
+        fi
+        sleep "$n"
+    done
+}
+
+# Run in a subshell so the cd does not leak.
+in_dir() (
+    cd "$1" || exit 1
+    shift
+    "$@"
+)
+
+require() {
+    command -v "$1" >/dev/null 2>&1 || die "missing dependency: $1" 127
+}
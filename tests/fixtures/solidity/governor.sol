@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+// FIXME: proposals can be executed twice if the call reverts late.
+
+/// @notice Something that can vote.
+interface IVotes {
+    function getVotes(address account) external view returns (uint256);
+}
+
+abstract contract Ownable {
+    address public owner;
+
+    modifier onlyOwner() {
+        require(msg.sender == owner, "not owner");
+        _;
+    }
+
+    function transferOwnership(address next) public virtual onlyOwner {
+        owner = next;
+    }
+}
+
+contract Governor is Ownable {
+    enum State { Pending, Active, Executed }
+
+    struct Proposal {
+        address target;
+        bytes data;
+        uint256 votes;
+        State state;
+    }
+
+    uint256 public constant QUORUM = 1000;
+
+    IVotes public immutable votes;
+    Proposal[] public proposals;
+
+    event Proposed(uint256 indexed id, address target);
+
+    modifier inState(uint256 id, State expected) {
+        require(proposals[id].state == expected, "wrong state");
+        _;
+    }
+
+    constructor(IVotes votes_) {
+        votes = votes_;
+        owner = msg.sender;
+    }
+
+    function propose(address target, bytes calldata data) external returns (uint256 id) {
+        id = proposals.length;
+        proposals.push(Proposal(target, data, 0, State.Pending));
+        emit Proposed(id, target);
+    }
+
+    function execute(uint256 id) external inState(id, State.Active) {
+        Proposal storage proposal = proposals[id];
+        require(proposal.votes >= QUORUM, "no quorum");
+        proposal.state = State.Executed;
+        (bool ok, ) = proposal.target.call(proposal.data);
+        if (!ok) revert("call failed");
+    }
+
+    function transferOwnership(address next) public override onlyOwner {
+        require(next != address(0), "zero owner");
+        super.transferOwnership(next);
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+/// @title Fixed-point helpers
+/// @dev All amounts use 18 decimals.
+library FixedMath {
+    uint256 internal constant WAD = 1e18;
+    uint256 internal constant HALF_WAD = WAD / 2;
+
+    error DivisionByZero();
+
+    /// @notice Multiply two WAD values, rounding half up.
+    function mulWad(uint256 a, uint256 b) internal pure returns (uint256) {
+        return (a * b + HALF_WAD) / WAD;
+    }
+
+    function divWad(uint256 a, uint256 b) internal pure returns (uint256) {
+        if (b == 0) revert DivisionByZero();
+        return (a * WAD + b / 2) / b;
+    }
+
+    function min(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a < b ? a : b;
+    }
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+import {FixedMath} from "./math.sol";
+
+/**
+ * @title Simple fee-charging token
+ * @notice Transfers pay a fee to the treasury.
+ */
+contract FeeToken {
+    using FixedMath for uint256;
+
+    string public constant NAME = "Fee Token";
+    uint8 public constant DECIMALS = 18;
+    uint256 public constant MAX_FEE = 5e16;
+
+    address public immutable treasury;
+    uint256 public totalSupply;
+    uint256 public fee;
+
+    mapping(address => uint256) public balanceOf;
+    mapping(address => mapping(address => uint256)) public allowance;
+
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event FeeChanged(uint256 previous, uint256 current);
+
+    error InsufficientBalance(uint256 available, uint256 required);
+
+    constructor(address treasury_, uint256 supply) {
+        treasury = treasury_;
+        totalSupply = supply;
+        balanceOf[msg.sender] = supply;
+    }
+
+    /// @notice Move `value` tokens to `to`, minus the fee.
+    function transfer(address to, uint256 value) external returns (bool) {
+        uint256 available = balanceOf[msg.sender];
+        if (available < value) {
+            revert InsufficientBalance(available, value);
+        }
+        // TODO: exempt the treasury from fees.
+        uint256 charged = value.mulWad(fee);
+        unchecked {
+            balanceOf[msg.sender] = available - value;
+        }
+        balanceOf[to] += value - charged;
+        balanceOf[treasury] += charged;
+        emit Transfer(msg.sender, to, value - charged);
+        return true;
+    }
+
+    function approve(address spender, uint256 value) external returns (bool) {
+        allowance[msg.sender][spender] = value;
+        return true;
+    }
+
+    function setFee(uint256 next) external {
+        require(msg.sender == treasury, "only treasury");
+        require(next <= MAX_FEE, "fee too high");
+        emit FeeChanged(fee, next);
+        fee = next;
+    }
+
+    fallback() external {
+        revert("unknown call");
+    }
+}
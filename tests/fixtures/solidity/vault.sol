@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+import "./IERC20.sol";
+
+/// @title Minimal token vault
+interface IVault {
+    function deposit(uint256 amount) external;
+    function balanceOf(address account) external view returns (uint256);
+}
+
+// Internal bookkeeping note.
+contract Vault is IVault {
+    struct Position {
+        uint256 amount;
+        uint64 since;
+    }
+
+    enum Status { Open, Paused }
+
+    event Deposited(address indexed account, uint256 amount);
+
+    error NotOwner(address caller);
+
+    address public owner;
+    IERC20 public immutable token;
+    mapping(address => Position) private positions;
+
+    modifier onlyOwner() {
+        if (msg.sender != owner) revert NotOwner(msg.sender);
+        _;
+    }
+
+    constructor(IERC20 token_) {
+        owner = msg.sender;
+        token = token_;
+    }
+
+    /// @notice Deposit `amount` tokens into the vault.
+    function deposit(uint256 amount) external {
+        token.transferFrom(msg.sender, address(this), amount);
+        positions[msg.sender].amount += amount;
+        emit Deposited(msg.sender, amount);
+    }
+
+    function balanceOf(address account) external view returns (uint256) {
+        return positions[account].amount;
+    }
+
+    function setOwner(address next) external onlyOwner {
+        owner = next;
+    }
+
+    receive() external payable {
+        revert("no ether");
+    }
+}
@@ -0,0 +1,30 @@
+import React, { useState } from "react";
+
+export interface ButtonProps {
+  label: string;
+  onClick: () => void;
+}
+
+export function Button({ label, onClick }: ButtonProps): JSX.Element {
+  return (
+    <button className="btn" onClick={onClick}>
+      {label}
+    </button>
+  );
+}
+
+export const Counter = ({ start }: { start: number }) => {
+  const [count, setCount] = useState<number>(start);
+  return (
+    <div>
+      <span>{count}</span>
+      <Button label="+" onClick={() => setCount(count + 1)} />
+    </div>
+  );
+};
+
+export class Panel extends React.Component<{ title: string }> {
+  render() {
+    return <section><h2>{this.props.title}</h2>{this.props.children}</section>;
+  }
+}
@@ -0,0 +1,36 @@
+// Top-level constants holding large template literals.
+
+export const WELCOME_EMAIL = `
+<html>
+  <body>
+    <table>
+      <tr><td>Row 0</td><td>{{value_0}}</td></tr>
+      <tr><td>Row 1</td><td>{{value_1}}</td></tr>
+      <tr><td>Row 2</td><td>{{value_2}}</td></tr>
+      <tr><td>Row 3</td><td>{{value_3}}</td></tr>
+      <tr><td>Row 4</td><td>{{value_4}}</td></tr>
+      <tr><td>Row 5</td><td>{{value_5}}</td></tr>
+      <tr><td>Row 6</td><td>{{value_6}}</td></tr>
+      <tr><td>Row 7</td><td>{{value_7}}</td></tr>
+      <tr><td>Row 8</td><td>{{value_8}}</td></tr>
+      <tr><td>Row 9</td><td>{{value_9}}</td></tr>
+      <tr><td>Row 10</td><td>{{value_10}}</td></tr>
+      <tr><td>Row 11</td><td>{{value_11}}</td></tr>
+      <tr><td>Row 12</td><td>{{value_12}}</td></tr>
+      <tr><td>Row 13</td><td>{{value_13}}</td></tr>
+      <tr><td>Row 14</td><td>{{value_14}}</td></tr>
+      <tr><td>Row 15</td><td>{{value_15}}</td></tr>
+      <tr><td>Row 16</td><td>{{value_16}}</td></tr>
+      <tr><td>Row 17</td><td>{{value_17}}</td></tr>
+      <tr><td>Row 18</td><td>{{value_18}}</td></tr>
+      <tr><td>Row 19</td><td>{{value_19}}</td></tr>
+    </table>
+  </body>
+</html>
+`;
+
+export const GET_USER = `query GetUser($id: ID!) { user(id: $id) { name } }`;
+
+export function renderEmail(name: string): string {
+  return WELCOME_EMAIL.replace("{{name}}", name);
+}
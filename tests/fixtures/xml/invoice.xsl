@@ -0,0 +1,24 @@
+<?xml version="1.0" encoding="UTF-8"?>
+<xsl:stylesheet version="1.0" xmlns:xsl="http://www.w3.org/1999/XSL/Transform">
+  <xsl:output method="html" indent="yes"/>
+
+  <xsl:template match="/invoice">
+    <html>
+      <body>
+        <h1>Invoice <xsl:value-of select="@number"/></h1>
+        <table>
+          <xsl:for-each select="line[amount &gt; 0]">
+            <xsl:sort select="amount" data-type="number" order="descending"/>
+            <tr>
+              <td><xsl:value-of select="description"/></td>
+              <td><xsl:value-of select="format-number(amount, '0.00')"/></td>
+            </tr>
+          </xsl:for-each>
+        </table>
+        <xsl:if test="count(line) &lt; 1">
+          <p>No lines.</p>
+        </xsl:if>
+      </body>
+    </html>
+  </xsl:template>
+</xsl:stylesheet>